@@ -6,6 +6,9 @@
 //! Cache key: (file_content_hash, config_hash, rumdl_version)
 //! Cache value: Vec<LintWarning>
 //! Storage: .rumdl_cache/{version}/{hash}.json
+//!
+//! Warnings from rules that aren't `cacheable()` (their results depend on other files)
+//! are never stored; those rules are re-run on every cache hit.
 
 use rumdl_lib::rule::LintWarning;
 use serde::{Deserialize, Serialize};
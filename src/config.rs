@@ -117,11 +117,54 @@ impl MarkdownFlavor {
     }
 }
 
+/// Comment syntax recognized for inline configuration (`rumdl-disable`, `markdownlint-enable`, ...)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum InlineCommentSyntax {
+    /// HTML comments: `<!-- rumdl-disable MD013 -->`
+    Html,
+    /// JSX comments, for MDX where HTML comments aren't valid: `{/* rumdl-disable MD013 */}`
+    /// HTML comments are still recognized.
+    Jsx,
+}
+
+impl FromStr for InlineCommentSyntax {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "html" => Ok(Self::Html),
+            "jsx" | "mdx" => Ok(Self::Jsx),
+            _ => Err(format!("Unknown inline comment syntax: {s}")),
+        }
+    }
+}
+
+impl InlineCommentSyntax {
+    /// Default comment syntax for a flavor: JSX for MDX, HTML otherwise
+    pub fn for_flavor(flavor: MarkdownFlavor) -> Self {
+        if flavor == MarkdownFlavor::MDX {
+            Self::Jsx
+        } else {
+            Self::Html
+        }
+    }
+}
+
+/// Rule IDs that were renamed, mapped to their current ID
+const RENAMED_RULES: &[(&str, &str)] = &[("MD901", "MD063")];
+
 /// Normalizes configuration keys (rule names, option names) to lowercase kebab-case.
+///
+/// Renamed rule IDs (e.g. MD901) resolve to their current ID (MD063).
 pub fn normalize_key(key: &str) -> String {
     // If the key looks like a rule name (e.g., MD013), uppercase it
     if key.len() == 5 && key.to_ascii_lowercase().starts_with("md") && key[2..].chars().all(|c| c.is_ascii_digit()) {
-        key.to_ascii_uppercase()
+        let rule_name = key.to_ascii_uppercase();
+        RENAMED_RULES
+            .iter()
+            .find(|(old, _)| *old == rule_name)
+            .map_or(rule_name, |(_, new)| new.to_string())
     } else {
         key.replace('_', "-").to_ascii_lowercase()
     }
@@ -140,10 +183,69 @@ pub struct RuleConfig {
 fn arbitrary_value_schema(_gen: &mut schemars::SchemaGenerator) -> schemars::Schema {
     schemars::json_schema!({
         "type": "object",
+        "properties": {
+            "enabled-for-flavors": {
+                "description": "Markdown flavors this rule runs under, replacing the rule's built-in flavor support",
+                "type": "array",
+                "items": { "$ref": "#/$defs/MarkdownFlavor" }
+            }
+        },
         "additionalProperties": true
     })
 }
 
+/// Lowest rule severity the editor shows as a diagnostic
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum MinSeverity {
+    /// Show every warning and error
+    #[default]
+    Warning,
+    /// Show errors only
+    Error,
+}
+
+/// Editor settings shared by everyone working on the repository (`[vscode]`)
+///
+/// Unset values fall back to each developer's own editor settings.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct VscodeConfig {
+    /// Apply all available fixes when a document is saved
+    #[serde(alias = "format_on_save", skip_serializing_if = "Option::is_none")]
+    pub format_on_save: Option<bool>,
+
+    /// Lowest severity shown as a diagnostic ("warning" or "error")
+    #[serde(alias = "min_severity", skip_serializing_if = "Option::is_none")]
+    pub min_severity: Option<MinSeverity>,
+}
+
+impl VscodeConfig {
+    /// Whether a warning of the given severity should be shown in the editor
+    pub fn shows_severity(&self, severity: crate::rule::Severity) -> bool {
+        match self.min_severity.unwrap_or_default() {
+            MinSeverity::Warning => true,
+            MinSeverity::Error => severity == crate::rule::Severity::Error,
+        }
+    }
+}
+
+/// Rule selection for `rumdl fmt` (`[fmt]`)
+///
+/// Layered on top of the rules enabled for `check`, so `fmt` can apply a conservative
+/// subset while `check --fix` still applies everything.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct FmtConfig {
+    /// Rules `fmt` applies (empty means every enabled rule)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub enable: Vec<String>,
+
+    /// Rules `fmt` never applies, even if enabled for `check`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub disable: Vec<String>,
+}
+
 /// Represents the complete configuration loaded from rumdl.toml
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, schemars::JsonSchema)]
 #[schemars(
@@ -159,6 +261,14 @@ pub struct Config {
     #[serde(default, rename = "per-file-ignores")]
     pub per_file_ignores: HashMap<String, Vec<String>>,
 
+    /// Editor settings applied by the language server and VS Code extension
+    #[serde(default)]
+    pub vscode: VscodeConfig,
+
+    /// Rules applied by `rumdl fmt`, narrowing the rules enabled for `check`
+    #[serde(default)]
+    pub fmt: FmtConfig,
+
     /// Rule-specific configurations (e.g., MD013, MD007, MD044)
     /// Each rule section can contain options specific to that rule.
     ///
@@ -237,6 +347,22 @@ impl Config {
 
         ignored_rules
     }
+
+    /// This configuration with every rule section filled in: values that aren't set
+    /// explicitly get the rule's default, so the result lists every option of every rule
+    pub fn with_rule_defaults(&self) -> Config {
+        let mut effective = self.clone();
+        for rule in rules::all_rules(&Config::default()) {
+            let Some((rule_name, toml::Value::Table(defaults))) = rule.default_config_section() else {
+                continue;
+            };
+            let section = effective.rules.entry(rule_name.to_uppercase()).or_default();
+            for (key, value) in defaults {
+                section.values.entry(normalize_key(&key)).or_insert(value);
+            }
+        }
+        effective
+    }
 }
 
 /// Global configuration options
@@ -299,16 +425,90 @@ pub struct GlobalConfig {
     #[serde(default, alias = "cache_dir", skip_serializing_if = "Option::is_none")]
     pub cache_dir: Option<String>,
 
+    /// Directory that reported file paths are made relative to (default: paths as given)
+    /// A relative path is resolved against the project root. Can also be set via --report-root
+    #[serde(default, alias = "report_root", skip_serializing_if = "Option::is_none")]
+    pub report_root: Option<String>,
+
     /// Whether caching is enabled (default: true)
     /// Can also be disabled via --no-cache CLI flag
     #[serde(default = "default_true")]
     pub cache: bool,
+
+    /// Files or directories that mark the project root, in priority order (default: .git,
+    /// .rumdl.toml, rumdl.toml, .config/rumdl.toml, pyproject.toml, package.json, Cargo.toml)
+    /// The project root anchors the cache directory and path-relative settings
+    #[serde(
+        default = "default_root_markers",
+        alias = "root_markers",
+        skip_serializing_if = "is_default_root_markers"
+    )]
+    pub root_markers: Vec<String>,
+
+    /// Comment syntax for inline configuration comments: "html" or "jsx"
+    /// Defaults to "jsx" for the MDX flavor and "html" otherwise
+    #[serde(default, alias = "inline_comment_syntax", skip_serializing_if = "Option::is_none")]
+    pub inline_comment_syntax: Option<InlineCommentSyntax>,
+
+    /// Whether config files in subdirectories of the project apply to the files below them
+    /// (default: false). Nearer config files take precedence over the project config
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub hierarchical: bool,
 }
 
+/// Default project root markers, in priority order.
+///
+/// Each marker is searched for upward from the starting directory before the next
+/// marker is tried, so an enclosing `.git` wins over a nested `package.json`.
+pub const DEFAULT_ROOT_MARKERS: &[&str] = &[
+    ".git",
+    ".rumdl.toml",
+    "rumdl.toml",
+    ".config/rumdl.toml",
+    "pyproject.toml",
+    "package.json",
+    "Cargo.toml",
+];
+
 fn default_respect_gitignore() -> bool {
     true
 }
 
+fn default_root_markers() -> Vec<String> {
+    DEFAULT_ROOT_MARKERS.iter().map(|s| s.to_string()).collect()
+}
+
+fn is_default_root_markers(markers: &[String]) -> bool {
+    markers
+        .iter()
+        .map(String::as_str)
+        .eq(DEFAULT_ROOT_MARKERS.iter().copied())
+}
+
+/// Find the project root by searching upward from `start_dir` for each marker in turn.
+///
+/// Markers are tried in order; the first marker found in `start_dir` or any of its
+/// ancestors determines the root. Returns `None` if no marker is found.
+pub fn find_project_root(start_dir: &Path, markers: &[String]) -> Option<std::path::PathBuf> {
+    const MAX_DEPTH: usize = 100;
+
+    for marker in markers {
+        let mut current = start_dir.to_path_buf();
+        for _ in 0..MAX_DEPTH {
+            if current.join(marker).exists() {
+                log::debug!("[rumdl-config] Found root marker '{marker}' at: {}", current.display());
+                return Some(current);
+            }
+
+            if !current.pop() {
+                break;
+            }
+        }
+    }
+
+    None
+}
+
 fn default_true() -> bool {
     true
 }
@@ -330,7 +530,11 @@ impl Default for GlobalConfig {
             flavor: MarkdownFlavor::default(),
             force_exclude: false,
             cache_dir: None,
+            report_root: None,
             cache: true,
+            root_markers: default_root_markers(),
+            inline_comment_syntax: None,
+            hierarchical: false,
         }
     }
 }
@@ -439,6 +643,10 @@ pub enum ConfigError {
     /// Configuration file already exists
     #[error("Configuration file already exists at {path}")]
     FileExists { path: String },
+
+    /// Config files extend each other in a cycle
+    #[error("Circular extend in config files: {chain}")]
+    CircularExtend { chain: String },
 }
 
 /// Get a rule-specific configuration value
@@ -540,6 +748,25 @@ disable = ["MD001"]
         assert_eq!(config.global.disable, vec!["MD001".to_string()]);
     }
 
+    #[test]
+    fn test_with_rule_defaults() {
+        let config: Config = toml::from_str(
+            r#"
+[MD013]
+line-length = 120
+"#,
+        )
+        .unwrap();
+        let effective = config.with_rule_defaults();
+
+        // Explicit values are kept, the rest are filled in from the rule defaults
+        let md013 = &effective.rules["MD013"].values;
+        assert_eq!(md013["line-length"], toml::Value::Integer(120));
+        assert_eq!(md013["code-blocks"], toml::Value::Boolean(true));
+        assert_eq!(effective.rules["MD007"].values["indent"], toml::Value::Integer(2));
+        assert_eq!(effective.global, config.global);
+    }
+
     #[test]
     fn test_pyproject_toml_root_level_config() {
         let temp_dir = tempdir().unwrap();
@@ -989,6 +1216,10 @@ mixed = [1, "two", true]
         assert_eq!(normalize_key("Md001"), "MD001");
         assert_eq!(normalize_key("mD001"), "MD001");
 
+        // Renamed rules
+        assert_eq!(normalize_key("MD901"), "MD063");
+        assert_eq!(normalize_key("md901"), "MD063");
+
         // Non-rule names
         assert_eq!(normalize_key("line_length"), "line-length");
         assert_eq!(normalize_key("line-length"), "line-length");
@@ -1217,6 +1448,94 @@ local_time = 07:32:00
         assert!(ignored.is_empty());
     }
 
+    #[test]
+    fn test_vscode_section_parsing() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join(".rumdl.toml");
+        let config_content = r#"
+[vscode]
+format-on-save = true
+min_severity = "Error"
+format-on-change = true
+"#;
+        fs::write(&config_path, config_content).unwrap();
+
+        let sourced = SourcedConfig::load_with_discovery(Some(config_path.to_str().unwrap()), None, true).unwrap();
+        let registry = RuleRegistry::from_rules(&rules::all_rules(&Config::default()));
+        let warnings = validate_config_sourced(&sourced, &registry);
+        assert_eq!(warnings.len(), 1);
+        assert!(
+            warnings[0]
+                .message
+                .ends_with("format-on-change (did you mean: format-on-save?)")
+        );
+
+        let config: Config = sourced.into();
+        assert_eq!(config.vscode.format_on_save, Some(true));
+        assert_eq!(config.vscode.min_severity, Some(MinSeverity::Error));
+        assert!(!config.vscode.shows_severity(crate::rule::Severity::Warning));
+        assert!(config.rules.is_empty());
+    }
+
+    #[test]
+    fn test_vscode_section_pyproject_toml() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join("pyproject.toml");
+        let config_content = r#"
+[tool.rumdl.vscode]
+format-on-save = false
+min-severity = "warning"
+"#;
+        fs::write(&config_path, config_content).unwrap();
+
+        let sourced = SourcedConfig::load_with_discovery(Some(config_path.to_str().unwrap()), None, true).unwrap();
+        let config: Config = sourced.into();
+        assert_eq!(config.vscode.format_on_save, Some(false));
+        assert_eq!(config.vscode.min_severity, Some(MinSeverity::Warning));
+        assert!(config.rules.is_empty());
+    }
+
+    #[test]
+    fn test_fmt_section_parsing() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join(".rumdl.toml");
+        let config_content = r#"
+[fmt]
+enable = ["MD009", "md012"]
+disable = ["MD047"]
+disabel = ["MD010"]
+"#;
+        fs::write(&config_path, config_content).unwrap();
+
+        let sourced = SourcedConfig::load_with_discovery(Some(config_path.to_str().unwrap()), None, true).unwrap();
+        let registry = RuleRegistry::from_rules(&rules::all_rules(&Config::default()));
+        let warnings = validate_config_sourced(&sourced, &registry);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.ends_with("disabel (did you mean: disable?)"));
+
+        let config: Config = sourced.into();
+        assert_eq!(config.fmt.enable, vec!["MD009".to_string(), "MD012".to_string()]);
+        assert_eq!(config.fmt.disable, vec!["MD047".to_string()]);
+        assert!(config.rules.is_empty());
+    }
+
+    #[test]
+    fn test_fmt_section_pyproject_toml() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join("pyproject.toml");
+        let config_content = r#"
+[tool.rumdl.fmt]
+disable = ["md013"]
+"#;
+        fs::write(&config_path, config_content).unwrap();
+
+        let sourced = SourcedConfig::load_with_discovery(Some(config_path.to_str().unwrap()), None, true).unwrap();
+        let config: Config = sourced.into();
+        assert!(config.fmt.enable.is_empty());
+        assert_eq!(config.fmt.disable, vec!["MD013".to_string()]);
+        assert!(config.rules.is_empty());
+    }
+
     #[test]
     fn test_per_file_ignores_pyproject_toml() {
         let temp_dir = tempdir().unwrap();
@@ -1595,7 +1914,11 @@ pub struct SourcedGlobalConfig {
     pub flavor: SourcedValue<MarkdownFlavor>,
     pub force_exclude: SourcedValue<bool>,
     pub cache_dir: Option<SourcedValue<String>>,
+    pub report_root: Option<SourcedValue<String>>,
     pub cache: SourcedValue<bool>,
+    pub root_markers: SourcedValue<Vec<String>>,
+    pub inline_comment_syntax: Option<SourcedValue<InlineCommentSyntax>>,
+    pub hierarchical: SourcedValue<bool>,
 }
 
 impl Default for SourcedGlobalConfig {
@@ -1613,7 +1936,11 @@ impl Default for SourcedGlobalConfig {
             flavor: SourcedValue::new(MarkdownFlavor::default(), ConfigSource::Default),
             force_exclude: SourcedValue::new(false, ConfigSource::Default),
             cache_dir: None,
+            report_root: None,
             cache: SourcedValue::new(true, ConfigSource::Default),
+            root_markers: SourcedValue::new(default_root_markers(), ConfigSource::Default),
+            inline_comment_syntax: None,
+            hierarchical: SourcedValue::new(false, ConfigSource::Default),
         }
     }
 }
@@ -1629,7 +1956,11 @@ pub struct SourcedRuleConfig {
 pub struct SourcedConfigFragment {
     pub global: SourcedGlobalConfig,
     pub per_file_ignores: SourcedValue<HashMap<String, Vec<String>>>,
+    pub vscode: SourcedValue<VscodeConfig>,
+    pub fmt: SourcedValue<FmtConfig>,
     pub rules: BTreeMap<String, SourcedRuleConfig>,
+    /// Config files this one extends, as written (relative paths are relative to this file)
+    pub extend: Vec<String>,
     pub unknown_keys: Vec<(String, String, Option<String>)>, // (section, key, file_path)
                                                              // Note: loaded_files is tracked globally in SourcedConfig.
 }
@@ -1639,7 +1970,10 @@ impl Default for SourcedConfigFragment {
         Self {
             global: SourcedGlobalConfig::default(),
             per_file_ignores: SourcedValue::new(HashMap::new(), ConfigSource::Default),
+            vscode: SourcedValue::new(VscodeConfig::default(), ConfigSource::Default),
+            fmt: SourcedValue::new(FmtConfig::default(), ConfigSource::Default),
             rules: BTreeMap::new(),
+            extend: Vec::new(),
             unknown_keys: Vec::new(),
         }
     }
@@ -1649,11 +1983,15 @@ impl Default for SourcedConfigFragment {
 pub struct SourcedConfig {
     pub global: SourcedGlobalConfig,
     pub per_file_ignores: SourcedValue<HashMap<String, Vec<String>>>,
+    pub vscode: SourcedValue<VscodeConfig>,
+    pub fmt: SourcedValue<FmtConfig>,
     pub rules: BTreeMap<String, SourcedRuleConfig>,
     pub loaded_files: Vec<String>,
     pub unknown_keys: Vec<(String, String, Option<String>)>, // (section, key, file_path)
     /// Project root directory (parent of config file), used for resolving relative paths
     pub project_root: Option<std::path::PathBuf>,
+    /// Directory of the project config file, where hierarchical config resolution starts
+    pub config_dir: Option<std::path::PathBuf>,
 }
 
 impl Default for SourcedConfig {
@@ -1661,10 +1999,13 @@ impl Default for SourcedConfig {
         Self {
             global: SourcedGlobalConfig::default(),
             per_file_ignores: SourcedValue::new(HashMap::new(), ConfigSource::Default),
+            vscode: SourcedValue::new(VscodeConfig::default(), ConfigSource::Default),
+            fmt: SourcedValue::new(FmtConfig::default(), ConfigSource::Default),
             rules: BTreeMap::new(),
             loaded_files: Vec::new(),
             unknown_keys: Vec::new(),
             project_root: None,
+            config_dir: None,
         }
     }
 }
@@ -1678,16 +2019,16 @@ impl SourcedConfig {
         self.global.enable.merge_override(
             fragment.global.enable.value,
             fragment.global.enable.source,
-            fragment.global.enable.overrides.first().and_then(|o| o.file.clone()),
-            fragment.global.enable.overrides.first().and_then(|o| o.line),
+            fragment.global.enable.overrides.last().and_then(|o| o.file.clone()),
+            fragment.global.enable.overrides.last().and_then(|o| o.line),
         );
 
         // Disable uses union semantics (user can add to project disables)
         self.global.disable.merge_union(
             fragment.global.disable.value,
             fragment.global.disable.source,
-            fragment.global.disable.overrides.first().and_then(|o| o.file.clone()),
-            fragment.global.disable.overrides.first().and_then(|o| o.line),
+            fragment.global.disable.overrides.last().and_then(|o| o.file.clone()),
+            fragment.global.disable.overrides.last().and_then(|o| o.line),
         );
 
         // Conflict resolution: Enable overrides disable
@@ -1699,14 +2040,14 @@ impl SourcedConfig {
         self.global.include.merge_override(
             fragment.global.include.value,
             fragment.global.include.source,
-            fragment.global.include.overrides.first().and_then(|o| o.file.clone()),
-            fragment.global.include.overrides.first().and_then(|o| o.line),
+            fragment.global.include.overrides.last().and_then(|o| o.file.clone()),
+            fragment.global.include.overrides.last().and_then(|o| o.line),
         );
         self.global.exclude.merge_override(
             fragment.global.exclude.value,
             fragment.global.exclude.source,
-            fragment.global.exclude.overrides.first().and_then(|o| o.file.clone()),
-            fragment.global.exclude.overrides.first().and_then(|o| o.line),
+            fragment.global.exclude.overrides.last().and_then(|o| o.file.clone()),
+            fragment.global.exclude.overrides.last().and_then(|o| o.line),
         );
         self.global.respect_gitignore.merge_override(
             fragment.global.respect_gitignore.value,
@@ -1715,9 +2056,9 @@ impl SourcedConfig {
                 .global
                 .respect_gitignore
                 .overrides
-                .first()
+                .last()
                 .and_then(|o| o.file.clone()),
-            fragment.global.respect_gitignore.overrides.first().and_then(|o| o.line),
+            fragment.global.respect_gitignore.overrides.last().and_then(|o| o.line),
         );
         self.global.line_length.merge_override(
             fragment.global.line_length.value,
@@ -1726,29 +2067,29 @@ impl SourcedConfig {
                 .global
                 .line_length
                 .overrides
-                .first()
+                .last()
                 .and_then(|o| o.file.clone()),
-            fragment.global.line_length.overrides.first().and_then(|o| o.line),
+            fragment.global.line_length.overrides.last().and_then(|o| o.line),
         );
         self.global.fixable.merge_override(
             fragment.global.fixable.value,
             fragment.global.fixable.source,
-            fragment.global.fixable.overrides.first().and_then(|o| o.file.clone()),
-            fragment.global.fixable.overrides.first().and_then(|o| o.line),
+            fragment.global.fixable.overrides.last().and_then(|o| o.file.clone()),
+            fragment.global.fixable.overrides.last().and_then(|o| o.line),
         );
         self.global.unfixable.merge_override(
             fragment.global.unfixable.value,
             fragment.global.unfixable.source,
-            fragment.global.unfixable.overrides.first().and_then(|o| o.file.clone()),
-            fragment.global.unfixable.overrides.first().and_then(|o| o.line),
+            fragment.global.unfixable.overrides.last().and_then(|o| o.file.clone()),
+            fragment.global.unfixable.overrides.last().and_then(|o| o.line),
         );
 
         // Merge flavor
         self.global.flavor.merge_override(
             fragment.global.flavor.value,
             fragment.global.flavor.source,
-            fragment.global.flavor.overrides.first().and_then(|o| o.file.clone()),
-            fragment.global.flavor.overrides.first().and_then(|o| o.line),
+            fragment.global.flavor.overrides.last().and_then(|o| o.file.clone()),
+            fragment.global.flavor.overrides.last().and_then(|o| o.line),
         );
 
         // Merge force_exclude
@@ -1759,9 +2100,9 @@ impl SourcedConfig {
                 .global
                 .force_exclude
                 .overrides
-                .first()
+                .last()
                 .and_then(|o| o.file.clone()),
-            fragment.global.force_exclude.overrides.first().and_then(|o| o.line),
+            fragment.global.force_exclude.overrides.last().and_then(|o| o.line),
         );
 
         // Merge output_format if present
@@ -1770,8 +2111,8 @@ impl SourcedConfig {
                 output_format.merge_override(
                     output_format_fragment.value,
                     output_format_fragment.source,
-                    output_format_fragment.overrides.first().and_then(|o| o.file.clone()),
-                    output_format_fragment.overrides.first().and_then(|o| o.line),
+                    output_format_fragment.overrides.last().and_then(|o| o.file.clone()),
+                    output_format_fragment.overrides.last().and_then(|o| o.line),
                 );
             } else {
                 self.global.output_format = Some(output_format_fragment);
@@ -1784,21 +2125,79 @@ impl SourcedConfig {
                 cache_dir.merge_override(
                     cache_dir_fragment.value,
                     cache_dir_fragment.source,
-                    cache_dir_fragment.overrides.first().and_then(|o| o.file.clone()),
-                    cache_dir_fragment.overrides.first().and_then(|o| o.line),
+                    cache_dir_fragment.overrides.last().and_then(|o| o.file.clone()),
+                    cache_dir_fragment.overrides.last().and_then(|o| o.line),
                 );
             } else {
                 self.global.cache_dir = Some(cache_dir_fragment);
             }
         }
 
+        // Merge report_root if present
+        if let Some(report_root_fragment) = fragment.global.report_root {
+            if let Some(ref mut report_root) = self.global.report_root {
+                report_root.merge_override(
+                    report_root_fragment.value,
+                    report_root_fragment.source,
+                    report_root_fragment.overrides.last().and_then(|o| o.file.clone()),
+                    report_root_fragment.overrides.last().and_then(|o| o.line),
+                );
+            } else {
+                self.global.report_root = Some(report_root_fragment);
+            }
+        }
+
         // Merge cache if not default (only override when explicitly set)
         if fragment.global.cache.source != ConfigSource::Default {
             self.global.cache.merge_override(
                 fragment.global.cache.value,
                 fragment.global.cache.source,
-                fragment.global.cache.overrides.first().and_then(|o| o.file.clone()),
-                fragment.global.cache.overrides.first().and_then(|o| o.line),
+                fragment.global.cache.overrides.last().and_then(|o| o.file.clone()),
+                fragment.global.cache.overrides.last().and_then(|o| o.line),
+            );
+        }
+
+        // Merge root_markers only when explicitly set
+        if fragment.global.root_markers.source != ConfigSource::Default {
+            self.global.root_markers.merge_override(
+                fragment.global.root_markers.value,
+                fragment.global.root_markers.source,
+                fragment
+                    .global
+                    .root_markers
+                    .overrides
+                    .last()
+                    .and_then(|o| o.file.clone()),
+                fragment.global.root_markers.overrides.last().and_then(|o| o.line),
+            );
+        }
+
+        // Merge inline_comment_syntax if present
+        if let Some(syntax_fragment) = fragment.global.inline_comment_syntax {
+            if let Some(ref mut syntax) = self.global.inline_comment_syntax {
+                syntax.merge_override(
+                    syntax_fragment.value,
+                    syntax_fragment.source,
+                    syntax_fragment.overrides.last().and_then(|o| o.file.clone()),
+                    syntax_fragment.overrides.last().and_then(|o| o.line),
+                );
+            } else {
+                self.global.inline_comment_syntax = Some(syntax_fragment);
+            }
+        }
+
+        // Merge hierarchical only when explicitly set
+        if fragment.global.hierarchical.source != ConfigSource::Default {
+            self.global.hierarchical.merge_override(
+                fragment.global.hierarchical.value,
+                fragment.global.hierarchical.source,
+                fragment
+                    .global
+                    .hierarchical
+                    .overrides
+                    .last()
+                    .and_then(|o| o.file.clone()),
+                fragment.global.hierarchical.overrides.last().and_then(|o| o.line),
             );
         }
 
@@ -1806,8 +2205,24 @@ impl SourcedConfig {
         self.per_file_ignores.merge_override(
             fragment.per_file_ignores.value,
             fragment.per_file_ignores.source,
-            fragment.per_file_ignores.overrides.first().and_then(|o| o.file.clone()),
-            fragment.per_file_ignores.overrides.first().and_then(|o| o.line),
+            fragment.per_file_ignores.overrides.last().and_then(|o| o.file.clone()),
+            fragment.per_file_ignores.overrides.last().and_then(|o| o.line),
+        );
+
+        // Merge vscode settings
+        self.vscode.merge_override(
+            fragment.vscode.value,
+            fragment.vscode.source,
+            fragment.vscode.overrides.last().and_then(|o| o.file.clone()),
+            fragment.vscode.overrides.last().and_then(|o| o.line),
+        );
+
+        // Merge fmt rule selection
+        self.fmt.merge_override(
+            fragment.fmt.value,
+            fragment.fmt.source,
+            fragment.fmt.overrides.last().and_then(|o| o.file.clone()),
+            fragment.fmt.overrides.last().and_then(|o| o.line),
         );
 
         // Merge rule configs
@@ -1819,8 +2234,8 @@ impl SourcedConfig {
                     .values
                     .entry(key.clone())
                     .or_insert_with(|| SourcedValue::new(sourced_value_fragment.value.clone(), ConfigSource::Default));
-                let file_from_fragment = sourced_value_fragment.overrides.first().and_then(|o| o.file.clone());
-                let line_from_fragment = sourced_value_fragment.overrides.first().and_then(|o| o.line);
+                let file_from_fragment = sourced_value_fragment.overrides.last().and_then(|o| o.file.clone());
+                let line_from_fragment = sourced_value_fragment.overrides.last().and_then(|o| o.line);
                 sv_entry.merge_override(
                     sourced_value_fragment.value,  // Use the value from the fragment
                     sourced_value_fragment.source, // Use the source from the fragment
@@ -1844,41 +2259,37 @@ impl SourcedConfig {
         Self::load_with_discovery(config_path, cli_overrides, false)
     }
 
-    /// Finds project root by walking up from start_dir looking for .git directory.
-    /// Falls back to start_dir if no .git found.
-    fn find_project_root_from(start_dir: &Path) -> std::path::PathBuf {
-        let mut current = start_dir.to_path_buf();
-        const MAX_DEPTH: usize = 100;
-
-        for _ in 0..MAX_DEPTH {
-            if current.join(".git").exists() {
-                log::debug!("[rumdl-config] Found .git at: {}", current.display());
-                return current;
-            }
+    /// Finds project root by walking up from start_dir looking for the given root markers.
+    /// Falls back to start_dir if no marker is found.
+    fn find_project_root_from(start_dir: &Path, markers: &[String]) -> std::path::PathBuf {
+        find_project_root(start_dir, markers).unwrap_or_else(|| {
+            log::debug!(
+                "[rumdl-config] No root marker found, using config location as project root: {}",
+                start_dir.display()
+            );
+            start_dir.to_path_buf()
+        })
+    }
 
-            match current.parent() {
-                Some(parent) => current = parent.to_path_buf(),
-                None => break,
-            }
+    /// Returns the project root, detecting it from the current directory when no
+    /// configuration file was loaded.
+    ///
+    /// Used to anchor the cache directory and schema path outside of config discovery.
+    pub fn resolve_project_root(&self) -> Option<std::path::PathBuf> {
+        if let Some(root) = &self.project_root {
+            return Some(root.clone());
         }
-
-        // No .git found, use start_dir as project root
-        log::debug!(
-            "[rumdl-config] No .git found, using config location as project root: {}",
-            start_dir.display()
-        );
-        start_dir.to_path_buf()
+        let cwd = std::env::current_dir().ok()?;
+        find_project_root(&cwd, &self.global.root_markers.value)
     }
 
     /// Discover configuration file by traversing up the directory tree.
     /// Returns the first configuration file found.
-    /// Discovers config file and returns both the config path and project root.
-    /// Returns: (config_file_path, project_root_path)
-    /// Project root is the directory containing .git, or config parent as fallback.
+    /// Returns: (config_file_path, config_directory)
+    /// The project root is resolved from the config directory once root markers are known.
     fn discover_config_upward() -> Option<(std::path::PathBuf, std::path::PathBuf)> {
         use std::env;
 
-        const CONFIG_FILES: &[&str] = &[".rumdl.toml", "rumdl.toml", ".config/rumdl.toml", "pyproject.toml"];
         const MAX_DEPTH: usize = 100; // Prevent infinite traversal
 
         let start_dir = match env::current_dir() {
@@ -1901,32 +2312,12 @@ impl SourcedConfig {
 
             log::debug!("[rumdl-config] Searching for config in: {}", current_dir.display());
 
-            // Check for config files in order of precedence (only if not already found)
-            if found_config.is_none() {
-                for config_name in CONFIG_FILES {
-                    let config_path = current_dir.join(config_name);
-
-                    if config_path.exists() {
-                        // For pyproject.toml, verify it contains [tool.rumdl] section
-                        if *config_name == "pyproject.toml" {
-                            if let Ok(content) = std::fs::read_to_string(&config_path) {
-                                if content.contains("[tool.rumdl]") || content.contains("tool.rumdl") {
-                                    log::debug!("[rumdl-config] Found config file: {}", config_path.display());
-                                    // Store config, but continue looking for .git
-                                    found_config = Some((config_path.clone(), current_dir.clone()));
-                                    break;
-                                }
-                                log::debug!("[rumdl-config] Found pyproject.toml but no [tool.rumdl] section");
-                                continue;
-                            }
-                        } else {
-                            log::debug!("[rumdl-config] Found config file: {}", config_path.display());
-                            // Store config, but continue looking for .git
-                            found_config = Some((config_path.clone(), current_dir.clone()));
-                            break;
-                        }
-                    }
-                }
+            // Store the first config found, but continue looking for .git. An enclosing config
+            // with `hierarchical = true` takes over, and applies the nearer ones per directory
+            if let Some(config_path) = Self::find_config_in_dir(&current_dir)
+                && (found_config.is_none() || Self::enables_hierarchical(&config_path))
+            {
+                found_config = Some((config_path, current_dir.clone()));
             }
 
             // Check for .git directory (stop boundary)
@@ -1948,15 +2339,156 @@ impl SourcedConfig {
             }
         }
 
-        // If config found, determine project root by walking up from config location
-        if let Some((config_path, config_dir)) = found_config {
-            let project_root = Self::find_project_root_from(&config_dir);
-            return Some((config_path, project_root));
-        }
+        found_config
+    }
 
+    /// Returns the config file in `dir` with the highest precedence, if any.
+    /// A pyproject.toml only counts when it has a [tool.rumdl] section.
+    fn find_config_in_dir(dir: &Path) -> Option<std::path::PathBuf> {
+        const CONFIG_FILES: &[&str] = &[".rumdl.toml", "rumdl.toml", ".config/rumdl.toml", "pyproject.toml"];
+
+        for config_name in CONFIG_FILES {
+            let config_path = dir.join(config_name);
+            if !config_path.exists() {
+                continue;
+            }
+            if *config_name == "pyproject.toml" {
+                match std::fs::read_to_string(&config_path) {
+                    Ok(content) if content.contains("[tool.rumdl]") || content.contains("tool.rumdl") => {}
+                    Ok(_) => {
+                        log::debug!("[rumdl-config] Found pyproject.toml but no [tool.rumdl] section");
+                        continue;
+                    }
+                    Err(_) => continue,
+                }
+            }
+            log::debug!("[rumdl-config] Found config file: {}", config_path.display());
+            return Some(config_path);
+        }
         None
     }
 
+    /// Whether the config file turns on hierarchical resolution (unreadable files do not)
+    fn enables_hierarchical(config_file: &Path) -> bool {
+        let path_str = config_file.display().to_string();
+        let Ok(content) = std::fs::read_to_string(config_file) else {
+            return false;
+        };
+        let fragment = if config_file.file_name().is_some_and(|name| name == "pyproject.toml") {
+            parse_pyproject_toml(&content, &path_str).ok().flatten()
+        } else {
+            parse_rumdl_toml(&content, &path_str, ConfigSource::ProjectConfig).ok()
+        };
+        fragment.is_some_and(|fragment| fragment.global.hierarchical.value)
+    }
+
+    /// Parses a config file and merges it, after the config files it extends.
+    ///
+    /// A pyproject.toml is read from its [tool.rumdl] section; any other file is parsed as a
+    /// rumdl TOML config with the given source. Extended files take the source of the file
+    /// that extends them, so the extending file wins over its bases.
+    fn merge_config_file(&mut self, config_file: &Path, source: ConfigSource) -> Result<(), ConfigError> {
+        self.merge_config_file_extending(config_file, source, &mut Vec::new())
+    }
+
+    /// `merge_config_file` with the chain of files currently being extended, to detect cycles
+    fn merge_config_file_extending(
+        &mut self,
+        config_file: &Path,
+        source: ConfigSource,
+        chain: &mut Vec<std::path::PathBuf>,
+    ) -> Result<(), ConfigError> {
+        let path_str = config_file.display().to_string();
+        let canonical = std::fs::canonicalize(config_file).unwrap_or_else(|_| config_file.to_path_buf());
+        if let Some(start) = chain.iter().position(|p| *p == canonical) {
+            let cycle: Vec<String> = chain[start..]
+                .iter()
+                .chain(std::iter::once(&canonical))
+                .map(|p| p.display().to_string())
+                .collect();
+            return Err(ConfigError::CircularExtend {
+                chain: cycle.join(" -> "),
+            });
+        }
+
+        let content = std::fs::read_to_string(config_file).map_err(|e| ConfigError::IoError {
+            source: e,
+            path: path_str.clone(),
+        })?;
+        let is_pyproject = config_file.file_name().is_some_and(|name| name == "pyproject.toml");
+        let fragment = if is_pyproject {
+            match parse_pyproject_toml(&content, &path_str)? {
+                Some(fragment) => fragment,
+                None => return Ok(()),
+            }
+        } else {
+            parse_rumdl_toml(&content, &path_str, source)?
+        };
+
+        let extend_source = if is_pyproject {
+            ConfigSource::PyprojectToml
+        } else {
+            source
+        };
+        let base_dir = config_file.parent().unwrap_or(Path::new(""));
+        chain.push(canonical);
+        for extend_path in &fragment.extend {
+            log::debug!("[rumdl-config] {path_str} extends {extend_path}");
+            self.merge_config_file_extending(&base_dir.join(extend_path), extend_source, chain)?;
+        }
+        chain.pop();
+
+        self.merge(fragment);
+        self.loaded_files.push(path_str);
+        Ok(())
+    }
+
+    /// Returns the configuration for files in `dir` when `hierarchical` is enabled.
+    ///
+    /// Config files in the directories between the project config directory (exclusive) and
+    /// `dir` (inclusive) are merged onto this configuration outermost first, so the config
+    /// nearest to `dir` takes precedence. Returns `None` when hierarchical resolution is off,
+    /// `dir` is outside the project, or no config file lies on the way to `dir`.
+    pub fn for_directory(&self, dir: &Path) -> Result<Option<SourcedConfig>, ConfigError> {
+        if !self.global.hierarchical.value {
+            return Ok(None);
+        }
+        let Some(config_dir) = &self.config_dir else {
+            return Ok(None);
+        };
+        // A config path given as a bare file name lives in the current directory
+        let config_dir = if config_dir.as_os_str().is_empty() {
+            Path::new(".")
+        } else {
+            config_dir.as_path()
+        };
+        let config_dir = std::fs::canonicalize(config_dir).unwrap_or_else(|_| config_dir.to_path_buf());
+        let dir = if dir.as_os_str().is_empty() {
+            Path::new(".")
+        } else {
+            dir
+        };
+        let Ok(dir) = std::fs::canonicalize(dir) else {
+            return Ok(None);
+        };
+        let Ok(relative) = dir.strip_prefix(&config_dir) else {
+            return Ok(None);
+        };
+
+        let mut resolved: Option<SourcedConfig> = None;
+        let mut current = config_dir.clone();
+        for component in relative.components() {
+            current.push(component);
+            if let Some(config_file) = Self::find_config_in_dir(&current) {
+                log::debug!("[rumdl-config] Merging nested config file: {}", config_file.display());
+                resolved
+                    .get_or_insert_with(|| self.clone())
+                    .merge_config_file(&config_file, ConfigSource::ProjectConfig)?;
+            }
+        }
+        Ok(resolved)
+    }
+
     /// Internal implementation that accepts config directory for testing
     fn user_configuration_path_impl(config_dir: &Path) -> Option<std::path::PathBuf> {
         let config_dir = config_dir.join("rumdl");
@@ -2041,6 +2573,7 @@ impl SourcedConfig {
             log::debug!("[rumdl-config] Explicit config_path provided: {config_path:?}");
         }
         let mut sourced_config = SourcedConfig::default();
+        let mut root_search_start: Option<std::path::PathBuf> = None;
 
         // 1. Always load user configuration first (unless auto-discovery is disabled)
         // User config serves as the base layer that project configs build upon
@@ -2052,29 +2585,11 @@ impl SourcedConfig {
             };
 
             if let Some(user_config_path) = user_config_path {
-                let path_str = user_config_path.display().to_string();
-                let filename = user_config_path.file_name().and_then(|n| n.to_str()).unwrap_or("");
-
-                log::debug!("[rumdl-config] Loading user configuration file: {path_str}");
-
-                if filename == "pyproject.toml" {
-                    let content = std::fs::read_to_string(&user_config_path).map_err(|e| ConfigError::IoError {
-                        source: e,
-                        path: path_str.clone(),
-                    })?;
-                    if let Some(fragment) = parse_pyproject_toml(&content, &path_str)? {
-                        sourced_config.merge(fragment);
-                        sourced_config.loaded_files.push(path_str);
-                    }
-                } else {
-                    let content = std::fs::read_to_string(&user_config_path).map_err(|e| ConfigError::IoError {
-                        source: e,
-                        path: path_str.clone(),
-                    })?;
-                    let fragment = parse_rumdl_toml(&content, &path_str, ConfigSource::UserConfig)?;
-                    sourced_config.merge(fragment);
-                    sourced_config.loaded_files.push(path_str);
-                }
+                log::debug!(
+                    "[rumdl-config] Loading user configuration file: {}",
+                    user_config_path.display()
+                );
+                sourced_config.merge_config_file(&user_config_path, ConfigSource::UserConfig)?;
             } else {
                 log::debug!("[rumdl-config] No user configuration file found");
             }
@@ -2087,34 +2602,24 @@ impl SourcedConfig {
             log::debug!("[rumdl-config] Trying to load config file: {filename}");
             let path_str = path.to_string();
 
-            // Find project root by walking up from config location looking for .git
-            if let Some(config_parent) = path_obj.parent() {
-                let project_root = Self::find_project_root_from(config_parent);
-                log::debug!(
-                    "[rumdl-config] Project root (from explicit config): {}",
-                    project_root.display()
-                );
-                sourced_config.project_root = Some(project_root);
-            }
+            // Project root is found by walking up from the config location
+            root_search_start = path_obj.parent().map(Path::to_path_buf);
+            sourced_config.config_dir = root_search_start
+                .as_deref()
+                .map(|dir| {
+                    if dir.ends_with(".config") {
+                        dir.parent().unwrap_or(dir)
+                    } else {
+                        dir
+                    }
+                })
+                .map(Path::to_path_buf);
 
             // Known markdownlint config files
             const MARKDOWNLINT_FILENAMES: &[&str] = &[".markdownlint.json", ".markdownlint.yaml", ".markdownlint.yml"];
 
             if filename == "pyproject.toml" || filename == ".rumdl.toml" || filename == "rumdl.toml" {
-                let content = std::fs::read_to_string(path).map_err(|e| ConfigError::IoError {
-                    source: e,
-                    path: path_str.clone(),
-                })?;
-                if filename == "pyproject.toml" {
-                    if let Some(fragment) = parse_pyproject_toml(&content, &path_str)? {
-                        sourced_config.merge(fragment);
-                        sourced_config.loaded_files.push(path_str.clone());
-                    }
-                } else {
-                    let fragment = parse_rumdl_toml(&content, &path_str, ConfigSource::ProjectConfig)?;
-                    sourced_config.merge(fragment);
-                    sourced_config.loaded_files.push(path_str.clone());
-                }
+                sourced_config.merge_config_file(path_obj, ConfigSource::ProjectConfig)?;
             } else if MARKDOWNLINT_FILENAMES.contains(&filename)
                 || path_str.ends_with(".json")
                 || path_str.ends_with(".jsonc")
@@ -2128,47 +2633,24 @@ impl SourcedConfig {
                 // markdownlint is fallback only
             } else {
                 // Try TOML only
-                let content = std::fs::read_to_string(path).map_err(|e| ConfigError::IoError {
-                    source: e,
-                    path: path_str.clone(),
-                })?;
-                let fragment = parse_rumdl_toml(&content, &path_str, ConfigSource::ProjectConfig)?;
-                sourced_config.merge(fragment);
-                sourced_config.loaded_files.push(path_str.clone());
+                sourced_config.merge_config_file(path_obj, ConfigSource::ProjectConfig)?;
             }
         }
 
         // 3. Perform auto-discovery for project config if not skipped AND no explicit config path
         if !skip_auto_discovery && config_path.is_none() {
             // Look for project configuration files (override user config)
-            if let Some((config_file, project_root)) = Self::discover_config_upward() {
-                let path_str = config_file.display().to_string();
-                let filename = config_file.file_name().and_then(|n| n.to_str()).unwrap_or("");
-
-                log::debug!("[rumdl-config] Loading discovered config file: {path_str}");
-                log::debug!("[rumdl-config] Project root: {}", project_root.display());
-
-                // Store project root for cache directory resolution
-                sourced_config.project_root = Some(project_root);
-
-                if filename == "pyproject.toml" {
-                    let content = std::fs::read_to_string(&config_file).map_err(|e| ConfigError::IoError {
-                        source: e,
-                        path: path_str.clone(),
-                    })?;
-                    if let Some(fragment) = parse_pyproject_toml(&content, &path_str)? {
-                        sourced_config.merge(fragment);
-                        sourced_config.loaded_files.push(path_str);
-                    }
-                } else if filename == ".rumdl.toml" || filename == "rumdl.toml" {
-                    let content = std::fs::read_to_string(&config_file).map_err(|e| ConfigError::IoError {
-                        source: e,
-                        path: path_str.clone(),
-                    })?;
-                    let fragment = parse_rumdl_toml(&content, &path_str, ConfigSource::ProjectConfig)?;
-                    sourced_config.merge(fragment);
-                    sourced_config.loaded_files.push(path_str);
-                }
+            if let Some((config_file, config_dir)) = Self::discover_config_upward() {
+                log::debug!(
+                    "[rumdl-config] Loading discovered config file: {}",
+                    config_file.display()
+                );
+
+                // Project root is found by walking up from the config location
+                root_search_start = Some(config_dir.clone());
+                sourced_config.config_dir = Some(config_dir);
+
+                sourced_config.merge_config_file(&config_file, ConfigSource::ProjectConfig)?;
             } else {
                 log::debug!("[rumdl-config] No configuration file found via upward traversal");
 
@@ -2231,6 +2713,13 @@ impl SourcedConfig {
             // No rule-specific CLI overrides implemented yet
         }
 
+        // 5. Resolve project root from the config location using the configured root markers
+        if let Some(start) = root_search_start {
+            let project_root = Self::find_project_root_from(&start, &sourced_config.global.root_markers.value);
+            log::debug!("[rumdl-config] Project root: {}", project_root.display());
+            sourced_config.project_root = Some(project_root);
+        }
+
         // Unknown keys are now collected during parsing and validated via validate_config_sourced()
 
         Ok(sourced_config)
@@ -2273,11 +2762,17 @@ impl From<SourcedConfig> for Config {
             flavor: sourced.global.flavor.value,
             force_exclude: sourced.global.force_exclude.value,
             cache_dir: sourced.global.cache_dir.as_ref().map(|v| v.value.clone()),
+            report_root: sourced.global.report_root.as_ref().map(|v| v.value.clone()),
             cache: sourced.global.cache.value,
+            root_markers: sourced.global.root_markers.value,
+            inline_comment_syntax: sourced.global.inline_comment_syntax.as_ref().map(|v| v.value),
+            hierarchical: sourced.global.hierarchical.value,
         };
         Config {
             global,
             per_file_ignores: sourced.per_file_ignores.value,
+            vscode: sourced.vscode.value,
+            fmt: sourced.fmt.value,
             rules,
         }
     }
@@ -2330,6 +2825,12 @@ impl RuleRegistry {
         self.rule_schemas.get(rule).map(|schema| {
             let mut all_keys = std::collections::BTreeSet::new();
 
+            // Options accepted by every rule
+            all_keys.insert(crate::disabled_contexts::DISABLE_IN_KEY.to_string());
+            all_keys.insert(crate::disabled_contexts::DISABLE_IN_KEY.replace('-', "_"));
+            all_keys.insert(crate::flavor_overrides::ENABLED_FOR_FLAVORS_KEY.to_string());
+            all_keys.insert(crate::flavor_overrides::ENABLED_FOR_FLAVORS_KEY.replace('-', "_"));
+
             // Add original keys from schema
             for key in schema.keys() {
                 all_keys.insert(key.clone());
@@ -2455,7 +2956,30 @@ pub fn validate_config_sourced(sourced: &SourcedConfig, registry: &RuleRegistry)
             }
         }
     }
-    // 3. Unknown global options (from unknown_keys)
+    // 3. Unknown rules in enable/disable lists (these must be rule IDs, not aliases)
+    for (list, names) in [
+        ("enable", &sourced.global.enable.value),
+        ("disable", &sourced.global.disable.value),
+    ] {
+        for name in names {
+            if name == "all" || known_rules.contains(name) {
+                continue;
+            }
+            let message = match crate::markdownlint_config::markdownlint_to_rumdl_rule_key(name) {
+                Some(rule_id) if known_rules.contains(rule_id) => {
+                    format!("Unknown rule in {list} list: {name} (did you mean: {rule_id}?)")
+                }
+                _ => format!("Unknown rule in {list} list: {name}"),
+            };
+            warnings.push(ConfigValidationWarning {
+                message,
+                rule: Some(name.clone()),
+                key: Some(list.to_string()),
+            });
+        }
+    }
+
+    // 4. Unknown global options (from unknown_keys)
     let known_global_keys = vec![
         "enable".to_string(),
         "disable".to_string(),
@@ -2469,7 +2993,11 @@ pub fn validate_config_sourced(sourced: &SourcedConfig, registry: &RuleRegistry)
         "force-exclude".to_string(),
         "output-format".to_string(),
         "cache-dir".to_string(),
+        "report-root".to_string(),
         "cache".to_string(),
+        "root-markers".to_string(),
+        "inline-comment-syntax".to_string(),
+        "hierarchical".to_string(),
     ];
 
     for (section, key, file_path) in &sourced.unknown_keys {
@@ -2490,6 +3018,36 @@ pub fn validate_config_sourced(sourced: &SourcedConfig, registry: &RuleRegistry)
                 rule: None,
                 key: Some(key.clone()),
             });
+        } else if section == "[vscode]" {
+            let known_vscode_keys = ["format-on-save".to_string(), "min-severity".to_string()];
+            let message = match (suggest_similar_key(key, &known_vscode_keys), file_path) {
+                (Some(suggestion), Some(path)) => {
+                    format!("Unknown vscode option in {path}: {key} (did you mean: {suggestion}?)")
+                }
+                (Some(suggestion), None) => format!("Unknown vscode option: {key} (did you mean: {suggestion}?)"),
+                (None, Some(path)) => format!("Unknown vscode option in {path}: {key}"),
+                (None, None) => format!("Unknown vscode option: {key}"),
+            };
+            warnings.push(ConfigValidationWarning {
+                message,
+                rule: None,
+                key: Some(key.clone()),
+            });
+        } else if section == "[fmt]" {
+            let known_fmt_keys = ["enable".to_string(), "disable".to_string()];
+            let message = match (suggest_similar_key(key, &known_fmt_keys), file_path) {
+                (Some(suggestion), Some(path)) => {
+                    format!("Unknown fmt option in {path}: {key} (did you mean: {suggestion}?)")
+                }
+                (Some(suggestion), None) => format!("Unknown fmt option: {key} (did you mean: {suggestion}?)"),
+                (None, Some(path)) => format!("Unknown fmt option in {path}: {key}"),
+                (None, None) => format!("Unknown fmt option: {key}"),
+            };
+            warnings.push(ConfigValidationWarning {
+                message,
+                rule: None,
+                key: Some(key.clone()),
+            });
         } else if !key.is_empty() {
             // This is an unknown rule section (key is empty means it's a section header)
             // No suggestions for rule names - just warn
@@ -2531,8 +3089,10 @@ fn toml_type_name(val: &toml::Value) -> &'static str {
 
 /// Calculate Levenshtein distance between two strings (simple implementation)
 fn levenshtein_distance(s1: &str, s2: &str) -> usize {
-    let len1 = s1.len();
-    let len2 = s2.len();
+    let s1_chars: Vec<char> = s1.chars().collect();
+    let s2_chars: Vec<char> = s2.chars().collect();
+    let len1 = s1_chars.len();
+    let len2 = s2_chars.len();
 
     if len1 == 0 {
         return len2;
@@ -2541,9 +3101,6 @@ fn levenshtein_distance(s1: &str, s2: &str) -> usize {
         return len1;
     }
 
-    let s1_chars: Vec<char> = s1.chars().collect();
-    let s2_chars: Vec<char> = s2.chars().collect();
-
     let mut prev_row: Vec<usize> = (0..=len2).collect();
     let mut curr_row = vec![0; len2 + 1];
 
@@ -2562,7 +3119,7 @@ fn levenshtein_distance(s1: &str, s2: &str) -> usize {
 }
 
 /// Suggest a similar key from a list of valid keys using fuzzy matching
-fn suggest_similar_key(unknown: &str, valid_keys: &[String]) -> Option<String> {
+pub(crate) fn suggest_similar_key(unknown: &str, valid_keys: &[String]) -> Option<String> {
     let unknown_lower = unknown.to_lowercase();
     let max_distance = 2.max(unknown.len() / 3); // Allow up to 2 edits or 30% of string length
 
@@ -2614,6 +3171,13 @@ fn parse_pyproject_toml(content: &str, path: &str) -> Result<Option<SourcedConfi
     if let Some(rumdl_config) = doc.get("tool").and_then(|t| t.get("rumdl"))
         && let Some(rumdl_table) = rumdl_config.as_table()
     {
+        // Config files to inherit from, loaded before this one
+        if let Some(extend) = rumdl_table.get("extend") {
+            fragment.extend = Vec::<String>::deserialize(extend.clone())
+                .or_else(|_| String::deserialize(extend.clone()).map(|extend_path| vec![extend_path]))
+                .map_err(|_| invalid_extend_error(path))?;
+        }
+
         // Helper function to extract global config from a table
         let extract_global_config = |fragment: &mut SourcedConfigFragment, table: &toml::value::Table| {
             // Extract global options from the given table
@@ -2753,11 +3317,56 @@ fn parse_pyproject_toml(content: &str, path: &str) -> Result<Option<SourcedConfi
                 }
             }
 
+            if let Some(report_root) = table.get("report-root").or_else(|| table.get("report_root"))
+                && let Ok(value) = String::deserialize(report_root.clone())
+            {
+                if fragment.global.report_root.is_none() {
+                    fragment.global.report_root = Some(SourcedValue::new(value.clone(), source));
+                } else {
+                    fragment
+                        .global
+                        .report_root
+                        .as_mut()
+                        .unwrap()
+                        .push_override(value, source, file.clone(), None);
+                }
+            }
+
             if let Some(cache) = table.get("cache")
                 && let Ok(value) = bool::deserialize(cache.clone())
             {
                 fragment.global.cache.push_override(value, source, file.clone(), None);
             }
+
+            if let Some(root_markers) = table.get("root-markers").or_else(|| table.get("root_markers"))
+                && let Ok(values) = Vec::<String>::deserialize(root_markers.clone())
+            {
+                fragment
+                    .global
+                    .root_markers
+                    .push_override(values, source, file.clone(), None);
+            }
+
+            if let Some(syntax) = table
+                .get("inline-comment-syntax")
+                .or_else(|| table.get("inline_comment_syntax"))
+                && let Ok(value) = InlineCommentSyntax::deserialize(syntax.clone())
+            {
+                if let Some(existing) = fragment.global.inline_comment_syntax.as_mut() {
+                    existing.push_override(value, source, file.clone(), None);
+                } else {
+                    fragment.global.inline_comment_syntax = Some(SourcedValue::new(value, source));
+                }
+            }
+
+            if let Some(hierarchical) = table.get("hierarchical")
+                && let Ok(value) = bool::deserialize(hierarchical.clone())
+            {
+                fragment
+                    .global
+                    .hierarchical
+                    .push_override(value, source, file.clone(), None);
+            }
         };
 
         // First, check for [tool.rumdl.global] section
@@ -2793,6 +3402,28 @@ fn parse_pyproject_toml(content: &str, path: &str) -> Result<Option<SourcedConfi
                 .push_override(per_file_map, source, file.clone(), None);
         }
 
+        // --- Extract [tool.rumdl.vscode] editor settings ---
+        if let Some(vscode_value) = rumdl_table.get("vscode") {
+            match VscodeConfig::deserialize(vscode_value.clone()) {
+                Ok(vscode) => fragment.vscode.push_override(vscode, source, file.clone(), None),
+                Err(e) => log::warn!("[WARN] Invalid [tool.rumdl.vscode] section in {path}: {e}"),
+            }
+        }
+
+        // --- Extract [tool.rumdl.fmt] rule selection ---
+        if let Some(fmt_value) = rumdl_table.get("fmt") {
+            match FmtConfig::deserialize(fmt_value.clone()) {
+                Ok(fmt) => {
+                    let fmt = FmtConfig {
+                        enable: fmt.enable.iter().map(|s| normalize_key(s)).collect(),
+                        disable: fmt.disable.iter().map(|s| normalize_key(s)).collect(),
+                    };
+                    fragment.fmt.push_override(fmt, source, file.clone(), None);
+                }
+                Err(e) => log::warn!("[WARN] Invalid [tool.rumdl.fmt] section in {path}: {e}"),
+            }
+        }
+
         // --- Extract rule-specific configurations ---
         for (key, value) in rumdl_table {
             let norm_rule_key = normalize_key(key);
@@ -2815,11 +3446,21 @@ fn parse_pyproject_toml(content: &str, path: &str) -> Result<Option<SourcedConfi
                 "unfixable",
                 "per-file-ignores",
                 "per_file_ignores",
+                "vscode",
+                "fmt",
                 "global",
                 "flavor",
                 "cache_dir",
                 "cache-dir",
+                "report_root",
+                "report-root",
                 "cache",
+                "root_markers",
+                "root-markers",
+                "inline_comment_syntax",
+                "inline-comment-syntax",
+                "hierarchical",
+                "extend",
             ]
             .contains(&norm_rule_key.as_str())
             {
@@ -2933,13 +3574,24 @@ fn parse_pyproject_toml(content: &str, path: &str) -> Result<Option<SourcedConfi
         || !fragment.global.unfixable.value.is_empty()
         || fragment.global.output_format.is_some()
         || fragment.global.cache_dir.is_some()
+        || fragment.global.report_root.is_some()
         || !fragment.global.cache.value
+        || fragment.global.root_markers.source != ConfigSource::Default
+        || fragment.global.inline_comment_syntax.is_some()
+        || fragment.global.hierarchical.value
+        || !fragment.extend.is_empty()
         || !fragment.per_file_ignores.value.is_empty()
+        || fragment.vscode.source != ConfigSource::Default
+        || fragment.fmt.source != ConfigSource::Default
         || !fragment.rules.is_empty();
     if has_any { Ok(Some(fragment)) } else { Ok(None) }
 }
 
 /// Parses rumdl.toml / .rumdl.toml content.
+fn invalid_extend_error(path: &str) -> ConfigError {
+    ConfigError::ParseError(format!("{path}: `extend` must be a path or an array of paths"))
+}
+
 fn parse_rumdl_toml(content: &str, path: &str, source: ConfigSource) -> Result<SourcedConfigFragment, ConfigError> {
     let doc = content
         .parse::<DocumentMut>()
@@ -3112,6 +3764,28 @@ fn parse_rumdl_toml(content: &str, path: &str, source: ConfigSource) -> Result<S
                         );
                     }
                 }
+                "report_root" | "report-root" => {
+                    if let Some(toml_edit::Value::String(formatted_string)) = value_item.as_value() {
+                        let val = formatted_string.value().clone();
+                        if fragment.global.report_root.is_none() {
+                            fragment.global.report_root = Some(SourcedValue::new(val.clone(), source));
+                        } else {
+                            fragment.global.report_root.as_mut().unwrap().push_override(
+                                val,
+                                source,
+                                file.clone(),
+                                None,
+                            );
+                        }
+                    } else {
+                        log::warn!(
+                            "[WARN] Expected string for global key '{}' in {}, found {}",
+                            key,
+                            path,
+                            value_item.type_name()
+                        );
+                    }
+                }
                 "cache" => {
                     if let Some(toml_edit::Value::Boolean(b)) = value_item.as_value() {
                         let val = *b.value();
@@ -3125,6 +3799,42 @@ fn parse_rumdl_toml(content: &str, path: &str, source: ConfigSource) -> Result<S
                         );
                     }
                 }
+                "hierarchical" => {
+                    if let Some(toml_edit::Value::Boolean(b)) = value_item.as_value() {
+                        let val = *b.value();
+                        fragment
+                            .global
+                            .hierarchical
+                            .push_override(val, source, file.clone(), None);
+                    } else {
+                        log::warn!(
+                            "[WARN] Expected boolean for global key '{}' in {}, found {}",
+                            key,
+                            path,
+                            value_item.type_name()
+                        );
+                    }
+                }
+                "root_markers" | "root-markers" => {
+                    if let Some(toml_edit::Value::Array(formatted_array)) = value_item.as_value() {
+                        let values: Vec<String> = formatted_array
+                            .iter()
+                            .filter_map(|item| item.as_str())
+                            .map(|s| s.to_string())
+                            .collect();
+                        fragment
+                            .global
+                            .root_markers
+                            .push_override(values, source, file.clone(), None);
+                    } else {
+                        log::warn!(
+                            "[WARN] Expected array for global key '{}' in {}, found {}",
+                            key,
+                            path,
+                            value_item.type_name()
+                        );
+                    }
+                }
                 "fixable" => {
                     if let Some(toml_edit::Value::Array(formatted_array)) = value_item.as_value() {
                         let values: Vec<String> = formatted_array
@@ -3165,6 +3875,27 @@ fn parse_rumdl_toml(content: &str, path: &str, source: ConfigSource) -> Result<S
                         );
                     }
                 }
+                "inline_comment_syntax" | "inline-comment-syntax" => {
+                    if let Some(toml_edit::Value::String(formatted_string)) = value_item.as_value() {
+                        let val = formatted_string.value();
+                        if let Ok(syntax) = InlineCommentSyntax::from_str(val) {
+                            if let Some(existing) = fragment.global.inline_comment_syntax.as_mut() {
+                                existing.push_override(syntax, source, file.clone(), None);
+                            } else {
+                                fragment.global.inline_comment_syntax = Some(SourcedValue::new(syntax, source));
+                            }
+                        } else {
+                            log::warn!("[WARN] Unknown inline comment syntax '{val}' in {path}");
+                        }
+                    } else {
+                        log::warn!(
+                            "[WARN] Expected string for global key '{}' in {}, found {}",
+                            key,
+                            path,
+                            value_item.type_name()
+                        );
+                    }
+                }
                 "flavor" => {
                     if let Some(toml_edit::Value::String(formatted_string)) = value_item.as_value() {
                         let val = formatted_string.value();
@@ -3218,12 +3949,93 @@ fn parse_rumdl_toml(content: &str, path: &str, source: ConfigSource) -> Result<S
             .push_override(per_file_map, source, file.clone(), None);
     }
 
+    // Handle [vscode] section
+    if let Some(vscode_item) = doc.get("vscode")
+        && let Some(vscode_table) = vscode_item.as_table()
+    {
+        let mut vscode = VscodeConfig::default();
+        for (key, value_item) in vscode_table.iter() {
+            match normalize_key(key).as_str() {
+                "format-on-save" => match value_item.as_bool() {
+                    Some(value) => vscode.format_on_save = Some(value),
+                    None => log::warn!(
+                        "[WARN] Expected boolean for vscode key '{key}' in {path}, found {}",
+                        value_item.type_name()
+                    ),
+                },
+                "min-severity" => {
+                    match value_item
+                        .as_str()
+                        .and_then(|s| toml::Value::String(s.to_ascii_lowercase()).try_into().ok())
+                    {
+                        Some(value) => vscode.min_severity = Some(value),
+                        None => log::warn!("[WARN] Expected \"warning\" or \"error\" for vscode key '{key}' in {path}"),
+                    }
+                }
+                _ => {
+                    fragment
+                        .unknown_keys
+                        .push(("[vscode]".to_string(), key.to_string(), Some(path.to_string())));
+                    log::warn!("[WARN] Unknown key in [vscode] section of {path}: {key}");
+                }
+            }
+        }
+        fragment.vscode.push_override(vscode, source, file.clone(), None);
+    }
+
+    // Handle [fmt] section
+    if let Some(fmt_item) = doc.get("fmt")
+        && let Some(fmt_table) = fmt_item.as_table()
+    {
+        let mut fmt = FmtConfig::default();
+        for (key, value_item) in fmt_table.iter() {
+            let target = match normalize_key(key).as_str() {
+                "enable" => &mut fmt.enable,
+                "disable" => &mut fmt.disable,
+                _ => {
+                    fragment
+                        .unknown_keys
+                        .push(("[fmt]".to_string(), key.to_string(), Some(path.to_string())));
+                    log::warn!("[WARN] Unknown key in [fmt] section of {path}: {key}");
+                    continue;
+                }
+            };
+            match value_item.as_array() {
+                Some(array) => {
+                    *target = array
+                        .iter()
+                        .filter_map(|item| item.as_str())
+                        .map(normalize_key)
+                        .collect()
+                }
+                None => log::warn!(
+                    "[WARN] Expected array for fmt key '{key}' in {path}, found {}",
+                    value_item.type_name()
+                ),
+            }
+        }
+        fragment.fmt.push_override(fmt, source, file.clone(), None);
+    }
+
+    // Config files to inherit from, loaded before this one
+    if let Some(extend) = doc.get("extend") {
+        fragment.extend = match extend.as_value() {
+            Some(toml_edit::Value::String(extend_path)) => vec![extend_path.value().clone()],
+            Some(toml_edit::Value::Array(extend_paths)) if extend_paths.iter().all(|p| p.is_str()) => extend_paths
+                .iter()
+                .filter_map(|p| p.as_str())
+                .map(str::to_string)
+                .collect(),
+            _ => return Err(invalid_extend_error(path)),
+        };
+    }
+
     // Rule-specific: all other top-level tables
     for (key, item) in doc.iter() {
-        let norm_rule_name = key.to_ascii_uppercase();
+        let norm_rule_name = normalize_key(key).to_ascii_uppercase();
 
         // Skip known special sections
-        if key == "global" || key == "per-file-ignores" {
+        if key == "global" || key == "per-file-ignores" || key == "vscode" || key == "fmt" || key == "extend" {
             continue;
         }
 
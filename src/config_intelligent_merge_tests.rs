@@ -178,7 +178,10 @@ mod tests {
         let mut project_fragment = SourcedConfigFragment {
             global: SourcedGlobalConfig::default(),
             per_file_ignores: SourcedValue::new(Default::default(), ConfigSource::Default),
+            vscode: SourcedValue::new(Default::default(), ConfigSource::Default),
+            fmt: SourcedValue::new(Default::default(), ConfigSource::Default),
             rules: Default::default(),
+            extend: vec![],
             unknown_keys: vec![],
         };
         project_fragment.global.disable = make_sourced_vec(vec!["MD047"], ConfigSource::PyprojectToml);
@@ -207,7 +210,10 @@ mod tests {
         let mut project_fragment = SourcedConfigFragment {
             global: SourcedGlobalConfig::default(),
             per_file_ignores: SourcedValue::new(Default::default(), ConfigSource::Default),
+            vscode: SourcedValue::new(Default::default(), ConfigSource::Default),
+            fmt: SourcedValue::new(Default::default(), ConfigSource::Default),
             rules: Default::default(),
+            extend: vec![],
             unknown_keys: vec![],
         };
         project_fragment.global.enable = make_sourced_vec(vec!["MD013"], ConfigSource::PyprojectToml);
@@ -271,7 +277,10 @@ mod tests {
         let project_fragment = SourcedConfigFragment {
             global: SourcedGlobalConfig::default(),
             per_file_ignores: SourcedValue::new(Default::default(), ConfigSource::Default),
+            vscode: SourcedValue::new(Default::default(), ConfigSource::Default),
+            fmt: SourcedValue::new(Default::default(), ConfigSource::Default),
             rules: Default::default(),
+            extend: vec![],
             unknown_keys: vec![],
         };
 
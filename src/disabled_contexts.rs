@@ -0,0 +1,152 @@
+//!
+//! Context-scoped rule disabling.
+//!
+//! A rule can be disabled only inside particular document contexts via the
+//! per-rule `disable-in` option:
+//!
+//! ```toml
+//! [MD013]
+//! disable-in = ["blockquote", "list"]
+//! ```
+//!
+//! Warnings whose line falls inside one of the listed contexts are dropped in the
+//! lint loop; the rule still reports everywhere else. Fixing follows the same
+//! boundary: such a rule is fixed warning by warning, and fixes that touch a
+//! disabled line are left out.
+
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::LintWarning;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// Config key used in rule sections to scope disabling to contexts
+pub const DISABLE_IN_KEY: &str = "disable-in";
+
+/// A document context in which a rule can be disabled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DisabledContext {
+    /// Lines inside a blockquote (`> quoted`)
+    Blockquote,
+    /// Lines inside a list, including continuation lines of list items
+    List,
+    /// Lines inside fenced or indented code blocks
+    Code,
+}
+
+impl DisabledContext {
+    /// Check whether the given 1-indexed line lies inside this context
+    pub fn contains_line(self, ctx: &LintContext, line: usize) -> bool {
+        let Some(info) = ctx.line_info(line) else {
+            return false;
+        };
+        match self {
+            DisabledContext::Blockquote => info.blockquote.is_some(),
+            DisabledContext::List => {
+                info.list_item.is_some()
+                    || ctx
+                        .list_blocks()
+                        .iter()
+                        .any(|block| line >= block.start_line && line <= block.end_line)
+            }
+            DisabledContext::Code => info.in_code_block,
+        }
+    }
+}
+
+impl FromStr for DisabledContext {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "blockquote" | "blockquotes" => Ok(DisabledContext::Blockquote),
+            "list" | "lists" => Ok(DisabledContext::List),
+            "code" | "code-block" | "code_block" => Ok(DisabledContext::Code),
+            _ => Err(format!(
+                "Unknown context '{s}' (expected one of: blockquote, list, code)"
+            )),
+        }
+    }
+}
+
+/// Per-rule map of contexts in which the rule's warnings are suppressed
+#[derive(Debug, Clone, Default)]
+pub struct DisabledContexts {
+    by_rule: HashMap<String, Vec<DisabledContext>>,
+}
+
+impl DisabledContexts {
+    /// Collect `disable-in` settings from every rule section of the config
+    ///
+    /// Unknown context names are logged and ignored.
+    pub fn from_config(config: &Config) -> Self {
+        let mut by_rule = HashMap::new();
+
+        for (rule_name, rule_config) in &config.rules {
+            let Some(value) = rule_config
+                .values
+                .get(DISABLE_IN_KEY)
+                .or_else(|| rule_config.values.get("disable_in"))
+            else {
+                continue;
+            };
+            let Some(items) = value.as_array() else {
+                log::warn!("[{rule_name}] {DISABLE_IN_KEY} must be an array of context names");
+                continue;
+            };
+
+            let contexts: Vec<DisabledContext> = items
+                .iter()
+                .filter_map(|item| item.as_str())
+                .filter_map(|name| match name.parse() {
+                    Ok(context) => Some(context),
+                    Err(e) => {
+                        log::warn!("[{rule_name}] {e}");
+                        None
+                    }
+                })
+                .collect();
+
+            if !contexts.is_empty() {
+                by_rule.insert(rule_name.to_ascii_uppercase(), contexts);
+            }
+        }
+
+        Self { by_rule }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_rule.is_empty()
+    }
+
+    /// Check whether `rule_name` is disabled in any context
+    pub fn has_rule(&self, rule_name: &str) -> bool {
+        self.by_rule.contains_key(rule_name)
+    }
+
+    /// Check whether a warning from `rule_name` on `line` (1-indexed) should be suppressed
+    pub fn is_disabled(&self, rule_name: &str, ctx: &LintContext, line: usize) -> bool {
+        self.by_rule
+            .get(rule_name)
+            .is_some_and(|contexts| contexts.iter().any(|c| c.contains_line(ctx, line)))
+    }
+
+    /// Keep only the fixes from `rule_name` whose edits stay outside its disabled contexts
+    ///
+    /// A fix is dropped when its warning line, or any line its replacement range
+    /// spans, lies inside a disabled context. Warnings without a fix are dropped too.
+    pub fn retain_fixable(&self, rule_name: &str, ctx: &LintContext, warnings: Vec<LintWarning>) -> Vec<LintWarning> {
+        warnings
+            .into_iter()
+            .filter(|w| {
+                let Some(fix) = &w.fix else {
+                    return false;
+                };
+                let (start_line, _) = ctx.offset_to_line_col(fix.range.start);
+                let (end_line, _) = ctx.offset_to_line_col(fix.range.end);
+                !self.is_disabled(rule_name, ctx, w.line)
+                    && !(start_line..=end_line).any(|line| self.is_disabled(rule_name, ctx, line))
+            })
+            .collect()
+    }
+}
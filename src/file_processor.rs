@@ -8,10 +8,12 @@ use ignore::WalkBuilder;
 use ignore::overrides::OverrideBuilder;
 use rumdl_config::normalize_key;
 use rumdl_lib::config as rumdl_config;
+use rumdl_lib::exit_codes::exit;
 use rumdl_lib::lint_context::LintContext;
 use rumdl_lib::rule::Rule;
-use std::collections::HashSet;
-use std::path::Path;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 /// Expands directory-style patterns to also match files within them.
 /// Pattern "dir/path" becomes ["dir/path", "dir/path/**"] to match both
@@ -38,7 +40,7 @@ pub fn get_enabled_rules_from_checkargs(args: &crate::CheckArgs, config: &rumdl_
     let all_rules: Vec<Box<dyn Rule>> = rumdl_lib::rules::all_rules(config);
 
     // 2. Determine the final list of enabled rules based on precedence
-    let final_rules: Vec<Box<dyn Rule>>;
+    let mut final_rules: Vec<Box<dyn Rule>>;
 
     // Rule names provided via CLI flags
     let cli_enable_set: Option<HashSet<&str>> = args
@@ -180,6 +182,17 @@ pub fn get_enabled_rules_from_checkargs(args: &crate::CheckArgs, config: &rumdl_
         final_rules = current_rules; // Assign the final filtered vector
     }
 
+    // 3. `fmt` applies only the [fmt] subset of the rules enabled above
+    if args.fix_mode == crate::FixMode::Format {
+        let fmt_enable_set: HashSet<String> = config.fmt.enable.iter().map(|s| normalize_key(s)).collect();
+        let fmt_disable_set: HashSet<String> = config.fmt.disable.iter().map(|s| normalize_key(s)).collect();
+        final_rules.retain(|rule| {
+            let normalized_rule_name = normalize_key(rule.name());
+            (fmt_enable_set.is_empty() || fmt_enable_set.contains(&normalized_rule_name))
+                && !fmt_disable_set.contains(&normalized_rule_name)
+        });
+    }
+
     // 4. Print enabled rules if verbose
     if args.verbose {
         println!("Enabled rules:");
@@ -191,6 +204,77 @@ pub fn get_enabled_rules_from_checkargs(args: &crate::CheckArgs, config: &rumdl_
 
     final_rules
 }
+/// Characters that mark a path argument as a glob pattern
+const GLOB_METACHARACTERS: &[char] = &['*', '?', '[', '{'];
+
+/// Expand path arguments that still contain glob metacharacters
+///
+/// Shells like Windows `cmd` pass `docs/**/*.md` through unexpanded, so rumdl expands such
+/// patterns itself, relative to the current directory. Paths that exist literally are never
+/// treated as patterns, and `--no-glob` disables expansion entirely.
+pub fn expand_glob_paths(paths: &[String], args: &crate::CheckArgs) -> Result<Vec<String>, Box<dyn Error>> {
+    let mut expanded = Vec::with_capacity(paths.len());
+
+    for path in paths {
+        if args.no_glob || !path.contains(GLOB_METACHARACTERS) || Path::new(path).exists() {
+            expanded.push(path.clone());
+            continue;
+        }
+
+        // `\` is an escape character in glob syntax, but a path separator on Windows
+        let pattern = if cfg!(windows) {
+            path.replace('\\', "/")
+        } else {
+            path.clone()
+        };
+        let pattern = pattern.strip_prefix("./").unwrap_or(&pattern);
+
+        let matcher = globset::GlobBuilder::new(pattern)
+            .literal_separator(true)
+            .build()
+            .map_err(|e| format!("Invalid glob pattern '{path}': {e}"))?
+            .compile_matcher();
+
+        // Only walk the literal directory prefix of the pattern
+        let base_components: Vec<&str> = pattern
+            .split('/')
+            .take_while(|component| !component.contains(GLOB_METACHARACTERS))
+            .collect();
+        let base = match base_components.join("/") {
+            b if b.is_empty() && pattern.starts_with('/') => "/".to_string(),
+            b if b.is_empty() => ".".to_string(),
+            b => b,
+        };
+
+        let mut walk_builder = WalkBuilder::new(&base);
+        walk_builder
+            .standard_filters(args.respect_gitignore)
+            .hidden(false)
+            .require_git(false);
+
+        let mut matches: Vec<String> = walk_builder
+            .build()
+            .filter_map(Result::ok)
+            .filter(|entry| entry.path().is_file())
+            .map(|entry| {
+                let file_path = entry.path().to_string_lossy().to_string();
+                file_path.strip_prefix("./").map(str::to_string).unwrap_or(file_path)
+            })
+            .filter(|file_path| matcher.is_match(file_path))
+            .collect();
+
+        if matches.is_empty() {
+            return Err(format!("No files matched pattern: {path}").into());
+        }
+
+        matches.sort();
+        expanded.extend(matches);
+    }
+
+    Ok(expanded)
+}
+
+/// Find the Markdown files to check, sorted and without duplicates
 pub fn find_markdown_files(
     paths: &[String],
     args: &crate::CheckArgs,
@@ -198,6 +282,30 @@ pub fn find_markdown_files(
     project_root: Option<&std::path::Path>,
 ) -> Result<Vec<String>, Box<dyn Error>> {
     let mut file_paths = Vec::new();
+    walk_markdown_files(paths, args, config, project_root, |file_path| {
+        file_paths.push(file_path)
+    })?;
+    file_paths.sort();
+    file_paths.dedup();
+    Ok(file_paths)
+}
+
+/// Pass each Markdown file to check to `emit` as soon as it is found, in walk order
+///
+/// Explicitly named files are validated before any file is emitted, so an error means
+/// nothing was emitted. Each file is emitted once.
+pub fn walk_markdown_files(
+    paths: &[String],
+    args: &crate::CheckArgs,
+    config: &rumdl_config::Config,
+    project_root: Option<&std::path::Path>,
+    mut emit: impl FnMut(String),
+) -> Result<(), Box<dyn Error>> {
+    let mut file_paths = Vec::new();
+
+    // Expand glob patterns the shell left alone (e.g. `docs/**/*.md` in Windows cmd)
+    let expanded_paths = expand_glob_paths(paths, args)?;
+    let paths = expanded_paths.as_slice();
 
     // --- Configure ignore::WalkBuilder ---
     // Start with the first path, add others later
@@ -454,12 +562,58 @@ pub fn find_markdown_files(
         if processed_explicit_files {
             file_paths.sort();
             file_paths.dedup();
-            return Ok(file_paths);
+            file_paths.into_iter().for_each(emit);
+            return Ok(());
         }
     }
 
+    // --- Post-walk exclude pattern filtering ---
+    // The ignore crate's overrides may not work correctly when the walker path prefix
+    // differs from the config file location. Apply exclude patterns manually here.
+    let canonical_root = project_root.and_then(|root| root.canonicalize().ok());
+    let exclude_matchers: Vec<globset::GlobMatcher> = if project_root.is_some() {
+        final_exclude_patterns
+            .iter()
+            .filter_map(|pattern| globset::Glob::new(pattern).ok())
+            .map(|glob| glob.compile_matcher())
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let is_excluded = |file_path: &str| {
+        if exclude_matchers.is_empty() {
+            return false;
+        }
+        // Compute path relative to project_root for pattern matching
+        let path_for_matching = canonical_root
+            .as_ref()
+            .zip(Path::new(file_path).canonicalize().ok())
+            .and_then(|(root, path)| path.strip_prefix(root).ok().map(|p| p.to_string_lossy().to_string()))
+            .unwrap_or_else(|| file_path.to_string());
+        exclude_matchers
+            .iter()
+            .any(|matcher| matcher.is_match(&path_for_matching))
+    };
+
+    // --- Final Explicit Markdown Filter ---
+    // Only apply the extension filter if --include was NOT explicitly provided via CLI
+    // When --include is provided, respect the user's explicit intent about which files to check
+    let has_markdown_extension = |path_str: &str| {
+        // Ensure only files with markdown extensions are returned,
+        // regardless of how ignore crate overrides interacted with type filters.
+        has_explicit_cli_include
+            || Path::new(path_str).extension().is_some_and(|ext| {
+                matches!(
+                    ext.to_str(),
+                    Some("md" | "markdown" | "mdx" | "mkd" | "mkdn" | "mdown" | "mdwn" | "qmd" | "rmd" | "Rmd")
+                )
+            })
+    };
+
     // --- Execute Walk ---
 
+    // WalkBuilder may yield a file more than once (e.g. overlapping input paths)
+    let mut seen = HashSet::new();
     for result in walk_builder.build() {
         match result {
             Ok(entry) => {
@@ -468,13 +622,18 @@ pub fn find_markdown_files(
                 // Check if it's a file and if it wasn't explicitly excluded by overrides
                 if path.is_file() {
                     let file_path = path.to_string_lossy().to_string();
-                    // Clean the path before pushing
+                    // Clean the path before emitting
                     let cleaned_path = if let Some(stripped) = file_path.strip_prefix("./") {
                         stripped.to_string()
                     } else {
                         file_path
                     };
-                    file_paths.push(cleaned_path);
+                    if has_markdown_extension(&cleaned_path)
+                        && !is_excluded(&cleaned_path)
+                        && seen.insert(cleaned_path.clone())
+                    {
+                        emit(cleaned_path);
+                    }
                 }
             }
             Err(err) => {
@@ -486,66 +645,21 @@ pub fn find_markdown_files(
         }
     }
 
-    // Remove duplicate paths if WalkBuilder might yield them (e.g. multiple input paths)
-    file_paths.sort();
-    file_paths.dedup();
-
-    // --- Post-walk exclude pattern filtering ---
-    // The ignore crate's overrides may not work correctly when the walker path prefix
-    // differs from the config file location. Apply exclude patterns manually here.
-    if !final_exclude_patterns.is_empty()
-        && let Some(root) = project_root
-    {
-        file_paths.retain(|file_path| {
-            let path = Path::new(file_path);
-            // Compute path relative to project_root for pattern matching
-            let path_for_matching = if let Ok(canonical_path) = path.canonicalize() {
-                if let Ok(canonical_root) = root.canonicalize() {
-                    if let Ok(relative) = canonical_path.strip_prefix(&canonical_root) {
-                        relative.to_string_lossy().to_string()
-                    } else {
-                        file_path.clone()
-                    }
-                } else {
-                    file_path.clone()
-                }
-            } else {
-                file_path.clone()
-            };
-
-            // Check if any exclude pattern matches
-            for pattern in &final_exclude_patterns {
-                if let Ok(glob) = globset::Glob::new(pattern) {
-                    let matcher = glob.compile_matcher();
-                    if matcher.is_match(&path_for_matching) {
-                        return false; // Exclude this file
-                    }
-                }
-            }
-            true // Keep this file
-        });
-    }
-
-    // --- Final Explicit Markdown Filter ---
-    // Only apply the extension filter if --include was NOT explicitly provided via CLI
-    // When --include is provided, respect the user's explicit intent about which files to check
-    if !has_explicit_cli_include {
-        // Ensure only files with markdown extensions are returned,
-        // regardless of how ignore crate overrides interacted with type filters.
-        file_paths.retain(|path_str| {
-            let path = Path::new(path_str);
-            path.extension().is_some_and(|ext| {
-                matches!(
-                    ext.to_str(),
-                    Some("md" | "markdown" | "mdx" | "mkd" | "mkdn" | "mdown" | "mdwn" | "qmd" | "rmd" | "Rmd")
-                )
-            })
-        });
-    }
-    // -------------------------------------
-
-    Ok(file_paths) // Ensure the function returns the result
+    Ok(())
 }
+/// Count the warnings that have a fix and whose rule may be fixed under the current config
+pub fn count_fixable(config: &rumdl_config::Config, warnings: &[rumdl_lib::rule::LintWarning]) -> usize {
+    warnings
+        .iter()
+        .filter(|w| {
+            w.fix.is_some()
+                && w.rule_name
+                    .as_ref()
+                    .is_some_and(|name| is_rule_actually_fixable(config, name))
+        })
+        .count()
+}
+
 pub fn is_rule_actually_fixable(config: &rumdl_config::Config, rule_name: &str) -> bool {
     // Check unfixable list
     if config
@@ -565,17 +679,68 @@ pub fn is_rule_actually_fixable(config: &rumdl_config::Config, rule_name: &str)
     true
 }
 
+/// Copy `file_path` to `file_path` + `suffix` before it is overwritten by fixes
+pub fn write_backup(file_path: &str, suffix: &str) -> std::io::Result<()> {
+    std::fs::copy(file_path, format!("{file_path}{suffix}")).map(|_| ())
+}
+
+/// Directory that reported file paths are made relative to, from `--report-root` or `report-root`
+///
+/// The CLI value is relative to the working directory and the config value to the project
+/// root, so a shared config reports the same paths wherever rumdl is run from.
+pub fn resolve_report_root(
+    args: &crate::CheckArgs,
+    config: &rumdl_config::Config,
+    project_root: Option<&Path>,
+) -> Option<PathBuf> {
+    let root = match (&args.report_root, &config.global.report_root) {
+        (Some(cli_root), _) => PathBuf::from(cli_root),
+        (None, Some(config_root)) => match project_root {
+            Some(project_root) if Path::new(config_root).is_relative() => project_root.join(config_root),
+            _ => PathBuf::from(config_root),
+        },
+        (None, None) => return None,
+    };
+
+    match std::fs::canonicalize(&root) {
+        Ok(root) => Some(root),
+        Err(e) => {
+            if !args.silent {
+                eprintln!(
+                    "{}: Report root '{}' is not usable ({}); reporting paths as given",
+                    "Warning".yellow().bold(),
+                    root.display(),
+                    e
+                );
+            }
+            None
+        }
+    }
+}
+
+/// `file_path` as it appears in output: relative to `report_root`, with forward slashes,
+/// when the file lies inside it, and unchanged otherwise
+pub fn report_path(file_path: &str, report_root: Option<&Path>) -> String {
+    let relative = report_root.and_then(|root| {
+        let canonical = std::fs::canonicalize(file_path).ok()?;
+        Some(canonical.strip_prefix(root).ok()?.to_string_lossy().replace('\\', "/"))
+    });
+    relative.unwrap_or_else(|| file_path.to_string())
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn process_file_with_formatter(
     file_path: &str,
     rules: &[Box<dyn Rule>],
     fix_mode: crate::FixMode,
     diff: bool,
+    backup_suffix: Option<&str>,
     verbose: bool,
     quiet: bool,
     silent: bool,
     output_format: &rumdl_lib::output::OutputFormat,
     output_writer: &rumdl_lib::output::OutputWriter,
+    report_root: Option<&Path>,
     config: &rumdl_config::Config,
     cache: Option<std::sync::Arc<std::sync::Mutex<LintCache>>>,
 ) -> (
@@ -598,12 +763,13 @@ pub fn process_file_with_formatter(
 
     // Format and output warnings (show diagnostics unless silent)
     if !silent && fix_mode == crate::FixMode::Check {
+        let display_path = report_path(file_path, report_root);
         if diff {
             // In diff mode, only show warnings for unfixable issues
             let unfixable_warnings: Vec<_> = all_warnings.iter().filter(|w| w.fix.is_none()).cloned().collect();
 
             if !unfixable_warnings.is_empty() {
-                let formatted = formatter.format_warnings(&unfixable_warnings, file_path);
+                let formatted = formatter.format_warnings(&unfixable_warnings, &display_path);
                 if !formatted.is_empty() {
                     output_writer.writeln(&formatted).unwrap_or_else(|e| {
                         eprintln!("Error writing output: {e}");
@@ -612,7 +778,7 @@ pub fn process_file_with_formatter(
             }
         } else {
             // In check mode, show all warnings with [*] for fixable issues
-            let formatted = formatter.format_warnings(&all_warnings, file_path);
+            let formatted = formatter.format_warnings(&all_warnings, &display_path);
             if !formatted.is_empty() {
                 output_writer.writeln(&formatted).unwrap_or_else(|e| {
                     eprintln!("Error writing output: {e}");
@@ -626,7 +792,7 @@ pub fn process_file_with_formatter(
     if diff {
         // In diff mode, apply fixes to a copy and show diff
         let original_content = content.clone();
-        warnings_fixed = apply_fixes_coordinated(rules, &all_warnings, &mut content, true, true, config);
+        warnings_fixed = apply_fixes_coordinated(rules, &all_warnings, &mut content, file_path, false, true, config);
 
         if warnings_fixed > 0 {
             let diff_output = formatter::generate_diff(&original_content, &content, file_path);
@@ -645,15 +811,38 @@ pub fn process_file_with_formatter(
             file_index,
         );
     } else if fix_mode != crate::FixMode::Check {
-        // Apply fixes using Fix Coordinator
-        warnings_fixed = apply_fixes_coordinated(rules, &all_warnings, &mut content, quiet, silent, config);
+        // Apply fixes using Fix Coordinator. A hash (rather than a copy of the content) is
+        // enough to tell whether the file changed and needs a backup
+        let original_hash = backup_suffix.map(|_| blake3::hash(content.as_bytes()));
+        warnings_fixed =
+            apply_fixes_coordinated(rules, &all_warnings, &mut content, file_path, verbose, silent, config);
+
+        // Write fixed content back to file (unchanged files never get a backup)
+        let changed = original_hash.is_none_or(|hash| hash != blake3::hash(content.as_bytes()));
+        if warnings_fixed > 0 && changed {
+            // Denormalize back to original line ending before writing (LF content is written as is)
+            let content_to_write = if original_line_ending == rumdl_lib::utils::LineEnding::Lf {
+                std::borrow::Cow::Borrowed(content.as_str())
+            } else {
+                std::borrow::Cow::Owned(rumdl_lib::utils::normalize_line_ending(&content, original_line_ending))
+            };
 
-        // Write fixed content back to file
-        if warnings_fixed > 0 {
-            // Denormalize back to original line ending before writing
-            let content_to_write = rumdl_lib::utils::normalize_line_ending(&content, original_line_ending);
+            let backup_result = match backup_suffix {
+                Some(suffix) => write_backup(file_path, suffix),
+                None => Ok(()),
+            };
 
-            if let Err(err) = std::fs::write(file_path, &content_to_write)
+            if let Err(err) = backup_result {
+                // Never overwrite a file whose backup could not be written
+                if !silent {
+                    eprintln!(
+                        "{} Failed to write backup for {}, leaving it unchanged: {}",
+                        "Error:".red().bold(),
+                        file_path,
+                        err
+                    );
+                }
+            } else if let Err(err) = std::fs::write(file_path, content_to_write.as_bytes())
                 && !silent
             {
                 eprintln!(
@@ -748,6 +937,83 @@ pub struct ProcessFileResult {
     pub file_index: rumdl_lib::workspace_index::FileIndex,
 }
 
+/// Rules that apply to a file after per-file-ignores
+pub fn rules_for_file(rules: &[Box<dyn Rule>], config: &rumdl_config::Config, path: &Path) -> Vec<Box<dyn Rule>> {
+    let ignored_rules = config.get_ignored_rules_for_file(path);
+    rules
+        .iter()
+        .filter(|rule| !ignored_rules.contains(rule.name()))
+        .cloned()
+        .collect()
+}
+
+/// Config and rules for the files of a directory with its own config files
+pub struct DirectoryConfig {
+    pub config: rumdl_config::Config,
+    pub rules: Vec<Box<dyn Rule>>,
+}
+
+/// Per-directory configuration for `hierarchical = true`
+///
+/// Resolved once per directory and shared between workers. Directories without config
+/// files of their own below the project config use the project-wide config and rules.
+pub struct DirectoryConfigs<'a> {
+    base: &'a rumdl_config::SourcedConfig,
+    args: &'a crate::CheckArgs,
+    resolved: std::sync::Mutex<HashMap<PathBuf, Option<Arc<DirectoryConfig>>>>,
+}
+
+impl<'a> DirectoryConfigs<'a> {
+    /// Returns `None` unless the config enables hierarchical resolution
+    pub fn new(base: &'a rumdl_config::SourcedConfig, args: &'a crate::CheckArgs) -> Option<Self> {
+        base.global.hierarchical.value.then(|| Self {
+            base,
+            args,
+            resolved: std::sync::Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Config and rules for `file_path`, or `None` to use the project-wide ones
+    pub fn for_file(&self, file_path: &str) -> Option<Arc<DirectoryConfig>> {
+        let dir = Path::new(file_path).parent().unwrap_or(Path::new("")).to_path_buf();
+        if let Some(resolved) = self.resolved.lock().unwrap().get(&dir) {
+            return resolved.clone();
+        }
+
+        let resolved = match self.base.for_directory(&dir) {
+            Ok(sourced) => sourced.map(|sourced| {
+                let config: rumdl_config::Config = sourced.into();
+                let rules = get_enabled_rules_from_checkargs(self.args, &config);
+                Arc::new(DirectoryConfig { config, rules })
+            }),
+            Err(e) => {
+                eprintln!("{}: {}", "Config error".red().bold(), e);
+                exit::tool_error();
+            }
+        };
+        self.resolved.lock().unwrap().insert(dir, resolved.clone());
+        resolved
+    }
+
+    /// Drops the warnings of rules that the config for `file_path` does not enable
+    pub fn retain_enabled(&self, file_path: &str, warnings: &mut Vec<rumdl_lib::rule::LintWarning>) {
+        if let Some(dir_config) = self.for_file(file_path) {
+            let enabled: HashSet<&str> = dir_config.rules.iter().map(|rule| rule.name()).collect();
+            warnings.retain(|w| w.rule_name.as_deref().is_none_or(|name| enabled.contains(name)));
+        }
+    }
+}
+
+/// Flavor for `file_path`: detected from the file extension (.mdx, .qmd, .Rmd) when the
+/// config uses Standard, otherwise the configured flavor
+fn flavor_for_file(config: &rumdl_config::Config, file_path: &str) -> rumdl_config::MarkdownFlavor {
+    if config.markdown_flavor() == rumdl_config::MarkdownFlavor::Standard {
+        rumdl_config::MarkdownFlavor::from_path(Path::new(file_path))
+    } else {
+        config.markdown_flavor()
+    }
+}
+
 pub fn process_file_inner(
     file_path: &str,
     rules: &[Box<dyn Rule>],
@@ -815,8 +1081,10 @@ pub fn process_file_with_index(
     // Detect original line ending before any processing
     let original_line_ending = rumdl_lib::utils::detect_line_ending_enum(&content);
 
-    // Normalize to LF for all internal processing
-    content = rumdl_lib::utils::normalize_line_ending(&content, rumdl_lib::utils::LineEnding::Lf);
+    // Normalize to LF for all internal processing (LF files are used as read, without a copy)
+    if original_line_ending != rumdl_lib::utils::LineEnding::Lf {
+        content = rumdl_lib::utils::normalize_line_ending(&content, rumdl_lib::utils::LineEnding::Lf);
+    }
 
     // Early content analysis for ultra-fast skip decisions
     if content.is_empty() {
@@ -834,29 +1102,37 @@ pub fn process_file_with_index(
     // Note: Cache only stores single-file warnings; cross-file checks must run fresh
     if let Some(ref cache_arc) = cache {
         let mut cache_guard = cache_arc.lock().expect("Cache mutex poisoned");
-        if let Some(cached_warnings) = cache_guard.get(&content, &config_hash, &rules_hash) {
+        if let Some(mut cached_warnings) = cache_guard.get(&content, &config_hash, &rules_hash) {
             drop(cache_guard); // Release lock immediately
 
             if verbose && !quiet {
                 println!("Cache hit for {file_path}");
             }
+            let flavor = flavor_for_file(config, file_path);
+
+            // Rules that depend on more than this file's content are never cached; re-run them
+            let uncacheable_rules: Vec<Box<dyn Rule>> = rules_for_file(rules, config, Path::new(file_path))
+                .into_iter()
+                .filter(|rule| !rule.cacheable())
+                .collect();
+            if !uncacheable_rules.is_empty() {
+                let source_file = Some(std::path::PathBuf::from(file_path));
+                let (fresh_warnings, _) = rumdl_lib::lint_and_index_with_config(
+                    &content,
+                    &uncacheable_rules,
+                    verbose,
+                    config,
+                    flavor,
+                    source_file,
+                );
+                cached_warnings.extend(fresh_warnings.unwrap_or_default());
+                cached_warnings.sort_by_key(|w| (w.line, w.column));
+            }
+
             // Count fixable warnings from cache
-            let fixable_warnings = cached_warnings
-                .iter()
-                .filter(|w| {
-                    w.fix.is_some()
-                        && w.rule_name
-                            .as_ref()
-                            .is_some_and(|name| is_rule_actually_fixable(config, name))
-                })
-                .count();
+            let fixable_warnings = count_fixable(config, &cached_warnings);
 
             // Build FileIndex for cross-file analysis on cache hit (lightweight, no rule checking)
-            let flavor = if config.markdown_flavor() == rumdl_lib::config::MarkdownFlavor::Standard {
-                rumdl_lib::config::MarkdownFlavor::from_path(Path::new(file_path))
-            } else {
-                config.markdown_flavor()
-            };
             let file_index = rumdl_lib::build_file_index_only(&content, rules, flavor);
 
             return ProcessFileResult {
@@ -873,31 +1149,13 @@ pub fn process_file_with_index(
 
     let lint_start = Instant::now();
 
-    // Filter rules based on per-file-ignores configuration
-    let ignored_rules_for_file = config.get_ignored_rules_for_file(Path::new(file_path));
-    let filtered_rules: Vec<_> = if !ignored_rules_for_file.is_empty() {
-        rules
-            .iter()
-            .filter(|rule| !ignored_rules_for_file.contains(rule.name()))
-            .map(|r| dyn_clone::clone_box(&**r))
-            .collect()
-    } else {
-        rules.to_vec()
-    };
-
-    // Determine flavor: use file extension if config uses Standard, otherwise use config flavor
-    let flavor = if config.markdown_flavor() == rumdl_lib::config::MarkdownFlavor::Standard {
-        // Auto-detect from file extension for .mdx, .qmd, .Rmd files
-        rumdl_lib::config::MarkdownFlavor::from_path(Path::new(file_path))
-    } else {
-        // Use explicitly configured flavor
-        config.markdown_flavor()
-    };
+    let filtered_rules = rules_for_file(rules, config, Path::new(file_path));
+    let flavor = flavor_for_file(config, file_path);
 
     // Use lint_and_index for single-file linting + index contribution
     let source_file = Some(std::path::PathBuf::from(file_path));
     let (warnings_result, file_index) =
-        rumdl_lib::lint_and_index(&content, &filtered_rules, verbose, flavor, source_file);
+        rumdl_lib::lint_and_index_with_config(&content, &filtered_rules, verbose, config, flavor, source_file);
 
     // Combine all warnings
     let mut all_warnings = warnings_result.unwrap_or_default();
@@ -914,15 +1172,7 @@ pub fn process_file_with_index(
     let total_warnings = all_warnings.len();
 
     // Count fixable issues (excluding unfixable rules)
-    let fixable_warnings = all_warnings
-        .iter()
-        .filter(|w| {
-            w.fix.is_some()
-                && w.rule_name
-                    .as_ref()
-                    .is_some_and(|name| is_rule_actually_fixable(config, name))
-        })
-        .count();
+    let fixable_warnings = count_fixable(config, &all_warnings);
 
     let lint_end_time = Instant::now();
     let lint_time = lint_end_time.duration_since(lint_start);
@@ -938,8 +1188,19 @@ pub fn process_file_with_index(
 
     // Store in cache before returning (lock briefly for cache write)
     if let Some(ref cache_arc) = cache {
+        // Results of uncacheable rules are left out; they are re-run on every cache hit
+        let uncacheable: HashSet<&str> = rules
+            .iter()
+            .filter(|rule| !rule.cacheable())
+            .map(|rule| rule.name())
+            .collect();
+        let cacheable_warnings: Vec<_> = all_warnings
+            .iter()
+            .filter(|w| !w.rule_name.as_deref().is_some_and(|name| uncacheable.contains(name)))
+            .cloned()
+            .collect();
         let mut cache_guard = cache_arc.lock().expect("Cache mutex poisoned");
-        cache_guard.set(&content, &config_hash, &rules_hash, all_warnings.clone());
+        cache_guard.set(&content, &config_hash, &rules_hash, cacheable_warnings);
         // Unlock happens automatically when cache_guard goes out of scope
     }
 
@@ -956,7 +1217,8 @@ pub fn apply_fixes_coordinated(
     rules: &[Box<dyn Rule>],
     all_warnings: &[rumdl_lib::rule::LintWarning],
     content: &mut String,
-    _quiet: bool,
+    file_path: &str,
+    verbose: bool,
     silent: bool,
     config: &rumdl_config::Config,
 ) -> usize {
@@ -966,6 +1228,16 @@ pub fn apply_fixes_coordinated(
     let start = Instant::now();
     let coordinator = FixCoordinator::new();
 
+    // Overlapping fixes can't both apply to the same text; say which one waits its turn
+    if verbose && !silent {
+        for conflict in coordinator.find_fix_conflicts(rules, all_warnings, config) {
+            eprintln!(
+                "{}:{}: fix conflict: {} deferred, {} (line {}) edits the same text first",
+                file_path, conflict.deferred_line, conflict.deferred, conflict.winner, conflict.winner_line
+            );
+        }
+    }
+
     // Apply fixes iteratively (up to 100 iterations to ensure convergence, same as Ruff)
     match coordinator.apply_fixes_iterative(rules, all_warnings, content, config, 100) {
         Ok((rules_applied, iterations, ctx_creations, fixed_rule_names, converged)) => {
@@ -1010,3 +1282,113 @@ pub fn apply_fixes_coordinated(
         }
     }
 }
+
+/// Maximum number of extra fix passes `--check-idempotent` runs looking for a fixpoint
+const MAX_IDEMPOTENCY_PASSES: usize = 10;
+
+/// Check that one fix pass settles `file_path`, for `--check-idempotent`
+///
+/// Warnings that are still fixable after a pass are reported, since the fix should have
+/// resolved them, and further passes are run to see whether the document settles. With
+/// `write`, the file has already been fixed once and the settled content is written back;
+/// otherwise everything happens in memory. Returns true when one pass was enough.
+pub fn check_fix_idempotency(
+    file_path: &str,
+    rules: &[Box<dyn Rule>],
+    config: &rumdl_config::Config,
+    write: bool,
+    silent: bool,
+) -> bool {
+    use rumdl_lib::fix_coordinator::FixCoordinator;
+
+    let Ok(original) = crate::read_file_efficiently(Path::new(file_path)) else {
+        // Unreadable files were already reported by the lint pass
+        return true;
+    };
+    let line_ending = rumdl_lib::utils::detect_line_ending_enum(&original);
+    let mut content = rumdl_lib::utils::normalize_line_ending(&original, rumdl_lib::utils::LineEnding::Lf);
+
+    let rules = rules_for_file(rules, config, Path::new(file_path));
+    let flavor = flavor_for_file(config, file_path);
+    let coordinator = FixCoordinator::new();
+    let report_error = |e: String| {
+        if !silent {
+            eprintln!("Warning: Fix coordinator failed for {file_path}: {e}");
+        }
+    };
+
+    if !write {
+        // Dry run: apply the regular fix pass in memory first
+        let result = coordinator
+            .unsettled_warnings(&rules, &content, config, flavor)
+            .and_then(|warnings| coordinator.apply_fixes_iterative(&rules, &warnings, &mut content, config, 100));
+        if let Err(e) = result {
+            report_error(e);
+            return true;
+        }
+    }
+
+    let mut unsettled = match coordinator.unsettled_warnings(&rules, &content, config, flavor) {
+        Ok(unsettled) => unsettled,
+        Err(e) => {
+            report_error(e);
+            return true;
+        }
+    };
+    if unsettled.is_empty() {
+        return true;
+    }
+    unsettled.sort_by_key(|w| (w.line, w.column));
+
+    let fixed_once = content.clone();
+    let (passes, remaining) =
+        match coordinator.apply_fixes_until_stable(&rules, &mut content, config, flavor, MAX_IDEMPOTENCY_PASSES) {
+            Ok(result) => result,
+            Err(e) => {
+                report_error(e);
+                return false;
+            }
+        };
+
+    if !silent {
+        for warning in &unsettled {
+            eprintln!(
+                "{}:{}:{}: {} {} {}",
+                file_path.blue().underline(),
+                warning.line.to_string().cyan(),
+                warning.column.to_string().cyan(),
+                format!("[{:5}]", warning.rule_name.as_deref().unwrap_or("unknown")).yellow(),
+                warning.message,
+                "[not settled]".red()
+            );
+        }
+        let plural = if passes == 1 { "" } else { "es" };
+        if remaining.is_empty() {
+            eprintln!(
+                "{} Fixes for {file_path} are not idempotent; they settle after {passes} more pass{plural}",
+                "Warning:".yellow().bold()
+            );
+        } else {
+            eprintln!(
+                "{} Fixes for {file_path} are not idempotent and did not settle after {passes} more pass{plural}",
+                "Warning:".yellow().bold()
+            );
+        }
+    }
+
+    if write && content != fixed_once {
+        let content_to_write = rumdl_lib::utils::normalize_line_ending(&content, line_ending);
+        if let Err(err) = std::fs::write(file_path, content_to_write.as_bytes())
+            && !silent
+        {
+            eprintln!(
+                "{} Failed to write fixed content to file {}: {}",
+                "Error:".red().bold(),
+                file_path,
+                err
+            );
+        }
+    }
+
+    false
+}
@@ -1,4 +1,5 @@
-use crate::config::Config;
+use crate::config::{Config, MarkdownFlavor};
+use crate::disabled_contexts::DisabledContexts;
 use crate::lint_context::LintContext;
 use crate::rule::{LintWarning, Rule};
 use std::collections::hash_map::DefaultHasher;
@@ -8,6 +9,20 @@ use std::hash::{Hash, Hasher};
 /// Maximum number of fix iterations before stopping (same as Ruff)
 const MAX_ITERATIONS: usize = 100;
 
+/// Whether fixes for `rule_name` may be applied under the `fixable`/`unfixable` settings
+fn is_fixable_by_config(config: &Config, rule_name: &str) -> bool {
+    if config
+        .global
+        .unfixable
+        .iter()
+        .any(|r| r.eq_ignore_ascii_case(rule_name))
+    {
+        return false;
+    }
+
+    config.global.fixable.is_empty() || config.global.fixable.iter().any(|r| r.eq_ignore_ascii_case(rule_name))
+}
+
 /// Calculate hash of content for convergence detection
 fn hash_content(content: &str) -> u64 {
     let mut hasher = DefaultHasher::new();
@@ -15,6 +30,23 @@ fn hash_content(content: &str) -> u64 {
     hasher.finish()
 }
 
+/// Two rules whose fixes edit overlapping text
+///
+/// Only one fix can be applied to the original text. The rule that runs first (see
+/// [`FixCoordinator::get_optimal_order`]) wins; the deferred rule's fix is computed again
+/// from the content the winner left behind, so it still applies if its warning remains.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixConflict {
+    /// Rule whose fix is applied first
+    pub winner: String,
+    /// Line of the winning fix's warning
+    pub winner_line: usize,
+    /// Rule whose fix is deferred
+    pub deferred: String,
+    /// Line of the deferred fix's warning
+    pub deferred_line: usize,
+}
+
 /// Coordinates rule fixing to minimize the number of passes needed
 pub struct FixCoordinator {
     /// Rules that should run before others (rule -> rules that depend on it)
@@ -58,6 +90,10 @@ impl FixCoordinator {
     }
 
     /// Get the optimal order for running rules based on dependencies
+    ///
+    /// Prerequisites come before the rules that depend on them; otherwise rules keep
+    /// their order in `rules`. The order is deterministic, and it is also the priority
+    /// used to resolve [`FixConflict`]s.
     pub fn get_optimal_order<'a>(&self, rules: &'a [Box<dyn Rule>]) -> Vec<&'a dyn Rule> {
         // Build a map of rule names to rules for quick lookup
         let rule_map: HashMap<&str, &dyn Rule> = rules.iter().map(|r| (r.name(), r.as_ref())).collect();
@@ -94,8 +130,10 @@ impl FixCoordinator {
 
             visiting.insert(rule_name.to_string());
 
-            // Visit dependencies first
+            // Visit dependencies first, in a fixed order
             if let Some(deps) = reverse_deps.get(rule_name) {
+                let mut deps: Vec<&str> = deps.iter().copied().collect();
+                deps.sort_unstable();
                 for dep in deps {
                     if rule_map.contains_key(dep) {
                         visit(dep, rule_map, reverse_deps, visited, visiting, sorted);
@@ -134,8 +172,74 @@ impl FixCoordinator {
         sorted
     }
 
+    /// Find fixes from different rules that edit overlapping text
+    ///
+    /// Only warnings with a fix, from rules the configuration allows to be fixed, are
+    /// considered. Identical edits are not conflicts. Each conflict names the rule that
+    /// wins by running first and the rule whose fix is deferred.
+    pub fn find_fix_conflicts(
+        &self,
+        rules: &[Box<dyn Rule>],
+        warnings: &[LintWarning],
+        config: &Config,
+    ) -> Vec<FixConflict> {
+        let priority: HashMap<&str, usize> = self
+            .get_optimal_order(rules)
+            .iter()
+            .enumerate()
+            .map(|(i, rule)| (rule.name(), i))
+            .collect();
+
+        let mut fixes: Vec<(&LintWarning, &str, usize)> = warnings
+            .iter()
+            .filter(|w| w.fix.is_some())
+            .filter_map(|w| {
+                let name = w.rule_name.as_deref()?;
+                let rank = *priority.get(name)?;
+                is_fixable_by_config(config, name).then_some((w, name, rank))
+            })
+            .collect();
+        fixes.sort_by_key(|(w, _, rank)| (w.fix.as_ref().map(|f| f.range.start), *rank));
+
+        let mut conflicts = Vec::new();
+        for (i, (a, a_name, a_rank)) in fixes.iter().enumerate() {
+            let a_fix = a.fix.as_ref().expect("filtered to warnings with fixes");
+            for (b, b_name, b_rank) in &fixes[i + 1..] {
+                let b_fix = b.fix.as_ref().expect("filtered to warnings with fixes");
+                // Sorted by start, so nothing further along can overlap `a`
+                if b_fix.range.start > a_fix.range.end
+                    || (b_fix.range.start == a_fix.range.end && !a_fix.range.is_empty())
+                {
+                    break;
+                }
+                let overlaps = b_fix.range.start < a_fix.range.end || b_fix.range == a_fix.range;
+                if a_name == b_name || !overlaps || a_fix == b_fix {
+                    continue;
+                }
+
+                let ((winner, winner_name), (deferred, deferred_name)) = if a_rank <= b_rank {
+                    ((a, a_name), (b, b_name))
+                } else {
+                    ((b, b_name), (a, a_name))
+                };
+                conflicts.push(FixConflict {
+                    winner: winner_name.to_string(),
+                    winner_line: winner.line,
+                    deferred: deferred_name.to_string(),
+                    deferred_line: deferred.line,
+                });
+            }
+        }
+
+        conflicts
+    }
+
     /// Apply fixes iteratively until no more fixes are needed or max iterations reached
     /// Returns (rules_fixed_count, iterations, context_creations, fixed_rule_names, converged)
+    ///
+    /// `content` is fixed in place. Peak memory is roughly the content, one `LintContext`
+    /// for it, and the fixed copy returned by the rule being applied; no other copies of
+    /// the document are kept between rules or iterations.
     pub fn apply_fixes_iterative(
         &self,
         rules: &[Box<dyn Rule>],
@@ -150,6 +254,9 @@ impl FixCoordinator {
         // Get optimal rule order
         let ordered_rules = self.get_optimal_order(rules);
 
+        // Rules disabled in some contexts can't use their whole-document fix
+        let disabled_contexts = DisabledContexts::from_config(config);
+
         // Group warnings by rule for quick lookup
         let mut warnings_by_rule: HashMap<&str, Vec<&LintWarning>> = HashMap::new();
         for warning in all_warnings {
@@ -190,35 +297,31 @@ impl FixCoordinator {
                 }
 
                 // Check if rule is disabled
-                if config
-                    .global
-                    .unfixable
-                    .iter()
-                    .any(|r| r.eq_ignore_ascii_case(rule.name()))
-                {
+                if !is_fixable_by_config(config, rule.name()) {
                     processed_rules.insert(rule.name());
                     continue;
                 }
 
-                if !config.global.fixable.is_empty()
-                    && !config
-                        .global
-                        .fixable
-                        .iter()
-                        .any(|r| r.eq_ignore_ascii_case(rule.name()))
-                {
-                    processed_rules.insert(rule.name());
-                    continue;
-                }
-
-                // Create context for this specific rule
-                let ctx = LintContext::new(content, config.markdown_flavor(), None);
-                total_ctx_creations += 1;
+                // Create context for this specific rule and apply its fix. The context is
+                // dropped before the fixed content replaces the old one, so at most one
+                // context and two copies of the document are alive at a time
+                let fix_result = {
+                    let ctx = LintContext::new(content, config.markdown_flavor(), None);
+                    total_ctx_creations += 1;
+                    if disabled_contexts.has_rule(rule.name()) {
+                        rule.check(&ctx).map_err(|e| e.to_string()).and_then(|warnings| {
+                            let fixable = disabled_contexts.retain_fixable(rule.name(), &ctx, warnings);
+                            crate::utils::fix_utils::apply_warning_fixes(content, &fixable)
+                        })
+                    } else {
+                        rule.fix(&ctx).map_err(|e| e.to_string())
+                    }
+                };
 
-                // Apply fix
-                match rule.fix(&ctx) {
+                match fix_result {
                     Ok(fixed_content) => {
                         if fixed_content != *content {
+                            // Move, don't copy: the previous content is freed here
                             *content = fixed_content;
                             fixes_in_iteration += 1;
                             any_fix_applied = true;
@@ -273,6 +376,61 @@ impl FixCoordinator {
             converged,
         ))
     }
+
+    /// Lint `content` and return the warnings that a fix pass should have resolved:
+    /// those with a fix, from rules the configuration allows to be fixed
+    ///
+    /// After [`apply_fixes_iterative`](Self::apply_fixes_iterative), any such warning
+    /// means the fixes were not idempotent.
+    pub fn unsettled_warnings(
+        &self,
+        rules: &[Box<dyn Rule>],
+        content: &str,
+        config: &Config,
+        flavor: MarkdownFlavor,
+    ) -> Result<Vec<LintWarning>, String> {
+        let warnings = crate::lint_with_config(content, rules, false, config, flavor).map_err(|e| e.to_string())?;
+        Ok(warnings
+            .into_iter()
+            .filter(|w| {
+                w.fix.is_some()
+                    && w.rule_name
+                        .as_deref()
+                        .is_some_and(|name| is_fixable_by_config(config, name))
+            })
+            .collect())
+    }
+
+    /// Apply whole fix passes until no fixable warnings remain, a pass changes nothing,
+    /// or `max_passes` passes have run
+    /// Returns (passes_applied, remaining_unsettled_warnings)
+    ///
+    /// A document that is already settled needs zero passes.
+    pub fn apply_fixes_until_stable(
+        &self,
+        rules: &[Box<dyn Rule>],
+        content: &mut String,
+        config: &Config,
+        flavor: MarkdownFlavor,
+        max_passes: usize,
+    ) -> Result<(usize, Vec<LintWarning>), String> {
+        let mut passes = 0;
+        loop {
+            let unsettled = self.unsettled_warnings(rules, content, config, flavor)?;
+            if unsettled.is_empty() || passes >= max_passes {
+                return Ok((passes, unsettled));
+            }
+
+            let previous_hash = hash_content(content);
+            self.apply_fixes_iterative(rules, &unsettled, content, config, MAX_ITERATIONS)?;
+            passes += 1;
+
+            // The fixes no longer change anything, so further passes can't help
+            if hash_content(content) == previous_hash {
+                return Ok((passes, unsettled));
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -356,6 +514,70 @@ mod tests {
         assert!(md013_idx < md009_idx, "MD013 should come before MD009");
     }
 
+    #[test]
+    fn test_find_fix_conflicts() {
+        let coordinator = FixCoordinator::new();
+        let mock = |name: &'static str| -> Box<dyn Rule> {
+            Box::new(MockRule {
+                name,
+                warnings: vec![],
+                fix_content: String::new(),
+            })
+        };
+        // MD009 runs after MD013 (its prerequisite) even though it is listed first
+        let rules = vec![mock("MD009"), mock("MD013"), mock("MD037"), mock("MD049")];
+        let warning = |rule: &str, line: usize, range: std::ops::Range<usize>, replacement: &str| LintWarning {
+            line,
+            column: 1,
+            end_line: line,
+            end_column: 1,
+            message: String::new(),
+            rule_name: Some(rule.to_string()),
+            severity: crate::rule::Severity::Warning,
+            fix: Some(crate::rule::Fix {
+                range,
+                replacement: replacement.to_string(),
+            }),
+        };
+
+        let warnings = vec![
+            warning("MD049", 3, 10..14, "*x*"),
+            warning("MD037", 3, 10..14, "_x_"),
+            warning("MD009", 5, 30..32, ""),
+            warning("MD013", 4, 20..40, "wrapped"),
+            // Adjacent and identical edits don't conflict
+            warning("MD037", 6, 50..52, "a"),
+            warning("MD049", 6, 52..54, "b"),
+            warning("MD037", 7, 60..62, "c"),
+            warning("MD049", 7, 60..62, "c"),
+        ];
+        let config = Config::default();
+
+        let conflicts = coordinator.find_fix_conflicts(&rules, &warnings, &config);
+        assert_eq!(
+            conflicts,
+            vec![
+                FixConflict {
+                    winner: "MD037".to_string(),
+                    winner_line: 3,
+                    deferred: "MD049".to_string(),
+                    deferred_line: 3,
+                },
+                FixConflict {
+                    winner: "MD013".to_string(),
+                    winner_line: 4,
+                    deferred: "MD009".to_string(),
+                    deferred_line: 5,
+                },
+            ]
+        );
+
+        // Fixes the configuration won't apply can't conflict
+        let mut config = Config::default();
+        config.global.unfixable = vec!["MD049".to_string()];
+        assert_eq!(coordinator.find_fix_conflicts(&rules, &warnings, &config).len(), 1);
+    }
+
     #[test]
     fn test_single_iteration_fix() {
         let coordinator = FixCoordinator::new();
@@ -390,6 +612,8 @@ mod tests {
         let config = Config {
             global: GlobalConfig::default(),
             per_file_ignores: HashMap::new(),
+            vscode: Default::default(),
+            fmt: Default::default(),
             rules: Default::default(),
         };
 
@@ -466,6 +690,8 @@ mod tests {
         let config = Config {
             global: GlobalConfig::default(),
             per_file_ignores: HashMap::new(),
+            vscode: Default::default(),
+            fmt: Default::default(),
             rules: Default::default(),
         };
 
@@ -513,6 +739,8 @@ mod tests {
         let mut config = Config {
             global: GlobalConfig::default(),
             per_file_ignores: HashMap::new(),
+            vscode: Default::default(),
+            fmt: Default::default(),
             rules: Default::default(),
         };
         config.global.unfixable = vec!["MD001".to_string()];
@@ -580,6 +808,8 @@ mod tests {
         let config = Config {
             global: GlobalConfig::default(),
             per_file_ignores: HashMap::new(),
+            vscode: Default::default(),
+            fmt: Default::default(),
             rules: Default::default(),
         };
 
@@ -601,6 +831,8 @@ mod tests {
         let config = Config {
             global: GlobalConfig::default(),
             per_file_ignores: HashMap::new(),
+            vscode: Default::default(),
+            fmt: Default::default(),
             rules: Default::default(),
         };
 
@@ -649,4 +881,124 @@ mod tests {
         // Should return all rules despite cycle
         assert_eq!(ordered.len(), 3);
     }
+
+    /// Collapses one doubled letter per fix, so "xxxx" takes three passes to settle
+    #[derive(Clone)]
+    struct CollapseOnceRule {
+        /// A broken fix that never changes the content
+        stuck: bool,
+    }
+
+    impl Rule for CollapseOnceRule {
+        fn name(&self) -> &'static str {
+            "MD998"
+        }
+        fn check(&self, ctx: &LintContext) -> LintResult {
+            Ok(ctx
+                .content
+                .find("xx")
+                .map(|pos| LintWarning {
+                    line: 1,
+                    column: pos + 1,
+                    end_line: 1,
+                    end_column: pos + 3,
+                    message: "Doubled letter".to_string(),
+                    rule_name: Some("MD998".to_string()),
+                    severity: crate::rule::Severity::Warning,
+                    fix: Some(crate::rule::Fix {
+                        range: pos..pos + 2,
+                        replacement: "x".to_string(),
+                    }),
+                })
+                .into_iter()
+                .collect())
+        }
+        fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+            if self.stuck {
+                return Ok(ctx.content.to_string());
+            }
+            Ok(ctx.content.replacen("xx", "x", 1))
+        }
+        fn description(&self) -> &'static str {
+            "Collapses doubled letters one at a time"
+        }
+        fn category(&self) -> RuleCategory {
+            RuleCategory::Whitespace
+        }
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_apply_fixes_until_stable() {
+        let coordinator = FixCoordinator::new();
+        let config = Config::default();
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(CollapseOnceRule { stuck: false })];
+
+        // One pass leaves fixable warnings behind: the fix is not idempotent
+        let mut content = "axxxxb\n".to_string();
+        let warnings = coordinator
+            .unsettled_warnings(&rules, &content, &config, MarkdownFlavor::Standard)
+            .unwrap();
+        coordinator
+            .apply_fixes_iterative(&rules, &warnings, &mut content, &config, 100)
+            .unwrap();
+        assert_eq!(content, "axxxb\n");
+        assert_eq!(
+            coordinator
+                .unsettled_warnings(&rules, &content, &config, MarkdownFlavor::Standard)
+                .unwrap()
+                .len(),
+            1
+        );
+
+        let (passes, remaining) = coordinator
+            .apply_fixes_until_stable(&rules, &mut content, &config, MarkdownFlavor::Standard, 10)
+            .unwrap();
+        assert_eq!(passes, 2);
+        assert!(remaining.is_empty());
+        assert_eq!(content, "axb\n");
+
+        // Already settled content needs no passes
+        let (passes, _) = coordinator
+            .apply_fixes_until_stable(&rules, &mut content, &config, MarkdownFlavor::Standard, 10)
+            .unwrap();
+        assert_eq!(passes, 0);
+    }
+
+    #[test]
+    fn test_apply_fixes_until_stable_stuck_or_bounded() {
+        let coordinator = FixCoordinator::new();
+        let config = Config::default();
+
+        // A fix that changes nothing stops after one pass with the warning still there
+        let stuck: Vec<Box<dyn Rule>> = vec![Box::new(CollapseOnceRule { stuck: true })];
+        let mut content = "axxb\n".to_string();
+        let (passes, remaining) = coordinator
+            .apply_fixes_until_stable(&stuck, &mut content, &config, MarkdownFlavor::Standard, 10)
+            .unwrap();
+        assert_eq!(passes, 1);
+        assert_eq!(remaining.len(), 1);
+
+        // The number of passes is bounded
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(CollapseOnceRule { stuck: false })];
+        let mut content = "axxxxxxb\n".to_string();
+        let (passes, remaining) = coordinator
+            .apply_fixes_until_stable(&rules, &mut content, &config, MarkdownFlavor::Standard, 2)
+            .unwrap();
+        assert_eq!(passes, 2);
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(content, "axxxxb\n");
+
+        // Rules excluded with `unfixable` never count as unsettled
+        let mut config = Config::default();
+        config.global.unfixable = vec!["MD998".to_string()];
+        assert!(
+            coordinator
+                .unsettled_warnings(&rules, "axxb\n", &config, MarkdownFlavor::Standard)
+                .unwrap()
+                .is_empty()
+        );
+    }
 }
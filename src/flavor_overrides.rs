@@ -0,0 +1,75 @@
+//!
+//! Per-rule control over which Markdown flavors a rule runs under.
+//!
+//! Each rule decides by default which flavors it applies to (see
+//! [`Rule::applies_to_flavor`]). The per-rule `enabled-for-flavors` option
+//! replaces that decision with an explicit list:
+//!
+//! ```toml
+//! [MD033]
+//! enabled-for-flavors = ["standard", "mkdocs"]  # Don't flag JSX in MDX files
+//!
+//! [MD107]
+//! enabled-for-flavors = ["standard", "mkdocs"]  # Check abbreviations everywhere
+//! ```
+
+use crate::config::{Config, MarkdownFlavor};
+use crate::rule::Rule;
+use std::collections::HashMap;
+
+/// Config key used in rule sections to list the flavors a rule runs under
+pub const ENABLED_FOR_FLAVORS_KEY: &str = "enabled-for-flavors";
+
+/// Per-rule flavor lists that override [`Rule::applies_to_flavor`]
+#[derive(Debug, Clone, Default)]
+pub struct FlavorOverrides {
+    by_rule: HashMap<String, Vec<MarkdownFlavor>>,
+}
+
+impl FlavorOverrides {
+    /// Collect `enabled-for-flavors` settings from every rule section of the config
+    ///
+    /// Unknown flavor names are logged and ignored.
+    pub fn from_config(config: &Config) -> Self {
+        let mut by_rule = HashMap::new();
+
+        for (rule_name, rule_config) in &config.rules {
+            let Some(value) = rule_config
+                .values
+                .get(ENABLED_FOR_FLAVORS_KEY)
+                .or_else(|| rule_config.values.get("enabled_for_flavors"))
+            else {
+                continue;
+            };
+            let Some(items) = value.as_array() else {
+                log::warn!("[{rule_name}] {ENABLED_FOR_FLAVORS_KEY} must be an array of flavor names");
+                continue;
+            };
+
+            let flavors: Vec<MarkdownFlavor> = items
+                .iter()
+                .filter_map(|item| item.as_str())
+                .filter_map(|name| match name.parse() {
+                    Ok(flavor) => Some(flavor),
+                    Err(e) => {
+                        log::warn!("[{rule_name}] {e}");
+                        None
+                    }
+                })
+                .collect();
+
+            // An empty list is kept: it turns the rule off for every flavor
+            by_rule.insert(rule_name.to_ascii_uppercase(), flavors);
+        }
+
+        Self { by_rule }
+    }
+
+    /// Check whether `rule` runs for documents of `flavor`
+    pub fn applies(&self, rule: &dyn Rule, flavor: MarkdownFlavor) -> bool {
+        match self.by_rule.get(rule.name()) {
+            Some(flavors) => flavors.contains(&flavor),
+            None => rule.applies_to_flavor(flavor),
+        }
+    }
+}
@@ -3,6 +3,7 @@
 use colored::*;
 use rumdl_lib::config as rumdl_config;
 use rumdl_lib::rule::Rule;
+use std::collections::BTreeMap;
 
 /// Arguments for printing check results
 pub struct PrintResultsArgs<'a> {
@@ -218,13 +219,10 @@ pub fn format_toml_value(val: &toml::Value) -> String {
     }
 }
 
-/// Print statistics about lint warnings by rule
-pub fn print_statistics(warnings: &[rumdl_lib::rule::LintWarning]) {
-    use std::collections::HashMap;
-
-    // Group warnings by rule name
-    let mut rule_counts: HashMap<&str, usize> = HashMap::new();
-    let mut fixable_counts: HashMap<&str, usize> = HashMap::new();
+/// Count warnings by rule name, as `(violations, fixable)` per rule
+fn count_by_rule(warnings: &[rumdl_lib::rule::LintWarning]) -> (BTreeMap<&str, usize>, BTreeMap<&str, usize>) {
+    let mut rule_counts: BTreeMap<&str, usize> = BTreeMap::new();
+    let mut fixable_counts: BTreeMap<&str, usize> = BTreeMap::new();
 
     for warning in warnings {
         let rule_name = warning.rule_name.as_deref().unwrap_or("unknown");
@@ -235,6 +233,13 @@ pub fn print_statistics(warnings: &[rumdl_lib::rule::LintWarning]) {
         }
     }
 
+    (rule_counts, fixable_counts)
+}
+
+/// Print statistics about lint warnings by rule
+pub fn print_statistics(warnings: &[rumdl_lib::rule::LintWarning]) {
+    let (rule_counts, fixable_counts) = count_by_rule(warnings);
+
     // Sort rules by count (descending)
     let mut sorted_rules: Vec<_> = rule_counts.iter().collect();
     sorted_rules.sort_by(|a, b| b.1.cmp(a.1));
@@ -271,6 +276,115 @@ pub fn print_statistics(warnings: &[rumdl_lib::rule::LintWarning]) {
     );
 }
 
+/// One `--statistics-history` record
+#[derive(serde::Serialize)]
+pub struct StatisticsRecord<'a> {
+    /// RFC 3339 time of the run, in UTC
+    pub timestamp: String,
+    /// `HEAD` of the git repository in the working directory, if any
+    pub commit: Option<String>,
+    pub files: usize,
+    pub violations: usize,
+    pub fixable: usize,
+    /// Violations per rule, in rule name order
+    pub rules: BTreeMap<&'a str, usize>,
+}
+
+impl<'a> StatisticsRecord<'a> {
+    /// Aggregate a run's warnings the same way `--statistics` does
+    pub fn new(warnings: &'a [rumdl_lib::rule::LintWarning], files: usize, commit: Option<String>) -> Self {
+        let (rules, fixable_counts) = count_by_rule(warnings);
+        Self {
+            timestamp: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            commit,
+            files,
+            violations: warnings.len(),
+            fixable: fixable_counts.values().sum(),
+            rules,
+        }
+    }
+}
+
+/// Current git commit of the working directory, or `None` outside a repository
+pub fn current_git_commit() -> Option<String> {
+    let output = std::process::Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .stderr(std::process::Stdio::null())
+        .output()
+        .ok()?;
+    let commit = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (output.status.success() && !commit.is_empty()).then_some(commit)
+}
+
+/// Append a run's statistics to a JSON Lines history file, creating it if needed
+pub fn append_statistics_history(
+    path: &std::path::Path,
+    warnings: &[rumdl_lib::rule::LintWarning],
+    files: usize,
+) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let record = StatisticsRecord::new(warnings, files, current_git_commit());
+    let line = serde_json::to_string(&record)?;
+    let mut file = std::fs::OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{line}")
+}
+
+/// Count warnings by severity, as `(errors, warnings)`
+pub fn count_by_severity(warnings: &[rumdl_lib::rule::LintWarning]) -> (usize, usize) {
+    let errors = warnings
+        .iter()
+        .filter(|w| w.severity == rumdl_lib::rule::Severity::Error)
+        .count();
+    (errors, warnings.len() - errors)
+}
+
+/// The one-line `--summary` report.
+///
+/// The wording never changes with the counts (no singular forms, no colors), so scripts
+/// can match it with `rumdl: (\d+) errors, (\d+) warnings, (\d+) fixable, (\d+) files`.
+pub fn summary_line(errors: usize, warnings: usize, fixable: usize, files: usize) -> String {
+    format!("rumdl: {errors} errors, {warnings} warnings, {fixable} fixable, {files} files")
+}
+
+/// The `--summary-format json` report, printed to stderr as a single line
+#[derive(serde::Serialize)]
+pub struct SummaryRecord<'a> {
+    pub files_checked: usize,
+    pub files_with_issues: usize,
+    pub total_warnings: usize,
+    pub fixable: usize,
+    /// Warnings per rule, in rule name order
+    pub by_rule: BTreeMap<&'a str, usize>,
+}
+
+impl<'a> SummaryRecord<'a> {
+    pub fn new(
+        warnings: impl IntoIterator<Item = &'a rumdl_lib::rule::LintWarning>,
+        files_checked: usize,
+        files_with_issues: usize,
+        fixable: usize,
+    ) -> Self {
+        let mut by_rule: BTreeMap<&str, usize> = BTreeMap::new();
+        for warning in warnings {
+            *by_rule
+                .entry(warning.rule_name.as_deref().unwrap_or("unknown"))
+                .or_insert(0) += 1;
+        }
+        Self {
+            files_checked,
+            files_with_issues,
+            total_warnings: by_rule.values().sum(),
+            fixable,
+            by_rule,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_else(|_| "{}".to_string())
+    }
+}
+
 /// Generate a unified diff between original and modified content
 pub fn generate_diff(original: &str, modified: &str, file_path: &str) -> String {
     let mut diff = String::new();
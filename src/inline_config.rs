@@ -7,6 +7,7 @@
 //! - `<!-- markdownlint-enable MD001 MD002 -->` - Re-enable specific rules
 //! - `<!-- markdownlint-disable-line MD001 -->` - Disable rules for current line
 //! - `<!-- markdownlint-disable-next-line MD001 -->` - Disable rules for next line
+//! - `text <!-- rumdl: MD001 MD002 -->` - Trailing suppression for the current line (rumdl only)
 //! - `<!-- markdownlint-capture -->` - Capture current configuration state
 //! - `<!-- markdownlint-restore -->` - Restore captured configuration state
 //! - `<!-- markdownlint-disable-file -->` - Disable all rules for entire file
@@ -17,10 +18,15 @@
 //! - `<!-- prettier-ignore -->` - Disable all rules for next line (compatibility with prettier)
 //!
 //! Also supports rumdl-specific syntax with same semantics.
+//!
+//! With the JSX comment syntax (the default for MDX, where HTML comments aren't valid),
+//! the same directives can also be written as JSX comments: `{/* rumdl-disable MD013 */}`.
 
+use crate::config::InlineCommentSyntax;
 use crate::markdownlint_config::markdownlint_to_rumdl_rule_key;
 use crate::utils::code_block_utils::CodeBlockUtils;
 use serde_json::Value as JsonValue;
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 
 /// Normalize a rule name to its canonical form (e.g., "line-length" -> "MD013").
@@ -69,17 +75,22 @@ impl InlineConfig {
 
     /// Process all inline comments in the content and return the configuration state
     pub fn from_content(content: &str) -> Self {
+        Self::from_content_with_syntax(content, InlineCommentSyntax::Html)
+    }
+
+    /// Process all inline comments written in the given comment syntax
+    pub fn from_content_with_syntax(content: &str, syntax: InlineCommentSyntax) -> Self {
         let mut config = Self::new();
-        let lines: Vec<&str> = content.lines().collect();
+        let lines: Vec<Cow<str>> = content.lines().map(|line| to_html_comments(line, syntax)).collect();
 
         // Detect code blocks to skip comments within them
         let code_blocks = CodeBlockUtils::detect_code_blocks(content);
 
-        // Pre-compute line positions for checking if a line is in a code block
-        let mut line_positions = Vec::with_capacity(lines.len());
+        // Pre-compute line ranges for checking if a line is in a code block
+        let mut line_ranges = Vec::with_capacity(lines.len());
         let mut pos = 0;
-        for line in &lines {
-            line_positions.push(pos);
+        for line in content.lines() {
+            line_ranges.push((pos, pos + line.len()));
             pos += line.len() + 1; // +1 for newline
         }
 
@@ -89,6 +100,7 @@ impl InlineConfig {
         let mut capture_stack: Vec<(HashSet<String>, HashSet<String>)> = Vec::new();
 
         for (idx, line) in lines.iter().enumerate() {
+            let line: &str = line;
             let line_num = idx + 1; // 1-indexed
 
             // Store the current state for this line BEFORE processing comments
@@ -97,8 +109,7 @@ impl InlineConfig {
             config.enabled_at_line.insert(line_num, currently_enabled.clone());
 
             // Skip processing if this line is inside a code block
-            let line_start = line_positions[idx];
-            let line_end = line_start + line.len();
+            let (line_start, line_end) = line_ranges[idx];
             let in_code_block = code_blocks
                 .iter()
                 .any(|&(block_start, block_end)| line_start >= block_start && line_end <= block_end);
@@ -199,6 +210,18 @@ impl InlineConfig {
                 }
             }
 
+            // Check for a trailing same-line suppression (`text <!-- rumdl: MD013 -->`)
+            if let Some(rules) = parse_trailing_suppression_comment(line) {
+                let line_rules = config.line_disabled_rules.entry(line_num).or_default();
+                if rules.is_empty() {
+                    line_rules.insert("*".to_string());
+                } else {
+                    for rule in rules {
+                        line_rules.insert(normalize_rule_name(rule));
+                    }
+                }
+            }
+
             // Process state-changing comments in the order they appear
             // This handles multiple comments on the same line correctly
             let mut processed_capture = false;
@@ -348,6 +371,20 @@ impl InlineConfig {
         false
     }
 
+    /// Check if a rule is disabled at a specific line by a comment that names it
+    ///
+    /// Comments that disable all rules are not counted.
+    pub fn is_rule_disabled_by_name(&self, rule_name: &str, line_number: usize) -> bool {
+        let named = |rules: &HashSet<String>| !rules.contains("*") && rules.contains(rule_name);
+
+        named(&self.file_disabled_rules)
+            || self
+                .line_disabled_rules
+                .get(&line_number)
+                .is_some_and(|rules| rules.contains(rule_name))
+            || self.disabled_at_line.get(&line_number).is_some_and(named)
+    }
+
     /// Get all disabled rules at a specific line
     pub fn get_disabled_rules(&self, line_number: usize) -> HashSet<String> {
         let mut disabled = HashSet::new();
@@ -407,6 +444,47 @@ impl InlineConfig {
     }
 }
 
+/// Rewrite JSX comments as HTML comments so the directive parsers below handle both forms
+fn to_html_comments(line: &str, syntax: InlineCommentSyntax) -> Cow<'_, str> {
+    if syntax == InlineCommentSyntax::Jsx && line.contains("{/*") {
+        Cow::Owned(line.replace("{/*", "<!--").replace("*/}", "-->"))
+    } else {
+        Cow::Borrowed(line)
+    }
+}
+
+/// Split a directive's text into its rule list and the `reason: ...` that may follow it
+///
+/// `MD013 MD033 reason: generated table` gives `("MD013 MD033 ", Some("generated table"))`.
+/// The reason is `None` when it is missing or empty.
+pub fn split_reason(text: &str) -> (&str, Option<&str>) {
+    let lower = text.to_ascii_lowercase();
+    let marker = lower.match_indices("reason:").map(|(pos, _)| pos).find(|&pos| {
+        text[..pos]
+            .chars()
+            .next_back()
+            .is_none_or(|c| c.is_whitespace() || c == ',')
+    });
+
+    match marker {
+        Some(pos) => {
+            let reason = text[pos + "reason:".len()..].trim();
+            (&text[..pos], (!reason.is_empty()).then_some(reason))
+        }
+        None => (text, None),
+    }
+}
+
+/// Parse the rule list between a directive name and `-->` (empty vec means all rules)
+///
+/// A trailing `reason: ...` is not part of the list, so `<!-- rumdl-disable reason: vendored -->`
+/// still disables all rules.
+fn parse_rule_list(after_prefix: &str) -> Option<Vec<&str>> {
+    let end = after_prefix.find("-->")?;
+    let (rules, _reason) = split_reason(&after_prefix[..end]);
+    Some(rules.split_whitespace().collect())
+}
+
 /// Parse a disable comment and return the list of rules (empty vec means all rules)
 pub fn parse_disable_comment(line: &str) -> Option<Vec<&str>> {
     // Check for both rumdl-disable and markdownlint-disable
@@ -415,17 +493,9 @@ pub fn parse_disable_comment(line: &str) -> Option<Vec<&str>> {
             let after_prefix = &line[start + prefix.len()..];
 
             // Global disable: <!-- markdownlint-disable -->
-            if after_prefix.trim_start().starts_with("-->") {
-                return Some(Vec::new()); // Empty vec means all rules
-            }
-
             // Rule-specific disable: <!-- markdownlint-disable MD001 MD002 -->
-            if let Some(end) = after_prefix.find("-->") {
-                let rules_str = after_prefix[..end].trim();
-                if !rules_str.is_empty() {
-                    let rules: Vec<&str> = rules_str.split_whitespace().collect();
-                    return Some(rules);
-                }
+            if let Some(rules) = parse_rule_list(after_prefix) {
+                return Some(rules);
             }
         }
     }
@@ -441,17 +511,9 @@ pub fn parse_enable_comment(line: &str) -> Option<Vec<&str>> {
             let after_prefix = &line[start + prefix.len()..];
 
             // Global enable: <!-- markdownlint-enable -->
-            if after_prefix.trim_start().starts_with("-->") {
-                return Some(Vec::new()); // Empty vec means all rules
-            }
-
             // Rule-specific enable: <!-- markdownlint-enable MD001 MD002 -->
-            if let Some(end) = after_prefix.find("-->") {
-                let rules_str = after_prefix[..end].trim();
-                if !rules_str.is_empty() {
-                    let rules: Vec<&str> = rules_str.split_whitespace().collect();
-                    return Some(rules);
-                }
+            if let Some(rules) = parse_rule_list(after_prefix) {
+                return Some(rules);
             }
         }
     }
@@ -467,17 +529,9 @@ pub fn parse_disable_line_comment(line: &str) -> Option<Vec<&str>> {
             let after_prefix = &line[start + prefix.len()..];
 
             // Global disable-line: <!-- markdownlint-disable-line -->
-            if after_prefix.trim_start().starts_with("-->") {
-                return Some(Vec::new()); // Empty vec means all rules
-            }
-
             // Rule-specific disable-line: <!-- markdownlint-disable-line MD001 MD002 -->
-            if let Some(end) = after_prefix.find("-->") {
-                let rules_str = after_prefix[..end].trim();
-                if !rules_str.is_empty() {
-                    let rules: Vec<&str> = rules_str.split_whitespace().collect();
-                    return Some(rules);
-                }
+            if let Some(rules) = parse_rule_list(after_prefix) {
+                return Some(rules);
             }
         }
     }
@@ -493,24 +547,167 @@ pub fn parse_disable_next_line_comment(line: &str) -> Option<Vec<&str>> {
             let after_prefix = &line[start + prefix.len()..];
 
             // Global disable-next-line: <!-- markdownlint-disable-next-line -->
-            if after_prefix.trim_start().starts_with("-->") {
-                return Some(Vec::new()); // Empty vec means all rules
+            // Rule-specific disable-next-line: <!-- markdownlint-disable-next-line MD001 MD002 -->
+            if let Some(rules) = parse_rule_list(after_prefix) {
+                return Some(rules);
             }
+        }
+    }
 
-            // Rule-specific disable-next-line: <!-- markdownlint-disable-next-line MD001 MD002 -->
+    None
+}
+
+/// Parse a trailing same-line suppression comment: `text <!-- rumdl: MD013 MD033 -->`
+///
+/// Rules may be separated by spaces or commas; an empty list suppresses all rules.
+/// Comments inside inline code spans are ignored.
+pub fn parse_trailing_suppression_comment(line: &str) -> Option<Vec<&str>> {
+    const PREFIX: &str = "<!-- rumdl:";
+
+    let mut search_from = 0;
+    while let Some(offset) = line[search_from..].find(PREFIX) {
+        let start = search_from + offset;
+        if !is_in_inline_code(line, start) {
+            let after_prefix = &line[start + PREFIX.len()..];
             if let Some(end) = after_prefix.find("-->") {
-                let rules_str = after_prefix[..end].trim();
-                if !rules_str.is_empty() {
-                    let rules: Vec<&str> = rules_str.split_whitespace().collect();
-                    return Some(rules);
-                }
+                let (rules, _reason) = split_reason(&after_prefix[..end]);
+                let rules = rules
+                    .split(|c: char| c.is_whitespace() || c == ',')
+                    .filter(|rule| !rule.is_empty())
+                    .collect();
+                return Some(rules);
             }
         }
+        search_from = start + PREFIX.len();
     }
 
     None
 }
 
+/// Directive names that turn rules off
+const DISABLE_DIRECTIVES: &[&str] = &[
+    "rumdl-disable",
+    "rumdl-disable-line",
+    "rumdl-disable-next-line",
+    "rumdl-disable-file",
+    "markdownlint-disable",
+    "markdownlint-disable-line",
+    "markdownlint-disable-next-line",
+    "markdownlint-disable-file",
+];
+
+/// A comment that turns rules off, as written in a line
+#[derive(Debug, Clone, PartialEq)]
+pub struct DisableDirective<'a> {
+    /// Byte offset of the comment opener (`<!--` or `{/*`)
+    pub start: usize,
+    /// Byte offset just past the comment closer
+    pub end: usize,
+    /// Directive name, e.g. `rumdl-disable-next-line`, or `rumdl:` for a trailing suppression
+    pub name: &'a str,
+    /// Rules as written (empty means all rules)
+    pub rules: Vec<&'a str>,
+    pub reason: Option<&'a str>,
+}
+
+/// Find the disable, disable-line, disable-next-line, disable-file and trailing `rumdl:`
+/// comments in a line, in order
+pub fn find_disable_directives(line: &str, syntax: InlineCommentSyntax) -> Vec<DisableDirective<'_>> {
+    let mut delimiters = vec![("<!-- ", "-->")];
+    if syntax == InlineCommentSyntax::Jsx {
+        delimiters.push(("{/* ", "*/}"));
+    }
+
+    let mut directives = Vec::new();
+    for (open, close) in delimiters {
+        let mut search_from = 0;
+        while let Some(offset) = line[search_from..].find(open) {
+            let start = search_from + offset;
+            let body_start = start + open.len();
+            search_from = body_start;
+            let Some(body_len) = line[body_start..].find(close) else {
+                break;
+            };
+            let body = &line[body_start..body_start + body_len];
+            let end = body_start + body_len + close.len();
+
+            let (name, rest) = if let Some(rest) = body.strip_prefix("rumdl:") {
+                if is_in_inline_code(line, start) {
+                    continue;
+                }
+                ("rumdl:", rest)
+            } else {
+                let name_len = body.find(char::is_whitespace).unwrap_or(body.len());
+                if !DISABLE_DIRECTIVES.contains(&&body[..name_len]) {
+                    continue;
+                }
+                (&body[..name_len], &body[name_len..])
+            };
+
+            let (rules, reason) = split_reason(rest);
+            directives.push(DisableDirective {
+                start,
+                end,
+                name,
+                rules: rules
+                    .split(|c: char| c.is_whitespace() || c == ',')
+                    .filter(|rule| !rule.is_empty())
+                    .collect(),
+                reason,
+            });
+        }
+    }
+
+    directives.sort_by_key(|directive| directive.start);
+    directives
+}
+
+/// Check whether a byte position in a line falls inside an inline code span
+fn is_in_inline_code(line: &str, pos: usize) -> bool {
+    let bytes = line.as_bytes();
+    let run_end = |mut i: usize| {
+        while i < bytes.len() && bytes[i] == b'`' {
+            i += 1;
+        }
+        i
+    };
+
+    let mut i = 0;
+    while i < pos {
+        if bytes[i] != b'`' {
+            i += 1;
+            continue;
+        }
+
+        // A code span is closed by a backtick run of the same length
+        let open_end = run_end(i);
+        let open_len = open_end - i;
+        let mut j = open_end;
+        let mut close_end = None;
+        while j < bytes.len() {
+            if bytes[j] == b'`' {
+                let end = run_end(j);
+                if end - j == open_len {
+                    close_end = Some(end);
+                    break;
+                }
+                j = end;
+            } else {
+                j += 1;
+            }
+        }
+
+        match close_end {
+            Some(end) if pos < end => return true,
+            Some(end) => i = end,
+            // An unmatched backtick run is literal text
+            None => i = open_end,
+        }
+    }
+
+    false
+}
+
 /// Check if line contains a capture comment
 pub fn is_capture_comment(line: &str) -> bool {
     line.contains("<!-- markdownlint-capture -->") || line.contains("<!-- rumdl-capture -->")
@@ -529,17 +726,9 @@ pub fn parse_disable_file_comment(line: &str) -> Option<Vec<&str>> {
             let after_prefix = &line[start + prefix.len()..];
 
             // Global disable-file: <!-- markdownlint-disable-file -->
-            if after_prefix.trim_start().starts_with("-->") {
-                return Some(Vec::new()); // Empty vec means all rules
-            }
-
             // Rule-specific disable-file: <!-- markdownlint-disable-file MD001 MD002 -->
-            if let Some(end) = after_prefix.find("-->") {
-                let rules_str = after_prefix[..end].trim();
-                if !rules_str.is_empty() {
-                    let rules: Vec<&str> = rules_str.split_whitespace().collect();
-                    return Some(rules);
-                }
+            if let Some(rules) = parse_rule_list(after_prefix) {
+                return Some(rules);
             }
         }
     }
@@ -555,17 +744,9 @@ pub fn parse_enable_file_comment(line: &str) -> Option<Vec<&str>> {
             let after_prefix = &line[start + prefix.len()..];
 
             // Global enable-file: <!-- markdownlint-enable-file -->
-            if after_prefix.trim_start().starts_with("-->") {
-                return Some(Vec::new()); // Empty vec means all rules
-            }
-
             // Rule-specific enable-file: <!-- markdownlint-enable-file MD001 MD002 -->
-            if let Some(end) = after_prefix.find("-->") {
-                let rules_str = after_prefix[..end].trim();
-                if !rules_str.is_empty() {
-                    let rules: Vec<&str> = rules_str.split_whitespace().collect();
-                    return Some(rules);
-                }
+            if let Some(rules) = parse_rule_list(after_prefix) {
+                return Some(rules);
             }
         }
     }
@@ -634,6 +815,113 @@ mod tests {
         assert_eq!(parse_disable_line_comment("Some regular text"), None);
     }
 
+    #[test]
+    fn test_parse_trailing_suppression_comment() {
+        assert_eq!(
+            parse_trailing_suppression_comment("Long line <!-- rumdl: MD013 -->"),
+            Some(vec!["MD013"])
+        );
+        assert_eq!(
+            parse_trailing_suppression_comment("<b>x</b> <!-- rumdl:MD013, MD033 -->"),
+            Some(vec!["MD013", "MD033"])
+        );
+        assert_eq!(parse_trailing_suppression_comment("Text <!-- rumdl: -->"), Some(vec![]));
+        assert_eq!(
+            parse_trailing_suppression_comment("Text <!-- rumdl-disable-line MD013 -->"),
+            None
+        );
+        assert_eq!(parse_trailing_suppression_comment("Text <!-- rumdl: MD013"), None);
+
+        // Comments inside code spans are not suppressions
+        assert_eq!(
+            parse_trailing_suppression_comment("Use `<!-- rumdl: MD013 -->` here"),
+            None
+        );
+        assert_eq!(
+            parse_trailing_suppression_comment("Use ``a ` <!-- rumdl: MD013 -->`` here"),
+            None
+        );
+        assert_eq!(
+            parse_trailing_suppression_comment("`code` text <!-- rumdl: MD013 -->"),
+            Some(vec!["MD013"])
+        );
+        // An unmatched backtick does not start a code span
+        assert_eq!(
+            parse_trailing_suppression_comment("a ` b <!-- rumdl: MD013 -->"),
+            Some(vec!["MD013"])
+        );
+    }
+
+    #[test]
+    fn test_directive_reasons() {
+        assert_eq!(
+            split_reason("MD013 reason: generated table"),
+            ("MD013 ", Some("generated table"))
+        );
+        assert_eq!(split_reason(" Reason: vendored"), (" ", Some("vendored")));
+        assert_eq!(split_reason("MD013 reason:"), ("MD013 ", None));
+        assert_eq!(split_reason("MD013 treason:"), ("MD013 treason:", None));
+
+        assert_eq!(
+            parse_disable_comment("<!-- rumdl-disable MD013 MD033 reason: generated table -->"),
+            Some(vec!["MD013", "MD033"])
+        );
+        assert_eq!(
+            parse_disable_comment("<!-- rumdl-disable reason: vendored -->"),
+            Some(vec![])
+        );
+        assert_eq!(
+            parse_trailing_suppression_comment("Text <!-- rumdl: MD013, MD033 reason: long URL -->"),
+            Some(vec!["MD013", "MD033"])
+        );
+
+        let config = InlineConfig::from_content(
+            "<!-- rumdl-disable MD013 reason: generated -->
+Text
+",
+        );
+        assert!(config.is_rule_disabled("MD013", 2));
+        assert!(!config.is_rule_disabled("MD033", 2));
+    }
+
+    #[test]
+    fn test_find_disable_directives() {
+        let line = "Text <!-- rumdl-disable-line MD013 reason: URL --> <!-- rumdl-enable --> <!-- rumdl: MD033 -->";
+        let directives = find_disable_directives(line, InlineCommentSyntax::Html);
+        assert_eq!(directives.len(), 2);
+        assert_eq!(directives[0].name, "rumdl-disable-line");
+        assert_eq!(directives[0].rules, vec!["MD013"]);
+        assert_eq!(directives[0].reason, Some("URL"));
+        assert_eq!(
+            &line[directives[0].start..directives[0].end],
+            "<!-- rumdl-disable-line MD013 reason: URL -->"
+        );
+        assert_eq!(directives[1].name, "rumdl:");
+        assert_eq!(directives[1].reason, None);
+
+        // JSX comments only count with the JSX syntax
+        let line = "{/* markdownlint-disable */}";
+        assert!(find_disable_directives(line, InlineCommentSyntax::Html).is_empty());
+        assert_eq!(
+            find_disable_directives(line, InlineCommentSyntax::Jsx)[0].rules,
+            Vec::<&str>::new()
+        );
+
+        assert!(find_disable_directives("Use `<!-- rumdl: MD013 -->` here", InlineCommentSyntax::Html).is_empty());
+    }
+
+    #[test]
+    fn test_trailing_suppression_applies_to_its_line_only() {
+        let content = "Long line <!-- rumdl: MD013 line-length -->\nAnother long line\n";
+        let config = InlineConfig::from_content(content);
+        assert!(config.is_rule_disabled("MD013", 1));
+        assert!(!config.is_rule_disabled("MD033", 1));
+        assert!(!config.is_rule_disabled("MD013", 2));
+
+        let config = InlineConfig::from_content("Text <!-- rumdl: -->\n");
+        assert!(config.is_rule_disabled("MD033", 1));
+    }
+
     #[test]
     fn test_inline_config_from_content() {
         let content = r#"# Test Document
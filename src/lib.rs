@@ -1,7 +1,9 @@
 pub mod config;
+pub mod disabled_contexts;
 pub mod exit_codes;
 pub mod filtered_lines;
 pub mod fix_coordinator;
+pub mod flavor_overrides;
 pub mod inline_config;
 pub mod lint_context;
 pub mod markdownlint_config;
@@ -20,6 +22,8 @@ pub mod utils;
 
 // Native-only modules (require tokio, tower-lsp, etc.)
 #[cfg(feature = "native")]
+pub mod link_checker;
+#[cfg(feature = "native")]
 pub mod lsp;
 #[cfg(feature = "native")]
 pub mod output;
@@ -204,11 +208,144 @@ pub fn build_file_index_only(
 ///
 /// Returns: (warnings, FileIndex) - the FileIndex contains headings/links for cross-file rules
 pub fn lint_and_index(
+    content: &str,
+    rules: &[Box<dyn Rule>],
+    verbose: bool,
+    flavor: crate::config::MarkdownFlavor,
+    source_file: Option<std::path::PathBuf>,
+) -> (LintResult, crate::workspace_index::FileIndex) {
+    let disabled_contexts = crate::disabled_contexts::DisabledContexts::default();
+    let flavor_overrides = crate::flavor_overrides::FlavorOverrides::default();
+    lint_and_index_impl(
+        content,
+        rules,
+        verbose,
+        flavor,
+        source_file,
+        &disabled_contexts,
+        &flavor_overrides,
+        None,
+    )
+}
+
+/// Lint a file with config-driven warning filtering and contribute to the workspace index
+///
+/// Same as [`lint_and_index`], but also drops warnings for rules disabled in specific
+/// contexts via their `disable-in` option, picks the rules to run for `flavor` using
+/// their `enabled-for-flavors` option, and reads inline configuration comments in the
+/// configured `inline-comment-syntax`.
+pub fn lint_and_index_with_config(
+    content: &str,
+    rules: &[Box<dyn Rule>],
+    verbose: bool,
+    config: &crate::config::Config,
+    flavor: crate::config::MarkdownFlavor,
+    source_file: Option<std::path::PathBuf>,
+) -> (LintResult, crate::workspace_index::FileIndex) {
+    let disabled_contexts = crate::disabled_contexts::DisabledContexts::from_config(config);
+    let flavor_overrides = crate::flavor_overrides::FlavorOverrides::from_config(config);
+    lint_and_index_impl(
+        content,
+        rules,
+        verbose,
+        flavor,
+        source_file,
+        &disabled_contexts,
+        &flavor_overrides,
+        config.global.inline_comment_syntax,
+    )
+}
+
+/// Lint a file with config-driven warning filtering (see [`lint_and_index_with_config`])
+pub fn lint_with_config(
+    content: &str,
+    rules: &[Box<dyn Rule>],
+    verbose: bool,
+    config: &crate::config::Config,
+    flavor: crate::config::MarkdownFlavor,
+) -> LintResult {
+    let (result, _file_index) = lint_and_index_with_config(content, rules, verbose, config, flavor, None);
+    result
+}
+
+/// Format a document by applying every available fix, like `rumdl fmt`
+///
+/// Returns only the rewritten content, with the original line endings preserved.
+/// Unfixable issues are left in place; lint the result to find them.
+pub fn format_content(
+    content: &str,
+    rules: &[Box<dyn Rule>],
+    config: &crate::config::Config,
+    flavor: crate::config::MarkdownFlavor,
+) -> Result<String, crate::rule::LintError> {
+    let original_line_ending = crate::utils::detect_line_ending_enum(content);
+    let mut fixed = crate::utils::normalize_line_ending(content, crate::utils::LineEnding::Lf);
+
+    let warnings = lint_with_config(&fixed, rules, false, config, flavor)?;
+    if warnings.is_empty() {
+        return Ok(content.to_string());
+    }
+
+    crate::fix_coordinator::FixCoordinator::new()
+        .apply_fixes_iterative(rules, &warnings, &mut fixed, config, 100)
+        .map_err(crate::rule::LintError::FixFailed)?;
+
+    Ok(crate::utils::normalize_line_ending(&fixed, original_line_ending))
+}
+
+/// Format a document and report the warnings that remain, in one call
+///
+/// Returns `(fixed_content, remaining_warnings)`. The fixed content is what
+/// [`format_content`] would produce, and the warnings come from a final check of it,
+/// so their positions refer to the fixed content. Uses the default configuration
+/// for the given flavor; see [`lint_fix_and_report_with_config`] to pass one.
+pub fn lint_fix_and_report(
+    content: &str,
+    rules: &[Box<dyn Rule>],
+    flavor: crate::config::MarkdownFlavor,
+) -> Result<(String, Vec<crate::rule::LintWarning>), crate::rule::LintError> {
+    let mut config = crate::config::Config::default();
+    config.global.flavor = flavor;
+    lint_fix_and_report_with_config(content, rules, &config, flavor)
+}
+
+/// Format a document and report the remaining warnings (see [`lint_fix_and_report`])
+pub fn lint_fix_and_report_with_config(
+    content: &str,
+    rules: &[Box<dyn Rule>],
+    config: &crate::config::Config,
+    flavor: crate::config::MarkdownFlavor,
+) -> Result<(String, Vec<crate::rule::LintWarning>), crate::rule::LintError> {
+    let original_line_ending = crate::utils::detect_line_ending_enum(content);
+    let mut fixed = crate::utils::normalize_line_ending(content, crate::utils::LineEnding::Lf);
+
+    let warnings = lint_with_config(&fixed, rules, false, config, flavor)?;
+    // Nothing is fixable, so the first check already is the final one
+    if !warnings.iter().any(|w| w.fix.is_some()) {
+        return Ok((content.to_string(), warnings));
+    }
+
+    crate::fix_coordinator::FixCoordinator::new()
+        .apply_fixes_iterative(rules, &warnings, &mut fixed, config, 100)
+        .map_err(crate::rule::LintError::FixFailed)?;
+
+    let remaining = lint_with_config(&fixed, rules, false, config, flavor)?;
+    Ok((
+        crate::utils::normalize_line_ending(&fixed, original_line_ending),
+        remaining,
+    ))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn lint_and_index_impl(
     content: &str,
     rules: &[Box<dyn Rule>],
     _verbose: bool,
     flavor: crate::config::MarkdownFlavor,
     source_file: Option<std::path::PathBuf>,
+    disabled_contexts: &crate::disabled_contexts::DisabledContexts,
+    flavor_overrides: &crate::flavor_overrides::FlavorOverrides,
+    comment_syntax: Option<crate::config::InlineCommentSyntax>,
 ) -> (LintResult, crate::workspace_index::FileIndex) {
     let mut warnings = Vec::new();
     // Compute content hash for change detection
@@ -224,7 +361,8 @@ pub fn lint_and_index(
     }
 
     // Parse inline configuration comments once
-    let inline_config = crate::inline_config::InlineConfig::from_content(content);
+    let comment_syntax = comment_syntax.unwrap_or_else(|| crate::config::InlineCommentSyntax::for_flavor(flavor));
+    let inline_config = crate::inline_config::InlineConfig::from_content_with_syntax(content, comment_syntax);
 
     // Export inline config data to FileIndex for cross-file rule filtering
     let (file_disabled, line_disabled) = inline_config.export_for_file_index();
@@ -234,9 +372,10 @@ pub fn lint_and_index(
     // Analyze content characteristics for rule filtering
     let characteristics = ContentCharacteristics::analyze(content);
 
-    // Filter rules based on content characteristics
+    // Filter rules based on flavor and content characteristics
     let applicable_rules: Vec<_> = rules
         .iter()
+        .filter(|rule| flavor_overrides.applies(rule.as_ref(), flavor))
         .filter(|rule| !characteristics.should_skip_rule(rule.as_ref()))
         .collect();
 
@@ -275,10 +414,12 @@ pub fn lint_and_index(
                             rule_name_to_check
                         };
 
-                        !inline_config.is_rule_disabled(
-                            base_rule_name,
-                            warning.line, // Already 1-indexed
-                        )
+                        let disabled_inline = if rule.honors_blanket_disable() {
+                            inline_config.is_rule_disabled(base_rule_name, warning.line) // Already 1-indexed
+                        } else {
+                            inline_config.is_rule_disabled_by_name(base_rule_name, warning.line)
+                        };
+                        !disabled_inline && !disabled_contexts.is_disabled(base_rule_name, &lint_ctx, warning.line)
                     })
                     .collect();
                 warnings.extend(filtered_warnings);
@@ -373,6 +514,62 @@ pub fn run_cross_file_checks(
     Ok(warnings)
 }
 
+/// Lint a set of in-memory documents, including cross-file checks between them
+///
+/// Each document is identified by a virtual path, which is used like a real file's path:
+/// to resolve relative links, to apply `per-file-ignores`, and to pick the flavor from
+/// the extension when the config doesn't set one. The workspace index is built from these
+/// documents alone, so links between them are validated without reading them from disk.
+///
+/// Per-file checks run without a source file, so checks that need the filesystem (such
+/// as MD057's check of links to images and other assets) are skipped. MD057 still looks
+/// on disk for Markdown link targets that are not among the documents.
+///
+/// Paths should be unique. Returns each document's path with its warnings (per-file and
+/// cross-file), in input order.
+pub fn lint_documents<P: AsRef<std::path::Path>, C: AsRef<str>>(
+    documents: &[(P, C)],
+    rules: &[Box<dyn Rule>],
+    config: &crate::config::Config,
+) -> Vec<(std::path::PathBuf, LintResult)> {
+    let mut workspace_index = crate::workspace_index::WorkspaceIndex::new();
+    let mut linted = Vec::with_capacity(documents.len());
+
+    for (path, content) in documents {
+        let path = path.as_ref().to_path_buf();
+        let ignored_rules = config.get_ignored_rules_for_file(&path);
+        let doc_rules: Vec<Box<dyn Rule>> = rules
+            .iter()
+            .filter(|rule| !ignored_rules.contains(rule.name()))
+            .cloned()
+            .collect();
+        let flavor = if config.markdown_flavor() == crate::config::MarkdownFlavor::Standard {
+            crate::config::MarkdownFlavor::from_path(&path)
+        } else {
+            config.markdown_flavor()
+        };
+
+        let (result, file_index) =
+            lint_and_index_with_config(content.as_ref(), &doc_rules, false, config, flavor, None);
+        workspace_index.update_file(&path, file_index);
+        linted.push((path, doc_rules, result));
+    }
+
+    linted
+        .into_iter()
+        .map(|(path, doc_rules, result)| {
+            let result = result.and_then(|mut warnings| {
+                if let Some(file_index) = workspace_index.get_file(&path) {
+                    warnings.extend(run_cross_file_checks(&path, file_index, &doc_rules, &workspace_index)?);
+                    warnings.sort_by_key(|w| (w.line, w.column));
+                }
+                Ok(warnings)
+            });
+            (path, result)
+        })
+        .collect()
+}
+
 /// Get the profiling report
 pub fn get_profiling_report() -> String {
     profiling::get_report()
@@ -598,4 +795,89 @@ mod tests {
         let chars = ContentCharacteristics::analyze("text > not a quote");
         assert!(!chars.has_blockquotes);
     }
+
+    #[test]
+    fn test_format_content() {
+        let config = crate::config::Config::default();
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(MD009TrailingSpaces::default())];
+        let flavor = crate::config::MarkdownFlavor::Standard;
+
+        let formatted = format_content("# Title\n\nText   \n", &rules, &config, flavor).unwrap();
+        assert_eq!(formatted, "# Title\n\nText\n");
+
+        // Line endings are preserved
+        let formatted = format_content("# Title\r\n\r\nText   \r\n", &rules, &config, flavor).unwrap();
+        assert_eq!(formatted, "# Title\r\n\r\nText\r\n");
+
+        // Clean content is returned unchanged
+        let formatted = format_content("# Title\r\n", &rules, &config, flavor).unwrap();
+        assert_eq!(formatted, "# Title\r\n");
+    }
+
+    #[test]
+    fn test_lint_fix_and_report() {
+        let rules: Vec<Box<dyn Rule>> = vec![
+            Box::new(MD009TrailingSpaces::default()),
+            Box::new(MD024NoDuplicateHeading::default()),
+        ];
+        let flavor = crate::config::MarkdownFlavor::Standard;
+
+        // Only the trailing spaces are fixable; the duplicate heading is reported
+        let (fixed, remaining) =
+            lint_fix_and_report("# Title\r\n\r\nText   \r\n\r\n# Title\r\n", &rules, flavor).unwrap();
+        assert_eq!(fixed, "# Title\r\n\r\nText\r\n\r\n# Title\r\n");
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].rule_name.as_deref(), Some("MD024"));
+        assert_eq!(remaining[0].line, 5);
+
+        let (fixed, remaining) = lint_fix_and_report("# Title\n", &rules, flavor).unwrap();
+        assert_eq!(fixed, "# Title\n");
+        assert!(remaining.is_empty());
+    }
+
+    #[test]
+    fn test_lint_skips_rules_for_other_flavors() {
+        /// Flags every document, but only applies to MDX
+        #[derive(Clone)]
+        struct MdxOnlyRule;
+
+        impl Rule for MdxOnlyRule {
+            fn name(&self) -> &'static str {
+                "MDX-ONLY"
+            }
+            fn description(&self) -> &'static str {
+                "Test rule that only applies to MDX"
+            }
+            fn applies_to_flavor(&self, flavor: crate::config::MarkdownFlavor) -> bool {
+                flavor == crate::config::MarkdownFlavor::MDX
+            }
+            fn check(&self, _ctx: &crate::lint_context::LintContext) -> crate::rule::LintResult {
+                Ok(vec![crate::rule::LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line: 1,
+                    column: 1,
+                    end_line: 1,
+                    end_column: 1,
+                    message: "flagged".to_string(),
+                    severity: crate::rule::Severity::Warning,
+                    fix: None,
+                }])
+            }
+            fn fix(&self, ctx: &crate::lint_context::LintContext) -> Result<String, crate::rule::LintError> {
+                Ok(ctx.content.to_string())
+            }
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+        }
+
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(MdxOnlyRule)];
+        let content = "# Title\n";
+
+        let warnings = lint(content, &rules, false, crate::config::MarkdownFlavor::Standard).unwrap();
+        assert!(warnings.is_empty());
+
+        let warnings = lint(content, &rules, false, crate::config::MarkdownFlavor::MDX).unwrap();
+        assert_eq!(warnings.len(), 1);
+    }
 }
@@ -0,0 +1,411 @@
+//! Reachability checks for external links, used by MD141
+//!
+//! Requests are made with `curl`, which handles TLS and follows redirects, so link
+//! shorteners are judged by the page they point to. Checking only happens after the CLI
+//! turns it on for `--check-links-online`; the LSP server never does, and the module
+//! doesn't exist in the WASM build.
+//!
+//! Links found reachable are cached in `external_links.json` in the cache directory and
+//! reused until they are older than the configured TTL. Unreachable links are never
+//! cached, so a link that was down last night is requested again.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Condvar, Mutex, MutexGuard, OnceLock};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+const CACHE_FILE_NAME: &str = "external_links.json";
+
+const USER_AGENT: &str = concat!("rumdl/", env!("CARGO_PKG_VERSION"), " (link check)");
+
+#[cfg(windows)]
+const NULL_DEVICE: &str = "NUL";
+#[cfg(not(windows))]
+const NULL_DEVICE: &str = "/dev/null";
+
+static CHECKER: OnceLock<LinkChecker> = OnceLock::new();
+
+/// Turn on online link checking for this process
+///
+/// Results are cached in `cache_dir` when one is given. Fails if `curl` can't be run.
+pub fn enable(cache_dir: Option<&Path>) -> std::io::Result<()> {
+    Command::new("curl").arg("--version").output()?;
+    CHECKER.get_or_init(|| LinkChecker::new(cache_dir));
+    Ok(())
+}
+
+/// The process-wide checker, if online checking was turned on
+pub fn active() -> Option<&'static LinkChecker> {
+    CHECKER.get()
+}
+
+/// Write the process-wide checker's cache, if checking was turned on
+pub fn save_cache() {
+    if let Some(checker) = active()
+        && let Err(e) = checker.save()
+    {
+        log::warn!("Failed to save external link cache: {e}");
+    }
+}
+
+/// Limits for checking external links
+#[derive(Debug, Clone)]
+pub struct LinkCheckOptions {
+    /// Time allowed for each request, including redirects
+    pub timeout: Duration,
+    /// Maximum number of requests in flight at once, across all files
+    pub concurrency: usize,
+    /// Minimum time between requests to the same host
+    pub host_delay: Duration,
+    /// How long a reachable result is reused from the cache
+    pub cache_ttl: Duration,
+}
+
+/// Outcome of checking one URL
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkStatus {
+    Reachable,
+    /// The server returned an error status or couldn't be reached; holds the reason
+    Unreachable(String),
+    /// The server asked us to slow down, so the link's state is unknown
+    RateLimited,
+}
+
+/// Checks external URLs, at most once per URL per run
+pub struct LinkChecker {
+    cache_path: Option<PathBuf>,
+    /// Unix time each URL was last found reachable; loaded from and saved to the cache
+    reachable_at: Mutex<HashMap<String, u64>>,
+    /// TTL of the most recent check, used to prune the cache when saving
+    cache_ttl_secs: AtomicU64,
+    /// Results from this run, so URLs shared by several files are requested once
+    results: Mutex<HashMap<String, LinkStatus>>,
+    /// Earliest time the next request to each host may start
+    next_request_at: Mutex<HashMap<String, Instant>>,
+    in_flight: Mutex<usize>,
+    request_finished: Condvar,
+}
+
+/// A slot among the requests allowed in flight, released on drop
+struct RequestSlot<'a>(&'a LinkChecker);
+
+impl Drop for RequestSlot<'_> {
+    fn drop(&mut self) {
+        *lock(&self.0.in_flight) -= 1;
+        self.0.request_finished.notify_one();
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().expect("Link checker mutex poisoned")
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+impl LinkChecker {
+    /// Create a checker, loading cached results from `cache_dir` if given
+    pub fn new(cache_dir: Option<&Path>) -> Self {
+        let cache_path = cache_dir.map(|dir| dir.join(CACHE_FILE_NAME));
+        let reachable_at = cache_path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+        Self {
+            cache_path,
+            reachable_at: Mutex::new(reachable_at),
+            cache_ttl_secs: AtomicU64::new(u64::MAX),
+            results: Mutex::new(HashMap::new()),
+            next_request_at: Mutex::new(HashMap::new()),
+            in_flight: Mutex::new(0),
+            request_finished: Condvar::new(),
+        }
+    }
+
+    /// Check each URL, requesting only those not already known from this run or the cache
+    pub fn check(&self, urls: &[&str], options: &LinkCheckOptions) -> HashMap<String, LinkStatus> {
+        let now = unix_now();
+        let ttl = options.cache_ttl.as_secs();
+        self.cache_ttl_secs.store(ttl, Ordering::Relaxed);
+
+        let mut statuses = HashMap::new();
+        let mut pending = Vec::new();
+        {
+            let results = lock(&self.results);
+            let reachable_at = lock(&self.reachable_at);
+            for &url in urls {
+                if statuses.contains_key(url) || pending.contains(&url) {
+                    continue;
+                }
+                if let Some(status) = results.get(url) {
+                    statuses.insert(url.to_string(), status.clone());
+                } else if reachable_at.get(url).is_some_and(|&at| now.saturating_sub(at) < ttl) {
+                    statuses.insert(url.to_string(), LinkStatus::Reachable);
+                } else {
+                    pending.push(url);
+                }
+            }
+        }
+
+        let workers = options.concurrency.clamp(1, pending.len().max(1));
+        let queue = Mutex::new(pending.into_iter());
+        let fresh: Vec<(String, LinkStatus)> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..workers)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut checked = Vec::new();
+                        loop {
+                            // Take the next URL without holding the queue lock during the request
+                            let next = lock(&queue).next();
+                            let Some(url) = next else {
+                                break checked;
+                            };
+                            checked.push((url.to_string(), self.status_of(url, options)));
+                        }
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().expect("Link check worker panicked"))
+                .collect()
+        });
+
+        let mut results = lock(&self.results);
+        let mut reachable_at = lock(&self.reachable_at);
+        for (url, status) in fresh {
+            if status == LinkStatus::Reachable {
+                reachable_at.insert(url.clone(), now);
+            }
+            results.insert(url.clone(), status.clone());
+            statuses.insert(url, status);
+        }
+        statuses
+    }
+
+    /// Write reachable results to the cache file, dropping entries older than the TTL
+    pub fn save(&self) -> std::io::Result<()> {
+        let Some(path) = &self.cache_path else {
+            return Ok(());
+        };
+        let now = unix_now();
+        let ttl = self.cache_ttl_secs.load(Ordering::Relaxed);
+        let mut reachable_at = lock(&self.reachable_at).clone();
+        reachable_at.retain(|_, at| now.saturating_sub(*at) < ttl);
+        let json = serde_json::to_string(&reachable_at)?;
+
+        // Write atomically: write to temp file then rename
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let temp_path = path.with_extension(format!("json.tmp.{}", std::process::id()));
+        fs::write(&temp_path, json)?;
+        fs::rename(&temp_path, path)
+    }
+
+    /// Request a URL, retrying once if the failure may be transient
+    fn status_of(&self, url: &str, options: &LinkCheckOptions) -> LinkStatus {
+        let mut result = self.probe(url, options);
+        if matches!(result, Err(_) | Ok(408 | 500..)) {
+            result = self.probe(url, options);
+        }
+        match result {
+            Ok(429) => LinkStatus::RateLimited,
+            Ok(code) if code < 400 => LinkStatus::Reachable,
+            Ok(code) => LinkStatus::Unreachable(format!("HTTP {code}")),
+            Err(reason) => LinkStatus::Unreachable(reason),
+        }
+    }
+
+    /// Send a HEAD request, falling back to GET for servers (including some link
+    /// shorteners) that don't support HEAD
+    fn probe(&self, url: &str, options: &LinkCheckOptions) -> Result<u16, String> {
+        match self.request(url, true, options)? {
+            405 | 501 => self.request(url, false, options),
+            code => Ok(code),
+        }
+    }
+
+    /// Run curl once and return the final HTTP status after redirects
+    fn request(&self, url: &str, head: bool, options: &LinkCheckOptions) -> Result<u16, String> {
+        self.wait_for_host(url, options.host_delay);
+        let _slot = self.acquire_slot(options.concurrency);
+
+        let mut command = Command::new("curl");
+        command
+            .args(["--silent", "--show-error", "--location", "--max-redirs", "10"])
+            .args(["--output", NULL_DEVICE, "--write-out", "%{http_code}"])
+            .args(["--user-agent", USER_AGENT])
+            .arg("--max-time")
+            .arg(options.timeout.as_secs_f64().to_string());
+        if head {
+            command.arg("--head");
+        }
+        let output = command
+            .arg("--url")
+            .arg(url)
+            .output()
+            .map_err(|e| format!("failed to run curl: {e}"))?;
+
+        let code = String::from_utf8_lossy(&output.stdout).trim().parse().unwrap_or(0);
+        if output.status.success() && code != 0 {
+            return Ok(code);
+        }
+        // curl reports errors as `curl: (6) Could not resolve host: ...`
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let reason = stderr.lines().next().unwrap_or_default();
+        let reason = reason.split_once(") ").map_or(reason, |(_, message)| message).trim();
+        Err(if reason.is_empty() {
+            "request failed".to_string()
+        } else {
+            reason.to_string()
+        })
+    }
+
+    /// Sleep until the host's next request slot, and reserve the one after it
+    fn wait_for_host(&self, url: &str, delay: Duration) {
+        let host = url::Url::parse(url)
+            .ok()
+            .and_then(|parsed| parsed.host_str().map(str::to_string))
+            .unwrap_or_default();
+        let wait = {
+            let mut next_request_at = lock(&self.next_request_at);
+            let now = Instant::now();
+            let slot = next_request_at
+                .get(&host)
+                .copied()
+                .filter(|&at| at > now)
+                .unwrap_or(now);
+            next_request_at.insert(host, slot + delay);
+            slot - now
+        };
+        std::thread::sleep(wait);
+    }
+
+    fn acquire_slot(&self, limit: usize) -> RequestSlot<'_> {
+        let mut in_flight = lock(&self.in_flight);
+        while *in_flight >= limit.max(1) {
+            in_flight = self
+                .request_finished
+                .wait(in_flight)
+                .expect("Link checker mutex poisoned");
+        }
+        *in_flight += 1;
+        RequestSlot(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicUsize;
+
+    /// Serve canned responses on localhost, counting requests
+    fn serve() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&requests);
+        std::thread::spawn(move || {
+            let mut flaky_failed = false;
+            for stream in listener.incoming().flatten() {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let mut reader = BufReader::new(&stream);
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut header = String::new();
+                while reader.read_line(&mut header).is_ok_and(|n| n > 2) {
+                    header.clear();
+                }
+
+                let mut parts = request_line.split(' ');
+                let (method, path) = (parts.next().unwrap_or_default(), parts.next().unwrap_or_default());
+                let status = match path {
+                    "/ok" => "200 OK",
+                    "/moved" => "301 Moved Permanently\r\nLocation: /ok",
+                    "/no-head" if method == "HEAD" => "405 Method Not Allowed",
+                    "/no-head" => "200 OK",
+                    "/flaky" if !flaky_failed => {
+                        flaky_failed = true;
+                        "503 Service Unavailable"
+                    }
+                    "/flaky" => "200 OK",
+                    _ => "404 Not Found",
+                };
+                let response = format!("HTTP/1.1 {status}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n");
+                let _ = (&stream).write_all(response.as_bytes());
+            }
+        });
+        (base, requests)
+    }
+
+    fn options() -> LinkCheckOptions {
+        LinkCheckOptions {
+            timeout: Duration::from_secs(5),
+            concurrency: 4,
+            host_delay: Duration::ZERO,
+            cache_ttl: Duration::from_secs(3600),
+        }
+    }
+
+    #[test]
+    fn test_statuses() {
+        let (base, _) = serve();
+        let closed = {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            format!("http://{}/", listener.local_addr().unwrap())
+        };
+        let urls: Vec<String> = ["/ok", "/moved", "/no-head", "/flaky", "/missing"]
+            .iter()
+            .map(|path| format!("{base}{path}"))
+            .chain([closed.clone()])
+            .collect();
+        let urls: Vec<&str> = urls.iter().map(String::as_str).collect();
+
+        let statuses = LinkChecker::new(None).check(&urls, &options());
+        assert_eq!(statuses[urls[0]], LinkStatus::Reachable);
+        assert_eq!(statuses[urls[1]], LinkStatus::Reachable);
+        assert_eq!(statuses[urls[2]], LinkStatus::Reachable);
+        assert_eq!(statuses[urls[3]], LinkStatus::Reachable);
+        assert_eq!(statuses[urls[4]], LinkStatus::Unreachable("HTTP 404".to_string()));
+        assert!(matches!(&statuses[closed.as_str()], LinkStatus::Unreachable(reason) if !reason.is_empty()));
+    }
+
+    #[test]
+    fn test_reachable_results_are_cached() {
+        let (base, requests) = serve();
+        let cache_dir = tempfile::tempdir().unwrap();
+        let (ok, missing) = (format!("{base}/ok"), format!("{base}/missing"));
+        let urls = [ok.as_str(), missing.as_str(), ok.as_str()];
+
+        let checker = LinkChecker::new(Some(cache_dir.path()));
+        checker.check(&urls, &options());
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+        checker.check(&urls, &options());
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+        checker.save().unwrap();
+
+        // A new run reuses the reachable result but requests the broken link again
+        let statuses = LinkChecker::new(Some(cache_dir.path())).check(&urls, &options());
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+        assert_eq!(statuses[ok.as_str()], LinkStatus::Reachable);
+
+        // Expired entries are requested again
+        let expired = LinkCheckOptions {
+            cache_ttl: Duration::ZERO,
+            ..options()
+        };
+        LinkChecker::new(Some(cache_dir.path())).check(&[ok.as_str()], &expired);
+        assert_eq!(requests.load(Ordering::SeqCst), 4);
+    }
+}
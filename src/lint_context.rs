@@ -3,6 +3,7 @@ use crate::rules::front_matter_utils::FrontMatterUtils;
 use crate::utils::code_block_utils::{CodeBlockContext, CodeBlockUtils};
 use pulldown_cmark::{BrokenLink, Event, LinkType, Options, Parser, Tag, TagEnd};
 use regex::Regex;
+use serde::{Serialize, Serializer};
 use std::borrow::Cow;
 use std::path::PathBuf;
 use std::sync::LazyLock;
@@ -72,7 +73,7 @@ static BARE_EMAIL_PATTERN: LazyLock<Regex> =
 static BLOCKQUOTE_PREFIX_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(\s*>+\s*)").unwrap());
 
 /// Pre-computed information about a line
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct LineInfo {
     /// Byte offset where this line starts in the document
     pub byte_offset: usize,
@@ -112,7 +113,7 @@ impl LineInfo {
 }
 
 /// Information about a list item
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ListItemInfo {
     /// The marker used (*, -, +, or number with . or ))
     pub marker: String,
@@ -127,7 +128,7 @@ pub struct ListItemInfo {
 }
 
 /// Heading style type
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum HeadingStyle {
     /// ATX style heading (# Heading)
     ATX,
@@ -137,8 +138,13 @@ pub enum HeadingStyle {
     Setext2,
 }
 
+/// pulldown-cmark's `LinkType` has no `Serialize` impl, so it is written by variant name
+fn serialize_link_type<S: Serializer>(link_type: &LinkType, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&format_args!("{link_type:?}"))
+}
+
 /// Parsed link information
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ParsedLink<'a> {
     /// Line number (1-indexed)
     pub line: usize,
@@ -159,6 +165,7 @@ pub struct ParsedLink<'a> {
     /// Reference ID for reference links
     pub reference_id: Option<Cow<'a, str>>,
     /// Link type from pulldown-cmark
+    #[serde(serialize_with = "serialize_link_type")]
     pub link_type: LinkType,
 }
 
@@ -172,7 +179,7 @@ pub struct BrokenLinkInfo {
 }
 
 /// Parsed footnote reference (e.g., `[^1]`, `[^note]`)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct FootnoteRef {
     /// The footnote ID (without the ^ prefix)
     pub id: String,
@@ -185,7 +192,7 @@ pub struct FootnoteRef {
 }
 
 /// Parsed image information
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ParsedImage<'a> {
     /// Line number (1-indexed)
     pub line: usize,
@@ -206,11 +213,12 @@ pub struct ParsedImage<'a> {
     /// Reference ID for reference images
     pub reference_id: Option<Cow<'a, str>>,
     /// Link type from pulldown-cmark
+    #[serde(serialize_with = "serialize_link_type")]
     pub link_type: LinkType,
 }
 
 /// Reference definition [ref]: url "title"
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ReferenceDef {
     /// Line number (1-indexed)
     pub line: usize,
@@ -227,7 +235,7 @@ pub struct ReferenceDef {
 }
 
 /// Parsed code span information
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct CodeSpan {
     /// Line number where the code span starts (1-indexed)
     pub line: usize,
@@ -248,7 +256,7 @@ pub struct CodeSpan {
 }
 
 /// Information about a heading
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct HeadingInfo {
     /// Heading level (1-6 for ATX, 1-2 for Setext)
     pub level: u8,
@@ -273,7 +281,7 @@ pub struct HeadingInfo {
 }
 
 /// Information about a blockquote line
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct BlockquoteInfo {
     /// Nesting level (1 for >, 2 for >>, etc.)
     pub nesting_level: usize,
@@ -294,7 +302,7 @@ pub struct BlockquoteInfo {
 }
 
 /// Information about a list block
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ListBlock {
     /// Line number where the list starts (1-indexed)
     pub start_line: usize,
@@ -653,6 +661,16 @@ impl<'a> LintContext<'a> {
         Arc::clone(cache.get_or_insert_with(|| Arc::new(Self::parse_code_spans(self.content, &self.lines))))
     }
 
+    /// Get list blocks - pre-computed during LintContext construction
+    pub fn list_blocks(&self) -> &[ListBlock] {
+        &self.list_blocks
+    }
+
+    /// Get table blocks - pre-computed during LintContext construction
+    pub fn table_blocks(&self) -> &[crate::utils::table_utils::TableBlock] {
+        &self.table_blocks
+    }
+
     /// Get HTML comment ranges - pre-computed during LintContext construction
     pub fn html_comment_ranges(&self) -> &[crate::utils::skip_context::ByteRange] {
         &self.html_comment_ranges
@@ -375,58 +375,45 @@ fn find_cross_file_links(content: &str) -> Vec<CrossFileLinkIndex> {
     use pulldown_cmark::{Event, Parser, Tag};
 
     let mut links = Vec::new();
-    let parser = Parser::new(content);
-
-    let mut current_line = 1;
-    let mut current_col = 1;
-
-    for event in parser {
-        match event {
-            Event::Start(Tag::Link { dest_url, .. }) | Event::Start(Tag::Image { dest_url, .. }) => {
-                let url = dest_url.as_ref();
-
-                // Skip external URLs and anchor-only links
-                if url.starts_with("http://")
-                    || url.starts_with("https://")
-                    || url.starts_with("mailto:")
-                    || url.starts_with('#')
-                {
-                    continue;
-                }
+    // Line and start of the line of the last position counted; links come in document order
+    let (mut line, mut line_start, mut counted) = (1, 0, 0);
+
+    for (event, range) in Parser::new(content).into_offset_iter() {
+        if let Event::Start(Tag::Link { dest_url, .. }) | Event::Start(Tag::Image { dest_url, .. }) = event {
+            let url = dest_url.as_ref();
+
+            // Skip external URLs and anchor-only links
+            if url.starts_with("http://")
+                || url.starts_with("https://")
+                || url.starts_with("mailto:")
+                || url.starts_with('#')
+            {
+                continue;
+            }
 
-                // Parse the URL for file path and fragment
-                let (file_path, fragment) = if let Some(hash_pos) = url.find('#') {
-                    (&url[..hash_pos], &url[hash_pos + 1..])
-                } else {
-                    (url, "")
-                };
+            // Parse the URL for file path and fragment
+            let (file_path, fragment) = if let Some(hash_pos) = url.find('#') {
+                (&url[..hash_pos], &url[hash_pos + 1..])
+            } else {
+                (url, "")
+            };
 
-                // Only track links that point to files (with or without fragments)
-                if !file_path.is_empty() {
-                    links.push(CrossFileLinkIndex {
-                        target_path: file_path.to_string(),
-                        fragment: fragment.to_string(),
-                        line: current_line,
-                        column: current_col,
-                    });
-                }
-            }
-            Event::Text(text) => {
-                // Update position tracking
-                for ch in text.chars() {
-                    if ch == '\n' {
-                        current_line += 1;
-                        current_col = 1;
-                    } else {
-                        current_col += 1;
-                    }
+            // Only track links that point to files (with or without fragments)
+            if !file_path.is_empty() {
+                // Position of the link's opening `[` (or `!` for images), like the rules report
+                let skipped = content.get(counted..range.start).unwrap_or_default();
+                for (idx, _) in skipped.match_indices('\n') {
+                    line += 1;
+                    line_start = counted + idx + 1;
                 }
+                counted = counted.max(range.start);
+                links.push(CrossFileLinkIndex {
+                    target_path: file_path.to_string(),
+                    fragment: fragment.to_string(),
+                    line,
+                    column: range.start.saturating_sub(line_start) + 1,
+                });
             }
-            Event::SoftBreak | Event::HardBreak => {
-                current_line += 1;
-                current_col = 1;
-            }
-            _ => {}
         }
     }
 
@@ -458,6 +445,10 @@ Also check [section](./guide.md#install) and [external](https://example.com).
 
         assert_eq!(links[1].target_path, "./guide.md");
         assert_eq!(links[1].fragment, "install");
+
+        // Positions are those of the opening bracket
+        assert_eq!((links[0].line, links[0].column), (4, 5));
+        assert_eq!((links[1].line, links[1].column), (6, 12));
     }
 
     #[test]
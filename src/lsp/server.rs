@@ -6,6 +6,7 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::Result;
 use tokio::sync::{RwLock, mpsc};
@@ -14,9 +15,11 @@ use tower_lsp::lsp_types::*;
 use tower_lsp::{Client, LanguageServer};
 
 use crate::config::Config;
-use crate::lint;
 use crate::lsp::index_worker::IndexWorker;
-use crate::lsp::types::{IndexState, IndexUpdate, RumdlLspConfig, warning_to_code_actions, warning_to_diagnostic};
+use crate::lsp::types::{
+    IndexState, IndexUpdate, RumdlLspConfig, apply_content_change, cross_file_related_information,
+    warning_to_code_actions, warning_to_diagnostic,
+};
 use crate::rule::{FixCapability, Rule};
 use crate::rules;
 use crate::workspace_index::WorkspaceIndex;
@@ -24,6 +27,10 @@ use crate::workspace_index::WorkspaceIndex;
 /// Supported markdown file extensions (without leading dot)
 const MARKDOWN_EXTENSIONS: &[&str] = &["md", "markdown", "mdx", "mkd", "mkdn", "mdown", "mdwn", "qmd", "rmd"];
 
+/// How long a document has to stay unchanged before it is linted, so that
+/// rapid keystrokes coalesce into one lint pass
+const CHANGE_DEBOUNCE: Duration = Duration::from_millis(100);
+
 /// Check if a file extension is a markdown extension
 #[inline]
 fn is_markdown_extension(ext: &str) -> bool {
@@ -285,7 +292,7 @@ impl RumdlLanguageServer {
         filtered_rules = self.apply_lsp_config_overrides(filtered_rules, &lsp_config);
 
         // Run rumdl linting with the configured flavor
-        let mut all_warnings = match crate::lint(text, &filtered_rules, false, flavor) {
+        let mut all_warnings = match crate::lint_with_config(text, &filtered_rules, false, &rumdl_config, flavor) {
             Ok(warnings) => warnings,
             Err(e) => {
                 log::error!("Failed to lint document {uri}: {e}");
@@ -294,24 +301,40 @@ impl RumdlLanguageServer {
         };
 
         // Run cross-file checks if workspace index is ready
-        if let Some(ref path) = file_path {
-            let index_state = self.index_state.read().await.clone();
-            if matches!(index_state, IndexState::Ready) {
-                let workspace_index = self.workspace_index.read().await;
-                if let Some(file_index) = workspace_index.get_file(path) {
-                    match crate::run_cross_file_checks(path, file_index, &filtered_rules, &workspace_index) {
-                        Ok(cross_file_warnings) => {
-                            all_warnings.extend(cross_file_warnings);
-                        }
-                        Err(e) => {
-                            log::warn!("Failed to run cross-file checks for {uri}: {e}");
-                        }
-                    }
+        let index_state = self.index_state.read().await.clone();
+        let workspace_index = if file_path.is_some() && matches!(index_state, IndexState::Ready) {
+            Some(self.workspace_index.read().await)
+        } else {
+            None
+        };
+        let file_index = workspace_index
+            .as_ref()
+            .zip(file_path.as_ref())
+            .and_then(|(workspace_index, path)| workspace_index.get_file(path));
+        if let (Some(workspace_index), Some(path), Some(file_index)) = (&workspace_index, &file_path, file_index) {
+            match crate::run_cross_file_checks(path, file_index, &filtered_rules, workspace_index) {
+                Ok(cross_file_warnings) => {
+                    all_warnings.extend(cross_file_warnings);
+                }
+                Err(e) => {
+                    log::warn!("Failed to run cross-file checks for {uri}: {e}");
                 }
             }
         }
 
-        let diagnostics = all_warnings.iter().map(warning_to_diagnostic).collect();
+        // Hide severities below the repository's `[vscode] min-severity`
+        let diagnostics = all_warnings
+            .iter()
+            .filter(|w| rumdl_config.vscode.shows_severity(w.severity))
+            .map(|warning| {
+                let mut diagnostic = warning_to_diagnostic(warning);
+                // Point broken cross-file links at the target that was looked for
+                if let (Some(path), Some(file_index)) = (&file_path, file_index) {
+                    diagnostic.related_information = cross_file_related_information(warning, path, file_index);
+                }
+                diagnostic
+            })
+            .collect();
         Ok(diagnostics)
     }
 
@@ -376,7 +399,7 @@ impl RumdlLanguageServer {
         let mut rules_with_warnings = std::collections::HashSet::new();
         let mut fixed_text = text.to_string();
 
-        match lint(&fixed_text, &filtered_rules, false, flavor) {
+        match crate::lint_with_config(&fixed_text, &filtered_rules, false, &rumdl_config, flavor) {
             Ok(warnings) => {
                 for warning in warnings {
                     if let Some(rule_name) = &warning.rule_name {
@@ -465,7 +488,7 @@ impl RumdlLanguageServer {
         // Apply LSP config overrides (select_rules, ignore_rules from VSCode settings)
         filtered_rules = self.apply_lsp_config_overrides(filtered_rules, &lsp_config);
 
-        match crate::lint(text, &filtered_rules, false, flavor) {
+        match crate::lint_with_config(text, &filtered_rules, false, &rumdl_config, flavor) {
             Ok(warnings) => {
                 let mut actions = Vec::new();
                 let mut fixable_count = 0;
@@ -793,7 +816,7 @@ impl LanguageServer for RumdlLanguageServer {
             capabilities: ServerCapabilities {
                 text_document_sync: Some(TextDocumentSyncCapability::Options(TextDocumentSyncOptions {
                     open_close: Some(true),
-                    change: Some(TextDocumentSyncKind::FULL),
+                    change: Some(TextDocumentSyncKind::INCREMENTAL),
                     will_save: Some(false),
                     will_save_wait_until: Some(true),
                     save: Some(TextDocumentSyncSaveOptions::SaveOptions(SaveOptions {
@@ -974,36 +997,66 @@ impl LanguageServer for RumdlLanguageServer {
         let uri = params.text_document.uri;
         let version = params.text_document.version;
 
-        if let Some(change) = params.content_changes.into_iter().next() {
-            let text = change.text;
+        if params.content_changes.is_empty() {
+            return;
+        }
 
-            let entry = DocumentEntry {
-                content: text.clone(),
-                version: Some(version),
-                from_disk: false,
-            };
-            self.documents.write().await.insert(uri.clone(), entry);
-
-            // Send update to index worker for cross-file analysis
-            if let Ok(path) = uri.to_file_path() {
-                let _ = self
-                    .update_tx
-                    .send(IndexUpdate::FileChanged {
-                        path,
-                        content: text.clone(),
-                    })
-                    .await;
+        // Apply the changes in order; each range refers to the text left by the previous one
+        let text = {
+            let mut documents = self.documents.write().await;
+            let mut text = documents.remove(&uri).map(|entry| entry.content).unwrap_or_default();
+            for change in &params.content_changes {
+                apply_content_change(&mut text, change);
             }
+            documents.insert(
+                uri.clone(),
+                DocumentEntry {
+                    content: text.clone(),
+                    version: Some(version),
+                    from_disk: false,
+                },
+            );
+            text
+        };
 
-            self.update_diagnostics(uri, text).await;
+        // Send update to index worker for cross-file analysis
+        if let Ok(path) = uri.to_file_path() {
+            let _ = self
+                .update_tx
+                .send(IndexUpdate::FileChanged { path, content: text })
+                .await;
         }
+
+        // Lint once the document stops changing. A later change skips this pass, and the
+        // pass scheduled by that change lints the newer text
+        let server = self.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(CHANGE_DEBOUNCE).await;
+            let latest = server
+                .documents
+                .read()
+                .await
+                .get(&uri)
+                .filter(|entry| entry.version == Some(version))
+                .map(|entry| entry.content.clone());
+            if let Some(text) = latest {
+                server.update_diagnostics(uri, text).await;
+            }
+        });
     }
 
     async fn will_save_wait_until(&self, params: WillSaveTextDocumentParams) -> JsonRpcResult<Option<Vec<TextEdit>>> {
         let config_guard = self.config.read().await;
-        let enable_auto_fix = config_guard.enable_auto_fix;
+        let mut enable_auto_fix = config_guard.enable_auto_fix;
         drop(config_guard);
 
+        // A repository's `[vscode] format-on-save` takes precedence over the editor setting
+        if let Ok(path) = params.text_document.uri.to_file_path()
+            && let Some(format_on_save) = self.resolve_config_for_file(&path).await.vscode.format_on_save
+        {
+            enable_auto_fix = format_on_save;
+        }
+
         if !enable_auto_fix {
             return Ok(None);
         }
@@ -1177,6 +1230,11 @@ impl LanguageServer for RumdlLanguageServer {
 
         log::debug!("Formatting request for: {uri}");
 
+        // Excluded files are left as they are, like they are by auto-fix on save
+        if self.should_exclude_uri(&uri).await {
+            return Ok(Some(Vec::new()));
+        }
+
         if let Some(text) = self.get_document_content(&uri).await {
             // Get config with LSP overrides
             let config_guard = self.config.read().await;
@@ -1200,58 +1258,22 @@ impl LanguageServer for RumdlLanguageServer {
             // Apply LSP config overrides
             filtered_rules = self.apply_lsp_config_overrides(filtered_rules, &lsp_config);
 
-            // Use warning fixes for all rules
-            match crate::lint(&text, &filtered_rules, false, flavor) {
-                Ok(warnings) => {
-                    log::debug!(
-                        "Found {} warnings, {} with fixes",
-                        warnings.len(),
-                        warnings.iter().filter(|w| w.fix.is_some()).count()
-                    );
-
-                    let has_fixes = warnings.iter().any(|w| w.fix.is_some());
-                    if has_fixes {
-                        // Only apply fixes from fixable rules during formatting
-                        // Unfixable rules provide warning-level fixes for Quick Fix actions,
-                        // but should not be applied during bulk format operations
-                        let fixable_warnings: Vec<_> = warnings
-                            .iter()
-                            .filter(|w| {
-                                if let Some(rule_name) = &w.rule_name {
-                                    filtered_rules
-                                        .iter()
-                                        .find(|r| r.name() == rule_name)
-                                        .map(|r| r.fix_capability() != FixCapability::Unfixable)
-                                        .unwrap_or(false)
-                                } else {
-                                    false
-                                }
-                            })
-                            .cloned()
-                            .collect();
-
-                        match crate::utils::fix_utils::apply_warning_fixes(&text, &fixable_warnings) {
-                            Ok(fixed_content) => {
-                                if fixed_content != text {
-                                    log::debug!("Returning formatting edits");
-                                    let end_position = self.get_end_position(&text);
-                                    let edit = TextEdit {
-                                        range: Range {
-                                            start: Position { line: 0, character: 0 },
-                                            end: end_position,
-                                        },
-                                        new_text: fixed_content,
-                                    };
-                                    return Ok(Some(vec![edit]));
-                                }
-                            }
-                            Err(e) => {
-                                log::error!("Failed to apply fixes: {e}");
-                            }
-                        }
-                    }
-                    Ok(Some(Vec::new()))
+            // Run the rules' fixes through the fix coordinator, the same way `rumdl fmt` does,
+            // so fixes that enable or conflict with others settle before the edit is returned
+            match crate::format_content(&text, &filtered_rules, &rumdl_config, flavor) {
+                Ok(fixed_content) if fixed_content != text => {
+                    log::debug!("Returning formatting edits");
+                    let end_position = self.get_end_position(&text);
+                    let edit = TextEdit {
+                        range: Range {
+                            start: Position { line: 0, character: 0 },
+                            end: end_position,
+                        },
+                        new_text: fixed_content,
+                    };
+                    Ok(Some(vec![edit]))
                 }
+                Ok(_) => Ok(Some(Vec::new())),
                 Err(e) => {
                     log::error!("Failed to format document: {e}");
                     Ok(Some(Vec::new()))
@@ -1469,9 +1491,10 @@ mod tests {
         // Test code action conversion (no fix, but should have ignore action)
         let uri = Url::parse("file:///test.md").unwrap();
         let actions = warning_to_code_actions(&warning, &uri, "Test content");
-        // Should have 1 action: ignore-line (no fix available)
-        assert_eq!(actions.len(), 1);
+        // Should have 2 actions: ignore-line and ignore-file (no fix available)
+        assert_eq!(actions.len(), 2);
         assert_eq!(actions[0].title, "Ignore MD001 for this line");
+        assert_eq!(actions[1].title, "Ignore MD001 for this file");
     }
 
     #[tokio::test]
@@ -1864,6 +1887,53 @@ line_length = 100
         );
     }
 
+    /// Test that a repository `[vscode]` section controls fix-on-save and shown severities
+    #[tokio::test]
+    async fn test_vscode_config_section() {
+        use std::fs;
+        use tempfile::tempdir;
+
+        let temp_dir = tempdir().unwrap();
+        let project = temp_dir.path().join("project");
+        fs::create_dir(&project).unwrap();
+        fs::write(
+            project.join(".rumdl.toml"),
+            "[vscode]\nformat-on-save = true\nmin-severity = \"error\"\n",
+        )
+        .unwrap();
+
+        let server = create_test_server();
+        server.workspace_roots.write().await.push(project.clone());
+
+        let test_file = project.join("test.md");
+        let text = "#Heading without space\n";
+        fs::write(&test_file, text).unwrap();
+        let uri = Url::from_file_path(&test_file).unwrap();
+        server.documents.write().await.insert(
+            uri.clone(),
+            DocumentEntry {
+                content: text.to_string(),
+                version: Some(1),
+                from_disk: false,
+            },
+        );
+
+        // MD018 is a warning, so it is hidden with min-severity = "error"
+        let diagnostics = server.lint_document(&uri, text).await.unwrap();
+        assert!(diagnostics.is_empty());
+
+        // The editor leaves auto-fix off, but the repository turns it on
+        assert!(!server.config.read().await.enable_auto_fix);
+        let edits = server
+            .will_save_wait_until(WillSaveTextDocumentParams {
+                text_document: TextDocumentIdentifier { uri },
+                reason: TextDocumentSaveReason::MANUAL,
+            })
+            .await
+            .unwrap();
+        assert_eq!(edits.unwrap()[0].new_text, "# Heading without space\n");
+    }
+
     /// Test that config cache works (cache hit scenario)
     #[tokio::test]
     async fn test_config_cache_hit() {
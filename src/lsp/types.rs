@@ -4,7 +4,7 @@
 //! following the Language Server Protocol specification.
 
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tower_lsp::lsp_types::*;
 
 /// State of the workspace index
@@ -124,6 +124,79 @@ pub fn warning_to_diagnostic(warning: &crate::rule::LintWarning) -> Diagnostic {
     }
 }
 
+/// Related information for a broken link to another file (MD057) or a fragment missing from
+/// one (MD051), pointing at the target that was looked for
+///
+/// The link is found in the file's workspace index by the warning's position, and its target is
+/// resolved the way the cross-file checks resolve it.
+pub fn cross_file_related_information(
+    warning: &crate::rule::LintWarning,
+    file_path: &Path,
+    file_index: &crate::workspace_index::FileIndex,
+) -> Option<Vec<DiagnosticRelatedInformation>> {
+    let rule_name = warning.rule_name.as_deref()?;
+    if rule_name != "MD051" && rule_name != "MD057" {
+        return None;
+    }
+
+    let link = file_index.cross_file_links.iter().find(|link| {
+        link.line == warning.line
+            && link.column == warning.column
+            && (rule_name == "MD057" || !link.fragment.is_empty())
+    })?;
+    let target = crate::rules::MD057ExistingRelativeLinks::resolve_target(file_path, &link.target_path);
+    let message = if rule_name == "MD057" {
+        format!("Looked for {}, which does not exist", target.display())
+    } else {
+        format!("No heading in {} has the anchor '#{}'", target.display(), link.fragment)
+    };
+
+    Some(vec![DiagnosticRelatedInformation {
+        location: Location {
+            uri: Url::from_file_path(&target).ok()?,
+            range: Range::default(),
+        },
+        message,
+    }])
+}
+
+/// Apply one of the editor's content changes to a document's text
+///
+/// A change without a range replaces the whole text. Range positions count UTF-16 code units,
+/// the LSP default encoding, and are clamped to the end of their line or of the document.
+pub fn apply_content_change(text: &mut String, change: &TextDocumentContentChangeEvent) {
+    match change.range {
+        Some(range) => {
+            let start = lsp_position_to_byte_offset(text, range.start);
+            let end = lsp_position_to_byte_offset(text, range.end).max(start);
+            text.replace_range(start..end, &change.text);
+        }
+        None => *text = change.text.clone(),
+    }
+}
+
+/// Convert an LSP position to a byte offset in `text`
+fn lsp_position_to_byte_offset(text: &str, position: Position) -> usize {
+    let mut line_start = 0;
+    for _ in 0..position.line {
+        match text[line_start..].find('\n') {
+            Some(newline) => line_start += newline + 1,
+            None => return text.len(),
+        }
+    }
+
+    let line = &text[line_start..];
+    let line = &line[..line.find('\n').unwrap_or(line.len())];
+    let mut units = 0;
+    for (idx, ch) in line.char_indices() {
+        if units >= position.character as usize {
+            return line_start + idx;
+        }
+        units += ch.len_utf16();
+    }
+    line_start + line.len()
+}
+
 /// Convert byte range to LSP range
 fn byte_range_to_lsp_range(text: &str, byte_range: std::ops::Range<usize>) -> Option<Range> {
     let mut line = 0u32;
@@ -208,6 +281,11 @@ pub fn warning_to_code_actions(warning: &crate::rule::LintWarning, uri: &Url, do
         actions.push(ignore_line_action);
     }
 
+    // Add ignore-file action
+    if let Some(ignore_file_action) = create_ignore_file_action(warning, uri, document_text) {
+        actions.push(ignore_file_action);
+    }
+
     actions
 }
 
@@ -371,6 +449,10 @@ fn extract_domain_for_placeholder(url: &str) -> &str {
 }
 
 /// Create an ignore-line code action that adds a rumdl-disable-line comment
+///
+/// Comments inside code blocks are not read, and one after an opening fence would become its
+/// info string, so for the first line of a fenced code block the action adds a
+/// rumdl-disable-next-line comment on the line above instead.
 fn create_ignore_line_action(warning: &crate::rule::LintWarning, uri: &Url, document_text: &str) -> Option<CodeAction> {
     let rule_id = warning.rule_name.as_ref()?;
     let warning_line = warning.line.saturating_sub(1);
@@ -385,28 +467,102 @@ fn create_ignore_line_action(warning: &crate::rule::LintWarning, uri: &Url, docu
         return None;
     }
 
-    // Calculate position at end of line
-    let line_end = Position {
-        line: warning_line as u32,
-        character: line_content.len() as u32,
+    // Byte offset where each line starts
+    let line_starts: Vec<usize> = std::iter::once(0)
+        .chain(document_text.match_indices('\n').map(|(idx, _)| idx + 1))
+        .collect();
+    let code_blocks = crate::utils::code_block_utils::CodeBlockUtils::detect_code_blocks(document_text);
+    // Blocks nested in lists or blockquotes start after the container's indentation or markers
+    let content_start = |line: usize| {
+        let text = lines.get(line).copied().unwrap_or_default();
+        line_starts[line] + text.len() - text.trim_start_matches(|c: char| c.is_whitespace() || c == '>').len()
+    };
+    let in_code_block = |line: usize| {
+        let start = content_start(line);
+        code_blocks
+            .iter()
+            .any(|&(block_start, block_end)| start >= block_start && start < block_end)
     };
 
-    // Use rumdl-disable-line syntax
-    let comment = format!(" <!-- rumdl-disable-line {rule_id} -->");
+    let edit = if !in_code_block(warning_line) {
+        // Calculate position at end of line
+        let line_end = Position {
+            line: warning_line as u32,
+            character: line_content.len() as u32,
+        };
+
+        // Use rumdl-disable-line syntax
+        TextEdit {
+            range: Range {
+                start: line_end,
+                end: line_end,
+            },
+            new_text: format!(" <!-- rumdl-disable-line {rule_id} -->"),
+        }
+    } else {
+        let marker = line_content.trim_start_matches(|c: char| c.is_whitespace() || c == '>');
+        let opens_fence = marker.starts_with("```") || marker.starts_with("~~~");
+        if !opens_fence || (warning_line > 0 && in_code_block(warning_line - 1)) {
+            return None;
+        }
+
+        // Keep the fence's indentation and blockquote markers so the comment stays in its container
+        let prefix = &line_content[..line_content.len() - marker.len()];
+        let line_start = Position {
+            line: warning_line as u32,
+            character: 0,
+        };
+        TextEdit {
+            range: Range {
+                start: line_start,
+                end: line_start,
+            },
+            new_text: format!("{prefix}<!-- rumdl-disable-next-line {rule_id} -->\n"),
+        }
+    };
+
+    let mut changes = std::collections::HashMap::new();
+    changes.insert(uri.clone(), vec![edit]);
+
+    Some(CodeAction {
+        title: format!("Ignore {rule_id} for this line"),
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(vec![warning_to_diagnostic(warning)]),
+        edit: Some(WorkspaceEdit {
+            changes: Some(changes),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        command: None,
+        is_preferred: Some(false), // Fix action is preferred
+        disabled: None,
+        data: None,
+    })
+}
+
+/// Create an ignore-file code action that adds a rumdl-disable-file comment
+/// at the top of the document, after any front matter
+fn create_ignore_file_action(warning: &crate::rule::LintWarning, uri: &Url, document_text: &str) -> Option<CodeAction> {
+    let rule_id = warning.rule_name.as_ref()?;
+
+    let insert_at = Position {
+        line: crate::rules::front_matter_utils::FrontMatterUtils::get_front_matter_end_line(document_text) as u32,
+        character: 0,
+    };
 
     let edit = TextEdit {
         range: Range {
-            start: line_end,
-            end: line_end,
+            start: insert_at,
+            end: insert_at,
         },
-        new_text: comment,
+        new_text: format!("<!-- rumdl-disable-file {rule_id} -->\n\n"),
     };
 
     let mut changes = std::collections::HashMap::new();
     changes.insert(uri.clone(), vec![edit]);
 
     Some(CodeAction {
-        title: format!("Ignore {rule_id} for this line"),
+        title: format!("Ignore {rule_id} for this file"),
         kind: Some(CodeActionKind::QUICKFIX),
         diagnostics: Some(vec![warning_to_diagnostic(warning)]),
         edit: Some(WorkspaceEdit {
@@ -415,7 +571,7 @@ fn create_ignore_line_action(warning: &crate::rule::LintWarning, uri: &Url, docu
             change_annotations: None,
         }),
         command: None,
-        is_preferred: Some(false), // Fix action is preferred
+        is_preferred: Some(false),
         disabled: None,
         data: None,
     })
@@ -745,6 +901,49 @@ mod tests {
         assert!(!config.enable_auto_fix); // Should use default
     }
 
+    fn change(range: Option<((u32, u32), (u32, u32))>, text: &str) -> TextDocumentContentChangeEvent {
+        TextDocumentContentChangeEvent {
+            range: range.map(|((start_line, start_char), (end_line, end_char))| Range {
+                start: Position::new(start_line, start_char),
+                end: Position::new(end_line, end_char),
+            }),
+            range_length: None,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_apply_content_change() {
+        let mut text = "# Title\n\nSome text\n".to_string();
+
+        apply_content_change(&mut text, &change(Some(((2, 5), (2, 9))), "words"));
+        assert_eq!(text, "# Title\n\nSome words\n");
+
+        // Insert a line, then delete across lines
+        apply_content_change(&mut text, &change(Some(((1, 0), (1, 0))), "\nNew line\n"));
+        assert_eq!(text, "# Title\n\nNew line\n\nSome words\n");
+        apply_content_change(&mut text, &change(Some(((2, 3), (4, 4))), ""));
+        assert_eq!(text, "# Title\n\nNew words\n");
+
+        // Positions past the end are clamped
+        apply_content_change(&mut text, &change(Some(((2, 99), (9, 0))), "!"));
+        assert_eq!(text, "# Title\n\nNew words!");
+
+        // A change without a range replaces everything
+        apply_content_change(&mut text, &change(None, "Replaced\n"));
+        assert_eq!(text, "Replaced\n");
+    }
+
+    #[test]
+    fn test_apply_content_change_counts_utf16_units() {
+        // The emoji is two UTF-16 code units and four bytes
+        let mut text = "a😀b é c\n".to_string();
+        apply_content_change(&mut text, &change(Some(((0, 3), (0, 4))), "B"));
+        assert_eq!(text, "a😀B é c\n");
+        apply_content_change(&mut text, &change(Some(((0, 5), (0, 6))), "e"));
+        assert_eq!(text, "a😀B e c\n");
+    }
+
     #[test]
     fn test_create_ignore_line_action_uses_rumdl_syntax() {
         let warning = LintWarning {
@@ -827,6 +1026,75 @@ mod tests {
         assert!(action.is_none());
     }
 
+    /// Apply the action's single edit to the document
+    fn apply_action(action: &CodeAction, uri: &Url, document: &str) -> String {
+        let edits = &action.edit.as_ref().unwrap().changes.as_ref().unwrap()[uri];
+        assert_eq!(edits.len(), 1);
+        let position = edits[0].range.start;
+        let mut lines: Vec<String> = document.split('\n').map(str::to_string).collect();
+        let line = &mut lines[position.line as usize];
+        line.insert_str(position.character as usize, &edits[0].new_text);
+        lines.join("\n")
+    }
+
+    fn md_warning(rule: &str, line: usize) -> LintWarning {
+        LintWarning {
+            line,
+            column: 1,
+            end_line: line,
+            end_column: 4,
+            rule_name: Some(rule.to_string()),
+            message: "Warning".to_string(),
+            severity: Severity::Warning,
+            fix: None,
+        }
+    }
+
+    #[test]
+    fn test_create_ignore_line_action_on_code_fence() {
+        let uri = Url::parse("file:///test.md").unwrap();
+        let document = "# Title\n\n- Item\n\n  ```\n  code\n  ```\n";
+
+        // A comment after the fence would become its info string, so it goes on the line above
+        let action = create_ignore_line_action(&md_warning("MD040", 5), &uri, document).unwrap();
+        let fixed = apply_action(&action, &uri, document);
+        assert_eq!(
+            fixed,
+            "# Title\n\n- Item\n\n  <!-- rumdl-disable-next-line MD040 -->\n  ```\n  code\n  ```\n"
+        );
+        let inline_config = crate::inline_config::InlineConfig::from_content(&fixed);
+        assert!(inline_config.is_rule_disabled("MD040", 6));
+
+        // Comments inside the block are never read
+        assert!(create_ignore_line_action(&md_warning("MD010", 6), &uri, document).is_none());
+    }
+
+    #[test]
+    fn test_create_ignore_file_action() {
+        let uri = Url::parse("file:///test.md").unwrap();
+        let document = "---\ntitle: Test\n---\n# Title\n\nText   \n";
+
+        let action = create_ignore_file_action(&md_warning("MD009", 6), &uri, document).unwrap();
+        assert_eq!(action.title, "Ignore MD009 for this file");
+        assert_eq!(action.is_preferred, Some(false));
+
+        // The comment goes after the front matter
+        let fixed = apply_action(&action, &uri, document);
+        assert_eq!(
+            fixed,
+            "---\ntitle: Test\n---\n<!-- rumdl-disable-file MD009 -->\n\n# Title\n\nText   \n"
+        );
+        let inline_config = crate::inline_config::InlineConfig::from_content(&fixed);
+        assert!(inline_config.is_rule_disabled("MD009", 8));
+        assert!(!inline_config.is_rule_disabled("MD013", 8));
+
+        let action = create_ignore_file_action(&md_warning("MD009", 3), &uri, "# Title\n\nText   \n").unwrap();
+        assert_eq!(
+            apply_action(&action, &uri, "# Title\n\nText   \n"),
+            "<!-- rumdl-disable-file MD009 -->\n\n# Title\n\nText   \n"
+        );
+    }
+
     #[test]
     fn test_warning_to_code_actions_with_fix() {
         let warning = LintWarning {
@@ -848,8 +1116,8 @@ mod tests {
 
         let actions = warning_to_code_actions(&warning, &uri, document_text);
 
-        // Should have 2 actions: fix and ignore-line
-        assert_eq!(actions.len(), 2);
+        // Should have 3 actions: fix, ignore-line and ignore-file
+        assert_eq!(actions.len(), 3);
 
         // First action should be fix (preferred)
         assert_eq!(actions[0].title, "Fix: Trailing spaces");
@@ -878,9 +1146,10 @@ mod tests {
 
         let actions = warning_to_code_actions(&warning, &uri, document_text);
 
-        // Should have 1 action: ignore-line only (no fix available)
-        assert_eq!(actions.len(), 1);
+        // Should have 2 actions: ignore-line and ignore-file (no fix available)
+        assert_eq!(actions.len(), 2);
         assert_eq!(actions[0].title, "Ignore MD033 for this line");
+        assert_eq!(actions[1].title, "Ignore MD033 for this file");
         assert_eq!(actions[0].is_preferred, Some(false));
     }
 
@@ -957,8 +1226,8 @@ mod tests {
 
         let actions = warning_to_code_actions(&warning, &uri, document_text);
 
-        // Should have 3 actions: fix (angle brackets), convert to link, and ignore
-        assert_eq!(actions.len(), 3);
+        // Should have 4 actions: fix (angle brackets), convert to link, and the two ignores
+        assert_eq!(actions.len(), 4);
 
         // First action should be the fix (angle brackets) - preferred
         assert_eq!(
@@ -1006,8 +1275,8 @@ mod tests {
 
         let actions = warning_to_code_actions(&warning, &uri, document_text);
 
-        // Should have 3 actions
-        assert_eq!(actions.len(), 3);
+        // Should have 4 actions
+        assert_eq!(actions.len(), 4);
 
         // Check convert to link action
         assert_eq!(actions[1].title, "Convert to markdown link");
@@ -72,9 +72,23 @@ fn handle_schema_command(action: SchemaAction) {
         schema_obj.insert(
             "additionalProperties".to_string(),
             serde_json::json!({
-                "$ref": "#/definitions/RuleConfig"
+                "$ref": "#/$defs/RuleConfig"
             }),
         );
+
+        // `extend` is resolved while loading config files, so it is not a Config field
+        if let Some(properties) = schema_obj.get_mut("properties").and_then(|p| p.as_object_mut()) {
+            properties.insert(
+                "extend".to_string(),
+                serde_json::json!({
+                    "description": "Config files to inherit from, loaded before this one. Relative paths are resolved against the directory of this file",
+                    "anyOf": [
+                        { "type": "string" },
+                        { "type": "array", "items": { "type": "string" } }
+                    ]
+                }),
+            );
+        }
     }
 
     let schema_json = serde_json::to_string_pretty(&schema_value).unwrap_or_else(|e| {
@@ -125,29 +139,25 @@ fn handle_schema_command(action: SchemaAction) {
 
 /// Get the path to the project's schema file
 fn get_project_schema_path() -> std::path::PathBuf {
-    // Try to find the project root by looking for Cargo.toml
-    let mut current_dir = std::env::current_dir().unwrap_or_else(|e| {
+    let current_dir = std::env::current_dir().unwrap_or_else(|e| {
         eprintln!("{}: Failed to get current directory: {}", "Error".red().bold(), e);
         exit::tool_error();
     });
 
-    loop {
-        let cargo_toml = current_dir.join("Cargo.toml");
-        if cargo_toml.exists() {
-            return current_dir.join("rumdl.schema.json");
+    // Find the project root using the configured root markers, falling back to the
+    // current directory when no marker is found
+    let root = match rumdl_config::SourcedConfig::load_with_discovery(None, None, false) {
+        Ok(sourced) => sourced.resolve_project_root(),
+        Err(_) => {
+            let markers: Vec<String> = rumdl_config::DEFAULT_ROOT_MARKERS
+                .iter()
+                .map(|s| s.to_string())
+                .collect();
+            rumdl_config::find_project_root(&current_dir, &markers)
         }
+    };
 
-        if !current_dir.pop() {
-            // Reached filesystem root without finding Cargo.toml
-            // Fall back to current directory
-            return std::env::current_dir()
-                .unwrap_or_else(|e| {
-                    eprintln!("{}: Failed to get current directory: {}", "Error".red().bold(), e);
-                    exit::tool_error();
-                })
-                .join("rumdl.schema.json");
-        }
-    }
+    root.unwrap_or(current_dir).join("rumdl.schema.json")
 }
 
 /// Efficiently read file content using memory mapping for large files
@@ -169,6 +179,33 @@ pub fn read_file_efficiently(path: &Path) -> Result<String, Box<dyn Error>> {
     }
 }
 
+/// Print configuration validation warnings. With `--strict-config` they are errors and
+/// the process exits before anything is linted.
+pub fn report_config_warnings(warnings: &[rumdl_config::ConfigValidationWarning], strict: bool, silent: bool) {
+    if warnings.is_empty() {
+        return;
+    }
+
+    if strict {
+        for warn in warnings {
+            eprintln!("{}: {}", "Config error".red().bold(), warn.message);
+        }
+        eprintln!(
+            "{}: {} configuration problem(s) found (--strict-config)",
+            "Error".red().bold(),
+            warnings.len()
+        );
+        exit::tool_error();
+    }
+
+    // Otherwise continue with the valid parts of the config
+    if !silent {
+        for warn in warnings {
+            eprintln!("\x1b[33m[config warning]\x1b[0m {}", warn.message);
+        }
+    }
+}
+
 /// Utility function to load configuration with standard CLI error handling.
 /// This eliminates duplication between different CLI commands that load configuration.
 fn load_config_with_cli_error_handling(config_path: Option<&str>, isolated: bool) -> rumdl_config::SourcedConfig {
@@ -351,6 +388,15 @@ enum Commands {
     },
     /// Clear the cache
     Clean,
+    /// Print how a file was parsed (headings, links, lists, per-line flags) as JSON
+    #[command(hide = true)]
+    DebugParse {
+        /// Markdown file to parse
+        file: String,
+        /// Markdown flavor to parse with (default: detected from the file extension)
+        #[arg(long)]
+        flavor: Option<String>,
+    },
     /// Show version information
     Version,
 }
@@ -386,6 +432,26 @@ pub struct CheckArgs {
     #[arg(long, help = "Show diff of what would be fixed instead of fixing files")]
     diff: bool,
 
+    /// Report fixes that need more than one pass to settle
+    #[arg(
+        long,
+        conflicts_with = "diff",
+        help = "Re-lint fixed content and report warnings one fix pass left behind (in memory unless --fix, which writes the settled result)"
+    )]
+    check_idempotent: bool,
+
+    /// Keep a copy of each file before writing fixes
+    #[arg(long, help = "Write a backup of each file (FILE.bak) before applying fixes")]
+    backup: bool,
+
+    /// Suffix for backup files written before fixing (implies --backup)
+    #[arg(
+        long,
+        value_name = "SUFFIX",
+        help = "Suffix for backup files written before applying fixes (implies --backup, default: .bak)"
+    )]
+    backup_suffix: Option<String>,
+
     /// List all available rules
     #[arg(short, long, default_value = "false")]
     list_rules: bool,
@@ -398,6 +464,14 @@ pub struct CheckArgs {
     #[arg(short, long, visible_alias = "rules")]
     enable: Option<String>,
 
+    /// Read rules to enable from a file, one or more per line (`-MDxxx` disables)
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Enable the rules listed in FILE, like --enable (`#` starts a comment, `-MDxxx` disables a rule)"
+    )]
+    rules_from: Option<String>,
+
     /// Extend the list of enabled rules (additive with config)
     #[arg(long)]
     extend_enable: Option<String>,
@@ -410,6 +484,20 @@ pub struct CheckArgs {
     #[arg(long)]
     exclude: Option<String>,
 
+    /// Treat path arguments literally instead of expanding glob patterns in them
+    #[arg(
+        long,
+        help = "Do not expand glob patterns in path arguments (for file names containing *, ?, [ or {)"
+    )]
+    no_glob: bool,
+
+    /// Fail before linting if the configuration has unknown rules, options or type mismatches
+    #[arg(
+        long,
+        help = "Treat configuration warnings (unknown rules or options, type mismatches) as errors"
+    )]
+    strict_config: bool,
+
     /// Disable all exclude patterns (lint all files regardless of exclude configuration)
     #[arg(long, help = "Disable all exclude patterns")]
     no_exclude: bool,
@@ -438,6 +526,30 @@ pub struct CheckArgs {
     #[arg(long)]
     statistics: bool,
 
+    /// Append this run's per-rule counts to a JSON Lines history file
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Append one JSON record of per-rule violation counts (with timestamp and git commit) to FILE"
+    )]
+    statistics_history: Option<String>,
+
+    /// Print a one-line summary of the run to stderr
+    #[arg(
+        long,
+        help = "Print a one-line summary to stderr: `rumdl: N errors, N warnings, N fixable, N files`"
+    )]
+    summary: bool,
+
+    /// Format of the run summary on stderr
+    #[arg(
+        long,
+        value_name = "FORMAT",
+        value_parser = ["text", "json"],
+        help = "Print the run summary to stderr as `text` (same as --summary) or `json`, one object with per-rule counts"
+    )]
+    summary_format: Option<String>,
+
     /// Print diagnostics, but nothing else
     #[arg(short, long, help = "Print diagnostics, but nothing else")]
     quiet: bool,
@@ -447,10 +559,28 @@ pub struct CheckArgs {
     output: String,
 
     /// Output format for linting results
-    #[arg(long, value_parser = ["text", "full", "concise", "grouped", "json", "json-lines", "github", "gitlab", "pylint", "azure", "sarif", "junit"],
-          help = "Output format for linting results (text, full, concise, grouped, json, json-lines, github, gitlab, pylint, azure, sarif, junit)")]
+    #[arg(long, value_parser = ["text", "full", "concise", "grouped", "json", "json-lines", "github", "gitlab", "code-climate", "codeclimate", "pylint", "azure", "sarif", "rdjson", "junit", "canonical"],
+          help = "Output format for linting results (text, full, concise, grouped, json, json-lines, github, gitlab, code-climate, pylint, azure, sarif, rdjson, junit, canonical)")]
     output_format: Option<String>,
 
+    /// How JUnit output maps violations onto testcases
+    #[arg(long, value_parser = ["file", "rule", "violation"],
+          help = "JUnit testcase granularity: one testcase per file (default), per file and rule, or per violation")]
+    junit_granularity: Option<String>,
+
+    /// Stop at the first file with violations
+    #[arg(
+        long,
+        conflicts_with = "fix",
+        help = "Stop at the first file with violations, checking the most recently modified files first"
+    )]
+    fail_fast: bool,
+
+    /// Order of warnings across all files
+    #[arg(long, value_parser = ["rule", "file", "severity"], conflicts_with_all = ["fix", "diff"],
+          help = "Sort warnings across all files by rule, file or severity (flat output formats only)")]
+    sort_by: Option<String>,
+
     /// Read from stdin instead of files
     #[arg(long, help = "Read from stdin instead of files")]
     stdin: bool,
@@ -493,10 +623,112 @@ pub struct CheckArgs {
     )]
     cache_dir: Option<String>,
 
+    /// Directory that reported file paths are made relative to
+    #[arg(
+        long,
+        value_name = "DIR",
+        help = "Report file paths relative to DIR in all output formats (default: report-root in config, or paths as given)"
+    )]
+    report_root: Option<String>,
+
+    /// Check that external links are reachable (MD141)
+    #[arg(
+        long,
+        help = "Check that external links are reachable (enables MD141, which makes network requests)"
+    )]
+    check_links_online: bool,
+
+    /// Write the effective configuration, with all rule defaults, to a TOML file
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = "Write the effective configuration, including every rule's defaults, to a TOML file before checking"
+    )]
+    dump_effective: Option<String>,
+
     #[arg(skip)]
     pub fix_mode: FixMode,
 }
 
+impl CheckArgs {
+    /// Whether the run summary goes to stderr as one JSON object (`--summary-format json`)
+    pub fn json_summary(&self) -> bool {
+        !self.silent && self.summary_format.as_deref() == Some("json")
+    }
+
+    /// Whether the one-line text summary goes to stderr (`--summary` or `--summary-format text`)
+    pub fn text_summary(&self) -> bool {
+        !self.silent && !self.json_summary() && (self.summary || self.summary_format.is_some())
+    }
+
+    /// Suffix for backup files, or `None` when backups are not requested
+    pub fn backup_suffix(&self) -> Option<&str> {
+        self.backup_suffix.as_deref().or(self.backup.then_some(".bak"))
+    }
+
+    /// Merge the rules listed in the `--rules-from` file into `--enable` and `--disable`
+    fn apply_rules_from(&mut self) {
+        let Some(path) = &self.rules_from else {
+            return;
+        };
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) => {
+                eprintln!("{}: Failed to read rules file '{}': {}", "Error".red().bold(), path, e);
+                exit::tool_error();
+            }
+        };
+
+        let (enable, disable) = parse_rules_file(&content);
+        let merge = |list: &mut Option<String>, rules: Vec<String>| {
+            if !rules.is_empty() {
+                *list = Some(list.iter().cloned().chain(rules).collect::<Vec<_>>().join(","));
+            }
+        };
+        merge(&mut self.enable, enable);
+        merge(&mut self.disable, disable);
+    }
+
+    /// Make sure MD141 is among the selected rules when `--check-links-online` is given
+    fn apply_check_links_online(&mut self) {
+        if !self.check_links_online {
+            return;
+        }
+        let list = if self.enable.is_some() {
+            &mut self.enable
+        } else {
+            &mut self.extend_enable
+        };
+        *list = Some(
+            list.iter()
+                .map(String::as_str)
+                .chain(["MD141"])
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+    }
+}
+
+/// Split a `--rules-from` file into the rules to enable and the rules to disable
+///
+/// Rules are separated by commas or whitespace, `#` starts a comment, and a leading `-`
+/// marks a rule to disable.
+fn parse_rules_file(content: &str) -> (Vec<String>, Vec<String>) {
+    let mut enable = Vec::new();
+    let mut disable = Vec::new();
+    for line in content.lines() {
+        let line = line.split('#').next().unwrap_or_default();
+        for entry in line.split([',', ' ', '\t']).filter(|entry| !entry.is_empty()) {
+            match entry.strip_prefix('-') {
+                Some(rule) if !rule.is_empty() => disable.push(rule.to_string()),
+                Some(_) => {}
+                None => enable.push(entry.to_string()),
+            }
+        }
+    }
+    (enable, disable)
+}
+
 /// Offer to install the VS Code extension during init
 fn offer_vscode_extension_install() {
     use rumdl_lib::vscode::VsCodeExtension;
@@ -675,7 +907,7 @@ fn resolve_cache_directory(cli: &Cli) -> std::path::PathBuf {
         .as_ref()
         .map(|sv| std::path::PathBuf::from(&sv.value));
 
-    let project_root = sourced.project_root.clone();
+    let project_root = sourced.resolve_project_root();
 
     // Resolve cache directory with precedence: env var → config → default
     let mut cache_dir = std::env::var("RUMDL_CACHE_DIR")
@@ -866,6 +1098,8 @@ build-backend = "setuptools.build_meta"
             }
             Commands::Check(mut args) => {
                 args.fix_mode = if args.fix { FixMode::CheckFix } else { FixMode::Check };
+                args.apply_rules_from();
+                args.apply_check_links_online();
 
                 if cli.no_config || cli.isolated {
                     run_check(&args, None, cli.no_config || cli.isolated);
@@ -875,6 +1109,7 @@ build-backend = "setuptools.build_meta"
             }
             Commands::Fmt(mut args) => {
                 args.fix_mode = FixMode::Format;
+                args.apply_rules_from();
 
                 if cli.no_config || cli.isolated {
                     run_check(&args, None, cli.no_config || cli.isolated);
@@ -883,71 +1118,8 @@ build-backend = "setuptools.build_meta"
                 }
             }
             Commands::Rule { rule } => {
-                use rumdl_lib::rules::*;
-                let all_rules: Vec<Box<dyn Rule>> = vec![
-                    Box::new(MD001HeadingIncrement),
-                    Box::new(MD003HeadingStyle::default()),
-                    Box::new(MD004UnorderedListStyle::new(UnorderedListStyle::Consistent)),
-                    Box::new(MD005ListIndent::default()),
-                    Box::new(MD007ULIndent::default()),
-                    Box::new(MD009TrailingSpaces::default()),
-                    Box::new(MD010NoHardTabs::default()),
-                    Box::new(MD011NoReversedLinks {}),
-                    Box::new(MD012NoMultipleBlanks::default()),
-                    Box::new(MD013LineLength::default()),
-                    Box::new(MD018NoMissingSpaceAtx {}),
-                    Box::new(MD019NoMultipleSpaceAtx {}),
-                    Box::new(MD020NoMissingSpaceClosedAtx {}),
-                    Box::new(MD021NoMultipleSpaceClosedAtx {}),
-                    Box::new(MD022BlanksAroundHeadings::default()),
-                    Box::new(MD023HeadingStartLeft {}),
-                    Box::new(MD024NoDuplicateHeading::default()),
-                    Box::new(MD025SingleTitle::default()),
-                    Box::new(MD026NoTrailingPunctuation::default()),
-                    Box::new(MD027MultipleSpacesBlockquote {}),
-                    Box::new(MD028NoBlanksBlockquote {}),
-                    Box::new(MD029OrderedListPrefix::default()),
-                    Box::new(MD030ListMarkerSpace::default()),
-                    Box::new(MD031BlanksAroundFences::default()),
-                    Box::new(MD032BlanksAroundLists),
-                    Box::new(MD033NoInlineHtml::default()),
-                    Box::new(MD034NoBareUrls {}),
-                    Box::new(MD035HRStyle::default()),
-                    Box::new(MD036NoEmphasisAsHeading::new(".,;:!?".to_string())),
-                    Box::new(MD037NoSpaceInEmphasis),
-                    Box::new(MD038NoSpaceInCode::default()),
-                    Box::new(MD039NoSpaceInLinks),
-                    Box::new(MD040FencedCodeLanguage {}),
-                    Box::new(MD041FirstLineHeading::default()),
-                    Box::new(MD042NoEmptyLinks::new()),
-                    Box::new(MD043RequiredHeadings::new(Vec::new())),
-                    Box::new(MD044ProperNames::new(Vec::new(), true)),
-                    Box::new(MD045NoAltText::new()),
-                    Box::new(MD046CodeBlockStyle::new(CodeBlockStyle::Consistent)),
-                    Box::new(MD047SingleTrailingNewline),
-                    Box::new(MD048CodeFenceStyle::new(CodeFenceStyle::Consistent)),
-                    Box::new(MD049EmphasisStyle::default()),
-                    Box::new(MD050StrongStyle::new(StrongStyle::Consistent)),
-                    Box::new(MD051LinkFragments::new()),
-                    Box::new(MD052ReferenceLinkImages::new()),
-                    Box::new(MD053LinkImageReferenceDefinitions::default()),
-                    Box::new(MD054LinkImageStyle::default()),
-                    Box::new(MD055TablePipeStyle::default()),
-                    Box::new(MD056TableColumnCount),
-                    Box::new(MD057ExistingRelativeLinks::default()),
-                    Box::new(MD058BlanksAroundTables::default()),
-                    Box::new(MD059LinkText::default()),
-                    Box::new(MD060TableFormat::default()),
-                    Box::new(MD061ForbiddenTerms::default()),
-                    Box::new(MD062LinkDestinationWhitespace::new()),
-                ];
                 if let Some(rule_query) = rule {
-                    let rule_query = rule_query.to_ascii_uppercase();
-                    let found = all_rules.iter().find(|r| {
-                        r.name().eq_ignore_ascii_case(&rule_query)
-                            || r.name().replace("MD", "") == rule_query.replace("MD", "")
-                    });
-                    if let Some(rule) = found {
+                    if let Some(rule) = rumdl_lib::rules::rule_by_name(&rule_query, &rumdl_config::Config::default()) {
                         println!(
                             "{} - {}\n\nDescription:\n  {}",
                             rule.name(),
@@ -955,10 +1127,109 @@ build-backend = "setuptools.build_meta"
                             rule.description()
                         );
                     } else {
-                        eprintln!("Rule '{rule_query}' not found.");
+                        eprintln!("Rule '{}' not found.", rule_query.to_ascii_uppercase());
                         exit::tool_error();
                     }
                 } else {
+                    use rumdl_lib::rules::*;
+                    let all_rules: Vec<Box<dyn Rule>> = vec![
+                        Box::new(MD001HeadingIncrement),
+                        Box::new(MD003HeadingStyle::default()),
+                        Box::new(MD004UnorderedListStyle::new(UnorderedListStyle::Consistent)),
+                        Box::new(MD005ListIndent::default()),
+                        Box::new(MD007ULIndent::default()),
+                        Box::new(MD009TrailingSpaces::default()),
+                        Box::new(MD010NoHardTabs::default()),
+                        Box::new(MD011NoReversedLinks {}),
+                        Box::new(MD012NoMultipleBlanks::default()),
+                        Box::new(MD013LineLength::default()),
+                        Box::new(MD018NoMissingSpaceAtx {}),
+                        Box::new(MD019NoMultipleSpaceAtx {}),
+                        Box::new(MD020NoMissingSpaceClosedAtx {}),
+                        Box::new(MD021NoMultipleSpaceClosedAtx {}),
+                        Box::new(MD022BlanksAroundHeadings::default()),
+                        Box::new(MD023HeadingStartLeft {}),
+                        Box::new(MD024NoDuplicateHeading::default()),
+                        Box::new(MD025SingleTitle::default()),
+                        Box::new(MD026NoTrailingPunctuation::default()),
+                        Box::new(MD027MultipleSpacesBlockquote {}),
+                        Box::new(MD028NoBlanksBlockquote {}),
+                        Box::new(MD029OrderedListPrefix::default()),
+                        Box::new(MD030ListMarkerSpace::default()),
+                        Box::new(MD031BlanksAroundFences::default()),
+                        Box::new(MD032BlanksAroundLists),
+                        Box::new(MD033NoInlineHtml::default()),
+                        Box::new(MD034NoBareUrls {}),
+                        Box::new(MD035HRStyle::default()),
+                        Box::new(MD036NoEmphasisAsHeading::new(".,;:!?".to_string())),
+                        Box::new(MD037NoSpaceInEmphasis),
+                        Box::new(MD038NoSpaceInCode::default()),
+                        Box::new(MD039NoSpaceInLinks),
+                        Box::new(MD040FencedCodeLanguage {}),
+                        Box::new(MD041FirstLineHeading::default()),
+                        Box::new(MD042NoEmptyLinks::new()),
+                        Box::new(MD043RequiredHeadings::new(Vec::new())),
+                        Box::new(MD044ProperNames::new(Vec::new(), true)),
+                        Box::new(MD045NoAltText::new()),
+                        Box::new(MD046CodeBlockStyle::new(CodeBlockStyle::Consistent)),
+                        Box::new(MD047SingleTrailingNewline),
+                        Box::new(MD048CodeFenceStyle::new(CodeFenceStyle::Consistent)),
+                        Box::new(MD049EmphasisStyle::default()),
+                        Box::new(MD050StrongStyle::new(StrongStyle::Consistent)),
+                        Box::new(MD051LinkFragments::new()),
+                        Box::new(MD052ReferenceLinkImages::new()),
+                        Box::new(MD053LinkImageReferenceDefinitions::default()),
+                        Box::new(MD054LinkImageStyle::default()),
+                        Box::new(MD055TablePipeStyle::default()),
+                        Box::new(MD056TableColumnCount),
+                        Box::new(MD057ExistingRelativeLinks::default()),
+                        Box::new(MD058BlanksAroundTables::default()),
+                        Box::new(MD059LinkText::default()),
+                        Box::new(MD060TableFormat::default()),
+                        Box::new(MD061ForbiddenTerms::default()),
+                        Box::new(MD062LinkDestinationWhitespace::new()),
+                        Box::new(MD063DuplicateFootnotes::new()),
+                        Box::new(MD064HeadingAnchorLinks::default()),
+                        Box::new(MD107AbbreviationDefinitions::default()),
+                        Box::new(MD108InvisibleCharacters::default()),
+                        Box::new(MD109AdmonitionTypes::default()),
+                        Box::new(MD110TableCellPadding::default()),
+                        Box::new(MD111ClosingFenceContent),
+                        Box::new(MD112AtxClosingSequence::default()),
+                        Box::new(MD113SnippetTargets::default()),
+                        Box::new(MD114MixedUpLinkSyntax),
+                        Box::new(MD115SetextUnderlineLength::default()),
+                        Box::new(MD116FrontMatterKeyOrder::default()),
+                        Box::new(MD117EmptyEmphasis),
+                        Box::new(MD118ListFenceIndent::default()),
+                        Box::new(MD119FencedCodeBlocks::default()),
+                        Box::new(MD120RedundantHeadingLabel::default()),
+                        Box::new(MD121OrderedListMarkerWidth::default()),
+                        Box::new(MD122BlockquoteLazyContinuation::default()),
+                        Box::new(MD123LinkTitleQuotes),
+                        Box::new(MD124LinkForwardSlashes),
+                        Box::new(MD125CodeBlockTrailingSpaces::default()),
+                        Box::new(MD126HeadingMatchesFilename::default()),
+                        Box::new(MD127HeadingOutline::default()),
+                        Box::new(MD128ListMarkerSpaceConsistency::default()),
+                        Box::new(MD129CodeBlockLanguageInference::default()),
+                        Box::new(MD130DocumentLength::default()),
+                        Box::new(MD131EmphasisNesting),
+                        Box::new(MD132TableColumnLimit::default()),
+                        Box::new(MD133HrListMarker::default()),
+                        Box::new(MD134MdxImportPaths::default()),
+                        Box::new(MD135AtxHeadingSpacing::default()),
+                        Box::new(MD136ReferenceDefinitionUrls::default()),
+                        Box::new(MD137DisableCommentReason::default()),
+                        Box::new(MD138HeadingLink::default()),
+                        Box::new(MD139SortedLists::default()),
+                        Box::new(MD140CodeFenceLength::default()),
+                        Box::new(MD141UnreachableLinks::default()),
+                        Box::new(MD142CodeIdentifierCase::default()),
+                        Box::new(MD143ListItemSpacing::default()),
+                        Box::new(MD144ListNestingDepth::default()),
+                        Box::new(MD145HeadingIdStyle::default()),
+                    ];
                     println!("Available rules:");
                     for rule in &all_rules {
                         println!("  {} - {}", rule.name(), rule.description());
@@ -1106,8 +1377,8 @@ build-backend = "setuptools.build_meta"
                                 );
                                 // Successfully handled 'get', exit the command processing
                             } else {
-                                let all_rules = rumdl_lib::rules::all_rules(&rumdl_config::Config::default());
-                                if let Some(rule) = all_rules.iter().find(|r| r.name() == section_part)
+                                if let Some(rule) =
+                                    rumdl_lib::rules::rule_by_name(section_part, &rumdl_config::Config::default())
                                     && let Some((_, toml::Value::Table(table))) = rule.default_config_section()
                                     && let Some(v) = table.get(&normalized_field)
                                 {
@@ -1548,6 +1819,9 @@ build-backend = "setuptools.build_meta"
             Commands::Clean => {
                 handle_clean_command(&cli);
             }
+            Commands::DebugParse { file, flavor } => {
+                handle_debug_parse_command(&file, flavor.as_deref());
+            }
             Commands::Version => {
                 // Use clap's version info
                 println!("rumdl {}", env!("CARGO_PKG_VERSION"));
@@ -1573,6 +1847,21 @@ fn run_check(args: &CheckArgs, global_config_path: Option<&str>, isolated: bool)
         exit::tool_error();
     }
 
+    if let Some(suffix) = args.backup_suffix() {
+        if args.fix_mode == FixMode::Check {
+            eprintln!("{}: --backup requires --fix", "Error".red().bold());
+            exit::tool_error();
+        }
+        if suffix.is_empty() || suffix.contains(['/', '\\']) {
+            eprintln!(
+                "{}: Invalid backup suffix '{}': it must be non-empty and cannot contain path separators",
+                "Error".red().bold(),
+                suffix
+            );
+            exit::tool_error();
+        }
+    }
+
     // Warn about deprecated --force-exclude flag
     if args.force_exclude {
         eprintln!(
@@ -1611,12 +1900,7 @@ fn run_check(args: &CheckArgs, global_config_path: Option<&str>, isolated: bool)
     let all_rules = rumdl_lib::rules::all_rules(&rumdl_config::Config::default());
     let registry = rumdl_config::RuleRegistry::from_rules(&all_rules);
     let validation_warnings = rumdl_config::validate_config_sourced(&sourced, &registry);
-    if !validation_warnings.is_empty() && !args.silent {
-        for warn in &validation_warnings {
-            eprintln!("\x1b[33m[config warning]\x1b[0m {}", warn.message);
-        }
-        // Do NOT exit; continue with valid config
-    }
+    report_config_warnings(&validation_warnings, args.strict_config, args.silent);
 
     // 4. Extract cache_dir and project_root before converting sourced
     let cache_dir_from_config = sourced
@@ -1626,9 +1910,16 @@ fn run_check(args: &CheckArgs, global_config_path: Option<&str>, isolated: bool)
         .map(|sv| std::path::PathBuf::from(&sv.value));
 
     let project_root = sourced.project_root.clone();
+    let cache_root = sourced.resolve_project_root();
 
     // 5. Convert to Config for the rest of the linter
-    let config: rumdl_config::Config = sourced.into();
+    let config: rumdl_config::Config = sourced.clone().into();
+    // With `hierarchical = true`, nested config files apply to the files below them
+    let dir_configs = file_processor::DirectoryConfigs::new(&sourced, args);
+
+    if let Some(path) = &args.dump_effective {
+        dump_effective_config(&config, Path::new(path));
+    }
 
     // 6. Initialize cache if enabled
     // CLI --no-cache flag takes precedence over config
@@ -1646,7 +1937,7 @@ fn run_check(args: &CheckArgs, global_config_path: Option<&str>, isolated: bool)
     // If cache_dir is relative and we have a project root, resolve relative to project root
     // This ensures cache is created at project root, not CWD (fixes issue #159)
     if cache_dir.is_relative()
-        && let Some(ref root) = project_root
+        && let Some(ref root) = cache_root
     {
         cache_dir = root.join(&cache_dir);
     }
@@ -1672,6 +1963,10 @@ fn run_check(args: &CheckArgs, global_config_path: Option<&str>, isolated: bool)
     // Use the same cache directory for workspace index cache (when cache is enabled)
     let workspace_cache_dir = if cache_enabled { Some(cache_dir.as_path()) } else { None };
 
+    if args.check_links_online {
+        enable_link_checking(workspace_cache_dir, silent);
+    }
+
     let has_issues = watch::perform_check_run(
         args,
         &config,
@@ -1679,81 +1974,51 @@ fn run_check(args: &CheckArgs, global_config_path: Option<&str>, isolated: bool)
         cache,
         workspace_cache_dir,
         project_root.as_deref(),
+        dir_configs.as_ref(),
+        None,
     );
+    rumdl_lib::link_checker::save_cache();
     if has_issues && args.fix_mode != FixMode::Format {
         exit::violations_found();
     }
 }
 
+/// Write `config`, with every rule's defaults filled in, to `path` as TOML
+fn dump_effective_config(config: &rumdl_config::Config, path: &Path) {
+    let toml = match toml::to_string_pretty(&config.with_rule_defaults()) {
+        Ok(toml) => toml,
+        Err(e) => {
+            eprintln!("{}: Failed to serialize config to TOML: {}", "Error".red().bold(), e);
+            exit::tool_error();
+        }
+    };
+    if let Err(e) = fs::write(path, toml) {
+        eprintln!(
+            "{}: Failed to write effective config to '{}': {}",
+            "Error".red().bold(),
+            path.display(),
+            e
+        );
+        exit::tool_error();
+    }
+}
+
+/// Turn on online link checking for MD141, caching reachable links in `cache_dir`
+pub fn enable_link_checking(cache_dir: Option<&Path>, silent: bool) {
+    if let Err(e) = rumdl_lib::link_checker::enable(cache_dir)
+        && !silent
+    {
+        eprintln!(
+            "{}: --check-links-online needs curl, which could not be run: {}",
+            "Warning".yellow().bold(),
+            e
+        );
+    }
+}
+
 // Handle explain command
 fn handle_explain_command(rule_query: &str) {
-    use rumdl_lib::rules::*;
-
-    // Get all rules
-    let all_rules: Vec<Box<dyn Rule>> = vec![
-        Box::new(MD001HeadingIncrement),
-        Box::new(MD003HeadingStyle::default()),
-        Box::new(MD004UnorderedListStyle::new(UnorderedListStyle::Consistent)),
-        Box::new(MD005ListIndent::default()),
-        Box::new(MD007ULIndent::default()),
-        Box::new(MD009TrailingSpaces::default()),
-        Box::new(MD010NoHardTabs::default()),
-        Box::new(MD011NoReversedLinks {}),
-        Box::new(MD012NoMultipleBlanks::default()),
-        Box::new(MD013LineLength::default()),
-        Box::new(MD018NoMissingSpaceAtx {}),
-        Box::new(MD019NoMultipleSpaceAtx {}),
-        Box::new(MD020NoMissingSpaceClosedAtx {}),
-        Box::new(MD021NoMultipleSpaceClosedAtx {}),
-        Box::new(MD022BlanksAroundHeadings::default()),
-        Box::new(MD023HeadingStartLeft {}),
-        Box::new(MD024NoDuplicateHeading::default()),
-        Box::new(MD025SingleTitle::default()),
-        Box::new(MD026NoTrailingPunctuation::default()),
-        Box::new(MD027MultipleSpacesBlockquote {}),
-        Box::new(MD028NoBlanksBlockquote {}),
-        Box::new(MD029OrderedListPrefix::default()),
-        Box::new(MD030ListMarkerSpace::default()),
-        Box::new(MD031BlanksAroundFences::default()),
-        Box::new(MD032BlanksAroundLists),
-        Box::new(MD033NoInlineHtml::default()),
-        Box::new(MD034NoBareUrls {}),
-        Box::new(MD035HRStyle::default()),
-        Box::new(MD036NoEmphasisAsHeading::new(".,;:!?".to_string())),
-        Box::new(MD037NoSpaceInEmphasis),
-        Box::new(MD038NoSpaceInCode::default()),
-        Box::new(MD039NoSpaceInLinks),
-        Box::new(MD040FencedCodeLanguage {}),
-        Box::new(MD041FirstLineHeading::default()),
-        Box::new(MD042NoEmptyLinks::new()),
-        Box::new(MD043RequiredHeadings::new(Vec::new())),
-        Box::new(MD044ProperNames::new(Vec::new(), true)),
-        Box::new(MD045NoAltText::new()),
-        Box::new(MD046CodeBlockStyle::new(CodeBlockStyle::Consistent)),
-        Box::new(MD047SingleTrailingNewline),
-        Box::new(MD048CodeFenceStyle::new(CodeFenceStyle::Consistent)),
-        Box::new(MD049EmphasisStyle::default()),
-        Box::new(MD050StrongStyle::new(StrongStyle::Consistent)),
-        Box::new(MD051LinkFragments::new()),
-        Box::new(MD052ReferenceLinkImages::new()),
-        Box::new(MD053LinkImageReferenceDefinitions::default()),
-        Box::new(MD054LinkImageStyle::default()),
-        Box::new(MD055TablePipeStyle::default()),
-        Box::new(MD056TableColumnCount),
-        Box::new(MD057ExistingRelativeLinks::default()),
-        Box::new(MD058BlanksAroundTables::default()),
-        Box::new(MD059LinkText::default()),
-        Box::new(MD060TableFormat::default()),
-    ];
-
-    // Find the rule
-    let rule_query_upper = rule_query.to_ascii_uppercase();
-    let found = all_rules.iter().find(|r| {
-        r.name().eq_ignore_ascii_case(&rule_query_upper)
-            || r.name().replace("MD", "") == rule_query_upper.replace("MD", "")
-    });
-
-    if let Some(rule) = found {
+    if let Some(rule) = rumdl_lib::rules::rule_by_name(rule_query, &rumdl_config::Config::default()) {
         let rule_name = rule.name();
         let rule_id = rule_name.to_lowercase();
 
@@ -1821,6 +2086,74 @@ fn handle_explain_command(rule_query: &str) {
     }
 }
 
+/// Dump the `LintContext` built for `file` as JSON, for bug reports and rule authors
+fn handle_debug_parse_command(file: &str, flavor: Option<&str>) {
+    let path = Path::new(file);
+    let flavor = match flavor.map(str::parse::<rumdl_config::MarkdownFlavor>) {
+        Some(Ok(flavor)) => flavor,
+        Some(Err(e)) => {
+            eprintln!("{}: {}", "Error".red().bold(), e);
+            exit::tool_error();
+        }
+        None => rumdl_config::MarkdownFlavor::from_path(path),
+    };
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) => {
+            eprintln!("{}: Failed to read '{}': {}", "Error".red().bold(), file, e);
+            exit::tool_error();
+        }
+    };
+
+    let ctx = rumdl_lib::LintContext::new(&content, flavor, Some(path.to_path_buf()));
+    let lines: Vec<serde_json::Value> = ctx
+        .lines
+        .iter()
+        .enumerate()
+        .map(|(idx, info)| {
+            let mut line = serde_json::json!({ "line": idx + 1, "text": info.content(ctx.content) });
+            if let (Some(line), serde_json::Value::Object(flags)) =
+                (line.as_object_mut(), serde_json::to_value(info).unwrap_or_default())
+            {
+                line.extend(flags);
+            }
+            line
+        })
+        .collect();
+    let headings: Vec<serde_json::Value> = ctx
+        .lines
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, info)| {
+            let mut heading = serde_json::to_value(info.heading.as_ref()?).ok()?;
+            heading.as_object_mut()?.insert("line".to_string(), (idx + 1).into());
+            Some(heading)
+        })
+        .collect();
+
+    let output = serde_json::json!({
+        "file": file,
+        "flavor": flavor,
+        "headings": headings,
+        "links": ctx.links,
+        "images": ctx.images,
+        "reference_defs": ctx.reference_defs,
+        "footnote_refs": ctx.footnote_refs,
+        "code_blocks": ctx.code_blocks,
+        "code_spans": *ctx.code_spans(),
+        "list_blocks": ctx.list_blocks(),
+        "tables": ctx.table_blocks(),
+        "lines": lines,
+    });
+    match serde_json::to_string_pretty(&output) {
+        Ok(json) => println!("{json}"),
+        Err(e) => {
+            eprintln!("{}: Failed to serialize parse result: {}", "Error".red().bold(), e);
+            exit::tool_error();
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1912,6 +2245,14 @@ mod tests {
         assert_eq!(format_size(1024u64 * 1024 * 1024 * 2 + 512 * 1024 * 1024), "2.50 GB");
     }
 
+    #[test]
+    fn test_parse_rules_file() {
+        let (enable, disable) =
+            parse_rules_file("# Approved rules\nMD001, MD003\nMD013 MD022 # long lines\n\n-MD033\n- ,\n");
+        assert_eq!(enable, vec!["MD001", "MD003", "MD013", "MD022"]);
+        assert_eq!(disable, vec!["MD033"]);
+    }
+
     #[test]
     fn test_format_size_terabytes() {
         assert_eq!(format_size(1024u64 * 1024 * 1024 * 1024), "1.00 TB");
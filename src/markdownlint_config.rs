@@ -90,6 +90,47 @@ pub fn markdownlint_to_rumdl_rule_key(key: &str) -> Option<&'static str> {
         "MD059" | "TABLE-CELL-ALIGNMENT" => Some("MD059"),
         "MD060" | "TABLE-FORMAT" => Some("MD060"),
         "MD061" | "FORBIDDEN-TERMS" => Some("MD061"),
+        "MD063" | "NO-DUPLICATE-FOOTNOTES" | "MD901" => Some("MD063"),
+        "MD064" | "HEADING-ANCHOR-LINKS" => Some("MD064"),
+        "MD107" | "ABBREVIATION-DEFINITIONS" => Some("MD107"),
+        "MD108" | "INVISIBLE-CHARACTERS" => Some("MD108"),
+        "MD109" | "ADMONITION-TYPES" => Some("MD109"),
+        "MD110" | "TABLE-CELL-PADDING" => Some("MD110"),
+        "MD111" | "CLOSING-FENCE-CONTENT" => Some("MD111"),
+        "MD112" | "ATX-CLOSING-SEQUENCE" => Some("MD112"),
+        "MD113" | "SNIPPET-TARGETS" => Some("MD113"),
+        "MD114" | "MIXED-UP-LINK-SYNTAX" => Some("MD114"),
+        "MD115" | "SETEXT-UNDERLINE-LENGTH" => Some("MD115"),
+        "MD116" | "FRONT-MATTER-KEY-ORDER" => Some("MD116"),
+        "MD117" | "EMPTY-EMPHASIS" => Some("MD117"),
+        "MD118" | "LIST-FENCE-INDENT" => Some("MD118"),
+        "MD119" | "FENCED-CODE-BLOCKS" => Some("MD119"),
+        "MD120" | "REDUNDANT-HEADING-LABEL" => Some("MD120"),
+        "MD121" | "OL-MARKER-WIDTH" => Some("MD121"),
+        "MD122" | "BLOCKQUOTE-LAZY-CONTINUATION" => Some("MD122"),
+        "MD123" | "LINK-TITLE-QUOTES" => Some("MD123"),
+        "MD124" | "LINK-FORWARD-SLASHES" => Some("MD124"),
+        "MD125" | "CODE-BLOCK-TRAILING-SPACES" => Some("MD125"),
+        "MD126" | "HEADING-MATCHES-FILENAME" => Some("MD126"),
+        "MD127" | "HEADING-OUTLINE" => Some("MD127"),
+        "MD128" | "LIST-MARKER-SPACE-CONSISTENCY" => Some("MD128"),
+        "MD129" | "CODE-BLOCK-LANGUAGE-INFERENCE" => Some("MD129"),
+        "MD130" | "DOCUMENT-LENGTH" => Some("MD130"),
+        "MD131" | "EMPHASIS-NESTING" => Some("MD131"),
+        "MD132" | "TABLE-COLUMN-LIMIT" => Some("MD132"),
+        "MD133" | "HR-LIST-MARKER" => Some("MD133"),
+        "MD134" | "MDX-IMPORT-PATHS" => Some("MD134"),
+        "MD135" | "ATX-HEADING-SPACING" => Some("MD135"),
+        "MD136" | "REFERENCE-DEFINITION-URLS" => Some("MD136"),
+        "MD137" | "DISABLE-COMMENT-REASON" => Some("MD137"),
+        "MD138" | "HEADING-LINK" => Some("MD138"),
+        "MD139" | "SORTED-LISTS" => Some("MD139"),
+        "MD140" | "CODE-FENCE-LENGTH" => Some("MD140"),
+        "MD141" | "UNREACHABLE-LINKS" => Some("MD141"),
+        "MD142" | "CODE-IDENTIFIER-CASE" => Some("MD142"),
+        "MD143" | "LIST-ITEM-SPACING" => Some("MD143"),
+        "MD144" | "LIST-NESTING-DEPTH" => Some("MD144"),
+        "MD145" | "HEADING-ID-STYLE" => Some("MD145"),
         _ => None,
     }
 }
@@ -817,6 +858,47 @@ ul-style:
             ("table-cell-alignment", "MD059"),
             ("table-format", "MD060"),
             ("forbidden-terms", "MD061"),
+            ("no-duplicate-footnotes", "MD063"),
+            ("heading-anchor-links", "MD064"),
+            ("abbreviation-definitions", "MD107"),
+            ("invisible-characters", "MD108"),
+            ("admonition-types", "MD109"),
+            ("table-cell-padding", "MD110"),
+            ("closing-fence-content", "MD111"),
+            ("atx-closing-sequence", "MD112"),
+            ("snippet-targets", "MD113"),
+            ("mixed-up-link-syntax", "MD114"),
+            ("setext-underline-length", "MD115"),
+            ("front-matter-key-order", "MD116"),
+            ("empty-emphasis", "MD117"),
+            ("list-fence-indent", "MD118"),
+            ("fenced-code-blocks", "MD119"),
+            ("redundant-heading-label", "MD120"),
+            ("ol-marker-width", "MD121"),
+            ("blockquote-lazy-continuation", "MD122"),
+            ("link-title-quotes", "MD123"),
+            ("link-forward-slashes", "MD124"),
+            ("code-block-trailing-spaces", "MD125"),
+            ("heading-matches-filename", "MD126"),
+            ("heading-outline", "MD127"),
+            ("list-marker-space-consistency", "MD128"),
+            ("code-block-language-inference", "MD129"),
+            ("document-length", "MD130"),
+            ("emphasis-nesting", "MD131"),
+            ("table-column-limit", "MD132"),
+            ("hr-list-marker", "MD133"),
+            ("mdx-import-paths", "MD134"),
+            ("atx-heading-spacing", "MD135"),
+            ("reference-definition-urls", "MD136"),
+            ("disable-comment-reason", "MD137"),
+            ("heading-link", "MD138"),
+            ("sorted-lists", "MD139"),
+            ("code-fence-length", "MD140"),
+            ("unreachable-links", "MD141"),
+            ("code-identifier-case", "MD142"),
+            ("list-item-spacing", "MD143"),
+            ("list-nesting-depth", "MD144"),
+            ("heading-id-style", "MD145"),
         ];
 
         for (alias, expected) in aliases {
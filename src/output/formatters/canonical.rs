@@ -0,0 +1,170 @@
+//! Canonical output format for hashing and diffing a run's violations
+//!
+//! One compact JSON object per line, with a fixed key order, sorted by file, position,
+//! rule and message. Paths use forward slashes and are relative to the working directory
+//! where possible, and nothing depends on timing or the machine, so two runs over the
+//! same violations produce byte-identical output.
+
+use crate::output::OutputFormatter;
+use crate::rule::{LintWarning, Severity};
+use serde::Serialize;
+use std::path::Path;
+
+/// Canonical formatter: sorted JSON Lines with normalized paths
+pub struct CanonicalFormatter;
+
+impl Default for CanonicalFormatter {
+    fn default() -> Self {
+        Self
+    }
+}
+
+impl CanonicalFormatter {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl OutputFormatter for CanonicalFormatter {
+    fn format_warnings(&self, warnings: &[LintWarning], file_path: &str) -> String {
+        format_canonical_report(&[(file_path.to_string(), warnings.to_vec())])
+    }
+}
+
+/// One violation; the field order here is the key order in the output
+#[derive(Serialize, PartialEq, Eq, PartialOrd, Ord)]
+struct CanonicalEntry<'a> {
+    file: String,
+    line: usize,
+    column: usize,
+    end_line: usize,
+    end_column: usize,
+    rule: &'a str,
+    message: &'a str,
+    severity: &'static str,
+    fixable: bool,
+}
+
+/// Format all warnings from a run as canonical lines, relative to the working directory
+pub fn format_canonical_report(all_warnings: &[(String, Vec<LintWarning>)]) -> String {
+    let base = std::env::current_dir().ok();
+    format_canonical_report_from(all_warnings, base.as_deref())
+}
+
+/// Format all warnings from a run as canonical lines, with paths relative to `base`
+pub fn format_canonical_report_from(all_warnings: &[(String, Vec<LintWarning>)], base: Option<&Path>) -> String {
+    let mut entries: Vec<CanonicalEntry> = all_warnings
+        .iter()
+        .flat_map(|(file_path, warnings)| {
+            let file = normalize_path(file_path, base);
+            warnings.iter().map(move |warning| {
+                let (end_line, end_column) = super::json::span_end(warning);
+                CanonicalEntry {
+                    file: file.clone(),
+                    line: warning.line,
+                    column: warning.column,
+                    end_line,
+                    end_column,
+                    rule: warning.rule_name.as_deref().unwrap_or("unknown"),
+                    message: &warning.message,
+                    severity: match warning.severity {
+                        Severity::Error => "error",
+                        Severity::Warning => "warning",
+                    },
+                    fixable: warning.fix.is_some(),
+                }
+            })
+        })
+        .collect();
+    entries.sort();
+
+    entries
+        .iter()
+        .filter_map(|entry| serde_json::to_string(entry).ok())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Forward-slash path relative to `base` when it lies inside it, without a leading `./`
+pub fn normalize_path(file_path: &str, base: Option<&Path>) -> String {
+    let path = Path::new(file_path);
+    let relative = base.and_then(|base| path.strip_prefix(base).ok()).unwrap_or(path);
+
+    let normalized = relative.to_string_lossy().replace('\\', "/");
+    let mut normalized = normalized.as_str();
+    while let Some(rest) = normalized.strip_prefix("./") {
+        normalized = rest;
+    }
+    normalized.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rule::Fix;
+
+    fn warning(line: usize, rule: &str, message: &str, fixable: bool) -> LintWarning {
+        LintWarning {
+            line,
+            column: 1,
+            end_line: line,
+            end_column: 5,
+            rule_name: Some(rule.to_string()),
+            message: message.to_string(),
+            severity: Severity::Warning,
+            fix: fixable.then(|| Fix {
+                range: 0..1,
+                replacement: String::new(),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_sorted_and_stable() {
+        let run_a = vec![
+            ("./b.md".to_string(), vec![warning(2, "MD013", "Line too long", false)]),
+            (
+                "a.md".to_string(),
+                vec![
+                    warning(9, "MD009", "Trailing spaces", true),
+                    warning(3, "MD022", "Blank", false),
+                ],
+            ),
+        ];
+        let run_b = vec![
+            (
+                "a.md".to_string(),
+                vec![
+                    warning(3, "MD022", "Blank", false),
+                    warning(9, "MD009", "Trailing spaces", true),
+                ],
+            ),
+            ("b.md".to_string(), vec![warning(2, "MD013", "Line too long", false)]),
+        ];
+
+        let output = format_canonical_report_from(&run_a, None);
+        assert_eq!(output, format_canonical_report_from(&run_b, None));
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[0],
+            r#"{"file":"a.md","line":3,"column":1,"end_line":3,"end_column":5,"rule":"MD022","message":"Blank","severity":"warning","fixable":false}"#
+        );
+        assert!(lines[1].contains(r#""line":9"#) && lines[1].contains(r#""fixable":true"#));
+        assert!(lines[2].starts_with(r#"{"file":"b.md""#));
+    }
+
+    #[test]
+    fn test_normalize_path() {
+        let base = Path::new("/work/repo");
+        assert_eq!(normalize_path("/work/repo/docs/a.md", Some(base)), "docs/a.md");
+        assert_eq!(normalize_path("/elsewhere/a.md", Some(base)), "/elsewhere/a.md");
+        assert_eq!(normalize_path("././docs/a.md", Some(base)), "docs/a.md");
+        assert_eq!(normalize_path("docs\\a.md", None), "docs/a.md");
+    }
+
+    #[test]
+    fn test_empty_report() {
+        assert_eq!(format_canonical_report_from(&[], None), "");
+    }
+}
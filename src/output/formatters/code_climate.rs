@@ -0,0 +1,189 @@
+//! Code Climate JSON format, as consumed by GitLab's Code Quality widget
+//!
+//! Fingerprints hash the rule, the file path and the text of the flagged line rather
+//! than its line number, so an issue keeps its fingerprint when unrelated edits move
+//! it up or down the file and GitLab's MR diff only shows issues that really changed.
+
+use crate::output::OutputFormatter;
+use crate::rule::{LintWarning, Severity};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+/// Code Climate formatter
+/// Outputs an array of Code Climate issues for GitLab Code Quality reports
+pub struct CodeClimateFormatter;
+
+impl Default for CodeClimateFormatter {
+    fn default() -> Self {
+        Self
+    }
+}
+
+impl CodeClimateFormatter {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl OutputFormatter for CodeClimateFormatter {
+    fn format_warnings(&self, warnings: &[LintWarning], file_path: &str) -> String {
+        let content = std::fs::read_to_string(file_path).ok();
+        let issues = code_climate_issues(file_path, warnings, content.as_deref());
+        serde_json::to_string_pretty(&issues).unwrap_or_else(|_| "[]".to_string())
+    }
+}
+
+/// Format all warnings as a Code Climate report, reading each file for fingerprint context
+pub fn format_code_climate_report(all_warnings: &[(String, Vec<LintWarning>)]) -> String {
+    format_code_climate_report_with_sources(all_warnings, |path| std::fs::read_to_string(path).ok())
+}
+
+/// Format all warnings as a Code Climate report, with file contents supplied by `source`
+/// (for input that isn't on disk, such as stdin)
+pub fn format_code_climate_report_with_sources(
+    all_warnings: &[(String, Vec<LintWarning>)],
+    source: impl Fn(&str) -> Option<String>,
+) -> String {
+    let mut issues = Vec::new();
+    for (file_path, warnings) in all_warnings {
+        let content = source(file_path);
+        issues.extend(code_climate_issues(file_path, warnings, content.as_deref()));
+    }
+
+    serde_json::to_string_pretty(&issues).unwrap_or_else(|_| "[]".to_string())
+}
+
+/// Build the issues for one file
+fn code_climate_issues(file_path: &str, warnings: &[LintWarning], content: Option<&str>) -> Vec<Value> {
+    let lines: Vec<&str> = content.map(|c| c.lines().collect()).unwrap_or_default();
+    // Identical issues (same rule and line text) are told apart by their order in the file
+    let mut occurrences: HashMap<(&str, &str), usize> = HashMap::new();
+
+    warnings
+        .iter()
+        .map(|warning| {
+            let rule_name = warning.rule_name.as_deref().unwrap_or("unknown");
+            let context = lines
+                .get(warning.line.saturating_sub(1))
+                .map_or(warning.message.as_str(), |line| line.trim());
+            let occurrence = occurrences.entry((rule_name, context)).or_insert(0);
+            *occurrence += 1;
+
+            json!({
+                "type": "issue",
+                "check_name": rule_name,
+                "description": warning.message,
+                "categories": ["Style"],
+                "fingerprint": fingerprint(rule_name, file_path, context, *occurrence),
+                "severity": match warning.severity {
+                    Severity::Error => "major",
+                    Severity::Warning => "minor",
+                },
+                "location": {
+                    "path": file_path,
+                    "lines": {
+                        "begin": warning.line,
+                        "end": warning.end_line.max(warning.line)
+                    }
+                }
+            })
+        })
+        .collect()
+}
+
+/// Stable fingerprint from the rule, path and line text (never the line number)
+fn fingerprint(rule_name: &str, file_path: &str, context: &str, occurrence: usize) -> String {
+    let key = format!("{rule_name}\0{file_path}\0{context}\0{occurrence}");
+    blake3::hash(key.as_bytes()).to_hex().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn warning(line: usize, rule: &str, severity: Severity) -> LintWarning {
+        LintWarning {
+            line,
+            column: 1,
+            end_line: line,
+            end_column: 5,
+            rule_name: Some(rule.to_string()),
+            message: format!("{rule} message"),
+            severity,
+            fix: None,
+        }
+    }
+
+    fn report(content: &str, warnings: Vec<LintWarning>) -> Vec<Value> {
+        let all = vec![("docs/guide.md".to_string(), warnings)];
+        let output = format_code_climate_report_with_sources(&all, |_| Some(content.to_string()));
+        serde_json::from_str(&output).unwrap()
+    }
+
+    #[test]
+    fn test_issue_shape() {
+        let issues = report(
+            "# Title\nText\n",
+            vec![
+                warning(2, "MD013", Severity::Warning),
+                warning(1, "MD001", Severity::Error),
+            ],
+        );
+
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0]["type"], "issue");
+        assert_eq!(issues[0]["check_name"], "MD013");
+        assert_eq!(issues[0]["description"], "MD013 message");
+        assert_eq!(issues[0]["severity"], "minor");
+        assert_eq!(issues[0]["location"]["path"], "docs/guide.md");
+        assert_eq!(issues[0]["location"]["lines"]["begin"], 2);
+        assert_eq!(issues[0]["location"]["lines"]["end"], 2);
+        assert_eq!(issues[1]["severity"], "major");
+        assert_eq!(issues[0]["fingerprint"].as_str().unwrap().len(), 64);
+    }
+
+    #[test]
+    fn test_fingerprint_survives_line_moves() {
+        let before = report("# Title\nSome  text\n", vec![warning(2, "MD013", Severity::Warning)]);
+        let after = report(
+            "# Title\n\nNew paragraph\n\nSome  text\n",
+            vec![warning(5, "MD013", Severity::Warning)],
+        );
+        assert_eq!(before[0]["fingerprint"], after[0]["fingerprint"]);
+
+        let edited = report("# Title\nOther  text\n", vec![warning(2, "MD013", Severity::Warning)]);
+        assert_ne!(before[0]["fingerprint"], edited[0]["fingerprint"]);
+    }
+
+    #[test]
+    fn test_duplicate_lines_get_distinct_fingerprints() {
+        let issues = report(
+            "- item\n- item\n",
+            vec![
+                warning(1, "MD004", Severity::Warning),
+                warning(2, "MD004", Severity::Warning),
+            ],
+        );
+        assert_ne!(issues[0]["fingerprint"], issues[1]["fingerprint"]);
+
+        let other_rule = report("- item\n", vec![warning(1, "MD007", Severity::Warning)]);
+        assert_ne!(issues[0]["fingerprint"], other_rule[0]["fingerprint"]);
+    }
+
+    #[test]
+    fn test_missing_source_falls_back_to_message() {
+        let all = vec![("missing.md".to_string(), vec![warning(3, "MD009", Severity::Warning)])];
+        let output = format_code_climate_report_with_sources(&all, |_| None);
+        let issues: Vec<Value> = serde_json::from_str(&output).unwrap();
+        assert_eq!(
+            issues[0]["fingerprint"],
+            fingerprint("MD009", "missing.md", "MD009 message", 1)
+        );
+    }
+
+    #[test]
+    fn test_empty_report() {
+        assert_eq!(format_code_climate_report(&[]), "[]");
+        assert_eq!(CodeClimateFormatter::new().format_warnings(&[], "test.md"), "[]");
+    }
+}
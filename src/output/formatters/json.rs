@@ -32,10 +32,13 @@ impl OutputFormatter for JsonFormatter {
         let json_warnings: Vec<Value> = warnings
             .iter()
             .map(|warning| {
+                let (end_line, end_column) = span_end(warning);
                 json!({
                     "file": file_path,
                     "line": warning.line,
                     "column": warning.column,
+                    "end_line": end_line,
+                    "end_column": end_column,
                     "rule": warning.rule_name.as_deref().unwrap_or("unknown"),
                     "message": warning.message,
                     "severity": "warning",
@@ -57,16 +60,28 @@ impl OutputFormatter for JsonFormatter {
     }
 }
 
+/// End of the span a warning covers, falling back to its start when no end was recorded
+pub(crate) fn span_end(warning: &LintWarning) -> (usize, usize) {
+    if (warning.end_line, warning.end_column) < (warning.line, warning.column) {
+        (warning.line, warning.column)
+    } else {
+        (warning.end_line, warning.end_column)
+    }
+}
+
 /// Helper to format all warnings from multiple files as a single JSON document
 pub fn format_all_warnings_as_json(all_warnings: &[(String, Vec<LintWarning>)]) -> String {
     let mut json_warnings = Vec::new();
 
     for (file_path, warnings) in all_warnings {
         for warning in warnings {
+            let (end_line, end_column) = span_end(warning);
             json_warnings.push(json!({
                 "file": file_path,
                 "line": warning.line,
                 "column": warning.column,
+                "end_line": end_line,
+                "end_column": end_column,
                 "rule": warning.rule_name.as_deref().unwrap_or("unknown"),
                 "message": warning.message,
                 "severity": "warning",
@@ -158,6 +173,8 @@ mod tests {
         assert_eq!(parsed[0]["file"], "README.md");
         assert_eq!(parsed[0]["line"], 10);
         assert_eq!(parsed[0]["column"], 5);
+        assert_eq!(parsed[0]["end_line"], 10);
+        assert_eq!(parsed[0]["end_column"], 15);
         assert_eq!(parsed[0]["rule"], "MD001");
         assert_eq!(
             parsed[0]["message"],
@@ -406,4 +423,23 @@ mod tests {
                 .contains("Multi\nline\nreplacement")
         );
     }
+
+    #[test]
+    fn test_span_end_falls_back_to_start() {
+        let warnings = vec![LintWarning {
+            line: 3,
+            column: 7,
+            end_line: 0,
+            end_column: 0,
+            rule_name: Some("MD001".to_string()),
+            message: "Test".to_string(),
+            severity: Severity::Warning,
+            fix: None,
+        }];
+
+        let output = format_all_warnings_as_json(&[("test.md".to_string(), warnings)]);
+        let parsed: Vec<Value> = serde_json::from_str(&output).unwrap();
+        assert_eq!(parsed[0]["end_line"], 3);
+        assert_eq!(parsed[0]["end_column"], 7);
+    }
 }
@@ -1,6 +1,7 @@
 //! JSON Lines output formatter (one JSON object per line)
 
 use crate::output::OutputFormatter;
+use crate::output::formatters::json::span_end;
 use crate::rule::LintWarning;
 use serde_json::json;
 
@@ -24,10 +25,13 @@ impl OutputFormatter for JsonLinesFormatter {
         let mut output = String::new();
 
         for warning in warnings {
+            let (end_line, end_column) = span_end(warning);
             let json_obj = json!({
                 "file": file_path,
                 "line": warning.line,
                 "column": warning.column,
+                "end_line": end_line,
+                "end_column": end_column,
                 "rule": warning.rule_name.as_deref().unwrap_or("unknown"),
                 "message": warning.message,
                 "severity": "warning",
@@ -97,6 +101,8 @@ mod tests {
         assert_eq!(json["file"], "README.md");
         assert_eq!(json["line"], 10);
         assert_eq!(json["column"], 5);
+        assert_eq!(json["end_line"], 10);
+        assert_eq!(json["end_column"], 15);
         assert_eq!(json["rule"], "MD001");
         assert_eq!(
             json["message"],
@@ -368,6 +374,8 @@ mod tests {
         assert!(json.get("file").is_some());
         assert!(json.get("line").is_some());
         assert!(json.get("column").is_some());
+        assert!(json.get("end_line").is_some());
+        assert!(json.get("end_column").is_some());
         assert!(json.get("rule").is_some());
         assert!(json.get("message").is_some());
         assert!(json.get("severity").is_some());
@@ -1,7 +1,9 @@
 //! JUnit XML output format
 
 use crate::output::OutputFormatter;
-use crate::rule::LintWarning;
+use crate::rule::{LintWarning, Rule};
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
 
 /// JUnit XML formatter for CI systems
 pub struct JunitFormatter;
@@ -65,54 +67,97 @@ impl OutputFormatter for JunitFormatter {
     }
 }
 
+/// How warnings are mapped onto JUnit `<testcase>` elements
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JunitGranularity {
+    /// One testcase per file, with every violation as a failure (default)
+    #[default]
+    File,
+    /// One testcase per (file, rule) pair, with that rule's violations as failures
+    Rule,
+    /// One testcase per violation
+    Violation,
+}
+
+impl FromStr for JunitGranularity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "file" => Ok(JunitGranularity::File),
+            "rule" => Ok(JunitGranularity::Rule),
+            "violation" => Ok(JunitGranularity::Violation),
+            _ => Err(format!(
+                "Invalid JUnit granularity: {s}. Valid values: file, rule, violation"
+            )),
+        }
+    }
+}
+
 /// Format all warnings as JUnit XML report
 pub fn format_junit_report(all_warnings: &[(String, Vec<LintWarning>)], duration_ms: u64) -> String {
+    format_junit_report_with_granularity(all_warnings, duration_ms, JunitGranularity::File, &HashMap::new())
+}
+
+/// Format all warnings as JUnit XML report with the given testcase granularity
+///
+/// `rule_descriptions` maps rule names to their descriptions; when a rule is present,
+/// its description is included in the failure message.
+pub fn format_junit_report_with_granularity(
+    all_warnings: &[(String, Vec<LintWarning>)],
+    duration_ms: u64,
+    granularity: JunitGranularity,
+    rule_descriptions: &HashMap<String, String>,
+) -> String {
     let mut xml = String::new();
     xml.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
     xml.push('\n');
 
+    // Build the testcases of every file up front so the totals are known
+    let suites: Vec<(&String, Vec<Testcase>)> = all_warnings
+        .iter()
+        .map(|(file_path, warnings)| (file_path, build_testcases(file_path, warnings, granularity)))
+        .collect();
+
     // Count total issues
     let total_issues: usize = all_warnings.iter().map(|(_, w)| w.len()).sum();
-    let files_with_issues = all_warnings.len();
+    let total_tests: usize = suites.iter().map(|(_, cases)| cases.len()).sum();
 
     // Convert duration to seconds
     let duration_secs = duration_ms as f64 / 1000.0;
 
     xml.push_str(&format!(
-        r#"<testsuites name="rumdl" tests="{files_with_issues}" failures="{total_issues}" errors="0" time="{duration_secs:.3}">"#
+        r#"<testsuites name="rumdl" tests="{total_tests}" failures="{total_issues}" errors="0" time="{duration_secs:.3}">"#
     ));
     xml.push('\n');
 
-    // Group warnings by file
-    for (file_path, warnings) in all_warnings {
+    for (file_path, testcases) in suites {
         let escaped_file = xml_escape(file_path);
+        let failures: usize = testcases.iter().map(|case| case.warnings.len()).sum();
 
         xml.push_str(&format!(
-            r#"  <testsuite name="{}" tests="1" failures="{}" errors="0" time="0.000">"#,
+            r#"  <testsuite name="{}" tests="{}" failures="{}" errors="0" time="0.000">"#,
             escaped_file,
-            warnings.len()
-        ));
-        xml.push('\n');
-
-        // Create a test case for the file
-        xml.push_str(&format!(
-            r#"    <testcase name="Lint {escaped_file}" classname="rumdl" time="0.000">"#
+            testcases.len(),
+            failures
         ));
         xml.push('\n');
 
-        // Add failures for each warning
-        for warning in warnings {
-            let rule_name = warning.rule_name.as_deref().unwrap_or("unknown");
-            let message = xml_escape(&warning.message);
-
+        for testcase in testcases {
             xml.push_str(&format!(
-                r#"      <failure type="{}" message="{}">{} at line {}, column {}</failure>"#,
-                rule_name, message, message, warning.line, warning.column
+                r#"    <testcase name="{}" classname="{}" time="0.000">"#,
+                xml_escape(&testcase.name),
+                xml_escape(&testcase.classname)
             ));
             xml.push('\n');
+
+            for warning in testcase.warnings {
+                push_failure(&mut xml, warning, rule_descriptions);
+            }
+
+            xml.push_str("    </testcase>\n");
         }
 
-        xml.push_str("    </testcase>\n");
         xml.push_str("  </testsuite>\n");
     }
 
@@ -120,6 +165,79 @@ pub fn format_junit_report(all_warnings: &[(String, Vec<LintWarning>)], duration
     xml
 }
 
+/// A JUnit testcase and the warnings reported as its failures
+struct Testcase<'a> {
+    name: String,
+    classname: String,
+    warnings: Vec<&'a LintWarning>,
+}
+
+/// Collect rule descriptions for use in failure messages
+pub fn rule_descriptions(rules: &[Box<dyn Rule>]) -> HashMap<String, String> {
+    rules
+        .iter()
+        .map(|rule| (rule.name().to_string(), rule.description().to_string()))
+        .collect()
+}
+
+fn build_testcases<'a>(
+    file_path: &str,
+    warnings: &'a [LintWarning],
+    granularity: JunitGranularity,
+) -> Vec<Testcase<'a>> {
+    match granularity {
+        JunitGranularity::File => vec![Testcase {
+            name: format!("Lint {file_path}"),
+            classname: "rumdl".to_string(),
+            warnings: warnings.iter().collect(),
+        }],
+        JunitGranularity::Rule => {
+            let mut by_rule: BTreeMap<&str, Vec<&LintWarning>> = BTreeMap::new();
+            for warning in warnings {
+                by_rule
+                    .entry(warning.rule_name.as_deref().unwrap_or("unknown"))
+                    .or_default()
+                    .push(warning);
+            }
+            by_rule
+                .into_iter()
+                .map(|(rule_name, warnings)| Testcase {
+                    name: rule_name.to_string(),
+                    classname: file_path.to_string(),
+                    warnings,
+                })
+                .collect()
+        }
+        JunitGranularity::Violation => warnings
+            .iter()
+            .map(|warning| Testcase {
+                name: format!(
+                    "{} at line {}, column {}",
+                    warning.rule_name.as_deref().unwrap_or("unknown"),
+                    warning.line,
+                    warning.column
+                ),
+                classname: file_path.to_string(),
+                warnings: vec![warning],
+            })
+            .collect(),
+    }
+}
+
+fn push_failure(xml: &mut String, warning: &LintWarning, rule_descriptions: &HashMap<String, String>) {
+    let rule_name = warning.rule_name.as_deref().unwrap_or("unknown");
+    let message = match rule_descriptions.get(rule_name) {
+        Some(description) => xml_escape(&format!("{rule_name} ({description}): {}", warning.message)),
+        None => xml_escape(&warning.message),
+    };
+
+    xml.push_str(&format!(
+        r#"      <failure type="{}" message="{}">{} at line {}, column {}</failure>"#,
+        rule_name, message, message, warning.line, warning.column
+    ));
+    xml.push('\n');
+}
+
 /// Escape special XML characters
 fn xml_escape(s: &str) -> String {
     s.replace('&', "&amp;")
@@ -453,4 +571,78 @@ mod tests {
         let output3 = format_junit_report(&warnings, 12345);
         assert!(output3.contains("time=\"12.345\""));
     }
+
+    fn granularity_fixture() -> Vec<(String, Vec<LintWarning>)> {
+        let warning = |line: usize, rule: &str, message: &str| LintWarning {
+            line,
+            column: 1,
+            end_line: line,
+            end_column: 5,
+            rule_name: Some(rule.to_string()),
+            message: message.to_string(),
+            severity: Severity::Warning,
+            fix: None,
+        };
+        vec![(
+            "docs/a.md".to_string(),
+            vec![
+                warning(3, "MD022", "Missing blank line"),
+                warning(1, "MD013", "Line too long"),
+                warning(9, "MD022", "Missing blank line"),
+            ],
+        )]
+    }
+
+    #[test]
+    fn test_granularity_from_str() {
+        assert_eq!("file".parse::<JunitGranularity>().unwrap(), JunitGranularity::File);
+        assert_eq!("Rule".parse::<JunitGranularity>().unwrap(), JunitGranularity::Rule);
+        assert_eq!(
+            "violation".parse::<JunitGranularity>().unwrap(),
+            JunitGranularity::Violation
+        );
+        assert!("suite".parse::<JunitGranularity>().is_err());
+    }
+
+    #[test]
+    fn test_rule_granularity() {
+        let output =
+            format_junit_report_with_granularity(&granularity_fixture(), 0, JunitGranularity::Rule, &HashMap::new());
+
+        assert!(output.contains("<testsuites name=\"rumdl\" tests=\"2\" failures=\"3\""));
+        assert!(output.contains("<testsuite name=\"docs/a.md\" tests=\"2\" failures=\"3\""));
+        // Rules are sorted by name
+        let md013 = output.find("<testcase name=\"MD013\" classname=\"docs/a.md\"").unwrap();
+        let md022 = output.find("<testcase name=\"MD022\" classname=\"docs/a.md\"").unwrap();
+        assert!(md013 < md022);
+        assert_eq!(output[md022..].matches("<failure type=\"MD022\"").count(), 2);
+    }
+
+    #[test]
+    fn test_violation_granularity() {
+        let output = format_junit_report_with_granularity(
+            &granularity_fixture(),
+            0,
+            JunitGranularity::Violation,
+            &HashMap::new(),
+        );
+
+        assert!(output.contains("<testsuite name=\"docs/a.md\" tests=\"3\" failures=\"3\""));
+        assert!(output.contains("<testcase name=\"MD022 at line 3, column 1\" classname=\"docs/a.md\""));
+        assert!(output.contains("<testcase name=\"MD013 at line 1, column 1\" classname=\"docs/a.md\""));
+        assert_eq!(output.matches("</testcase>").count(), 3);
+    }
+
+    #[test]
+    fn test_failure_includes_rule_description() {
+        let descriptions = HashMap::from([("MD013".to_string(), "Line length should not be excessive".to_string())]);
+        let output =
+            format_junit_report_with_granularity(&granularity_fixture(), 0, JunitGranularity::File, &descriptions);
+
+        assert!(output.contains(
+            "<failure type=\"MD013\" message=\"MD013 (Line length should not be excessive): Line too long\">"
+        ));
+        // Rules without a known description keep the plain message
+        assert!(output.contains("<failure type=\"MD022\" message=\"Missing blank line\">"));
+    }
 }
@@ -1,6 +1,8 @@
 //! Output formatter implementations
 
 pub mod azure;
+pub mod canonical;
+pub mod code_climate;
 pub mod concise;
 pub mod github;
 pub mod gitlab;
@@ -9,10 +11,13 @@ pub mod json;
 pub mod json_lines;
 pub mod junit;
 pub mod pylint;
+pub mod rdjson;
 pub mod sarif;
 pub mod text;
 
 pub use azure::AzureFormatter;
+pub use canonical::CanonicalFormatter;
+pub use code_climate::CodeClimateFormatter;
 pub use concise::ConciseFormatter;
 pub use github::GitHubFormatter;
 pub use gitlab::GitLabFormatter;
@@ -21,5 +26,6 @@ pub use json::JsonFormatter;
 pub use json_lines::JsonLinesFormatter;
 pub use junit::JunitFormatter;
 pub use pylint::PylintFormatter;
+pub use rdjson::RdjsonFormatter;
 pub use sarif::SarifFormatter;
 pub use text::TextFormatter;
@@ -0,0 +1,188 @@
+//! Reviewdog Diagnostic Format (rdjson), as read by `reviewdog -f=rdjson`
+//!
+//! Fixes become `suggestions`, which reviewdog can post as suggested changes. A fix is a
+//! byte range of the file, so the file's text is needed to turn it into lines and columns;
+//! when the text isn't available the diagnostic is reported without suggestions.
+
+use crate::output::OutputFormatter;
+use crate::rule::{LintWarning, Severity};
+use serde_json::{Value, json};
+
+/// Reviewdog rdjson formatter
+/// Outputs a single `DiagnosticResult` with one diagnostic per warning
+pub struct RdjsonFormatter;
+
+impl Default for RdjsonFormatter {
+    fn default() -> Self {
+        Self
+    }
+}
+
+impl RdjsonFormatter {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl OutputFormatter for RdjsonFormatter {
+    fn format_warnings(&self, warnings: &[LintWarning], file_path: &str) -> String {
+        let content = std::fs::read_to_string(file_path).ok();
+        diagnostic_result(rdjson_diagnostics(file_path, warnings, content.as_deref()))
+    }
+}
+
+/// Format all warnings as one rdjson `DiagnosticResult`, reading each file for fix positions
+pub fn format_rdjson_report(all_warnings: &[(String, Vec<LintWarning>)]) -> String {
+    format_rdjson_report_with_sources(all_warnings, |path| std::fs::read_to_string(path).ok())
+}
+
+/// Format all warnings as one rdjson `DiagnosticResult`, with file contents supplied by
+/// `source` (for input that isn't on disk, such as stdin)
+pub fn format_rdjson_report_with_sources(
+    all_warnings: &[(String, Vec<LintWarning>)],
+    source: impl Fn(&str) -> Option<String>,
+) -> String {
+    let mut diagnostics = Vec::new();
+    for (file_path, warnings) in all_warnings {
+        let content = warnings
+            .iter()
+            .any(|w| w.fix.is_some())
+            .then(|| source(file_path))
+            .flatten();
+        diagnostics.extend(rdjson_diagnostics(file_path, warnings, content.as_deref()));
+    }
+
+    diagnostic_result(diagnostics)
+}
+
+fn diagnostic_result(diagnostics: Vec<Value>) -> String {
+    let result = json!({
+        "source": {
+            "name": "rumdl",
+            "url": "https://github.com/rvben/rumdl"
+        },
+        "diagnostics": diagnostics
+    });
+    serde_json::to_string_pretty(&result).unwrap_or_else(|_| r#"{"diagnostics":[]}"#.to_string())
+}
+
+/// Build the diagnostics for one file
+fn rdjson_diagnostics(file_path: &str, warnings: &[LintWarning], content: Option<&str>) -> Vec<Value> {
+    warnings
+        .iter()
+        .map(|warning| {
+            let mut diagnostic = json!({
+                "message": warning.message,
+                "location": {
+                    "path": file_path,
+                    "range": {
+                        "start": { "line": warning.line, "column": warning.column },
+                        "end": {
+                            "line": warning.end_line.max(warning.line),
+                            "column": warning.end_column
+                        }
+                    }
+                },
+                "severity": match warning.severity {
+                    Severity::Error => "ERROR",
+                    Severity::Warning => "WARNING",
+                },
+                "code": { "value": warning.rule_name.as_deref().unwrap_or("unknown") }
+            });
+
+            if let (Some(fix), Some(content)) = (&warning.fix, content)
+                && let (Some(start), Some(end)) = (position(content, fix.range.start), position(content, fix.range.end))
+            {
+                diagnostic["suggestions"] = json!([{
+                    "range": { "start": start, "end": end },
+                    "text": fix.replacement
+                }]);
+            }
+
+            diagnostic
+        })
+        .collect()
+}
+
+/// Line and column of a byte offset; rdjson columns count UTF-8 bytes, starting at 1
+fn position(content: &str, offset: usize) -> Option<Value> {
+    let before = content.get(..offset)?;
+    let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+    Some(json!({
+        "line": before.matches('\n').count() + 1,
+        "column": offset - line_start + 1
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rule::Fix;
+
+    fn warning(fix: Option<Fix>) -> LintWarning {
+        LintWarning {
+            line: 2,
+            column: 5,
+            end_line: 2,
+            end_column: 8,
+            rule_name: Some("MD009".to_string()),
+            message: "3 trailing spaces found".to_string(),
+            severity: Severity::Warning,
+            fix,
+        }
+    }
+
+    fn report(warnings: Vec<LintWarning>, content: Option<&str>) -> Value {
+        let all = vec![("docs/guide.md".to_string(), warnings)];
+        let output = format_rdjson_report_with_sources(&all, |_| content.map(str::to_string));
+        serde_json::from_str(&output).unwrap()
+    }
+
+    #[test]
+    fn test_diagnostic_without_fix() {
+        let result = report(vec![warning(None)], Some("# Title\nText   \n"));
+
+        assert_eq!(result["source"]["name"], "rumdl");
+        let diagnostic = &result["diagnostics"][0];
+        assert_eq!(diagnostic["message"], "3 trailing spaces found");
+        assert_eq!(diagnostic["severity"], "WARNING");
+        assert_eq!(diagnostic["code"]["value"], "MD009");
+        assert_eq!(diagnostic["location"]["path"], "docs/guide.md");
+        assert_eq!(
+            diagnostic["location"]["range"],
+            json!({ "start": { "line": 2, "column": 5 }, "end": { "line": 2, "column": 8 } })
+        );
+        assert!(diagnostic.get("suggestions").is_none());
+    }
+
+    #[test]
+    fn test_diagnostic_with_fix() {
+        let fix = Fix {
+            range: 12..15,
+            replacement: String::new(),
+        };
+        let result = report(vec![warning(Some(fix))], Some("# Title\nText   \n"));
+
+        assert_eq!(
+            result["diagnostics"][0]["suggestions"],
+            json!([{
+                "range": { "start": { "line": 2, "column": 5 }, "end": { "line": 2, "column": 8 } },
+                "text": ""
+            }])
+        );
+
+        // Without the file's text there is no way to place the fix
+        let fix = Fix {
+            range: 12..15,
+            replacement: String::new(),
+        };
+        let result = report(vec![warning(Some(fix))], None);
+        assert!(result["diagnostics"][0].get("suggestions").is_none());
+    }
+
+    #[test]
+    fn test_empty_report() {
+        let result: Value = serde_json::from_str(&format_rdjson_report(&[])).unwrap();
+        assert_eq!(result["diagnostics"], json!([]));
+    }
+}
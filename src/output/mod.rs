@@ -46,14 +46,20 @@ pub enum OutputFormat {
     GitHub,
     /// GitLab Code Quality format
     GitLab,
+    /// Code Climate JSON with content-based fingerprints (GitLab Code Quality)
+    CodeClimate,
     /// Pylint-compatible format: file:line:column: CODE message
     Pylint,
     /// Azure Pipeline logging format
     Azure,
     /// SARIF 2.1.0 format
     Sarif,
+    /// Reviewdog Diagnostic Format (rdjson), with fixes as suggestions
+    Rdjson,
     /// JUnit XML format
     Junit,
+    /// Sorted JSON Lines with normalized paths, stable across runs and machines
+    Canonical,
 }
 
 impl FromStr for OutputFormat {
@@ -68,10 +74,13 @@ impl FromStr for OutputFormat {
             "json-lines" | "jsonlines" => Ok(OutputFormat::JsonLines),
             "github" => Ok(OutputFormat::GitHub),
             "gitlab" => Ok(OutputFormat::GitLab),
+            "code-climate" | "codeclimate" => Ok(OutputFormat::CodeClimate),
             "pylint" => Ok(OutputFormat::Pylint),
             "azure" => Ok(OutputFormat::Azure),
             "sarif" => Ok(OutputFormat::Sarif),
+            "rdjson" => Ok(OutputFormat::Rdjson),
             "junit" => Ok(OutputFormat::Junit),
+            "canonical" => Ok(OutputFormat::Canonical),
             _ => Err(format!("Unknown output format: {s}")),
         }
     }
@@ -88,12 +97,66 @@ impl OutputFormat {
             OutputFormat::JsonLines => Box::new(JsonLinesFormatter::new()),
             OutputFormat::GitHub => Box::new(GitHubFormatter::new()),
             OutputFormat::GitLab => Box::new(GitLabFormatter::new()),
+            OutputFormat::CodeClimate => Box::new(CodeClimateFormatter::new()),
             OutputFormat::Pylint => Box::new(PylintFormatter::new()),
             OutputFormat::Azure => Box::new(AzureFormatter::new()),
             OutputFormat::Sarif => Box::new(SarifFormatter::new()),
+            OutputFormat::Rdjson => Box::new(RdjsonFormatter::new()),
             OutputFormat::Junit => Box::new(JunitFormatter::new()),
+            OutputFormat::Canonical => Box::new(CanonicalFormatter::new()),
         }
     }
+
+    /// Whether the format is a flat list of warnings that `--sort-by` can reorder
+    /// (grouped, SARIF, JUnit and the Code Quality reports keep their own structure)
+    pub fn supports_sorting(&self) -> bool {
+        matches!(
+            self,
+            OutputFormat::Text
+                | OutputFormat::Concise
+                | OutputFormat::Json
+                | OutputFormat::JsonLines
+                | OutputFormat::GitHub
+                | OutputFormat::Pylint
+                | OutputFormat::Azure
+        )
+    }
+}
+
+/// Global ordering of warnings across all files
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortBy {
+    /// By rule, then file, then position
+    Rule,
+    /// By file, then position
+    File,
+    /// Errors before warnings, then by file and position
+    Severity,
+}
+
+impl FromStr for SortBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "rule" => Ok(SortBy::Rule),
+            "file" => Ok(SortBy::File),
+            "severity" => Ok(SortBy::Severity),
+            _ => Err(format!("Unknown sort order: {s}")),
+        }
+    }
+}
+
+/// Sort `(file, warning)` pairs from a whole run into the given order
+pub fn sort_warnings(warnings: &mut [(String, LintWarning)], sort_by: SortBy) {
+    use crate::rule::Severity;
+
+    let position = |(file, warning): &(String, LintWarning)| (file.clone(), warning.line, warning.column);
+    match sort_by {
+        SortBy::Rule => warnings.sort_by_cached_key(|entry| (entry.1.rule_name.clone(), position(entry))),
+        SortBy::File => warnings.sort_by_cached_key(position),
+        SortBy::Severity => warnings.sort_by_cached_key(|entry| (entry.1.severity != Severity::Error, position(entry))),
+    }
 }
 
 /// Output writer that handles stdout/stderr routing
@@ -199,10 +262,20 @@ mod tests {
         assert_eq!(OutputFormat::from_str("jsonlines").unwrap(), OutputFormat::JsonLines);
         assert_eq!(OutputFormat::from_str("github").unwrap(), OutputFormat::GitHub);
         assert_eq!(OutputFormat::from_str("gitlab").unwrap(), OutputFormat::GitLab);
+        assert_eq!(
+            OutputFormat::from_str("code-climate").unwrap(),
+            OutputFormat::CodeClimate
+        );
+        assert_eq!(
+            OutputFormat::from_str("codeclimate").unwrap(),
+            OutputFormat::CodeClimate
+        );
         assert_eq!(OutputFormat::from_str("pylint").unwrap(), OutputFormat::Pylint);
         assert_eq!(OutputFormat::from_str("azure").unwrap(), OutputFormat::Azure);
         assert_eq!(OutputFormat::from_str("sarif").unwrap(), OutputFormat::Sarif);
+        assert_eq!(OutputFormat::from_str("rdjson").unwrap(), OutputFormat::Rdjson);
         assert_eq!(OutputFormat::from_str("junit").unwrap(), OutputFormat::Junit);
+        assert_eq!(OutputFormat::from_str("canonical").unwrap(), OutputFormat::Canonical);
 
         // Case insensitive
         assert_eq!(OutputFormat::from_str("TEXT").unwrap(), OutputFormat::Text);
@@ -226,10 +299,13 @@ mod tests {
             OutputFormat::JsonLines,
             OutputFormat::GitHub,
             OutputFormat::GitLab,
+            OutputFormat::CodeClimate,
             OutputFormat::Pylint,
             OutputFormat::Azure,
             OutputFormat::Sarif,
+            OutputFormat::Rdjson,
             OutputFormat::Junit,
+            OutputFormat::Canonical,
         ];
 
         for format in &formats {
@@ -241,6 +317,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_sort_warnings() {
+        let warning = |line: usize, rule: &str, severity: Severity| LintWarning {
+            rule_name: Some(rule.to_string()),
+            severity,
+            ..create_test_warning(line, "Test")
+        };
+        let order = |sort_by: SortBy| {
+            let mut warnings = vec![
+                ("b.md".to_string(), warning(1, "MD013", Severity::Warning)),
+                ("a.md".to_string(), warning(5, "MD009", Severity::Warning)),
+                ("a.md".to_string(), warning(2, "MD013", Severity::Error)),
+            ];
+            sort_warnings(&mut warnings, sort_by);
+            warnings
+                .iter()
+                .map(|(file, w)| format!("{file}:{}", w.line))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(order(SortBy::Rule), ["a.md:5", "a.md:2", "b.md:1"]);
+        assert_eq!(order(SortBy::File), ["a.md:2", "a.md:5", "b.md:1"]);
+        assert_eq!(order(SortBy::Severity), ["a.md:2", "a.md:5", "b.md:1"]);
+        assert_eq!(SortBy::from_str("Rule").unwrap(), SortBy::Rule);
+        assert!(SortBy::from_str("column").is_err());
+    }
+
     #[test]
     fn test_output_writer_new() {
         let writer1 = OutputWriter::new(false, false, false);
@@ -409,10 +512,13 @@ mod tests {
             OutputFormat::JsonLines,
             OutputFormat::GitHub,
             OutputFormat::GitLab,
+            OutputFormat::CodeClimate,
             OutputFormat::Pylint,
             OutputFormat::Azure,
             OutputFormat::Sarif,
+            OutputFormat::Rdjson,
             OutputFormat::Junit,
+            OutputFormat::Canonical,
         ];
 
         for format in &formats {
@@ -8,6 +8,7 @@ use std::ops::Range;
 use thiserror::Error;
 
 // Import document structure
+use crate::config::MarkdownFlavor;
 use crate::lint_context::LintContext;
 
 // Macro to implement box_clone for Rule implementors
@@ -56,7 +57,9 @@ pub struct Fix {
 
 #[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
 pub enum Severity {
+    #[serde(alias = "error")]
     Error,
+    #[serde(alias = "warning")]
     Warning,
 }
 
@@ -113,6 +116,14 @@ pub trait Rule: DynClone + Send + Sync {
         false
     }
 
+    /// Whether this rule applies to documents of the given Markdown flavor
+    ///
+    /// Flavor-specific rules (e.g. MkDocs admonition checks) override this so the lint
+    /// loop skips them for other flavors without users having to disable them.
+    fn applies_to_flavor(&self, _flavor: MarkdownFlavor) -> bool {
+        true
+    }
+
     /// Get the category of this rule for selective processing
     fn category(&self) -> RuleCategory {
         RuleCategory::Other // Default implementation returns Other
@@ -152,6 +163,23 @@ pub trait Rule: DynClone + Send + Sync {
         CrossFileScope::None
     }
 
+    /// Whether this rule's single-file results can be reused from the lint cache
+    ///
+    /// The cache is keyed on the file's own content, so rules whose results also depend
+    /// on the filesystem (like MD057 checking that link targets exist) return `false`.
+    /// On a cache hit they are re-run while the other rules' results are reused.
+    fn cacheable(&self) -> bool {
+        true
+    }
+
+    /// Whether inline comments that disable all rules also silence this rule
+    ///
+    /// Rules that audit those comments return `false`, so a comment can't hide its own
+    /// warning. A comment naming the rule still disables it.
+    fn honors_blanket_disable(&self) -> bool {
+        true
+    }
+
     /// Contribute data to the workspace index during linting
     ///
     /// Called during the single-file linting phase for rules that return
@@ -91,7 +91,7 @@ impl MD004UnorderedListStyle {
         let mut dash_count = 0;
         let mut plus_count = 0;
 
-        for list_block in &ctx.list_blocks {
+        for list_block in ctx.list_blocks() {
             for &item_line in &list_block.item_lines {
                 if let Some(line_info) = ctx.line_info(item_line)
                     && let Some(list_item) = &line_info.list_item
@@ -149,7 +149,7 @@ impl Rule for MD004UnorderedListStyle {
         };
 
         // Use centralized list blocks for better performance and accuracy
-        for list_block in &ctx.list_blocks {
+        for list_block in ctx.list_blocks() {
             // Check each list item in this block
             // We need to check individual items even in mixed lists (ordered with nested unordered)
             for &item_line in &list_block.item_lines {
@@ -272,7 +272,7 @@ impl Rule for MD004UnorderedListStyle {
         };
 
         // Use centralized list blocks
-        for list_block in &ctx.list_blocks {
+        for list_block in ctx.list_blocks() {
             // Process each list item in this block
             // We need to check individual items even in mixed lists
             for &item_line in &list_block.item_lines {
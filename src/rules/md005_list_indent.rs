@@ -653,7 +653,7 @@ impl MD005ListIndent {
         }
 
         // Quick check for any list blocks before processing
-        if ctx.list_blocks.is_empty() {
+        if ctx.list_blocks().is_empty() {
             return Ok(Vec::new());
         }
 
@@ -661,7 +661,7 @@ impl MD005ListIndent {
 
         // Group consecutive list blocks that should be treated as one logical structure
         // This is needed because mixed list types (ordered/unordered) get split into separate blocks
-        let block_groups = self.group_related_list_blocks(&ctx.list_blocks);
+        let block_groups = self.group_related_list_blocks(ctx.list_blocks());
 
         for group in block_groups {
             self.check_list_block_group(ctx, &group, &mut warnings)?;
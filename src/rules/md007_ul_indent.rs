@@ -6,7 +6,7 @@ use crate::rule_config_serde::RuleConfig;
 use toml;
 
 mod md007_config;
-use md007_config::MD007Config;
+use md007_config::{IndentCharacter, MD007Config};
 
 #[derive(Debug, Clone, Default)]
 pub struct MD007ULIndent {
@@ -21,6 +21,7 @@ impl MD007ULIndent {
                 start_indented: false,
                 start_indent: crate::types::IndentSize::from_const(2),
                 style: md007_config::IndentStyle::TextAligned,
+                indent_style: IndentCharacter::Space,
             },
         }
     }
@@ -29,8 +30,16 @@ impl MD007ULIndent {
         Self { config }
     }
 
+    /// Columns a tab advances to: `indent` when indenting with tabs, otherwise 4
+    fn tab_width(&self) -> usize {
+        match self.config.indent_style {
+            IndentCharacter::Space => 4,
+            IndentCharacter::Tab => self.config.indent.get() as usize,
+        }
+    }
+
     /// Convert character position to visual column (accounting for tabs)
-    fn char_pos_to_visual_column(content: &str, char_pos: usize) -> usize {
+    fn char_pos_to_visual_column(content: &str, char_pos: usize, tab_width: usize) -> usize {
         let mut visual_col = 0;
 
         for (current_pos, ch) in content.chars().enumerate() {
@@ -38,8 +47,8 @@ impl MD007ULIndent {
                 break;
             }
             if ch == '\t' {
-                // Tab moves to next multiple of 4
-                visual_col = (visual_col / 4 + 1) * 4;
+                // Tab moves to next multiple of the tab width
+                visual_col = (visual_col / tab_width + 1) * tab_width;
             } else {
                 visual_col += 1;
             }
@@ -107,8 +116,9 @@ impl Rule for MD007ULIndent {
                 };
 
                 // Convert marker position to visual column
+                let tab_width = self.tab_width();
                 let visual_marker_column =
-                    Self::char_pos_to_visual_column(&content_for_calculation, adjusted_marker_column);
+                    Self::char_pos_to_visual_column(&content_for_calculation, adjusted_marker_column, tab_width);
 
                 // Calculate content visual column for text-aligned style
                 let visual_content_column = if line_info.blockquote.is_some() {
@@ -119,9 +129,9 @@ impl Rule for MD007ULIndent {
                         } else {
                             list_item.content_column
                         };
-                    Self::char_pos_to_visual_column(&content_for_calculation, adjusted_content_col)
+                    Self::char_pos_to_visual_column(&content_for_calculation, adjusted_content_col, tab_width)
                 } else {
-                    Self::char_pos_to_visual_column(line_info.content(ctx.content), list_item.content_column)
+                    Self::char_pos_to_visual_column(line_info.content(ctx.content), list_item.content_column, tab_width)
                 };
 
                 // For nesting detection, treat 1-space indent as if it's at column 0
@@ -154,8 +164,13 @@ impl Rule for MD007ULIndent {
                     // Now stack contains only parent items
                     let nesting_level = list_stack.len();
 
+                    // With tabs, each level is one more tab
+                    let expected_tabs = nesting_level + usize::from(self.config.start_indented);
+
                     // Calculate expected indent first to determine expected content position
-                    let expected_indent = if self.config.start_indented {
+                    let expected_indent = if self.config.indent_style == IndentCharacter::Tab {
+                        expected_tabs * tab_width
+                    } else if self.config.start_indented {
                         self.config.start_indent.get() as usize + (nesting_level * self.config.indent.get() as usize)
                     } else {
                         match self.config.style {
@@ -197,11 +212,50 @@ impl Rule for MD007ULIndent {
                         continue;
                     }
 
-                    if visual_marker_column != expected_indent {
+                    // Whitespace before the marker, after any blockquote prefix
+                    let leading = content_for_calculation.get(..adjusted_marker_column).unwrap_or("");
+                    let has_tab = leading.contains('\t');
+                    let has_space = leading.contains(' ');
+
+                    let (correct_indent, valid) = match self.config.indent_style {
+                        IndentCharacter::Space => (
+                            " ".repeat(expected_indent),
+                            visual_marker_column == expected_indent && !has_tab,
+                        ),
+                        IndentCharacter::Tab => {
+                            ("\t".repeat(expected_tabs), !has_space && leading.len() == expected_tabs)
+                        }
+                    };
+
+                    if !valid {
+                        let expected = match self.config.indent_style {
+                            IndentCharacter::Space => format!("{expected_indent} spaces"),
+                            IndentCharacter::Tab => format!("{expected_tabs} tabs"),
+                        };
+                        let found = if has_tab && has_space {
+                            None
+                        } else if self.config.indent_style == IndentCharacter::Tab {
+                            Some(if has_space {
+                                format!("{} spaces", leading.len())
+                            } else {
+                                format!("{} tabs", leading.len())
+                            })
+                        } else if visual_marker_column != expected_indent {
+                            Some(visual_marker_column.to_string())
+                        } else {
+                            Some("tabs".to_string())
+                        };
+                        let message = match found {
+                            Some(found) => {
+                                format!("Expected {expected} for indent depth {nesting_level}, found {found}")
+                            }
+                            None => format!(
+                                "Inconsistent indentation mixes tabs and spaces, expected {expected} for indent depth {nesting_level}"
+                            ),
+                        };
+
                         // Generate fix for this list item
                         let fix = {
-                            let correct_indent = " ".repeat(expected_indent);
-
                             // Build the replacement string - need to preserve everything before the list marker
                             // For blockquoted lines, this includes the blockquote prefix
                             let replacement = if line_info.blockquote.is_some() {
@@ -252,9 +306,7 @@ impl Rule for MD007ULIndent {
 
                         warnings.push(LintWarning {
                             rule_name: Some(self.name().to_string()),
-                            message: format!(
-                                "Expected {expected_indent} spaces for indent depth {nesting_level}, found {visual_marker_column}"
-                            ),
+                            message,
                             line: line_idx + 1, // Convert to 1-indexed
                             column: 1,          // Start of line
                             end_line: line_idx + 1,
@@ -632,6 +684,58 @@ repos:
         assert_eq!(fixed, "* Item 1\n  * Item 2\n    * Item 3");
     }
 
+    #[test]
+    fn test_tab_indent_style() {
+        let config = MD007Config {
+            indent_style: IndentCharacter::Tab,
+            ..Default::default()
+        };
+        let rule = MD007ULIndent::from_config_struct(config);
+
+        let content = "* Item 1\n\t* Item 2\n\t\t* Item 3\n\t* Item 4\n1. Ordered\n\t* Under ordered";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        assert!(rule.check(&ctx).unwrap().is_empty());
+
+        // Space-indented items are converted to tabs
+        let content = "* Item 1\n  * Item 2\n    * Item 3";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].message, "Expected 1 tabs for indent depth 1, found 2 spaces");
+        assert_eq!(rule.fix(&ctx).unwrap(), "* Item 1\n\t* Item 2\n\t\t* Item 3");
+    }
+
+    #[test]
+    fn test_mixed_tabs_and_spaces_reported() {
+        let content = "* Item 1\n\t* Item 2\n\t  * Item 3";
+
+        let rule = MD007ULIndent::from_config_struct(MD007Config {
+            indent_style: IndentCharacter::Tab,
+            ..Default::default()
+        });
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].line, 3);
+        assert_eq!(
+            result[0].message,
+            "Inconsistent indentation mixes tabs and spaces, expected 2 tabs for indent depth 2"
+        );
+        assert_eq!(rule.fix(&ctx).unwrap(), "* Item 1\n\t* Item 2\n\t\t* Item 3");
+
+        // With spaces, a tab whose width happens to match is still reported
+        let rule = MD007ULIndent::from_config_struct(MD007Config {
+            indent: crate::types::IndentSize::from_const(4),
+            style: md007_config::IndentStyle::Fixed,
+            ..Default::default()
+        });
+        let ctx = LintContext::new("* Item 1\n\t* Item 2", crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].message, "Expected 4 spaces for indent depth 1, found tabs");
+        assert_eq!(rule.fix(&ctx).unwrap(), "* Item 1\n    * Item 2");
+    }
+
     #[test]
     fn test_mixed_ordered_unordered_lists() {
         let rule = MD007ULIndent::default();
@@ -746,6 +850,7 @@ tags:
             start_indent: crate::types::IndentSize::from_const(4),
             indent: crate::types::IndentSize::from_const(2),
             style: md007_config::IndentStyle::TextAligned,
+            indent_style: IndentCharacter::Space,
         };
         let rule = MD007ULIndent::from_config_struct(config);
 
@@ -15,6 +15,17 @@ pub enum IndentStyle {
     Fixed,
 }
 
+/// Character used to indent nested unordered lists
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum IndentCharacter {
+    /// Indent with spaces (default)
+    #[default]
+    Space,
+    /// Indent with one tab per level; each tab counts as `indent` columns
+    Tab,
+}
+
 /// Configuration for MD007 (Unordered list indentation)
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "kebab-case")]
@@ -34,6 +45,10 @@ pub struct MD007Config {
     /// Indentation style: text-aligned (default) or fixed (markdownlint compatible)
     #[serde(default)]
     pub style: IndentStyle,
+
+    /// Indent with spaces (default) or tabs
+    #[serde(default, alias = "indent_style")]
+    pub indent_style: IndentCharacter,
 }
 
 fn default_indent() -> IndentSize {
@@ -51,6 +66,7 @@ impl Default for MD007Config {
             start_indented: false,
             start_indent: default_start_indent(),
             style: IndentStyle::default(),
+            indent_style: IndentCharacter::default(),
         }
     }
 }
@@ -4,7 +4,7 @@ use crate::utils::range_utils::calculate_trailing_range;
 use crate::utils::regex_cache::{ORDERED_LIST_MARKER_REGEX, UNORDERED_LIST_MARKER_REGEX, get_cached_regex};
 
 mod md009_config;
-use md009_config::MD009Config;
+use md009_config::{ListItemBreaks, MD009Config};
 
 // No need for lazy_static, we'll use get_cached_regex directly
 
@@ -20,6 +20,7 @@ impl MD009TrailingSpaces {
                 br_spaces: crate::types::BrSpaces::from_const(br_spaces),
                 strict,
                 list_item_empty_lines: false,
+                list_item_breaks: ListItemBreaks::Allow,
             },
         }
     }
@@ -32,6 +33,33 @@ impl MD009TrailingSpaces {
         line.chars().rev().take_while(|&c| c == ' ').count()
     }
 
+    /// Lines (1-indexed) where trailing spaces may not form a hard break
+    ///
+    /// With `list-item-breaks = "disallow"`, lines of list items and list blocks can't.
+    /// Built once per pass; `None` when hard breaks are allowed everywhere.
+    fn disallowed_break_lines(&self, ctx: &crate::lint_context::LintContext) -> Option<Vec<bool>> {
+        if self.config.list_item_breaks == ListItemBreaks::Allow {
+            return None;
+        }
+        let mut disallowed = vec![false; ctx.lines.len() + 1];
+        for block in ctx.list_blocks() {
+            let end = block.end_line.min(ctx.lines.len());
+            for flag in disallowed.iter_mut().take(end + 1).skip(block.start_line) {
+                *flag = true;
+            }
+        }
+        for (idx, info) in ctx.lines.iter().enumerate() {
+            if info.list_item.is_some() {
+                disallowed[idx + 1] = true;
+            }
+        }
+        Some(disallowed)
+    }
+
+    fn allows_hard_break(disallowed: Option<&[bool]>, line_num: usize) -> bool {
+        disallowed.is_none_or(|lines| !lines.get(line_num).copied().unwrap_or(false))
+    }
+
     fn is_empty_list_item_line(line: &str, prev_line: Option<&str>) -> bool {
         // A line is an empty list item line if:
         // 1. It's blank or only contains spaces
@@ -67,6 +95,7 @@ impl Rule for MD009TrailingSpaces {
         // Store lines in a Vec only for accessing previous line (for list item check)
         // This is still necessary due to the need to look back at prev_line
         let lines: Vec<&str> = content.lines().collect();
+        let disallowed_breaks = self.disallowed_break_lines(ctx);
 
         for (line_num, &line) in lines.iter().enumerate() {
             let trailing_spaces = Self::count_trailing_spaces(line);
@@ -119,7 +148,11 @@ impl Rule for MD009TrailingSpaces {
             // Special handling: if the content ends with a newline, the last line from .lines()
             // is not really the "last line" in terms of trailing spaces rules
             let is_truly_last_line = line_num == lines.len() - 1 && !content.ends_with('\n');
-            if !self.config.strict && !is_truly_last_line && trailing_spaces == self.config.br_spaces.get() {
+            let is_hard_break = !self.config.strict
+                && !is_truly_last_line
+                && trailing_spaces == self.config.br_spaces.get()
+                && Self::allows_hard_break(disallowed_breaks.as_deref(), line_num + 1);
+            if is_hard_break {
                 continue;
             }
 
@@ -155,10 +188,7 @@ impl Rule for MD009TrailingSpaces {
                         trimmed.len() + 1,
                         trailing_spaces,
                     ),
-                    replacement: if !self.config.strict
-                        && !is_truly_last_line
-                        && trailing_spaces == self.config.br_spaces.get()
-                    {
+                    replacement: if is_hard_break {
                         " ".repeat(self.config.br_spaces.get())
                     } else {
                         String::new()
@@ -186,6 +216,7 @@ impl Rule for MD009TrailingSpaces {
         // We need to collect lines since we need to look at previous lines for list item checks
         let lines: Vec<&str> = content.lines().collect();
         let mut result = String::with_capacity(content.len()); // Pre-allocate capacity
+        let disallowed_breaks = self.disallowed_break_lines(ctx);
 
         for (i, line) in lines.iter().enumerate() {
             // Fast path: if no trailing spaces, just add the line
@@ -249,6 +280,7 @@ impl Rule for MD009TrailingSpaces {
                 && trailing_spaces == self.config.br_spaces.get()
                 && !is_heading
                 && !is_empty_blockquote
+                && Self::allows_hard_break(disallowed_breaks.as_deref(), i + 1)
             {
                 // Preserve the exact number of spaces for hard line breaks
                 match self.config.br_spaces.get() {
@@ -660,4 +692,29 @@ mod tests {
         let fixed = rule.fix(&ctx).unwrap();
         assert_eq!(fixed, "Line with two spaces  \nNext line");
     }
+
+    #[test]
+    fn test_list_item_breaks_disallowed() {
+        let content = "Paragraph with a break  \nnext line.\n\n- Item with a break  \n  continued  \n  last line\n\nAnother break  \nhere.\n";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+
+        // By default, hard breaks are allowed everywhere
+        let rule = MD009TrailingSpaces::default();
+        assert!(rule.check(&ctx).unwrap().is_empty());
+        assert_eq!(rule.fix(&ctx).unwrap(), content);
+
+        let config = MD009Config {
+            list_item_breaks: ListItemBreaks::Disallow,
+            ..Default::default()
+        };
+        let rule = MD009TrailingSpaces::from_config_struct(config);
+        let result = rule.check(&ctx).unwrap();
+        let lines: Vec<usize> = result.iter().map(|w| w.line).collect();
+        assert_eq!(lines, [4, 5]);
+        assert_eq!(result[0].message, "2 trailing spaces found");
+        assert_eq!(
+            rule.fix(&ctx).unwrap(),
+            "Paragraph with a break  \nnext line.\n\n- Item with a break\n  continued\n  last line\n\nAnother break  \nhere.\n"
+        );
+    }
 }
@@ -2,6 +2,17 @@ use crate::rule_config_serde::RuleConfig;
 use crate::types::BrSpaces;
 use serde::{Deserialize, Serialize};
 
+/// Whether trailing-space hard breaks are kept in list items
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum ListItemBreaks {
+    /// Treat `br-spaces` trailing spaces as a hard break, as in paragraphs (default)
+    #[default]
+    Allow,
+    /// Report and remove trailing-space hard breaks in list items
+    Disallow,
+}
+
 /// Configuration for MD009 (Trailing spaces)
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 #[serde(rename_all = "kebab-case")]
@@ -17,6 +28,10 @@ pub struct MD009Config {
     /// Allow trailing spaces in empty list item lines (default: false)
     #[serde(default, alias = "list_item_empty_lines")]
     pub list_item_empty_lines: bool,
+
+    /// Whether hard breaks are allowed in list items (default: allow)
+    #[serde(default, alias = "list_item_breaks")]
+    pub list_item_breaks: ListItemBreaks,
 }
 
 impl RuleConfig for MD009Config {
@@ -49,6 +64,17 @@ mod tests {
         assert!(config.list_item_empty_lines);
     }
 
+    #[test]
+    fn test_list_item_breaks() {
+        assert_eq!(MD009Config::default().list_item_breaks, ListItemBreaks::Allow);
+
+        let config: MD009Config = toml::from_str("list-item-breaks = \"disallow\"").unwrap();
+        assert_eq!(config.list_item_breaks, ListItemBreaks::Disallow);
+        let config: MD009Config = toml::from_str("list_item_breaks = \"allow\"").unwrap();
+        assert_eq!(config.list_item_breaks, ListItemBreaks::Allow);
+        assert!(toml::from_str::<MD009Config>("list-item-breaks = \"never\"").is_err());
+    }
+
     #[test]
     fn test_br_spaces_validation() {
         // Test that invalid values are rejected
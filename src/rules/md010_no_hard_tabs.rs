@@ -22,6 +22,7 @@ impl MD010NoHardTabs {
         Self {
             config: MD010Config {
                 spaces_per_tab: crate::types::PositiveUsize::from_const(spaces_per_tab),
+                ..Default::default()
             },
         }
     }
@@ -30,6 +31,11 @@ impl MD010NoHardTabs {
         Self { config }
     }
 
+    /// Whether a line is in a code block that `code-blocks = false` leaves alone
+    fn is_skipped_code_line(&self, ctx: &crate::lint_context::LintContext, line_idx: usize) -> bool {
+        !self.config.code_blocks && ctx.lines.get(line_idx).is_some_and(|info| info.in_code_block)
+    }
+
     // Identify lines that are part of HTML comments
     fn find_html_comment_lines(lines: &[&str]) -> Vec<bool> {
         let mut in_html_comment = false;
@@ -173,7 +179,7 @@ impl Rule for MD010NoHardTabs {
             }
 
             // Skip if in fenced code block - code has its own formatting rules
-            if fenced_code_block_lines[line_num] {
+            if fenced_code_block_lines[line_num] || self.is_skipped_code_line(ctx, line_num) {
                 continue;
             }
 
@@ -256,7 +262,7 @@ impl Rule for MD010NoHardTabs {
             if html_comment_lines[i] {
                 // Preserve HTML comments as they are
                 result.push_str(line);
-            } else if fenced_code_block_lines[i] {
+            } else if fenced_code_block_lines[i] || self.is_skipped_code_line(ctx, i) {
                 // Preserve fenced code blocks as-is - code has its own formatting rules
                 result.push_str(line);
             } else {
@@ -558,6 +564,30 @@ mod tests {
         assert_eq!(fixed, expected);
     }
 
+    #[test]
+    fn test_code_blocks_disabled() {
+        let rule = MD010NoHardTabs::from_config_struct(MD010Config {
+            code_blocks: false,
+            ..Default::default()
+        });
+
+        let content = "Build\twith make:\n\n```make\nall:\n\tcc -o app main.c\n```\n\n- Item\n\n\tstray tab\n\nText\n\n\tindented\tcode\n";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        let lines: Vec<usize> = result.iter().map(|w| w.line).collect();
+        assert_eq!(lines, [1, 10]);
+
+        let fixed = rule.fix(&ctx).unwrap();
+        assert_eq!(
+            fixed,
+            "Build    with make:\n\n```make\nall:\n\tcc -o app main.c\n```\n\n- Item\n\n    stray tab\n\nText\n\n\tindented\tcode\n"
+        );
+
+        // By default the indented code block is checked too
+        let result = MD010NoHardTabs::default().check(&ctx).unwrap();
+        assert_eq!(result.len(), 4);
+    }
+
     #[test]
     fn test_find_html_comment_lines() {
         let lines = vec!["Normal", "<!-- Start", "Middle", "End -->", "After"];
@@ -9,16 +9,25 @@ pub struct MD010Config {
     /// Number of spaces per tab (default: 4)
     #[serde(default = "default_spaces_per_tab", alias = "spaces_per_tab")]
     pub spaces_per_tab: PositiveUsize,
+
+    /// Check indented code blocks for tabs (default: true); fenced code blocks are never checked
+    #[serde(default = "default_code_blocks", alias = "code_blocks")]
+    pub code_blocks: bool,
 }
 
 fn default_spaces_per_tab() -> PositiveUsize {
     PositiveUsize::from_const(4)
 }
 
+fn default_code_blocks() -> bool {
+    true
+}
+
 impl Default for MD010Config {
     fn default() -> Self {
         Self {
             spaces_per_tab: default_spaces_per_tab(),
+            code_blocks: default_code_blocks(),
         }
     }
 }
@@ -35,6 +44,7 @@ mod tests {
     fn test_default_config() {
         let config = MD010Config::default();
         assert_eq!(config.spaces_per_tab.get(), 4);
+        assert!(config.code_blocks);
     }
 
     #[test]
@@ -50,9 +60,11 @@ mod tests {
     fn test_snake_case_backwards_compatibility() {
         let toml_str = r#"
             spaces_per_tab = 8
+            code_blocks = false
         "#;
         let config: MD010Config = toml::from_str(toml_str).unwrap();
         assert_eq!(config.spaces_per_tab.get(), 8);
+        assert!(!config.code_blocks);
     }
 
     #[test]
@@ -10,6 +10,70 @@ use crate::utils::skip_context::is_in_math_context;
 // Reversed link detection pattern
 const REVERSED_LINK_REGEX_STR: &str = r"(^|[^\\])\(([^()]+)\)\[([^\]]+)\]";
 
+/// Whether a `(...)[...]` match is something other than a reversed link: a wiki link
+/// (`(url)[[page]]`), a footnote reference (`[link](url)[^1]`), escaped brackets, or the
+/// start of `(text)[ref](url)`. `rest` is the line after the match.
+pub(crate) fn is_adjacent_prose(bracket_content: &str, rest: &str) -> bool {
+    bracket_content.starts_with('[')
+        || bracket_content.ends_with(']')
+        || bracket_content.starts_with('^')
+        || bracket_content.ends_with('\\')
+        || rest.starts_with('(')
+}
+
+/// Given the contents of a reversed `(...)[...]` construct, return them as (text, url)
+pub(crate) fn reversed_order<'s>(paren_content: &'s str, bracket_content: &'s str) -> (&'s str, &'s str) {
+    use LinkComponent::*;
+
+    let paren_type = MD011NoReversedLinks::classify_component(paren_content);
+    let bracket_type = MD011NoReversedLinks::classify_component(bracket_content);
+
+    match (paren_type, bracket_type) {
+        // One side is clearly a URL - that's the URL
+        (ClearUrl, _) => (bracket_content, paren_content),
+        (_, ClearUrl) => (paren_content, bracket_content),
+
+        // One side is multi-word - that's the text, other is URL
+        (MultiWord, _) => (paren_content, bracket_content),
+        (_, MultiWord) => (bracket_content, paren_content),
+
+        // Both ambiguous: assume standard reversed pattern (url)[text]
+        (Ambiguous, Ambiguous) => (bracket_content, paren_content),
+    }
+}
+
+/// Like [`reversed_order`], for the contents of a reversed image `!(...)[...]`, as (alt, url)
+///
+/// Image sources are usually file paths, so a file-like side is taken as the URL. When
+/// neither side tells, the image form is read alt-first: `!(alt)[src]`.
+pub(crate) fn reversed_image_order<'s>(paren_content: &'s str, bracket_content: &'s str) -> (&'s str, &'s str) {
+    use LinkComponent::*;
+
+    match (looks_like_file(paren_content), looks_like_file(bracket_content)) {
+        (true, false) => return (bracket_content, paren_content),
+        (false, true) => return (paren_content, bracket_content),
+        _ => {}
+    }
+
+    match (
+        MD011NoReversedLinks::classify_component(paren_content),
+        MD011NoReversedLinks::classify_component(bracket_content),
+    ) {
+        (Ambiguous, Ambiguous) => (paren_content, bracket_content),
+        _ => reversed_order(paren_content, bracket_content),
+    }
+}
+
+/// Whether a single word reads as a file path: it has a `/` or ends in a file extension
+fn looks_like_file(s: &str) -> bool {
+    let trimmed = s.trim();
+    !trimmed.contains(' ')
+        && (trimmed.contains('/')
+            || trimmed.rsplit_once('.').is_some_and(|(stem, ext)| {
+                !stem.is_empty() && (1..=5).contains(&ext.len()) && ext.chars().all(|c| c.is_ascii_alphanumeric())
+            }))
+}
+
 /// Classification of a link component
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum LinkComponent {
@@ -30,29 +94,12 @@ struct ReversedLinkInfo {
     paren_content: String,
     /// Content found in square brackets
     bracket_content: String,
-    /// Classification of parentheses content
-    paren_type: LinkComponent,
-    /// Classification of bracket content
-    bracket_type: LinkComponent,
 }
 
 impl ReversedLinkInfo {
     /// Determine the correct order: returns (text, url)
     fn correct_order(&self) -> (&str, &str) {
-        use LinkComponent::*;
-
-        match (self.paren_type, self.bracket_type) {
-            // One side is clearly a URL - that's the URL
-            (ClearUrl, _) => (&self.bracket_content, &self.paren_content),
-            (_, ClearUrl) => (&self.paren_content, &self.bracket_content),
-
-            // One side is multi-word - that's the text, other is URL
-            (MultiWord, _) => (&self.paren_content, &self.bracket_content),
-            (_, MultiWord) => (&self.bracket_content, &self.paren_content),
-
-            // Both ambiguous: assume standard reversed pattern (url)[text]
-            (Ambiguous, Ambiguous) => (&self.bracket_content, &self.paren_content),
-        }
+        reversed_order(&self.paren_content, &self.bracket_content)
     }
 
     /// Get the original pattern as it appears in the source
@@ -113,38 +160,13 @@ impl MD011NoReversedLinks {
                 let paren_content = cap[2].to_string();
                 let bracket_content = cap[3].to_string();
 
-                // Skip wiki-link patterns: if bracket content starts with [ or ends with ]
-                // This handles cases like (url)[[wiki-link]] being misdetected
-                if bracket_content.starts_with('[') || bracket_content.ends_with(']') {
-                    last_end += match_obj.end();
-                    continue;
-                }
-
-                // Skip footnote references: [^footnote]
-                // This prevents false positives like [link](url)[^footnote]
-                if bracket_content.starts_with('^') {
-                    last_end += match_obj.end();
-                    continue;
-                }
-
-                // Check if the brackets at the end are escaped
-                if bracket_content.ends_with('\\') {
-                    last_end += match_obj.end();
-                    continue;
-                }
-
-                // Manual negative lookahead: skip if followed by (
-                // This prevents matching (text)[ref](url) patterns
+                // Reversed images (`!(alt)[url]`) are reported by MD114
                 let end_pos = last_end + match_obj.end();
-                if end_pos < line.len() && line[end_pos..].starts_with('(') {
+                if prechar == "!" || is_adjacent_prose(&bracket_content, &line[end_pos..]) {
                     last_end += match_obj.end();
                     continue;
                 }
 
-                // Classify both components
-                let paren_type = Self::classify_component(&paren_content);
-                let bracket_type = Self::classify_component(&bracket_content);
-
                 // Calculate the actual column (accounting for any prefix character)
                 let column = last_end + match_obj.start() + prechar.len() + 1;
 
@@ -153,8 +175,6 @@ impl MD011NoReversedLinks {
                     column,
                     paren_content,
                     bracket_content,
-                    paren_type,
-                    bracket_type,
                 });
 
                 last_end += match_obj.end();
@@ -199,30 +219,9 @@ impl Rule for MD011NoReversedLinks {
                 let paren_content = cap[2].to_string();
                 let bracket_content = cap[3].to_string();
 
-                // Skip wiki-link patterns: if bracket content starts with [ or ends with ]
-                // This handles cases like (url)[[wiki-link]] being misdetected
-                if bracket_content.starts_with('[') || bracket_content.ends_with(']') {
-                    last_end += match_obj.end();
-                    continue;
-                }
-
-                // Skip footnote references: [^footnote]
-                // This prevents false positives like [link](url)[^footnote]
-                if bracket_content.starts_with('^') {
-                    last_end += match_obj.end();
-                    continue;
-                }
-
-                // Check if the brackets at the end are escaped
-                if bracket_content.ends_with('\\') {
-                    last_end += match_obj.end();
-                    continue;
-                }
-
-                // Manual negative lookahead: skip if followed by (
-                // This prevents matching (text)[ref](url) patterns
+                // Reversed images (`!(alt)[url]`) are reported by MD114
                 let end_pos = last_end + match_obj.end();
-                if end_pos < line.len() && line[end_pos..].starts_with('(') {
+                if prechar == "!" || is_adjacent_prose(&bracket_content, &line[end_pos..]) {
                     last_end += match_obj.end();
                     continue;
                 }
@@ -241,17 +240,11 @@ impl Rule for MD011NoReversedLinks {
                     continue;
                 }
 
-                // Classify both components and determine correct order
-                let paren_type = Self::classify_component(&paren_content);
-                let bracket_type = Self::classify_component(&bracket_content);
-
                 let info = ReversedLinkInfo {
                     line_num,
                     column: match_start + 1,
                     paren_content,
                     bracket_content,
-                    paren_type,
-                    bracket_type,
                 };
 
                 let (text, url) = info.correct_order();
@@ -389,6 +382,16 @@ mod tests {
         assert_eq!(warnings.len(), 0);
     }
 
+    #[test]
+    fn test_md011_reversed_image_left_to_md114() {
+        let rule = MD011NoReversedLinks;
+
+        let content = "!(alt)[image.png]\n";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        assert!(rule.check(&ctx).unwrap().is_empty());
+        assert_eq!(rule.fix(&ctx).unwrap(), content);
+    }
+
     #[test]
     fn test_md011_fix() {
         let rule = MD011NoReversedLinks;
@@ -41,6 +41,14 @@ pub struct MD013Config {
     #[serde(default = "default_line_length", alias = "line_length")]
     pub line_length: LineLength,
 
+    /// Maximum length of heading lines (default: `line-length`, 0 means no limit)
+    #[serde(default, skip_serializing_if = "Option::is_none", alias = "heading_line_length")]
+    pub heading_line_length: Option<LineLength>,
+
+    /// Maximum length of lines inside code blocks (default: `line-length`, 0 means no limit)
+    #[serde(default, skip_serializing_if = "Option::is_none", alias = "code_block_line_length")]
+    pub code_block_line_length: Option<LineLength>,
+
     /// Check code blocks for line length (default: true)
     #[serde(default = "default_code_blocks", alias = "code_blocks")]
     pub code_blocks: bool,
@@ -84,6 +92,14 @@ pub struct MD013Config {
     /// Custom abbreviations are always added to the built-in defaults
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub abbreviations: Option<Vec<String>>,
+
+    /// Skip reference definition lines like `[ref]: https://...` (default: true)
+    /// Only the lines of the definition itself are exempt, not prose containing URLs
+    #[serde(
+        default = "default_ignore_reference_definitions",
+        alias = "ignore_reference_definitions"
+    )]
+    pub ignore_reference_definitions: bool,
 }
 
 fn default_line_length() -> LineLength {
@@ -106,10 +122,16 @@ fn default_paragraphs() -> bool {
     true
 }
 
+fn default_ignore_reference_definitions() -> bool {
+    true
+}
+
 impl Default for MD013Config {
     fn default() -> Self {
         Self {
             line_length: default_line_length(),
+            heading_line_length: None,
+            code_block_line_length: None,
             code_blocks: default_code_blocks(),
             tables: default_tables(),
             headings: default_headings(),
@@ -119,10 +141,32 @@ impl Default for MD013Config {
             reflow_mode: ReflowMode::default(),
             length_mode: LengthMode::default(),
             abbreviations: None,
+            ignore_reference_definitions: default_ignore_reference_definitions(),
         }
     }
 }
 
+impl MD013Config {
+    /// Limit applied to heading lines
+    pub fn heading_limit(&self) -> LineLength {
+        self.heading_line_length.unwrap_or(self.line_length)
+    }
+
+    /// Limit applied to lines inside code blocks
+    pub fn code_block_limit(&self) -> LineLength {
+        self.code_block_line_length.unwrap_or(self.line_length)
+    }
+
+    /// The lowest limit of any context, or `None` if every context is unlimited
+    pub fn shortest_limit(&self) -> Option<usize> {
+        [self.line_length, self.heading_limit(), self.code_block_limit()]
+            .into_iter()
+            .filter(|limit| !limit.is_unlimited())
+            .map(LineLength::get)
+            .min()
+    }
+}
+
 impl RuleConfig for MD013Config {
     const RULE_NAME: &'static str = "MD013";
 }
@@ -196,6 +240,8 @@ mod tests {
         // Test that serialization always uses kebab-case (primary format)
         let config = MD013Config {
             line_length: LineLength::from_const(80),
+            heading_line_length: None,
+            code_block_line_length: None,
             code_blocks: true,
             tables: true,
             headings: true,
@@ -205,6 +251,7 @@ mod tests {
             reflow_mode: ReflowMode::SentencePerLine,
             length_mode: LengthMode::default(),
             abbreviations: None,
+            ignore_reference_definitions: true,
         };
 
         let toml_str = toml::to_string(&config).unwrap();
@@ -281,4 +328,27 @@ mod tests {
         assert!(config.reflow, "reflow should be true");
         assert_eq!(config.reflow_mode, ReflowMode::SentencePerLine);
     }
+
+    #[test]
+    fn test_context_line_lengths_fall_back_to_line_length() {
+        let config: MD013Config = toml::from_str("line-length = 60").unwrap();
+        assert_eq!(config.heading_line_length, None);
+        assert_eq!(config.heading_limit().get(), 60);
+        assert_eq!(config.code_block_limit().get(), 60);
+        assert_eq!(config.shortest_limit(), Some(60));
+
+        let toml_str = r#"
+            line-length = 60
+            heading-line-length = 100
+            code_block_line_length = 0
+        "#;
+        let config: MD013Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.heading_limit().get(), 100);
+        assert!(config.code_block_limit().is_unlimited());
+        assert_eq!(config.shortest_limit(), Some(60));
+
+        // Unset context limits are left out of the serialized config
+        let toml_str = toml::to_string(&MD013Config::default()).unwrap();
+        assert!(!toml_str.contains("heading-line-length"));
+    }
 }
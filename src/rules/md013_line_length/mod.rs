@@ -35,6 +35,8 @@ impl MD013LineLength {
         Self {
             config: MD013Config {
                 line_length: crate::types::LineLength::new(line_length),
+                heading_line_length: None,
+                code_block_line_length: None,
                 code_blocks,
                 tables,
                 headings,
@@ -44,6 +46,7 @@ impl MD013LineLength {
                 reflow_mode: ReflowMode::default(),
                 length_mode: LengthMode::default(),
                 abbreviations: None,
+                ignore_reference_definitions: true,
             },
         }
     }
@@ -52,6 +55,17 @@ impl MD013LineLength {
         Self { config }
     }
 
+    /// The limit for a line, based on whether it is a heading, in a code block, or prose
+    fn line_limit(config: &MD013Config, line_info: &crate::lint_context::LineInfo) -> crate::types::LineLength {
+        if line_info.heading.is_some() {
+            config.heading_limit()
+        } else if line_info.in_code_block {
+            config.code_block_limit()
+        } else {
+            config.line_length
+        }
+    }
+
     fn should_ignore_line(
         &self,
         line: &str,
@@ -77,7 +91,11 @@ impl MD013LineLength {
         }
 
         // Only skip if the entire line is a link reference (quick check first)
-        if trimmed.starts_with('[') && trimmed.contains("]:") && LINK_REF_PATTERN.is_match(trimmed) {
+        if self.config.ignore_reference_definitions
+            && trimmed.starts_with('[')
+            && trimmed.contains("]:")
+            && LINK_REF_PATTERN.is_match(trimmed)
+        {
             return true;
         }
 
@@ -120,7 +138,10 @@ impl Rule for MD013LineLength {
         let mut warnings = Vec::new();
 
         // Check for inline configuration overrides
-        let inline_config = crate::inline_config::InlineConfig::from_content(content);
+        let inline_config = crate::inline_config::InlineConfig::from_content_with_syntax(
+            content,
+            crate::config::InlineCommentSyntax::for_flavor(ctx.flavor),
+        );
         let config_override = inline_config.get_rule_config("MD013");
 
         // Apply configuration override if present
@@ -130,6 +151,12 @@ impl Rule for MD013LineLength {
                 if let Some(line_length) = obj.get("line_length").and_then(|v| v.as_u64()) {
                     config.line_length = crate::types::LineLength::new(line_length as usize);
                 }
+                if let Some(line_length) = obj.get("heading_line_length").and_then(|v| v.as_u64()) {
+                    config.heading_line_length = Some(crate::types::LineLength::new(line_length as usize));
+                }
+                if let Some(line_length) = obj.get("code_block_line_length").and_then(|v| v.as_u64()) {
+                    config.code_block_line_length = Some(crate::types::LineLength::new(line_length as usize));
+                }
                 if let Some(code_blocks) = obj.get("code_blocks").and_then(|v| v.as_bool()) {
                     config.code_blocks = code_blocks;
                 }
@@ -142,6 +169,9 @@ impl Rule for MD013LineLength {
                 if let Some(strict) = obj.get("strict").and_then(|v| v.as_bool()) {
                     config.strict = strict;
                 }
+                if let Some(ignore) = obj.get("ignore_reference_definitions").and_then(|v| v.as_bool()) {
+                    config.ignore_reference_definitions = ignore;
+                }
                 if let Some(reflow) = obj.get("reflow").and_then(|v| v.as_bool()) {
                     config.reflow = reflow;
                 }
@@ -163,7 +193,7 @@ impl Rule for MD013LineLength {
 
         // Special handling: line_length = 0 means "no line length limit"
         // Skip all line length checks, but still allow reflow if enabled
-        let skip_length_checks = effective_config.line_length.is_unlimited();
+        let skip_length_checks = effective_config.shortest_limit().is_none();
 
         // Pre-filter lines that could be problematic to avoid processing all lines
         let mut candidate_lines = Vec::new();
@@ -175,7 +205,7 @@ impl Rule for MD013LineLength {
                 }
 
                 // Quick length check first
-                if line_info.byte_len > effective_config.line_length.get() {
+                if line_info.byte_len > Self::line_limit(&effective_config, line_info).effective_limit() {
                     candidate_lines.push(line_idx);
                 }
             }
@@ -209,7 +239,7 @@ impl Rule for MD013LineLength {
 
         // Use pre-computed table blocks from context
         // We need this for both the table skip check AND the paragraphs check
-        let table_blocks = &ctx.table_blocks;
+        let table_blocks = ctx.table_blocks();
         let mut table_lines_set = std::collections::HashSet::new();
         for table in table_blocks {
             table_lines_set.insert(table.header_line + 1);
@@ -219,6 +249,15 @@ impl Rule for MD013LineLength {
             }
         }
 
+        // Lines covered by reference definitions (`[ref]: url "title"`, possibly spanning lines)
+        let mut reference_def_lines_set = std::collections::HashSet::new();
+        if effective_config.ignore_reference_definitions {
+            for def in &ctx.reference_defs {
+                let (end_line, _) = ctx.offset_to_line_col(def.byte_end.saturating_sub(1).max(def.byte_offset));
+                reference_def_lines_set.extend(def.line..=end_line);
+            }
+        }
+
         // Process candidate lines for line length checks
         for &line_idx in &candidate_lines {
             let line_number = line_idx + 1;
@@ -227,8 +266,8 @@ impl Rule for MD013LineLength {
             // Calculate effective length excluding unbreakable URLs
             let effective_length = self.calculate_effective_length(line);
 
-            // Use single line length limit for all content
-            let line_limit = effective_config.line_length.get();
+            // Headings and code blocks may have their own limit
+            let line_limit = Self::line_limit(&effective_config, &ctx.lines[line_idx]).get();
 
             // Skip short lines immediately (double-check after effective length calculation)
             if effective_length <= line_limit {
@@ -254,6 +293,7 @@ impl Rule for MD013LineLength {
                     || (!effective_config.code_blocks
                         && ctx.line_info(line_number).is_some_and(|info| info.in_code_block))
                     || (!effective_config.tables && table_lines_set.contains(&line_number))
+                    || reference_def_lines_set.contains(&line_number)
                     || ctx.lines[line_number - 1].blockquote.is_some()
                     || ctx.line_info(line_number).is_some_and(|info| info.in_html_block)
                     || ctx.line_info(line_number).is_some_and(|info| info.in_html_comment)
@@ -384,15 +424,16 @@ impl Rule for MD013LineLength {
             return false;
         }
 
-        // Quick check: if total content is shorter than line limit, definitely skip
-        if ctx.content.len() <= self.config.line_length.get() {
+        // Quick check: if total content is shorter than the lowest limit, definitely skip
+        let Some(shortest_limit) = self.config.shortest_limit() else {
+            return true;
+        };
+        if ctx.content.len() <= shortest_limit {
             return true;
         }
 
         // Use more efficient check - any() with early termination instead of all()
-        !ctx.lines
-            .iter()
-            .any(|line| line.byte_len > self.config.line_length.get())
+        !ctx.lines.iter().any(|line| line.byte_len > shortest_limit)
     }
 
     fn default_config_section(&self) -> Option<(String, toml::Value)> {
@@ -454,8 +495,15 @@ impl MD013LineLength {
                     || info.in_esm_block
             });
 
+            // Blockquote paragraphs are reflowed behind their `>` prefix
+            if !should_skip_due_to_line_info && ctx.lines[i].blockquote.is_some() {
+                let (next, warning) = self.reflow_blockquote_paragraph(ctx, config, lines, &line_index, i);
+                warnings.extend(warning);
+                i = next;
+                continue;
+            }
+
             if should_skip_due_to_line_info
-                || (line_num > 0 && line_num <= ctx.lines.len() && ctx.lines[line_num - 1].blockquote.is_some())
                 || lines[i].trim().starts_with('#')
                 || TableUtils::is_potential_table_row(lines[i])
                 || lines[i].trim().is_empty()
@@ -540,7 +588,8 @@ impl MD013LineLength {
                         let trimmed = line_info.content(ctx.content).trim();
 
                         // Use pre-computed in_code_block from ctx
-                        if line_info.in_code_block {
+                        // Quoted lines are kept as they are too, so their `>` prefix survives
+                        if line_info.in_code_block || line_info.blockquote.is_some() {
                             list_item_lines.push(LineType::CodeBlock(
                                 line_info.content(ctx.content)[indent..].to_string(),
                                 indent,
@@ -1007,6 +1056,13 @@ impl MD013LineLength {
                     false
                 };
 
+                // Longest line of the item as written, leaving out code
+                let longest_line = (list_start..i)
+                    .filter(|&idx| !ctx.lines[idx].in_code_block)
+                    .map(|idx| self.calculate_effective_length(lines[idx]))
+                    .max()
+                    .unwrap_or(0);
+
                 let needs_reflow = match config.reflow_mode {
                     ReflowMode::Normalize => {
                         // Only reflow if:
@@ -1026,7 +1082,7 @@ impl MD013LineLength {
                     }
                     ReflowMode::Default => {
                         // In default mode, only reflow if lines exceed limit
-                        self.calculate_effective_length(&full_line) > config.line_length.get()
+                        longest_line > config.line_length.get()
                     }
                 };
 
@@ -1310,10 +1366,9 @@ impl MD013LineLength {
                                 }
                             }
                             ReflowMode::Default => {
-                                let combined_length = self.calculate_effective_length(&full_line);
                                 format!(
                                     "Line length {} exceeds {} characters",
-                                    combined_length,
+                                    longest_line,
                                     config.line_length.get()
                                 )
                             }
@@ -1560,6 +1615,167 @@ impl MD013LineLength {
         warnings
     }
 
+    /// Reflow the blockquote paragraph starting at line index `start`, keeping its prefix
+    ///
+    /// Returns the index of the first line after the paragraph, and a warning with the fix
+    /// when the paragraph needs reflowing. Quoted lists, headings, tables and code are left
+    /// alone, as are nested quotes and lazy continuation lines.
+    fn reflow_blockquote_paragraph(
+        &self,
+        ctx: &crate::lint_context::LintContext,
+        config: &MD013Config,
+        lines: &[&str],
+        line_index: &LineIndex,
+        start: usize,
+    ) -> (usize, Option<LintWarning>) {
+        let Some(first) = ctx.lines[start].blockquote.as_ref() else {
+            return (start + 1, None);
+        };
+        let is_paragraph_line = |idx: usize| {
+            let info = &ctx.lines[idx];
+            let Some(bq) = info.blockquote.as_ref() else {
+                return false;
+            };
+            let content = bq.content.trim();
+            bq.nesting_level == first.nesting_level
+                && !content.is_empty()
+                && !info.in_code_block
+                && !info.in_html_block
+                && !info.in_html_comment
+                && info.heading.is_none()
+                && info.list_item.is_none()
+                && !content.starts_with('#')
+                && !content.starts_with('<')
+                && !content.starts_with('>')
+                && !(content.starts_with('[') && content.contains("]:"))
+                && !is_list_item(content)
+                && !is_horizontal_rule(content)
+                && !content.chars().all(|c| c == '=' || c == '-')
+                && !TableUtils::is_potential_table_row(content)
+                && !is_template_directive_only(content)
+        };
+        if !is_paragraph_line(start) {
+            return (start + 1, None);
+        }
+
+        let mut end = start + 1;
+        while end < lines.len() && is_paragraph_line(end) && !has_hard_break(lines[end - 1]) {
+            end += 1;
+        }
+        // A setext underline turns the paragraph into a heading
+        if ctx.lines.get(end).is_some_and(|info| info.heading.is_some()) {
+            return (end + 1, None);
+        }
+
+        let contents: Vec<&str> = (start..end)
+            .filter_map(|idx| ctx.lines[idx].blockquote.as_ref())
+            .map(|bq| bq.content.as_str())
+            .collect();
+        let paragraph_text = contents.iter().map(|c| c.trim()).collect::<Vec<_>>().join(" ");
+        let prefix = first.prefix.as_str();
+        let prefix_len = self.calculate_string_length(prefix);
+        let limit = config.line_length.get();
+
+        let needs_reflow = match config.reflow_mode {
+            ReflowMode::Normalize => contents.len() > 1,
+            ReflowMode::SentencePerLine => {
+                split_into_sentences(&paragraph_text).len() > 1
+                    || (contents.len() > 1
+                        && (config.line_length.is_unlimited()
+                            || prefix_len + self.calculate_effective_length(&paragraph_text) <= limit))
+            }
+            ReflowMode::Default => (start..end).any(|idx| self.calculate_effective_length(lines[idx]) > limit),
+        };
+        if !needs_reflow {
+            return (end, None);
+        }
+
+        let reflow_options = crate::utils::text_reflow::ReflowOptions {
+            line_length: if config.line_length.is_unlimited() {
+                usize::MAX
+            } else {
+                limit.saturating_sub(prefix_len).max(1)
+            },
+            break_on_sentences: true,
+            preserve_breaks: false,
+            sentence_per_line: config.reflow_mode == ReflowMode::SentencePerLine,
+            abbreviations: config.abbreviations.clone(),
+        };
+        let mut reflowed = crate::utils::text_reflow::reflow_line(&paragraph_text, &reflow_options);
+        // Keep a hard break that ends the paragraph, in its original form
+        let hard_break_type = if lines[end - 1].ends_with('\\') {
+            Some("\\")
+        } else if lines[end - 1].ends_with("  ") {
+            Some("  ")
+        } else {
+            None
+        };
+        if let Some(break_marker) = hard_break_type
+            && let Some(line) = reflowed.last_mut()
+            && !has_hard_break(line)
+        {
+            line.push_str(break_marker);
+        }
+
+        let last_line = end - 1;
+        let start_offset = line_index.whole_line_range(start + 1).start;
+        let end_offset = if last_line == lines.len() - 1 && !ctx.content.ends_with('\n') {
+            line_index
+                .line_text_range(last_line + 1, 1, lines[last_line].len() + 1)
+                .end
+        } else {
+            line_index.whole_line_range(last_line + 1).end
+        };
+        let mut replacement = reflowed
+            .iter()
+            .map(|line| format!("{prefix}{line}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        if last_line < lines.len() - 1 || ctx.content.ends_with('\n') {
+            replacement.push('\n');
+        }
+        if ctx.content[start_offset..end_offset] == replacement {
+            return (end, None);
+        }
+
+        let (line, end_line, message) = match config.reflow_mode {
+            ReflowMode::Normalize => (
+                start + 1,
+                end,
+                format!("Paragraph could be normalized to use line length of {limit} characters"),
+            ),
+            ReflowMode::SentencePerLine => (
+                start + 1,
+                end,
+                "Paragraph should have one sentence per line".to_string(),
+            ),
+            ReflowMode::Default => {
+                let violating = (start..end)
+                    .find(|&idx| self.calculate_effective_length(lines[idx]) > limit)
+                    .unwrap_or(start);
+                (
+                    violating + 1,
+                    violating + 1,
+                    format!("Line length exceeds {limit} characters"),
+                )
+            }
+        };
+        let warning = LintWarning {
+            rule_name: Some(self.name().to_string()),
+            message,
+            line,
+            column: 1,
+            end_line,
+            end_column: lines[end_line - 1].len() + 1,
+            severity: Severity::Warning,
+            fix: Some(crate::rule::Fix {
+                range: start_offset..end_offset,
+                replacement,
+            }),
+        };
+        (end, Some(warning))
+    }
+
     /// Calculate string length based on the configured length mode
     fn calculate_string_length(&self, s: &str) -> usize {
         match self.config.length_mode {
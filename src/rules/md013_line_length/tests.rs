@@ -34,6 +34,33 @@ fn test_basic_line_length_violation() {
     assert!(result[0].message.contains("exceeds 50 characters"));
 }
 
+#[test]
+fn test_reference_definitions_ignored() {
+    let rule = MD013LineLength::new(40, false, false, false, false);
+    let content = "Text with a [reference link][docs] and more.\n\n\
+[docs]: <./docs/getting-started.md> \"The getting started guide for new users\"\n\n\
+Prose mentioning ./docs/getting-started.md that also runs long.\n";
+    let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+    let result = rule.check(&ctx).unwrap();
+
+    // Only the prose lines are reported, never the definition line
+    assert_eq!(result.len(), 2);
+    assert_eq!(result[0].line, 1);
+    assert_eq!(result[1].line, 5);
+
+    let config = MD013Config {
+        line_length: crate::types::LineLength::new(40),
+        heading_line_length: None,
+        code_block_line_length: None,
+        ignore_reference_definitions: false,
+        ..Default::default()
+    };
+    let rule = MD013LineLength::from_config_struct(config);
+    let result = rule.check(&ctx).unwrap();
+    assert_eq!(result.len(), 3);
+    assert_eq!(result[1].line, 3);
+}
+
 #[test]
 fn test_no_violation_under_limit() {
     let rule = MD013LineLength::new(100, false, false, false, false);
@@ -469,6 +496,8 @@ fn test_documentation_example_from_md051() {
 fn test_text_reflow_simple() {
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(30),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         ..Default::default()
     };
@@ -499,6 +528,8 @@ fn test_text_reflow_simple() {
 fn test_text_reflow_preserves_markdown_elements() {
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(40),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         ..Default::default()
     };
@@ -527,6 +558,8 @@ fn test_text_reflow_preserves_markdown_elements() {
 fn test_text_reflow_preserves_code_blocks() {
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(30),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         ..Default::default()
     };
@@ -554,6 +587,8 @@ More text after code block."#;
 fn test_text_reflow_preserves_lists() {
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(30),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         ..Default::default()
     };
@@ -611,6 +646,8 @@ fn test_issue_83_numbered_list_with_backticks() {
     // Test for issue #83: enable_reflow was incorrectly handling numbered lists
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(100),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         ..Default::default()
     };
@@ -651,6 +688,8 @@ fn test_reflow_with_hard_line_breaks() {
     // Test that lines with exactly 2 trailing spaces are preserved as hard breaks
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(40),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         ..Default::default()
     };
@@ -672,6 +711,8 @@ fn test_reflow_with_hard_line_breaks() {
 fn test_reflow_preserves_reference_links() {
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(40),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         ..Default::default()
     };
@@ -694,6 +735,8 @@ fn test_reflow_preserves_reference_links() {
 fn test_reflow_with_nested_markdown_elements() {
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(35),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         ..Default::default()
     };
@@ -712,6 +755,8 @@ fn test_reflow_with_unbalanced_markdown() {
     // Test edge case with unbalanced markdown
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(30),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         ..Default::default()
     };
@@ -736,6 +781,8 @@ fn test_reflow_fix_indicator() {
     // Test that reflow provides fix indicators
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(30),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         ..Default::default()
     };
@@ -758,6 +805,8 @@ fn test_no_fix_indicator_without_reflow() {
     // Test that without reflow, no fix is provided
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(30),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: false,
         ..Default::default()
     };
@@ -776,6 +825,8 @@ fn test_no_fix_indicator_without_reflow() {
 fn test_reflow_preserves_all_reference_link_types() {
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(40),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         ..Default::default()
     };
@@ -800,6 +851,8 @@ fn test_reflow_preserves_all_reference_link_types() {
 fn test_reflow_handles_images_correctly() {
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(40),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         ..Default::default()
     };
@@ -818,6 +871,8 @@ fn test_reflow_handles_images_correctly() {
 fn test_normalize_mode_flags_short_lines() {
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(100),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         ..Default::default()
@@ -838,6 +893,8 @@ fn test_normalize_mode_flags_short_lines() {
 fn test_normalize_mode_combines_short_lines() {
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(100),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         ..Default::default()
@@ -860,6 +917,8 @@ fn test_normalize_mode_combines_short_lines() {
 fn test_normalize_mode_preserves_paragraph_breaks() {
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(100),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         ..Default::default()
@@ -881,6 +940,8 @@ fn test_normalize_mode_preserves_paragraph_breaks() {
 fn test_default_mode_only_fixes_violations() {
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(100),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         reflow_mode: ReflowMode::Default, // Default mode
         ..Default::default()
@@ -904,6 +965,8 @@ fn test_default_mode_only_fixes_violations() {
 fn test_normalize_mode_with_lists() {
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(80),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         ..Default::default()
@@ -930,6 +993,8 @@ short lines.
 fn test_normalize_mode_with_code_blocks() {
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(100),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         ..Default::default()
@@ -961,6 +1026,8 @@ fn test_issue_76_use_case() {
     // This tests the exact use case from issue #76
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(999999), // Set absurdly high
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         ..Default::default()
@@ -988,6 +1055,8 @@ fn test_normalize_mode_single_line_unchanged() {
     // Single lines should not be flagged or changed
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(100),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         ..Default::default()
@@ -1008,6 +1077,8 @@ fn test_normalize_mode_single_line_unchanged() {
 fn test_normalize_mode_with_inline_code() {
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(80),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         ..Default::default()
@@ -1030,6 +1101,8 @@ fn test_normalize_mode_with_inline_code() {
 fn test_normalize_mode_with_emphasis() {
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(100),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         ..Default::default()
@@ -1049,6 +1122,8 @@ fn test_normalize_mode_with_emphasis() {
 fn test_normalize_mode_respects_hard_breaks() {
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(100),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         ..Default::default()
@@ -1073,6 +1148,8 @@ fn test_normalize_mode_respects_hard_breaks() {
 fn test_normalize_mode_with_links() {
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(100),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         ..Default::default()
@@ -1094,6 +1171,8 @@ fn test_normalize_mode_with_links() {
 fn test_normalize_mode_empty_lines_between_paragraphs() {
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(100),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         ..Default::default()
@@ -1117,6 +1196,8 @@ fn test_normalize_mode_empty_lines_between_paragraphs() {
 fn test_normalize_mode_mixed_list_types() {
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(80),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         ..Default::default()
@@ -1160,6 +1241,8 @@ with multiple lines."#;
 fn test_normalize_mode_with_horizontal_rules() {
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(100),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         ..Default::default()
@@ -1185,6 +1268,8 @@ fn test_normalize_mode_with_horizontal_rules() {
 fn test_normalize_mode_with_indented_code() {
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(100),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         ..Default::default()
@@ -1214,6 +1299,8 @@ fn test_normalize_mode_disabled_without_reflow() {
     // Normalize mode should have no effect if reflow is disabled
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(100),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: false, // Disabled
         reflow_mode: ReflowMode::Normalize,
         ..Default::default()
@@ -1236,6 +1323,8 @@ fn test_default_mode_with_long_lines() {
     // The paragraph-based approach treats consecutive lines as a unit
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(50),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         reflow_mode: ReflowMode::Default,
         ..Default::default()
@@ -1270,6 +1359,8 @@ fn test_normalize_vs_default_mode_same_content() {
     // Test default mode
     let default_config = MD013Config {
         line_length: crate::types::LineLength::from_const(100),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         reflow_mode: ReflowMode::Default,
         ..Default::default()
@@ -1281,6 +1372,8 @@ fn test_normalize_vs_default_mode_same_content() {
     // Test normalize mode
     let normalize_config = MD013Config {
         line_length: crate::types::LineLength::from_const(100),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         ..Default::default()
@@ -1315,6 +1408,8 @@ fn test_normalize_vs_default_mode_same_content() {
 fn test_normalize_mode_with_reference_definitions() {
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(100),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         ..Default::default()
@@ -1340,6 +1435,8 @@ fn test_normalize_mode_with_reference_definitions() {
 fn test_normalize_mode_with_html_comments() {
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(100),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         ..Default::default()
@@ -1369,6 +1466,8 @@ fn test_normalize_mode_line_starting_with_number() {
     // Regression test for the bug we fixed where "80 characters" was treated as a list
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(100),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         ..Default::default()
@@ -1391,6 +1490,8 @@ fn test_default_mode_preserves_list_structure() {
     // In default mode, list continuation lines should be preserved
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(80),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         reflow_mode: ReflowMode::Default,
         ..Default::default()
@@ -1429,6 +1530,8 @@ fn test_normalize_mode_multi_line_list_items_no_extra_spaces() {
     // Test that multi-line list items don't get extra spaces when normalized
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(80),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         ..Default::default()
@@ -1473,6 +1576,8 @@ fn test_normalize_mode_actual_numbered_list() {
     // Ensure actual numbered lists are still detected correctly
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(100),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         ..Default::default()
@@ -1622,6 +1727,8 @@ fn test_multi_paragraph_list_item_with_3_space_indent() {
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         line_length: crate::types::LineLength::from_const(999999),
+        heading_line_length: None,
+        code_block_line_length: None,
         ..Default::default()
     };
     let rule = MD013LineLength::from_config_struct(config);
@@ -1647,6 +1754,8 @@ fn test_multi_paragraph_list_item_with_4_space_indent() {
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         line_length: crate::types::LineLength::from_const(999999),
+        heading_line_length: None,
+        code_block_line_length: None,
         ..Default::default()
     };
     let rule = MD013LineLength::from_config_struct(config);
@@ -1681,6 +1790,8 @@ fn test_multi_paragraph_bullet_list_item() {
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         line_length: crate::types::LineLength::from_const(999999),
+        heading_line_length: None,
+        code_block_line_length: None,
         ..Default::default()
     };
     let rule = MD013LineLength::from_config_struct(config);
@@ -1705,6 +1816,8 @@ fn test_code_block_in_list_item_five_spaces() {
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         line_length: crate::types::LineLength::from_const(80),
+        heading_line_length: None,
+        code_block_line_length: None,
         ..Default::default()
     };
     let rule = MD013LineLength::from_config_struct(config);
@@ -1737,6 +1850,8 @@ fn test_fenced_code_block_in_list_item() {
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         line_length: crate::types::LineLength::from_const(80),
+        heading_line_length: None,
+        code_block_line_length: None,
         ..Default::default()
     };
     let rule = MD013LineLength::from_config_struct(config);
@@ -1772,6 +1887,8 @@ fn test_mixed_indentation_3_and_4_spaces() {
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         line_length: crate::types::LineLength::from_const(999999),
+        heading_line_length: None,
+        code_block_line_length: None,
         ..Default::default()
     };
     let rule = MD013LineLength::from_config_struct(config);
@@ -1802,6 +1919,8 @@ fn test_nested_list_in_multi_paragraph_item() {
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         line_length: crate::types::LineLength::from_const(999999),
+        heading_line_length: None,
+        code_block_line_length: None,
         ..Default::default()
     };
     let rule = MD013LineLength::from_config_struct(config);
@@ -1833,6 +1952,8 @@ fn test_nested_fence_markers_different_types() {
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         line_length: crate::types::LineLength::from_const(80),
+        heading_line_length: None,
+        code_block_line_length: None,
         ..Default::default()
     };
     let rule = MD013LineLength::from_config_struct(config);
@@ -1870,6 +1991,8 @@ fn test_nested_fence_markers_same_type() {
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         line_length: crate::types::LineLength::from_const(80),
+        heading_line_length: None,
+        code_block_line_length: None,
         ..Default::default()
     };
     let rule = MD013LineLength::from_config_struct(config);
@@ -1907,6 +2030,8 @@ fn test_sibling_list_item_breaks_parent() {
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         line_length: crate::types::LineLength::from_const(999999),
+        heading_line_length: None,
+        code_block_line_length: None,
         ..Default::default()
     };
     let rule = MD013LineLength::from_config_struct(config);
@@ -1932,6 +2057,8 @@ fn test_nested_list_at_continuation_indent_preserved() {
         reflow: true,
         reflow_mode: ReflowMode::Normalize,
         line_length: crate::types::LineLength::from_const(999999),
+        heading_line_length: None,
+        code_block_line_length: None,
         ..Default::default()
     };
     let rule = MD013LineLength::from_config_struct(config);
@@ -1967,6 +2094,8 @@ fn test_paragraphs_false_skips_regular_text() {
     // Test that paragraphs=false skips checking regular text
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(50),
+        heading_line_length: None,
+        code_block_line_length: None,
         paragraphs: false, // Don't check paragraphs
         code_blocks: true,
         tables: true,
@@ -1976,6 +2105,7 @@ fn test_paragraphs_false_skips_regular_text() {
         reflow_mode: ReflowMode::default(),
         length_mode: LengthMode::default(),
         abbreviations: None,
+        ignore_reference_definitions: true,
     };
     let rule = MD013LineLength::from_config_struct(config);
 
@@ -1997,6 +2127,8 @@ fn test_paragraphs_false_still_checks_code_blocks() {
     // Test that paragraphs=false still checks code blocks
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(50),
+        heading_line_length: None,
+        code_block_line_length: None,
         paragraphs: false, // Don't check paragraphs
         code_blocks: true, // But DO check code blocks
         tables: true,
@@ -2006,6 +2138,7 @@ fn test_paragraphs_false_still_checks_code_blocks() {
         reflow_mode: ReflowMode::default(),
         length_mode: LengthMode::default(),
         abbreviations: None,
+        ignore_reference_definitions: true,
     };
     let rule = MD013LineLength::from_config_struct(config);
 
@@ -2028,6 +2161,8 @@ fn test_paragraphs_false_still_checks_headings() {
     // Test that paragraphs=false still checks headings
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(50),
+        heading_line_length: None,
+        code_block_line_length: None,
         paragraphs: false, // Don't check paragraphs
         code_blocks: true,
         tables: true,
@@ -2037,6 +2172,7 @@ fn test_paragraphs_false_still_checks_headings() {
         reflow_mode: ReflowMode::default(),
         length_mode: LengthMode::default(),
         abbreviations: None,
+        ignore_reference_definitions: true,
     };
     let rule = MD013LineLength::from_config_struct(config);
 
@@ -2057,6 +2193,8 @@ fn test_paragraphs_false_with_reflow_sentence_per_line() {
     // Test issue #121 use case: paragraphs=false with sentence-per-line reflow
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(80),
+        heading_line_length: None,
+        code_block_line_length: None,
         paragraphs: false,
         code_blocks: true,
         tables: true,
@@ -2066,6 +2204,7 @@ fn test_paragraphs_false_with_reflow_sentence_per_line() {
         reflow_mode: ReflowMode::SentencePerLine,
         length_mode: LengthMode::default(),
         abbreviations: None,
+        ignore_reference_definitions: true,
     };
     let rule = MD013LineLength::from_config_struct(config);
 
@@ -2086,6 +2225,8 @@ fn test_paragraphs_true_checks_regular_text() {
     // Test that paragraphs=true (default) checks regular text
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(50),
+        heading_line_length: None,
+        code_block_line_length: None,
         paragraphs: true, // Default: DO check paragraphs
         code_blocks: true,
         tables: true,
@@ -2095,6 +2236,7 @@ fn test_paragraphs_true_checks_regular_text() {
         reflow_mode: ReflowMode::default(),
         length_mode: LengthMode::default(),
         abbreviations: None,
+        ignore_reference_definitions: true,
     };
     let rule = MD013LineLength::from_config_struct(config);
 
@@ -2115,6 +2257,8 @@ fn test_line_length_zero_disables_all_checks() {
     // Test that line_length = 0 disables all line length checks
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(0), // 0 = no limit
+        heading_line_length: None,
+        code_block_line_length: None,
         paragraphs: true,
         code_blocks: true,
         tables: true,
@@ -2124,6 +2268,7 @@ fn test_line_length_zero_disables_all_checks() {
         reflow_mode: ReflowMode::default(),
         length_mode: LengthMode::default(),
         abbreviations: None,
+        ignore_reference_definitions: true,
     };
     let rule = MD013LineLength::from_config_struct(config);
 
@@ -2144,6 +2289,8 @@ fn test_line_length_zero_with_headings() {
     // Test that line_length = 0 disables checks even for headings
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(0), // 0 = no limit
+        heading_line_length: None,
+        code_block_line_length: None,
         paragraphs: true,
         code_blocks: true,
         tables: true,
@@ -2153,6 +2300,7 @@ fn test_line_length_zero_with_headings() {
         reflow_mode: ReflowMode::default(),
         length_mode: LengthMode::default(),
         abbreviations: None,
+        ignore_reference_definitions: true,
     };
     let rule = MD013LineLength::from_config_struct(config);
 
@@ -2173,6 +2321,8 @@ fn test_line_length_zero_with_code_blocks() {
     // Test that line_length = 0 disables checks even for code blocks
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(0), // 0 = no limit
+        heading_line_length: None,
+        code_block_line_length: None,
         paragraphs: true,
         code_blocks: true, // Even with code_blocks enabled
         tables: true,
@@ -2182,6 +2332,7 @@ fn test_line_length_zero_with_code_blocks() {
         reflow_mode: ReflowMode::default(),
         length_mode: LengthMode::default(),
         abbreviations: None,
+        ignore_reference_definitions: true,
     };
     let rule = MD013LineLength::from_config_struct(config);
 
@@ -2202,6 +2353,8 @@ fn test_line_length_zero_with_sentence_per_line_reflow() {
     // Test issue #121 use case: line_length = 0 with sentence-per-line reflow
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(0), // 0 = no limit
+        heading_line_length: None,
+        code_block_line_length: None,
         paragraphs: true,
         code_blocks: true,
         tables: true,
@@ -2211,6 +2364,7 @@ fn test_line_length_zero_with_sentence_per_line_reflow() {
         reflow_mode: ReflowMode::SentencePerLine,
         length_mode: LengthMode::default(),
         abbreviations: None,
+        ignore_reference_definitions: true,
     };
     let rule = MD013LineLength::from_config_struct(config);
 
@@ -2259,6 +2413,8 @@ Final paragraph.
     let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
     let config = MD013Config {
         line_length: crate::types::LineLength::from_const(80),
+        heading_line_length: None,
+        code_block_line_length: None,
         code_blocks: true,
         tables: true,
         headings: true,
@@ -2268,6 +2424,7 @@ Final paragraph.
         reflow_mode: ReflowMode::SentencePerLine,
         length_mode: LengthMode::default(),
         abbreviations: None,
+        ignore_reference_definitions: true,
     };
     let rule = MD013LineLength::from_config_struct(config);
     let result = rule.check(&ctx).unwrap();
@@ -2314,3 +2471,143 @@ fn test_mixed_content_with_templates() {
     let content2 = "Start {{#something}} end";
     assert!(!is_template_directive_only(content2));
 }
+
+#[test]
+fn test_context_specific_line_lengths() {
+    let config = MD013Config {
+        line_length: crate::types::LineLength::from_const(40),
+        heading_line_length: Some(crate::types::LineLength::from_const(60)),
+        code_block_line_length: Some(crate::types::LineLength::from_const(70)),
+        ..Default::default()
+    };
+    let rule = MD013LineLength::from_config_struct(config);
+
+    let heading = format!("# {}", "word ".repeat(11).trim_end()); // 56 chars
+    let code = "let value = some_function(argument_one, argument_two); // ok"; // 60 chars
+    let prose = "This paragraph line is a bit longer than forty characters.";
+    let content = format!("{heading}\n\n```rust\n{code}\n```\n\n{prose}\n");
+    let ctx = LintContext::new(&content, MarkdownFlavor::Standard, None);
+    let warnings = rule.check(&ctx).unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].line, 7);
+    assert_eq!(warnings[0].message, "Line length 58 exceeds 40 characters");
+
+    let long_heading = format!("# {}", "word ".repeat(14).trim_end()); // 71 chars
+    let long_code = format!("{code} more text here"); // 75 chars
+    let content = format!("{long_heading}\n\n```rust\n{long_code}\n```\n");
+    let ctx = LintContext::new(&content, MarkdownFlavor::Standard, None);
+    let messages: Vec<_> = rule.check(&ctx).unwrap().into_iter().map(|w| w.message).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "Line length 71 exceeds 60 characters",
+            "Line length 75 exceeds 70 characters"
+        ]
+    );
+}
+
+#[test]
+fn test_context_line_length_with_unlimited_prose() {
+    // Prose is unlimited, but headings still have a limit
+    let config = MD013Config {
+        line_length: crate::types::LineLength::from_const(0),
+        heading_line_length: Some(crate::types::LineLength::from_const(30)),
+        ..Default::default()
+    };
+    let rule = MD013LineLength::from_config_struct(config);
+    let content = format!("# A heading that is definitely too long\n\n{}\n", "prose ".repeat(30));
+    let ctx = LintContext::new(&content, MarkdownFlavor::Standard, None);
+    let warnings = rule.check(&ctx).unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].line, 1);
+
+    // `headings = false` still exempts headings entirely
+    let config = MD013Config {
+        heading_line_length: Some(crate::types::LineLength::from_const(30)),
+        headings: false,
+        ..Default::default()
+    };
+    let rule = MD013LineLength::from_config_struct(config);
+    let ctx = LintContext::new(
+        "# A heading that is definitely too long\n",
+        MarkdownFlavor::Standard,
+        None,
+    );
+    assert!(rule.check(&ctx).unwrap().is_empty());
+}
+
+fn reflow_rule(line_length: usize) -> MD013LineLength {
+    MD013LineLength::from_config_struct(MD013Config {
+        line_length: crate::types::LineLength::from_const(line_length),
+        reflow: true,
+        ..Default::default()
+    })
+}
+
+fn reflow(rule: &MD013LineLength, content: &str) -> String {
+    let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+    rule.fix(&ctx).unwrap()
+}
+
+#[test]
+fn test_reflow_link_straddling_limit() {
+    let rule = reflow_rule(40);
+    // The link starts before column 40 and ends after it, so it moves to the next line whole
+    let content = "Read the guide first, then see [the setup page](https://example.com/setup) for more.\n";
+    assert_eq!(
+        reflow(&rule, content),
+        "Read the guide first, then see\n[the setup page](https://example.com/setup)\nfor more.\n"
+    );
+}
+
+#[test]
+fn test_reflow_keeps_inline_html_and_glued_elements() {
+    let rule = reflow_rule(40);
+    let content = "Press the <kbd class=\"key\">Enter</kbd> key to submit, or use the `submit`s list here.\n";
+    let fixed = reflow(&rule, content);
+    assert_eq!(
+        fixed,
+        "Press the <kbd class=\"key\">Enter</kbd>\nkey to submit, or use the `submit`s list\nhere.\n"
+    );
+    assert_eq!(reflow(&rule, &fixed), fixed);
+}
+
+#[test]
+fn test_reflow_nested_list_item_continuation() {
+    let rule = reflow_rule(40);
+    let content =
+        "- Parent item\n  - Nested item with text that runs well past the limit of forty.\n    continued here\n";
+    assert_eq!(
+        reflow(&rule, content),
+        "- Parent item\n  - Nested item with text that runs well\n    past the limit of forty. continued\n    here\n"
+    );
+}
+
+#[test]
+fn test_reflow_blockquote_keeps_prefix() {
+    let rule = reflow_rule(40);
+    let content = "> A quoted paragraph with `a code span` that goes past the limit.\n>\n>> Nested quote that is also long enough to need wrapping.\n";
+    let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+    let warnings = rule.check(&ctx).unwrap();
+    assert_eq!(warnings.len(), 2);
+    assert!(warnings.iter().all(|w| w.fix.is_some()));
+    assert_eq!(
+        rule.fix(&ctx).unwrap(),
+        "> A quoted paragraph with `a code span`\n> that goes past the limit.\n>\n>> Nested quote that is also long enough\n>> to need wrapping.\n"
+    );
+
+    // Quoted lists are left alone, and so are quotes inside list items
+    let content = "> - A quoted list item that goes well past the limit of forty.\n\n- Item\n\n  > A quote in a list item that goes past the limit of forty.\n";
+    assert_eq!(reflow(&rule, content), content);
+}
+
+#[test]
+fn test_reflow_leaves_wrapped_list_item_alone() {
+    // Every line fits, even though the item as a whole is longer than the limit
+    let rule = reflow_rule(40);
+    let content =
+        "- A list item that is already wrapped\n  at the limit, over several lines of\n  text that all fit.\n";
+    let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+    assert!(rule.check(&ctx).unwrap().is_empty());
+    assert_eq!(rule.fix(&ctx).unwrap(), content);
+}
@@ -247,11 +247,8 @@ impl Rule for MD018NoMissingSpaceAtx {
                     let is_unicode = get_cached_regex(UNICODE_HASHTAG_PATTERN_STR)
                         .map(|re| re.is_match(trimmed))
                         .unwrap_or(false);
-                    if is_emoji || is_unicode {
-                        continue;
-                    }
-
-                    if trimmed.len() > heading.marker.len() {
+                    // Emoji hashtags are left as they are, but still kept in the output
+                    if !is_emoji && !is_unicode && trimmed.len() > heading.marker.len() {
                         let after_marker = &trimmed[heading.marker.len()..];
                         if !after_marker.is_empty() && !after_marker.starts_with(' ') && !after_marker.starts_with('\t')
                         {
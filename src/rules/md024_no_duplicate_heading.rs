@@ -6,7 +6,7 @@ use crate::utils::range_utils::calculate_match_range;
 use std::collections::{HashMap, HashSet};
 
 mod md024_config;
-use md024_config::MD024Config;
+use md024_config::{DuplicateHeadingScope, MD024Config};
 
 #[derive(Clone, Debug, Default)]
 pub struct MD024NoDuplicateHeading {
@@ -19,6 +19,7 @@ impl MD024NoDuplicateHeading {
             config: MD024Config {
                 allow_different_nesting,
                 siblings_only,
+                scope: DuplicateHeadingScope::Siblings,
             },
         }
     }
@@ -47,7 +48,7 @@ impl Rule for MD024NoDuplicateHeading {
         let mut seen_headings: HashSet<String> = HashSet::new();
         let mut seen_headings_per_level: HashMap<u8, HashSet<String>> = HashMap::new();
 
-        // For siblings_only mode, track heading hierarchy
+        // For siblings and section scopes, track heading hierarchy
         let mut current_section_path: Vec<(u8, String)> = Vec::new(); // Stack of (level, heading_text)
         let mut seen_siblings: HashMap<String, HashSet<String>> = HashMap::new(); // parent_path -> set of child headings
 
@@ -101,18 +102,24 @@ impl Rule for MD024NoDuplicateHeading {
                     heading.text.len(),
                 );
 
-                if self.config.siblings_only {
+                let scope = self.config.scope();
+                if scope != DuplicateHeadingScope::Document {
                     // Update the section path based on the current heading level
                     while !current_section_path.is_empty() && current_section_path.last().unwrap().0 >= level {
                         current_section_path.pop();
                     }
 
-                    // Build parent path for sibling detection
-                    let parent_path = current_section_path
+                    // Build parent path for sibling detection; in section scope only the
+                    // enclosing H1/H2 headings count, and nesting levels may be told apart
+                    let mut parent_path = current_section_path
                         .iter()
+                        .filter(|(parent_level, _)| scope == DuplicateHeadingScope::Siblings || *parent_level <= 2)
                         .map(|(_, text)| text.as_str())
                         .collect::<Vec<_>>()
                         .join("/");
+                    if scope == DuplicateHeadingScope::Section && self.config.allow_different_nesting {
+                        parent_path.push_str(&format!("#{level}"));
+                    }
 
                     // Check if this heading is a duplicate among its siblings
                     let siblings = seen_siblings.entry(parent_path.clone()).or_default();
@@ -300,6 +307,7 @@ This has the same text but different level."#;
         let config = MD024Config {
             allow_different_nesting: false,
             siblings_only: false,
+            scope: DuplicateHeadingScope::Siblings,
         };
         let result = run_test(content, config);
         assert!(result.is_ok());
@@ -322,6 +330,7 @@ This has the same text but different level."#;
         let config = MD024Config {
             allow_different_nesting: true,
             siblings_only: false,
+            scope: DuplicateHeadingScope::Siblings,
         };
         let result = run_test(content, config);
         assert!(result.is_ok());
@@ -368,6 +377,7 @@ Without punctuation."#;
         let config = MD024Config {
             allow_different_nesting: false,
             siblings_only: false,
+            scope: DuplicateHeadingScope::Siblings,
         };
         let result = run_test(content, config);
         assert!(result.is_ok());
@@ -401,6 +411,7 @@ Duplicate code formatted."#;
         let config = MD024Config {
             allow_different_nesting: false,
             siblings_only: false,
+            scope: DuplicateHeadingScope::Siblings,
         };
         let result = run_test(content, config);
         assert!(result.is_ok());
@@ -427,6 +438,7 @@ Same subsection name in different section."#;
         let config = MD024Config {
             allow_different_nesting: false,
             siblings_only: false,
+            scope: DuplicateHeadingScope::Siblings,
         };
         let result = run_test(content, config);
         assert!(result.is_ok());
@@ -453,6 +465,7 @@ Same subsection name in different section."#;
         let config = MD024Config {
             allow_different_nesting: false,
             siblings_only: false,
+            scope: DuplicateHeadingScope::Siblings,
         };
         let result = run_test(content, config);
         assert!(result.is_ok());
@@ -523,6 +536,7 @@ Duplicate special chars."#;
         let config = MD024Config {
             allow_different_nesting: false,
             siblings_only: false,
+            scope: DuplicateHeadingScope::Siblings,
         };
         let result = run_test(content, config);
         assert!(result.is_ok());
@@ -554,6 +568,7 @@ Different section, but still a duplicate when allow_different_nesting is true."#
         let config = MD024Config {
             allow_different_nesting: true,
             siblings_only: false,
+            scope: DuplicateHeadingScope::Siblings,
         };
         let result = run_test(content, config);
         assert!(result.is_ok());
@@ -582,6 +597,7 @@ Duplicate with different style."#;
         let config = MD024Config {
             allow_different_nesting: false,
             siblings_only: false,
+            scope: DuplicateHeadingScope::Siblings,
         };
         let result = run_test(content, config);
         assert!(result.is_ok());
@@ -648,6 +664,7 @@ Exact match."#;
         let config = MD024Config {
             allow_different_nesting: false,
             siblings_only: false,
+            scope: DuplicateHeadingScope::Siblings,
         };
         let result = run_test(content, config);
         assert!(result.is_ok());
@@ -671,6 +688,7 @@ Exact match."#;
         let config = MD024Config {
             allow_different_nesting: false,
             siblings_only: false,
+            scope: DuplicateHeadingScope::Siblings,
         };
         let result = run_test(content, config);
         assert!(result.is_ok());
@@ -705,6 +723,7 @@ Another Overview in yet another section."#;
         let config = MD024Config {
             allow_different_nesting: true,
             siblings_only: false,
+            scope: DuplicateHeadingScope::Siblings,
         };
         let result = run_test(content, config);
         assert!(result.is_ok());
@@ -780,6 +799,7 @@ Not a duplicate."#;
         let config = MD024Config {
             allow_different_nesting: false,
             siblings_only: false,
+            scope: DuplicateHeadingScope::Siblings,
         };
         let result = run_test(content, config);
         assert!(result.is_ok());
@@ -805,6 +825,7 @@ Three in a row."#;
         let config = MD024Config {
             allow_different_nesting: false,
             siblings_only: false,
+            scope: DuplicateHeadingScope::Siblings,
         };
         let result = run_test(content, config);
         assert!(result.is_ok());
@@ -833,6 +854,7 @@ Different parent sections, so not siblings - no warning expected."#;
         let config = MD024Config {
             allow_different_nesting: false,
             siblings_only: true,
+            scope: DuplicateHeadingScope::Siblings,
         };
         let result = run_test(content, config);
         assert!(result.is_ok());
@@ -862,6 +884,7 @@ This 'First Subsection' IS a sibling duplicate."#;
         let config = MD024Config {
             allow_different_nesting: false,
             siblings_only: true,
+            scope: DuplicateHeadingScope::Siblings,
         };
         let result = run_test(content, config);
         assert!(result.is_ok());
@@ -885,6 +908,7 @@ Duplicate with code span."#;
         let config = MD024Config {
             allow_different_nesting: false,
             siblings_only: false,
+            scope: DuplicateHeadingScope::Siblings,
         };
         let result = run_test(content, config);
         assert!(result.is_ok());
@@ -902,6 +926,7 @@ Duplicate with code span."#;
         let config = MD024Config {
             allow_different_nesting: false,
             siblings_only: false,
+            scope: DuplicateHeadingScope::Siblings,
         };
         let result = run_test(&content, config);
         assert!(result.is_ok());
@@ -924,6 +949,7 @@ Duplicate with HTML entity."#;
         let config = MD024Config {
             allow_different_nesting: false,
             siblings_only: false,
+            scope: DuplicateHeadingScope::Siblings,
         };
         let result = run_test(content, config);
         assert!(result.is_ok());
@@ -948,6 +974,7 @@ All same text, different levels."#;
         let config = MD024Config {
             allow_different_nesting: true,
             siblings_only: false,
+            scope: DuplicateHeadingScope::Siblings,
         };
         let result = run_test(content, config);
         assert!(result.is_ok());
@@ -955,4 +982,47 @@ All same text, different levels."#;
         // With allow_different_nesting, there should be no warnings
         assert_eq!(warnings.len(), 0);
     }
+
+    #[test]
+    fn test_section_scope() {
+        let content = "# Changelog\n\n## 1.1.0\n\n### Added\n\n### Fixed\n\n## 1.0.0\n\n### Added\n\n### Fixed\n";
+        let config = MD024Config {
+            scope: DuplicateHeadingScope::Section,
+            ..Default::default()
+        };
+        assert!(run_test(content, config.clone()).unwrap().is_empty());
+
+        // Unlike siblings scope, duplicates deeper in the same H2 section are reported
+        let content = "## 1.0.0\n\n### Added\n\n#### Details\n\n### Fixed\n\n#### Details\n\n## 0.9.0\n\n### Fixed\n\n#### Details\n";
+        let warnings = run_test(content, config.clone()).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 9);
+        assert!(run_test(content, MD024Config::default()).unwrap().is_empty());
+
+        // Headings at different levels are told apart with allow-different-nesting
+        let content = "## 1.0.0\n\n### Notes\n\n#### Notes\n";
+        assert_eq!(run_test(content, config.clone()).unwrap().len(), 1);
+        let config = MD024Config {
+            allow_different_nesting: true,
+            ..config
+        };
+        assert!(run_test(content, config).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_scope_and_siblings_only() {
+        let content = "# A\n\n## Setup\n\n# B\n\n## Setup\n";
+        let config = MD024Config {
+            scope: DuplicateHeadingScope::Document,
+            ..Default::default()
+        };
+        assert_eq!(run_test(content, config).unwrap().len(), 1);
+        assert!(run_test(content, MD024Config::default()).unwrap().is_empty());
+
+        // `siblings-only = false` still means document scope, and doesn't affect section scope
+        let config: MD024Config = toml::from_str("siblings-only = false").unwrap();
+        assert_eq!(config.scope(), DuplicateHeadingScope::Document);
+        let config: MD024Config = toml::from_str("scope = \"section\"\nsiblings-only = false").unwrap();
+        assert_eq!(config.scope(), DuplicateHeadingScope::Section);
+    }
 }
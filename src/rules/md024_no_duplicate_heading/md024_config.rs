@@ -18,6 +18,36 @@ pub struct MD024Config {
     /// (GitHub, GitLab, etc.) handle this by adding numeric suffixes.
     #[serde(default = "default_siblings_only", alias = "siblings_only")]
     pub siblings_only: bool,
+
+    /// Where duplicates are looked for (default: siblings)
+    ///
+    /// For compatibility, `siblings_only = false` turns the default `siblings` scope into `document`.
+    #[serde(default)]
+    pub scope: DuplicateHeadingScope,
+}
+
+/// Which headings a heading is compared against
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DuplicateHeadingScope {
+    /// Every other heading in the document
+    Document,
+    /// Headings with the same parent heading
+    #[default]
+    Siblings,
+    /// Headings in the same H1/H2 section, at any depth
+    Section,
+}
+
+impl MD024Config {
+    /// The effective scope, taking `siblings_only = false` into account
+    pub fn scope(&self) -> DuplicateHeadingScope {
+        if !self.siblings_only && self.scope == DuplicateHeadingScope::Siblings {
+            DuplicateHeadingScope::Document
+        } else {
+            self.scope
+        }
+    }
 }
 
 fn default_siblings_only() -> bool {
@@ -29,6 +59,7 @@ impl Default for MD024Config {
         Self {
             allow_different_nesting: false,
             siblings_only: true,
+            scope: DuplicateHeadingScope::Siblings,
         }
     }
 }
@@ -3,13 +3,19 @@
 /// See [docs/md029.md](../../docs/md029.md) for full documentation, configuration, and examples.
 use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::rule_config_serde::RuleConfig;
-use crate::utils::regex_cache::ORDERED_LIST_MARKER_REGEX;
 use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
 use toml;
 
 mod md029_config;
 pub use md029_config::{ListStyle, MD029Config};
 
+/// An ordered list item: line number, line info and list item info
+type OrderedItem<'a> = (
+    usize,
+    &'a crate::lint_context::LineInfo,
+    &'a crate::lint_context::ListItemInfo,
+);
+
 #[derive(Debug, Clone, Default)]
 pub struct MD029OrderedListPrefix {
     config: MD029Config,
@@ -18,7 +24,10 @@ pub struct MD029OrderedListPrefix {
 impl MD029OrderedListPrefix {
     pub fn new(style: ListStyle) -> Self {
         Self {
-            config: MD029Config { style },
+            config: MD029Config {
+                style,
+                ..Default::default()
+            },
         }
     }
 
@@ -26,17 +35,6 @@ impl MD029OrderedListPrefix {
         Self { config }
     }
 
-    #[inline]
-    fn parse_marker_number(marker: &str) -> Option<usize> {
-        // Handle marker format like "1." or "1"
-        let num_part = if let Some(stripped) = marker.strip_suffix('.') {
-            stripped
-        } else {
-            marker
-        };
-        num_part.parse::<usize>().ok()
-    }
-
     #[inline]
     fn get_expected_number(&self, index: usize, detected_style: Option<ListStyle>) -> usize {
         // Use detected_style when the configuration is auto-detect mode (OneOrOrdered or Consistent)
@@ -70,8 +68,8 @@ impl MD029OrderedListPrefix {
             return ListStyle::OneOne;
         }
 
-        let first_num = Self::parse_marker_number(&items[0].2.marker);
-        let second_num = Self::parse_marker_number(&items[1].2.marker);
+        let first_num = items[0].2.number;
+        let second_num = items[1].2.number;
 
         // Fast path: Check for Ordered0 special case (starts with 0, 1)
         if matches!((first_num, second_num), (Some(0), Some(1))) {
@@ -86,9 +84,7 @@ impl MD029OrderedListPrefix {
 
         // Slow path: Both first items are "1", check if ALL are "1" (O(n))
         // This is necessary for lists like "1. 1. 1..." vs "1. 1. 2. 3..."
-        let all_ones = items
-            .iter()
-            .all(|(_, _, item)| Self::parse_marker_number(&item.marker) == Some(1));
+        let all_ones = items.iter().all(|(_, _, item)| item.number == Some(1));
 
         if all_ones {
             ListStyle::OneOne
@@ -207,6 +203,111 @@ impl MD029OrderedListPrefix {
         result
     }
 
+    /// Join lists that a code block or paragraph split in two, so numbering carries on
+    ///
+    /// A list that starts over at 0 or 1 is taken as a deliberate restart. Headings, thematic
+    /// breaks, HTML comments (the usual way to end a list on purpose) and other lists always
+    /// end a list.
+    fn join_interrupted_lists<'a>(
+        ctx: &crate::lint_context::LintContext,
+        groups: Vec<Vec<OrderedItem<'a>>>,
+    ) -> Vec<Vec<OrderedItem<'a>>> {
+        let mut joined: Vec<Vec<OrderedItem>> = Vec::with_capacity(groups.len());
+        for group in groups {
+            let Some(&(first_line, _, first_item)) = group.first() else {
+                continue;
+            };
+            let continues = first_item.number.is_some_and(|number| number > 1);
+            let previous = joined.iter().rposition(|prev| {
+                prev.first()
+                    .is_some_and(|&(_, _, item)| item.marker_column == first_item.marker_column)
+            });
+            match previous {
+                Some(idx) if continues && Self::is_interruption(ctx, &joined[idx], first_line, first_item) => {
+                    joined[idx].extend(group);
+                }
+                _ => joined.push(group),
+            }
+        }
+        joined
+    }
+
+    /// Whether only non-list blocks separate `previous` from the list starting at `first_line`
+    fn is_interruption(
+        ctx: &crate::lint_context::LintContext,
+        previous: &[OrderedItem],
+        first_line: usize,
+        first_item: &crate::lint_context::ListItemInfo,
+    ) -> bool {
+        let Some(&(last_line, last_info, last_item)) = previous.last() else {
+            return false;
+        };
+        let Some(first_info) = ctx.line_info(first_line) else {
+            return false;
+        };
+        // Same delimiter (`.` or `)`) and same container, e.g. the same blockquote
+        let column = first_item.marker_column;
+        if last_item.marker.chars().last() != first_item.marker.chars().last()
+            || last_info.content(ctx.content).get(..column) != first_info.content(ctx.content).get(..column)
+        {
+            return false;
+        }
+
+        (last_line + 1..first_line).all(|line_num| {
+            let Some(line_info) = ctx.line_info(line_num) else {
+                return false;
+            };
+            if line_info.in_code_block {
+                return true;
+            }
+            // Items outside the previous item's content start another list
+            let trimmed = line_info.content(ctx.content).trim();
+            line_info.heading.is_none()
+                && !line_info.in_html_comment
+                && line_info
+                    .list_item
+                    .as_ref()
+                    .is_none_or(|item| item.marker_column >= last_item.content_column)
+                && !["---", "***", "___"].iter().any(|rule| trimmed.starts_with(rule))
+        })
+    }
+
+    /// Indent the continuation lines of an item whose number gets longer (`9.` -> `10.`)
+    ///
+    /// Without this, paragraphs and nested lists indented to the old content column
+    /// would fall out of the item once the marker is wider.
+    fn continuation_indent_fixes(
+        ctx: &crate::lint_context::LintContext,
+        line_num: usize,
+        widened_by: usize,
+    ) -> Vec<Fix> {
+        let Some(list_item) = ctx.line_info(line_num).and_then(|info| info.list_item.as_ref()) else {
+            return Vec::new();
+        };
+
+        let mut fixes = Vec::new();
+        for (idx, line_info) in ctx.lines.iter().enumerate().skip(line_num) {
+            let line = line_info.content(ctx.content);
+            // Blank lines (including a bare `>` inside a blockquote) don't end the item
+            if line.trim_start_matches(['>', ' ', '\t']).is_empty() {
+                continue;
+            }
+            let indented = line
+                .get(list_item.marker_column..list_item.content_column)
+                .is_some_and(|gap| gap.chars().all(|c| c == ' ' || c == '\t'));
+            if !indented {
+                break;
+            }
+
+            let insert_at = ctx.line_offsets[idx] + list_item.marker_column;
+            fixes.push(Fix {
+                range: insert_at..insert_at,
+                replacement: " ".repeat(widened_by),
+            });
+        }
+        fixes
+    }
+
     /// Check a CommonMark-grouped list for correct ordering
     fn check_commonmark_list_group(
         &self,
@@ -260,7 +361,7 @@ impl MD029OrderedListPrefix {
 
             // Check each item
             for (idx, (line_num, line_info, list_item)) in items.iter().enumerate() {
-                if let Some(actual_num) = Self::parse_marker_number(&list_item.marker) {
+                if let Some(actual_num) = list_item.number {
                     let expected_num = self.get_expected_number(idx, detected_style.clone());
 
                     if actual_num != expected_num {
@@ -325,8 +426,12 @@ impl Rule for MD029OrderedListPrefix {
             return Ok(Vec::new());
         }
 
-        // Quick check for any ordered list markers before processing
-        if !ctx.content.contains('.') || !ctx.content.lines().any(|line| ORDERED_LIST_MARKER_REGEX.is_match(line)) {
+        // Quick check for any ordered list items (including ones inside blockquotes) before processing
+        if !ctx
+            .lines
+            .iter()
+            .any(|line| line.list_item.as_ref().is_some_and(|item| item.is_ordered))
+        {
             return Ok(Vec::new());
         }
 
@@ -335,7 +440,10 @@ impl Rule for MD029OrderedListPrefix {
         // Use pulldown-cmark's AST for authoritative list membership.
         // This fixes issues where heuristic-based grouping incorrectly splits lists.
         let line_to_list = Self::build_commonmark_list_membership(ctx.content);
-        let list_groups = Self::group_items_by_commonmark_list(ctx, &line_to_list);
+        let mut list_groups = Self::group_items_by_commonmark_list(ctx, &line_to_list);
+        if !self.config.restart_after_interruption {
+            list_groups = Self::join_interrupted_lists(ctx, list_groups);
+        }
 
         if list_groups.is_empty() {
             return Ok(Vec::new());
@@ -381,10 +489,14 @@ impl Rule for MD029OrderedListPrefix {
         }
 
         // Collect fixes and sort by position
-        let mut fixes: Vec<&Fix> = Vec::new();
+        let mut fixes: Vec<Fix> = Vec::new();
         for warning in &warnings {
             if let Some(ref fix) = warning.fix {
-                fixes.push(fix);
+                let widened_by = fix.replacement.len().saturating_sub(fix.range.len());
+                if widened_by > 0 {
+                    fixes.extend(Self::continuation_indent_fixes(ctx, warning.line, widened_by));
+                }
+                fixes.push(fix.clone());
             }
         }
         fixes.sort_by_key(|f| f.range.start);
@@ -24,6 +24,10 @@ pub struct MD029Config {
     /// Style for ordered list numbering (default: "one-or-ordered" - matches markdownlint)
     #[serde(default)]
     pub style: ListStyle,
+
+    /// Start numbering over when a code block or paragraph interrupts a list (default: false)
+    #[serde(default, alias = "restart_after_interruption")]
+    pub restart_after_interruption: bool,
 }
 
 impl RuleConfig for MD029Config {
@@ -102,6 +102,23 @@ impl MD032BlanksAroundLists {
         true
     }
 
+    /// Check if a "list" directly under a paragraph line is really a lazy continuation of it.
+    ///
+    /// A marker indented four or more columns past the paragraph can't start a list item,
+    /// and inserting a blank line would turn it into an indented code block instead.
+    fn is_lazy_continuation(ctx: &crate::lint_context::LintContext, start_line: usize) -> bool {
+        let (Some(prev), Some(current)) = (ctx.line_info(start_line - 1), ctx.line_info(start_line)) else {
+            return false;
+        };
+        !prev.is_blank
+            && prev.list_item.is_none()
+            && prev.blockquote.is_none()
+            && current.blockquote.is_none()
+            && !prev.in_code_block
+            && current.indent >= prev.indent + 4
+            && !Self::is_nested_list(ctx, start_line - 1, start_line)
+    }
+
     /// Check if the current list is nested within another list item
     fn is_nested_list(
         ctx: &crate::lint_context::LintContext,
@@ -129,7 +146,7 @@ impl MD032BlanksAroundLists {
     fn convert_list_blocks(&self, ctx: &crate::lint_context::LintContext) -> Vec<(usize, usize, String)> {
         let mut blocks: Vec<(usize, usize, String)> = Vec::new();
 
-        for block in &ctx.list_blocks {
+        for block in ctx.list_blocks() {
             // For MD032, we need to check if there are code blocks that should
             // split the list into separate segments
 
@@ -324,6 +341,10 @@ impl MD032BlanksAroundLists {
         }
 
         for &(start_line, end_line, ref prefix) in list_blocks {
+            if start_line > 1 && Self::is_lazy_continuation(ctx, start_line) {
+                continue;
+            }
+
             if start_line > 1 {
                 let prev_line_actual_idx_0 = start_line - 2;
                 let prev_line_actual_idx_1 = start_line - 1;
@@ -441,7 +462,7 @@ impl Rule for MD032BlanksAroundLists {
             return true;
         }
         // Verify list blocks actually exist
-        ctx.list_blocks.is_empty()
+        ctx.list_blocks().is_empty()
     }
 
     fn category(&self) -> RuleCategory {
@@ -478,6 +499,10 @@ impl MD032BlanksAroundLists {
 
         // Phase 1: Identify needed insertions
         for &(start_line, end_line, ref prefix) in &list_blocks {
+            if start_line > 1 && Self::is_lazy_continuation(ctx, start_line) {
+                continue;
+            }
+
             // Check before block
             if start_line > 1 {
                 let prev_line_actual_idx_0 = start_line - 2;
@@ -6,22 +6,27 @@
 use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::utils::kramdown_utils::{is_kramdown_block_attribute, is_kramdown_extension};
 use crate::utils::regex_cache::*;
-use std::collections::HashSet;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
 
 mod md033_config;
-use md033_config::MD033Config;
+use md033_config::{AttributeRequirement, MD033Config};
+
+/// An attribute in an HTML tag, with its optional double-quoted, single-quoted or bare value
+static HTML_ATTRIBUTE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"([^\s"'<>/=]+)(?:\s*=\s*(?:"([^"]*)"|'([^']*)'|([^\s"'=<>`]+)))?"#).unwrap());
 
 #[derive(Clone)]
 pub struct MD033NoInlineHtml {
     config: MD033Config,
     allowed: HashSet<String>,
+    required_attributes: HashMap<String, Vec<AttributeRequirement>>,
 }
 
 impl Default for MD033NoInlineHtml {
     fn default() -> Self {
-        let config = MD033Config::default();
-        let allowed = config.allowed_set();
-        Self { config, allowed }
+        Self::from_config_struct(MD033Config::default())
     }
 }
 
@@ -31,16 +36,20 @@ impl MD033NoInlineHtml {
     }
 
     pub fn with_allowed(allowed_vec: Vec<String>) -> Self {
-        let config = MD033Config {
-            allowed: allowed_vec.clone(),
-        };
-        let allowed = config.allowed_set();
-        Self { config, allowed }
+        Self::from_config_struct(MD033Config {
+            allowed: allowed_vec,
+            ..Default::default()
+        })
     }
 
     pub fn from_config_struct(config: MD033Config) -> Self {
         let allowed = config.allowed_set();
-        Self { config, allowed }
+        let required_attributes = config.attribute_requirements();
+        Self {
+            config,
+            allowed,
+            required_attributes,
+        }
     }
 
     // Efficient check for allowed tags using HashSet (case-insensitive)
@@ -58,6 +67,40 @@ impl MD033NoInlineHtml {
         self.allowed.contains(&tag_name.to_lowercase())
     }
 
+    /// The first `allowed-attributes` requirement an allowed tag doesn't meet, if any
+    ///
+    /// An attribute without a value only has to be present; `name=value` requires `value`
+    /// among the attribute's space-separated values (as in `rel`).
+    fn missing_attribute(&self, tag_name: &str, tag: &str) -> Option<String> {
+        let requirements = self.required_attributes.get(&tag_name.to_lowercase())?;
+
+        // Attributes follow the tag name
+        let rest = tag.trim_start_matches('<');
+        let rest = &rest[rest
+            .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+            .unwrap_or(rest.len())..];
+        let attributes: Vec<(&str, Option<&str>)> = HTML_ATTRIBUTE_REGEX
+            .captures_iter(rest)
+            .map(|cap| {
+                let value = cap.get(2).or(cap.get(3)).or(cap.get(4)).map(|m| m.as_str());
+                (cap.get(1).unwrap().as_str(), value)
+            })
+            .collect();
+
+        requirements.iter().find_map(|(name, expected)| {
+            let present = attributes.iter().any(|(attr, value)| {
+                attr.eq_ignore_ascii_case(name)
+                    && expected.as_deref().is_none_or(|expected| {
+                        value.is_some_and(|value| value.split_whitespace().any(|word| word == expected))
+                    })
+            });
+            (!present).then(|| match expected {
+                Some(expected) => format!("{name}=\"{expected}\""),
+                None => name.clone(),
+            })
+        })
+    }
+
     // Check if a tag is an HTML comment
     #[inline]
     fn is_html_comment(&self, tag: &str) -> bool {
@@ -330,8 +373,23 @@ impl Rule for MD033NoInlineHtml {
                 continue;
             }
 
-            // Skip allowed tags
+            // Skip allowed tags, unless they lack an attribute they are only allowed with
             if self.is_tag_allowed(tag) {
+                if let Some(required) = self.missing_attribute(&html_tag.tag_name, tag) {
+                    warnings.push(LintWarning {
+                        rule_name: Some(self.name().to_string()),
+                        line: line_num,
+                        column: html_tag.start_col + 1,
+                        end_line: line_num,
+                        end_column: html_tag.end_col + 1,
+                        message: format!(
+                            "Inline HTML <{}> is only allowed with attribute {required}: {tag}",
+                            html_tag.tag_name
+                        ),
+                        severity: Severity::Warning,
+                        fix: None,
+                    });
+                }
                 continue;
             }
 
@@ -625,4 +683,37 @@ mod tests {
         assert_eq!(&content[fix2.range.clone()], "<strong>second</strong>");
         assert_eq!(fix2.replacement, "second");
     }
+
+    #[test]
+    fn test_md033_allowed_attributes() {
+        let config: MD033Config = toml::from_str(
+            r#"
+allowed-elements = ["br"]
+allowed-attributes = ["img[alt]", "A[href][rel=\"noopener\"]"]
+"#,
+        )
+        .unwrap();
+        let rule = MD033NoInlineHtml::from_config_struct(config);
+
+        let content = "<img src=\"a.png\" alt=\"A\"> <IMG ALT='' src=b.png /> <br>\n\n<a href=\"x\" rel=\"noopener noreferrer\">x</a>";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        assert!(rule.check(&ctx).unwrap().is_empty());
+
+        let content = "<img src=\"a.png\">\n\n<a href=\"x\" rel=\"nofollow\">x</a> <span>y</span>";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(
+            result[0].message,
+            "Inline HTML <img> is only allowed with attribute alt: <img src=\"a.png\">"
+        );
+        assert!(result[0].fix.is_none());
+        assert_eq!(result[1].line, 3);
+        assert!(
+            result[1]
+                .message
+                .contains("only allowed with attribute rel=\"noopener\"")
+        );
+        assert_eq!(result[2].message, "Inline HTML found: <span>");
+    }
 }
@@ -1,17 +1,57 @@
 use crate::rule_config_serde::RuleConfig;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct MD033Config {
     #[serde(default, rename = "allowed-elements", alias = "allowed_elements", alias = "allowed")]
     pub allowed: Vec<String>,
+
+    /// Tags allowed only with certain attributes, e.g. `img[alt]` or `a[href][rel=noopener]`
+    #[serde(default, rename = "allowed-attributes", alias = "allowed_attributes")]
+    pub allowed_attributes: Vec<String>,
 }
 
+/// An attribute an allowed tag must carry, and a value it must include if any
+pub type AttributeRequirement = (String, Option<String>);
+
 impl MD033Config {
     /// Convert allowed elements to HashSet for efficient lookup
+    ///
+    /// Tags in `allowed-attributes` are included; their attributes are checked separately.
     pub fn allowed_set(&self) -> HashSet<String> {
-        self.allowed.iter().map(|s| s.to_lowercase()).collect()
+        self.allowed
+            .iter()
+            .map(|s| s.to_lowercase())
+            .chain(self.attribute_requirements().into_keys())
+            .collect()
+    }
+
+    /// Parse `allowed-attributes` selectors into the attributes each tag requires, by lowercase tag name
+    pub fn attribute_requirements(&self) -> HashMap<String, Vec<AttributeRequirement>> {
+        let mut requirements: HashMap<String, Vec<AttributeRequirement>> = HashMap::new();
+        for selector in &self.allowed_attributes {
+            let mut parts = selector.trim().split('[');
+            let tag = parts.next().unwrap_or("").trim().to_lowercase();
+            if tag.is_empty() {
+                continue;
+            }
+            let required = requirements.entry(tag).or_default();
+            for part in parts {
+                let constraint = part.trim().trim_end_matches(']');
+                let requirement = match constraint.split_once('=') {
+                    Some((name, value)) => (
+                        name.trim().to_string(),
+                        Some(value.trim().trim_matches(['"', '\'']).to_string()),
+                    ),
+                    None => (constraint.trim().to_string(), None),
+                };
+                if !requirement.0.is_empty() {
+                    required.push(requirement);
+                }
+            }
+        }
+        requirements
     }
 }
 
@@ -1,6 +1,7 @@
 use crate::rule::{LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::rule_config_serde::RuleConfig;
 use crate::utils::range_utils::calculate_heading_range;
+use crate::utils::regex_cache::get_cached_regex;
 use serde::{Deserialize, Serialize};
 
 /// Configuration for MD043 rule
@@ -36,6 +37,35 @@ impl RuleConfig for MD043Config {
     const RULE_NAME: &'static str = "MD043";
 }
 
+/// A single entry of the `headings` template
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum HeadingPattern<'a> {
+    /// `*`
+    ZeroOrMore,
+    /// `+`
+    OneOrMore,
+    /// `?`
+    ExactlyOne,
+    /// A heading that must be present, e.g. `## Usage`
+    Required(&'a str),
+    /// A heading prefixed with `?`, e.g. `?## FAQ`
+    Optional(&'a str),
+}
+
+impl<'a> HeadingPattern<'a> {
+    fn parse(pattern: &'a str) -> Self {
+        match pattern {
+            "*" => Self::ZeroOrMore,
+            "+" => Self::OneOrMore,
+            "?" => Self::ExactlyOne,
+            _ => match pattern.strip_prefix('?') {
+                Some(optional) => Self::Optional(optional.trim_start()),
+                None => Self::Required(pattern),
+            },
+        }
+    }
+}
+
 /// Rule MD043: Required headings present
 ///
 /// See [docs/md043.md](../../docs/md043.md) for full documentation, configuration, and examples.
@@ -59,13 +89,30 @@ impl MD043RequiredHeadings {
         Self { config }
     }
 
-    /// Compare two headings based on the match_case configuration
+    /// Compare a heading against a template entry based on the match_case configuration
+    ///
+    /// An entry wrapped in slashes, like `/## Release \d+/`, is a regex searched for in the
+    /// heading. An entry containing `*`, like `## Release *`, must match the whole heading,
+    /// with each `*` standing for any text. Any other entry must match the heading exactly.
     fn headings_match(&self, expected: &str, actual: &str) -> bool {
-        if self.config.match_case {
-            expected == actual
+        let pattern = if let Some(regex) = expected
+            .strip_prefix('/')
+            .and_then(|rest| rest.strip_suffix('/'))
+            .filter(|regex| !regex.is_empty())
+        {
+            regex.to_string()
+        } else if expected.contains('*') {
+            let parts: Vec<String> = expected.split('*').map(regex::escape).collect();
+            format!("^(?:{})$", parts.join(".*"))
+        } else if self.config.match_case {
+            return expected == actual;
         } else {
-            expected.to_lowercase() == actual.to_lowercase()
-        }
+            return expected.to_lowercase() == actual.to_lowercase();
+        };
+
+        let flags = if self.config.match_case { "" } else { "(?i)" };
+        // An invalid regex never matches, so the entry is reported as a missing heading
+        get_cached_regex(&format!("{flags}{pattern}")).is_ok_and(|re| re.is_match(actual))
     }
 
     fn extract_headings(&self, ctx: &crate::lint_context::LintContext) -> Vec<String> {
@@ -88,90 +135,119 @@ impl MD043RequiredHeadings {
     /// - `*` - Zero or more unspecified headings
     /// - `+` - One or more unspecified headings
     /// - `?` - Exactly one unspecified heading
+    /// - `?## Heading` - An optional heading that may be omitted
     ///
-    /// Returns (matched, expected_index, actual_index) indicating whether
-    /// all patterns were satisfied and the final positions in both sequences.
-    fn match_headings_with_wildcards(
-        &self,
-        actual_headings: &[String],
-        expected_patterns: &[String],
-    ) -> (bool, usize, usize) {
-        let mut exp_idx = 0;
-        let mut act_idx = 0;
-        let mut match_any = false; // Flexible matching mode for * and +
-
-        while exp_idx < expected_patterns.len() && act_idx < actual_headings.len() {
-            let pattern = &expected_patterns[exp_idx];
-
-            if pattern == "*" {
-                // Zero or more headings: peek ahead to next required pattern
-                exp_idx += 1;
-                if exp_idx >= expected_patterns.len() {
-                    // * at end means rest of headings are allowed
-                    return (true, exp_idx, actual_headings.len());
-                }
-                // Enable flexible matching until we find next required pattern
-                match_any = true;
-                continue;
-            } else if pattern == "+" {
-                // One or more headings: consume at least one
-                if act_idx >= actual_headings.len() {
-                    return (false, exp_idx, act_idx); // Need at least one heading
-                }
-                act_idx += 1;
-                exp_idx += 1;
-                // Enable flexible matching for remaining headings
-                match_any = true;
-                // If + is at the end, consume all remaining headings
-                if exp_idx >= expected_patterns.len() {
-                    return (true, exp_idx, actual_headings.len());
+    /// `*` and `+` stop at the first heading that matches one of the listed headings after
+    /// them, so `["*", "## Target"]` does not accept a duplicate `## Target`.
+    ///
+    /// Returns whether the whole heading sequence matches the whole pattern list.
+    fn match_headings_with_wildcards(&self, actual_headings: &[String], expected_patterns: &[String]) -> bool {
+        // `+` is `?` followed by `*`
+        let patterns: Vec<HeadingPattern> = expected_patterns
+            .iter()
+            .flat_map(|p| match HeadingPattern::parse(p) {
+                HeadingPattern::OneOrMore => vec![HeadingPattern::ExactlyOne, HeadingPattern::ZeroOrMore],
+                pattern => vec![pattern],
+            })
+            .collect();
+
+        let n = actual_headings.len();
+        let m = patterns.len();
+
+        // Headings a `*` at position j must not swallow: the listed headings up to the next required one
+        let stops: Vec<Vec<&str>> = (0..m)
+            .map(|j| {
+                let mut stop = Vec::new();
+                for pattern in &patterns[j + 1..] {
+                    match pattern {
+                        HeadingPattern::Optional(expected) => stop.push(*expected),
+                        HeadingPattern::Required(expected) => {
+                            stop.push(*expected);
+                            break;
+                        }
+                        _ => {}
+                    }
                 }
-                continue;
-            } else if pattern == "?" {
-                // Exactly one unspecified heading
-                act_idx += 1;
-                exp_idx += 1;
-                match_any = false;
-                continue;
-            }
-
-            // Literal pattern matching
-            let actual = &actual_headings[act_idx];
-            if self.headings_match(pattern, actual) {
-                // Exact match found
-                act_idx += 1;
-                exp_idx += 1;
-                match_any = false;
-            } else if match_any {
-                // In flexible mode, try next heading
-                act_idx += 1;
-            } else {
-                // No match and not in flexible mode
-                return (false, exp_idx, act_idx);
+                stop
+            })
+            .collect();
+
+        // matches[i][j]: patterns[j..] match headings[i..]
+        let mut matches = vec![vec![false; m + 1]; n + 1];
+        matches[n][m] = true;
+
+        for j in (0..m).rev() {
+            for i in (0..=n).rev() {
+                let has_heading = i < n;
+                matches[i][j] = match patterns[j] {
+                    HeadingPattern::ZeroOrMore => {
+                        matches[i][j + 1]
+                            || (has_heading
+                                && !stops[j]
+                                    .iter()
+                                    .any(|expected| self.headings_match(expected, &actual_headings[i]))
+                                && matches[i + 1][j])
+                    }
+                    HeadingPattern::OneOrMore | HeadingPattern::ExactlyOne => has_heading && matches[i + 1][j + 1],
+                    HeadingPattern::Required(expected) => {
+                        has_heading && self.headings_match(expected, &actual_headings[i]) && matches[i + 1][j + 1]
+                    }
+                    HeadingPattern::Optional(expected) => {
+                        matches[i][j + 1]
+                            || (has_heading
+                                && self.headings_match(expected, &actual_headings[i])
+                                && matches[i + 1][j + 1])
+                    }
+                };
             }
         }
 
-        // Handle remaining patterns
-        while exp_idx < expected_patterns.len() {
-            let pattern = &expected_patterns[exp_idx];
-            if pattern == "*" {
-                // * allows zero headings, continue
-                exp_idx += 1;
-            } else if pattern == "+" {
-                // + requires at least one heading but we're out of headings
-                return (false, exp_idx, act_idx);
-            } else if pattern == "?" {
-                // ? requires exactly one heading but we're out
-                return (false, exp_idx, act_idx);
+        matches[0][0]
+    }
+
+    /// Explain a failed match in terms of the required (non-wildcard, non-optional) headings
+    ///
+    /// Returns one `(heading index, message)` per required heading that is missing or out of
+    /// order. The heading index points at the heading where the required one was expected, or
+    /// is `None` when that position is past the last heading.
+    fn describe_required_mismatches(&self, actual_headings: &[String]) -> Vec<(Option<usize>, String)> {
+        let mut problems = Vec::new();
+        let mut cursor = 0;
+        let mut previous: Option<&str> = None;
+
+        for pattern in &self.config.headings {
+            let HeadingPattern::Required(expected) = HeadingPattern::parse(pattern) else {
+                continue;
+            };
+
+            let found_at = actual_headings[cursor..]
+                .iter()
+                .position(|actual| self.headings_match(expected, actual))
+                .map(|offset| cursor + offset);
+
+            if let Some(idx) = found_at {
+                cursor = idx + 1;
+            } else if let Some(idx) = actual_headings[..cursor]
+                .iter()
+                .position(|actual| self.headings_match(expected, actual))
+            {
+                let message = match previous {
+                    Some(prev) => format!("Required heading '{expected}' is out of order (expected after '{prev}')"),
+                    None => format!("Required heading '{expected}' is out of order"),
+                };
+                problems.push((Some(idx), message));
             } else {
-                // Literal pattern not satisfied
-                return (false, exp_idx, act_idx);
+                let message = match previous {
+                    Some(prev) => format!("Missing required heading '{expected}' (expected after '{prev}')"),
+                    None => format!("Missing required heading '{expected}'"),
+                };
+                problems.push(((cursor < actual_headings.len()).then_some(cursor), message));
             }
+
+            previous = Some(expected);
         }
 
-        // Check if we consumed all actual headings
-        let all_matched = act_idx == actual_headings.len() && exp_idx == expected_patterns.len();
-        (all_matched, exp_idx, act_idx)
+        problems
     }
 
     fn is_heading(&self, line_index: usize, ctx: &crate::lint_context::LintContext) -> bool {
@@ -201,17 +277,9 @@ impl Rule for MD043RequiredHeadings {
             return Ok(warnings);
         }
 
-        // Check if all patterns are only * wildcards (which allow zero headings)
-        let all_optional_wildcards = self.config.headings.iter().all(|p| p == "*");
-        if actual_headings.is_empty() && all_optional_wildcards {
-            // Allow empty documents when only * wildcards are specified
-            // (? and + require at least some headings)
-            return Ok(warnings);
-        }
-
-        // Use wildcard matching for pattern support
-        let (headings_match, _exp_idx, _act_idx) =
-            self.match_headings_with_wildcards(&actual_headings, &self.config.headings);
+        // Use wildcard matching for pattern support (also allows empty documents
+        // when only `*` wildcards and optional headings are specified)
+        let headings_match = self.match_headings_with_wildcards(&actual_headings, &self.config.headings);
 
         if !headings_match {
             // If no headings found but we have required headings, create a warning
@@ -229,7 +297,33 @@ impl Rule for MD043RequiredHeadings {
                 return Ok(warnings);
             }
 
-            // Create warnings for each heading that doesn't match
+            let heading_lines: Vec<usize> = (0..ctx.lines.len()).filter(|&i| self.is_heading(i, ctx)).collect();
+
+            // Point at the specific required headings that are missing or misordered
+            let problems = self.describe_required_mismatches(&actual_headings);
+            if !problems.is_empty() {
+                for (heading_idx, message) in problems {
+                    let line_idx = heading_idx
+                        .map_or_else(|| heading_lines.last().copied().unwrap_or(0), |idx| heading_lines[idx]);
+                    let (start_line, start_col, end_line, end_col) =
+                        calculate_heading_range(line_idx + 1, ctx.lines[line_idx].content(ctx.content));
+
+                    warnings.push(LintWarning {
+                        rule_name: Some(self.name().to_string()),
+                        line: start_line,
+                        column: start_col,
+                        end_line,
+                        end_column: end_col,
+                        message,
+                        severity: Severity::Warning,
+                        fix: None,
+                    });
+                }
+                return Ok(warnings);
+            }
+
+            // Otherwise the required headings are all in place, but the headings around them
+            // don't fit the wildcards: create warnings for each heading
             for (i, line_info) in ctx.lines.iter().enumerate() {
                 if self.is_heading(i, ctx) {
                     // Calculate precise character range for the entire heading
@@ -281,7 +375,7 @@ impl Rule for MD043RequiredHeadings {
         let actual_headings = self.extract_headings(ctx);
 
         // Check if headings already match using wildcard support - if so, no fix needed
-        let (headings_match, _, _) = self.match_headings_with_wildcards(&actual_headings, &self.config.headings);
+        let headings_match = self.match_headings_with_wildcards(&actual_headings, &self.config.headings);
         if headings_match {
             return Ok(content.to_string());
         }
@@ -1364,4 +1458,126 @@ mod tests {
 
         assert!(result.is_empty(), "* at end should allow trailing headings");
     }
+
+    fn rule_with(headings: &[&str]) -> MD043RequiredHeadings {
+        MD043RequiredHeadings::new(headings.iter().map(|h| h.to_string()).collect())
+    }
+
+    #[test]
+    fn test_optional_heading() {
+        let rule = rule_with(&["# Project", "?## FAQ", "## License"]);
+
+        for content in ["# Project\n\n## FAQ\n\n## License", "# Project\n\n## License"] {
+            let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+            assert!(rule.check(&ctx).unwrap().is_empty(), "{content}");
+        }
+
+        // Optional headings still have a fixed position
+        let content = "# Project\n\n## License\n\n## FAQ";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        assert!(!rule.check(&ctx).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_optional_heading_with_wildcards() {
+        let rule = rule_with(&["# Overview", "*", "? ## Changelog"]);
+
+        for content in [
+            "# Overview\n\n## Usage\n\n## API\n\n## Changelog",
+            "# Overview\n\n## Usage",
+            "# Overview",
+        ] {
+            let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+            assert!(rule.check(&ctx).unwrap().is_empty(), "{content}");
+        }
+    }
+
+    #[test]
+    fn test_reports_missing_required_heading() {
+        let rule = rule_with(&["# Overview", "*", "## Changelog"]);
+        let content = "# Overview\n\n## Usage\n\n## API";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+
+        // Reported at the first heading after the last required heading that was found
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].line, 3);
+        assert_eq!(
+            result[0].message,
+            "Missing required heading '## Changelog' (expected after '# Overview')"
+        );
+    }
+
+    #[test]
+    fn test_reports_misordered_required_heading() {
+        let rule = rule_with(&["# Overview", "*", "## Changelog"]);
+        let content = "# Overview\n\n## Changelog\n\n## Usage";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+
+        // `## Changelog` is found, but `## Usage` after it isn't allowed by the template
+        assert!(!result.is_empty());
+
+        let rule = rule_with(&["# Overview", "## Install", "*", "## Changelog"]);
+        let content = "# Overview\n\n## Changelog\n\n## Install";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].line, 3);
+        assert_eq!(
+            result[0].message,
+            "Required heading '## Changelog' is out of order (expected after '## Install')"
+        );
+    }
+
+    #[test]
+    fn test_regex_and_wildcard_headings() {
+        let rule = rule_with(&[
+            "# Changelog",
+            "/^## Release \\d+\\.\\d+\\.\\d+$/",
+            "## Release *",
+            "## Credits",
+        ]);
+        let content = "# Changelog\n\n## Release 1.2.3\n\n## release candidate\n\n## Credits";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        assert!(rule.check(&ctx).unwrap().is_empty());
+
+        // A literal entry still has to match the whole heading
+        let content = "# Changelog\n\n## Release 1.2.3\n\n## Release 1.2.2\n\n## Credits and thanks";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(
+            result[0].message,
+            "Missing required heading '## Credits' (expected after '## Release *')"
+        );
+
+        // `*` must match the whole heading, and regexes follow match-case
+        let rule = MD043RequiredHeadings::from_config_struct(MD043Config {
+            headings: vec!["## Release *".to_string(), "/^## v\\d/".to_string()],
+            match_case: true,
+        });
+        let ctx = LintContext::new("## Release 2\n\n## v2", crate::config::MarkdownFlavor::Standard, None);
+        assert!(rule.check(&ctx).unwrap().is_empty());
+        let ctx = LintContext::new("## Release 2\n\n## V2", crate::config::MarkdownFlavor::Standard, None);
+        assert_eq!(rule.check(&ctx).unwrap().len(), 1);
+        let ctx = LintContext::new("### Release 2\n\n## v2", crate::config::MarkdownFlavor::Standard, None);
+        assert_eq!(rule.check(&ctx).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_pattern_headings_out_of_order() {
+        let rule = rule_with(&["# Overview", "/^## Release /", "*", "## Install *"]);
+        let content = "# Overview\n\n## Install on Linux\n\n## Release 1.0";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].line, 3);
+        assert_eq!(
+            result[0].message,
+            "Required heading '## Install *' is out of order (expected after '/^## Release /')"
+        );
+    }
 }
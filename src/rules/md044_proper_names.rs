@@ -3,6 +3,7 @@ use crate::utils::regex_cache::{escape_regex, get_cached_fancy_regex};
 
 use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, Severity};
 use fancy_regex::Regex;
+use pulldown_cmark::LinkType;
 use std::collections::HashMap;
 use std::sync::LazyLock;
 use std::sync::{Arc, Mutex};
@@ -88,6 +89,8 @@ impl MD044ProperNames {
             code_blocks,
             html_elements: true, // Default to checking HTML elements
             html_comments: true, // Default to checking HTML comments
+            ignore_in_links: true,
+            ignore_in_urls: false,
         };
         let combined_pattern = Self::create_combined_pattern(&config);
         Self {
@@ -224,6 +227,8 @@ impl MD044ProperNames {
             None => return Vec::new(),
         };
 
+        let bare_urls = self.config.ignore_in_urls.then(|| ctx.bare_urls());
+
         // Use ctx.lines for better performance
         for (line_idx, line_info) in ctx.lines.iter().enumerate() {
             let line_num = line_idx + 1;
@@ -317,6 +322,15 @@ impl MD044ProperNames {
                             continue;
                         }
 
+                        // Skip if in a bare URL (when ignore_in_urls = true)
+                        if let Some(bare_urls) = &bare_urls
+                            && bare_urls
+                                .iter()
+                                .any(|url| url.byte_offset <= byte_pos && byte_pos < url.byte_end)
+                        {
+                            continue;
+                        }
+
                         // Find which proper name this matches
                         if let Some(proper_name) = self.get_proper_name_for(found_name) {
                             // Only flag if it's not already correct
@@ -351,19 +365,33 @@ impl MD044ProperNames {
     }
 
     /// Check if a byte position is within a link (inline links, reference links, or reference definitions)
+    ///
+    /// With `ignore_in_links = false`, link text and image alt text are checked like other
+    /// text, and only the destinations are skipped.
     fn is_in_link(&self, ctx: &crate::lint_context::LintContext, byte_pos: usize) -> bool {
+        let skip = |start: usize, end: usize, link_type: LinkType| {
+            start <= byte_pos
+                && byte_pos < end
+                && (self.config.ignore_in_links
+                    || !Self::link_text_range(ctx.content, start, end, link_type).contains(&byte_pos))
+        };
+
         // Check inline and reference links
-        for link in &ctx.links {
-            if link.byte_offset <= byte_pos && byte_pos < link.byte_end {
-                return true;
-            }
+        if ctx
+            .links
+            .iter()
+            .any(|link| skip(link.byte_offset, link.byte_end, link.link_type))
+        {
+            return true;
         }
 
         // Check images (which use similar syntax)
-        for image in &ctx.images {
-            if image.byte_offset <= byte_pos && byte_pos < image.byte_end {
-                return true;
-            }
+        if ctx
+            .images
+            .iter()
+            .any(|image| skip(image.byte_offset, image.byte_end, image.link_type))
+        {
+            return true;
         }
 
         // Check reference definitions [ref]: url "title" using regex pattern
@@ -376,6 +404,25 @@ impl MD044ProperNames {
         false
     }
 
+    /// Byte range of the text of a link or the alt text of an image spanning `start..end`
+    ///
+    /// Autolinks have no text apart from their URL, so their range is empty.
+    fn link_text_range(content: &str, start: usize, end: usize, link_type: LinkType) -> std::ops::Range<usize> {
+        let source = &content[start..end];
+        let text_end = match link_type {
+            LinkType::Inline => source.rfind("]("),
+            LinkType::Reference | LinkType::ReferenceUnknown | LinkType::Collapsed | LinkType::CollapsedUnknown => {
+                source.rfind("][")
+            }
+            LinkType::Shortcut | LinkType::ShortcutUnknown => source.rfind(']'),
+            _ => None,
+        };
+        match (source.find('['), text_end) {
+            (Some(open), Some(close)) if open < close => start + open + 1..start + close,
+            _ => start..start,
+        }
+    }
+
     // Check if a character is a word boundary (handles Unicode)
     fn is_word_boundary_char(c: char) -> bool {
         !c.is_alphanumeric()
@@ -757,6 +804,8 @@ javascript in code block
             code_blocks: true,
             html_elements: true,
             html_comments: true,
+            ignore_in_links: true,
+            ignore_in_urls: false,
         };
         let rule = MD044ProperNames::from_config_struct(config);
 
@@ -961,6 +1010,8 @@ Third line with RUST and PYTHON."#;
             code_blocks: true,    // Check code blocks
             html_elements: true,  // Check HTML elements
             html_comments: false, // Don't check HTML comments
+            ignore_in_links: true,
+            ignore_in_urls: false,
         };
         let rule = MD044ProperNames::from_config_struct(config);
 
@@ -983,6 +1034,8 @@ More javascript outside."#;
             code_blocks: true,   // Check code blocks
             html_elements: true, // Check HTML elements
             html_comments: true, // Check HTML comments
+            ignore_in_links: true,
+            ignore_in_urls: false,
         };
         let rule = MD044ProperNames::from_config_struct(config);
 
@@ -1007,6 +1060,8 @@ More javascript outside."#;
             code_blocks: true,    // Check code blocks
             html_elements: true,  // Check HTML elements
             html_comments: false, // Don't check HTML comments
+            ignore_in_links: true,
+            ignore_in_urls: false,
         };
         let rule = MD044ProperNames::from_config_struct(config);
 
@@ -1033,6 +1088,8 @@ More javascript outside."#;
             code_blocks: true,    // Check code blocks
             html_elements: true,  // Check HTML elements
             html_comments: false, // Don't check HTML comments
+            ignore_in_links: true,
+            ignore_in_urls: false,
         };
         let rule = MD044ProperNames::from_config_struct(config);
 
@@ -1123,4 +1180,46 @@ Real javascript should be flagged.
         assert!(result[0].message.contains("'javascript' should be 'JavaScript'"));
         assert!(result[0].line == 3); // "Real javascript should be flagged."
     }
+
+    #[test]
+    fn test_ignore_in_urls() {
+        let content = "Host it on github, see https://github.com/org/repo for an example.";
+
+        let rule = MD044ProperNames::new(vec!["GitHub".to_string()], true);
+        let ctx = create_context(content);
+        assert_eq!(rule.check(&ctx).unwrap().len(), 2);
+
+        let config = MD044Config {
+            names: vec!["GitHub".to_string()],
+            ignore_in_urls: true,
+            ..Default::default()
+        };
+        let rule = MD044ProperNames::from_config_struct(config);
+        let result = rule.check(&ctx).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].column, 12);
+        assert_eq!(
+            rule.fix(&ctx).unwrap(),
+            "Host it on GitHub, see https://github.com/org/repo for an example."
+        );
+    }
+
+    #[test]
+    fn test_link_text_checked_when_links_not_ignored() {
+        let config = MD044Config {
+            names: vec!["GitHub".to_string()],
+            ignore_in_links: false,
+            ..Default::default()
+        };
+        let rule = MD044ProperNames::from_config_struct(config);
+
+        let content = "See the [github docs](https://github.com/docs), [github][gh] and ![github logo](github.png).\n\n[gh]: https://github.com\n";
+        let ctx = create_context(content);
+        let columns: Vec<usize> = rule.check(&ctx).unwrap().iter().map(|w| w.column).collect();
+        assert_eq!(columns, [10, 50, 68]);
+        assert_eq!(
+            rule.fix(&ctx).unwrap(),
+            "See the [GitHub docs](https://github.com/docs), [GitHub][gh] and ![GitHub logo](github.png).\n\n[gh]: https://github.com\n"
+        );
+    }
 }
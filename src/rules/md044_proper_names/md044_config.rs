@@ -14,6 +14,18 @@ pub struct MD044Config {
 
     #[serde(default = "default_html_comments", rename = "html-comments", alias = "html_comments")]
     pub html_comments: bool,
+
+    /// Skip links and images entirely; when false, their text is checked but not their destinations
+    #[serde(
+        default = "default_ignore_in_links",
+        rename = "ignore-in-links",
+        alias = "ignore_in_links"
+    )]
+    pub ignore_in_links: bool,
+
+    /// Skip bare URLs such as `https://github.com/...` in text
+    #[serde(default, rename = "ignore-in-urls", alias = "ignore_in_urls")]
+    pub ignore_in_urls: bool,
 }
 
 impl Default for MD044Config {
@@ -23,6 +35,8 @@ impl Default for MD044Config {
             code_blocks: default_code_blocks(),
             html_elements: default_html_elements(),
             html_comments: default_html_comments(),
+            ignore_in_links: default_ignore_in_links(),
+            ignore_in_urls: false,
         }
     }
 }
@@ -39,6 +53,10 @@ fn default_html_comments() -> bool {
     false
 }
 
+fn default_ignore_in_links() -> bool {
+    true
+}
+
 impl RuleConfig for MD044Config {
     const RULE_NAME: &'static str = "MD044";
 }
@@ -93,5 +111,19 @@ mod tests {
         assert!(config.names.is_empty());
         assert!(!config.code_blocks); // Default is false (skip code blocks)
         assert!(!config.html_comments); // Default is false (skip HTML comments, matches markdownlint)
+        assert!(config.ignore_in_links);
+        assert!(!config.ignore_in_urls);
+    }
+
+    #[test]
+    fn test_link_and_url_options() {
+        let toml_str = r#"
+            names = ["GitHub"]
+            ignore-in-links = false
+            ignore_in_urls = true
+        "#;
+        let config: MD044Config = toml::from_str(toml_str).unwrap();
+        assert!(!config.ignore_in_links);
+        assert!(config.ignore_in_urls);
     }
 }
@@ -359,6 +359,20 @@ impl MD052ReferenceLinkImages {
         references
     }
 
+    /// Lines that look like reference definitions but sit inside code blocks, keyed by
+    /// lowercase label, with the 1-indexed line of the first one
+    fn definitions_in_code_blocks(ctx: &crate::lint_context::LintContext) -> HashMap<String, usize> {
+        let mut definitions = HashMap::new();
+        for (index, line) in ctx.lines.iter().enumerate().filter(|(_, line)| line.in_code_block) {
+            if let Some(reference) = REF_REGEX.captures(line.content(ctx.content)).and_then(|cap| cap.get(1)) {
+                definitions
+                    .entry(reference.as_str().to_lowercase())
+                    .or_insert(index + 1);
+            }
+        }
+        definitions
+    }
+
     fn find_undefined_references(
         &self,
         content: &str,
@@ -922,10 +936,17 @@ impl Rule for MD052ReferenceLinkImages {
 
         let references = self.extract_references(content, mkdocs_mode);
 
+        let undefined = self.find_undefined_references(content, &references, ctx, mkdocs_mode);
+
+        // A definition that ended up inside a code block explains why the reference is missing
+        let hidden_definitions = if undefined.is_empty() {
+            HashMap::new()
+        } else {
+            Self::definitions_in_code_blocks(ctx)
+        };
+
         // Use optimized detection method with cached link/image data
-        for (line_num, col, match_len, reference) in
-            self.find_undefined_references(content, &references, ctx, mkdocs_mode)
-        {
+        for (line_num, col, match_len, reference) in undefined {
             let lines: Vec<&str> = content.lines().collect();
             let line_content = lines.get(line_num).unwrap_or(&"");
 
@@ -939,7 +960,12 @@ impl Rule for MD052ReferenceLinkImages {
                 column: start_col,
                 end_line,
                 end_column: end_col,
-                message: format!("Reference '{reference}' not found"),
+                message: match hidden_definitions.get(&reference.to_lowercase()) {
+                    Some(definition_line) => format!(
+                        "Reference '{reference}' not found; its definition on line {definition_line} is inside a code block"
+                    ),
+                    None => format!("Reference '{reference}' not found"),
+                },
                 severity: Severity::Warning,
                 fix: None,
             });
@@ -1234,7 +1260,25 @@ Multiple `[one]` and `[two]` in code ignored, but [three] is not.
 
         // Reference defined in code block should not count
         assert_eq!(result.len(), 1);
-        assert!(result[0].message.contains("ref"));
+        assert_eq!(
+            result[0].message,
+            "Reference 'ref' not found; its definition on line 4 is inside a code block"
+        );
+    }
+
+    #[test]
+    fn test_definition_in_code_block_case_insensitive() {
+        let rule = MD052ReferenceLinkImages::new();
+        let content = "See [the docs][Docs] and [other][missing].\n\n~~~\n[docs]: https://example.com\n~~~\n";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(
+            result[0].message,
+            "Reference 'docs' not found; its definition on line 4 is inside a code block"
+        );
+        assert_eq!(result[1].message, "Reference 'missing' not found");
     }
 
     #[test]
@@ -225,6 +225,21 @@ impl MD053LinkImageReferenceDefinitions {
     /// Find all link and image reference definitions in the content.
     ///
     /// This method returns a HashMap where the key is the normalized reference ID and the value is a vector of (start_line, end_line) tuples.
+    /// Label of the reference definition on a 0-indexed line, as written
+    fn raw_label<'a>(ctx: &'a crate::lint_context::LintContext, line_idx: usize) -> Option<&'a str> {
+        let line = ctx.lines.get(line_idx)?.content(ctx.content);
+        let caps = REFERENCE_DEFINITION_REGEX.captures(line)?;
+        Some(caps.get(1)?.as_str().trim())
+    }
+
+    /// URL of the reference definition on a 0-indexed line
+    fn definition_url<'a>(ctx: &'a crate::lint_context::LintContext, line_idx: usize) -> Option<&'a str> {
+        ctx.reference_defs
+            .iter()
+            .find(|def| def.line == line_idx + 1)
+            .map(|def| def.url.as_str())
+    }
+
     fn find_definitions(&self, ctx: &crate::lint_context::LintContext) -> HashMap<String, Vec<(usize, usize)>> {
         let mut definitions: HashMap<String, Vec<(usize, usize)>> = HashMap::new();
 
@@ -450,6 +465,8 @@ impl Rule for MD053LinkImageReferenceDefinitions {
 
             if ranges.len() > 1 {
                 // Multiple definitions with exact same ID (already lowercase)
+                let first_line = ranges[0].0;
+                let first_label = Self::raw_label(ctx, first_line);
                 for (i, &(start_line, _)) in ranges.iter().enumerate() {
                     if i > 0 {
                         // Skip the first occurrence, report all others
@@ -458,13 +475,32 @@ impl Rule for MD053LinkImageReferenceDefinitions {
                         let (start_line_1idx, start_col, end_line, end_col) =
                             calculate_line_range(line_num, line_content);
 
+                        // Labels that differ only by case still resolve to the same reference,
+                        // so point at the definition that actually wins
+                        let label = Self::raw_label(ctx, start_line);
+                        let message = match (first_label, label) {
+                            (Some(first), Some(label)) if first != label => {
+                                let consequence =
+                                    if Self::definition_url(ctx, first_line) != Self::definition_url(ctx, start_line) {
+                                        ", so this URL is ignored"
+                                    } else {
+                                        ""
+                                    };
+                                format!(
+                                    "Duplicate link or image reference definition: [{label}] (same label as [{first}] on line {}; reference labels are case-insensitive{consequence})",
+                                    first_line + 1
+                                )
+                            }
+                            _ => format!("Duplicate link or image reference definition: [{definition_id}]"),
+                        };
+
                         warnings.push(LintWarning {
                             rule_name: Some(self.name().to_string()),
                             line: start_line_1idx,
                             column: start_col,
                             end_line,
                             end_column: end_col,
-                            message: format!("Duplicate link or image reference definition: [{definition_id}]"),
+                            message,
                             severity: Severity::Warning,
                             fix: None,
                         });
@@ -891,6 +927,27 @@ mod tests {
         assert_eq!(duplicate_warnings[1].line, 3);
     }
 
+    #[test]
+    fn test_duplicate_definitions_differing_only_by_case() {
+        let rule = MD053LinkImageReferenceDefinitions::new();
+        let content = "[Foo] and [bar]\n\n[foo]: https://a.example\n[Foo]: https://b.example\n[bar]: url\n[BAR]: url\n";
+        let ctx = LintContext::new(content, crate::config::MarkdownFlavor::Standard, None);
+        let mut result = rule.check(&ctx).unwrap();
+        result.sort_by_key(|w| w.line);
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].line, 4);
+        assert_eq!(
+            result[0].message,
+            "Duplicate link or image reference definition: [Foo] (same label as [foo] on line 3; reference labels are case-insensitive, so this URL is ignored)"
+        );
+        assert_eq!(result[1].line, 6);
+        assert_eq!(
+            result[1].message,
+            "Duplicate link or image reference definition: [BAR] (same label as [bar] on line 5; reference labels are case-insensitive)"
+        );
+    }
+
     #[test]
     fn test_duplicate_and_unused() {
         let rule = MD053LinkImageReferenceDefinitions::new();
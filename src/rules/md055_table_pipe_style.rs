@@ -264,7 +264,7 @@ impl Rule for MD055TablePipeStyle {
         };
 
         // Use pre-computed table blocks from context
-        let table_blocks = &ctx.table_blocks;
+        let table_blocks = ctx.table_blocks();
 
         // Process each table block
         for table_block in table_blocks {
@@ -368,7 +368,7 @@ impl Rule for MD055TablePipeStyle {
         };
 
         // Use pre-computed table blocks from context
-        let table_blocks = &ctx.table_blocks;
+        let table_blocks = ctx.table_blocks();
 
         // Create a copy of lines that we can modify
         let mut result_lines = lines.iter().map(|&s| s.to_string()).collect::<Vec<String>>();
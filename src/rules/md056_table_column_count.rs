@@ -137,7 +137,7 @@ impl Rule for MD056TableColumnCount {
         let lines: Vec<&str> = content.lines().collect();
 
         // Use pre-computed table blocks from context
-        let table_blocks = &ctx.table_blocks;
+        let table_blocks = ctx.table_blocks();
 
         for table_block in table_blocks {
             // Determine expected column count from header row
@@ -209,7 +209,7 @@ impl Rule for MD056TableColumnCount {
         let content = ctx.content;
         let flavor = ctx.flavor;
         let lines: Vec<&str> = content.lines().collect();
-        let table_blocks = &ctx.table_blocks;
+        let table_blocks = ctx.table_blocks();
 
         let mut result_lines: Vec<String> = lines.iter().map(|&s| s.to_string()).collect();
 
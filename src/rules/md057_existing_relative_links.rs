@@ -142,6 +142,25 @@ impl MD057ExistingRelativeLinks {
             .map(|base_path| base_path.join(link))
     }
 
+    /// Resolve a Markdown link target to the path that cross-file checks look for
+    ///
+    /// Targets starting with `/` are relative to the workspace root, others to the
+    /// directory of the linking file. `.` and `..` components are resolved.
+    pub fn resolve_target(file_path: &Path, target: &str) -> PathBuf {
+        let target_path = if target.starts_with('/') {
+            // Absolute path from workspace root (e.g., "/CONTRIBUTING.md")
+            // Walk up from the current file's directory to find the workspace root
+            resolve_absolute_link(file_path, target.trim_start_matches('/'))
+        } else if let Some(dir) = file_path.parent() {
+            dir.join(target)
+        } else {
+            Path::new(target).to_path_buf()
+        };
+
+        // Normalize the path (handle .., ., etc.)
+        normalize_path(&target_path)
+    }
+
     /// Process a single link and check if it exists
     fn process_link(&self, url: &str, line_num: usize, column: usize, warnings: &mut Vec<LintWarning>) {
         // Skip empty URLs
@@ -323,6 +342,11 @@ impl Rule for MD057ExistingRelativeLinks {
         CrossFileScope::Workspace
     }
 
+    fn cacheable(&self) -> bool {
+        // Link targets can be created or deleted without this file changing
+        false
+    }
+
     fn contribute_to_index(&self, ctx: &crate::lint_context::LintContext, index: &mut FileIndex) {
         let content = ctx.content;
 
@@ -403,24 +427,8 @@ impl Rule for MD057ExistingRelativeLinks {
     ) -> LintResult {
         let mut warnings = Vec::new();
 
-        // Get the directory containing this file for resolving relative links
-        let file_dir = file_path.parent();
-
         for cross_link in &file_index.cross_file_links {
-            // Resolve the relative path
-            let target_path = if cross_link.target_path.starts_with('/') {
-                // Absolute path from workspace root (e.g., "/CONTRIBUTING.md")
-                // Walk up from the current file's directory to find the workspace root
-                let stripped = cross_link.target_path.trim_start_matches('/');
-                resolve_absolute_link(file_path, stripped)
-            } else if let Some(dir) = file_dir {
-                dir.join(&cross_link.target_path)
-            } else {
-                Path::new(&cross_link.target_path).to_path_buf()
-            };
-
-            // Normalize the path (handle .., ., etc.)
-            let target_path = normalize_path(&target_path);
+            let target_path = Self::resolve_target(file_path, &cross_link.target_path);
 
             // Check if the target markdown file exists in the workspace index
             if !workspace_index.contains_file(&target_path) {
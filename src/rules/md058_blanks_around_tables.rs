@@ -116,7 +116,7 @@ impl Rule for MD058BlanksAroundTables {
         let lines: Vec<&str> = content.lines().collect();
 
         // Use pre-computed table blocks from context
-        let table_blocks = &ctx.table_blocks;
+        let table_blocks = ctx.table_blocks();
 
         for table_block in table_blocks {
             // Check for sufficient blank lines before table
@@ -660,7 +660,7 @@ impl Rule for MD060TableFormat {
         let mut warnings = Vec::new();
 
         let lines: Vec<&str> = content.lines().collect();
-        let table_blocks = &ctx.table_blocks;
+        let table_blocks = ctx.table_blocks();
 
         for table_block in table_blocks {
             let format_result = self.fix_table_block(&lines, table_block, ctx.flavor);
@@ -739,7 +739,7 @@ impl Rule for MD060TableFormat {
 
         let content = ctx.content;
         let lines: Vec<&str> = content.lines().collect();
-        let table_blocks = &ctx.table_blocks;
+        let table_blocks = ctx.table_blocks();
 
         let mut result_lines: Vec<String> = lines.iter().map(|&s| s.to_string()).collect();
 
@@ -1,6 +1,45 @@
+use crate::config::Config;
 use crate::lint_context::LintContext;
 use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use crate::rules::md123_link_title_quotes::split_unquoted_title;
+use crate::utils::skip_context::is_in_math_context;
 use pulldown_cmark::LinkType;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
+
+/// Inline link or image whose parenthesized part the parser rejected, e.g. `[x](/my page)`
+static BROKEN_INLINE_LINK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(!?)\[[^\[\]\n]*\]\(([^()<>\n]*)\)").unwrap());
+
+/// Destination followed by an optional quoted title
+static DESTINATION_AND_TITLE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"^(.*?)(\s+(?:"[^"]*"|'[^']*'))?$"#).unwrap());
+
+/// How to fix spaces inside a link destination
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum SpaceFix {
+    /// Percent-encode each space: `[x](/my%20page)`
+    #[default]
+    Encode,
+    /// Wrap the destination in angle brackets: `[x](</my page>)`
+    AngleBrackets,
+}
+
+/// Configuration for MD062 (Link destination whitespace)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD062Config {
+    /// How to fix spaces inside a destination: "encode" or "angle-brackets"
+    #[serde(default, alias = "space_fix")]
+    pub space_fix: SpaceFix,
+}
+
+impl RuleConfig for MD062Config {
+    const RULE_NAME: &'static str = "MD062";
+}
 
 /// Describes what type of whitespace issue was found
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -40,12 +79,95 @@ impl WhitespaceIssue {
 /// - `[text]( url )` - both
 ///
 /// The fix trims the whitespace: `[text](url)`
+///
+/// Spaces *inside* a bare destination (`[text](/my page)`, typically a pasted file name)
+/// stop the link from parsing at all, so they are found by scanning the text next to
+/// the parsed links. Those are fixed by percent-encoding the spaces or by wrapping the
+/// destination in angle brackets, as configured.
 #[derive(Debug, Default, Clone)]
-pub struct MD062LinkDestinationWhitespace;
+pub struct MD062LinkDestinationWhitespace {
+    config: MD062Config,
+}
 
 impl MD062LinkDestinationWhitespace {
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    pub fn from_config_struct(config: MD062Config) -> Self {
+        Self { config }
+    }
+
+    /// Find inline links and images that don't parse because their destination contains spaces
+    fn check_spaces_in_destination(&self, ctx: &LintContext, warnings: &mut Vec<LintWarning>) {
+        for (idx, line_info) in ctx.lines.iter().enumerate() {
+            let line = line_info.content(ctx.content);
+            if line_info.in_code_block || line_info.in_front_matter || !line.contains("](") {
+                continue;
+            }
+
+            for caps in BROKEN_INLINE_LINK.captures_iter(line) {
+                let whole = caps.get(0).unwrap();
+                let start = line_info.byte_offset + whole.start();
+                let end = line_info.byte_offset + whole.end();
+                let is_image = !caps[1].is_empty();
+
+                let escaped = line[..whole.start() + caps[1].len()].ends_with('\\');
+                let inside_parsed = ctx.links.iter().any(|l| l.byte_offset < end && start < l.byte_end)
+                    || ctx.images.iter().any(|i| i.byte_offset < end && start < i.byte_end);
+                if escaped
+                    || inside_parsed
+                    || ctx.is_in_code_block_or_span(start)
+                    || ctx.is_in_html_comment(start)
+                    || is_in_math_context(ctx, start)
+                    || ctx.is_in_jinja_range(start)
+                {
+                    continue;
+                }
+
+                let inner = caps.get(2).unwrap();
+                // `[x](url Title)` is an unquoted title, which MD123 quotes
+                if split_unquoted_title(inner.as_str()).is_some() {
+                    continue;
+                }
+                let Some(parts) = DESTINATION_AND_TITLE.captures(inner.as_str().trim()) else {
+                    continue;
+                };
+                let url = parts[1].trim();
+                let title = parts.get(2).map_or("", |t| t.as_str().trim_start());
+                // Only destinations that look like a path or URL, not prose in parentheses
+                if !url.contains(char::is_whitespace) || !url.contains(['/', '.', '#']) {
+                    continue;
+                }
+
+                let destination = match self.config.space_fix {
+                    SpaceFix::Encode => url.replace(' ', "%20").replace('\t', "%09"),
+                    SpaceFix::AngleBrackets => format!("<{url}>"),
+                };
+                let mut replacement = format!("{}(", &line[whole.start()..inner.start() - 1]);
+                replacement.push_str(&destination);
+                if !title.is_empty() {
+                    replacement.push(' ');
+                    replacement.push_str(title);
+                }
+                replacement.push(')');
+
+                let element = if is_image { "Image" } else { "Link" };
+                warnings.push(LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line: idx + 1,
+                    column: line[..whole.start()].chars().count() + 1,
+                    end_line: idx + 1,
+                    end_column: line[..whole.end()].chars().count() + 1,
+                    message: format!("{element} destination contains unencoded spaces"),
+                    severity: Severity::Warning,
+                    fix: Some(Fix {
+                        range: start..end,
+                        replacement,
+                    }),
+                });
+            }
+        }
     }
 
     /// Extract the destination portion from a link's raw text
@@ -175,7 +297,7 @@ impl Rule for MD062LinkDestinationWhitespace {
     }
 
     fn description(&self) -> &'static str {
-        "Link destination should not have leading or trailing whitespace"
+        "Link destination should not contain whitespace"
     }
 
     fn category(&self) -> RuleCategory {
@@ -265,6 +387,9 @@ impl Rule for MD062LinkDestinationWhitespace {
             }
         }
 
+        self.check_spaces_in_destination(ctx, &mut warnings);
+        warnings.sort_by_key(|w| (w.line, w.column));
+
         Ok(warnings)
     }
 
@@ -295,11 +420,20 @@ impl Rule for MD062LinkDestinationWhitespace {
         self
     }
 
-    fn from_config(_config: &crate::config::Config) -> Box<dyn Rule>
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
     where
         Self: Sized,
     {
-        Box::new(Self)
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD062Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
     }
 }
 
@@ -676,4 +810,40 @@ mod tests {
         let warnings = rule.check(&ctx).unwrap();
         assert!(warnings.is_empty());
     }
+
+    #[test]
+    fn test_spaces_inside_destination_encoded() {
+        let rule = MD062LinkDestinationWhitespace::new();
+        let content = "See [notes](/my page.md) and ![shot](img/screen shot.png \"Title\").\n";
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].message, "Link destination contains unencoded spaces");
+        assert_eq!(warnings[0].column, 5);
+        assert_eq!(warnings[1].message, "Image destination contains unencoded spaces");
+        assert_eq!(
+            rule.fix(&ctx).unwrap(),
+            "See [notes](/my%20page.md) and ![shot](img/screen%20shot.png \"Title\").\n"
+        );
+    }
+
+    #[test]
+    fn test_spaces_inside_destination_angle_brackets() {
+        let rule = MD062LinkDestinationWhitespace::from_config_struct(MD062Config {
+            space_fix: SpaceFix::AngleBrackets,
+        });
+        let content = "[notes]( docs/my file.md )\n";
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        assert_eq!(rule.fix(&ctx).unwrap(), "[notes](<docs/my file.md>)\n");
+    }
+
+    #[test]
+    fn test_valid_destinations_with_spaces_not_flagged() {
+        let rule = MD062LinkDestinationWhitespace::new();
+        // Angle brackets make spaces valid; prose in brackets and code are not links
+        let content =
+            "[a](<my page.md>) [b](see the docs) \\[c](my file.md) `[d](my file.md)`\n\n```\n[e](my file.md)\n```\n";
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        assert!(rule.check(&ctx).unwrap().is_empty());
+    }
 }
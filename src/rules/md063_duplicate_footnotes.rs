@@ -1,21 +1,25 @@
-use crate::rule::{LintResult, LintWarning, Rule, Severity};
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
 use crate::rule_config_serde::RuleConfig;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::sync::LazyLock;
 
-static FOOTNOTE_DEF_REGEX: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^(\s*)\[\^([a-zA-Z0-9_-]+)\]:\s*").unwrap());
+/// Footnote definition marker at the start of a line: `[^id]:`
+static FOOTNOTE_DEF_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(\s*)(\[\^([^\]\s]+)\]:)").unwrap());
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+/// Configuration for MD063 (Footnotes should not be duplicated)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub struct MD063Config {
     /// Check for duplicate footnote definitions (always an error in most parsers)
-    #[serde(default = "default_true")]
+    #[serde(default = "default_true", alias = "check_definitions")]
     pub check_definitions: bool,
+
     /// Check for duplicate footnote references (allowed by some parsers, but often a mistake)
-    #[serde(default = "default_false")]
+    #[serde(default, alias = "check_references")]
     pub check_references: bool,
 }
 
@@ -23,10 +27,6 @@ fn default_true() -> bool {
     true
 }
 
-fn default_false() -> bool {
-    false
-}
-
 impl Default for MD063Config {
     fn default() -> Self {
         Self {
@@ -40,16 +40,21 @@ impl RuleConfig for MD063Config {
     const RULE_NAME: &'static str = "MD063";
 }
 
-#[derive(Clone, Default)]
+/// Rule MD063: Footnotes should not be duplicated
+///
+/// See [docs/md063.md](../../docs/md063.md) for full documentation, configuration, and examples.
+///
+/// Renderers keep only one of several definitions with the same id, so the others are
+/// silently lost. Ids are compared case-insensitively, the way pulldown-cmark and GitHub
+/// match them. Which definition to keep is up to the author, so there is no automatic fix.
+#[derive(Debug, Clone, Default)]
 pub struct MD063DuplicateFootnotes {
     config: MD063Config,
 }
 
 impl MD063DuplicateFootnotes {
     pub fn new() -> Self {
-        Self {
-            config: MD063Config::default(),
-        }
+        Self::default()
     }
 
     pub fn from_config_struct(config: MD063Config) -> Self {
@@ -66,72 +71,75 @@ impl Rule for MD063DuplicateFootnotes {
         "Footnotes should not be duplicated"
     }
 
-    fn check(&self, ctx: &crate::lint_context::LintContext) -> LintResult {
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Other
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !ctx.content.contains("[^")
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
         let mut warnings = Vec::new();
+        if self.should_skip(ctx) {
+            return Ok(warnings);
+        }
 
-        // Check for duplicate definitions
         if self.config.check_definitions {
-            let mut seen_definitions: HashMap<String, usize> = HashMap::new();
+            // First definition line of each id, keyed by the lowercased id
+            let mut first_definitions: HashMap<String, usize> = HashMap::new();
 
             for (line_idx, line_info) in ctx.lines.iter().enumerate() {
                 if line_info.in_code_block || line_info.in_front_matter {
                     continue;
                 }
-
-                let content = line_info.content(ctx.content);
-                if let Some(cap) = FOOTNOTE_DEF_REGEX.captures(content) {
-                    if let Some(id_match) = cap.get(2) {
-                        let id = id_match.as_str();
-                        if let Some(&first_line) = seen_definitions.get(id) {
-                            warnings.push(LintWarning {
-                                message: format!(
-                                    "Duplicate footnote definition '[^{}]' (first defined on line {})",
-                                    id,
-                                    first_line + 1
-                                ),
-                                line: line_idx + 1,
-                                column: line_info.indent + 1,
-                                end_line: line_idx + 1,
-                                end_column: line_info.indent + 1 + cap.get(0).unwrap().len(),
-                                severity: Severity::Error,
-                                fix: None,
-                                rule_name: Some(self.name().to_string()),
-                            });
-                        } else {
-                            seen_definitions.insert(id.to_string(), line_idx);
-                        }
+                let Some(cap) = FOOTNOTE_DEF_REGEX.captures(line_info.content(ctx.content)) else {
+                    continue;
+                };
+                let id = &cap[3];
+                match first_definitions.get(&id.to_lowercase()) {
+                    Some(&first_line) => {
+                        let marker = cap.get(2).unwrap();
+                        warnings.push(LintWarning {
+                            rule_name: Some(self.name().to_string()),
+                            line: line_idx + 1,
+                            column: marker.start() + 1,
+                            end_line: line_idx + 1,
+                            end_column: marker.end() + 1,
+                            message: format!(
+                                "Footnote '{id}' is defined more than once (first defined on line {})",
+                                first_line + 1
+                            ),
+                            severity: Severity::Error,
+                            fix: None,
+                        });
+                    }
+                    None => {
+                        first_definitions.insert(id.to_lowercase(), line_idx);
                     }
                 }
             }
         }
 
-        // Check for duplicate references
         if self.config.check_references {
             let mut seen_references: HashSet<String> = HashSet::new();
 
             for footnote_ref in &ctx.footnote_refs {
-                // Skip if in code block (already handled by parser usually, but good to be safe)
-                if ctx.line_info(footnote_ref.line).is_some_and(|l| l.in_code_block) {
+                if ctx.line_info(footnote_ref.line).is_some_and(|info| info.in_code_block) {
                     continue;
                 }
-
-                if seen_references.contains(&footnote_ref.id) {
-                    // Calculate column from byte offset
-                    let (line, col) = ctx.offset_to_line_col(footnote_ref.byte_offset);
-                    let end_col = col + (footnote_ref.byte_end - footnote_ref.byte_offset);
-
+                if !seen_references.insert(footnote_ref.id.to_lowercase()) {
+                    let (line, column) = ctx.offset_to_line_col(footnote_ref.byte_offset);
                     warnings.push(LintWarning {
-                        message: format!("Duplicate footnote reference '[^{}]'", footnote_ref.id),
+                        rule_name: Some(self.name().to_string()),
                         line,
-                        column: col,
+                        column,
                         end_line: line,
-                        end_column: end_col,
+                        end_column: column + (footnote_ref.byte_end - footnote_ref.byte_offset),
+                        message: format!("Footnote '{}' is referenced more than once", footnote_ref.id),
                         severity: Severity::Warning,
                         fix: None,
-                        rule_name: Some(self.name().to_string()),
                     });
-                } else {
-                    seen_references.insert(footnote_ref.id.clone());
                 }
             }
         }
@@ -139,42 +147,96 @@ impl Rule for MD063DuplicateFootnotes {
         Ok(warnings)
     }
 
-    fn fix(&self, ctx: &crate::lint_context::LintContext) -> Result<String, crate::rule::LintError> {
-        // Fixing duplicate footnotes is complex (which one to keep?).
-        // For now, we don't support auto-fixing.
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        // Which definition to keep is up to the author
         Ok(ctx.content.to_string())
     }
 
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::Unfixable
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
 
-    fn from_config(config: &crate::config::Config) -> Box<dyn Rule>
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
     where
         Self: Sized,
     {
-        let rule_config = config
-            .rules
-            .get(MD063Config::RULE_NAME)
-            .and_then(|rc| serde_json::to_value(&rc.values).ok())
-            .and_then(|v| serde_json::from_value(v).ok())
-            .unwrap_or_default();
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD063Config>(config);
         Box::new(Self::from_config_struct(rule_config))
     }
+}
 
-    fn default_config_section(&self) -> Option<(String, toml::Value)> {
-        let default_config = MD063Config::default();
-        let json_value = serde_json::to_value(&default_config).ok()?;
-        let toml_value = crate::rule_config_serde::json_to_toml_value(&json_value)?;
-        
-        if let toml::Value::Table(table) = toml_value {
-            if !table.is_empty() {
-                Some((MD063Config::RULE_NAME.to_string(), toml::Value::Table(table)))
-            } else {
-                None
-            }
-        } else {
-            None
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(rule: &MD063DuplicateFootnotes, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_duplicate_definition() {
+        let rule = MD063DuplicateFootnotes::new();
+        let content = "Text[^1] and more[^2].\n\n[^1]: First.\n[^2]: Second.\n  [^1]: Again.\n[^1]: And again.\n";
+        let warnings = check(&rule, content);
+        let found: Vec<_> = warnings.iter().map(|w| (w.line, w.column, w.end_column)).collect();
+        assert_eq!(found, [(5, 3, 8), (6, 1, 6)]);
+        assert_eq!(
+            warnings[0].message,
+            "Footnote '1' is defined more than once (first defined on line 3)"
+        );
+
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        assert_eq!(rule.fix(&ctx).unwrap(), content);
+    }
+
+    #[test]
+    fn test_ids_are_case_insensitive() {
+        let rule = MD063DuplicateFootnotes::new();
+        let warnings = check(
+            &rule,
+            "Text[^Note].\n\n[^Note]: One.\n[^note]: Two.\n[^notes]: Three.\n",
+        );
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 4);
+        assert_eq!(
+            warnings[0].message,
+            "Footnote 'note' is defined more than once (first defined on line 3)"
+        );
+    }
+
+    #[test]
+    fn test_definitions_in_code_blocks_ignored() {
+        let rule = MD063DuplicateFootnotes::new();
+        let content = "Text[^1].\n\n[^1]: Real.\n\n```markdown\n[^1]: Example.\n```\n\n    [^1]: Indented example.\n";
+        assert!(check(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_references() {
+        let content = "One[^a], two[^A].\n\n[^a]: Note.\n";
+        assert!(check(&MD063DuplicateFootnotes::new(), content).is_empty());
+
+        let rule = MD063DuplicateFootnotes::from_config_struct(MD063Config {
+            check_references: true,
+            ..Default::default()
+        });
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!((warnings[0].line, warnings[0].column), (1, 13));
+        assert_eq!(warnings[0].message, "Footnote 'A' is referenced more than once");
     }
 }
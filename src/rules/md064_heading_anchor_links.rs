@@ -0,0 +1,217 @@
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use crate::utils::anchor_styles::AnchorStyle;
+use pulldown_cmark::LinkType;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Configuration for MD064 (Links to headings should match a heading in the file)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD064Config {
+    /// Whether the rule is enabled (opt-in, since MD051 already reports broken fragments)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Slug algorithm used to turn heading text into anchors
+    #[serde(default, alias = "anchor_style")]
+    pub anchor_style: AnchorStyle,
+}
+
+impl RuleConfig for MD064Config {
+    const RULE_NAME: &'static str = "MD064";
+}
+
+/// Rule MD064: Links to headings should match a heading in the file
+///
+/// See [docs/md064.md](../../docs/md064.md) for full documentation, configuration, and examples.
+///
+/// Only links that are nothing but a fragment (`[x](#section)`) are checked; links with a
+/// path before the `#` point at other files. Anchors are the slugs of the file's headings,
+/// with `-1`, `-2` and so on for repeats, plus any custom `{#id}`. The message suggests the
+/// closest anchor, but a typo may also be in the heading, so there is no automatic fix.
+#[derive(Debug, Clone, Default)]
+pub struct MD064HeadingAnchorLinks {
+    config: MD064Config,
+}
+
+impl MD064HeadingAnchorLinks {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            config: MD064Config {
+                enabled,
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn from_config_struct(config: MD064Config) -> Self {
+        Self { config }
+    }
+
+    /// Anchors of the file's headings in document order
+    fn heading_anchors(&self, ctx: &LintContext) -> Vec<String> {
+        let mut anchors = Vec::new();
+        let mut slug_counts: HashMap<String, usize> = HashMap::new();
+
+        for heading in ctx.lines.iter().filter_map(|info| info.heading.as_ref()) {
+            if let Some(custom_id) = &heading.custom_id {
+                anchors.push(custom_id.clone());
+            }
+
+            let slug = self.config.anchor_style.generate_fragment(&heading.text);
+            if slug.is_empty() {
+                continue;
+            }
+            let count = slug_counts.entry(slug.clone()).or_insert(0);
+            anchors.push(if *count == 0 { slug } else { format!("{slug}-{count}") });
+            *count += 1;
+        }
+
+        anchors
+    }
+}
+
+impl Rule for MD064HeadingAnchorLinks {
+    fn name(&self) -> &'static str {
+        "MD064"
+    }
+
+    fn description(&self) -> &'static str {
+        "Links to headings should match a heading in the file"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Link
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !self.config.enabled || ctx.links.is_empty() || !ctx.has_char('#')
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        if self.should_skip(ctx) {
+            return Ok(warnings);
+        }
+
+        let anchors = self.heading_anchors(ctx);
+        let known: HashSet<String> = anchors.iter().map(|anchor| anchor.to_lowercase()).collect();
+
+        for link in &ctx.links {
+            if link.is_reference
+                || matches!(link.link_type, LinkType::WikiLink { .. })
+                || ctx.is_in_jinja_range(link.byte_offset)
+            {
+                continue;
+            }
+            // Links with a path before the `#` point at another file
+            let Some(fragment) = link.url.strip_prefix('#') else {
+                continue;
+            };
+            if fragment.is_empty() || known.contains(&fragment.to_lowercase()) {
+                continue;
+            }
+
+            let message = match crate::config::suggest_similar_key(fragment, &anchors) {
+                Some(suggestion) => {
+                    format!("Link anchor '#{fragment}' does not match any heading (did you mean '#{suggestion}'?)")
+                }
+                None => format!("Link anchor '#{fragment}' does not match any heading"),
+            };
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line: link.line,
+                column: link.start_col + 1,
+                end_line: link.line,
+                end_column: link.end_col + 1,
+                message,
+                severity: Severity::Warning,
+                fix: None,
+            });
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        // The typo may be in the heading rather than the link
+        Ok(ctx.content.to_string())
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::Unfixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD064Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(rule: &MD064HeadingAnchorLinks, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_valid_anchors() {
+        let rule = MD064HeadingAnchorLinks::new(true);
+        let content = "# Install Guide\n\nSee [setup](#setup), [again](#setup-1) and [top](#Install-Guide).\n\n\
+                       ## Setup\n\n## Setup\n\nOther files: [a](other.md#missing), [b](https://example.com/#x).\n";
+        assert!(check(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_typo_suggests_closest_anchor() {
+        let rule = MD064HeadingAnchorLinks::new(true);
+        let content = "# Guide\n\nSee [install](#instalation) or [x](#zzz).\n\n## Installation\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!((warnings[0].line, warnings[0].column), (3, 5));
+        assert_eq!(
+            warnings[0].message,
+            "Link anchor '#instalation' does not match any heading (did you mean '#installation'?)"
+        );
+        assert_eq!(warnings[1].message, "Link anchor '#zzz' does not match any heading");
+    }
+
+    #[test]
+    fn test_custom_id_anchor() {
+        let rule = MD064HeadingAnchorLinks::new(true);
+        let content =
+            "# Guide\n\n## Getting Started {#start}\n\n[go](#start) and [slug](#getting-started)\n\n[typo](#strat)\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 7);
+        assert!(warnings[0].message.contains("did you mean '#start'"));
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let rule = MD064HeadingAnchorLinks::default();
+        assert!(check(&rule, "# Guide\n\n[x](#missing)\n").is_empty());
+    }
+}
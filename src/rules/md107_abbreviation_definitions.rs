@@ -0,0 +1,348 @@
+use crate::config::{Config, MarkdownFlavor};
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use crate::utils::mkdocs_abbreviations::{contains_abbreviation, parse_abbreviation_definition};
+use crate::utils::mkdocs_common::should_check_mkdocs;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Configuration for MD107 (Abbreviation definitions should be unique and used)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD107Config {
+    /// Also report abbreviation definitions that are never used in the document
+    #[serde(default, alias = "check_unused")]
+    pub check_unused: bool,
+}
+
+impl RuleConfig for MD107Config {
+    const RULE_NAME: &'static str = "MD107";
+}
+
+/// Rule MD107: Abbreviation definitions should be unique and used
+///
+/// See [docs/md107.md](../../docs/md107.md) for full documentation, configuration, and examples.
+///
+/// This rule only runs with the MkDocs flavor, where the `abbr` extension turns
+/// `*[HTML]: HyperText Markup Language` lines into abbreviation definitions.
+///
+/// It reports abbreviations that are defined more than once and, when `check-unused`
+/// is enabled, abbreviations that never appear in the document text.
+///
+/// The fix removes duplicate definitions, keeping the last one, which is the one the
+/// `abbr` extension renders.
+#[derive(Debug, Clone, Default)]
+pub struct MD107AbbreviationDefinitions {
+    config: MD107Config,
+}
+
+/// An abbreviation definition found in the document
+struct DefinitionLine<'a> {
+    /// 1-indexed line number
+    line: usize,
+    abbreviation: &'a str,
+    /// Column of the abbreviation text (1-indexed)
+    column: usize,
+}
+
+impl MD107AbbreviationDefinitions {
+    pub fn new(check_unused: bool) -> Self {
+        Self {
+            config: MD107Config { check_unused },
+        }
+    }
+
+    pub fn from_config_struct(config: MD107Config) -> Self {
+        Self { config }
+    }
+
+    /// Collect abbreviation definitions, skipping code blocks, front matter and HTML comments
+    fn collect_definitions<'a>(&self, ctx: &'a LintContext) -> Vec<DefinitionLine<'a>> {
+        let mut definitions = Vec::new();
+
+        for (idx, line_info) in ctx.lines.iter().enumerate() {
+            if line_info.in_code_block || line_info.in_front_matter || line_info.in_html_comment {
+                continue;
+            }
+
+            let line = line_info.content(ctx.content);
+            if let Some(def) = parse_abbreviation_definition(line) {
+                definitions.push(DefinitionLine {
+                    line: idx + 1,
+                    abbreviation: def.abbreviation,
+                    column: def.abbreviation_start + 1,
+                });
+            }
+        }
+
+        definitions
+    }
+
+    /// Check whether an abbreviation is used anywhere outside of definitions and code
+    fn is_used(ctx: &LintContext, definition_lines: &[usize], abbreviation: &str) -> bool {
+        ctx.lines.iter().enumerate().any(|(idx, line_info)| {
+            !line_info.in_code_block
+                && !line_info.in_front_matter
+                && !line_info.in_html_comment
+                && !definition_lines.contains(&(idx + 1))
+                && contains_abbreviation(line_info.content(ctx.content), abbreviation)
+        })
+    }
+
+    /// Byte range covering a whole line including its trailing newline
+    fn line_range(ctx: &LintContext, line: usize) -> std::ops::Range<usize> {
+        let info = &ctx.lines[line - 1];
+        let start = info.byte_offset;
+        let mut end = start + info.byte_len;
+        if ctx.content[end..].starts_with("\r\n") {
+            end += 2;
+        } else if ctx.content[end..].starts_with('\n') {
+            end += 1;
+        }
+        start..end
+    }
+}
+
+impl Rule for MD107AbbreviationDefinitions {
+    fn name(&self) -> &'static str {
+        "MD107"
+    }
+
+    fn description(&self) -> &'static str {
+        "Abbreviation definitions should be unique and used"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Other
+    }
+
+    fn applies_to_flavor(&self, flavor: MarkdownFlavor) -> bool {
+        should_check_mkdocs(flavor)
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !ctx.content.contains("*[")
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        if self.should_skip(ctx) {
+            return Ok(Vec::new());
+        }
+
+        let definitions = self.collect_definitions(ctx);
+        if definitions.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut warnings = Vec::new();
+        // The `abbr` extension uses the last definition of each abbreviation
+        let mut last_seen: HashMap<&str, usize> = HashMap::new();
+        for def in &definitions {
+            last_seen.insert(def.abbreviation, def.line);
+        }
+
+        for def in &definitions {
+            let last_line = last_seen[def.abbreviation];
+            if last_line != def.line {
+                warnings.push(LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line: def.line,
+                    column: def.column,
+                    end_line: def.line,
+                    end_column: def.column + def.abbreviation.chars().count(),
+                    message: format!(
+                        "Duplicate abbreviation definition for '{}' (overridden by line {last_line})",
+                        def.abbreviation
+                    ),
+                    severity: Severity::Warning,
+                    fix: Some(Fix {
+                        range: Self::line_range(ctx, def.line),
+                        replacement: String::new(),
+                    }),
+                });
+            }
+        }
+
+        if self.config.check_unused {
+            let definition_lines: Vec<usize> = definitions.iter().map(|d| d.line).collect();
+            for def in &definitions {
+                // Only report the definition in effect; duplicates are already reported above
+                if last_seen.get(def.abbreviation) != Some(&def.line) {
+                    continue;
+                }
+                if !Self::is_used(ctx, &definition_lines, def.abbreviation) {
+                    warnings.push(LintWarning {
+                        rule_name: Some(self.name().to_string()),
+                        line: def.line,
+                        column: def.column,
+                        end_line: def.line,
+                        end_column: def.column + def.abbreviation.chars().count(),
+                        message: format!("Abbreviation '{}' is defined but never used", def.abbreviation),
+                        severity: Severity::Warning,
+                        fix: None,
+                    });
+                }
+            }
+        }
+
+        warnings.sort_by_key(|w| w.line);
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+
+        let mut ranges: Vec<_> = warnings.into_iter().filter_map(|w| w.fix.map(|f| f.range)).collect();
+        if ranges.is_empty() {
+            return Ok(ctx.content.to_string());
+        }
+
+        ranges.sort_by_key(|r| r.start);
+
+        let mut content = ctx.content.to_string();
+        for range in ranges.into_iter().rev() {
+            content.replace_range(range, "");
+        }
+
+        Ok(content)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::ConditionallyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD107Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check_mkdocs(rule: &MD107AbbreviationDefinitions, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::MkDocs, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_unique_definitions() {
+        let rule = MD107AbbreviationDefinitions::default();
+        let content = "The HTML and CSS specs.\n\n*[HTML]: HyperText Markup Language\n*[CSS]: Cascading Style Sheets\n";
+        assert!(check_mkdocs(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_standard_flavor_skipped() {
+        let rule = MD107AbbreviationDefinitions::default();
+        let content = "*[HTML]: HyperText Markup Language\n*[HTML]: HyperText Markup Language\n";
+        assert!(!rule.applies_to_flavor(MarkdownFlavor::Standard));
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(rule)];
+        assert!(
+            crate::lint(content, &rules, false, MarkdownFlavor::Standard)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_duplicate_definition() {
+        let rule = MD107AbbreviationDefinitions::default();
+        let content = "HTML\n\n*[HTML]: HyperText Markup Language\n*[HTML]: Hypertext Markup Language\n";
+        let warnings = check_mkdocs(&rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 3);
+        assert_eq!(warnings[0].column, 3);
+        assert_eq!(
+            warnings[0].message,
+            "Duplicate abbreviation definition for 'HTML' (overridden by line 4)"
+        );
+    }
+
+    #[test]
+    fn test_abbreviations_are_case_sensitive() {
+        let rule = MD107AbbreviationDefinitions::default();
+        let content = "*[Go]: The Go language\n*[GO]: Game Over\n";
+        assert!(check_mkdocs(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_definitions_in_code_blocks_ignored() {
+        let rule = MD107AbbreviationDefinitions::default();
+        let content = "*[HTML]: HyperText Markup Language\n\n```markdown\n*[HTML]: HyperText Markup Language\n```\n";
+        assert!(check_mkdocs(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_unused_not_reported_by_default() {
+        let rule = MD107AbbreviationDefinitions::default();
+        let content = "No abbreviations here.\n\n*[HTML]: HyperText Markup Language\n";
+        assert!(check_mkdocs(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_unused_reported_when_enabled() {
+        let rule = MD107AbbreviationDefinitions::new(true);
+        let content = "The HTML spec and XCSS.\n\n*[HTML]: HyperText Markup Language\n*[CSS]: Cascading Style Sheets\n";
+        let warnings = check_mkdocs(&rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 4);
+        assert_eq!(warnings[0].message, "Abbreviation 'CSS' is defined but never used");
+        assert!(warnings[0].fix.is_none());
+    }
+
+    #[test]
+    fn test_usage_in_code_block_does_not_count() {
+        let rule = MD107AbbreviationDefinitions::new(true);
+        let content = "```\nHTML\n```\n\n*[HTML]: HyperText Markup Language\n";
+        let warnings = check_mkdocs(&rule, content);
+        assert_eq!(warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_fix_removes_duplicates() {
+        // The last definition is the one rendered, so it is the one kept
+        let rule = MD107AbbreviationDefinitions::default();
+        let content = "HTML\n\n*[HTML]: HyperText Markup Language\n*[CSS]: Cascading Style Sheets\n*[HTML]: Other\n";
+        let ctx = LintContext::new(content, MarkdownFlavor::MkDocs, None);
+        let fixed = rule.fix(&ctx).unwrap();
+        assert_eq!(fixed, "HTML\n\n*[CSS]: Cascading Style Sheets\n*[HTML]: Other\n");
+    }
+
+    #[test]
+    fn test_unused_reported_on_last_definition() {
+        let rule = MD107AbbreviationDefinitions::new(true);
+        let content = "Text\n\n*[HTML]: One\n*[HTML]: Two\n";
+        let warnings = check_mkdocs(&rule, content);
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].message.contains("overridden by line 4"));
+        assert_eq!(warnings[1].line, 4);
+        assert_eq!(warnings[1].message, "Abbreviation 'HTML' is defined but never used");
+    }
+
+    #[test]
+    fn test_fix_keeps_unused_definitions() {
+        let rule = MD107AbbreviationDefinitions::new(true);
+        let content = "Text\n\n*[HTML]: HyperText Markup Language\n";
+        let ctx = LintContext::new(content, MarkdownFlavor::MkDocs, None);
+        assert_eq!(rule.fix(&ctx).unwrap(), content);
+    }
+}
@@ -0,0 +1,413 @@
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use crate::utils::range_utils::calculate_match_range;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD108 (Invisible and unusual whitespace characters)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD108Config {
+    /// Characters that are allowed in the document, given as `U+00A0` code points or as literal characters
+    #[serde(default)]
+    pub allowed: Vec<String>,
+
+    /// Opt-in characters to flag as well, such as `U+00A0`, given like `allowed`
+    #[serde(default)]
+    pub disallowed: Vec<String>,
+}
+
+impl RuleConfig for MD108Config {
+    const RULE_NAME: &'static str = "MD108";
+}
+
+/// What the fix does with a flagged character
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Replacement {
+    /// Replace with a regular ASCII space
+    Space,
+    /// Remove the character entirely
+    Remove,
+}
+
+/// Characters flagged by this rule, with a human-readable name
+///
+/// Zero-width joiner and non-joiner are intentionally absent: they are required in emoji
+/// sequences and in several scripts.
+const FLAGGED_CHARACTERS: &[(char, &str, Replacement)] = &[
+    ('\u{1680}', "ogham space mark", Replacement::Space),
+    ('\u{2000}', "en quad", Replacement::Space),
+    ('\u{2001}', "em quad", Replacement::Space),
+    ('\u{2002}', "en space", Replacement::Space),
+    ('\u{2003}', "em space", Replacement::Space),
+    ('\u{2004}', "three-per-em space", Replacement::Space),
+    ('\u{2005}', "four-per-em space", Replacement::Space),
+    ('\u{2006}', "six-per-em space", Replacement::Space),
+    ('\u{2007}', "figure space", Replacement::Space),
+    ('\u{2008}', "punctuation space", Replacement::Space),
+    ('\u{2009}', "thin space", Replacement::Space),
+    ('\u{200A}', "hair space", Replacement::Space),
+    ('\u{202F}', "narrow no-break space", Replacement::Space),
+    ('\u{205F}', "medium mathematical space", Replacement::Space),
+    ('\u{180E}', "mongolian vowel separator", Replacement::Remove),
+    ('\u{200B}', "zero width space", Replacement::Remove),
+    ('\u{2060}', "word joiner", Replacement::Remove),
+    ('\u{FEFF}', "zero width no-break space", Replacement::Remove),
+];
+
+/// Characters only flagged when listed in `disallowed`
+///
+/// Both are often typed on purpose: a no-break space keeps words or table cells from
+/// wrapping, and the ideographic space is the regular space in CJK text.
+const OPT_IN_CHARACTERS: &[(char, &str, Replacement)] = &[
+    ('\u{00A0}', "no-break space", Replacement::Space),
+    ('\u{3000}', "ideographic space", Replacement::Space),
+];
+
+/// Rule MD108: Invisible and unusual whitespace characters
+///
+/// See [docs/md108.md](../../docs/md108.md) for full documentation, configuration, and examples.
+///
+/// This rule flags zero-width characters and other invisible or unusual Unicode whitespace
+/// that is usually pasted in by accident. No-break and ideographic spaces are only flagged
+/// when listed in `disallowed`. Code blocks, code spans and front matter are not checked.
+///
+/// The fix replaces unusual spaces with a regular space and removes zero-width characters.
+#[derive(Debug, Clone, Default)]
+pub struct MD108InvisibleCharacters {
+    allowed: Vec<char>,
+    disallowed: Vec<char>,
+}
+
+impl MD108InvisibleCharacters {
+    pub fn new(allowed: Vec<char>, disallowed: Vec<char>) -> Self {
+        Self { allowed, disallowed }
+    }
+
+    pub fn from_config_struct(config: MD108Config) -> Self {
+        let allowed = Self::parse_characters(&config.allowed, "allowed");
+        let disallowed = Self::parse_characters(&config.disallowed, "disallowed")
+            .into_iter()
+            .filter(|c| {
+                let known = OPT_IN_CHARACTERS.iter().any(|(opt_in, _, _)| opt_in == c);
+                if !known {
+                    log::warn!(
+                        "[MD108] Ignoring disallowed character U+{:04X} (only U+00A0 and U+3000 can be opted in)",
+                        *c as u32
+                    );
+                }
+                known
+            })
+            .collect();
+        Self { allowed, disallowed }
+    }
+
+    /// Parse a list of config entries, logging and skipping invalid ones
+    fn parse_characters(entries: &[String], option: &str) -> Vec<char> {
+        entries
+            .iter()
+            .filter_map(|entry| match Self::parse_character(entry) {
+                Some(c) => Some(c),
+                None => {
+                    log::warn!(
+                        "[MD108] Ignoring invalid {option} character '{entry}' (expected U+XXXX or a single character)"
+                    );
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Parse an `allowed` entry: either a `U+XXXX` code point or a single literal character
+    fn parse_character(entry: &str) -> Option<char> {
+        // Only trim ASCII whitespace: the entry itself may be a Unicode space
+        let entry = entry.trim_matches(|c: char| c.is_ascii_whitespace());
+        if let Some(hex) = entry.strip_prefix("U+").or_else(|| entry.strip_prefix("u+")) {
+            return u32::from_str_radix(hex, 16).ok().and_then(char::from_u32);
+        }
+        let mut chars = entry.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(c),
+            _ => None,
+        }
+    }
+
+    fn lookup(&self, c: char) -> Option<(&'static str, Replacement)> {
+        if self.allowed.contains(&c) {
+            return None;
+        }
+        FLAGGED_CHARACTERS
+            .iter()
+            .chain(
+                OPT_IN_CHARACTERS
+                    .iter()
+                    .filter(|(opt_in, _, _)| self.disallowed.contains(opt_in)),
+            )
+            .find(|(flagged, _, _)| *flagged == c)
+            .map(|(_, name, replacement)| (*name, *replacement))
+    }
+}
+
+impl Rule for MD108InvisibleCharacters {
+    fn name(&self) -> &'static str {
+        "MD108"
+    }
+
+    fn description(&self) -> &'static str {
+        "Invisible and unusual whitespace characters"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Whitespace
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        // Every flagged character is outside the ASCII range
+        ctx.content.is_ascii()
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        if self.should_skip(ctx) {
+            return Ok(Vec::new());
+        }
+
+        let code_spans = ctx.code_spans();
+        let mut warnings = Vec::new();
+
+        for (idx, line_info) in ctx.lines.iter().enumerate() {
+            if line_info.in_code_block || line_info.in_front_matter {
+                continue;
+            }
+
+            let line = line_info.content(ctx.content);
+            if line.is_ascii() {
+                continue;
+            }
+
+            for (byte_idx, c) in line.char_indices() {
+                let Some((name, replacement)) = self.lookup(c) else {
+                    continue;
+                };
+
+                let offset = line_info.byte_offset + byte_idx;
+                // A byte order mark at the very start of the file is legitimate
+                if offset == 0 && c == '\u{FEFF}' {
+                    continue;
+                }
+                if code_spans
+                    .iter()
+                    .any(|span| offset >= span.byte_offset && offset < span.byte_end)
+                {
+                    continue;
+                }
+
+                let (line_num, column, end_line, end_column) =
+                    calculate_match_range(idx + 1, line, byte_idx, c.len_utf8());
+                let code_point = format!("U+{:04X}", c as u32);
+                let (message, replacement_text) = match replacement {
+                    Replacement::Space => (
+                        format!("Unusual whitespace character {code_point} ({name}) should be a regular space"),
+                        " ",
+                    ),
+                    Replacement::Remove => (format!("Invisible character {code_point} ({name}) found"), ""),
+                };
+
+                warnings.push(LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line: line_num,
+                    column,
+                    end_line,
+                    end_column,
+                    message,
+                    severity: Severity::Warning,
+                    fix: Some(Fix {
+                        range: offset..offset + c.len_utf8(),
+                        replacement: replacement_text.to_string(),
+                    }),
+                });
+            }
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+        if warnings.is_empty() {
+            return Ok(ctx.content.to_string());
+        }
+
+        let mut content = ctx.content.to_string();
+        // Warnings are produced in document order, so apply them back to front
+        for fix in warnings.into_iter().rev().filter_map(|w| w.fix) {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+
+        Ok(content)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::FullyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let to_code_points = |chars: &[char]| chars.iter().map(|c| format!("U+{:04X}", *c as u32)).collect();
+        let config = MD108Config {
+            allowed: to_code_points(&self.allowed),
+            disallowed: to_code_points(&self.disallowed),
+        };
+        let json_value = serde_json::to_value(&config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD108Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(rule: &MD108InvisibleCharacters, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    fn fix(rule: &MD108InvisibleCharacters, content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_plain_text_is_valid() {
+        let rule = MD108InvisibleCharacters::default();
+        assert!(check(&rule, "# Title\n\nJust regular text, café and 日本語.\n").is_empty());
+    }
+
+    #[test]
+    fn test_non_breaking_space_is_opt_in() {
+        let content = "# Title\n\nSome\u{00A0}text\n";
+        assert!(check(&MD108InvisibleCharacters::default(), content).is_empty());
+
+        let rule = MD108InvisibleCharacters::new(Vec::new(), vec!['\u{00A0}']);
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 3);
+        assert_eq!(warnings[0].column, 5);
+        assert_eq!(warnings[0].end_column, 6);
+        assert!(warnings[0].message.contains("U+00A0 (no-break space)"));
+    }
+
+    #[test]
+    fn test_zero_width_space() {
+        let rule = MD108InvisibleCharacters::default();
+        let warnings = check(&rule, "Zero\u{200B}width\n");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].message,
+            "Invisible character U+200B (zero width space) found"
+        );
+    }
+
+    #[test]
+    fn test_column_counts_characters() {
+        let rule = MD108InvisibleCharacters::default();
+        let warnings = check(&rule, "日本\u{2003}語\n");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].column, 3);
+    }
+
+    #[test]
+    fn test_code_is_skipped() {
+        let rule = MD108InvisibleCharacters::default();
+        let content = "Use `a\u{2003}b` here.\n\n```\ncode\u{200B}here\n```\n";
+        assert!(check(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_front_matter_is_skipped() {
+        let rule = MD108InvisibleCharacters::default();
+        let content = "---\ntitle: A\u{2003}B\n---\n\n# Title\n";
+        assert!(check(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_leading_bom_is_allowed() {
+        let rule = MD108InvisibleCharacters::default();
+        assert!(check(&rule, "\u{FEFF}# Title\n").is_empty());
+        assert_eq!(check(&rule, "# Title\u{FEFF}\n").len(), 1);
+    }
+
+    #[test]
+    fn test_joiners_are_not_flagged() {
+        let rule = MD108InvisibleCharacters::default();
+        assert!(check(&rule, "Family: 👨\u{200D}👩\u{200D}👧 and می\u{200C}خواهم\n").is_empty());
+    }
+
+    #[test]
+    fn test_allowed_characters() {
+        let config = MD108Config {
+            allowed: vec!["U+2002".to_string(), "\u{2009}".to_string()],
+            disallowed: Vec::new(),
+        };
+        let rule = MD108InvisibleCharacters::from_config_struct(config);
+        let warnings = check(&rule, "10\u{2002}km, 5\u{2009}kg and a\u{200B}b\n");
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("U+200B"));
+    }
+
+    #[test]
+    fn test_parse_character() {
+        assert_eq!(MD108InvisibleCharacters::parse_character("U+00A0"), Some('\u{00A0}'));
+        assert_eq!(MD108InvisibleCharacters::parse_character("u+200b"), Some('\u{200B}'));
+        assert_eq!(MD108InvisibleCharacters::parse_character("\u{00A0}"), Some('\u{00A0}'));
+        assert_eq!(MD108InvisibleCharacters::parse_character("U+ZZZZ"), None);
+        assert_eq!(MD108InvisibleCharacters::parse_character("ab"), None);
+    }
+
+    #[test]
+    fn test_fix() {
+        let rule = MD108InvisibleCharacters::default();
+        let content = "Some\u{2002}text with\u{200B}invisible `co\u{2002}de`\u{2003}here\n";
+        assert_eq!(fix(&rule, content), "Some text withinvisible `co\u{2002}de` here\n");
+    }
+
+    #[test]
+    fn test_fix_respects_allowed() {
+        let rule = MD108InvisibleCharacters::new(vec!['\u{2002}'], Vec::new());
+        let content = "10\u{2002}km\u{200B}\n";
+        assert_eq!(fix(&rule, content), "10\u{2002}km\n");
+    }
+
+    #[test]
+    fn test_disallowed_characters() {
+        let content = "Dragonfly\u{00A0}BSD and 日本\u{3000}語\n";
+        assert!(check(&MD108InvisibleCharacters::default(), content).is_empty());
+
+        let config = MD108Config {
+            allowed: Vec::new(),
+            disallowed: vec!["U+00A0".to_string(), "\u{3000}".to_string(), "U+0041".to_string()],
+        };
+        let rule = MD108InvisibleCharacters::from_config_struct(config);
+        // Only the opt-in characters are accepted; U+0041 ("A") is ignored
+        assert_eq!(rule.disallowed, vec!['\u{00A0}', '\u{3000}']);
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[1].message.contains("U+3000 (ideographic space)"));
+        assert_eq!(fix(&rule, content), "Dragonfly BSD and 日本 語\n");
+    }
+}
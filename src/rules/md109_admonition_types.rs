@@ -0,0 +1,264 @@
+use crate::config::{Config, MarkdownFlavor};
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use crate::utils::mkdocs_admonitions::{KNOWN_ADMONITION_TYPES, get_admonition_type};
+use crate::utils::mkdocs_common::should_check_mkdocs;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD109 (Admonition types should be known and lowercase)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD109Config {
+    /// Additional admonition types accepted besides the Material for MkDocs types
+    #[serde(default, alias = "custom_types")]
+    pub custom_types: Vec<String>,
+}
+
+impl RuleConfig for MD109Config {
+    const RULE_NAME: &'static str = "MD109";
+}
+
+/// Rule MD109: Admonition types should be known and lowercase
+///
+/// See [docs/md109.md](../../docs/md109.md) for full documentation, configuration, and examples.
+///
+/// This rule only runs with the MkDocs flavor. It checks the type keyword of
+/// `!!!` and `???` admonitions against the types styled by Material for MkDocs
+/// (plus any `custom-types`) and requires it to be lowercase.
+///
+/// The fix lowercases known types; unknown types must be corrected manually.
+#[derive(Debug, Clone, Default)]
+pub struct MD109AdmonitionTypes {
+    config: MD109Config,
+}
+
+impl MD109AdmonitionTypes {
+    pub fn new(custom_types: Vec<String>) -> Self {
+        Self::from_config_struct(MD109Config { custom_types })
+    }
+
+    pub fn from_config_struct(config: MD109Config) -> Self {
+        Self { config }
+    }
+
+    fn is_known(&self, lowercase_type: &str) -> bool {
+        KNOWN_ADMONITION_TYPES.contains(&lowercase_type)
+            || self
+                .config
+                .custom_types
+                .iter()
+                .any(|t| t.eq_ignore_ascii_case(lowercase_type))
+    }
+
+    fn suggest(&self, lowercase_type: &str) -> Option<String> {
+        let candidates: Vec<String> = KNOWN_ADMONITION_TYPES
+            .iter()
+            .map(|t| t.to_string())
+            .chain(self.config.custom_types.iter().map(|t| t.to_lowercase()))
+            .collect();
+        crate::config::suggest_similar_key(lowercase_type, &candidates)
+    }
+}
+
+impl Rule for MD109AdmonitionTypes {
+    fn name(&self) -> &'static str {
+        "MD109"
+    }
+
+    fn description(&self) -> &'static str {
+        "Admonition types should be known and lowercase"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Other
+    }
+
+    fn applies_to_flavor(&self, flavor: MarkdownFlavor) -> bool {
+        should_check_mkdocs(flavor)
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !ctx.content.contains("!!!") && !ctx.content.contains("???")
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        if self.should_skip(ctx) {
+            return Ok(Vec::new());
+        }
+
+        let mut warnings = Vec::new();
+
+        for (idx, line_info) in ctx.lines.iter().enumerate() {
+            if line_info.in_code_block || line_info.in_front_matter || line_info.in_html_comment {
+                continue;
+            }
+
+            let line = line_info.content(ctx.content);
+            let Some((admonition_type, type_start)) = get_admonition_type(line) else {
+                continue;
+            };
+
+            let lowercase = admonition_type.to_lowercase();
+            let column = type_start + 1;
+            let end_column = column + admonition_type.chars().count();
+
+            let (message, fix) = if !self.is_known(&lowercase) {
+                let message = match self.suggest(&lowercase) {
+                    Some(suggestion) => {
+                        format!("Unknown admonition type '{admonition_type}' (did you mean '{suggestion}'?)")
+                    }
+                    None => format!("Unknown admonition type '{admonition_type}'"),
+                };
+                (message, None)
+            } else if lowercase != admonition_type {
+                let start = line_info.byte_offset + type_start;
+                (
+                    format!("Admonition type '{admonition_type}' should be lowercase '{lowercase}'"),
+                    Some(Fix {
+                        range: start..start + admonition_type.len(),
+                        replacement: lowercase,
+                    }),
+                )
+            } else {
+                continue;
+            };
+
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line: idx + 1,
+                column,
+                end_line: idx + 1,
+                end_column,
+                message,
+                severity: Severity::Warning,
+                fix,
+            });
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+
+        let mut content = ctx.content.to_string();
+        for fix in warnings.into_iter().rev().filter_map(|w| w.fix) {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+
+        Ok(content)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::ConditionallyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD109Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check_mkdocs(rule: &MD109AdmonitionTypes, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::MkDocs, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_known_lowercase_types() {
+        let rule = MD109AdmonitionTypes::default();
+        let content = "!!! note\n    Text\n\n??? tip \"Title\"\n    Text\n\n???+ faq inline end\n    Text\n";
+        assert!(check_mkdocs(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_standard_flavor_skipped() {
+        let rule = MD109AdmonitionTypes::default();
+        assert!(!rule.applies_to_flavor(MarkdownFlavor::Standard));
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(rule)];
+        let warnings = crate::lint("!!! WARNING\n    Text\n", &rules, false, MarkdownFlavor::Standard).unwrap();
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_uppercase_type() {
+        let rule = MD109AdmonitionTypes::default();
+        let warnings = check_mkdocs(&rule, "!!! WARNING \"Careful\"\n    Text\n");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].column, 5);
+        assert_eq!(warnings[0].end_column, 12);
+        assert_eq!(
+            warnings[0].message,
+            "Admonition type 'WARNING' should be lowercase 'warning'"
+        );
+        assert!(warnings[0].fix.is_some());
+    }
+
+    #[test]
+    fn test_unknown_type_with_suggestion() {
+        let rule = MD109AdmonitionTypes::default();
+        let warnings = check_mkdocs(&rule, "!!! waring\n    Text\n");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].message,
+            "Unknown admonition type 'waring' (did you mean 'warning'?)"
+        );
+        assert!(warnings[0].fix.is_none());
+    }
+
+    #[test]
+    fn test_unknown_type_without_suggestion() {
+        let rule = MD109AdmonitionTypes::default();
+        let warnings = check_mkdocs(&rule, "!!! kangaroo\n    Text\n");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].message, "Unknown admonition type 'kangaroo'");
+    }
+
+    #[test]
+    fn test_custom_types() {
+        let rule = MD109AdmonitionTypes::new(vec!["Recipe".to_string()]);
+        assert!(check_mkdocs(&rule, "!!! recipe\n    Text\n").is_empty());
+        let warnings = check_mkdocs(&rule, "!!! Recipe\n    Text\n");
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("should be lowercase 'recipe'"));
+    }
+
+    #[test]
+    fn test_code_blocks_ignored() {
+        let rule = MD109AdmonitionTypes::default();
+        let content = "```markdown\n!!! Note\n    Text\n```\n";
+        assert!(check_mkdocs(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_fix_lowercases_known_types() {
+        let rule = MD109AdmonitionTypes::default();
+        let content = "!!! Note \"Keep Title\"\n    Text\n\n??? waring\n    Text\n\n???+ TIP\n    Text\n";
+        let ctx = LintContext::new(content, MarkdownFlavor::MkDocs, None);
+        assert_eq!(
+            rule.fix(&ctx).unwrap(),
+            "!!! note \"Keep Title\"\n    Text\n\n??? waring\n    Text\n\n???+ tip\n    Text\n"
+        );
+    }
+}
@@ -0,0 +1,376 @@
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use crate::utils::table_utils::{TableBlock, TableUtils};
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD110 (Table cells should have one space of padding)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD110Config {
+    /// Whether the rule is enabled (opt-in, like MD060)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Leave column-aligned tables alone, even though alignment adds extra padding
+    #[serde(default = "default_allow_aligned", alias = "allow_aligned")]
+    pub allow_aligned: bool,
+}
+
+fn default_allow_aligned() -> bool {
+    true
+}
+
+impl Default for MD110Config {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            allow_aligned: default_allow_aligned(),
+        }
+    }
+}
+
+impl RuleConfig for MD110Config {
+    const RULE_NAME: &'static str = "MD110";
+}
+
+/// Rule MD110: Table cells should have one space of padding
+///
+/// See [docs/md110.md](../../docs/md110.md) for full documentation, configuration, and examples.
+///
+/// Requires exactly one space between each pipe and the cell content (`| a | b |`).
+/// Unlike MD060 it never changes column widths: the fix only normalizes the padding
+/// of each cell, and delimiter cells keep their alignment colons next to the dashes.
+#[derive(Debug, Clone, Default)]
+pub struct MD110TableCellPadding {
+    config: MD110Config,
+}
+
+/// A table row split into its line prefix (indentation, blockquote markers) and the row itself
+struct TableRow<'a> {
+    /// 0-indexed line number
+    line_idx: usize,
+    prefix: &'a str,
+    body: &'a str,
+    cells: Vec<String>,
+    has_leading: bool,
+    has_trailing: bool,
+}
+
+impl MD110TableCellPadding {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            config: MD110Config {
+                enabled,
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn from_config_struct(config: MD110Config) -> Self {
+        Self { config }
+    }
+
+    fn table_rows<'a>(ctx: &'a LintContext, table: &TableBlock) -> Vec<TableRow<'a>> {
+        std::iter::once(table.header_line)
+            .chain(std::iter::once(table.delimiter_line))
+            .chain(table.content_lines.iter().copied())
+            .filter_map(|line_idx| {
+                let line = ctx.lines.get(line_idx)?.content(ctx.content);
+                let prefix_len = line.len() - line.trim_start_matches(|c: char| c.is_whitespace() || c == '>').len();
+                let (prefix, rest) = line.split_at(prefix_len);
+                let body = rest.trim_end();
+                let cells = TableUtils::split_table_row_with_flavor(body, ctx.flavor);
+                if cells.is_empty() {
+                    return None;
+                }
+                Some(TableRow {
+                    line_idx,
+                    prefix,
+                    body,
+                    cells,
+                    has_leading: body.starts_with('|'),
+                    has_trailing: body.ends_with('|') && body.len() > 1,
+                })
+            })
+            .collect()
+    }
+
+    /// Character offsets of the delimiter pipes within a row
+    fn pipe_positions(row: &TableRow) -> Vec<usize> {
+        let mut positions = Vec::new();
+        let mut offset = 0;
+        if row.has_leading {
+            positions.push(0);
+            offset = 1;
+        }
+        for (i, cell) in row.cells.iter().enumerate() {
+            offset += cell.chars().count();
+            if i + 1 < row.cells.len() || row.has_trailing {
+                positions.push(offset);
+                offset += 1;
+            }
+        }
+        positions
+    }
+
+    /// A table is column-aligned when every pipe lines up and every cell has some padding
+    fn is_aligned(rows: &[TableRow]) -> bool {
+        let Some(first) = rows.first() else {
+            return false;
+        };
+        let positions = Self::pipe_positions(first);
+        rows.iter().all(|row| {
+            Self::pipe_positions(row) == positions
+                && row.cells.iter().enumerate().all(|(i, cell)| {
+                    let padded_left = (i == 0 && !row.has_leading) || cell.starts_with(' ');
+                    let padded_right = (i + 1 == row.cells.len() && !row.has_trailing) || cell.ends_with(' ');
+                    padded_left && padded_right
+                })
+        })
+    }
+
+    /// Rebuild a row with exactly one space of padding in every cell
+    fn normalize_row(row: &TableRow) -> String {
+        let mut normalized = String::with_capacity(row.body.len());
+        if row.has_leading {
+            normalized.push('|');
+        }
+        for (i, cell) in row.cells.iter().enumerate() {
+            let content = cell.trim_matches([' ', '\t']);
+            let is_last = i + 1 == row.cells.len();
+            let pad_left = row.has_leading || i > 0;
+            let pad_right = row.has_trailing || !is_last;
+
+            if content.is_empty() {
+                if pad_left || pad_right {
+                    normalized.push(' ');
+                }
+            } else {
+                if pad_left {
+                    normalized.push(' ');
+                }
+                normalized.push_str(content);
+                if pad_right {
+                    normalized.push(' ');
+                }
+            }
+
+            if !is_last || row.has_trailing {
+                normalized.push('|');
+            }
+        }
+        normalized
+    }
+}
+
+impl Rule for MD110TableCellPadding {
+    fn name(&self) -> &'static str {
+        "MD110"
+    }
+
+    fn description(&self) -> &'static str {
+        "Table cells should have one space of padding"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Table
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !self.config.enabled || !ctx.likely_has_tables()
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        if self.should_skip(ctx) {
+            return Ok(Vec::new());
+        }
+
+        let mut warnings = Vec::new();
+
+        for table in ctx.table_blocks() {
+            let rows = Self::table_rows(ctx, table);
+            if self.config.allow_aligned && Self::is_aligned(&rows) {
+                continue;
+            }
+
+            for row in &rows {
+                let normalized = Self::normalize_row(row);
+                if normalized == row.body {
+                    continue;
+                }
+
+                // Point at the first character that differs from the normalized row
+                let diff_at = row
+                    .body
+                    .chars()
+                    .zip(normalized.chars())
+                    .take_while(|(a, b)| a == b)
+                    .count();
+                let column = row.prefix.chars().count() + diff_at + 1;
+                let end_column = row.prefix.chars().count() + row.body.chars().count() + 1;
+
+                let start = ctx.lines[row.line_idx].byte_offset + row.prefix.len();
+                warnings.push(LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line: row.line_idx + 1,
+                    column,
+                    end_line: row.line_idx + 1,
+                    end_column,
+                    message: "Table cells should have exactly one space of padding".to_string(),
+                    severity: Severity::Warning,
+                    fix: Some(Fix {
+                        range: start..start + row.body.len(),
+                        replacement: normalized,
+                    }),
+                });
+            }
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+
+        let mut content = ctx.content.to_string();
+        for fix in warnings.into_iter().rev().filter_map(|w| w.fix) {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+
+        Ok(content)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::FullyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD110Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(rule: &MD110TableCellPadding, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    fn fix(rule: &MD110TableCellPadding, content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let rule = MD110TableCellPadding::default();
+        assert!(check(&rule, "|a|b|\n|-|-|\n|1|2|\n").is_empty());
+    }
+
+    #[test]
+    fn test_compact_table_is_valid() {
+        let rule = MD110TableCellPadding::new(true);
+        let content = "| a | b |\n| --- | :-: |\n| 1 | 2 |\n";
+        assert!(check(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_tight_table() {
+        let rule = MD110TableCellPadding::new(true);
+        let content = "|a|b|\n|---|---|\n|1|2|\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 3);
+        assert_eq!(warnings[0].line, 1);
+        assert_eq!(warnings[0].column, 2);
+        assert_eq!(fix(&rule, content), "| a | b |\n| --- | --- |\n| 1 | 2 |\n");
+    }
+
+    #[test]
+    fn test_extra_padding() {
+        let rule = MD110TableCellPadding::new(true);
+        let content = "| a |  b  |\n| --- | --- |\n| 1 | 2 |\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].column, 7);
+        assert_eq!(fix(&rule, content), "| a | b |\n| --- | --- |\n| 1 | 2 |\n");
+    }
+
+    #[test]
+    fn test_alignment_colons_stay_adjacent() {
+        let rule = MD110TableCellPadding::new(true);
+        let content = "| a | b | c |\n|:---|---:|:-:|\n| 1 | 2 | 3 |\n";
+        assert_eq!(
+            fix(&rule, content),
+            "| a | b | c |\n| :--- | ---: | :-: |\n| 1 | 2 | 3 |\n"
+        );
+    }
+
+    #[test]
+    fn test_aligned_table_allowed() {
+        let rule = MD110TableCellPadding::new(true);
+        let content = "| Name  | Count |\n| ----- | ----: |\n| a     |     1 |\n";
+        assert!(check(&rule, content).is_empty());
+
+        let strict = MD110TableCellPadding::from_config_struct(MD110Config {
+            enabled: true,
+            allow_aligned: false,
+        });
+        assert_eq!(check(&strict, content).len(), 2);
+        assert_eq!(
+            fix(&strict, content),
+            "| Name | Count |\n| ----- | ----: |\n| a | 1 |\n"
+        );
+    }
+
+    #[test]
+    fn test_rows_without_outer_pipes() {
+        let rule = MD110TableCellPadding::new(true);
+        let content = "a|b\n---|---\n1  |  2\n";
+        assert_eq!(fix(&rule, content), "a | b\n--- | ---\n1 | 2\n");
+    }
+
+    #[test]
+    fn test_empty_cells_and_escaped_pipes() {
+        let rule = MD110TableCellPadding::new(true);
+        let content = "| a | b |\n| --- | --- |\n|| x \\| y  |\n";
+        assert_eq!(fix(&rule, content), "| a | b |\n| --- | --- |\n| | x \\| y |\n");
+    }
+
+    #[test]
+    fn test_indented_table_in_list() {
+        let rule = MD110TableCellPadding::new(true);
+        let content = "- Item\n\n  |a|b|\n  |---|---|\n  |1|2|\n";
+        assert_eq!(
+            fix(&rule, content),
+            "- Item\n\n  | a | b |\n  | --- | --- |\n  | 1 | 2 |\n"
+        );
+    }
+
+    #[test]
+    fn test_tables_in_code_blocks_ignored() {
+        let rule = MD110TableCellPadding::new(true);
+        let content = "```\n|a|b|\n|-|-|\n```\n";
+        assert!(check(&rule, content).is_empty());
+    }
+}
@@ -0,0 +1,271 @@
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::utils::code_block_utils::CodeBlockUtils;
+
+/// Rule MD111: Closing code fences should not be followed by content
+///
+/// See [docs/md111.md](../../docs/md111.md) for full documentation, configuration, and examples.
+///
+/// A line like ` ```text ` or ` ``` done ` inside a fenced code block is not a valid
+/// closing fence, so the block silently continues until the next bare fence (or the end
+/// of the document). This rule flags such lines: fence characters of the opening fence's
+/// kind, at least as long as the opening fence, followed by anything else. Like closing
+/// fences, they must be indented less than 4 columns past the enclosing container.
+///
+/// The fix moves the trailing content to its own line after the fence.
+#[derive(Debug, Clone, Default)]
+pub struct MD111ClosingFenceContent;
+
+/// Split a line into its container prefix (indentation, blockquote markers) and the rest
+fn split_prefix(line: &str) -> (&str, &str) {
+    let prefix_len = line.len() - line.trim_start_matches(|c: char| c.is_whitespace() || c == '>').len();
+    line.split_at(prefix_len)
+}
+
+/// Width of the blockquote markers at the start of a container prefix, including the
+/// optional space after the last `>`
+fn blockquote_width(prefix: &str) -> usize {
+    prefix
+        .rfind('>')
+        .map_or(0, |i| i + 1 + usize::from(prefix[i + 1..].starts_with(' ')))
+}
+
+/// Column where the content of the container holding the fence on `line_num` starts
+///
+/// That is the content column of the innermost list item the fence belongs to, or the end
+/// of the blockquote markers. A closing fence may be indented at most 3 columns past it.
+fn container_column(ctx: &LintContext, line_num: usize, opening_prefix: &str) -> usize {
+    // Walk back to the list item whose content the fence continues, if any
+    let mut min_indent = opening_prefix.len();
+    let mut list_column = 0;
+    for line_info in ctx.lines[..line_num].iter().rev() {
+        if let Some(item) = &line_info.list_item {
+            if item.content_column <= min_indent {
+                list_column = item.content_column;
+                break;
+            }
+            min_indent = min_indent.min(item.marker_column);
+        } else if !line_info.is_blank {
+            min_indent = min_indent.min(line_info.indent);
+        }
+        if min_indent == 0 {
+            break;
+        }
+    }
+    list_column.max(blockquote_width(opening_prefix))
+}
+
+/// Return the fence character and length if `body` starts with a code fence
+fn fence_run(body: &str) -> Option<(char, usize)> {
+    let fence_char = body.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let len = body.chars().take_while(|c| *c == fence_char).count();
+    (len >= 3).then_some((fence_char, len))
+}
+
+impl MD111ClosingFenceContent {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Rule for MD111ClosingFenceContent {
+    fn name(&self) -> &'static str {
+        "MD111"
+    }
+
+    fn description(&self) -> &'static str {
+        "Closing code fences should not be followed by content"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::CodeBlock
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        ctx.code_blocks.is_empty() || (!ctx.content.contains("```") && !ctx.content.contains("~~~"))
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        if self.should_skip(ctx) {
+            return Ok(Vec::new());
+        }
+
+        let mut warnings = Vec::new();
+
+        for (block_start, block_end) in CodeBlockUtils::detect_fenced_code_blocks(ctx.content) {
+            let (first_line, _) = ctx.offset_to_line_col(block_start);
+            let Some(opening) = ctx.lines.get(first_line - 1) else {
+                continue;
+            };
+
+            let (opening_prefix, opening_body) = split_prefix(opening.content(ctx.content));
+            let Some((fence_char, fence_len)) = fence_run(opening_body) else {
+                continue;
+            };
+            let container = container_column(ctx, first_line, opening_prefix);
+
+            for (idx, line_info) in ctx.lines.iter().enumerate().skip(first_line) {
+                if line_info.byte_offset >= block_end {
+                    break;
+                }
+
+                let line = line_info.content(ctx.content);
+                let (prefix, body) = split_prefix(line);
+                // Indented 4+ columns past the container, fence characters are block content
+                if prefix.len().saturating_sub(container) > 3 {
+                    continue;
+                }
+                let Some((c, len)) = fence_run(body) else {
+                    continue;
+                };
+                if c != fence_char || len < fence_len {
+                    continue;
+                }
+
+                // Fence characters are ASCII, so the run length is also its byte length
+                let trailing = body[len..].trim();
+                if trailing.is_empty() {
+                    // A real closing fence
+                    break;
+                }
+
+                let start = line_info.byte_offset + prefix.len() + len;
+                let end = line_info.byte_offset + line.trim_end().len();
+
+                warnings.push(LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line: idx + 1,
+                    column: prefix.chars().count() + len + 1,
+                    end_line: idx + 1,
+                    end_column: line.trim_end().chars().count() + 1,
+                    message: format!("Unexpected content after closing code fence: '{trailing}'"),
+                    severity: Severity::Warning,
+                    fix: Some(Fix {
+                        range: start..end,
+                        replacement: format!("\n{prefix}{trailing}"),
+                    }),
+                });
+
+                // The fix turns this line into the closing fence
+                break;
+            }
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+
+        let mut content = ctx.content.to_string();
+        for fix in warnings.into_iter().rev().filter_map(|w| w.fix) {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+
+        Ok(content)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::FullyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn from_config(_config: &crate::config::Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        Box::new(MD111ClosingFenceContent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD111ClosingFenceContent.check(&ctx).unwrap()
+    }
+
+    fn fix(content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD111ClosingFenceContent.fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_valid_fences() {
+        let content = "```rust\nfn main() {}\n```\n\n~~~\ntext\n~~~  \n";
+        assert!(check(content).is_empty());
+    }
+
+    #[test]
+    fn test_text_after_closing_fence() {
+        let content = "```bash\nls\n``` and then run it\n\nParagraph\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 3);
+        assert_eq!(warnings[0].column, 4);
+        assert_eq!(
+            warnings[0].message,
+            "Unexpected content after closing code fence: 'and then run it'"
+        );
+        assert_eq!(fix(content), "```bash\nls\n```\nand then run it\n\nParagraph\n");
+    }
+
+    #[test]
+    fn test_info_string_on_closing_fence() {
+        let content = "```\ncode\n```text\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(fix(content), "```\ncode\n```\ntext\n");
+    }
+
+    #[test]
+    fn test_shorter_or_different_fences_are_content() {
+        // Shorter runs and the other fence character are plain code block content
+        let content = "````markdown\n```python\nprint()\n```\n~~~ text\n````\n";
+        assert!(check(content).is_empty());
+    }
+
+    #[test]
+    fn test_indented_code_block_ignored() {
+        let content = "Text\n\n    ```\n    ``` not a fence\n";
+        assert!(check(content).is_empty());
+    }
+
+    #[test]
+    fn test_fence_in_list_keeps_indentation() {
+        let content = "- Item\n\n  ```\n  code\n  ``` note\n";
+        assert_eq!(fix(content), "- Item\n\n  ```\n  code\n  ```\n  note\n");
+    }
+
+    #[test]
+    fn test_fence_in_blockquote() {
+        let content = "> ```\n> code\n> ``` note\n";
+        assert_eq!(fix(content), "> ```\n> code\n> ```\n> note\n");
+    }
+
+    #[test]
+    fn test_indented_fence_like_line_is_content() {
+        // Indented 4 spaces, these lines are part of the outer block, not closing fences
+        let content = "# T\n\n```markdown\n    ```python\n    x = 1\n    ```\n```\n";
+        assert!(check(content).is_empty());
+        assert_eq!(fix(content), content);
+    }
+
+    #[test]
+    fn test_indented_fence_like_line_in_list_is_content() {
+        let content = "- Item\n\n  ```markdown\n      ``` text\n  ```\n";
+        assert!(check(content).is_empty());
+    }
+
+    #[test]
+    fn test_closing_fence_indented_up_to_three_in_list() {
+        let content = "- Item\n\n  ```\n  code\n     ``` note\n";
+        assert_eq!(check(content).len(), 1);
+    }
+}
@@ -0,0 +1,271 @@
+use crate::config::Config;
+use crate::lint_context::{HeadingInfo, HeadingStyle, LintContext};
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// Policy for closing hashes on ATX headings
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum ClosingSequenceStyle {
+    /// Use whatever the first ATX heading in the document uses
+    #[default]
+    Consistent,
+    /// Every ATX heading has closing hashes matching its opening hashes (`## Title ##`)
+    Always,
+    /// No ATX heading has closing hashes (`## Title`)
+    Never,
+}
+
+/// Configuration for MD112 (ATX heading closing sequences)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD112Config {
+    /// Whether the rule is enabled (opt-in, since MD003 already covers closed ATX headings)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Closing hash policy: "consistent", "always", or "never"
+    #[serde(default)]
+    pub closed: ClosingSequenceStyle,
+}
+
+impl RuleConfig for MD112Config {
+    const RULE_NAME: &'static str = "MD112";
+}
+
+/// Rule MD112: ATX closing sequence style should be consistent
+///
+/// See [docs/md112.md](../../docs/md112.md) for full documentation, configuration, and examples.
+///
+/// Setext headings have no closing sequence and are skipped. Custom IDs (`{#id}`) are kept
+/// at the end of the heading when closing hashes are added or removed.
+#[derive(Debug, Clone, Default)]
+pub struct MD112AtxClosingSequence {
+    config: MD112Config,
+}
+
+impl MD112AtxClosingSequence {
+    pub fn new(enabled: bool, closed: ClosingSequenceStyle) -> Self {
+        Self {
+            config: MD112Config { enabled, closed },
+        }
+    }
+
+    pub fn from_config_struct(config: MD112Config) -> Self {
+        Self { config }
+    }
+
+    /// Rebuild the heading after its opening hashes, with or without closing hashes
+    fn rebuild_heading(heading: &HeadingInfo, closed: bool) -> String {
+        let raw_text = heading.raw_text.as_str();
+
+        // Custom IDs go after the closing sequence
+        let (text, custom_id) = match raw_text.rfind(" {#") {
+            Some(id_start) if heading.custom_id.is_some() && raw_text.ends_with('}') => {
+                (raw_text[..id_start].trim_end(), &raw_text[id_start..])
+            }
+            _ => (raw_text, ""),
+        };
+
+        if closed {
+            format!("{text} {}{custom_id}", heading.marker)
+        } else {
+            format!("{text}{custom_id}")
+        }
+    }
+}
+
+impl Rule for MD112AtxClosingSequence {
+    fn name(&self) -> &'static str {
+        "MD112"
+    }
+
+    fn description(&self) -> &'static str {
+        "ATX closing sequence style should be consistent"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Heading
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !self.config.enabled || !ctx.likely_has_headings()
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        if !self.config.enabled {
+            return Ok(Vec::new());
+        }
+
+        let mut warnings = Vec::new();
+        let mut expected = match self.config.closed {
+            ClosingSequenceStyle::Always => Some(true),
+            ClosingSequenceStyle::Never => Some(false),
+            ClosingSequenceStyle::Consistent => None,
+        };
+
+        for (idx, line_info) in ctx.lines.iter().enumerate() {
+            let Some(heading) = &line_info.heading else {
+                continue;
+            };
+            if heading.style != HeadingStyle::ATX || heading.raw_text.is_empty() {
+                continue;
+            }
+
+            let closed = *expected.get_or_insert(heading.has_closing_sequence);
+
+            let message = if closed && !heading.has_closing_sequence {
+                "Missing closing sequence on ATX heading".to_string()
+            } else if !closed && heading.has_closing_sequence {
+                "Unexpected closing sequence on ATX heading".to_string()
+            } else if closed && heading.closing_sequence != heading.marker {
+                format!(
+                    "Closing sequence '{}' should match the opening '{}'",
+                    heading.closing_sequence, heading.marker
+                )
+            } else {
+                continue;
+            };
+
+            let line = line_info.content(ctx.content);
+            let content_end = line.trim_end().len();
+            let start = line_info.byte_offset + heading.content_column.min(content_end);
+
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line: idx + 1,
+                column: heading.marker_column + 1,
+                end_line: idx + 1,
+                end_column: line[..content_end].chars().count() + 1,
+                message,
+                severity: Severity::Warning,
+                fix: Some(Fix {
+                    range: start..line_info.byte_offset + content_end,
+                    replacement: Self::rebuild_heading(heading, closed),
+                }),
+            });
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+
+        let mut content = ctx.content.to_string();
+        for fix in warnings.into_iter().rev().filter_map(|w| w.fix) {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+
+        Ok(content)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::FullyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD112Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(rule: &MD112AtxClosingSequence, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    fn fix(rule: &MD112AtxClosingSequence, content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let rule = MD112AtxClosingSequence::default();
+        let content = "# Title #\n\n## Section\n";
+        assert!(check(&rule, content).is_empty());
+        assert_eq!(fix(&rule, content), content);
+    }
+
+    #[test]
+    fn test_consistent_follows_first_heading() {
+        let rule = MD112AtxClosingSequence::new(true, ClosingSequenceStyle::Consistent);
+        assert!(check(&rule, "# Title #\n\n## Section ##\n").is_empty());
+        assert!(check(&rule, "# Title\n\n## Section\n").is_empty());
+
+        let content = "# Title #\n\n## Section\n\n### Sub ###\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 3);
+        assert_eq!(warnings[0].message, "Missing closing sequence on ATX heading");
+        assert_eq!(fix(&rule, content), "# Title #\n\n## Section ##\n\n### Sub ###\n");
+    }
+
+    #[test]
+    fn test_never_removes_closing_hashes() {
+        let rule = MD112AtxClosingSequence::new(true, ClosingSequenceStyle::Never);
+        let content = "# Title #\n\n## Section ####   \n\n### Plain\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].message, "Unexpected closing sequence on ATX heading");
+        // Trailing whitespace is left to MD009
+        assert_eq!(fix(&rule, content), "# Title\n\n## Section   \n\n### Plain\n");
+    }
+
+    #[test]
+    fn test_always_matches_opening_count() {
+        let rule = MD112AtxClosingSequence::new(true, ClosingSequenceStyle::Always);
+        let content = "# Title\n\n## Section #\n\n### Sub ###\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(
+            warnings[1].message,
+            "Closing sequence '#' should match the opening '##'"
+        );
+        assert_eq!(fix(&rule, content), "# Title #\n\n## Section ##\n\n### Sub ###\n");
+    }
+
+    #[test]
+    fn test_custom_ids_preserved() {
+        let always = MD112AtxClosingSequence::new(true, ClosingSequenceStyle::Always);
+        assert_eq!(fix(&always, "## Install {#install}\n"), "## Install ## {#install}\n");
+
+        let never = MD112AtxClosingSequence::new(true, ClosingSequenceStyle::Never);
+        assert_eq!(fix(&never, "## Install ## {#install}\n"), "## Install {#install}\n");
+    }
+
+    #[test]
+    fn test_setext_and_code_blocks_skipped() {
+        let rule = MD112AtxClosingSequence::new(true, ClosingSequenceStyle::Always);
+        let content = "Title\n=====\n\n```\n# not a heading\n```\n";
+        assert!(check(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_indented_heading() {
+        let rule = MD112AtxClosingSequence::new(true, ClosingSequenceStyle::Never);
+        assert_eq!(fix(&rule, "  ## Title ##\n"), "  ## Title\n");
+    }
+}
@@ -0,0 +1,281 @@
+use crate::config::{Config, MarkdownFlavor};
+use crate::lint_context::LintContext;
+use crate::rule::{FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use crate::utils::mkdocs_common::should_check_mkdocs;
+use crate::utils::mkdocs_snippets::{is_snippet_block_delimiter, is_snippet_marker};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+/// Single-line snippet include: `--8<-- "path"` (the closing quote is optional for detection)
+static SNIPPET_INCLUDE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"^(;*)-+8<-+\s+(["'])([^"']*)"#).unwrap());
+
+/// Configuration for MD113 (Snippet include targets should exist)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD113Config {
+    /// Directories snippet paths are resolved against, like the Snippets extension's
+    /// `base_path` option (relative to the working directory)
+    #[serde(default = "default_base_path", alias = "base_path")]
+    pub base_path: Vec<String>,
+}
+
+fn default_base_path() -> Vec<String> {
+    vec![".".to_string()]
+}
+
+impl Default for MD113Config {
+    fn default() -> Self {
+        Self {
+            base_path: default_base_path(),
+        }
+    }
+}
+
+impl RuleConfig for MD113Config {
+    const RULE_NAME: &'static str = "MD113";
+}
+
+/// Rule MD113: Snippet include targets should exist
+///
+/// See [docs/md113.md](../../docs/md113.md) for full documentation, configuration, and examples.
+///
+/// Checks the files included with MkDocs Snippets (`--8<-- "file.md"` and multi-line
+/// `--8<--` blocks). Paths are resolved against each configured base path and the
+/// directory of the including file. Remote (URL) snippets and section markers are ignored.
+///
+/// Documents without a known file path (e.g. unnamed stdin) are not checked.
+#[derive(Debug, Clone, Default)]
+pub struct MD113SnippetTargets {
+    config: MD113Config,
+}
+
+impl MD113SnippetTargets {
+    pub fn new(base_path: Vec<String>) -> Self {
+        Self::from_config_struct(MD113Config { base_path })
+    }
+
+    pub fn from_config_struct(config: MD113Config) -> Self {
+        Self { config }
+    }
+
+    /// Strip a `:start:end` line range or `:section` name from a snippet path
+    fn file_part(target: &str) -> &str {
+        target.split(':').next().unwrap_or(target).trim()
+    }
+
+    fn is_remote(target: &str) -> bool {
+        target.starts_with("http://") || target.starts_with("https://")
+    }
+
+    fn target_exists(&self, target: &str, source_dir: Option<&Path>) -> bool {
+        let path = Path::new(target);
+        if path.is_absolute() {
+            return path.exists();
+        }
+
+        self.config
+            .base_path
+            .iter()
+            .map(PathBuf::from)
+            .chain(source_dir.map(Path::to_path_buf))
+            .any(|base| base.join(path).exists())
+    }
+}
+
+impl Rule for MD113SnippetTargets {
+    fn name(&self) -> &'static str {
+        "MD113"
+    }
+
+    fn description(&self) -> &'static str {
+        "Snippet include targets should exist"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Other
+    }
+
+    fn applies_to_flavor(&self, flavor: MarkdownFlavor) -> bool {
+        should_check_mkdocs(flavor)
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        ctx.source_file.is_none() || !ctx.content.contains("8<")
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        if self.should_skip(ctx) {
+            return Ok(Vec::new());
+        }
+
+        let source_dir = ctx.source_file.as_deref().and_then(Path::parent);
+        let mut warnings = Vec::new();
+        let mut in_block = false;
+
+        // Snippets is a preprocessor, so includes inside code blocks count too
+        for (idx, line_info) in ctx.lines.iter().enumerate() {
+            if line_info.in_front_matter {
+                continue;
+            }
+
+            let line = line_info.content(ctx.content);
+            let trimmed = line.trim();
+            let indent = line.len() - line.trim_start().len();
+
+            // (byte offset of the target within the line, target)
+            let target = if is_snippet_block_delimiter(line) {
+                in_block = !in_block;
+                continue;
+            } else if in_block {
+                // One path per line inside `--8<--` blocks; `;` comments a line out
+                if trimmed.is_empty() || trimmed.starts_with(';') {
+                    continue;
+                }
+                (indent, trimmed)
+            } else if is_snippet_marker(line)
+                && let Some(caps) = SNIPPET_INCLUDE.captures(trimmed)
+            {
+                // A leading `;` escapes the include
+                if !caps[1].is_empty() {
+                    continue;
+                }
+                let path = caps.get(3).unwrap();
+                (indent + path.start(), path.as_str())
+            } else {
+                continue;
+            };
+
+            let (offset, raw_target) = target;
+            let file = Self::file_part(raw_target);
+            if file.is_empty() || Self::is_remote(raw_target) || self.target_exists(file, source_dir) {
+                continue;
+            }
+
+            let column = line[..offset].chars().count() + 1;
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line: idx + 1,
+                column,
+                end_line: idx + 1,
+                end_column: column + file.chars().count(),
+                message: format!("Snippet target not found: {file}"),
+                severity: Severity::Warning,
+                fix: None,
+            });
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        Ok(ctx.content.to_string())
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::Unfixable
+    }
+
+    fn cacheable(&self) -> bool {
+        // Snippet files can be created or deleted without this file changing
+        false
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD113Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn check_in(dir: &Path, rule: &MD113SnippetTargets, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::MkDocs, Some(dir.join("docs/index.md")));
+        rule.check(&ctx).unwrap()
+    }
+
+    fn setup() -> (tempfile::TempDir, MD113SnippetTargets) {
+        let dir = tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("docs")).unwrap();
+        fs::create_dir_all(dir.path().join("snippets")).unwrap();
+        fs::write(dir.path().join("snippets/abbr.md"), "*[HTML]: Hyper Text\n").unwrap();
+        fs::write(dir.path().join("docs/local.md"), "Local\n").unwrap();
+        let rule = MD113SnippetTargets::new(vec![dir.path().join("snippets").to_string_lossy().to_string()]);
+        (dir, rule)
+    }
+
+    #[test]
+    fn test_existing_targets() {
+        let (dir, rule) = setup();
+        let content = "--8<-- \"abbr.md\"\n\n--8<-- 'local.md'\n\n--8<-- \"abbr.md:1:2\"\n";
+        assert!(check_in(dir.path(), &rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_missing_target() {
+        let (dir, rule) = setup();
+        let content = "# Title\n\n--8<-- \"missing.md\"\n";
+        let warnings = check_in(dir.path(), &rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 3);
+        assert_eq!(warnings[0].column, 9);
+        assert_eq!(warnings[0].message, "Snippet target not found: missing.md");
+    }
+
+    #[test]
+    fn test_block_format() {
+        let (dir, rule) = setup();
+        let content = "--8<--\nabbr.md\n;commented.md\ngone.md\n--8<--\n";
+        let warnings = check_in(dir.path(), &rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 4);
+        assert_eq!(warnings[0].message, "Snippet target not found: gone.md");
+    }
+
+    #[test]
+    fn test_includes_inside_code_blocks() {
+        let (dir, rule) = setup();
+        let content = "```python\n--8<-- \"missing.py\"\n```\n";
+        assert_eq!(check_in(dir.path(), &rule, content).len(), 1);
+    }
+
+    #[test]
+    fn test_ignored_forms() {
+        let (dir, rule) = setup();
+        let content =
+            ";--8<-- \"escaped.md\"\n\n--8<-- \"https://example.com/remote.md\"\n\n<!-- --8<-- [start:intro] -->\n";
+        assert!(check_in(dir.path(), &rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_requires_mkdocs_and_source_file() {
+        let rule = MD113SnippetTargets::default();
+        let content = "--8<-- \"missing.md\"\n";
+
+        assert!(!rule.applies_to_flavor(MarkdownFlavor::Standard));
+
+        let ctx = LintContext::new(content, MarkdownFlavor::MkDocs, None);
+        assert!(rule.check(&ctx).unwrap().is_empty());
+    }
+}
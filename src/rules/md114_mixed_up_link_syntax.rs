@@ -0,0 +1,281 @@
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rules::md011_no_reversed_links::{is_adjacent_prose, reversed_image_order};
+use crate::utils::range_utils::calculate_match_range;
+use crate::utils::regex_cache::get_cached_regex;
+use crate::utils::skip_context::is_in_math_context;
+
+// Reversed image detection pattern: `!(alt)[url]`
+const REVERSED_IMAGE_REGEX_STR: &str = r"(^|[^\\])!\(([^()]+)\)\[([^\]]+)\]";
+
+/// Rule MD114: Image and reference syntax should not be reversed or mixed up
+///
+/// See [docs/md114.md](../../docs/md114.md) for full documentation, configuration, and examples.
+///
+/// Rounds out MD011 (reversed links) with two more bracket mix-ups:
+/// - Reversed images: `!(alt)[image.png]` instead of `![alt](image.png)`
+/// - Parentheses around a reference label: `[text](label)` or `![alt](label)` where `label`
+///   is a defined reference and not a plausible URL, instead of `[text][label]`
+#[derive(Debug, Clone, Default)]
+pub struct MD114MixedUpLinkSyntax;
+
+impl MD114MixedUpLinkSyntax {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn is_skipped(ctx: &LintContext, byte_pos: usize) -> bool {
+        ctx.is_in_code_block_or_span(byte_pos)
+            || ctx.is_in_html_comment(byte_pos)
+            || is_in_math_context(ctx, byte_pos)
+            || ctx.is_in_jinja_range(byte_pos)
+            || ctx.is_in_front_matter(byte_pos)
+    }
+
+    fn check_reversed_images(&self, ctx: &LintContext, warnings: &mut Vec<LintWarning>) {
+        let Ok(re) = get_cached_regex(REVERSED_IMAGE_REGEX_STR) else {
+            return;
+        };
+
+        for (idx, line_info) in ctx.lines.iter().enumerate() {
+            let line = line_info.content(ctx.content);
+            if !line.contains("!(") {
+                continue;
+            }
+
+            let mut last_end = 0;
+            while let Some(cap) = re.captures(&line[last_end..]) {
+                let match_obj = cap.get(0).unwrap();
+                let end_pos = last_end + match_obj.end();
+                let match_start = last_end + match_obj.start() + cap[1].len();
+                last_end = end_pos;
+
+                let (paren_content, bracket_content) = (&cap[2], &cap[3]);
+                if is_adjacent_prose(bracket_content, &line[end_pos..])
+                    || Self::is_skipped(ctx, line_info.byte_offset + match_start)
+                {
+                    continue;
+                }
+
+                let (alt, url) = reversed_image_order(paren_content, bracket_content);
+                let replacement = format!("![{alt}]({url})");
+                let (start_line, start_col, end_line, end_col) =
+                    calculate_match_range(idx + 1, line, match_start, end_pos - match_start);
+
+                warnings.push(LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line: start_line,
+                    column: start_col,
+                    end_line,
+                    end_column: end_col,
+                    message: format!("Reversed image syntax: use {replacement} instead"),
+                    severity: Severity::Warning,
+                    fix: Some(Fix {
+                        range: line_info.byte_offset + match_start..line_info.byte_offset + end_pos,
+                        replacement,
+                    }),
+                });
+            }
+        }
+    }
+
+    /// A destination that reads as a reference label rather than a URL or path
+    fn looks_like_label(destination: &str) -> bool {
+        !destination.is_empty()
+            && !destination
+                .chars()
+                .any(|c| c.is_whitespace() || matches!(c, '/' | '.' | ':' | '#' | '?' | '@' | '<'))
+    }
+
+    /// Build a warning for `[text](label)` / `![alt](label)` spanning `byte_offset..byte_end`
+    fn mixed_up_reference(
+        &self,
+        ctx: &LintContext,
+        byte_offset: usize,
+        byte_end: usize,
+        destination: &str,
+        kind: &str,
+    ) -> Option<LintWarning> {
+        if !Self::looks_like_label(destination)
+            || ctx.get_reference_url(destination).is_none()
+            || Self::is_skipped(ctx, byte_offset)
+        {
+            return None;
+        }
+
+        // Only the exact form `](label)` is rewritten; titles or spacing mean it was meant inline
+        let source = ctx.content.get(byte_offset..byte_end)?;
+        let paren_start = source.rfind("](")? + 1;
+        if source[paren_start..] != format!("({destination})") {
+            return None;
+        }
+
+        let (line, column) = ctx.offset_to_line_col(byte_offset + paren_start);
+        let (end_line, end_column) = ctx.offset_to_line_col(byte_end);
+
+        Some(LintWarning {
+            rule_name: Some(self.name().to_string()),
+            line,
+            column,
+            end_line,
+            end_column,
+            message: format!(
+                "Reference {kind} written with parentheses: use [{destination}] instead of ({destination})"
+            ),
+            severity: Severity::Warning,
+            fix: Some(Fix {
+                range: byte_offset + paren_start..byte_end,
+                replacement: format!("[{destination}]"),
+            }),
+        })
+    }
+}
+
+impl Rule for MD114MixedUpLinkSyntax {
+    fn name(&self) -> &'static str {
+        "MD114"
+    }
+
+    fn description(&self) -> &'static str {
+        "Image and reference syntax should not be reversed or mixed up"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Link
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        ctx.content.is_empty() || !ctx.likely_has_links_or_images()
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+
+        self.check_reversed_images(ctx, &mut warnings);
+
+        if !ctx.reference_defs.is_empty() {
+            for link in ctx.links.iter().filter(|l| !l.is_reference) {
+                warnings.extend(self.mixed_up_reference(ctx, link.byte_offset, link.byte_end, &link.url, "link"));
+            }
+            for image in ctx.images.iter().filter(|i| !i.is_reference) {
+                warnings.extend(self.mixed_up_reference(ctx, image.byte_offset, image.byte_end, &image.url, "image"));
+            }
+        }
+
+        warnings.sort_by_key(|w| (w.line, w.column));
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+
+        let mut content = ctx.content.to_string();
+        for fix in warnings.into_iter().rev().filter_map(|w| w.fix) {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+
+        Ok(content)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::FullyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn from_config(_config: &crate::config::Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        Box::new(MD114MixedUpLinkSyntax)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD114MixedUpLinkSyntax.check(&ctx).unwrap()
+    }
+
+    fn fix(content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD114MixedUpLinkSyntax.fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_reversed_image() {
+        let content = "See !(A diagram)[images/flow.png] below.\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].column, 5);
+        assert_eq!(
+            warnings[0].message,
+            "Reversed image syntax: use ![A diagram](images/flow.png) instead"
+        );
+        assert_eq!(fix(content), "See ![A diagram](images/flow.png) below.\n");
+    }
+
+    #[test]
+    fn test_reversed_image_url_first() {
+        assert_eq!(
+            fix("!(https://example.com/a.png)[Logo]\n"),
+            "![Logo](https://example.com/a.png)\n"
+        );
+    }
+
+    #[test]
+    fn test_reversed_image_single_words() {
+        // Neither side is a clear URL: the file-like side is the source
+        assert_eq!(fix("!(alt)[img.png]\n"), "![alt](img.png)\n");
+        assert_eq!(fix("!(img.png)[alt]\n"), "![alt](img.png)\n");
+        assert_eq!(fix("!(Logo)[assets/logo]\n"), "![Logo](assets/logo)\n");
+        // Otherwise the alt text comes first, as written
+        assert_eq!(fix("!(Logo)[logo]\n"), "![Logo](logo)\n");
+        assert_eq!(
+            check("!(alt)[img.png]\n")[0].message,
+            "Reversed image syntax: use ![alt](img.png) instead"
+        );
+    }
+
+    #[test]
+    fn test_prose_not_flagged() {
+        // Escaped bang, footnotes, and `(text)[ref](url)` are not reversed images
+        let content = "Wow\\!(really)[sic]\n\nDone!(see note)[^1]\n\n!(a)[b](c)\n\n[^1]: Note\n";
+        assert!(check(content).is_empty());
+    }
+
+    #[test]
+    fn test_code_skipped() {
+        let content = "`!(alt)[img.png]`\n\n```\n!(alt)[img.png]\n```\n";
+        assert!(check(content).is_empty());
+    }
+
+    #[test]
+    fn test_reference_label_in_parentheses() {
+        let content = "Read the [guide](install) and ![logo](logo).\n\n[install]: ./install.md\n[logo]: logo.png\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].column, 17);
+        assert_eq!(
+            warnings[0].message,
+            "Reference link written with parentheses: use [install] instead of (install)"
+        );
+        assert_eq!(
+            fix(content),
+            "Read the [guide][install] and ![logo][logo].\n\n[install]: ./install.md\n[logo]: logo.png\n"
+        );
+    }
+
+    #[test]
+    fn test_real_destinations_not_flagged() {
+        // Paths and undefined labels are ordinary inline links
+        let content = "[a](install.md) [b](./install) [c](other) [d](install \"Title\")\n\n[install]: ./install.md\n";
+        assert!(check(content).is_empty());
+    }
+}
@@ -0,0 +1,247 @@
+use crate::config::Config;
+use crate::lint_context::{HeadingStyle, LintContext};
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+use unicode_width::UnicodeWidthStr;
+
+/// How long a setext underline must be relative to its heading text
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum UnderlineLengthStyle {
+    /// The underline must be at least as wide as the heading text
+    #[default]
+    AtLeast,
+    /// The underline must be exactly as wide as the heading text
+    #[serde(alias = "exactly")]
+    Exact,
+}
+
+/// Configuration for MD115 (Setext underline length)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD115Config {
+    /// Whether the rule is enabled (opt-in, like MD110 and MD060)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Underline length policy: "at-least" or "exact"
+    #[serde(default)]
+    pub style: UnderlineLengthStyle,
+}
+
+impl RuleConfig for MD115Config {
+    const RULE_NAME: &'static str = "MD115";
+}
+
+/// Rule MD115: Setext heading underlines should match the heading text length
+///
+/// See [docs/md115.md](../../docs/md115.md) for full documentation, configuration, and examples.
+///
+/// Lengths are compared by display width, so wide (CJK, emoji) and combining characters
+/// count the way they look in a monospace editor.
+#[derive(Debug, Clone, Default)]
+pub struct MD115SetextUnderlineLength {
+    config: MD115Config,
+}
+
+impl MD115SetextUnderlineLength {
+    pub fn new(enabled: bool, style: UnderlineLengthStyle) -> Self {
+        Self {
+            config: MD115Config { enabled, style },
+        }
+    }
+
+    pub fn from_config_struct(config: MD115Config) -> Self {
+        Self { config }
+    }
+}
+
+impl Rule for MD115SetextUnderlineLength {
+    fn name(&self) -> &'static str {
+        "MD115"
+    }
+
+    fn description(&self) -> &'static str {
+        "Setext heading underlines should match the heading text length"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Heading
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !self.config.enabled || !ctx.likely_has_headings() || (!ctx.content.contains('=') && !ctx.content.contains('-'))
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        if !self.config.enabled {
+            return Ok(Vec::new());
+        }
+
+        let mut warnings = Vec::new();
+
+        for (idx, line_info) in ctx.lines.iter().enumerate() {
+            let Some(heading) = &line_info.heading else {
+                continue;
+            };
+            if heading.style == HeadingStyle::ATX || line_info.blockquote.is_some() {
+                continue;
+            }
+            let Some(underline_info) = ctx.lines.get(idx + 1) else {
+                continue;
+            };
+
+            let text_width = heading.raw_text.width();
+            let underline_width = heading.marker.width();
+            let too_short = underline_width < text_width;
+            let flagged = match self.config.style {
+                UnderlineLengthStyle::AtLeast => too_short,
+                UnderlineLengthStyle::Exact => underline_width != text_width,
+            };
+            if !flagged || text_width == 0 {
+                continue;
+            }
+
+            let message = if too_short {
+                format!("Setext underline is shorter than the heading text ({underline_width} < {text_width})")
+            } else {
+                format!("Setext underline is longer than the heading text ({underline_width} > {text_width})")
+            };
+
+            let underline_char = if heading.style == HeadingStyle::Setext1 {
+                '='
+            } else {
+                '-'
+            };
+            let start = underline_info.byte_offset + heading.marker_column;
+            let underline_line = underline_info.content(ctx.content);
+
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line: idx + 2,
+                column: underline_line[..heading.marker_column].chars().count() + 1,
+                end_line: idx + 2,
+                end_column: underline_line[..heading.marker_column].chars().count() + underline_width + 1,
+                message,
+                severity: Severity::Warning,
+                fix: Some(Fix {
+                    range: start..start + heading.marker.len(),
+                    replacement: underline_char.to_string().repeat(text_width),
+                }),
+            });
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+
+        let mut content = ctx.content.to_string();
+        for fix in warnings.into_iter().rev().filter_map(|w| w.fix) {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+
+        Ok(content)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::FullyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD115Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(rule: &MD115SetextUnderlineLength, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    fn fix(rule: &MD115SetextUnderlineLength, content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let rule = MD115SetextUnderlineLength::default();
+        let content = "Title\n==\n";
+        assert!(check(&rule, content).is_empty());
+        assert_eq!(fix(&rule, content), content);
+    }
+
+    #[test]
+    fn test_short_underline() {
+        let rule = MD115SetextUnderlineLength::new(true, UnderlineLengthStyle::AtLeast);
+        let content = "Title\n==\n\nSection\n-------\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 2);
+        assert_eq!(
+            warnings[0].message,
+            "Setext underline is shorter than the heading text (2 < 5)"
+        );
+        assert_eq!(fix(&rule, content), "Title\n=====\n\nSection\n-------\n");
+    }
+
+    #[test]
+    fn test_long_underline() {
+        let at_least = MD115SetextUnderlineLength::new(true, UnderlineLengthStyle::AtLeast);
+        let content = "Title\n==========\n";
+        assert!(check(&at_least, content).is_empty());
+
+        let exact = MD115SetextUnderlineLength::new(true, UnderlineLengthStyle::Exact);
+        let warnings = check(&exact, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].message,
+            "Setext underline is longer than the heading text (10 > 5)"
+        );
+        assert_eq!(fix(&exact, content), "Title\n=====\n");
+    }
+
+    #[test]
+    fn test_display_width() {
+        let rule = MD115SetextUnderlineLength::new(true, UnderlineLengthStyle::Exact);
+        // Each CJK character takes two columns; combining accents take none
+        assert!(check(&rule, "日本語\n------\n").is_empty());
+        assert!(check(&rule, "Cafe\u{301}\n====\n").is_empty());
+        assert_eq!(fix(&rule, "日本語\n---\n"), "日本語\n------\n");
+    }
+
+    #[test]
+    fn test_indented_underline_and_trailing_space() {
+        let rule = MD115SetextUnderlineLength::new(true, UnderlineLengthStyle::AtLeast);
+        assert_eq!(fix(&rule, "Heading\n  ---  \n"), "Heading\n  -------  \n");
+    }
+
+    #[test]
+    fn test_atx_ignored() {
+        let rule = MD115SetextUnderlineLength::new(true, UnderlineLengthStyle::Exact);
+        assert!(check(&rule, "# Title\n\nText\n").is_empty());
+    }
+}
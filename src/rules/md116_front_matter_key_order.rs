@@ -0,0 +1,337 @@
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use crate::rules::front_matter_utils::{FrontMatterType, FrontMatterUtils};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+use std::sync::LazyLock;
+
+/// Top-level YAML key: `key:` at column 0 (bare or quoted)
+static YAML_KEY: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"^("[^"]+"|'[^']+'|[^\s#:\-\[\]{}"'][^:#]*?)\s*:(\s|$)"#).unwrap());
+
+/// Top-level TOML key: `key =` at column 0 (bare or quoted)
+static TOML_KEY: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"^("[^"]+"|'[^']+'|[A-Za-z0-9_.-]+)\s*="#).unwrap());
+
+/// Configuration for MD116 (Front matter key order)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD116Config {
+    /// Canonical order of top-level front matter keys (empty disables the rule)
+    #[serde(default)]
+    pub order: Vec<String>,
+}
+
+impl RuleConfig for MD116Config {
+    const RULE_NAME: &'static str = "MD116";
+}
+
+/// A top-level front matter entry: the key line plus its nested/continuation lines,
+/// and any comments or blank lines directly above it
+#[derive(Debug)]
+struct Entry {
+    key: String,
+    /// 1-based line number of the key
+    line: usize,
+    range: Range<usize>,
+}
+
+/// Rule MD116: Front matter keys should follow the configured order
+///
+/// See [docs/md116.md](../../docs/md116.md) for full documentation, configuration, and examples.
+///
+/// Works on YAML (`---`) and TOML (`+++`) front matter. Only top-level keys are
+/// compared; nested maps, arrays and multi-line values move with their key unchanged.
+/// Keys that are not in the configured order are left where they are.
+#[derive(Debug, Clone, Default)]
+pub struct MD116FrontMatterKeyOrder {
+    config: MD116Config,
+}
+
+impl MD116FrontMatterKeyOrder {
+    pub fn new(order: Vec<String>) -> Self {
+        Self::from_config_struct(MD116Config { order })
+    }
+
+    pub fn from_config_struct(config: MD116Config) -> Self {
+        Self { config }
+    }
+
+    fn rank(&self, key: &str) -> Option<usize> {
+        self.config.order.iter().position(|k| k == key)
+    }
+
+    /// Split the front matter body into top-level entries.
+    ///
+    /// Returns the entries and the byte range of the body they cover.
+    fn parse_entries(ctx: &LintContext) -> Option<(Vec<Entry>, Range<usize>)> {
+        let is_toml = match FrontMatterUtils::detect_front_matter_type(ctx.content) {
+            FrontMatterType::Yaml => false,
+            FrontMatterType::Toml => true,
+            _ => return None,
+        };
+        let key_pattern = if is_toml { &*TOML_KEY } else { &*YAML_KEY };
+        // 1-based line of the closing delimiter
+        let closing_line = FrontMatterUtils::get_front_matter_end_line(ctx.content);
+        if closing_line < 3 {
+            return None;
+        }
+
+        let body_start = ctx.lines.get(1)?.byte_offset;
+        let mut body_end = ctx.lines.get(closing_line - 1)?.byte_offset;
+        let mut entries: Vec<Entry> = Vec::new();
+        // Start of comments/blank lines waiting to attach to the next key
+        let mut pending_start: Option<usize> = None;
+
+        for (idx, line_info) in ctx.lines.iter().enumerate().take(closing_line - 1).skip(1) {
+            let line = line_info.content(ctx.content);
+            let offset = line_info.byte_offset;
+
+            // Keys after a TOML table header belong to that table, so stop there
+            if is_toml && line.starts_with('[') {
+                body_end = pending_start.unwrap_or(offset);
+                break;
+            }
+
+            if line.trim().is_empty() || line.starts_with('#') {
+                pending_start.get_or_insert(offset);
+                continue;
+            }
+
+            if let Some(caps) = key_pattern.captures(line) {
+                let key = caps[1].trim_matches(|c| c == '"' || c == '\'').to_string();
+                let start = pending_start.take().unwrap_or(offset);
+                if let Some(last) = entries.last_mut() {
+                    last.range.end = start;
+                }
+                entries.push(Entry {
+                    key,
+                    line: idx + 1,
+                    range: start..start,
+                });
+            } else if entries.is_empty() {
+                // Not a key/value mapping we understand
+                return None;
+            } else {
+                // Indented or continuation lines (nested maps, arrays, block scalars)
+                pending_start = None;
+            }
+        }
+
+        // Trailing comments after the last entry stay at the end of the block
+        if let Some(start) = pending_start {
+            body_end = body_end.min(start);
+        }
+        if let Some(last) = entries.last_mut() {
+            last.range.end = body_end;
+        }
+
+        Some((entries, body_start..body_end))
+    }
+
+    /// Entries reordered so listed keys follow the configured order, with unlisted keys
+    /// keeping their slots
+    fn sorted<'a>(&self, entries: &'a [Entry]) -> Vec<&'a Entry> {
+        let mut listed: Vec<&Entry> = entries.iter().filter(|e| self.rank(&e.key).is_some()).collect();
+        listed.sort_by_key(|e| self.rank(&e.key));
+        let mut listed = listed.into_iter();
+
+        entries
+            .iter()
+            .map(|e| {
+                if self.rank(&e.key).is_some() {
+                    listed.next().unwrap()
+                } else {
+                    e
+                }
+            })
+            .collect()
+    }
+}
+
+impl Rule for MD116FrontMatterKeyOrder {
+    fn name(&self) -> &'static str {
+        "MD116"
+    }
+
+    fn description(&self) -> &'static str {
+        "Front matter keys should follow the configured order"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Other
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        self.config.order.is_empty() || !ctx.lines.first().is_some_and(|l| l.in_front_matter)
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        if self.should_skip(ctx) {
+            return Ok(Vec::new());
+        }
+        let Some((entries, body)) = Self::parse_entries(ctx) else {
+            return Ok(Vec::new());
+        };
+
+        // The first listed key that comes after a key it should precede
+        let mut latest: Option<(usize, &Entry)> = None;
+        let mut misplaced = None;
+        for entry in &entries {
+            let Some(rank) = self.rank(&entry.key) else {
+                continue;
+            };
+            match latest {
+                Some((latest_rank, before)) if rank < latest_rank => {
+                    misplaced = Some((entry, before));
+                    break;
+                }
+                _ => latest = Some((rank, entry)),
+            }
+        }
+        let Some((entry, before)) = misplaced else {
+            return Ok(Vec::new());
+        };
+
+        let mut replacement = String::with_capacity(body.len());
+        for sorted in self.sorted(&entries) {
+            replacement.push_str(&ctx.content[sorted.range.clone()]);
+        }
+
+        let key_line = ctx.lines[entry.line - 1].content(ctx.content);
+        Ok(vec![LintWarning {
+            rule_name: Some(self.name().to_string()),
+            line: entry.line,
+            column: 1,
+            end_line: entry.line,
+            end_column: key_line.chars().count() + 1,
+            message: format!("Front matter key '{}' should come before '{}'", entry.key, before.key),
+            severity: Severity::Warning,
+            fix: Some(Fix {
+                range: body,
+                replacement,
+            }),
+        }])
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+
+        let mut content = ctx.content.to_string();
+        for fix in warnings.into_iter().rev().filter_map(|w| w.fix) {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+
+        Ok(content)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::FullyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD116Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn rule() -> MD116FrontMatterKeyOrder {
+        MD116FrontMatterKeyOrder::new(
+            ["title", "description", "date", "tags"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+        )
+    }
+
+    fn check(content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule().check(&ctx).unwrap()
+    }
+
+    fn fix(content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule().fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_ordered_keys() {
+        let content = "---\ntitle: Post\nauthor: Me\ndate: 2024-01-01\ntags: [a]\n---\n\n# Post\n";
+        assert!(check(content).is_empty());
+    }
+
+    #[test]
+    fn test_yaml_out_of_order() {
+        let content = "---\ndate: 2024-01-01\ntitle: Post\ndescription: About\n---\n\n# Post\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 3);
+        assert_eq!(
+            warnings[0].message,
+            "Front matter key 'title' should come before 'date'"
+        );
+        assert_eq!(
+            fix(content),
+            "---\ntitle: Post\ndescription: About\ndate: 2024-01-01\n---\n\n# Post\n"
+        );
+    }
+
+    #[test]
+    fn test_nested_values_and_comments_move_with_key() {
+        let content =
+            "---\ntags:\n  - rust\n  - lint\n# Shown in listings\ntitle: Post\nextra:\n  title: Nested\n---\n";
+        assert_eq!(
+            fix(content),
+            "---\n# Shown in listings\ntitle: Post\ntags:\n  - rust\n  - lint\nextra:\n  title: Nested\n---\n"
+        );
+    }
+
+    #[test]
+    fn test_unlisted_keys_keep_their_place() {
+        let content = "---\nlayout: post\ndate: 2024-01-01\nslug: post\ntitle: Post\n---\n";
+        assert_eq!(
+            fix(content),
+            "---\nlayout: post\ntitle: Post\nslug: post\ndate: 2024-01-01\n---\n"
+        );
+    }
+
+    #[test]
+    fn test_toml_front_matter() {
+        let content = "+++\ntags = [\n  \"a\",\n]\ntitle = \"Post\"\n\n[extra]\ndate = 1\n+++\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            fix(content),
+            "+++\ntitle = \"Post\"\ntags = [\n  \"a\",\n]\n\n[extra]\ndate = 1\n+++\n"
+        );
+    }
+
+    #[test]
+    fn test_disabled_without_order() {
+        let content = "---\ndate: 2024-01-01\ntitle: Post\n---\n";
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        assert!(MD116FrontMatterKeyOrder::default().check(&ctx).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,277 @@
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::utils::skip_context::is_in_math_context;
+use std::ops::Range;
+
+/// Rule MD117: Emphasis and strong markers should not be empty
+///
+/// See [docs/md117.md](../../docs/md117.md) for full documentation, configuration, and examples.
+///
+/// Flags marker pairs with nothing (or only whitespace) between them, usually left
+/// behind when the emphasized text was deleted:
+/// - `** **`, `__ __`, `* *`, `_ _`, `*** ***` (whitespace-only content)
+/// - `****` (empty strong)
+///
+/// `**` and `__` on their own are too ambiguous to flag (`__init__`, globs, blanks),
+/// and thematic breaks such as `***` or `* * *` are not emphasis at all.
+#[derive(Debug, Clone, Default)]
+pub struct MD117EmptyEmphasis;
+
+impl MD117EmptyEmphasis {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Byte ranges (within `line`) of runs of one marker character, starting at `from`
+    fn marker_runs(line: &str, from: usize) -> Vec<Range<usize>> {
+        let bytes = line.as_bytes();
+        let mut runs = Vec::new();
+        let mut i = from;
+        while i < bytes.len() {
+            let c = bytes[i];
+            if c != b'*' && c != b'_' {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            while i < bytes.len() && bytes[i] == c {
+                i += 1;
+            }
+            // Escaped markers are literal text
+            if start == 0 || bytes[start - 1] != b'\\' {
+                runs.push(start..i);
+            }
+        }
+        runs
+    }
+
+    /// Find empty marker pairs in a line, as byte ranges within the line
+    fn find_empty(line: &str, from: usize) -> Vec<Range<usize>> {
+        let runs = Self::marker_runs(line, from);
+        let mut found = Vec::new();
+        let mut i = 0;
+        while i < runs.len() {
+            let run = &runs[i];
+            let marker = line.as_bytes()[run.start];
+
+            // `****`: an empty strong span
+            if marker == b'*' && run.len() == 4 {
+                found.push(run.clone());
+                i += 1;
+                continue;
+            }
+
+            // `** **` and friends: the same run on both sides of whitespace
+            if let Some(next) = runs.get(i + 1)
+                && run.len() <= 3
+                && next.len() == run.len()
+                && line.as_bytes()[next.start] == marker
+                && next.start > run.end
+                && line[run.end..next.start].chars().all(|c| c == ' ' || c == '\t')
+            {
+                found.push(run.start..next.end);
+                i += 2;
+                continue;
+            }
+
+            i += 1;
+        }
+        found
+    }
+
+    /// Widen a removal so it doesn't leave a doubled space behind
+    fn removal_range(line: &str, range: Range<usize>, content_start: usize) -> Range<usize> {
+        let before = line[..range.start].chars().next_back();
+        let after = line[range.end..].chars().next();
+        let is_space = |c: Option<char>| matches!(c, Some(' ' | '\t'));
+
+        if is_space(before) && (is_space(after) || after.is_none()) {
+            range.start - 1..range.end
+        } else if range.start == content_start && is_space(after) {
+            range.start..range.end + 1
+        } else {
+            range
+        }
+    }
+
+    fn is_horizontal_rule(trimmed: &str) -> bool {
+        let mut chars = trimmed.chars().filter(|c| !c.is_whitespace());
+        let Some(first) = chars.next() else {
+            return false;
+        };
+        matches!(first, '*' | '_' | '-')
+            && trimmed.chars().filter(|&c| c == first).count() >= 3
+            && chars.all(|c| c == first)
+    }
+}
+
+impl Rule for MD117EmptyEmphasis {
+    fn name(&self) -> &'static str {
+        "MD117"
+    }
+
+    fn description(&self) -> &'static str {
+        "Emphasis and strong markers should not be empty"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Emphasis
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !ctx.likely_has_emphasis()
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        // Real emphasis spans, so `*a* *b*` isn't read as an empty `* *`
+        let spans = ctx.emphasis_spans();
+
+        for (idx, line_info) in ctx.lines.iter().enumerate() {
+            if line_info.in_code_block
+                || line_info.in_front_matter
+                || line_info.in_html_block
+                || line_info.in_html_comment
+                || line_info.in_mkdocstrings
+            {
+                continue;
+            }
+
+            let line = line_info.content(ctx.content);
+            if !line.contains(['*', '_']) || Self::is_horizontal_rule(line.trim()) {
+                continue;
+            }
+
+            // Start after a list marker so `* *text*` isn't mistaken for `* *`
+            let content_start = line_info
+                .list_item
+                .as_ref()
+                .map_or(line_info.indent, |item| item.content_column)
+                .min(line.len());
+            if !line.is_char_boundary(content_start) {
+                continue;
+            }
+
+            for range in Self::find_empty(line, content_start) {
+                let start = line_info.byte_offset + range.start;
+                let end = line_info.byte_offset + range.end;
+                if spans.iter().any(|s| s.byte_offset < end && start < s.byte_end)
+                    || ctx.is_in_code_block_or_span(start)
+                    || ctx.is_in_html_comment(start)
+                    || is_in_math_context(ctx, start)
+                    || ctx.is_in_jinja_range(start)
+                {
+                    continue;
+                }
+
+                let markers = &line[range.clone()];
+                let kind = match markers.chars().take_while(|&c| c == '*' || c == '_').count() {
+                    4 | 2 => "strong",
+                    3 => "strong emphasis",
+                    _ => "emphasis",
+                };
+                let removal = Self::removal_range(line, range.clone(), content_start);
+
+                warnings.push(LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line: idx + 1,
+                    column: line[..range.start].chars().count() + 1,
+                    end_line: idx + 1,
+                    end_column: line[..range.end].chars().count() + 1,
+                    message: format!("Empty {kind} markers: {markers}"),
+                    severity: Severity::Warning,
+                    fix: Some(Fix {
+                        range: line_info.byte_offset + removal.start..line_info.byte_offset + removal.end,
+                        replacement: String::new(),
+                    }),
+                });
+            }
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+
+        let mut content = ctx.content.to_string();
+        for fix in warnings.into_iter().rev().filter_map(|w| w.fix) {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+
+        Ok(content)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::FullyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn from_config(_config: &crate::config::Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        Box::new(MD117EmptyEmphasis)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD117EmptyEmphasis.check(&ctx).unwrap()
+    }
+
+    fn fix(content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD117EmptyEmphasis.fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_empty_strong() {
+        let content = "Some **** text and __ __ more.\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].column, 6);
+        assert_eq!(warnings[0].message, "Empty strong markers: ****");
+        assert_eq!(warnings[1].message, "Empty strong markers: __ __");
+        assert_eq!(fix(content), "Some text and more.\n");
+    }
+
+    #[test]
+    fn test_whitespace_emphasis() {
+        let content = "Start * * end, *** *** and _ _.\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 3);
+        assert_eq!(warnings[1].message, "Empty strong emphasis markers: *** ***");
+        assert_eq!(fix(content), "Start end, and .\n");
+    }
+
+    #[test]
+    fn test_line_start() {
+        assert_eq!(fix("** ** Leftover\n"), "Leftover\n");
+    }
+
+    #[test]
+    fn test_horizontal_rules_not_flagged() {
+        let content = "Text\n\n***\n\n* * *\n\n****\n\n_ _ _\n";
+        assert!(check(content).is_empty());
+    }
+
+    #[test]
+    fn test_real_emphasis_not_flagged() {
+        let content = "*a* *b* and **c** **d**, __init__ __main__, `** **`, \\** **\n\n* *item*\n";
+        assert!(check(content).is_empty());
+    }
+
+    #[test]
+    fn test_code_block_skipped() {
+        assert!(check("```\n** **\n```\n").is_empty());
+    }
+}
@@ -0,0 +1,372 @@
+use crate::config::Config;
+use crate::lint_context::{LintContext, ListItemInfo};
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Configuration for MD118 (fenced code blocks in list items)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD118Config {
+    /// Whether the rule is enabled (opt-in, since the fix moves blocks into or out of lists)
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+impl RuleConfig for MD118Config {
+    const RULE_NAME: &'static str = "MD118";
+}
+
+/// A fenced code block and the list item it belongs to
+#[derive(Debug)]
+struct ListFence {
+    /// 0-based line indices of the opening and closing fences
+    open: usize,
+    close: usize,
+    /// Indentation of the opening fence
+    indent: usize,
+    /// Content column of the owning list item
+    expected: usize,
+}
+
+/// Rule MD118: Fenced code blocks in list items should align with the item content
+///
+/// See [docs/md118.md](../../docs/md118.md) for full documentation, configuration, and examples.
+///
+/// A fence indented less than the item's content column ends the list, restarting
+/// numbering for ordered lists. Fences are compared against the nearest enclosing item
+/// from `list_blocks`, walking up through nested items; a fence at or left of the marker
+/// only counts as part of the list when the same ordered list continues right after it.
+/// Fences indented further sit inside the item content and are left alone, since
+/// CommonMark strips the opening fence's indentation from the code lines.
+#[derive(Debug, Clone, Default)]
+pub struct MD118ListFenceIndent {
+    config: MD118Config,
+}
+
+impl MD118ListFenceIndent {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            config: MD118Config { enabled },
+        }
+    }
+
+    pub fn from_config_struct(config: MD118Config) -> Self {
+        Self { config }
+    }
+
+    /// The fence character run opening a fenced code block, if `trimmed` starts one
+    fn fence_marker(trimmed: &str) -> Option<&str> {
+        let c = trimmed.chars().next().filter(|c| *c == '`' || *c == '~')?;
+        let len = trimmed.chars().take_while(|&x| x == c).count();
+        (len >= 3).then(|| &trimmed[..len])
+    }
+
+    /// Whether `next` continues the ordered list that `prev` belongs to
+    ///
+    /// Bullet lists carry no numbering, so a fence between two of them is taken as a
+    /// separate block rather than a gap in one list.
+    fn continues_list(prev: &ListItemInfo, next: &ListItemInfo) -> bool {
+        prev.is_ordered
+            && next.is_ordered
+            && prev.marker.chars().last() == next.marker.chars().last()
+            && prev.number.zip(next.number).is_some_and(|(p, n)| n == p + 1)
+    }
+
+    /// Find the content column of the list item owning a fence at `open` indented `indent`
+    fn owning_item(
+        ctx: &LintContext,
+        items: &HashSet<usize>,
+        open: usize,
+        close: usize,
+        indent: usize,
+    ) -> Option<usize> {
+        // Items passed on the way up
+        let mut seen: Vec<&ListItemInfo> = Vec::new();
+
+        for idx in (0..open).rev() {
+            let info = &ctx.lines[idx];
+            if info.is_blank || info.in_code_block {
+                continue;
+            }
+            if items.contains(&idx) {
+                let item = info.list_item.as_ref()?;
+                if indent > item.marker_column {
+                    return Some(item.content_column);
+                }
+                seen.push(item);
+                if item.marker_column == 0 {
+                    break;
+                }
+                continue;
+            }
+            // Text at column 0 is outside any list; indented text continues an item
+            if info.indent == 0 || info.heading.is_some() {
+                break;
+            }
+        }
+
+        // At or left of the marker: only part of the list if the list resumes afterwards
+        let next = (close + 1..ctx.lines.len()).find(|&idx| !ctx.lines[idx].is_blank)?;
+        if !items.contains(&next) {
+            return None;
+        }
+        let next_item = ctx.lines[next].list_item.as_ref()?;
+        seen.iter()
+            .find(|item| item.marker_column == next_item.marker_column)
+            .filter(|item| Self::continues_list(item, next_item))
+            .map(|item| item.content_column)
+    }
+
+    fn find_fences(ctx: &LintContext) -> Vec<ListFence> {
+        let items: HashSet<usize> = ctx
+            .list_blocks()
+            .iter()
+            .filter(|block| block.blockquote_prefix.is_empty())
+            .flat_map(|block| block.item_lines.iter().map(|line| line - 1))
+            .collect();
+        if items.is_empty() {
+            return Vec::new();
+        }
+
+        let mut fences = Vec::new();
+        for &(start, end) in &ctx.code_blocks {
+            let (open_line, _) = ctx.offset_to_line_col(start);
+            let (close_line, _) = ctx.offset_to_line_col(end.saturating_sub(1).max(start));
+            let (open, close) = (open_line - 1, close_line - 1);
+            if close <= open || items.contains(&open) {
+                continue;
+            }
+
+            let open_info = &ctx.lines[open];
+            let open_text = open_info.content(ctx.content);
+            let Some(marker) = Self::fence_marker(open_text.trim_start()) else {
+                continue;
+            };
+            let close_text = ctx.lines[close].content(ctx.content).trim();
+            let is_closed = close_text.starts_with(marker) && close_text.chars().all(|c| marker.starts_with(c));
+
+            // Tabs make the target column ambiguous, and blockquotes shift every column
+            let block = &ctx.lines[open..=close];
+            if !is_closed
+                || block.iter().any(|l| l.blockquote.is_some())
+                || block.iter().any(|l| {
+                    let text = l.content(ctx.content);
+                    text[..text.len() - text.trim_start().len()].contains('\t')
+                })
+            {
+                continue;
+            }
+
+            let indent = open_info.indent;
+            let Some(expected) = Self::owning_item(ctx, &items, open, close, indent) else {
+                continue;
+            };
+            // Fences indented past the content column are inside the item already
+            if indent < expected {
+                fences.push(ListFence {
+                    open,
+                    close,
+                    indent,
+                    expected,
+                });
+            }
+        }
+        fences
+    }
+
+    /// Indent every line of the fenced block by the same amount
+    fn reindent(ctx: &LintContext, fence: &ListFence) -> String {
+        let mut out = String::new();
+        for (i, info) in ctx.lines[fence.open..=fence.close].iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            let line = info.content(ctx.content);
+            if !line.trim().is_empty() {
+                out.push_str(&" ".repeat(fence.expected - fence.indent));
+            }
+            out.push_str(line);
+        }
+        out
+    }
+}
+
+impl Rule for MD118ListFenceIndent {
+    fn name(&self) -> &'static str {
+        "MD118"
+    }
+
+    fn description(&self) -> &'static str {
+        "Fenced code blocks in list items should align with the item content"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::List
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !self.config.enabled || !ctx.likely_has_lists() || !ctx.likely_has_code() || ctx.list_blocks().is_empty()
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        if !self.config.enabled {
+            return Ok(Vec::new());
+        }
+
+        let mut warnings = Vec::new();
+
+        for fence in Self::find_fences(ctx) {
+            let open_info = &ctx.lines[fence.open];
+            let close_info = &ctx.lines[fence.close];
+
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line: fence.open + 1,
+                column: fence.indent + 1,
+                end_line: fence.open + 1,
+                end_column: open_info.content(ctx.content).chars().count() + 1,
+                message: format!(
+                    "Code fence in list item is under-indented ({} spaces, expected {})",
+                    fence.indent, fence.expected
+                ),
+                severity: Severity::Warning,
+                fix: Some(Fix {
+                    range: open_info.byte_offset..close_info.byte_offset + close_info.content(ctx.content).len(),
+                    replacement: Self::reindent(ctx, &fence),
+                }),
+            });
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+
+        let mut content = ctx.content.to_string();
+        for fix in warnings.into_iter().rev().filter_map(|w| w.fix) {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+
+        Ok(content)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::FullyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD118Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD118ListFenceIndent::new(true).check(&ctx).unwrap()
+    }
+
+    fn fix(content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD118ListFenceIndent::new(true).fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_aligned_fence() {
+        let content = "1. Step one\n\n   ```bash\n   echo hi\n   ```\n\n2. Step two\n";
+        assert!(check(content).is_empty());
+    }
+
+    #[test]
+    fn test_under_indented_fence() {
+        let content = "1. Step one\n\n ```bash\n echo hi\n   indented\n ```\n\n2. Step two\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 3);
+        assert_eq!(
+            warnings[0].message,
+            "Code fence in list item is under-indented (1 spaces, expected 3)"
+        );
+        assert_eq!(
+            fix(content),
+            "1. Step one\n\n   ```bash\n   echo hi\n     indented\n   ```\n\n2. Step two\n"
+        );
+    }
+
+    #[test]
+    fn test_over_indented_fence_not_flagged() {
+        // The opening fence's indentation is stripped from the code, so nothing leaks in
+        let content = "- Item\n\n    ```\n    code\n    ```\n";
+        assert!(check(content).is_empty());
+    }
+
+    #[test]
+    fn test_fence_between_items_at_margin() {
+        let content = "1. Step one\n\n```bash\necho hi\n```\n\n2. Step two\n";
+        assert_eq!(
+            fix(content),
+            "1. Step one\n\n   ```bash\n   echo hi\n   ```\n\n2. Step two\n"
+        );
+    }
+
+    #[test]
+    fn test_fence_after_list_not_flagged() {
+        let content = "- Item\n\n```\ncode\n```\n\nParagraph\n";
+        assert!(check(content).is_empty());
+        assert!(check("# Title\n\n```\ncode\n```\n").is_empty());
+    }
+
+    #[test]
+    fn test_nested_items() {
+        // Aligned with the nested item, then with the parent after the nested list ends
+        let content = "- Parent\n  - Child\n\n    ```\n    child code\n    ```\n\n  ```\n  parent code\n  ```\n";
+        assert!(check(content).is_empty());
+
+        let content = "- Parent\n  - Child\n\n   ```\n   code\n   ```\n";
+        assert_eq!(fix(content), "- Parent\n  - Child\n\n    ```\n    code\n    ```\n");
+    }
+
+    #[test]
+    fn test_code_block_between_two_different_lists() {
+        // Ordered list followed by a bullet list
+        assert!(check("1. a\n2. b\n\n```bash\ncode\n```\n\n- item\n").is_empty());
+        // Two bullet lists
+        assert!(check("- a\n- b\n\n```bash\n```\n\n- c\n").is_empty());
+        // Different delimiter
+        assert!(check("1. a\n\n```\ncode\n```\n\n2) b\n").is_empty());
+        // Numbering restarts
+        assert!(check("1. a\n2. b\n\n```\ncode\n```\n\n1. c\n").is_empty());
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let content = "1. Step one\n\n ```bash\n echo hi\n ```\n\n2. Step two\n";
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        assert!(MD118ListFenceIndent::default().check(&ctx).unwrap().is_empty());
+
+        let rule = MD118ListFenceIndent::from_config(&crate::config::Config::default());
+        assert!(rule.check(&ctx).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,284 @@
+use crate::config::{Config, MarkdownFlavor};
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use crate::utils::code_block_utils::CodeBlockUtils;
+use crate::utils::{mkdocs_admonitions, mkdocs_tabs};
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD119 (Indented code blocks should be fenced)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD119Config {
+    /// Whether the rule is enabled (opt-in, like MD110)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Language added to the opening fence by the fix (empty leaves it bare)
+    #[serde(default, alias = "default_language")]
+    pub default_language: String,
+}
+
+impl RuleConfig for MD119Config {
+    const RULE_NAME: &'static str = "MD119";
+}
+
+/// Rule MD119: Code blocks should be fenced
+///
+/// See [docs/md119.md](../../docs/md119.md) for full documentation, configuration, and examples.
+///
+/// A stricter companion to MD040: indented code blocks can't carry a language, so they
+/// are flagged and converted to fenced blocks. Detection uses the CommonMark parser, so
+/// indented list continuation text is never mistaken for code. MkDocs admonition and tab
+/// content, which is indented by design, is skipped.
+#[derive(Debug, Clone, Default)]
+pub struct MD119FencedCodeBlocks {
+    config: MD119Config,
+}
+
+impl MD119FencedCodeBlocks {
+    pub fn new(enabled: bool, default_language: &str) -> Self {
+        Self {
+            config: MD119Config {
+                enabled,
+                default_language: default_language.to_string(),
+            },
+        }
+    }
+
+    pub fn from_config_struct(config: MD119Config) -> Self {
+        Self { config }
+    }
+
+    /// Visual column of byte `pos` within `line`, expanding tabs to multiples of four
+    fn column_of(line: &str, pos: usize) -> usize {
+        line[..pos]
+            .chars()
+            .fold(0, |col, c| if c == '\t' { (col / 4 + 1) * 4 } else { col + 1 })
+    }
+
+    /// Remove the four columns of code indentation from a line, after `base` columns of
+    /// container indentation
+    fn strip_code_indent(line: &str, base: usize) -> String {
+        let mut col = 0;
+        for (i, c) in line.char_indices() {
+            if col >= base + 4 {
+                return format!("{}{}", " ".repeat(col - 4), &line[i..]);
+            }
+            match c {
+                ' ' => col += 1,
+                '\t' => col = (col / 4 + 1) * 4,
+                _ => return format!("{}{}", " ".repeat(col.min(base)), &line[i..]),
+            }
+        }
+        String::new()
+    }
+
+    /// Opening/closing fence long enough not to clash with backtick runs in the code
+    fn fence_for(code: &[String]) -> String {
+        let longest = code
+            .iter()
+            .map(|line| line.trim_start().chars().take_while(|&c| c == '`').count())
+            .max()
+            .unwrap_or(0);
+        "`".repeat(longest.max(2) + 1)
+    }
+}
+
+impl Rule for MD119FencedCodeBlocks {
+    fn name(&self) -> &'static str {
+        "MD119"
+    }
+
+    fn description(&self) -> &'static str {
+        "Code blocks should be fenced"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::CodeBlock
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !self.config.enabled || (!ctx.content.contains("    ") && !ctx.content.contains('\t'))
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        if self.should_skip(ctx) {
+            return Ok(warnings);
+        }
+
+        for (start, end) in CodeBlockUtils::detect_indented_code_blocks(ctx.content) {
+            if ctx.is_in_front_matter(start)
+                || (ctx.flavor == MarkdownFlavor::MkDocs
+                    && (mkdocs_admonitions::is_within_admonition(ctx.content, start)
+                        || mkdocs_tabs::is_within_tab_content(ctx.content, start)))
+            {
+                continue;
+            }
+
+            let (first_line, _) = ctx.offset_to_line_col(start);
+            let (last_line, _) = ctx.offset_to_line_col(end.saturating_sub(1).max(start));
+            let block = &ctx.lines[first_line - 1..last_line];
+            // Blockquote prefixes would have to be rebuilt on every line
+            if block.iter().any(|l| l.blockquote.is_some() || l.in_mkdocstrings) {
+                continue;
+            }
+
+            let first = &block[0];
+            let first_text = first.content(ctx.content);
+            let base = Self::column_of(first_text, start - first.byte_offset).saturating_sub(4);
+
+            let code: Vec<String> = block
+                .iter()
+                .map(|l| Self::strip_code_indent(l.content(ctx.content), base))
+                .collect();
+            let fence = Self::fence_for(&code);
+            let indent = " ".repeat(base);
+
+            let mut replacement = format!("{indent}{fence}{}\n", self.config.default_language);
+            for line in &code {
+                replacement.push_str(line);
+                replacement.push('\n');
+            }
+            replacement.push_str(&indent);
+            replacement.push_str(&fence);
+
+            let last = &block[block.len() - 1];
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line: first_line,
+                column: base + 1,
+                end_line: last_line,
+                end_column: last.content(ctx.content).chars().count() + 1,
+                message: "Indented code block should be fenced".to_string(),
+                severity: Severity::Warning,
+                fix: Some(Fix {
+                    range: first.byte_offset..last.byte_offset + last.byte_len,
+                    replacement,
+                }),
+            });
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+
+        let mut content = ctx.content.to_string();
+        for fix in warnings.into_iter().rev().filter_map(|w| w.fix) {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+
+        Ok(content)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::FullyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD119Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(rule: &MD119FencedCodeBlocks, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    fn fix(rule: &MD119FencedCodeBlocks, content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_indented_block_fenced() {
+        let rule = MD119FencedCodeBlocks::new(true, "");
+        let content = "Text\n\n    fn main() {\n        run();\n\n    }\n\nMore\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 3);
+        assert_eq!(warnings[0].end_line, 6);
+        assert_eq!(
+            fix(&rule, content),
+            "Text\n\n```\nfn main() {\n    run();\n\n}\n```\n\nMore\n"
+        );
+    }
+
+    #[test]
+    fn test_default_language_and_tabs() {
+        let rule = MD119FencedCodeBlocks::new(true, "bash");
+        assert_eq!(
+            fix(&rule, "Run:\n\n\tmake\n\t\tall\n"),
+            "Run:\n\n```bash\nmake\n\tall\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_fenced_blocks_ignored() {
+        let rule = MD119FencedCodeBlocks::new(true, "");
+        assert!(check(&rule, "```rust\n    indented inside fence\n```\n").is_empty());
+    }
+
+    #[test]
+    fn test_list_continuation_not_code() {
+        let rule = MD119FencedCodeBlocks::new(true, "");
+        let content = "1. Step\n\n   Continuation text\n\n    - nested\n      item text\n";
+        assert!(check(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_code_in_list_item() {
+        let rule = MD119FencedCodeBlocks::new(true, "");
+        let content = "- Item\n\n      make install\n\n  After\n";
+        assert_eq!(
+            fix(&rule, content),
+            "- Item\n\n  ```\n  make install\n  ```\n\n  After\n"
+        );
+    }
+
+    #[test]
+    fn test_backticks_in_code() {
+        let rule = MD119FencedCodeBlocks::new(true, "");
+        assert_eq!(
+            fix(&rule, "Example:\n\n    ```js\n    x\n    ```\n"),
+            "Example:\n\n````\n```js\nx\n```\n````\n"
+        );
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let rule = MD119FencedCodeBlocks::default();
+        assert!(check(&rule, "Text\n\n    code\n").is_empty());
+    }
+
+    #[test]
+    fn test_mkdocs_admonition_skipped() {
+        let rule = MD119FencedCodeBlocks::new(true, "");
+        let content = "!!! note\n\n    Admonition body\n";
+        let ctx = LintContext::new(content, MarkdownFlavor::MkDocs, None);
+        assert!(rule.check(&ctx).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,237 @@
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Configuration for MD120 (Emphasis labels should not repeat the heading below them)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD120Config {
+    /// Whether the rule is enabled (opt-in, like MD110)
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+impl RuleConfig for MD120Config {
+    const RULE_NAME: &'static str = "MD120";
+}
+
+/// Rule MD120: Emphasis labels should not repeat the heading below them
+///
+/// See [docs/md120.md](../../docs/md120.md) for full documentation, configuration, and examples.
+///
+/// Document converters often keep a bold "label" paragraph above the heading that
+/// replaced it. A line made only of emphasis is reported when the next non-blank line is
+/// a heading and the words of one are contained in the other, so emphasis that merely
+/// happens to precede an unrelated heading is left alone.
+#[derive(Debug, Clone, Default)]
+pub struct MD120RedundantHeadingLabel {
+    config: MD120Config,
+}
+
+impl MD120RedundantHeadingLabel {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            config: MD120Config { enabled },
+        }
+    }
+
+    pub fn from_config_struct(config: MD120Config) -> Self {
+        Self { config }
+    }
+
+    /// The text of a line that consists of a single emphasis span (an optional trailing
+    /// colon is allowed outside the markers)
+    fn label_text(ctx: &LintContext, line_num: usize) -> Option<String> {
+        let info = &ctx.lines[line_num - 1];
+        let line = info.content(ctx.content);
+        let trimmed = line.trim_end();
+        let trimmed = trimmed.strip_suffix(':').unwrap_or(trimmed);
+
+        let spans = ctx.emphasis_spans_on_line(line_num);
+        let [span] = spans.as_slice() else {
+            return None;
+        };
+        (span.start_col == info.indent && span.end_col == trimmed.len()).then(|| span.content.clone())
+    }
+
+    /// Lowercase alphanumeric words of a text
+    fn words(text: &str) -> HashSet<String> {
+        text.split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(str::to_lowercase)
+            .collect()
+    }
+
+    /// Whether one text's words are all contained in the other's
+    fn is_related(label: &str, heading: &str) -> bool {
+        let label = Self::words(label);
+        let heading = Self::words(heading);
+        !label.is_empty() && !heading.is_empty() && (label.is_subset(&heading) || heading.is_subset(&label))
+    }
+}
+
+impl Rule for MD120RedundantHeadingLabel {
+    fn name(&self) -> &'static str {
+        "MD120"
+    }
+
+    fn description(&self) -> &'static str {
+        "Emphasis labels should not repeat the heading below them"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Heading
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !self.config.enabled || !ctx.likely_has_headings() || (!ctx.content.contains('*') && !ctx.content.contains('_'))
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        if self.should_skip(ctx) {
+            return Ok(warnings);
+        }
+
+        for (idx, info) in ctx.lines.iter().enumerate() {
+            if info.is_blank
+                || info.in_code_block
+                || info.in_front_matter
+                || info.in_html_block
+                || info.in_html_comment
+                || info.heading.is_some()
+                || info.list_item.is_some()
+                || info.blockquote.is_some()
+            {
+                continue;
+            }
+            // A label ends its paragraph: the previous line must not continue into it
+            if idx > 0 && !ctx.lines[idx - 1].is_blank && ctx.lines[idx - 1].heading.is_none() {
+                continue;
+            }
+            let Some(label) = Self::label_text(ctx, idx + 1) else {
+                continue;
+            };
+
+            let Some(next) = (idx + 1..ctx.lines.len()).find(|&i| !ctx.lines[i].is_blank) else {
+                continue;
+            };
+            let Some(heading) = &ctx.lines[next].heading else {
+                continue;
+            };
+            if !Self::is_related(&label, &heading.text) {
+                continue;
+            }
+
+            let line = info.content(ctx.content);
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line: idx + 1,
+                column: info.indent + 1,
+                end_line: idx + 1,
+                end_column: line.trim_end().chars().count() + 1,
+                message: format!("Emphasis label '{label}' repeats the heading below it"),
+                severity: Severity::Warning,
+                fix: Some(Fix {
+                    range: info.byte_offset..ctx.lines[next].byte_offset,
+                    replacement: String::new(),
+                }),
+            });
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+
+        let mut content = ctx.content.to_string();
+        for fix in warnings.into_iter().rev().filter_map(|w| w.fix) {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+
+        Ok(content)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::FullyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD120Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD120RedundantHeadingLabel::new(true).check(&ctx).unwrap()
+    }
+
+    fn fix(content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD120RedundantHeadingLabel::new(true).fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_label_repeating_heading() {
+        let content = "# Guide\n\n**Installation**\n\n## Installation\n\nRun it.\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 3);
+        assert_eq!(
+            warnings[0].message,
+            "Emphasis label 'Installation' repeats the heading below it"
+        );
+        assert_eq!(fix(content), "# Guide\n\n## Installation\n\nRun it.\n");
+    }
+
+    #[test]
+    fn test_related_text_variants() {
+        // Italic, trailing colon, case and partial overlap all count as related
+        assert_eq!(check("*Setup:*\n## Project setup\n").len(), 1);
+        assert_eq!(check("__Getting Started__:\n\n### getting started\n").len(), 1);
+        assert_eq!(fix("_Usage_\n## Usage\n"), "## Usage\n");
+    }
+
+    #[test]
+    fn test_unrelated_or_not_label() {
+        // Emphasis before an unrelated heading is legitimate
+        assert!(check("**Note: this is deprecated**\n\n## Configuration\n").is_empty());
+        // Emphasis that is only part of the line
+        assert!(check("See **Installation** below\n\n## Installation\n").is_empty());
+        // Label not followed by a heading
+        assert!(check("**Installation**\n\nText\n\n## Installation\n").is_empty());
+        // Last line of a paragraph
+        assert!(check("Some text\n**Installation**\n\n## Installation\n").is_empty());
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let ctx = LintContext::new("**Usage**\n\n## Usage\n", MarkdownFlavor::Standard, None);
+        assert!(MD120RedundantHeadingLabel::default().check(&ctx).unwrap().is_empty());
+    }
+}
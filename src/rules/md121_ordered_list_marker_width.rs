@@ -0,0 +1,416 @@
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use serde::{Deserialize, Serialize};
+
+/// How ordered list items with numbers of different widths are aligned
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MarkerWidthStyle {
+    /// Pad numbers with leading zeros: `09.` next to `10.`
+    #[default]
+    #[serde(alias = "zero_padded")]
+    ZeroPadded,
+    /// Pad the space after the marker: `9.  Item` next to `10. Item`
+    #[serde(alias = "space_aligned")]
+    SpaceAligned,
+}
+
+/// Configuration for MD121 (Ordered list markers should have a consistent width)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD121Config {
+    /// Whether the rule is enabled (opt-in, like MD110)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// How shorter numbers are padded to the width of the longest
+    #[serde(default)]
+    pub style: MarkerWidthStyle,
+}
+
+impl RuleConfig for MD121Config {
+    const RULE_NAME: &'static str = "MD121";
+}
+
+/// An item whose marker has to change, and how its content moves
+#[derive(Debug)]
+struct ItemEdit {
+    /// 0-based line index of the item
+    line: usize,
+    /// Byte range in the line replaced by `replacement`
+    range: std::ops::Range<usize>,
+    replacement: String,
+    /// Columns the item content moves by (negative moves it left)
+    shift: isize,
+    /// 0-based line indices of indented content belonging to the item
+    body: Vec<usize>,
+    message: String,
+}
+
+/// Rule MD121: Ordered list markers should have a consistent width
+///
+/// See [docs/md121.md](../../docs/md121.md) for full documentation, configuration, and examples.
+///
+/// Once a list reaches `10.`, its items no longer line up with the single-digit ones.
+/// Items at the same level of an ordered list whose numbers differ in width are padded
+/// to the widest number, either with leading zeros or with extra space after the marker.
+/// Since padding moves the item content, the fix moves the item's indented content
+/// (continuation paragraphs, nested lists, code) by the same amount.
+#[derive(Debug, Clone, Default)]
+pub struct MD121OrderedListMarkerWidth {
+    config: MD121Config,
+}
+
+impl MD121OrderedListMarkerWidth {
+    pub fn new(enabled: bool, style: MarkerWidthStyle) -> Self {
+        Self {
+            config: MD121Config { enabled, style },
+        }
+    }
+
+    pub fn from_config_struct(config: MD121Config) -> Self {
+        Self { config }
+    }
+
+    /// Ordered lists as CommonMark parses them, each a list of `(item line, last line)`
+    /// pairs (0-based)
+    fn ordered_lists(content: &str) -> Vec<Vec<(usize, usize)>> {
+        let line_starts: Vec<usize> = std::iter::once(0)
+            .chain(content.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        let byte_to_line = |offset: usize| line_starts.partition_point(|&start| start <= offset) - 1;
+
+        let mut lists = Vec::new();
+        // Open lists, `None` for bullet lists
+        let mut stack: Vec<Option<Vec<(usize, usize)>>> = Vec::new();
+        for (event, range) in Parser::new_ext(content, Options::empty()).into_offset_iter() {
+            match event {
+                Event::Start(Tag::List(start)) => stack.push(start.map(|_| Vec::new())),
+                Event::End(TagEnd::List(_)) => {
+                    if let Some(Some(items)) = stack.pop() {
+                        lists.push(items);
+                    }
+                }
+                Event::Start(Tag::Item) => {
+                    if let Some(Some(items)) = stack.last_mut() {
+                        let first = byte_to_line(range.start);
+                        let last = byte_to_line(range.end.saturating_sub(1).max(range.start));
+                        items.push((first, last));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        lists.sort_by_key(|items| items.first().map(|&(line, _)| line));
+        lists
+    }
+
+    fn find_edits(&self, ctx: &LintContext) -> Vec<ItemEdit> {
+        let mut edits = Vec::new();
+
+        for items in Self::ordered_lists(ctx.content) {
+            // Blockquote prefixes would have to be rebuilt on every line
+            let items: Vec<(usize, usize)> = items
+                .into_iter()
+                .filter(|&(line, _)| {
+                    ctx.lines.get(line).is_some_and(|info| {
+                        info.blockquote.is_none() && info.list_item.as_ref().is_some_and(|item| item.is_ordered)
+                    })
+                })
+                .collect();
+
+            let widths = items
+                .iter()
+                .filter_map(|&(line, _)| Some(ctx.lines[line].list_item.as_ref()?.marker.len()));
+            let (Some(min), Some(max)) = (widths.clone().min(), widths.max()) else {
+                continue;
+            };
+            if min == max {
+                continue;
+            }
+
+            for &(line, last) in &items {
+                if let Some(edit) = self.item_edit(ctx, line, last + 1, max) {
+                    edits.push(edit);
+                }
+            }
+        }
+
+        edits.sort_by_key(|edit| edit.line);
+        edits
+    }
+
+    /// The edit for the item on line `idx`, whose content runs until line `body_end`
+    /// (exclusive), given the widest marker of its list
+    fn item_edit(&self, ctx: &LintContext, idx: usize, body_end: usize, max_width: usize) -> Option<ItemEdit> {
+        let info = &ctx.lines[idx];
+        let item = info.list_item.as_ref()?;
+        let line = info.content(ctx.content);
+        let marker_end = item.marker_column + item.marker.len();
+        let gap = line.get(marker_end..item.content_column)?;
+        // Tabs make the content column ambiguous
+        if gap.contains('\t') {
+            return None;
+        }
+        let has_content = item.content_column < line.trim_end().len();
+
+        let (range, replacement, shift, message) = match self.config.style {
+            MarkerWidthStyle::ZeroPadded => {
+                let padding = max_width - item.marker.len();
+                if padding == 0 {
+                    return None;
+                }
+                let digits = max_width - 1;
+                (
+                    item.marker_column..item.marker_column,
+                    "0".repeat(padding),
+                    padding as isize,
+                    format!("Ordered list number should be zero-padded to {digits} digits"),
+                )
+            }
+            MarkerWidthStyle::SpaceAligned => {
+                let expected_gap = 1 + max_width - item.marker.len();
+                if !has_content || gap.len() == expected_gap {
+                    return None;
+                }
+                let column = item.marker_column + max_width + 2;
+                (
+                    marker_end..item.content_column,
+                    " ".repeat(expected_gap),
+                    expected_gap as isize - gap.len() as isize,
+                    format!("List item content should start at column {column} to align with the widest marker"),
+                )
+            }
+        };
+
+        // Indented content of the item moves with it; lazy continuation lines stay put
+        let body = (idx + 1..body_end.min(ctx.lines.len()))
+            .filter(|&i| {
+                let l = &ctx.lines[i];
+                !l.is_blank && l.indent >= item.content_column
+            })
+            .collect::<Vec<_>>();
+        if body.iter().any(|&i| {
+            let text = ctx.lines[i].content(ctx.content);
+            text[..text.len() - text.trim_start().len()].contains('\t')
+        }) {
+            return None;
+        }
+
+        Some(ItemEdit {
+            line: idx,
+            range,
+            replacement,
+            shift,
+            body,
+            message,
+        })
+    }
+
+    /// Rewrite lines `first..=last` with the given edits applied
+    fn rewrite(ctx: &LintContext, edits: &[&ItemEdit], first: usize, last: usize) -> String {
+        let mut out = String::new();
+        for idx in first..=last {
+            if idx > first {
+                out.push('\n');
+            }
+            let mut line = ctx.lines[idx].content(ctx.content).to_string();
+            if let Some(edit) = edits.iter().find(|e| e.line == idx) {
+                line.replace_range(edit.range.clone(), &edit.replacement);
+            }
+            let shift: isize = edits.iter().filter(|e| e.body.contains(&idx)).map(|e| e.shift).sum();
+            if shift > 0 {
+                line.insert_str(0, &" ".repeat(shift as usize));
+            } else if shift < 0 {
+                let spaces = line.len() - line.trim_start_matches(' ').len();
+                line.drain(..spaces.min(shift.unsigned_abs()));
+            }
+            out.push_str(&line);
+        }
+        out
+    }
+}
+
+impl Rule for MD121OrderedListMarkerWidth {
+    fn name(&self) -> &'static str {
+        "MD121"
+    }
+
+    fn description(&self) -> &'static str {
+        "Ordered list markers should have a consistent width"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::List
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !self.config.enabled || !ctx.list_blocks().iter().any(|block| block.is_ordered)
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        if self.should_skip(ctx) {
+            return Ok(Vec::new());
+        }
+
+        let edits = self.find_edits(ctx);
+        let mut warnings = Vec::new();
+        for edit in &edits {
+            let last = edit.body.last().copied().unwrap_or(edit.line);
+            // The fix covers the item and everything nested in it
+            let nested: Vec<&ItemEdit> = edits.iter().filter(|e| e.line >= edit.line && e.line <= last).collect();
+            let first_info = &ctx.lines[edit.line];
+            let last_info = &ctx.lines[last];
+            let item = first_info.list_item.as_ref().expect("edits are made for list items");
+
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line: edit.line + 1,
+                column: item.marker_column + 1,
+                end_line: edit.line + 1,
+                end_column: item.content_column + 1,
+                message: edit.message.clone(),
+                severity: Severity::Warning,
+                fix: Some(Fix {
+                    range: first_info.byte_offset..last_info.byte_offset + last_info.content(ctx.content).len(),
+                    replacement: Self::rewrite(ctx, &nested, edit.line, last),
+                }),
+            });
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        if self.should_skip(ctx) {
+            return Ok(ctx.content.to_string());
+        }
+
+        let edits = self.find_edits(ctx);
+        if edits.is_empty() {
+            return Ok(ctx.content.to_string());
+        }
+
+        // Apply all edits line by line, so nested items compose with their parents
+        let all: Vec<&ItemEdit> = edits.iter().collect();
+        let first = edits.iter().map(|e| e.line).min().unwrap_or(0);
+        let last = edits
+            .iter()
+            .map(|e| e.body.last().copied().unwrap_or(e.line))
+            .max()
+            .unwrap_or(first);
+        let start = ctx.lines[first].byte_offset;
+        let end = ctx.lines[last].byte_offset + ctx.lines[last].content(ctx.content).len();
+
+        let mut content = ctx.content.to_string();
+        content.replace_range(start..end, &Self::rewrite(ctx, &all, first, last));
+        Ok(content)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::FullyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD121Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn list(range: std::ops::RangeInclusive<usize>) -> String {
+        range.map(|i| format!("{i}. Item {i}\n")).collect()
+    }
+
+    fn check(style: MarkerWidthStyle, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD121OrderedListMarkerWidth::new(true, style).check(&ctx).unwrap()
+    }
+
+    fn fix(style: MarkerWidthStyle, content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD121OrderedListMarkerWidth::new(true, style).fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_zero_padded() {
+        let content = list(8..=11);
+        let warnings = check(MarkerWidthStyle::ZeroPadded, &content);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(
+            warnings[0].message,
+            "Ordered list number should be zero-padded to 2 digits"
+        );
+        assert_eq!(
+            fix(MarkerWidthStyle::ZeroPadded, &content),
+            "08. Item 8\n09. Item 9\n10. Item 10\n11. Item 11\n"
+        );
+    }
+
+    #[test]
+    fn test_space_aligned() {
+        let content = list(8..=11);
+        let warnings = check(MarkerWidthStyle::SpaceAligned, &content);
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].message.contains("column 5"));
+        assert_eq!(
+            fix(MarkerWidthStyle::SpaceAligned, &content),
+            "8.  Item 8\n9.  Item 9\n10. Item 10\n11. Item 11\n"
+        );
+    }
+
+    #[test]
+    fn test_consistent_widths_not_flagged() {
+        assert!(check(MarkerWidthStyle::ZeroPadded, &list(1..=9)).is_empty());
+        assert!(check(MarkerWidthStyle::SpaceAligned, &list(10..=12)).is_empty());
+        assert!(check(MarkerWidthStyle::ZeroPadded, "08. a\n09. b\n10. c\n").is_empty());
+        assert!(check(MarkerWidthStyle::SpaceAligned, "9.  a\n10. b\n").is_empty());
+    }
+
+    #[test]
+    fn test_item_content_moves_with_marker() {
+        let content = "9. Nine\n\n   More text\n\n   - nested\n\n10. Ten\n";
+        let fixed = fix(MarkerWidthStyle::SpaceAligned, content);
+        assert_eq!(fixed, "9.  Nine\n\n    More text\n\n    - nested\n\n10. Ten\n");
+
+        // The warning's own fix gives the same result
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        let warnings = MD121OrderedListMarkerWidth::new(true, MarkerWidthStyle::SpaceAligned)
+            .check(&ctx)
+            .unwrap();
+        let fix = warnings[0].fix.as_ref().unwrap();
+        let mut applied = content.to_string();
+        applied.replace_range(fix.range.clone(), &fix.replacement);
+        assert_eq!(applied, fixed);
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let content = list(8..=11);
+        let ctx = LintContext::new(&content, MarkdownFlavor::Standard, None);
+        assert!(MD121OrderedListMarkerWidth::default().check(&ctx).unwrap().is_empty());
+    }
+}
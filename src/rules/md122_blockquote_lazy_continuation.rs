@@ -0,0 +1,255 @@
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD122 (Blockquote lines should not rely on lazy continuation)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD122Config {
+    /// Whether the rule is enabled (opt-in, like MD110)
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+impl RuleConfig for MD122Config {
+    const RULE_NAME: &'static str = "MD122";
+}
+
+/// Rule MD122: Blockquote lines should not rely on lazy continuation
+///
+/// See [docs/md122.md](../../docs/md122.md) for full documentation, configuration, and examples.
+///
+/// CommonMark lets a paragraph inside a blockquote continue on lines without `>`. Whether
+/// such a line still belongs to the quote depends on what the line is, so the blockquote
+/// extents come from the CommonMark parser rather than from the `>` markers: a non-blank
+/// line inside a quote with fewer markers than the quote's depth is a lazy continuation.
+#[derive(Debug, Clone, Default)]
+pub struct MD122BlockquoteLazyContinuation {
+    config: MD122Config,
+}
+
+impl MD122BlockquoteLazyContinuation {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            config: MD122Config { enabled },
+        }
+    }
+
+    pub fn from_config_struct(config: MD122Config) -> Self {
+        Self { config }
+    }
+
+    /// Blockquote depth of every line, as CommonMark parses it
+    fn quote_depths(content: &str, line_count: usize) -> Vec<usize> {
+        let line_starts: Vec<usize> = std::iter::once(0)
+            .chain(content.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        let byte_to_line = |offset: usize| line_starts.partition_point(|&start| start <= offset) - 1;
+
+        let mut depths = vec![0; line_count];
+        let mut open = Vec::new();
+        for (event, range) in Parser::new_ext(content, Options::empty()).into_offset_iter() {
+            match event {
+                Event::Start(Tag::BlockQuote(_)) => open.push(range),
+                Event::End(TagEnd::BlockQuote(_)) => {
+                    if let Some(range) = open.pop() {
+                        let first = byte_to_line(range.start);
+                        let last = byte_to_line(range.end.saturating_sub(1).max(range.start));
+                        for depth in depths.iter_mut().take(last + 1).skip(first) {
+                            *depth += 1;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        depths
+    }
+
+    /// Number of `>` markers a line starts with, and the byte length of that prefix
+    fn markers(line: &str) -> (usize, usize) {
+        let bytes = line.as_bytes();
+        let mut depth = 0;
+        let mut end = 0;
+        let mut pos = 0;
+        loop {
+            let indent = bytes[pos..].iter().take_while(|&&b| b == b' ').count();
+            if indent > 3 || bytes.get(pos + indent) != Some(&b'>') {
+                return (depth, end);
+            }
+            depth += 1;
+            pos += indent + 1;
+            if bytes.get(pos) == Some(&b' ') {
+                pos += 1;
+            }
+            end = pos;
+        }
+    }
+
+    /// The prefix to give a lazy line: that of the closest line above with all `depth`
+    /// markers, or plain `> ` markers if there is none
+    fn prefix_for(lines: &[&str], idx: usize, depth: usize) -> String {
+        (0..idx)
+            .rev()
+            .map(|i| (lines[i], Self::markers(lines[i])))
+            .find(|(_, (markers, _))| *markers == depth)
+            .map(|(line, (_, end))| {
+                let prefix = line[..end].trim_end();
+                format!("{prefix} ")
+            })
+            .unwrap_or_else(|| "> ".repeat(depth))
+    }
+}
+
+impl Rule for MD122BlockquoteLazyContinuation {
+    fn name(&self) -> &'static str {
+        "MD122"
+    }
+
+    fn description(&self) -> &'static str {
+        "Blockquote lines should not rely on lazy continuation"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Blockquote
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !self.config.enabled || !ctx.content.contains('>')
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        if self.should_skip(ctx) {
+            return Ok(warnings);
+        }
+
+        let lines: Vec<&str> = ctx.lines.iter().map(|info| info.content(ctx.content)).collect();
+        let depths = Self::quote_depths(ctx.content, lines.len());
+
+        for (idx, (&line, &depth)) in lines.iter().zip(&depths).enumerate() {
+            let info = &ctx.lines[idx];
+            if depth == 0 || info.is_blank || info.in_front_matter {
+                continue;
+            }
+            let (markers, prefix_end) = Self::markers(line);
+            if markers >= depth {
+                continue;
+            }
+
+            let text = line[prefix_end..].trim_start();
+            let replacement = format!("{}{text}", Self::prefix_for(&lines, idx, depth));
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line: idx + 1,
+                column: 1,
+                end_line: idx + 1,
+                end_column: line.chars().count() + 1,
+                message: "Lazy continuation line in blockquote should start with '>'".to_string(),
+                severity: Severity::Warning,
+                fix: Some(Fix {
+                    range: info.byte_offset..info.byte_offset + line.len(),
+                    replacement,
+                }),
+            });
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+
+        let mut content = ctx.content.to_string();
+        for fix in warnings.into_iter().rev().filter_map(|w| w.fix) {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+
+        Ok(content)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::FullyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD122Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD122BlockquoteLazyContinuation::new(true).check(&ctx).unwrap()
+    }
+
+    fn fix(content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD122BlockquoteLazyContinuation::new(true).fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_lazy_line_flagged_and_fixed() {
+        let content = "> First line\nsecond line\n  third line\n\nAfter\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].line, 2);
+        assert_eq!(
+            warnings[0].message,
+            "Lazy continuation line in blockquote should start with '>'"
+        );
+        assert_eq!(fix(content), "> First line\n> second line\n> third line\n\nAfter\n");
+    }
+
+    #[test]
+    fn test_end_of_blockquote_not_flagged() {
+        // Blank lines, headings, lists and fences end the quote instead of continuing it
+        assert!(check("> Quote\n\nParagraph\n").is_empty());
+        assert!(check("> Quote\n# Heading\n").is_empty());
+        assert!(check("> Quote\n- item\n").is_empty());
+        assert!(check("> Quote\n```\ncode\n```\n").is_empty());
+        assert!(check("> ```\n> code\n> ```\nafter\n").is_empty());
+    }
+
+    #[test]
+    fn test_nested_quote_prefix() {
+        let content = "> > Nested line\n> continued\nlazy\n";
+        assert_eq!(check(content).len(), 2);
+        assert_eq!(fix(content), "> > Nested line\n> > continued\n> > lazy\n");
+        assert_eq!(fix(">> Nested\nlazy\n"), ">> Nested\n>> lazy\n");
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let ctx = LintContext::new("> Quote\nlazy\n", MarkdownFlavor::Standard, None);
+        assert!(
+            MD122BlockquoteLazyContinuation::default()
+                .check(&ctx)
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
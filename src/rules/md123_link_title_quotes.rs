@@ -0,0 +1,213 @@
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::utils::skip_context::is_in_math_context;
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Inline link or image whose parenthesized part the parser rejected, e.g. `[x](url title)`
+static BROKEN_INLINE_LINK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(!?)\[[^\[\]\n]*\]\(([^()<>\n]*)\)").unwrap());
+
+/// File extension at the end of a path segment, e.g. `.md` or `.png`
+static FILE_EXTENSION: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\.[A-Za-z0-9]{1,5}$").unwrap());
+
+/// Split the inside of `[text](...)` into a destination and an unquoted title, when the
+/// first word is a complete URL or file path and the rest reads like a title
+///
+/// `https://example.com Example site` and `guide.md Setup guide` split; `/my page` and
+/// `docs/my file.md` don't, since those are destinations with spaces in them (see MD062).
+pub(crate) fn split_unquoted_title(inner: &str) -> Option<(&str, &str)> {
+    let inner = inner.trim();
+    let (url, title) = inner.split_once(char::is_whitespace)?;
+    let title = title.trim_start();
+    if title.is_empty() || title.starts_with(['"', '\'', '(']) || url.starts_with('<') {
+        return None;
+    }
+
+    let path = url.split(['#', '?']).next().unwrap_or(url);
+    let last_segment = path.rsplit('/').next().unwrap_or(path);
+    let complete_url = url.contains("://")
+        || url.starts_with("mailto:")
+        || url.starts_with('#')
+        || FILE_EXTENSION.is_match(last_segment);
+    // A title is prose, not the rest of a path
+    let path_like_title = title.contains('/') || FILE_EXTENSION.is_match(title);
+
+    (complete_url && !path_like_title).then_some((url, title))
+}
+
+/// Rule MD123: Link and image titles should be quoted
+///
+/// See [docs/md123.md](../../docs/md123.md) for full documentation, configuration, and examples.
+///
+/// `[text](url Title)` is not a link at all: an unquoted title makes the parser reject the
+/// whole destination, so the text is rendered literally. Lines are scanned for inline links
+/// the parser rejected, and those whose destination is a complete URL or file path followed
+/// by prose are reported. The fix quotes the title.
+#[derive(Debug, Default, Clone)]
+pub struct MD123LinkTitleQuotes;
+
+impl Rule for MD123LinkTitleQuotes {
+    fn name(&self) -> &'static str {
+        "MD123"
+    }
+
+    fn description(&self) -> &'static str {
+        "Link and image titles should be quoted"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Link
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !ctx.content.contains("](")
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        if self.should_skip(ctx) {
+            return Ok(warnings);
+        }
+
+        for (idx, line_info) in ctx.lines.iter().enumerate() {
+            let line = line_info.content(ctx.content);
+            if line_info.in_code_block || line_info.in_front_matter || !line.contains("](") {
+                continue;
+            }
+
+            for caps in BROKEN_INLINE_LINK.captures_iter(line) {
+                let whole = caps.get(0).unwrap();
+                let start = line_info.byte_offset + whole.start();
+                let end = line_info.byte_offset + whole.end();
+                let is_image = !caps[1].is_empty();
+
+                let escaped = line[..whole.start() + caps[1].len()].ends_with('\\');
+                let inside_parsed = ctx.links.iter().any(|l| l.byte_offset < end && start < l.byte_end)
+                    || ctx.images.iter().any(|i| i.byte_offset < end && start < i.byte_end);
+                if escaped
+                    || inside_parsed
+                    || ctx.is_in_code_block_or_span(start)
+                    || ctx.is_in_html_comment(start)
+                    || is_in_math_context(ctx, start)
+                    || ctx.is_in_jinja_range(start)
+                {
+                    continue;
+                }
+
+                let inner = caps.get(2).unwrap();
+                let Some((url, title)) = split_unquoted_title(inner.as_str()) else {
+                    continue;
+                };
+
+                let title = title.replace('"', "\\\"");
+                let replacement = format!("{}({url} \"{title}\")", &line[whole.start()..inner.start() - 1]);
+                let element = if is_image { "Image" } else { "Link" };
+                warnings.push(LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line: idx + 1,
+                    column: line[..whole.start()].chars().count() + 1,
+                    end_line: idx + 1,
+                    end_column: line[..whole.end()].chars().count() + 1,
+                    message: format!("{element} title should be quoted"),
+                    severity: Severity::Warning,
+                    fix: Some(Fix {
+                        range: start..end,
+                        replacement,
+                    }),
+                });
+            }
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+
+        let mut content = ctx.content.to_string();
+        for fix in warnings.into_iter().rev().filter_map(|w| w.fix) {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+
+        Ok(content)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn from_config(_config: &crate::config::Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        Box::new(MD123LinkTitleQuotes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD123LinkTitleQuotes.check(&ctx).unwrap()
+    }
+
+    fn fix(content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD123LinkTitleQuotes.fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_unquoted_title_flagged_and_fixed() {
+        let content = "See [the site](https://example.com Example site) and ![logo](logo.png Our logo).\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].message, "Link title should be quoted");
+        assert_eq!(warnings[1].message, "Image title should be quoted");
+        assert_eq!(
+            fix(content),
+            "See [the site](https://example.com \"Example site\") and ![logo](logo.png \"Our logo\").\n"
+        );
+    }
+
+    #[test]
+    fn test_title_with_quotes_escaped() {
+        assert_eq!(
+            fix("[guide](guide.md#setup The \"setup\" step)\n"),
+            "[guide](guide.md#setup \"The \\\"setup\\\" step\")\n"
+        );
+    }
+
+    #[test]
+    fn test_valid_or_unrelated_not_flagged() {
+        // Quoted titles parse fine
+        assert!(check("[a](https://example.com \"Title\") [b](b.md 'Title') [c](c.md (Title))\n").is_empty());
+        // Destinations with spaces are MD062's concern
+        assert!(check("[a](/my page) [b](docs/my file.md)\n").is_empty());
+        // Prose in parentheses after brackets
+        assert!(check("[note](see the appendix for details)\n").is_empty());
+        assert!(check("`[a](https://example.com Title)`\n").is_empty());
+    }
+
+    #[test]
+    fn test_split_unquoted_title() {
+        assert_eq!(
+            split_unquoted_title("https://example.com  Example"),
+            Some(("https://example.com", "Example"))
+        );
+        assert_eq!(
+            split_unquoted_title("#intro Introduction"),
+            Some(("#intro", "Introduction"))
+        );
+        assert_eq!(
+            split_unquoted_title("mailto:me@example.com Email me"),
+            Some(("mailto:me@example.com", "Email me"))
+        );
+        assert_eq!(split_unquoted_title("file.md"), None);
+        assert_eq!(split_unquoted_title("my file.md"), None);
+        assert_eq!(split_unquoted_title("a.md b/c"), None);
+    }
+}
@@ -0,0 +1,237 @@
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// URL scheme such as `https:` or `mailto:` (also matches Windows drive letters like `C:`)
+static URL_SCHEME: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[A-Za-z][A-Za-z0-9+.-]*:").unwrap());
+
+/// Rule MD124: Relative links should use forward slashes
+///
+/// See [docs/md124.md](../../docs/md124.md) for full documentation, configuration, and examples.
+///
+/// `[page](docs\page.md)` works when the file is opened locally on Windows, but browsers and
+/// static site generators treat `\` as part of the file name, so the link breaks once the
+/// document is published. Inline links and images with a relative path containing a
+/// backslash are reported, and the fix replaces the backslashes with forward slashes.
+#[derive(Debug, Default, Clone)]
+pub struct MD124LinkForwardSlashes;
+
+impl MD124LinkForwardSlashes {
+    /// Byte range of the path part of an inline destination, if it is relative and contains a backslash
+    ///
+    /// `url` is the destination as parsed. It is located in the raw source so that only
+    /// backslashes written literally are touched: a destination whose backslashes escape
+    /// punctuation (`a\_b.md`) no longer matches the source and is left alone.
+    fn backslash_path_range(content: &str, start: usize, end: usize, url: &str) -> Option<std::ops::Range<usize>> {
+        if url.starts_with(['#', '/', '?']) || url.starts_with("//") || URL_SCHEME.is_match(url) {
+            return None;
+        }
+        let path_len = url.find(['#', '?']).unwrap_or(url.len());
+        if !url[..path_len].contains('\\') {
+            return None;
+        }
+
+        let raw = &content[start..end];
+        raw.rmatch_indices("](").find_map(|(pos, _)| {
+            let after = &raw[pos + 2..];
+            let trimmed = after.trim_start();
+            let offset = pos + 2 + (after.len() - trimmed.len()) + usize::from(trimmed.starts_with('<'));
+            raw[offset..]
+                .starts_with(url)
+                .then(|| start + offset..start + offset + path_len)
+        })
+    }
+}
+
+impl Rule for MD124LinkForwardSlashes {
+    fn name(&self) -> &'static str {
+        "MD124"
+    }
+
+    fn description(&self) -> &'static str {
+        "Relative links should use forward slashes"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Link
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !ctx.content.contains('\\') || (ctx.links.is_empty() && ctx.images.is_empty())
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        if self.should_skip(ctx) {
+            return Ok(warnings);
+        }
+
+        let links = ctx
+            .links
+            .iter()
+            .filter(|l| !l.is_reference)
+            .map(|l| (l.byte_offset, l.byte_end, &l.url, "Link"));
+        let images = ctx
+            .images
+            .iter()
+            .filter(|i| !i.is_reference)
+            .map(|i| (i.byte_offset, i.byte_end, &i.url, "Image"));
+
+        for (start, end, url, element) in links.chain(images) {
+            let Some(range) = Self::backslash_path_range(ctx.content, start, end, url) else {
+                continue;
+            };
+
+            let (line, column) = ctx.offset_to_line_col(range.start);
+            let (end_line, end_column) = ctx.offset_to_line_col(range.end);
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line,
+                column,
+                end_line,
+                end_column,
+                message: format!("{element} path should use forward slashes instead of backslashes"),
+                severity: Severity::Warning,
+                fix: Some(Fix {
+                    replacement: ctx.content[range.clone()].replace('\\', "/"),
+                    range,
+                }),
+            });
+        }
+
+        warnings.sort_by_key(|w| w.fix.as_ref().map(|f| f.range.start));
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+
+        let mut content = ctx.content.to_string();
+        for fix in warnings.into_iter().rev().filter_map(|w| w.fix) {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+
+        Ok(content)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn from_config(_config: &crate::config::Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        Box::new(MD124LinkForwardSlashes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD124LinkForwardSlashes.check(&ctx).unwrap()
+    }
+
+    fn fix(content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD124LinkForwardSlashes.fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_backslashes_flagged_and_fixed() {
+        let content = "See [the page](docs\\guide\\page.md#setup) and ![logo](..\\images\\logo.png \"Logo\").\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(
+            warnings[0].message,
+            "Link path should use forward slashes instead of backslashes"
+        );
+        assert_eq!(warnings[0].column, 16);
+        assert_eq!(
+            warnings[1].message,
+            "Image path should use forward slashes instead of backslashes"
+        );
+        assert_eq!(
+            fix(content),
+            "See [the page](docs/guide/page.md#setup) and ![logo](../images/logo.png \"Logo\").\n"
+        );
+    }
+
+    #[test]
+    fn test_nested_image_in_link() {
+        let content = "[![badge](img\\badge.svg)](docs\\index.md)\n";
+        assert_eq!(check(content).len(), 2);
+        assert_eq!(fix(content), "[![badge](img/badge.svg)](docs/index.md)\n");
+    }
+
+    #[test]
+    fn test_valid_or_external_not_flagged() {
+        assert!(check("[a](docs/page.md) [b](https://example.com/a\\b) [c](C:\\docs\\page.md)\n").is_empty());
+        // Escaped punctuation is not a path separator
+        assert!(check("[a](my\\_file.md)\n").is_empty());
+        // Backslashes in the fragment are not part of the path
+        assert!(check("[a](page.md#a\\b)\n").is_empty());
+        assert!(check("`[a](docs\\page.md)`\n").is_empty());
+    }
+
+    #[test]
+    fn test_fix_is_idempotent() {
+        let fixed = fix("[a](docs\\a.md) ![b](img\\b.png)\n");
+        assert!(check(&fixed).is_empty());
+        assert_eq!(fix(&fixed), fixed);
+    }
+
+    #[test]
+    fn test_code_blocks_skipped() {
+        let content = "```markdown\n[a](docs\\page.md)\n```\n\n    [b](docs\\page.md)\n";
+        assert!(check(content).is_empty());
+        assert_eq!(fix(content), content);
+    }
+
+    #[test]
+    fn test_front_matter_skipped() {
+        let content = "---\nlink: \"[a](docs\\\\page.md)\"\n---\n\n# Title\n";
+        assert!(check(content).is_empty());
+    }
+
+    #[test]
+    fn test_reference_links_not_flagged() {
+        let content = "See [the page][page].\n\n[page]: docs\\page.md\n";
+        assert!(check(content).is_empty());
+    }
+
+    #[test]
+    fn test_angle_bracket_destination() {
+        let content = "[a](<docs\\my page.md>)\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].column, 6);
+        assert_eq!(fix(content), "[a](<docs/my page.md>)\n");
+    }
+
+    #[test]
+    fn test_query_string_kept() {
+        assert_eq!(fix("[a](docs\\page.md?v=1\\2)\n"), "[a](docs/page.md?v=1\\2)\n");
+    }
+
+    #[test]
+    fn test_positions_on_later_lines() {
+        let content = "# Title\n\nText first.\nThen [a](x\\y.md).\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 4);
+        assert_eq!(warnings[0].column, 10);
+        assert_eq!(warnings[0].end_column, 16);
+    }
+
+    #[test]
+    fn test_no_links() {
+        assert!(check("").is_empty());
+        assert!(check("A path like docs\\page.md in prose.\n").is_empty());
+    }
+}
@@ -0,0 +1,272 @@
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD125 (No trailing whitespace in code blocks)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD125Config {
+    /// Whether the rule is enabled (opt-in, like MD110)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Whether warnings carry a fix that strips the whitespace
+    #[serde(default)]
+    pub fix: bool,
+
+    /// Languages whose trailing whitespace can be meaningful; reported but never fixed
+    #[serde(default = "default_preserve_languages", alias = "preserve_languages")]
+    pub preserve_languages: Vec<String>,
+}
+
+fn default_preserve_languages() -> Vec<String> {
+    ["markdown", "md", "diff", "patch"].map(String::from).to_vec()
+}
+
+impl Default for MD125Config {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            fix: false,
+            preserve_languages: default_preserve_languages(),
+        }
+    }
+}
+
+impl RuleConfig for MD125Config {
+    const RULE_NAME: &'static str = "MD125";
+}
+
+/// Rule MD125: No trailing whitespace in code blocks
+///
+/// See [docs/md125.md](../../docs/md125.md) for full documentation, configuration, and examples.
+///
+/// MD009 is about prose; this rule looks only at the content lines of code blocks, where
+/// trailing whitespace is usually a copy-paste artifact. Because some languages give it
+/// meaning, the fix is opt-in and is withheld for the languages in `preserve-languages`,
+/// taken from the first word of the fence info string.
+#[derive(Debug, Clone, Default)]
+pub struct MD125CodeBlockTrailingSpaces {
+    config: MD125Config,
+}
+
+impl MD125CodeBlockTrailingSpaces {
+    pub fn new(enabled: bool, fix: bool) -> Self {
+        Self {
+            config: MD125Config {
+                enabled,
+                fix,
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn from_config_struct(config: MD125Config) -> Self {
+        Self { config }
+    }
+
+    /// Language named by a fence info string: `rust` for `rust,ignore`, `python` for `{python}`
+    fn language(info: &str) -> String {
+        info.split_whitespace()
+            .next()
+            .unwrap_or("")
+            .trim_start_matches('{')
+            .split([',', '}'])
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase()
+    }
+
+    /// Zero-based index of every code block content line, with the language of its block
+    fn code_lines(ctx: &LintContext) -> Vec<(usize, String)> {
+        let mut lines: Vec<(usize, String)> = Vec::new();
+        let mut language = None;
+        for (event, range) in Parser::new_ext(ctx.content, Options::empty()).into_offset_iter() {
+            match event {
+                Event::Start(Tag::CodeBlock(kind)) => {
+                    language = Some(match kind {
+                        CodeBlockKind::Fenced(info) => Self::language(&info),
+                        CodeBlockKind::Indented => String::new(),
+                    });
+                }
+                Event::End(TagEnd::CodeBlock) => language = None,
+                Event::Text(_) if !range.is_empty() => {
+                    let Some(language) = &language else {
+                        continue;
+                    };
+                    let (first, _) = ctx.offset_to_line_col(range.start);
+                    let (last, _) = ctx.offset_to_line_col(range.end - 1);
+                    for line in first - 1..last {
+                        if lines.last().is_none_or(|(prev, _)| *prev < line) {
+                            lines.push((line, language.clone()));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        lines
+    }
+}
+
+impl Rule for MD125CodeBlockTrailingSpaces {
+    fn name(&self) -> &'static str {
+        "MD125"
+    }
+
+    fn description(&self) -> &'static str {
+        "No trailing whitespace in code blocks"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::CodeBlock
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !self.config.enabled || !ctx.lines.iter().any(|l| l.in_code_block)
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        if self.should_skip(ctx) {
+            return Ok(warnings);
+        }
+
+        for (idx, language) in Self::code_lines(ctx) {
+            let Some(info) = ctx.lines.get(idx) else {
+                continue;
+            };
+            let line = info.content(ctx.content);
+            let trimmed_len = line.trim_end_matches([' ', '\t']).len();
+            if trimmed_len == line.len() {
+                continue;
+            }
+
+            let fixable = self.config.fix
+                && !self
+                    .config
+                    .preserve_languages
+                    .iter()
+                    .any(|l| l.eq_ignore_ascii_case(&language));
+            let start = info.byte_offset + trimmed_len;
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line: idx + 1,
+                column: line[..trimmed_len].chars().count() + 1,
+                end_line: idx + 1,
+                end_column: line.chars().count() + 1,
+                message: "Trailing whitespace in code block".to_string(),
+                severity: Severity::Warning,
+                fix: fixable.then(|| Fix {
+                    range: start..info.byte_offset + line.len(),
+                    replacement: String::new(),
+                }),
+            });
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+
+        let mut content = ctx.content.to_string();
+        for fix in warnings.into_iter().rev().filter_map(|w| w.fix) {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+
+        Ok(content)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::ConditionallyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD125Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(rule: &MD125CodeBlockTrailingSpaces, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    fn fix(rule: &MD125CodeBlockTrailingSpaces, content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_only_code_lines_flagged() {
+        let rule = MD125CodeBlockTrailingSpaces::new(true, false);
+        let content = "Prose line  \n\n```rust  \nlet x = 1;  \n\tlet y = 2;\t\n```\n\n    indented  \n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.iter().map(|w| w.line).collect::<Vec<_>>(), vec![4, 5, 8]);
+        assert_eq!(warnings[0].message, "Trailing whitespace in code block");
+        assert_eq!(warnings[0].column, 11);
+        assert!(warnings.iter().all(|w| w.fix.is_none()));
+        assert_eq!(fix(&rule, content), content);
+    }
+
+    #[test]
+    fn test_fix_strips_whitespace() {
+        let rule = MD125CodeBlockTrailingSpaces::new(true, true);
+        let content = "> ```sh\n> echo hi   \n> ```\n\n- item\n\n  ```{python}\n  print(1) \n  ```\n";
+        assert_eq!(check(&rule, content).len(), 2);
+        assert_eq!(
+            fix(&rule, content),
+            "> ```sh\n> echo hi\n> ```\n\n- item\n\n  ```{python}\n  print(1)\n  ```\n"
+        );
+    }
+
+    #[test]
+    fn test_preserved_languages_not_fixed() {
+        let rule = MD125CodeBlockTrailingSpaces::new(true, true);
+        let content = "```diff\n-old  \n+new\n```\n\n```Markdown\nline break  \nnext\n```\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings.iter().all(|w| w.fix.is_none()));
+        assert_eq!(fix(&rule, content), content);
+
+        let rule = MD125CodeBlockTrailingSpaces::from_config_struct(MD125Config {
+            enabled: true,
+            fix: true,
+            preserve_languages: vec!["text".to_string()],
+        });
+        assert_eq!(
+            fix(&rule, content),
+            "```diff\n-old\n+new\n```\n\n```Markdown\nline break\nnext\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let rule = MD125CodeBlockTrailingSpaces::default();
+        assert!(check(&rule, "```\ncode  \n```\n").is_empty());
+    }
+}
@@ -0,0 +1,307 @@
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use crate::utils::anchor_styles::AnchorStyle;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD126 (Top-level heading should match the file name)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD126Config {
+    /// Whether the rule is enabled (opt-in, like MD110)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// File names that are not checked (case-insensitive)
+    #[serde(default = "default_exempt_files", alias = "exempt_files")]
+    pub exempt_files: Vec<String>,
+
+    /// Slug algorithm applied to the heading text
+    #[serde(default, alias = "anchor_style")]
+    pub anchor_style: AnchorStyle,
+}
+
+fn default_exempt_files() -> Vec<String> {
+    vec!["README.md".to_string(), "index.md".to_string()]
+}
+
+impl Default for MD126Config {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            exempt_files: default_exempt_files(),
+            anchor_style: AnchorStyle::default(),
+        }
+    }
+}
+
+impl RuleConfig for MD126Config {
+    const RULE_NAME: &'static str = "MD126";
+}
+
+/// Rule MD126: Top-level heading should match the file name
+///
+/// See [docs/md126.md](../../docs/md126.md) for full documentation, configuration, and examples.
+///
+/// Wikis and generated navigation often expect `page-name.md` to be titled "Page Name".
+/// The first H1 is slugified with the configured anchor style (or its custom ID is used)
+/// and compared to the file stem. Renaming either side is a judgment call, so there is no
+/// fix. Documents linted without a file path are skipped.
+#[derive(Debug, Clone, Default)]
+pub struct MD126HeadingMatchesFilename {
+    config: MD126Config,
+}
+
+impl MD126HeadingMatchesFilename {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            config: MD126Config {
+                enabled,
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn from_config_struct(config: MD126Config) -> Self {
+        Self { config }
+    }
+}
+
+impl Rule for MD126HeadingMatchesFilename {
+    fn name(&self) -> &'static str {
+        "MD126"
+    }
+
+    fn description(&self) -> &'static str {
+        "Top-level heading should match the file name"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Heading
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        // `likely_has_headings` misses setext H1s (`===` underlines), so look for a heading directly
+        !self.config.enabled || ctx.source_file.is_none() || !ctx.lines.iter().any(|l| l.heading.is_some())
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        if self.should_skip(ctx) {
+            return Ok(warnings);
+        }
+
+        let Some(path) = ctx.source_file.as_deref() else {
+            return Ok(warnings);
+        };
+        let (Some(file_name), Some(stem)) = (
+            path.file_name().and_then(|n| n.to_str()),
+            path.file_stem().and_then(|s| s.to_str()),
+        ) else {
+            return Ok(warnings);
+        };
+        if self
+            .config
+            .exempt_files
+            .iter()
+            .any(|exempt| exempt.eq_ignore_ascii_case(file_name))
+        {
+            return Ok(warnings);
+        }
+
+        let Some((idx, heading)) = ctx
+            .lines
+            .iter()
+            .enumerate()
+            .find_map(|(idx, info)| info.heading.as_ref().filter(|h| h.level == 1).map(|h| (idx, h)))
+        else {
+            return Ok(warnings);
+        };
+
+        let slug = match &heading.custom_id {
+            Some(id) => id.clone(),
+            None => self.config.anchor_style.generate_fragment(&heading.text),
+        };
+        let expected = stem.to_lowercase();
+        if slug == expected {
+            return Ok(warnings);
+        }
+
+        let line = ctx.lines[idx].content(ctx.content);
+        warnings.push(LintWarning {
+            rule_name: Some(self.name().to_string()),
+            line: idx + 1,
+            column: 1,
+            end_line: idx + 1,
+            end_column: line.chars().count() + 1,
+            message: format!(
+                "Heading '{}' should match file name '{file_name}' (slug '{slug}', expected '{expected}')",
+                heading.text
+            ),
+            severity: Severity::Warning,
+            fix: None,
+        });
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        // Renaming the heading or the file is left to the author
+        Ok(ctx.content.to_string())
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::Unfixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD126Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+    use std::path::PathBuf;
+
+    fn check(content: &str, path: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, Some(PathBuf::from(path)));
+        MD126HeadingMatchesFilename::new(true).check(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_matching_heading() {
+        assert!(check("# Page Name\n\nText\n", "wiki/page-name.md").is_empty());
+        assert!(check("Intro\n\nPage Name\n=========\n", "Page-Name.md").is_empty());
+        assert!(check("# Getting Started {#setup}\n", "setup.md").is_empty());
+        // Only the first H1 counts
+        assert!(check("## Overview\n\n# Page Name\n\n# Other\n", "page-name.md").is_empty());
+    }
+
+    #[test]
+    fn test_mismatched_heading() {
+        let warnings = check("---\ntitle: x\n---\n\n# Installation Guide\n", "docs/install.md");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 5);
+        assert_eq!(
+            warnings[0].message,
+            "Heading 'Installation Guide' should match file name 'install.md' (slug 'installation-guide', expected 'install')"
+        );
+        assert!(warnings[0].fix.is_none());
+    }
+
+    #[test]
+    fn test_skipped_cases() {
+        // Exempt index files, no H1, headings in code, and no source file
+        assert!(check("# Project\n", "README.md").is_empty());
+        assert!(check("# Home\n", "docs/Index.md").is_empty());
+        assert!(check("## Section\n", "page.md").is_empty());
+        assert!(check("```\n# Title\n```\n", "page.md").is_empty());
+
+        let ctx = LintContext::new("# Title\n", MarkdownFlavor::Standard, None);
+        assert!(MD126HeadingMatchesFilename::new(true).check(&ctx).unwrap().is_empty());
+        let ctx = LintContext::new("# Title\n", MarkdownFlavor::Standard, Some(PathBuf::from("page.md")));
+        assert!(MD126HeadingMatchesFilename::default().check(&ctx).unwrap().is_empty());
+    }
+
+    fn check_with(config: MD126Config, content: &str, path: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, Some(PathBuf::from(path)));
+        MD126HeadingMatchesFilename::from_config_struct(config)
+            .check(&ctx)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_punctuation_and_extension() {
+        assert!(check("# What's New?\n", "whats-new.md").is_empty());
+        assert!(check("# Release Notes\n", "docs/release-notes.markdown").is_empty());
+    }
+
+    #[test]
+    fn test_setext_mismatch_position() {
+        let warnings = check("Intro text.\n\nWrong Title\n===========\n", "page.md");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 3);
+        assert_eq!(warnings[0].column, 1);
+        assert_eq!(warnings[0].end_column, 12);
+    }
+
+    #[test]
+    fn test_front_matter_comment_not_a_heading() {
+        let content = "---\n# yaml comment\ntitle: Page\n---\n\n# Page\n";
+        assert!(check(content, "page.md").is_empty());
+        assert_eq!(check(content, "other.md")[0].line, 6);
+    }
+
+    #[test]
+    fn test_custom_exempt_files() {
+        let config = MD126Config {
+            enabled: true,
+            exempt_files: vec!["CHANGELOG.md".to_string()],
+            ..Default::default()
+        };
+        assert!(check_with(config.clone(), "# Changes\n", "changelog.md").is_empty());
+        // Replacing the list drops the defaults
+        assert_eq!(check_with(config, "# Project\n", "README.md").len(), 1);
+    }
+
+    #[test]
+    fn test_anchor_style() {
+        assert!(check("# snake_case\n", "snake_case.md").is_empty());
+
+        let kramdown = MD126Config {
+            enabled: true,
+            anchor_style: AnchorStyle::Kramdown,
+            ..Default::default()
+        };
+        assert!(check_with(kramdown.clone(), "# snake_case\n", "snakecase.md").is_empty());
+        assert_eq!(check_with(kramdown, "# snake_case\n", "snake_case.md").len(), 1);
+    }
+
+    #[test]
+    fn test_fix_leaves_content_unchanged() {
+        let content = "# Wrong Title\n";
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, Some(PathBuf::from("page.md")));
+        let rule = MD126HeadingMatchesFilename::new(true);
+        assert_eq!(rule.fix(&ctx).unwrap(), content);
+        assert_eq!(rule.fix_capability(), FixCapability::Unfixable);
+    }
+
+    #[test]
+    fn test_config_from_toml() {
+        let mut config = Config::default();
+        let mut rule_config = crate::config::RuleConfig::default();
+        rule_config
+            .values
+            .insert("enabled".to_string(), toml::Value::Boolean(true));
+        rule_config.values.insert(
+            "exempt_files".to_string(),
+            toml::Value::Array(vec![toml::Value::String("notes.md".to_string())]),
+        );
+        config.rules.insert("MD126".to_string(), rule_config);
+
+        let rule = MD126HeadingMatchesFilename::from_config(&config);
+        let ctx = LintContext::new("# Misc\n", MarkdownFlavor::Standard, Some(PathBuf::from("notes.md")));
+        assert!(rule.check(&ctx).unwrap().is_empty());
+        let ctx = LintContext::new("# Misc\n", MarkdownFlavor::Standard, Some(PathBuf::from("index.md")));
+        assert_eq!(rule.check(&ctx).unwrap().len(), 1);
+    }
+}
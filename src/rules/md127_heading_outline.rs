@@ -0,0 +1,255 @@
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use crate::utils::range_utils::calculate_heading_range;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD127 (Headings should form a single outline rooted at an H1)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD127Config {
+    /// Whether the rule is enabled (opt-in, like MD110)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Front matter key that stands in for the H1 root (empty to ignore front matter)
+    #[serde(default = "default_front_matter_title", alias = "front_matter_title")]
+    pub front_matter_title: String,
+}
+
+fn default_front_matter_title() -> String {
+    "title".to_string()
+}
+
+impl Default for MD127Config {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            front_matter_title: default_front_matter_title(),
+        }
+    }
+}
+
+impl RuleConfig for MD127Config {
+    const RULE_NAME: &'static str = "MD127";
+}
+
+/// Rule MD127: Headings should form a single outline rooted at an H1
+///
+/// See [docs/md127.md](../../docs/md127.md) for full documentation, configuration, and examples.
+///
+/// MD001 only compares each heading with the one before it, so an outline that starts at
+/// H3 or has several H1 roots passes. This rule walks all headings with a stack of open
+/// levels and reports the first heading that can't be placed in a tree under one H1 (or
+/// under a front matter title standing in for it). Repairing an outline means choosing
+/// new levels for whole sections, so there is no fix.
+#[derive(Debug, Clone, Default)]
+pub struct MD127HeadingOutline {
+    config: MD127Config,
+}
+
+impl MD127HeadingOutline {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            config: MD127Config {
+                enabled,
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn from_config_struct(config: MD127Config) -> Self {
+        Self { config }
+    }
+
+    /// Whether the front matter sets the configured title key
+    fn has_front_matter_title(&self, ctx: &LintContext) -> bool {
+        let key = self.config.front_matter_title.as_str();
+        !key.is_empty()
+            && ctx.lines.iter().filter(|l| l.in_front_matter).any(|l| {
+                l.content(ctx.content)
+                    .strip_prefix(key)
+                    .is_some_and(|rest| rest.trim_start().starts_with([':', '=']))
+            })
+    }
+}
+
+impl Rule for MD127HeadingOutline {
+    fn name(&self) -> &'static str {
+        "MD127"
+    }
+
+    fn description(&self) -> &'static str {
+        "Headings should form a single outline rooted at an H1"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Heading
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !self.config.enabled || !ctx.likely_has_headings()
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        if self.should_skip(ctx) {
+            return Ok(warnings);
+        }
+
+        // Levels of the headings enclosing the current position; a front matter title is the root
+        let mut open: Vec<u8> = Vec::new();
+        let mut root_line = None;
+        if self.has_front_matter_title(ctx) {
+            open.push(1);
+        }
+
+        for (idx, info) in ctx.lines.iter().enumerate() {
+            let Some(heading) = &info.heading else {
+                continue;
+            };
+            let level = heading.level;
+
+            let problem = if level == 1 && !open.is_empty() {
+                Some(match root_line {
+                    Some(line) => {
+                        format!("Outline should have a single H1 root, but the H1 on line {line} is already the root")
+                    }
+                    None => format!(
+                        "Outline should have a single H1 root, but front matter already sets '{}'",
+                        self.config.front_matter_title
+                    ),
+                })
+            } else if open.is_empty() && level != 1 {
+                Some(format!(
+                    "Outline should start with an H1, but the first heading is an H{level}"
+                ))
+            } else if open.last().is_some_and(|&parent| level > parent + 1) {
+                Some(format!("H{level} heading has no parent H{} in the outline", level - 1))
+            } else {
+                None
+            };
+
+            if let Some(message) = problem {
+                let (line, column, end_line, end_column) = calculate_heading_range(idx + 1, info.content(ctx.content));
+                warnings.push(LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line,
+                    column,
+                    end_line,
+                    end_column,
+                    message,
+                    severity: Severity::Warning,
+                    fix: None,
+                });
+                break;
+            }
+
+            while open.last().is_some_and(|&parent| parent >= level) {
+                open.pop();
+            }
+            open.push(level);
+            if level == 1 {
+                root_line = Some(idx + 1);
+            }
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        // Choosing new levels for whole sections needs the author's judgment
+        Ok(ctx.content.to_string())
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::Unfixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD127Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD127HeadingOutline::new(true).check(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_valid_outline() {
+        assert!(check("# Title\n\n## A\n\n### A.1\n\n## B\n\n### B.1\n\n#### B.1.1\n\n## C\n").is_empty());
+        assert!(check("Title\n=====\n\nSection\n-------\n\n### Detail\n").is_empty());
+        assert!(check("No headings here\n").is_empty());
+    }
+
+    #[test]
+    fn test_first_offending_heading_reported() {
+        let warnings = check("## Intro\n\n# Title\n\n### Deep\n");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 1);
+        assert_eq!(
+            warnings[0].message,
+            "Outline should start with an H1, but the first heading is an H2"
+        );
+
+        let warnings = check("# Title\n\n## A\n\n#### Deep\n\n# Again\n");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 5);
+        assert_eq!(warnings[0].message, "H4 heading has no parent H3 in the outline");
+
+        let warnings = check("# Title\n\n## A\n\n# Second\n");
+        assert_eq!(warnings[0].line, 5);
+        assert_eq!(
+            warnings[0].message,
+            "Outline should have a single H1 root, but the H1 on line 1 is already the root"
+        );
+        assert!(warnings[0].fix.is_none());
+    }
+
+    #[test]
+    fn test_front_matter_title_is_root() {
+        assert!(check("---\ntitle: Guide\n---\n\n## Install\n\n### Linux\n").is_empty());
+
+        let warnings = check("---\ntitle: Guide\n---\n\n# Guide\n");
+        assert_eq!(
+            warnings[0].message,
+            "Outline should have a single H1 root, but front matter already sets 'title'"
+        );
+
+        let rule = MD127HeadingOutline::from_config_struct(MD127Config {
+            enabled: true,
+            front_matter_title: String::new(),
+        });
+        let ctx = LintContext::new("---\ntitle: Guide\n---\n\n## Install\n", MarkdownFlavor::Standard, None);
+        assert_eq!(rule.check(&ctx).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let ctx = LintContext::new("### Deep\n", MarkdownFlavor::Standard, None);
+        assert!(MD127HeadingOutline::default().check(&ctx).unwrap().is_empty());
+    }
+}
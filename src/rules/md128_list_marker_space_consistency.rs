@@ -0,0 +1,266 @@
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD128 (Spaces after list markers should be consistent within a list)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD128Config {
+    /// Whether the rule is enabled (opt-in, like MD110)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Spaces every item should use; 0 matches the first item of each list
+    #[serde(default)]
+    pub spaces: usize,
+}
+
+impl RuleConfig for MD128Config {
+    const RULE_NAME: &'static str = "MD128";
+}
+
+/// A list item's marker end and the spaces that follow it
+struct ItemSpacing {
+    line: usize,
+    marker_end: usize,
+    spaces: usize,
+    content_column: usize,
+}
+
+/// Rule MD128: Spaces after list markers should be consistent within a list
+///
+/// See [docs/md128.md](../../docs/md128.md) for full documentation, configuration, and examples.
+///
+/// MD030 checks every item against fixed counts. This rule compares sibling items of each
+/// list block instead, so a list written with `-   ` stays that way as long as every item
+/// agrees. Ordered lists whose items already align their content (`9.  Nine` above
+/// `10. Ten`) are left alone, since the differing spacing is intentional there.
+#[derive(Debug, Clone, Default)]
+pub struct MD128ListMarkerSpaceConsistency {
+    config: MD128Config,
+}
+
+impl MD128ListMarkerSpaceConsistency {
+    pub fn new(enabled: bool, spaces: usize) -> Self {
+        Self {
+            config: MD128Config { enabled, spaces },
+        }
+    }
+
+    pub fn from_config_struct(config: MD128Config) -> Self {
+        Self { config }
+    }
+
+    /// Items of each list block grouped with their siblings (same marker column, same parent)
+    fn sibling_groups(ctx: &LintContext) -> Vec<Vec<ItemSpacing>> {
+        let mut groups: Vec<Vec<ItemSpacing>> = Vec::new();
+        for block in ctx.list_blocks() {
+            // Open sibling groups by marker column; a shallower item closes the deeper ones
+            let mut open: Vec<(usize, usize)> = Vec::new();
+            for &line_num in &block.item_lines {
+                let Some(info) = ctx.lines.get(line_num - 1) else {
+                    continue;
+                };
+                let Some(item) = &info.list_item else {
+                    continue;
+                };
+                if info.in_code_block {
+                    continue;
+                }
+
+                while open.last().is_some_and(|&(column, _)| column > item.marker_column) {
+                    open.pop();
+                }
+                let group = match open.last() {
+                    Some(&(column, group)) if column == item.marker_column => group,
+                    _ => {
+                        groups.push(Vec::new());
+                        open.push((item.marker_column, groups.len() - 1));
+                        groups.len() - 1
+                    }
+                };
+
+                let line = info.content(ctx.content);
+                let marker_end = item.marker_column + item.marker.len();
+                let gap = line.get(marker_end..item.content_column).unwrap_or("");
+                // Empty items have no spacing to compare, and tabs are MD010's concern
+                if gap.is_empty() || !gap.bytes().all(|b| b == b' ') || item.content_column >= line.len() {
+                    continue;
+                }
+                groups[group].push(ItemSpacing {
+                    line: line_num,
+                    marker_end,
+                    spaces: gap.len(),
+                    content_column: item.content_column,
+                });
+            }
+        }
+        groups
+    }
+}
+
+impl Rule for MD128ListMarkerSpaceConsistency {
+    fn name(&self) -> &'static str {
+        "MD128"
+    }
+
+    fn description(&self) -> &'static str {
+        "Spaces after list markers should be consistent within a list"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::List
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !self.config.enabled || ctx.list_blocks().is_empty()
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        if self.should_skip(ctx) {
+            return Ok(warnings);
+        }
+
+        for group in Self::sibling_groups(ctx) {
+            let Some(first) = group.first() else {
+                continue;
+            };
+            let uniform = group.iter().all(|item| item.spaces == first.spaces);
+            let aligned = group.iter().all(|item| item.content_column == first.content_column);
+            let ordered = ctx.lines[first.line - 1]
+                .list_item
+                .as_ref()
+                .is_some_and(|item| item.is_ordered);
+            if !uniform && aligned && ordered {
+                continue;
+            }
+
+            let expected = if self.config.spaces > 0 {
+                self.config.spaces
+            } else {
+                first.spaces
+            };
+            for item in group.iter().filter(|item| item.spaces != expected) {
+                let start = ctx.line_offsets[item.line - 1] + item.marker_end;
+                let column = ctx.lines[item.line - 1].content(ctx.content)[..item.marker_end]
+                    .chars()
+                    .count()
+                    + 1;
+                warnings.push(LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line: item.line,
+                    column,
+                    end_line: item.line,
+                    end_column: column + item.spaces,
+                    message: format!(
+                        "Spaces after list marker should match the rest of the list (Expected: {expected}; Actual: {})",
+                        item.spaces
+                    ),
+                    severity: Severity::Warning,
+                    fix: Some(Fix {
+                        range: start..start + item.spaces,
+                        replacement: " ".repeat(expected),
+                    }),
+                });
+            }
+        }
+
+        warnings.sort_by_key(|w| w.line);
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+
+        let mut content = ctx.content.to_string();
+        for fix in warnings.into_iter().rev().filter_map(|w| w.fix) {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+
+        Ok(content)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::FullyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD128Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(rule: &MD128ListMarkerSpaceConsistency, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    fn fix(rule: &MD128ListMarkerSpaceConsistency, content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_normalizes_to_first_item() {
+        let rule = MD128ListMarkerSpaceConsistency::new(true, 0);
+        let content = "-   One\n- Two\n-  Three\n    - Nested\n    -   Nested two\n-   Four\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.iter().map(|w| w.line).collect::<Vec<_>>(), vec![2, 3, 5]);
+        assert_eq!(
+            warnings[0].message,
+            "Spaces after list marker should match the rest of the list (Expected: 3; Actual: 1)"
+        );
+        assert_eq!(
+            fix(&rule, content),
+            "-   One\n-   Two\n-   Three\n    - Nested\n    - Nested two\n-   Four\n"
+        );
+        // Consistent lists, even with non-default spacing, pass
+        assert!(check(&rule, "-  a\n-  b\n\n1. x\n2. y\n").is_empty());
+    }
+
+    #[test]
+    fn test_configured_count_and_blockquotes() {
+        let rule = MD128ListMarkerSpaceConsistency::new(true, 1);
+        let content = "> -  q\n> -   r\n";
+        assert_eq!(check(&rule, content).len(), 2);
+        assert_eq!(fix(&rule, content), "> - q\n> - r\n");
+    }
+
+    #[test]
+    fn test_aligned_ordered_items_left_alone() {
+        let rule = MD128ListMarkerSpaceConsistency::new(true, 0);
+        assert!(check(&rule, "8.  Eight\n9.  Nine\n10. Ten\n").is_empty());
+        assert_eq!(fix(&rule, "9. Nine\n10.  Ten\n"), "9. Nine\n10. Ten\n");
+        // Empty items and tabs are not compared
+        assert!(check(&rule, "- a\n-\n-\tb\n").is_empty());
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let rule = MD128ListMarkerSpaceConsistency::default();
+        assert!(check(&rule, "- a\n-   b\n").is_empty());
+    }
+}
@@ -0,0 +1,342 @@
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
+
+static RUST_FN_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s*(pub(\([a-z]+\))? )?(async )?fn [A-Za-z_]\w*(<[^>]*>)?\(").unwrap());
+static PYTHON_DEF_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^\s*(async )?(def [A-Za-z_]\w*\(.*\)|class [A-Za-z_]\w*(\(.*\))?)( -> .+)?:\s*$").unwrap()
+});
+static GO_FUNC_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^func (\([^)]*\) )?[A-Za-z_]\w*\(").unwrap());
+static YAML_LINE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s*(- )?([A-Za-z_][\w.-]*:(\s.*)?|- .*|-)$").unwrap());
+static YAML_KEY_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\s*(- )?[A-Za-z_][\w.-]*:(\s|$)").unwrap());
+
+/// Configuration for MD129 (Missing code block languages should be inferred from the content)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD129Config {
+    /// Whether the rule is enabled (opt-in, like MD110)
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+impl RuleConfig for MD129Config {
+    const RULE_NAME: &'static str = "MD129";
+}
+
+/// A fenced code block without a language
+struct UnlabeledFence {
+    /// Zero-based line of the opening fence
+    line: usize,
+    /// Byte offset just past the fence marker
+    marker_end: usize,
+    /// Byte offset of the end of the opening fence line
+    line_end: usize,
+    code: String,
+}
+
+/// Rule MD129: Missing code block languages should be inferred from the content
+///
+/// See [docs/md129.md](../../docs/md129.md) for full documentation, configuration, and examples.
+///
+/// MD040 can only fill in a placeholder language. This rule reports the same fences but
+/// runs a small classifier over the code first: a shebang, content that parses as JSON,
+/// or unambiguous keywords such as `fn`/`def`/`func` and YAML `key:` lines. The fix is
+/// only offered when exactly one language matches; everything else stays a plain warning.
+#[derive(Debug, Clone, Default)]
+pub struct MD129CodeBlockLanguageInference {
+    config: MD129Config,
+}
+
+impl MD129CodeBlockLanguageInference {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            config: MD129Config { enabled },
+        }
+    }
+
+    pub fn from_config_struct(config: MD129Config) -> Self {
+        Self { config }
+    }
+
+    /// Fenced code blocks whose info string is empty
+    fn unlabeled_fences(ctx: &LintContext) -> Vec<UnlabeledFence> {
+        let mut fences = Vec::new();
+        let mut current: Option<UnlabeledFence> = None;
+        for (event, range) in Parser::new_ext(ctx.content, Options::empty()).into_offset_iter() {
+            match event {
+                Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))) if info.trim().is_empty() => {
+                    let (line, _) = ctx.offset_to_line_col(range.start);
+                    let Some(info) = ctx.lines.get(line - 1) else {
+                        continue;
+                    };
+                    let text = info.content(ctx.content);
+                    let fence_start = range.start - info.byte_offset;
+                    let marker_len = text[fence_start..]
+                        .bytes()
+                        .take_while(|&b| b == b'`' || b == b'~')
+                        .count();
+                    current = Some(UnlabeledFence {
+                        line: line - 1,
+                        marker_end: range.start + marker_len,
+                        line_end: info.byte_offset + text.len(),
+                        code: String::new(),
+                    });
+                }
+                Event::Text(text) => {
+                    if let Some(fence) = current.as_mut() {
+                        fence.code.push_str(&text);
+                    }
+                }
+                Event::End(TagEnd::CodeBlock) => fences.extend(current.take()),
+                _ => {}
+            }
+        }
+        fences
+    }
+
+    /// The language the code is written in, when the evidence points to exactly one
+    fn infer_language(code: &str) -> Option<&'static str> {
+        let trimmed = code.trim();
+        if trimmed.is_empty() {
+            return None;
+        }
+
+        let first_line = trimmed.lines().next().unwrap_or("");
+        if let Some(interpreter) = first_line.strip_prefix("#!") {
+            return Self::shebang_language(interpreter);
+        }
+
+        let mut candidates = Vec::new();
+        if (trimmed.starts_with('{') || trimmed.starts_with('['))
+            && serde_json::from_str::<serde_json::Value>(trimmed).is_ok()
+        {
+            candidates.push("json");
+        }
+        let lines: Vec<&str> = code.lines().collect();
+        if lines.iter().any(|l| RUST_FN_REGEX.is_match(l))
+            && lines
+                .iter()
+                .any(|l| l.contains("let ") || l.contains(" -> ") || l.contains("::"))
+        {
+            candidates.push("rust");
+        }
+        if lines.iter().any(|l| PYTHON_DEF_REGEX.is_match(l)) {
+            candidates.push("python");
+        }
+        if lines.iter().any(|l| l.starts_with("package ")) && lines.iter().any(|l| GO_FUNC_REGEX.is_match(l)) {
+            candidates.push("go");
+        }
+        if Self::looks_like_yaml(&lines) {
+            candidates.push("yaml");
+        }
+
+        match candidates.as_slice() {
+            [language] => Some(language),
+            _ => None,
+        }
+    }
+
+    /// Language named by a shebang's interpreter (`/usr/bin/env python3`, `/bin/bash -e`)
+    fn shebang_language(interpreter: &str) -> Option<&'static str> {
+        let mut words = interpreter.split_whitespace();
+        let mut program = words.next()?.rsplit('/').next()?;
+        if program == "env" {
+            program = words.find(|w| !w.starts_with('-'))?;
+        }
+        let program = program.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.');
+        match program {
+            "sh" => Some("sh"),
+            "bash" => Some("bash"),
+            "zsh" => Some("zsh"),
+            "python" => Some("python"),
+            "ruby" => Some("ruby"),
+            "perl" => Some("perl"),
+            "node" => Some("javascript"),
+            _ => None,
+        }
+    }
+
+    /// Every line is a `key:` entry, list item or comment, with at least two keys
+    fn looks_like_yaml(lines: &[&str]) -> bool {
+        let content_lines: Vec<&str> = lines
+            .iter()
+            .copied()
+            .filter(|l| !l.trim().is_empty() && !l.trim_start().starts_with('#'))
+            .collect();
+        content_lines.first().is_some_and(|l| YAML_KEY_REGEX.is_match(l))
+            && content_lines.iter().all(|l| YAML_LINE_REGEX.is_match(l))
+            && content_lines.iter().filter(|l| YAML_KEY_REGEX.is_match(l)).count() >= 2
+            && serde_yaml::from_str::<serde_yaml::Value>(&lines.join("\n")).is_ok_and(|v| v.is_mapping())
+    }
+}
+
+impl Rule for MD129CodeBlockLanguageInference {
+    fn name(&self) -> &'static str {
+        "MD129"
+    }
+
+    fn description(&self) -> &'static str {
+        "Missing code block languages should be inferred from the content"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::CodeBlock
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !self.config.enabled || !ctx.likely_has_code()
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        if self.should_skip(ctx) {
+            return Ok(warnings);
+        }
+
+        for fence in Self::unlabeled_fences(ctx) {
+            let line = ctx.lines[fence.line].content(ctx.content);
+            let marker_column = fence.marker_end - ctx.lines[fence.line].byte_offset;
+            let column = line[..marker_column].chars().count() + 1;
+            let language = Self::infer_language(&fence.code);
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line: fence.line + 1,
+                column,
+                end_line: fence.line + 1,
+                end_column: line.chars().count() + 1,
+                message: match language {
+                    Some(language) => format!("Code block missing language (looks like {language})"),
+                    None => "Code block missing language (could not infer one confidently)".to_string(),
+                },
+                severity: Severity::Warning,
+                fix: language.map(|language| Fix {
+                    range: fence.marker_end..fence.line_end,
+                    replacement: language.to_string(),
+                }),
+            });
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+
+        let mut content = ctx.content.to_string();
+        for fix in warnings.into_iter().rev().filter_map(|w| w.fix) {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+
+        Ok(content)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::ConditionallyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD129Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD129CodeBlockLanguageInference::new(true).check(&ctx).unwrap()
+    }
+
+    fn fix(content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD129CodeBlockLanguageInference::new(true).fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_infers_language() {
+        let infer = MD129CodeBlockLanguageInference::infer_language;
+        assert_eq!(infer("#!/usr/bin/env python3\nprint(1)\n"), Some("python"));
+        assert_eq!(infer("#!/bin/bash -e\necho hi\n"), Some("bash"));
+        assert_eq!(infer("#!/usr/bin/env -S node\n"), Some("javascript"));
+        assert_eq!(infer("{\n  \"name\": \"rumdl\"\n}\n"), Some("json"));
+        assert_eq!(infer("fn main() {\n    let x = 1;\n}\n"), Some("rust"));
+        assert_eq!(infer("def greet(name):\n    print(name)\n"), Some("python"));
+        assert_eq!(infer("package main\n\nfunc main() {\n}\n"), Some("go"));
+        assert_eq!(infer("name: rumdl\nsteps:\n  - run: cargo test\n"), Some("yaml"));
+    }
+
+    #[test]
+    fn test_low_confidence_not_inferred() {
+        let infer = MD129CodeBlockLanguageInference::infer_language;
+        assert_eq!(infer("npm install rumdl\n"), None);
+        assert_eq!(infer("Note: this is prose\n"), None);
+        assert_eq!(infer("{ not json }\n"), None);
+        assert_eq!(infer("fn main() {}\n"), None);
+        assert_eq!(infer("#!/usr/bin/awk -f\n"), None);
+        assert_eq!(infer(""), None);
+    }
+
+    #[test]
+    fn test_warnings_and_fix() {
+        let content = "# Title\n\n```\n{\"a\": 1}\n```\n\n~~~~ \necho hi\n~~~~\n\n```rust\nfn x() {}\n```\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].line, 3);
+        assert_eq!(warnings[0].column, 4);
+        assert_eq!(warnings[0].message, "Code block missing language (looks like json)");
+        assert_eq!(
+            warnings[1].message,
+            "Code block missing language (could not infer one confidently)"
+        );
+        assert!(warnings[1].fix.is_none());
+        assert_eq!(
+            fix(content),
+            "# Title\n\n```json\n{\"a\": 1}\n```\n\n~~~~ \necho hi\n~~~~\n\n```rust\nfn x() {}\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_nested_fences() {
+        let content = "> ```\n> #!/bin/sh\n> ls\n> ```\n\n- item\n\n  ```\n  key: value\n  other: 2\n  ```\n";
+        assert_eq!(
+            fix(content),
+            "> ```sh\n> #!/bin/sh\n> ls\n> ```\n\n- item\n\n  ```yaml\n  key: value\n  other: 2\n  ```\n"
+        );
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let ctx = LintContext::new("```\n{}\n```\n", MarkdownFlavor::Standard, None);
+        assert!(
+            MD129CodeBlockLanguageInference::default()
+                .check(&ctx)
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,283 @@
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD130 (Documents should not be excessively long)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD130Config {
+    /// Whether the rule is enabled (opt-in, like MD110)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Maximum number of lines (0 for no limit)
+    #[serde(default = "default_max_lines", alias = "max_lines")]
+    pub max_lines: usize,
+
+    /// Maximum size in bytes (0 for no limit)
+    #[serde(default = "default_max_bytes", alias = "max_bytes")]
+    pub max_bytes: usize,
+}
+
+fn default_max_lines() -> usize {
+    10_000
+}
+
+fn default_max_bytes() -> usize {
+    1_000_000
+}
+
+impl Default for MD130Config {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_lines: default_max_lines(),
+            max_bytes: default_max_bytes(),
+        }
+    }
+}
+
+impl RuleConfig for MD130Config {
+    const RULE_NAME: &'static str = "MD130";
+}
+
+/// Rule MD130: Documents should not be excessively long
+///
+/// See [docs/md130.md](../../docs/md130.md) for full documentation, configuration, and examples.
+///
+/// Very large files are usually accidental concatenations or generated output. The line
+/// count and size are already known from the parsed context, so this rule only compares
+/// them with the limits and reports once, at the top of the document. Splitting a file is
+/// up to the author, so there is no fix.
+#[derive(Debug, Clone, Default)]
+pub struct MD130DocumentLength {
+    config: MD130Config,
+}
+
+impl MD130DocumentLength {
+    pub fn new(enabled: bool, max_lines: usize, max_bytes: usize) -> Self {
+        Self {
+            config: MD130Config {
+                enabled,
+                max_lines,
+                max_bytes,
+            },
+        }
+    }
+
+    pub fn from_config_struct(config: MD130Config) -> Self {
+        Self { config }
+    }
+}
+
+impl Rule for MD130DocumentLength {
+    fn name(&self) -> &'static str {
+        "MD130"
+    }
+
+    fn description(&self) -> &'static str {
+        "Documents should not be excessively long"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Other
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !self.config.enabled || ctx.content.is_empty()
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        if self.should_skip(ctx) {
+            return Ok(warnings);
+        }
+
+        let line_count = ctx.lines.len();
+        let byte_count = ctx.content.len();
+        let mut exceeded = Vec::new();
+        if self.config.max_lines > 0 && line_count > self.config.max_lines {
+            exceeded.push(format!("{line_count} lines (limit: {})", self.config.max_lines));
+        }
+        if self.config.max_bytes > 0 && byte_count > self.config.max_bytes {
+            exceeded.push(format!("{byte_count} bytes (limit: {})", self.config.max_bytes));
+        }
+        if exceeded.is_empty() {
+            return Ok(warnings);
+        }
+
+        let first_line = ctx.lines.first().map_or("", |line| line.content(ctx.content));
+        warnings.push(LintWarning {
+            rule_name: Some(self.name().to_string()),
+            line: 1,
+            column: 1,
+            end_line: 1,
+            end_column: first_line.chars().count() + 1,
+            message: format!(
+                "Document has {}; consider splitting it into smaller files",
+                exceeded.join(" and ")
+            ),
+            severity: Severity::Warning,
+            fix: None,
+        });
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        // Where to split a document is the author's decision
+        Ok(ctx.content.to_string())
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::Unfixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD130Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(rule: &MD130DocumentLength, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_within_limits() {
+        let rule = MD130DocumentLength::new(true, 3, 100);
+        assert!(check(&rule, "# Title\n\nText\n").is_empty());
+        assert!(check(&rule, "").is_empty());
+    }
+
+    #[test]
+    fn test_reports_once_at_line_one() {
+        let rule = MD130DocumentLength::new(true, 3, 0);
+        let content = "# Title\n\nOne\n\nTwo\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            (warnings[0].line, warnings[0].column, warnings[0].end_column),
+            (1, 1, 8)
+        );
+        assert_eq!(
+            warnings[0].message,
+            "Document has 5 lines (limit: 3); consider splitting it into smaller files"
+        );
+        assert!(warnings[0].fix.is_none());
+
+        let rule = MD130DocumentLength::new(true, 3, 10);
+        assert_eq!(
+            check(&rule, content)[0].message,
+            "Document has 5 lines (limit: 3) and 18 bytes (limit: 10); consider splitting it into smaller files"
+        );
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let rule = MD130DocumentLength::default();
+        assert!(check(&rule, &"line\n".repeat(20_000)).is_empty());
+    }
+
+    #[test]
+    fn test_limit_is_inclusive() {
+        let rule = MD130DocumentLength::new(true, 3, 14);
+        assert!(check(&rule, "# Title\n\nText\n").is_empty());
+        assert_eq!(check(&rule, "# Title\n\nText\nMore\n").len(), 1);
+    }
+
+    #[test]
+    fn test_zero_disables_a_limit() {
+        let rule = MD130DocumentLength::new(true, 0, 0);
+        assert!(check(&rule, &"line\n".repeat(20_000)).is_empty());
+
+        let bytes_only = MD130DocumentLength::new(true, 0, 10);
+        assert_eq!(
+            check(&bytes_only, "# Title\n\nSome text\n")[0].message,
+            "Document has 19 bytes (limit: 10); consider splitting it into smaller files"
+        );
+    }
+
+    #[test]
+    fn test_code_blocks_and_front_matter_count() {
+        // Length is about the file, so no content is skipped
+        let rule = MD130DocumentLength::new(true, 4, 0);
+        assert_eq!(check(&rule, "---\ntitle: x\n---\n\n# Title\n").len(), 1);
+        assert_eq!(check(&rule, "# Title\n\n```\ncode\n```\n").len(), 1);
+    }
+
+    #[test]
+    fn test_bytes_counted_not_characters() {
+        let rule = MD130DocumentLength::new(true, 0, 10);
+        // Five characters, fifteen bytes
+        assert_eq!(check(&rule, "日本語です").len(), 1);
+    }
+
+    #[test]
+    fn test_end_column_counts_characters() {
+        let rule = MD130DocumentLength::new(true, 1, 0);
+        let warnings = check(&rule, "# 日本語\n\nText\n");
+        assert_eq!(warnings[0].end_column, 6);
+    }
+
+    #[test]
+    fn test_no_trailing_newline() {
+        let rule = MD130DocumentLength::new(true, 2, 0);
+        assert!(check(&rule, "# Title\nText").is_empty());
+        assert_eq!(check(&rule, "# Title\nText\nMore").len(), 1);
+    }
+
+    #[test]
+    fn test_fix_leaves_content_unchanged() {
+        let rule = MD130DocumentLength::new(true, 1, 0);
+        let content = "# Title\n\nText\n";
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        assert_eq!(rule.fix(&ctx).unwrap(), content);
+        assert_eq!(rule.fix_capability(), FixCapability::Unfixable);
+    }
+
+    #[test]
+    fn test_config_keys() {
+        let mut config = Config::default();
+        let mut rule_config = crate::config::RuleConfig::default();
+        rule_config
+            .values
+            .insert("enabled".to_string(), toml::Value::Boolean(true));
+        rule_config
+            .values
+            .insert("max-lines".to_string(), toml::Value::Integer(2));
+        rule_config
+            .values
+            .insert("max_bytes".to_string(), toml::Value::Integer(0));
+        config.rules.insert("MD130".to_string(), rule_config);
+
+        let rule = MD130DocumentLength::from_config(&config);
+        let ctx = LintContext::new("# Title\n\nText\n", MarkdownFlavor::Standard, None);
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.starts_with("Document has 3 lines (limit: 2);"));
+    }
+}
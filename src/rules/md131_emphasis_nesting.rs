@@ -0,0 +1,322 @@
+use crate::lint_context::LintContext;
+use crate::rule::{FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::utils::skip_context::is_in_math_context;
+
+/// How a delimiter run can act, from the CommonMark flanking rules
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RunRole {
+    Open,
+    Close,
+    /// Intraword runs such as `foo*bar*`, which may do either
+    Either,
+}
+
+/// An emphasis opener that hasn't been closed yet
+struct OpenRun {
+    marker: u8,
+    len: usize,
+    line: usize,
+    column: usize,
+    /// An outer span closed while this one was open; reported once this one closes too
+    overlap: Option<LintWarning>,
+}
+
+/// Rule MD131: Emphasis and strong spans should nest, not overlap
+///
+/// See [docs/md131.md](../../docs/md131.md) for full documentation, configuration, and examples.
+///
+/// Walks the `*` and `_` delimiter runs of each paragraph with a stack of openers. A
+/// closer that matches an opener further down the stack, while a different one is still
+/// open above it, means the spans interleave (`**a *b** c*`) instead of nesting
+/// (`**a *b* c**`). CommonMark resolves that into something the author didn't write, so
+/// the early closer is reported, but only once the inner span is closed as well; an opener
+/// that is never closed is just a literal character. Which span should end first is a
+/// guess, so there is no fix. Runs of three or more markers are ambiguous and reset the scan.
+#[derive(Debug, Clone, Default)]
+pub struct MD131EmphasisNesting;
+
+impl MD131EmphasisNesting {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Role of the run `line[start..end]`, or `None` when it can't be emphasis
+    fn run_role(line: &str, start: usize, end: usize, marker: u8) -> Option<RunRole> {
+        let prev = line[..start].chars().next_back();
+        let next = line[end..].chars().next();
+        let is_space = |c: Option<char>| c.is_none_or(char::is_whitespace);
+        let is_punct = |c: Option<char>| {
+            c.is_some_and(|c| c.is_ascii_punctuation() || (!c.is_alphanumeric() && !c.is_whitespace()))
+        };
+
+        let left = !is_space(next) && (!is_punct(next) || is_space(prev) || is_punct(prev));
+        let right = !is_space(prev) && (!is_punct(prev) || is_space(next) || is_punct(next));
+        let (open, close) = if marker == b'_' {
+            (left && (!right || is_punct(prev)), right && (!left || is_punct(next)))
+        } else {
+            (left, right)
+        };
+
+        match (open, close) {
+            (true, false) => Some(RunRole::Open),
+            (false, true) => Some(RunRole::Close),
+            (true, true) => Some(RunRole::Either),
+            (false, false) => None,
+        }
+    }
+
+    /// Whether the line can't continue the previous paragraph's inline content
+    fn starts_block(ctx: &LintContext, idx: usize) -> bool {
+        let info = &ctx.lines[idx];
+        info.is_blank
+            || info.heading.is_some()
+            || info.list_item.is_some()
+            || ctx
+                .table_blocks()
+                .iter()
+                .any(|table| table.start_line <= idx && idx <= table.end_line)
+    }
+}
+
+impl Rule for MD131EmphasisNesting {
+    fn name(&self) -> &'static str {
+        "MD131"
+    }
+
+    fn description(&self) -> &'static str {
+        "Emphasis and strong spans should nest, not overlap"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Emphasis
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !ctx.likely_has_emphasis()
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        let mut open: Vec<OpenRun> = Vec::new();
+
+        for (idx, line_info) in ctx.lines.iter().enumerate() {
+            let skipped = line_info.in_code_block
+                || line_info.in_front_matter
+                || line_info.in_html_block
+                || line_info.in_html_comment
+                || line_info.in_mkdocstrings;
+            if skipped || Self::starts_block(ctx, idx) {
+                open.clear();
+            }
+            if skipped {
+                continue;
+            }
+
+            let line = line_info.content(ctx.content);
+            let bytes = line.as_bytes();
+            let mut i = 0;
+            while i < bytes.len() {
+                let marker = bytes[i];
+                if marker != b'*' && marker != b'_' {
+                    i += 1;
+                    continue;
+                }
+                let start = i;
+                while i < bytes.len() && bytes[i] == marker {
+                    i += 1;
+                }
+                let pos = line_info.byte_offset + start;
+                // Escaped markers, code, math and templates are literal text
+                if (start > 0 && bytes[start - 1] == b'\\')
+                    || ctx.is_in_code_block_or_span(pos)
+                    || ctx.is_in_html_comment(pos)
+                    || is_in_math_context(ctx, pos)
+                    || ctx.is_in_jinja_range(pos)
+                {
+                    continue;
+                }
+                let len = i - start;
+                if len >= 3 {
+                    open.clear();
+                    continue;
+                }
+                let Some(role) = Self::run_role(line, start, i, marker) else {
+                    continue;
+                };
+
+                let matches = |run: &OpenRun| run.marker == marker && run.len == len;
+                if role != RunRole::Open && open.last().is_some_and(matches) {
+                    warnings.extend(open.pop().and_then(|run| run.overlap));
+                    continue;
+                }
+                if role != RunRole::Close {
+                    open.push(OpenRun {
+                        marker,
+                        len,
+                        line: idx + 1,
+                        column: line[..start].chars().count() + 1,
+                        overlap: None,
+                    });
+                    continue;
+                }
+
+                // A closer for an outer span while an inner one is still open
+                let Some(outer) = open.iter().rposition(matches) else {
+                    continue;
+                };
+                let inner = open.last_mut().expect("outer opener is below another opener");
+                let inner_markers = String::from(inner.marker as char).repeat(inner.len);
+                let opened_at = if inner.line == idx + 1 {
+                    format!("column {}", inner.column)
+                } else {
+                    format!("line {}, column {}", inner.line, inner.column)
+                };
+                let column = line[..start].chars().count() + 1;
+                inner.overlap.get_or_insert(LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line: idx + 1,
+                    column,
+                    end_line: idx + 1,
+                    end_column: column + len,
+                    message: format!(
+                        "Overlapping emphasis: '{}' closes before the '{inner_markers}' opened at {opened_at} is closed",
+                        &line[start..i]
+                    ),
+                    severity: Severity::Warning,
+                    fix: None,
+                });
+                open.remove(outer);
+            }
+        }
+
+        warnings.sort_by_key(|w| (w.line, w.column));
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        // Which span should end first is the author's call
+        Ok(ctx.content.to_string())
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::Unfixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn from_config(_config: &crate::config::Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        Box::new(MD131EmphasisNesting)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD131EmphasisNesting.check(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_valid_nesting() {
+        assert!(check("**a *b* c** and *a **b** c* and _a __b__ c_\n").is_empty());
+        assert!(check("*one* **two** _three_ __four__ ***five***\n").is_empty());
+        assert!(check("snake_case_name and **Note:** text and foo*bar*baz\n").is_empty());
+        assert!(check("Globs like src/*.rs and **/*.md, and a * b * c\n").is_empty());
+        // An inner opener that never closes is a literal character, not an overlap
+        assert!(check("**See [`derive`][crate::_features].** and *a **b* c\n").is_empty());
+    }
+
+    #[test]
+    fn test_overlapping_spans() {
+        let warnings = check("Some **a *b** c* text\n");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            (warnings[0].line, warnings[0].column, warnings[0].end_column),
+            (1, 12, 14)
+        );
+        assert_eq!(
+            warnings[0].message,
+            "Overlapping emphasis: '**' closes before the '*' opened at column 10 is closed"
+        );
+        assert!(warnings[0].fix.is_none());
+
+        let warnings = check("*a __b* c__\n");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].column, 7);
+
+        // Spans can overlap across the lines of a paragraph
+        let warnings = check("**wrapped\ntext *here** more*\n");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 2);
+        assert!(warnings[0].message.ends_with("opened at column 6 is closed"));
+        let warnings = check("*wrapped **text\nhere* more**\n");
+        assert!(warnings[0].message.ends_with("opened at line 1, column 10 is closed"));
+    }
+
+    #[test]
+    fn test_skipped_contexts() {
+        assert!(check("`**a *b** c*`\n\n```\n**a *b** c*\n```\n").is_empty());
+        assert!(check("\\**a *b\\** c*\n").is_empty());
+        // Paragraph boundaries reset the scan
+        assert!(check("**a *b\n\nc** d*\n").is_empty());
+        assert!(check("- **a *b\n- c** d*\n").is_empty());
+    }
+
+    #[test]
+    fn test_front_matter_and_html_skipped() {
+        assert!(check("---\ntitle: \"**a *b** c*\"\n---\n\n# Title\n").is_empty());
+        assert!(check("<div>\n**a *b** c*\n</div>\n").is_empty());
+        assert!(check("<!-- **a *b** c* -->\n").is_empty());
+    }
+
+    #[test]
+    fn test_math_skipped() {
+        assert!(check("Inline $a*b*c = **x*$ math\n\n$$\n**a *b** c*\n$$\n").is_empty());
+    }
+
+    #[test]
+    fn test_triple_markers_reset_scan() {
+        assert!(check("***a *b** c*\n").is_empty());
+        assert!(check("**a *b *** c** d*\n").is_empty());
+    }
+
+    #[test]
+    fn test_headings_and_tables_are_separate_blocks() {
+        assert!(check("# **a *b\n\nc** d*\n").is_empty());
+        assert!(check("**a *b\n| x | y |\n| - | - |\n| c** | d* |\n").is_empty());
+        // Overlap inside a single heading is still reported
+        assert_eq!(check("## **a *b** c*\n").len(), 1);
+    }
+
+    #[test]
+    fn test_blockquote_overlap() {
+        let warnings = check("> Some **a *b** c* text\n");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].column, 14);
+    }
+
+    #[test]
+    fn test_multiple_overlaps_sorted() {
+        let warnings = check("*a __b* c__\n\n**d *e** f*\n");
+        assert_eq!(
+            warnings.iter().map(|w| (w.line, w.column)).collect::<Vec<_>>(),
+            vec![(1, 7), (3, 7)]
+        );
+    }
+
+    #[test]
+    fn test_fix_leaves_content_unchanged() {
+        let content = "Some **a *b** c* text\n";
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        assert_eq!(MD131EmphasisNesting.fix(&ctx).unwrap(), content);
+        assert_eq!(MD131EmphasisNesting.fix_capability(), FixCapability::Unfixable);
+    }
+}
@@ -0,0 +1,253 @@
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use crate::utils::range_utils::calculate_line_range;
+use crate::utils::table_utils::TableUtils;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD132 (Tables should not have too many columns)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD132Config {
+    /// Whether the rule is enabled (opt-in, like MD110)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Maximum number of columns in a table's header row
+    #[serde(default = "default_max_columns", alias = "max_columns")]
+    pub max_columns: usize,
+}
+
+fn default_max_columns() -> usize {
+    8
+}
+
+impl Default for MD132Config {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_columns: default_max_columns(),
+        }
+    }
+}
+
+impl RuleConfig for MD132Config {
+    const RULE_NAME: &'static str = "MD132";
+}
+
+/// Rule MD132: Tables should not have too many columns
+///
+/// See [docs/md132.md](../../docs/md132.md) for full documentation, configuration, and examples.
+///
+/// The header row defines how many columns a table is meant to have, so only it is counted;
+/// body rows with a different count are MD056's concern. A table that is too wide usually
+/// wants to be a list or several tables, which is a restructuring call, so there is no fix.
+#[derive(Debug, Clone, Default)]
+pub struct MD132TableColumnLimit {
+    config: MD132Config,
+}
+
+impl MD132TableColumnLimit {
+    pub fn new(enabled: bool, max_columns: usize) -> Self {
+        Self {
+            config: MD132Config { enabled, max_columns },
+        }
+    }
+
+    pub fn from_config_struct(config: MD132Config) -> Self {
+        Self { config }
+    }
+}
+
+impl Rule for MD132TableColumnLimit {
+    fn name(&self) -> &'static str {
+        "MD132"
+    }
+
+    fn description(&self) -> &'static str {
+        "Tables should not have too many columns"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Table
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !self.config.enabled || !ctx.likely_has_tables()
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        if self.should_skip(ctx) {
+            return Ok(warnings);
+        }
+
+        for table in ctx.table_blocks() {
+            // Table detection doesn't know about indented code blocks
+            let Some(line_info) = ctx.lines.get(table.header_line).filter(|info| !info.in_code_block) else {
+                continue;
+            };
+            let header = line_info.content(ctx.content);
+            let columns = TableUtils::count_cells_with_flavor(header, ctx.flavor);
+            if columns <= self.config.max_columns {
+                continue;
+            }
+
+            let (start_line, start_col, end_line, end_col) = calculate_line_range(table.header_line + 1, header);
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line: start_line,
+                column: start_col,
+                end_line,
+                end_column: end_col,
+                message: format!(
+                    "Table has {columns} columns (limit: {}); consider splitting it or using a list",
+                    self.config.max_columns
+                ),
+                severity: Severity::Warning,
+                fix: None,
+            });
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        // How to restructure a wide table is the author's decision
+        Ok(ctx.content.to_string())
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::Unfixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD132Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(rule: &MD132TableColumnLimit, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_within_limit() {
+        let rule = MD132TableColumnLimit::new(true, 3);
+        assert!(check(&rule, "| a | b | c |\n|---|---|---|\n| 1 | 2 | 3 |\n").is_empty());
+        // Escaped pipes are cell content
+        assert!(check(&rule, "| a \\| b | c | d |\n|---|---|---|\n").is_empty());
+    }
+
+    #[test]
+    fn test_wide_table() {
+        let rule = MD132TableColumnLimit::new(true, 3);
+        let content = "Text\n\n| a | b | c | d |\n|---|---|---|---|\n| 1 | 2 | 3 | 4 |\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!((warnings[0].line, warnings[0].column), (3, 1));
+        assert_eq!(
+            warnings[0].message,
+            "Table has 4 columns (limit: 3); consider splitting it or using a list"
+        );
+        assert!(warnings[0].fix.is_none());
+
+        // Body rows don't change the count; mismatches are MD056's job
+        let content = "| a | b |\n|---|---|\n| 1 | 2 | 3 | 4 |\n";
+        assert!(check(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let rule = MD132TableColumnLimit::default();
+        let header = format!("|{}\n|{}\n", " a |".repeat(20), "---|".repeat(20));
+        assert!(check(&rule, &header).is_empty());
+    }
+
+    #[test]
+    fn test_default_limit() {
+        let rule = MD132TableColumnLimit::new(true, default_max_columns());
+        let table = |n: usize| format!("|{}\n|{}\n", " a |".repeat(n), "---|".repeat(n));
+        assert!(check(&rule, &table(8)).is_empty());
+        assert_eq!(check(&rule, &table(9)).len(), 1);
+    }
+
+    #[test]
+    fn test_rows_without_outer_pipes() {
+        let rule = MD132TableColumnLimit::new(true, 2);
+        let warnings = check(&rule, "a | b | c\n--|--|--\n1 | 2 | 3\n");
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.starts_with("Table has 3 columns"));
+    }
+
+    #[test]
+    fn test_each_wide_table_reported() {
+        let rule = MD132TableColumnLimit::new(true, 2);
+        let content = "| a | b | c |\n|---|---|---|\n\nText\n\n| a | b |\n|---|---|\n\n| a | b | c |\n|---|---|---|\n";
+        let lines: Vec<usize> = check(&rule, content).iter().map(|w| w.line).collect();
+        assert_eq!(lines, vec![1, 9]);
+    }
+
+    #[test]
+    fn test_code_blocks_and_front_matter_skipped() {
+        let rule = MD132TableColumnLimit::new(true, 2);
+        let wide = "| a | b | c |\n|---|---|---|\n";
+        assert!(check(&rule, &format!("```\n{wide}```\n")).is_empty());
+        assert!(check(&rule, &format!("    {}\n    {}\n", "| a | b | c |", "|---|---|---|")).is_empty());
+        assert!(check(&rule, "---\ncols: \"| a | b | c |\"\n---\n\n# Title\n").is_empty());
+    }
+
+    #[test]
+    fn test_table_in_list() {
+        let rule = MD132TableColumnLimit::new(true, 2);
+        assert_eq!(check(&rule, "- Item\n\n  | a | b | c |\n  |---|---|---|\n").len(), 1);
+    }
+
+    #[test]
+    fn test_fix_leaves_content_unchanged() {
+        let rule = MD132TableColumnLimit::new(true, 1);
+        let content = "| a | b |\n|---|---|\n";
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        assert_eq!(rule.fix(&ctx).unwrap(), content);
+        assert_eq!(rule.fix_capability(), FixCapability::Unfixable);
+    }
+
+    #[test]
+    fn test_config_keys() {
+        let mut config = Config::default();
+        let mut rule_config = crate::config::RuleConfig::default();
+        rule_config
+            .values
+            .insert("enabled".to_string(), toml::Value::Boolean(true));
+        rule_config
+            .values
+            .insert("max_columns".to_string(), toml::Value::Integer(1));
+        config.rules.insert("MD132".to_string(), rule_config);
+
+        let rule = MD132TableColumnLimit::from_config(&config);
+        let ctx = LintContext::new("| a | b |\n|---|---|\n", MarkdownFlavor::Standard, None);
+        assert_eq!(rule.check(&ctx).unwrap().len(), 1);
+    }
+}
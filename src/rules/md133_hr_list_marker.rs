@@ -0,0 +1,314 @@
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use crate::utils::range_utils::calculate_line_range;
+use pulldown_cmark::{Event, Options, Parser};
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD133 (Thematic breaks should not use the marker of nearby lists)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD133Config {
+    /// Whether the rule is enabled (opt-in, like MD110)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// How many lines away a list item can be and still count as nearby
+    #[serde(default = "default_proximity")]
+    pub proximity: usize,
+}
+
+fn default_proximity() -> usize {
+    10
+}
+
+impl Default for MD133Config {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            proximity: default_proximity(),
+        }
+    }
+}
+
+impl RuleConfig for MD133Config {
+    const RULE_NAME: &'static str = "MD133";
+}
+
+/// Rule MD133: Thematic breaks should not use the marker of nearby lists
+///
+/// See [docs/md133.md](../../docs/md133.md) for full documentation, configuration, and examples.
+///
+/// `-` and `*` are both bullet markers and thematic break characters, so a `- - -` or
+/// `***` right next to a list with the same bullets reads like another list item. Thematic
+/// breaks come from the parser, which keeps setext underlines and front matter out; list
+/// items come from the line info. Each break within `proximity` lines of an unordered list
+/// item with the same character is reported. Which of the two to change is a style choice,
+/// so there is no fix.
+#[derive(Debug, Clone, Default)]
+pub struct MD133HrListMarker {
+    config: MD133Config,
+}
+
+impl MD133HrListMarker {
+    pub fn new(enabled: bool, proximity: usize) -> Self {
+        Self {
+            config: MD133Config { enabled, proximity },
+        }
+    }
+
+    pub fn from_config_struct(config: MD133Config) -> Self {
+        Self { config }
+    }
+
+    /// Zero-based line and character of every thematic break
+    fn thematic_breaks(ctx: &LintContext) -> Vec<(usize, char)> {
+        Parser::new_ext(ctx.content, Options::empty())
+            .into_offset_iter()
+            .filter_map(|(event, range)| {
+                if !matches!(event, Event::Rule) {
+                    return None;
+                }
+                let (line, _) = ctx.offset_to_line_col(range.start);
+                let marker = ctx.content[range.start..].trim_start().chars().next()?;
+                Some((line - 1, marker))
+            })
+            .filter(|&(line, _)| ctx.lines.get(line).is_some_and(|info| !info.in_front_matter))
+            .collect()
+    }
+}
+
+impl Rule for MD133HrListMarker {
+    fn name(&self) -> &'static str {
+        "MD133"
+    }
+
+    fn description(&self) -> &'static str {
+        "Thematic breaks should not use the marker of nearby lists"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Other
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !self.config.enabled || !ctx.likely_has_lists()
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        if self.should_skip(ctx) {
+            return Ok(warnings);
+        }
+
+        let breaks = Self::thematic_breaks(ctx);
+        if breaks.is_empty() {
+            return Ok(warnings);
+        }
+
+        // A spaced break like `- - -` also looks like a list item to the line scanner
+        let bullets: Vec<(usize, char)> = ctx
+            .lines
+            .iter()
+            .enumerate()
+            .filter(|(idx, info)| {
+                !info.in_code_block && !info.in_front_matter && !breaks.iter().any(|(line, _)| line == idx)
+            })
+            .filter_map(|(idx, info)| {
+                let item = info.list_item.as_ref().filter(|item| !item.is_ordered)?;
+                Some((idx, item.marker.chars().next()?))
+            })
+            .collect();
+        if bullets.is_empty() {
+            return Ok(warnings);
+        }
+
+        for (line, marker) in breaks {
+            let nearby = |&&(item_line, _): &&(usize, char)| item_line.abs_diff(line) <= self.config.proximity;
+            let Some(&(item_line, _)) = bullets
+                .iter()
+                .filter(nearby)
+                .filter(|(_, bullet)| *bullet == marker)
+                .min_by_key(|(item_line, _)| item_line.abs_diff(line))
+            else {
+                continue;
+            };
+
+            // `_` can't be a bullet, so it never clashes; prefer the other bullet character
+            let other = if marker == '-' { '*' } else { '-' };
+            let suggestion = if bullets.iter().filter(nearby).any(|(_, bullet)| *bullet == other) {
+                "___"
+            } else if other == '*' {
+                "***"
+            } else {
+                "---"
+            };
+
+            let text = ctx.lines[line].content(ctx.content);
+            let (start_line, start_col, end_line, end_col) = calculate_line_range(line + 1, text);
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line: start_line,
+                column: start_col,
+                end_line,
+                end_column: end_col,
+                message: format!(
+                    "Thematic break uses '{marker}' like the list item on line {}; use a different character, such as '{suggestion}'",
+                    item_line + 1
+                ),
+                severity: Severity::Warning,
+                fix: None,
+            });
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        // Whether the break or the list should change is a style choice
+        Ok(ctx.content.to_string())
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::Unfixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD133Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(rule: &MD133HrListMarker, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_distinct_markers() {
+        let rule = MD133HrListMarker::new(true, 10);
+        assert!(check(&rule, "- one\n- two\n\n***\n\n- three\n").is_empty());
+        assert!(check(&rule, "* one\n\n---\n\nText\n").is_empty());
+        // Setext underlines and front matter delimiters aren't thematic breaks
+        assert!(check(&rule, "---\ntitle: x\n---\n\nHeading\n---\n\n- item\n").is_empty());
+    }
+
+    #[test]
+    fn test_shared_marker() {
+        let rule = MD133HrListMarker::new(true, 10);
+        let warnings = check(&rule, "- one\n- two\n\n- - -\n\nText\n");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!((warnings[0].line, warnings[0].column), (4, 1));
+        assert_eq!(
+            warnings[0].message,
+            "Thematic break uses '-' like the list item on line 2; use a different character, such as '***'"
+        );
+        assert!(warnings[0].fix.is_none());
+
+        // With both bullets nearby, only `_` is unambiguous
+        let warnings = check(&rule, "* one\n\n***\n\n- two\n");
+        assert!(warnings[0].message.ends_with("such as '___'"));
+    }
+
+    #[test]
+    fn test_proximity() {
+        let rule = MD133HrListMarker::new(true, 2);
+        let content = "- one\n\nText\n\nMore\n\n---\n";
+        assert!(check(&rule, content).is_empty());
+        assert_eq!(check(&MD133HrListMarker::new(true, 6), content).len(), 1);
+        assert!(check(&MD133HrListMarker::default(), content).is_empty());
+    }
+
+    #[test]
+    fn test_asterisk_break_suggests_dashes() {
+        let rule = MD133HrListMarker::new(true, 10);
+        let warnings = check(&rule, "Text\n\n* * *\n\n* one\n* two\n");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].message,
+            "Thematic break uses '*' like the list item on line 5; use a different character, such as '---'"
+        );
+    }
+
+    #[test]
+    fn test_underscore_and_ordered_lists_never_clash() {
+        let rule = MD133HrListMarker::new(true, 10);
+        assert!(check(&rule, "- one\n- two\n\n___\n\n* three\n").is_empty());
+        assert!(check(&rule, "1. one\n2. two\n\n---\n\n3) three\n").is_empty());
+    }
+
+    #[test]
+    fn test_nearest_item_reported() {
+        let rule = MD133HrListMarker::new(true, 10);
+        let warnings = check(&rule, "- one\n\nText\n\n---\n\n- two\n");
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("list item on line 7"));
+
+        // Nested items count too
+        let warnings = check(&rule, "* outer\n  - inner\n\n---\n");
+        assert!(warnings[0].message.contains("list item on line 2"));
+    }
+
+    #[test]
+    fn test_code_blocks_skipped() {
+        let rule = MD133HrListMarker::new(true, 10);
+        assert!(check(&rule, "- one\n\n```\n---\n```\n").is_empty());
+        assert!(check(&rule, "```\n- one\n```\n\n---\n").is_empty());
+        assert!(check(&rule, "Text\n\n    - one\n\n---\n").is_empty());
+    }
+
+    #[test]
+    fn test_zero_proximity() {
+        let rule = MD133HrListMarker::new(true, 0);
+        assert!(check(&rule, "- one\n\n---\n").is_empty());
+    }
+
+    #[test]
+    fn test_fix_leaves_content_unchanged() {
+        let rule = MD133HrListMarker::new(true, 10);
+        let content = "- one\n\n---\n";
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        assert_eq!(rule.fix(&ctx).unwrap(), content);
+        assert_eq!(rule.fix_capability(), FixCapability::Unfixable);
+    }
+
+    #[test]
+    fn test_config_keys() {
+        let mut config = Config::default();
+        let mut rule_config = crate::config::RuleConfig::default();
+        rule_config
+            .values
+            .insert("enabled".to_string(), toml::Value::Boolean(true));
+        rule_config
+            .values
+            .insert("proximity".to_string(), toml::Value::Integer(1));
+        config.rules.insert("MD133".to_string(), rule_config);
+
+        let rule = MD133HrListMarker::from_config(&config);
+        let ctx = LintContext::new("- one\n\nText\n\n---\n", MarkdownFlavor::Standard, None);
+        assert!(rule.check(&ctx).unwrap().is_empty());
+        let ctx = LintContext::new("- one\n---\n", MarkdownFlavor::Standard, None);
+        assert_eq!(rule.check(&ctx).unwrap().len(), 1);
+    }
+}
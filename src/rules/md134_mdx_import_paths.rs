@@ -0,0 +1,250 @@
+use crate::config::{Config, MarkdownFlavor};
+use crate::lint_context::LintContext;
+use crate::rule::{FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::LazyLock;
+
+/// The module specifier of `import ... from "x"`, `export ... from "x"` or `import "x"`
+static IMPORT_SOURCE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?:\bfrom|^\s*import)\s*(["'])([^"']+)["']"#).unwrap());
+
+/// Configuration for MD134 (MDX import paths should resolve)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD134Config {
+    /// Extensions tried, in order, for imports written without one and for `index` files
+    #[serde(default = "default_extensions")]
+    pub extensions: Vec<String>,
+}
+
+fn default_extensions() -> Vec<String> {
+    ["js", "jsx", "ts", "tsx", "mjs", "cjs", "mdx", "md", "json"]
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+impl Default for MD134Config {
+    fn default() -> Self {
+        Self {
+            extensions: default_extensions(),
+        }
+    }
+}
+
+impl RuleConfig for MD134Config {
+    const RULE_NAME: &'static str = "MD134";
+}
+
+/// Rule MD134: MDX import paths should resolve
+///
+/// See [docs/md134.md](../../docs/md134.md) for full documentation, configuration, and examples.
+///
+/// Checks the relative module specifiers (`./` and `../`) of the import and export
+/// statements at the top of an MDX file. A specifier resolves if it names an existing
+/// file, the same path with one of the configured extensions, or a directory with an
+/// `index` file. Bare package imports are left to the bundler.
+///
+/// Documents without a known file path (e.g. unnamed stdin) are not checked.
+#[derive(Debug, Clone, Default)]
+pub struct MD134MdxImportPaths {
+    config: MD134Config,
+}
+
+impl MD134MdxImportPaths {
+    pub fn new(extensions: Vec<String>) -> Self {
+        Self::from_config_struct(MD134Config { extensions })
+    }
+
+    pub fn from_config_struct(config: MD134Config) -> Self {
+        Self { config }
+    }
+
+    fn is_relative(specifier: &str) -> bool {
+        specifier.starts_with("./") || specifier.starts_with("../")
+    }
+
+    fn resolves(&self, path: &Path) -> bool {
+        if path.is_file() {
+            return true;
+        }
+
+        let with_extension = |base: &Path| {
+            self.config.extensions.iter().any(|ext| {
+                let mut candidate = base.as_os_str().to_owned();
+                candidate.push(".");
+                candidate.push(ext.trim_start_matches('.'));
+                Path::new(&candidate).is_file()
+            })
+        };
+        with_extension(path) || (path.is_dir() && with_extension(&path.join("index")))
+    }
+}
+
+impl Rule for MD134MdxImportPaths {
+    fn name(&self) -> &'static str {
+        "MD134"
+    }
+
+    fn description(&self) -> &'static str {
+        "MDX import paths should resolve"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Other
+    }
+
+    fn applies_to_flavor(&self, flavor: MarkdownFlavor) -> bool {
+        flavor.supports_esm_blocks()
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        ctx.source_file.is_none() || !ctx.lines.iter().any(|l| l.in_esm_block)
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        if self.should_skip(ctx) {
+            return Ok(Vec::new());
+        }
+
+        let source_dir = ctx
+            .source_file
+            .as_deref()
+            .and_then(Path::parent)
+            .unwrap_or(Path::new(""));
+        let mut warnings = Vec::new();
+
+        for (idx, line_info) in ctx.lines.iter().enumerate() {
+            if !line_info.in_esm_block {
+                continue;
+            }
+
+            let line = line_info.content(ctx.content);
+            for caps in IMPORT_SOURCE.captures_iter(line) {
+                let specifier = caps.get(2).unwrap();
+                // Query strings and fragments (`./data.json?raw`) are bundler hints
+                let file = specifier.as_str().split(['?', '#']).next().unwrap_or_default();
+                if !Self::is_relative(file) || self.resolves(&source_dir.join(file)) {
+                    continue;
+                }
+
+                let column = line[..specifier.start()].chars().count() + 1;
+                warnings.push(LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line: idx + 1,
+                    column,
+                    end_line: idx + 1,
+                    end_column: column + specifier.as_str().chars().count(),
+                    message: format!("Import path not found: {}", specifier.as_str()),
+                    severity: Severity::Warning,
+                    fix: None,
+                });
+            }
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        Ok(ctx.content.to_string())
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::Unfixable
+    }
+
+    fn cacheable(&self) -> bool {
+        // Imported modules can be created or deleted without this file changing
+        false
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD134Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn check_in(dir: &Path, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::MDX, Some(dir.join("docs/page.mdx")));
+        MD134MdxImportPaths::default().check(&ctx).unwrap()
+    }
+
+    fn setup() -> tempfile::TempDir {
+        let dir = tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("docs/components/Tabs")).unwrap();
+        fs::write(dir.path().join("docs/components/Chart.tsx"), "").unwrap();
+        fs::write(dir.path().join("docs/components/Tabs/index.jsx"), "").unwrap();
+        fs::write(dir.path().join("docs/data.json"), "{}").unwrap();
+        fs::write(dir.path().join("shared.js"), "").unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_resolving_imports() {
+        let dir = setup();
+        let content = "import Chart from './components/Chart'\n\
+                       import Tabs from \"./components/Tabs\"\n\
+                       import data from './data.json?raw'\n\
+                       import { helper } from '../shared'\n\
+                       export { default as Shared } from '../shared.js'\n\
+                       import React from 'react'\n\
+                       \n\
+                       # Title\n";
+        assert!(check_in(dir.path(), content).is_empty());
+    }
+
+    #[test]
+    fn test_missing_import() {
+        let dir = setup();
+        let content = "import Chart from './components/Chart'\nimport Table from './components/Table'\n\n# Title\n";
+        let warnings = check_in(dir.path(), content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!((warnings[0].line, warnings[0].column), (2, 20));
+        assert_eq!(warnings[0].message, "Import path not found: ./components/Table");
+
+        // Side-effect imports are checked too
+        assert_eq!(check_in(dir.path(), "import './styles.css'\n").len(), 1);
+    }
+
+    #[test]
+    fn test_only_esm_block_is_checked() {
+        let dir = setup();
+        let content = "# Title\n\nimport Missing from './missing'\n\n```js\nimport x from './gone'\n```\n";
+        assert!(check_in(dir.path(), content).is_empty());
+    }
+
+    #[test]
+    fn test_requires_mdx_and_source_file() {
+        let rule = MD134MdxImportPaths::default();
+        let content = "import Missing from './missing'\n";
+
+        assert!(!rule.applies_to_flavor(MarkdownFlavor::Standard));
+
+        let ctx = LintContext::new(content, MarkdownFlavor::MDX, None);
+        assert!(rule.check(&ctx).unwrap().is_empty());
+    }
+}
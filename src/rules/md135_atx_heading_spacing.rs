@@ -0,0 +1,254 @@
+use crate::config::Config;
+use crate::lint_context::{HeadingStyle, LintContext};
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD135 (ATX heading text should be separated from its markers by one space)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD135Config {
+    /// Whether the rule is enabled (opt-in, like MD110)
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+impl RuleConfig for MD135Config {
+    const RULE_NAME: &'static str = "MD135";
+}
+
+/// The parts of an ATX heading line after its opening marker
+struct AtxSpacing<'a> {
+    /// Whitespace between the opening marker and the text
+    opening_gap: &'a str,
+    text: &'a str,
+    /// Whitespace between the text and the closing sequence, if the heading is closed
+    closing_gap: &'a str,
+    closing: &'a str,
+}
+
+/// Rule MD135: ATX heading text should be separated from its markers by one space
+///
+/// See [docs/md135.md](../../docs/md135.md) for full documentation, configuration, and examples.
+///
+/// MD018 through MD021 each check one side of the text for one kind of mistake, so a
+/// heading like `##Title  ##` gets two warnings and two fixes. This rule reports each
+/// heading once and rewrites both sides in a single edit. A heading like `#Title#` with
+/// no space on either side is treated as closed, as MD020 does; `# C#` is not.
+#[derive(Debug, Clone, Default)]
+pub struct MD135AtxHeadingSpacing {
+    config: MD135Config,
+}
+
+impl MD135AtxHeadingSpacing {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            config: MD135Config { enabled },
+        }
+    }
+
+    pub fn from_config_struct(config: MD135Config) -> Self {
+        Self { config }
+    }
+
+    /// Split what follows the opening marker; `None` for empty headings
+    fn split(after_marker: &str, closed: bool) -> Option<AtxSpacing<'_>> {
+        let inner = after_marker.trim_start_matches([' ', '\t']);
+        if inner.is_empty() {
+            return None;
+        }
+        let opening_gap = &after_marker[..after_marker.len() - inner.len()];
+
+        let without_closing = inner.trim_end_matches('#');
+        let closing = &inner[without_closing.len()..];
+        let text = without_closing.trim_end_matches([' ', '\t']);
+        let closing_gap = &without_closing[text.len()..];
+
+        let unspaced_closed = opening_gap.is_empty() && closing_gap.is_empty();
+        if closing.is_empty() || text.is_empty() || !(closed || unspaced_closed) {
+            return Some(AtxSpacing {
+                opening_gap,
+                text: inner,
+                closing_gap: "",
+                closing: "",
+            });
+        }
+
+        Some(AtxSpacing {
+            opening_gap,
+            text,
+            closing_gap,
+            closing,
+        })
+    }
+}
+
+impl Rule for MD135AtxHeadingSpacing {
+    fn name(&self) -> &'static str {
+        "MD135"
+    }
+
+    fn description(&self) -> &'static str {
+        "ATX heading text should be separated from its markers by one space"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Heading
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !self.config.enabled || !ctx.likely_has_headings()
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        if self.should_skip(ctx) {
+            return Ok(warnings);
+        }
+
+        for (idx, line_info) in ctx.lines.iter().enumerate() {
+            let Some(heading) = &line_info.heading else {
+                continue;
+            };
+            if !matches!(heading.style, HeadingStyle::ATX) || line_info.in_html_block {
+                continue;
+            }
+
+            let line = line_info.content(ctx.content);
+            let marker_end = heading.marker_column + heading.marker.len();
+            let after_marker = line[marker_end..].trim_end();
+            // `#️⃣` is a keycap emoji, not a marker followed by text (see MD018)
+            if after_marker.starts_with(['\u{FE0F}', '\u{20E3}']) {
+                continue;
+            }
+            let Some(parts) = Self::split(after_marker, heading.has_closing_sequence) else {
+                continue;
+            };
+
+            let closed = !parts.closing.is_empty();
+            if parts.opening_gap == " " && (!closed || parts.closing_gap == " ") {
+                continue;
+            }
+
+            let replacement = if closed {
+                format!(" {} {}", parts.text, parts.closing)
+            } else {
+                format!(" {}", parts.text)
+            };
+            let message = if closed {
+                format!(
+                    "Expected one space inside {} and {} on closed heading",
+                    heading.marker, parts.closing
+                )
+            } else {
+                format!("Expected one space after {} in heading", heading.marker)
+            };
+
+            let start = ctx.line_offsets[idx] + marker_end;
+            let column = line[..marker_end].chars().count() + 1;
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line: idx + 1,
+                column,
+                end_line: idx + 1,
+                end_column: column + after_marker.chars().count(),
+                message,
+                severity: Severity::Warning,
+                fix: Some(Fix {
+                    range: start..start + after_marker.len(),
+                    replacement,
+                }),
+            });
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+
+        let mut content = ctx.content.to_string();
+        for fix in warnings.into_iter().rev().filter_map(|w| w.fix) {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+
+        Ok(content)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::FullyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD135Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD135AtxHeadingSpacing::new(true).check(&ctx).unwrap()
+    }
+
+    fn fix(content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD135AtxHeadingSpacing::new(true).fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_single_space() {
+        assert!(check("# Title\n\n## Closed ##\n\n### C#\n\n#\n\nText\n===\n").is_empty());
+        assert!(check("#️⃣ Keycap\n").is_empty());
+        assert!(check("```\n#  Not a heading\n```\n").is_empty());
+    }
+
+    #[test]
+    fn test_open_headings() {
+        let warnings = check("#Title\n\n##   Spaced\n\n#\tTab\n");
+        assert_eq!(warnings.len(), 3);
+        assert_eq!((warnings[0].line, warnings[0].column), (1, 2));
+        assert_eq!(warnings[0].message, "Expected one space after # in heading");
+        assert_eq!(
+            fix("#Title\n\n##   Spaced\n\n#\tTab\n"),
+            "# Title\n\n## Spaced\n\n# Tab\n"
+        );
+    }
+
+    #[test]
+    fn test_closed_headings() {
+        let content = "#Title#\n\n##  Both  ##\n\n##Right  ##\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 3);
+        assert_eq!(
+            warnings[1].message,
+            "Expected one space inside ## and ## on closed heading"
+        );
+        assert_eq!(fix(content), "# Title #\n\n## Both ##\n\n## Right ##\n");
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let ctx = LintContext::new("#Title\n", MarkdownFlavor::Standard, None);
+        assert!(MD135AtxHeadingSpacing::default().check(&ctx).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,318 @@
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
+
+/// A line that would be a reference definition if its URL had no spaces:
+/// label, URL (possibly with spaces), optional title
+static SPACED_DEFINITION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^[ ]{0,3}\[([^\]]+)\]:[ \t]*([a-zA-Z][a-zA-Z0-9+.-]*://[^"'(<>]*?)[ \t]*((?:"[^"]*"|'[^']*'|\([^)]*\))?)[ \t]*$"#)
+        .unwrap()
+});
+
+/// Characters that end a sentence rather than a URL
+const TRAILING_PUNCTUATION: &[char] = &['.', ',', ';', ':', '!', '?'];
+
+/// Configuration for MD136 (Reference definition URLs should be well-formed)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD136Config {
+    /// Whether the rule is enabled (opt-in, like MD110)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Also flag `http://` URLs, for sites known to serve HTTPS (local hosts are exempt)
+    #[serde(default, alias = "require_https")]
+    pub require_https: bool,
+}
+
+impl RuleConfig for MD136Config {
+    const RULE_NAME: &'static str = "MD136";
+}
+
+/// Rule MD136: Reference definition URLs should be well-formed
+///
+/// See [docs/md136.md](../../docs/md136.md) for full documentation, configuration, and examples.
+///
+/// Definitions usually sit at the bottom of a file, away from the links that use them, so
+/// a bad URL there goes unnoticed. Three problems are reported at the definition line:
+/// sentence punctuation copied along with the URL, spaces in the URL (which stop the line
+/// from being a definition at all, so it is matched separately from `reference_defs`), and,
+/// with `require-https`, plain `http://`.
+#[derive(Debug, Clone, Default)]
+pub struct MD136ReferenceDefinitionUrls {
+    config: MD136Config,
+}
+
+impl MD136ReferenceDefinitionUrls {
+    pub fn new(enabled: bool, require_https: bool) -> Self {
+        Self {
+            config: MD136Config { enabled, require_https },
+        }
+    }
+
+    pub fn from_config_struct(config: MD136Config) -> Self {
+        Self { config }
+    }
+
+    /// Length of the punctuation at the end of `url` that isn't part of it
+    ///
+    /// A closing parenthesis only counts when it has no opening partner, so
+    /// `https://en.wikipedia.org/wiki/Rust_(programming_language)` is left alone.
+    fn trailing_punctuation_len(url: &str) -> usize {
+        let mut end = url.len();
+        loop {
+            let rest = &url[..end];
+            let unbalanced_paren = rest.ends_with(')') && rest.matches(')').count() > rest.matches('(').count();
+            if !rest.ends_with(TRAILING_PUNCTUATION) && !unbalanced_paren {
+                return url.len() - end;
+            }
+            end -= 1;
+        }
+    }
+
+    fn is_local_host(url: &str) -> bool {
+        let host = url
+            .trim_start_matches("http://")
+            .split(['/', ':', '?', '#'])
+            .next()
+            .unwrap_or_default();
+        host == "localhost" || host.ends_with(".localhost") || host.starts_with("127.") || host == "[::1]"
+    }
+
+    /// What is wrong with a definition URL and the URL to use instead
+    fn problem(&self, url: &str) -> Option<(String, String)> {
+        let punctuation = Self::trailing_punctuation_len(url);
+        if punctuation > 0 && punctuation < url.len() {
+            let (kept, dropped) = url.split_at(url.len() - punctuation);
+            return Some((
+                format!("Reference definition URL ends with '{dropped}', which is probably not part of it"),
+                kept.to_string(),
+            ));
+        }
+
+        if self.config.require_https && url.starts_with("http://") && !Self::is_local_host(url) {
+            return Some((
+                "Reference definition URL uses http://; use https://".to_string(),
+                format!("https://{}", &url["http://".len()..]),
+            ));
+        }
+
+        None
+    }
+
+    fn warning(
+        &self,
+        ctx: &LintContext,
+        line: usize,
+        start: usize,
+        url: &str,
+        message: String,
+        replacement: String,
+    ) -> LintWarning {
+        let line_info = &ctx.lines[line - 1];
+        let text = line_info.content(ctx.content);
+        let column = text[..start].chars().count() + 1;
+        LintWarning {
+            rule_name: Some(self.name().to_string()),
+            line,
+            column,
+            end_line: line,
+            end_column: column + url.chars().count(),
+            message,
+            severity: Severity::Warning,
+            fix: Some(Fix {
+                range: line_info.byte_offset + start..line_info.byte_offset + start + url.len(),
+                replacement,
+            }),
+        }
+    }
+}
+
+impl Rule for MD136ReferenceDefinitionUrls {
+    fn name(&self) -> &'static str {
+        "MD136"
+    }
+
+    fn description(&self) -> &'static str {
+        "Reference definition URLs should be well-formed"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Link
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !self.config.enabled || !ctx.content.contains("]:")
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        if self.should_skip(ctx) {
+            return Ok(warnings);
+        }
+
+        for def in &ctx.reference_defs {
+            // Footnote definitions hold prose, not a URL
+            if def.id.starts_with('^') {
+                continue;
+            }
+            let text = ctx.lines[def.line - 1].content(ctx.content);
+            let Some(label_end) = text.find("]:") else {
+                continue;
+            };
+            let Some(url_start) = text[label_end..].find(def.url.as_str()).map(|pos| label_end + pos) else {
+                continue;
+            };
+
+            // `<url>` keeps its brackets; only the URL inside is checked
+            let (url_start, url) = match def.url.strip_prefix('<').and_then(|url| url.strip_suffix('>')) {
+                Some(inner) => (url_start + 1, inner),
+                None => (url_start, def.url.as_str()),
+            };
+            if let Some((message, replacement)) = self.problem(url) {
+                warnings.push(self.warning(ctx, def.line, url_start, url, message, replacement));
+            }
+        }
+
+        for (idx, line_info) in ctx.lines.iter().enumerate() {
+            if line_info.in_code_block
+                || line_info.in_front_matter
+                || line_info.in_html_block
+                || ctx.reference_defs.iter().any(|def| def.line == idx + 1)
+            {
+                continue;
+            }
+            let text = line_info.content(ctx.content);
+            let Some(caps) = SPACED_DEFINITION.captures(text) else {
+                continue;
+            };
+            let url = caps.get(2).unwrap();
+            if caps[1].starts_with('^') || !url.as_str().contains([' ', '\t']) {
+                continue;
+            }
+            warnings.push(self.warning(
+                ctx,
+                idx + 1,
+                url.start(),
+                url.as_str(),
+                "Reference definition URL contains whitespace, so the line is not a definition".to_string(),
+                url.as_str().split_whitespace().collect::<Vec<_>>().join("%20"),
+            ));
+        }
+
+        warnings.sort_by_key(|w| (w.line, w.column));
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+
+        let mut content = ctx.content.to_string();
+        for fix in warnings.into_iter().rev().filter_map(|w| w.fix) {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+
+        Ok(content)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::FullyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD136Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(rule: &MD136ReferenceDefinitionUrls, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    fn fix(rule: &MD136ReferenceDefinitionUrls, content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_valid_definitions() {
+        let rule = MD136ReferenceDefinitionUrls::new(true, true);
+        let content = "[a]: https://example.com/\n\
+                       [b]: https://en.wikipedia.org/wiki/Rust_(programming_language)\n\
+                       [c]: <https://example.com/a b> \"Title\"\n\
+                       [d]: http://localhost:8080/docs\n\
+                       [^note]: See the appendix.\n";
+        assert!(check(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_trailing_punctuation() {
+        let rule = MD136ReferenceDefinitionUrls::new(true, false);
+        let content = "See [docs].\n\n[docs]: https://example.com/docs.\n[api]: <https://example.com/api),> \"API\"\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!((warnings[0].line, warnings[0].column), (3, 9));
+        assert_eq!(
+            warnings[0].message,
+            "Reference definition URL ends with '.', which is probably not part of it"
+        );
+        assert_eq!(
+            fix(&rule, content),
+            "See [docs].\n\n[docs]: https://example.com/docs\n[api]: <https://example.com/api> \"API\"\n"
+        );
+    }
+
+    #[test]
+    fn test_whitespace() {
+        let rule = MD136ReferenceDefinitionUrls::new(true, false);
+        let content = "[guide]: https://example.com/user guide.pdf \"Guide\"\n\n[note]: some words here\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].column, 10);
+        assert_eq!(
+            fix(&rule, content),
+            "[guide]: https://example.com/user%20guide.pdf \"Guide\"\n\n[note]: some words here\n"
+        );
+    }
+
+    #[test]
+    fn test_require_https() {
+        let content = "[a]: http://example.com/page\n";
+        assert!(check(&MD136ReferenceDefinitionUrls::new(true, false), content).is_empty());
+
+        let rule = MD136ReferenceDefinitionUrls::new(true, true);
+        assert_eq!(check(&rule, content).len(), 1);
+        assert_eq!(fix(&rule, content), "[a]: https://example.com/page\n");
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let rule = MD136ReferenceDefinitionUrls::default();
+        assert!(check(&rule, "[a]: https://example.com/.\n").is_empty());
+    }
+}
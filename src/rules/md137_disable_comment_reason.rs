@@ -0,0 +1,349 @@
+use crate::config::{Config, InlineCommentSyntax};
+use crate::inline_config::find_disable_directives;
+use crate::lint_context::LintContext;
+use crate::rule::{FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// Configuration for MD137 (Inline disable comments should give a reason)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD137Config {
+    /// Whether the rule is enabled (opt-in, like MD110)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Severity of the reported warnings ("warning" or "error")
+    #[serde(default = "default_severity")]
+    pub severity: Severity,
+
+    /// Git revision whose reason-less comments are allowed to stay, e.g. the commit or tag
+    /// where the requirement was introduced
+    #[serde(default, skip_serializing_if = "Option::is_none", alias = "baseline_ref")]
+    pub baseline_ref: Option<String>,
+}
+
+fn default_severity() -> Severity {
+    Severity::Warning
+}
+
+impl Default for MD137Config {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            severity: default_severity(),
+            baseline_ref: None,
+        }
+    }
+}
+
+impl RuleConfig for MD137Config {
+    const RULE_NAME: &'static str = "MD137";
+}
+
+/// Rule MD137: Inline disable comments should give a reason
+///
+/// See [docs/md137.md](../../docs/md137.md) for full documentation, configuration, and examples.
+///
+/// Disable, disable-line, disable-next-line, disable-file and trailing `rumdl:` comments
+/// need a `reason: ...` after their rule list. Enable, capture and restore comments don't
+/// suppress anything and are not checked.
+///
+/// With `baseline-ref`, comments on lines that `git blame` attributes to that revision or
+/// its ancestors are left alone, so the requirement only applies going forward.
+#[derive(Debug, Clone, Default)]
+pub struct MD137DisableCommentReason {
+    config: MD137Config,
+}
+
+impl MD137DisableCommentReason {
+    pub fn new(enabled: bool) -> Self {
+        Self::from_config_struct(MD137Config {
+            enabled,
+            ..Default::default()
+        })
+    }
+
+    pub fn from_config_struct(config: MD137Config) -> Self {
+        Self { config }
+    }
+
+    /// Lines among `lines` that were last changed in `baseline_ref` or one of its ancestors
+    ///
+    /// Blames the content being linted rather than the file on disk, so unsaved editor
+    /// buffers work. Outside a repository (or without git) nothing is grandfathered.
+    fn lines_in_baseline(path: &Path, content: &str, lines: &[usize], baseline_ref: &str) -> HashSet<usize> {
+        let dir = path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        let Some(file_name) = path.file_name() else {
+            return HashSet::new();
+        };
+
+        let mut command = Command::new("git");
+        command
+            .current_dir(dir)
+            .args(["blame", "--line-porcelain", "--contents", "-"]);
+        for line in lines {
+            command.arg(format!("-L{line},{line}"));
+        }
+        command.arg("--").arg(file_name);
+
+        let Ok(mut child) = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+        else {
+            return HashSet::new();
+        };
+        if let Some(mut stdin) = child.stdin.take() {
+            // A write error means git exited early; its status below reports the failure
+            let _ = stdin.write_all(content.as_bytes());
+        }
+        let Ok(output) = child.wait_with_output() else {
+            return HashSet::new();
+        };
+        if !output.status.success() {
+            return HashSet::new();
+        }
+
+        // Each blamed line starts with `<commit> <original line> <final line> ...`
+        let mut commit_lines: HashMap<String, Vec<usize>> = HashMap::new();
+        for header in String::from_utf8_lossy(&output.stdout).lines() {
+            let mut fields = header.split(' ');
+            let (Some(commit), Some(_), Some(final_line)) = (fields.next(), fields.next(), fields.next()) else {
+                continue;
+            };
+            let is_commit = commit.len() >= 40 && commit.bytes().all(|b| b.is_ascii_hexdigit());
+            // Uncommitted lines are blamed on the all-zero commit
+            if !is_commit || commit.bytes().all(|b| b == b'0') {
+                continue;
+            }
+            if let Ok(final_line) = final_line.parse() {
+                commit_lines.entry(commit.to_string()).or_default().push(final_line);
+            }
+        }
+
+        commit_lines
+            .into_iter()
+            .filter(|(commit, _)| {
+                Command::new("git")
+                    .current_dir(dir)
+                    .args(["merge-base", "--is-ancestor", commit, baseline_ref])
+                    .stderr(Stdio::null())
+                    .status()
+                    .is_ok_and(|status| status.success())
+            })
+            .flat_map(|(_, lines)| lines)
+            .collect()
+    }
+}
+
+impl Rule for MD137DisableCommentReason {
+    fn name(&self) -> &'static str {
+        "MD137"
+    }
+
+    fn description(&self) -> &'static str {
+        "Inline disable comments should give a reason"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Other
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !self.config.enabled || !(ctx.content.contains("-disable") || ctx.content.contains("rumdl:"))
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        if self.should_skip(ctx) {
+            return Ok(warnings);
+        }
+
+        let syntax = InlineCommentSyntax::for_flavor(ctx.flavor);
+        for (idx, line_info) in ctx.lines.iter().enumerate() {
+            if line_info.in_code_block {
+                continue;
+            }
+
+            let line = line_info.content(ctx.content);
+            for directive in find_disable_directives(line, syntax) {
+                if directive.reason.is_some() {
+                    continue;
+                }
+
+                let column = line[..directive.start].chars().count() + 1;
+                warnings.push(LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line: idx + 1,
+                    column,
+                    end_line: idx + 1,
+                    end_column: column + line[directive.start..directive.end].chars().count(),
+                    message: format!("{} comment has no reason (add 'reason: ...')", directive.name),
+                    severity: self.config.severity,
+                    fix: None,
+                });
+            }
+        }
+
+        if let (Some(baseline_ref), Some(path)) = (&self.config.baseline_ref, &ctx.source_file)
+            && !warnings.is_empty()
+        {
+            let mut lines: Vec<usize> = warnings.iter().map(|w| w.line).collect();
+            lines.dedup();
+            let grandfathered = Self::lines_in_baseline(path, ctx.content, &lines, baseline_ref);
+            warnings.retain(|w| !grandfathered.contains(&w.line));
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        Ok(ctx.content.to_string())
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::Unfixable
+    }
+
+    fn honors_blanket_disable(&self) -> bool {
+        // `<!-- rumdl-disable-file -->` must not hide its own missing reason
+        false
+    }
+
+    fn cacheable(&self) -> bool {
+        // Which lines predate the baseline depends on git history, not just the content
+        self.config.baseline_ref.is_none()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD137Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+    use std::fs;
+
+    fn check(rule: &MD137DisableCommentReason, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_comments_with_reasons() {
+        let rule = MD137DisableCommentReason::new(true);
+        let content = "<!-- rumdl-disable MD013 reason: generated table -->\n\
+                       Text <!-- rumdl-disable-line MD033 reason: embed -->\n\
+                       <!-- markdownlint-disable-next-line reason: vendored -->\n\
+                       Text <!-- rumdl: MD013 reason: long URL -->\n\
+                       <!-- rumdl-enable MD013 -->\n\
+                       <!-- rumdl-capture -->\n\
+                       \n\
+                       ```markdown\n\
+                       <!-- rumdl-disable -->\n\
+                       ```\n";
+        assert!(check(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_comments_without_reasons() {
+        let rule = MD137DisableCommentReason::new(true);
+        let content = "<!-- rumdl-disable-file -->\n\
+                       Text <!-- markdownlint-disable-line MD013 -->\n\
+                       Text <!-- rumdl: MD033 reason: -->\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 3);
+        assert_eq!(
+            warnings[0].message,
+            "rumdl-disable-file comment has no reason (add 'reason: ...')"
+        );
+        assert_eq!(
+            (warnings[1].line, warnings[1].column, warnings[1].end_column),
+            (2, 6, 46)
+        );
+        assert_eq!(warnings[2].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn test_severity_and_jsx_comments() {
+        let config: MD137Config = toml::from_str("enabled = true\nseverity = \"error\"").unwrap();
+        let rule = MD137DisableCommentReason::from_config_struct(config);
+        let ctx = LintContext::new("{/* rumdl-disable MD013 */}\n\n# Title\n", MarkdownFlavor::MDX, None);
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        assert!(check(&MD137DisableCommentReason::default(), "<!-- rumdl-disable -->\n").is_empty());
+    }
+
+    #[test]
+    fn test_baseline_ref_grandfathers_existing_comments() {
+        let dir = tempfile::tempdir().unwrap();
+        let git = |args: &[&str]| {
+            let status = Command::new("git")
+                .current_dir(dir.path())
+                .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+                .args(args)
+                .stdout(Stdio::null())
+                .status()
+                .unwrap();
+            assert!(status.success(), "git {args:?} failed");
+        };
+        let path = dir.path().join("doc.md");
+
+        git(&["init", "-q"]);
+        fs::write(&path, "# Doc\n\n<!-- rumdl-disable MD013 -->\n").unwrap();
+        git(&["add", "doc.md"]);
+        git(&["commit", "-q", "-m", "old"]);
+        git(&["tag", "reasons-required"]);
+        fs::write(
+            &path,
+            "# Doc\n\n<!-- rumdl-disable MD013 -->\n<!-- rumdl-disable MD033 -->\n",
+        )
+        .unwrap();
+        git(&["commit", "-q", "-a", "-m", "new"]);
+
+        let rule = MD137DisableCommentReason::from_config_struct(MD137Config {
+            enabled: true,
+            baseline_ref: Some("reasons-required".to_string()),
+            ..Default::default()
+        });
+        assert!(!rule.cacheable());
+
+        // Committed after the baseline, and not yet committed at all
+        let content = "# Doc\n\n<!-- rumdl-disable MD013 -->\n<!-- rumdl-disable MD033 -->\n<!-- rumdl-disable -->\n";
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, Some(path));
+        let lines: Vec<usize> = rule.check(&ctx).unwrap().iter().map(|w| w.line).collect();
+        assert_eq!(lines, vec![4, 5]);
+    }
+}
@@ -0,0 +1,203 @@
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD138 (Heading text should not be a single link)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD138Config {
+    /// Whether the rule is enabled (opt-in, like MD110)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Fix by replacing the link with its text. Off by default because the URL is dropped
+    #[serde(default)]
+    pub unlink: bool,
+}
+
+impl RuleConfig for MD138Config {
+    const RULE_NAME: &'static str = "MD138";
+}
+
+/// Rule MD138: Heading text should not be a single link
+///
+/// See [docs/md138.md](../../docs/md138.md) for full documentation, configuration, and examples.
+///
+/// Some renderers and table-of-contents generators build the heading anchor or TOC entry
+/// from the rendered link rather than the text, so `## [Title](url)` ends up with a broken
+/// anchor or a nested link. Headings where the link is only part of the text are fine.
+/// Undefined references like `## [Title][missing]` render as plain text and are ignored.
+#[derive(Debug, Clone, Default)]
+pub struct MD138HeadingLink {
+    config: MD138Config,
+}
+
+impl MD138HeadingLink {
+    pub fn new(enabled: bool, unlink: bool) -> Self {
+        Self {
+            config: MD138Config { enabled, unlink },
+        }
+    }
+
+    pub fn from_config_struct(config: MD138Config) -> Self {
+        Self { config }
+    }
+}
+
+impl Rule for MD138HeadingLink {
+    fn name(&self) -> &'static str {
+        "MD138"
+    }
+
+    fn description(&self) -> &'static str {
+        "Heading text should not be a single link"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Heading
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !self.config.enabled || ctx.links.is_empty() || !ctx.likely_has_headings()
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        if self.should_skip(ctx) {
+            return Ok(warnings);
+        }
+
+        for link in &ctx.links {
+            // Undefined references have no URL and render as plain text
+            if link.is_reference && link.url.is_empty() {
+                continue;
+            }
+            let line_info = &ctx.lines[link.line - 1];
+            let Some(heading) = &line_info.heading else {
+                continue;
+            };
+            if ctx.content[link.byte_offset..link.byte_end] != *heading.text.trim() {
+                continue;
+            }
+
+            let line = line_info.content(ctx.content);
+            let column = line[..link.start_col].chars().count() + 1;
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line: link.line,
+                column,
+                end_line: link.line,
+                end_column: column + ctx.content[link.byte_offset..link.byte_end].chars().count(),
+                message: format!("Heading text is entirely a link; use plain text '{}'", link.text),
+                severity: Severity::Warning,
+                fix: self.config.unlink.then(|| Fix {
+                    range: link.byte_offset..link.byte_end,
+                    replacement: link.text.to_string(),
+                }),
+            });
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+
+        let mut content = ctx.content.to_string();
+        for fix in warnings.into_iter().rev().filter_map(|w| w.fix) {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+
+        Ok(content)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        if self.config.unlink {
+            FixCapability::FullyFixable
+        } else {
+            FixCapability::Unfixable
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD138Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(rule: &MD138HeadingLink, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    fn fix(rule: &MD138HeadingLink, content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_link_as_part_of_heading() {
+        let rule = MD138HeadingLink::new(true, true);
+        let content = "# Using [rumdl](https://example.com)\n\n\
+                       ## [Install](install.md) guide\n\n\
+                       ### [Missing][nowhere]\n\n\
+                       Text with [a link](https://example.com).\n";
+        assert!(check(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_heading_is_a_link() {
+        let rule = MD138HeadingLink::new(true, false);
+        let content =
+            "# Title\n\n## [Install](install.md)\n\n### [API][api] ###\n\n[Setext](x.md)\n---\n\n[api]: api.md\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 3);
+        assert_eq!(
+            (warnings[0].line, warnings[0].column, warnings[0].end_column),
+            (3, 4, 25)
+        );
+        assert_eq!(
+            warnings[0].message,
+            "Heading text is entirely a link; use plain text 'Install'"
+        );
+        assert_eq!(warnings[1].line, 5);
+        assert_eq!(warnings[2].line, 7);
+        assert!(warnings.iter().all(|w| w.fix.is_none()));
+        assert_eq!(fix(&rule, content), content);
+    }
+
+    #[test]
+    fn test_unlink_fix() {
+        let rule = MD138HeadingLink::new(true, true);
+        let content = "## [Install `rumdl`](install.md) {#install}\n\n[Setext](x.md)\n===\n";
+        assert_eq!(check(&rule, content).len(), 2);
+        assert_eq!(fix(&rule, content), "## Install `rumdl` {#install}\n\nSetext\n===\n");
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        assert!(check(&MD138HeadingLink::default(), "# [Title](url)\n").is_empty());
+    }
+}
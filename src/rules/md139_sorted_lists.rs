@@ -0,0 +1,340 @@
+use crate::config::Config;
+use crate::lint_context::{LintContext, ListBlock};
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
+
+/// Marks the list that follows it as one that must stay sorted
+const SORTED_MARKER: &str = "<!-- rumdl-sorted -->";
+
+/// Link destinations and reference labels, which shouldn't affect the order
+static LINK_TARGET: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\]\([^)]*\)|\]\[[^\]]*\]").unwrap());
+
+/// Configuration for MD139 (Designated lists should be in alphabetical order)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD139Config {
+    /// Whether the rule is enabled (opt-in, like MD110)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Headings whose sections hold sorted lists (matched case-insensitively)
+    #[serde(default)]
+    pub headings: Vec<String>,
+
+    /// Sort uppercase before lowercase instead of ignoring case
+    #[serde(default, alias = "case_sensitive")]
+    pub case_sensitive: bool,
+
+    /// Leading words to skip when comparing items, such as `["a", "an", "the"]`
+    #[serde(default)]
+    pub articles: Vec<String>,
+}
+
+impl RuleConfig for MD139Config {
+    const RULE_NAME: &'static str = "MD139";
+}
+
+/// A top-level item of a sorted list
+struct SortedItem {
+    /// Line of the item marker (1-indexed)
+    line: usize,
+    /// Byte range of the item's lines, without trailing blank lines
+    range: std::ops::Range<usize>,
+    /// Item text with markup removed, for messages
+    label: String,
+    key: String,
+}
+
+/// Rule MD139: Designated lists should be in alphabetical order
+///
+/// See [docs/md139.md](../../docs/md139.md) for full documentation, configuration, and examples.
+///
+/// A list is checked when it follows a `<!-- rumdl-sorted -->` comment or is a top-level list
+/// in a section whose heading is listed in `headings`. Only unordered lists are checked,
+/// since numbering already implies an order. Nested items move with their parent and
+/// blank lines between items stay where they are, so loose lists stay loose.
+#[derive(Debug, Clone, Default)]
+pub struct MD139SortedLists {
+    config: MD139Config,
+}
+
+impl MD139SortedLists {
+    pub fn from_config_struct(config: MD139Config) -> Self {
+        Self { config }
+    }
+
+    /// Whether the list is marked with a comment or sits under a configured heading
+    fn is_designated(&self, ctx: &LintContext, block: &ListBlock) -> bool {
+        let mut preceding = ctx.lines[..block.start_line - 1].iter().rev();
+        if let Some(line) = preceding.clone().find(|line| !line.is_blank)
+            && line.content(ctx.content).trim() == SORTED_MARKER
+        {
+            return true;
+        }
+
+        !self.config.headings.is_empty()
+            && preceding.find_map(|line| line.heading.as_ref()).is_some_and(|heading| {
+                self.config
+                    .headings
+                    .iter()
+                    .any(|name| name.trim().eq_ignore_ascii_case(heading.text.trim()))
+            })
+    }
+
+    /// Text an item is sorted by, and the same text for messages
+    fn sort_key(&self, text: &str) -> (String, String) {
+        let label: String = LINK_TARGET
+            .replace_all(text, "]")
+            .chars()
+            .filter(|c| !matches!(c, '*' | '_' | '`' | '[' | ']'))
+            .collect();
+        let label = label.trim().to_string();
+
+        let mut key = label.as_str();
+        if let Some((first, rest)) = key.split_once(' ')
+            && self.config.articles.iter().any(|a| a.eq_ignore_ascii_case(first))
+        {
+            key = rest.trim_start();
+        }
+        let key = if self.config.case_sensitive {
+            key.to_string()
+        } else {
+            key.to_lowercase()
+        };
+        (label, key)
+    }
+
+    /// The top-level items of a list, each with its nested items and continuation lines
+    fn items(&self, ctx: &LintContext, block: &ListBlock) -> Vec<SortedItem> {
+        let Some(marker_column) = ctx.lines[block.start_line - 1]
+            .list_item
+            .as_ref()
+            .map(|item| item.marker_column)
+        else {
+            return Vec::new();
+        };
+        let starts: Vec<usize> = block
+            .item_lines
+            .iter()
+            .copied()
+            .filter(|&line| {
+                ctx.lines[line - 1]
+                    .list_item
+                    .as_ref()
+                    .is_some_and(|item| item.marker_column == marker_column)
+            })
+            .collect();
+
+        starts
+            .iter()
+            .enumerate()
+            .map(|(i, &start)| {
+                let next = starts.get(i + 1).map_or(block.end_line + 1, |&next| next);
+                let end = (start..next)
+                    .rev()
+                    .find(|&line| !ctx.lines[line - 1].is_blank)
+                    .unwrap_or(start);
+                let first = &ctx.lines[start - 1];
+                let last = &ctx.lines[end - 1];
+                let content_column = first.list_item.as_ref().map_or(0, |item| item.content_column);
+                let text = first.content(ctx.content).get(content_column..).unwrap_or_default();
+                let (label, key) = self.sort_key(text);
+                SortedItem {
+                    line: start,
+                    range: first.byte_offset..last.byte_offset + last.byte_len,
+                    label,
+                    key,
+                }
+            })
+            .collect()
+    }
+}
+
+impl Rule for MD139SortedLists {
+    fn name(&self) -> &'static str {
+        "MD139"
+    }
+
+    fn description(&self) -> &'static str {
+        "Designated lists should be in alphabetical order"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::List
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !self.config.enabled
+            || ctx.list_blocks().is_empty()
+            || (self.config.headings.is_empty() && !ctx.content.contains(SORTED_MARKER))
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        if self.should_skip(ctx) {
+            return Ok(warnings);
+        }
+
+        for block in ctx.list_blocks() {
+            if block.is_ordered || block.nesting_level > 0 || !self.is_designated(ctx, block) {
+                continue;
+            }
+
+            let items = self.items(ctx, block);
+            let Some(pos) = items.windows(2).position(|pair| pair[0].key > pair[1].key) else {
+                continue;
+            };
+
+            let mut sorted: Vec<&SortedItem> = items.iter().collect();
+            sorted.sort_by(|a, b| a.key.cmp(&b.key));
+            let mut replacement = String::new();
+            for (i, item) in sorted.iter().enumerate() {
+                replacement.push_str(&ctx.content[item.range.clone()]);
+                if let Some(next) = items.get(i + 1) {
+                    replacement.push_str(&ctx.content[items[i].range.end..next.range.start]);
+                }
+            }
+
+            let (out_of_order, before) = (&items[pos + 1], &items[pos]);
+            let line = out_of_order.line;
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line,
+                column: 1,
+                end_line: line,
+                end_column: ctx.lines[line - 1].content(ctx.content).chars().count() + 1,
+                message: format!(
+                    "List is not in alphabetical order: '{}' should come before '{}'",
+                    out_of_order.label, before.label
+                ),
+                severity: Severity::Warning,
+                fix: Some(Fix {
+                    range: items[0].range.start..items[items.len() - 1].range.end,
+                    replacement,
+                }),
+            });
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+
+        let mut content = ctx.content.to_string();
+        for fix in warnings.into_iter().rev().filter_map(|w| w.fix) {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+
+        Ok(content)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::FullyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD139Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn rule(headings: &[&str]) -> MD139SortedLists {
+        MD139SortedLists::from_config_struct(MD139Config {
+            enabled: true,
+            headings: headings.iter().map(|h| h.to_string()).collect(),
+            ..Default::default()
+        })
+    }
+
+    fn check(rule: &MD139SortedLists, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    fn fix(rule: &MD139SortedLists, content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_only_designated_lists() {
+        let content = "- b\n- a\n\n<!-- rumdl-sorted -->\n- **Apple**\n- [banana](b.md)\n- Cherry\n\n1. z\n2. a\n";
+        assert!(check(&rule(&[]), content).is_empty());
+        assert!(check(&MD139SortedLists::default(), "<!-- rumdl-sorted -->\n- b\n- a\n").is_empty());
+    }
+
+    #[test]
+    fn test_marker_comment() {
+        let rule = rule(&[]);
+        let content = "<!-- rumdl-sorted -->\n\n- Windows\n- [Linux](linux.md)\n- macOS\n\nText\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 4);
+        assert_eq!(
+            warnings[0].message,
+            "List is not in alphabetical order: 'Linux' should come before 'Windows'"
+        );
+        assert_eq!(
+            fix(&rule, content),
+            "<!-- rumdl-sorted -->\n\n- [Linux](linux.md)\n- macOS\n- Windows\n\nText\n"
+        );
+    }
+
+    #[test]
+    fn test_heading_sections_keep_nested_items_and_spacing() {
+        let rule = rule(&["glossary"]);
+        let content = "# Glossary\n\n- Zeta\n  - nested z\n\n- Alpha\n\n  Continued.\n\n- Mu\n\n# Other\n\n- b\n- a\n";
+        assert_eq!(check(&rule, content).len(), 1);
+        assert_eq!(
+            fix(&rule, content),
+            "# Glossary\n\n- Alpha\n\n  Continued.\n\n- Mu\n\n- Zeta\n  - nested z\n\n# Other\n\n- b\n- a\n"
+        );
+    }
+
+    #[test]
+    fn test_case_and_articles() {
+        let content = "<!-- rumdl-sorted -->\n- apple\n- The Banana\n- Cherry\n";
+        assert!(!check(&rule(&[]), content).is_empty());
+
+        let with_articles = MD139SortedLists::from_config_struct(MD139Config {
+            enabled: true,
+            articles: vec!["the".to_string()],
+            ..Default::default()
+        });
+        assert!(check(&with_articles, content).is_empty());
+
+        let case_sensitive = MD139SortedLists::from_config_struct(MD139Config {
+            enabled: true,
+            case_sensitive: true,
+            ..Default::default()
+        });
+        assert_eq!(
+            fix(&case_sensitive, "<!-- rumdl-sorted -->\n- apple\n- Banana\n"),
+            "<!-- rumdl-sorted -->\n- Banana\n- apple\n"
+        );
+    }
+}
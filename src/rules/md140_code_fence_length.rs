@@ -0,0 +1,239 @@
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use crate::utils::code_block_utils::CodeBlockUtils;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD140 (Code fences should be no longer than their content requires)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD140Config {
+    /// Whether the rule is enabled (opt-in, like MD110)
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+impl RuleConfig for MD140Config {
+    const RULE_NAME: &'static str = "MD140";
+}
+
+/// Split a line into its container prefix (indentation, blockquote markers) and the rest
+fn split_prefix(line: &str) -> (&str, &str) {
+    let prefix_len = line.len() - line.trim_start_matches(|c: char| c.is_whitespace() || c == '>').len();
+    line.split_at(prefix_len)
+}
+
+/// Length of the run of `fence_char` that `body` starts with
+fn run_len(body: &str, fence_char: char) -> usize {
+    body.chars().take_while(|c| *c == fence_char).count()
+}
+
+/// Rule MD140: Code fences should be no longer than their content requires
+///
+/// See [docs/md140.md](../../docs/md140.md) for full documentation, configuration, and examples.
+///
+/// A fence only has to be longer than any run of its character that starts a line inside
+/// the block, since only such a line could close it early. Runs elsewhere in a line don't
+/// matter. A block that shows a ```` ``` ```` sample keeps its four-backtick fence; one that
+/// doesn't is shortened to three, and a closing fence longer than the opening one is
+/// trimmed to match. Unclosed blocks are left alone.
+#[derive(Debug, Clone, Default)]
+pub struct MD140CodeFenceLength {
+    config: MD140Config,
+}
+
+impl MD140CodeFenceLength {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            config: MD140Config { enabled },
+        }
+    }
+
+    pub fn from_config_struct(config: MD140Config) -> Self {
+        Self { config }
+    }
+}
+
+impl Rule for MD140CodeFenceLength {
+    fn name(&self) -> &'static str {
+        "MD140"
+    }
+
+    fn description(&self) -> &'static str {
+        "Code fences should be no longer than their content requires"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::CodeBlock
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !self.config.enabled
+            || ctx.code_blocks.is_empty()
+            || (!ctx.content.contains("````") && !ctx.content.contains("~~~~"))
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        if self.should_skip(ctx) {
+            return Ok(warnings);
+        }
+
+        for (block_start, block_end) in CodeBlockUtils::detect_fenced_code_blocks(ctx.content) {
+            let first_line = ctx.offset_to_line_col(block_start).0;
+            let last_line = ctx.offset_to_line_col(block_end.saturating_sub(1).max(block_start)).0;
+            if last_line <= first_line {
+                continue;
+            }
+
+            let opening = &ctx.lines[first_line - 1];
+            let (open_prefix, open_body) = split_prefix(opening.content(ctx.content));
+            let Some(fence_char) = open_body.chars().next().filter(|c| *c == '`' || *c == '~') else {
+                continue;
+            };
+            let open_len = run_len(open_body, fence_char);
+
+            let closing = &ctx.lines[last_line - 1];
+            let (close_prefix, close_body) = split_prefix(closing.content(ctx.content));
+            let close_len = run_len(close_body, fence_char);
+            if close_len < open_len || !close_body[close_len..].trim().is_empty() {
+                // The block runs to the end of its container without a closing fence
+                continue;
+            }
+
+            let longest_inner_run = ctx.lines[first_line..last_line - 1]
+                .iter()
+                .map(|line| run_len(split_prefix(line.content(ctx.content)).1, fence_char))
+                .max()
+                .unwrap_or(0);
+            let needed = (longest_inner_run + 1).max(3);
+            if open_len < needed || (open_len == needed && close_len == needed) {
+                continue;
+            }
+
+            let chars = if fence_char == '`' { "backticks" } else { "tildes" };
+            let message = if open_len > needed {
+                format!("Code fence uses {open_len} {chars} where {needed} are enough")
+            } else {
+                format!("Closing code fence uses {close_len} {chars}; expected {needed} to match the opening fence")
+            };
+
+            // Fence characters are ASCII, so run lengths are also byte lengths
+            let open_start = opening.byte_offset + open_prefix.len();
+            let close_start = closing.byte_offset + close_prefix.len();
+            let fence = fence_char.to_string().repeat(needed);
+            let column = open_prefix.chars().count() + 1;
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line: first_line,
+                column,
+                end_line: first_line,
+                end_column: column + open_len,
+                message,
+                severity: Severity::Warning,
+                fix: Some(Fix {
+                    range: open_start..close_start + close_len,
+                    replacement: format!("{fence}{}{fence}", &ctx.content[open_start + open_len..close_start]),
+                }),
+            });
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+
+        let mut content = ctx.content.to_string();
+        for fix in warnings.into_iter().rev().filter_map(|w| w.fix) {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+
+        Ok(content)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::FullyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD140Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD140CodeFenceLength::new(true).check(&ctx).unwrap()
+    }
+
+    fn fix(content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        MD140CodeFenceLength::new(true).fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_minimal_fences() {
+        let content = "```rust\nlet s = \"````\";\n```\n\n````markdown\n```bash\nls\n```\n````\n\n~~~\n~~ text\n~~~\n";
+        assert!(check(content).is_empty());
+    }
+
+    #[test]
+    fn test_long_fences() {
+        let content = "`````python\nprint('``')\n`````\n\n~~~~\ntext\n~~~~~~\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(
+            (warnings[0].line, warnings[0].column, warnings[0].end_column),
+            (1, 1, 6)
+        );
+        assert_eq!(warnings[0].message, "Code fence uses 5 backticks where 3 are enough");
+        assert_eq!(fix(content), "```python\nprint('``')\n```\n\n~~~\ntext\n~~~\n");
+    }
+
+    #[test]
+    fn test_nested_samples_keep_needed_length() {
+        let content = "``````markdown\n````text\ninner\n````\n``````\n";
+        assert_eq!(fix(content), "`````markdown\n````text\ninner\n````\n`````\n");
+    }
+
+    #[test]
+    fn test_closing_fence_longer_than_opening() {
+        let content = "> ```\n> code\n> ````\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].message,
+            "Closing code fence uses 4 backticks; expected 3 to match the opening fence"
+        );
+        assert_eq!(fix(content), "> ```\n> code\n> ```\n");
+    }
+
+    #[test]
+    fn test_unclosed_and_disabled() {
+        assert!(check("````\ncode\n").is_empty());
+        let ctx = LintContext::new("````\ncode\n````\n", MarkdownFlavor::Standard, None);
+        assert!(MD140CodeFenceLength::default().check(&ctx).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,331 @@
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{FixCapability, LintError, LintResult, Rule, RuleCategory};
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "native")]
+use crate::link_checker::{LinkCheckOptions, LinkStatus};
+#[cfg(feature = "native")]
+use crate::rule::{LintWarning, Severity};
+#[cfg(feature = "native")]
+use std::time::Duration;
+
+/// Configuration for MD141 (External links should be reachable)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD141Config {
+    /// Seconds allowed for each request, including redirects
+    #[serde(default = "default_timeout")]
+    pub timeout: u64,
+
+    /// Maximum number of requests in flight at once
+    #[serde(default = "default_concurrency")]
+    pub concurrency: usize,
+
+    /// Minimum milliseconds between requests to the same host
+    #[serde(default = "default_host_delay_ms", alias = "host_delay_ms")]
+    pub host_delay_ms: u64,
+
+    /// Hours a reachable result is reused from the cache
+    #[serde(default = "default_cache_ttl_hours", alias = "cache_ttl_hours")]
+    pub cache_ttl_hours: u64,
+
+    /// Hosts that are never requested; subdomains are included
+    #[serde(default = "default_exclude_hosts", alias = "exclude_hosts")]
+    pub exclude_hosts: Vec<String>,
+}
+
+fn default_timeout() -> u64 {
+    10
+}
+
+fn default_concurrency() -> usize {
+    8
+}
+
+fn default_host_delay_ms() -> u64 {
+    500
+}
+
+fn default_cache_ttl_hours() -> u64 {
+    24
+}
+
+fn default_exclude_hosts() -> Vec<String> {
+    ["localhost", "127.0.0.1", "example.com", "example.org", "example.net"]
+        .map(String::from)
+        .to_vec()
+}
+
+impl Default for MD141Config {
+    fn default() -> Self {
+        Self {
+            timeout: default_timeout(),
+            concurrency: default_concurrency(),
+            host_delay_ms: default_host_delay_ms(),
+            cache_ttl_hours: default_cache_ttl_hours(),
+            exclude_hosts: default_exclude_hosts(),
+        }
+    }
+}
+
+impl RuleConfig for MD141Config {
+    const RULE_NAME: &'static str = "MD141";
+}
+
+/// An external URL and where it appears
+#[cfg(feature = "native")]
+struct ExternalLink {
+    line: usize,
+    column: usize,
+    end_column: usize,
+    url: String,
+}
+
+/// Rule MD141: External links should be reachable
+///
+/// See [docs/md141.md](../../docs/md141.md) for full documentation, configuration, and examples.
+///
+/// Checking makes network requests, so the rule only runs when `rumdl check` is given
+/// `--check-links-online`; without it (and always in the LSP server and WASM build) it
+/// reports nothing. Inline links, autolinks, reference definitions and bare URLs are
+/// checked. The requests, rate limits and result cache live in `link_checker`.
+#[derive(Debug, Clone, Default)]
+pub struct MD141UnreachableLinks {
+    config: MD141Config,
+}
+
+impl MD141UnreachableLinks {
+    pub fn from_config_struct(config: MD141Config) -> Self {
+        Self { config }
+    }
+}
+
+#[cfg(feature = "native")]
+impl MD141UnreachableLinks {
+    fn is_excluded(&self, url: &str) -> bool {
+        let Some(host) = url::Url::parse(url)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_lowercase))
+        else {
+            return true;
+        };
+        self.config.exclude_hosts.iter().any(|excluded| {
+            let excluded = excluded.to_lowercase();
+            host == excluded || host.ends_with(&format!(".{excluded}"))
+        })
+    }
+
+    /// The http(s) URLs in the document, without fragments, in document order
+    fn external_links(&self, ctx: &LintContext) -> Vec<ExternalLink> {
+        let mut found = Vec::new();
+        let mut add = |line: usize, start_col: usize, end_col: usize, url: &str| {
+            if !(url.starts_with("http://") || url.starts_with("https://")) {
+                return;
+            }
+            let url = url.split('#').next().unwrap_or(url);
+            if self.is_excluded(url) {
+                return;
+            }
+            let text = ctx.lines[line - 1].content(ctx.content);
+            let column = text.get(..start_col).map_or(1, |before| before.chars().count() + 1);
+            let end_column = text.get(..end_col).map_or(column, |before| before.chars().count() + 1);
+            found.push(ExternalLink {
+                line,
+                column,
+                end_column,
+                url: url.to_string(),
+            });
+        };
+
+        // Reference links are reported once, at their definition
+        for link in ctx.links.iter().filter(|link| !link.is_reference) {
+            add(link.line, link.start_col, link.end_col, &link.url);
+        }
+        for def in &ctx.reference_defs {
+            let text = ctx.lines[def.line - 1].content(ctx.content);
+            add(def.line, 0, text.len(), &def.url);
+        }
+        for bare in ctx.bare_urls().iter() {
+            let in_definition = ctx.reference_defs.iter().any(|def| def.line == bare.line);
+            if !in_definition && !ctx.is_in_code_block_or_span(bare.byte_offset) {
+                add(bare.line, bare.start_col, bare.end_col, &bare.url);
+            }
+        }
+        found.sort_by_key(|link| (link.line, link.column));
+        found
+    }
+
+    fn check_with(&self, ctx: &LintContext, checker: &crate::link_checker::LinkChecker) -> Vec<LintWarning> {
+        let links = self.external_links(ctx);
+        let urls: Vec<&str> = links.iter().map(|link| link.url.as_str()).collect();
+        let options = LinkCheckOptions {
+            timeout: Duration::from_secs(self.config.timeout),
+            concurrency: self.config.concurrency,
+            host_delay: Duration::from_millis(self.config.host_delay_ms),
+            cache_ttl: Duration::from_secs(self.config.cache_ttl_hours * 3600),
+        };
+        let statuses = checker.check(&urls, &options);
+
+        links
+            .into_iter()
+            .filter_map(|link| {
+                let Some(LinkStatus::Unreachable(reason)) = statuses.get(&link.url) else {
+                    return None;
+                };
+                Some(LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line: link.line,
+                    column: link.column,
+                    end_line: link.line,
+                    end_column: link.end_column,
+                    message: format!("External link is unreachable: {} ({reason})", link.url),
+                    severity: Severity::Warning,
+                    fix: None,
+                })
+            })
+            .collect()
+    }
+}
+
+impl Rule for MD141UnreachableLinks {
+    fn name(&self) -> &'static str {
+        "MD141"
+    }
+
+    fn description(&self) -> &'static str {
+        "External links should be reachable"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Link
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        #[cfg(feature = "native")]
+        let enabled = crate::link_checker::active().is_some();
+        #[cfg(not(feature = "native"))]
+        let enabled = false;
+        !enabled || !ctx.content.contains("http")
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        if self.should_skip(ctx) {
+            return Ok(Vec::new());
+        }
+
+        #[cfg(feature = "native")]
+        if let Some(checker) = crate::link_checker::active() {
+            return Ok(self.check_with(ctx, checker));
+        }
+        Ok(Vec::new())
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        // Where a dead link should point is the author's decision
+        Ok(ctx.content.to_string())
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::Unfixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD141Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+
+    fn cacheable(&self) -> bool {
+        // Results depend on remote servers, not just this file's content
+        false
+    }
+}
+
+#[cfg(all(test, feature = "native"))]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    #[test]
+    fn test_external_links() {
+        let rule = MD141UnreachableLinks::default();
+        let content = "See [docs](https://rumdl.dev/docs#install) and <https://github.com/rvben>.\n\n\
+                       Bare: https://crates.io/crates/rumdl here.\n\n\
+                       [Ref][r], [local](guide.md), [mail](mailto:a@b.c), [demo](http://localhost:8080/).\n\n\
+                       `https://in.code/span`\n\n\
+                       [r]: https://docs.rs/rumdl\n";
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        let links = rule.external_links(&ctx);
+        let found: Vec<_> = links.iter().map(|link| (link.line, link.url.as_str())).collect();
+        assert_eq!(
+            found,
+            [
+                (1, "https://rumdl.dev/docs"),
+                (1, "https://github.com/rvben"),
+                (3, "https://crates.io/crates/rumdl"),
+                (9, "https://docs.rs/rumdl"),
+            ]
+        );
+        assert_eq!((links[0].column, links[0].end_column), (5, 43));
+    }
+
+    #[test]
+    fn test_exclude_hosts() {
+        let rule = MD141UnreachableLinks::from_config_struct(MD141Config {
+            exclude_hosts: vec!["Internal.Corp".to_string()],
+            ..Default::default()
+        });
+        assert!(rule.is_excluded("https://wiki.internal.corp/page"));
+        assert!(rule.is_excluded("https://internal.corp"));
+        assert!(!rule.is_excluded("https://notinternal.corp"));
+    }
+
+    #[test]
+    fn test_unreachable_link() {
+        let closed = {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            format!("http://{}/page", listener.local_addr().unwrap())
+        };
+        let rule = MD141UnreachableLinks::from_config_struct(MD141Config {
+            timeout: 5,
+            host_delay_ms: 0,
+            exclude_hosts: Vec::new(),
+            ..Default::default()
+        });
+        let content = format!("# Links\n\nSee <{closed}#top>.\n");
+        let ctx = LintContext::new(&content, MarkdownFlavor::Standard, None);
+
+        let checker = crate::link_checker::LinkChecker::new(None);
+        let warnings = rule.check_with(&ctx, &checker);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!((warnings[0].line, warnings[0].column), (3, 5));
+        assert!(
+            warnings[0]
+                .message
+                .starts_with(&format!("External link is unreachable: {closed} ("))
+        );
+    }
+
+    #[test]
+    fn test_off_without_online_checking() {
+        // Online checking is only turned on by the CLI, never in unit tests
+        let ctx = LintContext::new("<https://example.invalid/>\n", MarkdownFlavor::Standard, None);
+        assert!(MD141UnreachableLinks::default().check(&ctx).unwrap().is_empty());
+    }
+}
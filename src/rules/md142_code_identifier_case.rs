@@ -0,0 +1,267 @@
+use crate::config::Config;
+use crate::lint_context::LintContext;
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Configuration for MD142 (Identifiers in inline code should use their canonical casing)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD142Config {
+    /// Whether the rule is enabled (opt-in, like MD110)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Identifiers in their canonical casing, such as `["line_length", "MD013"]`
+    #[serde(default)]
+    pub identifiers: Vec<String>,
+}
+
+impl RuleConfig for MD142Config {
+    const RULE_NAME: &'static str = "MD142";
+}
+
+/// Rule MD142: Identifiers in inline code should use their canonical casing
+///
+/// See [docs/md142.md](../../docs/md142.md) for full documentation, configuration, and examples.
+///
+/// Like MD044 for proper names, but for inline code: a code span whose whole content
+/// matches a configured identifier case-insensitively must match it exactly. Spans where
+/// the identifier is only part of the content, like `` `line_length = 80` ``, are left
+/// alone, since the rest of the span may follow different conventions.
+#[derive(Debug, Clone, Default)]
+pub struct MD142CodeIdentifierCase {
+    config: MD142Config,
+    /// Canonical identifiers keyed by their lowercase form
+    canonical: HashMap<String, String>,
+}
+
+impl MD142CodeIdentifierCase {
+    pub fn new(enabled: bool, identifiers: Vec<String>) -> Self {
+        Self::from_config_struct(MD142Config { enabled, identifiers })
+    }
+
+    pub fn from_config_struct(config: MD142Config) -> Self {
+        let canonical = config
+            .identifiers
+            .iter()
+            .map(|identifier| (identifier.to_lowercase(), identifier.clone()))
+            .collect();
+        Self { config, canonical }
+    }
+}
+
+impl Rule for MD142CodeIdentifierCase {
+    fn name(&self) -> &'static str {
+        "MD142"
+    }
+
+    fn description(&self) -> &'static str {
+        "Identifiers in inline code should use their canonical casing"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Other
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !self.config.enabled || self.canonical.is_empty() || !ctx.likely_has_code()
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        if self.should_skip(ctx) {
+            return Ok(warnings);
+        }
+
+        for span in ctx.code_spans().iter() {
+            let identifier = span.content.trim();
+            let Some(canonical) = self.canonical.get(&identifier.to_lowercase()) else {
+                continue;
+            };
+            if identifier == canonical {
+                continue;
+            }
+
+            // Point at the identifier itself, inside the backticks and any padding
+            let inner = &ctx.content[span.byte_offset + span.backtick_count..span.byte_end - span.backtick_count];
+            let start = span.byte_offset + span.backtick_count + (inner.len() - inner.trim_start().len());
+            let line = ctx.offset_to_line_col(start).0;
+            // Backticks in front matter values are YAML/TOML text, not inline code
+            if ctx.lines[line - 1].in_front_matter || ctx.is_in_html_comment(start) {
+                continue;
+            }
+            let column = ctx.content[ctx.lines[line - 1].byte_offset..start].chars().count() + 1;
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line,
+                column,
+                end_line: line,
+                end_column: column + identifier.chars().count(),
+                message: format!("Inline code '{identifier}' should be written as '{canonical}'"),
+                severity: Severity::Warning,
+                fix: Some(Fix {
+                    range: start..start + identifier.len(),
+                    replacement: canonical.clone(),
+                }),
+            });
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+
+        let mut content = ctx.content.to_string();
+        for fix in warnings.into_iter().rev().filter_map(|w| w.fix) {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+
+        Ok(content)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::FullyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD142Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn rule() -> MD142CodeIdentifierCase {
+        MD142CodeIdentifierCase::new(true, vec!["line_length".to_string(), "MD013".to_string()])
+    }
+
+    fn check(rule: &MD142CodeIdentifierCase, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    fn fix(rule: &MD142CodeIdentifierCase, content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_canonical_casing() {
+        let content = "Set `line_length` in `[MD013]`, e.g. `line_length = 80`. Prose Line_Length is MD044's job.\n\n\
+                       ```toml\nLine_Length = 80\n```\n";
+        assert!(check(&rule(), content).is_empty());
+    }
+
+    #[test]
+    fn test_wrong_casing() {
+        let content = "Set `Line_Length` and `` md013 `` here.\n";
+        let warnings = check(&rule(), content);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(
+            (warnings[0].line, warnings[0].column, warnings[0].end_column),
+            (1, 6, 17)
+        );
+        assert_eq!(
+            warnings[0].message,
+            "Inline code 'Line_Length' should be written as 'line_length'"
+        );
+        assert_eq!(warnings[1].column, 26);
+        assert_eq!(fix(&rule(), content), "Set `line_length` and `` MD013 `` here.\n");
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let rule = MD142CodeIdentifierCase::from_config_struct(MD142Config {
+            identifiers: vec!["line_length".to_string()],
+            ..Default::default()
+        });
+        assert!(check(&rule, "`LINE_LENGTH`\n").is_empty());
+    }
+
+    #[test]
+    fn test_fix_is_idempotent() {
+        let fixed = fix(&rule(), "`LINE_LENGTH` and `Md013`\n");
+        assert_eq!(fixed, "`line_length` and `MD013`\n");
+        assert!(check(&rule(), &fixed).is_empty());
+        assert_eq!(fix(&rule(), &fixed), fixed);
+    }
+
+    #[test]
+    fn test_front_matter_and_html_comments_skipped() {
+        let content = "---\ntitle: \"`LINE_LENGTH`\"\n---\n\n<!-- `Md013` -->\n\nText\n";
+        assert!(check(&rule(), content).is_empty());
+        assert_eq!(fix(&rule(), content), content);
+    }
+
+    #[test]
+    fn test_indented_code_skipped() {
+        assert!(check(&rule(), "Text\n\n    `LINE_LENGTH`\n").is_empty());
+    }
+
+    #[test]
+    fn test_positions_with_multibyte_prefix() {
+        let warnings = check(&rule(), "# Title\n\nÜber `md013` ist\n");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            (warnings[0].line, warnings[0].column, warnings[0].end_column),
+            (3, 7, 12)
+        );
+    }
+
+    #[test]
+    fn test_spans_in_lists_tables_and_links() {
+        let content = "- `LINE_LENGTH`\n\n| Key |\n| --- |\n| `Md013` |\n\n[`Md013`](md013.md)\n";
+        assert_eq!(check(&rule(), content).len(), 3);
+        assert_eq!(
+            fix(&rule(), content),
+            "- `line_length`\n\n| Key |\n| --- |\n| `MD013` |\n\n[`MD013`](md013.md)\n"
+        );
+    }
+
+    #[test]
+    fn test_no_identifiers_configured() {
+        let rule = MD142CodeIdentifierCase::new(true, Vec::new());
+        assert!(check(&rule, "`anything`\n").is_empty());
+    }
+
+    #[test]
+    fn test_config_keys() {
+        let mut config = Config::default();
+        let mut rule_config = crate::config::RuleConfig::default();
+        rule_config
+            .values
+            .insert("enabled".to_string(), toml::Value::Boolean(true));
+        rule_config.values.insert(
+            "identifiers".to_string(),
+            toml::Value::Array(vec![toml::Value::String("rumdl".to_string())]),
+        );
+        config.rules.insert("MD142".to_string(), rule_config);
+
+        let rule = MD142CodeIdentifierCase::from_config(&config);
+        let ctx = LintContext::new("Run `Rumdl` now\n", MarkdownFlavor::Standard, None);
+        let warnings = rule.check(&ctx).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].message, "Inline code 'Rumdl' should be written as 'rumdl'");
+    }
+}
@@ -0,0 +1,370 @@
+use crate::config::Config;
+use crate::lint_context::{LineInfo, LintContext, ListBlock};
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// Whether list items are separated by blank lines
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ListItemSpacingStyle {
+    /// Each list follows the spacing most of its items already use
+    #[default]
+    Consistent,
+    /// Items are separated by blank lines
+    Loose,
+    /// Items follow each other directly
+    Tight,
+}
+
+/// Configuration for MD143 (List items should be consistently separated by blank lines)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD143Config {
+    /// Whether the rule is enabled (opt-in, like MD110)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Spacing every list should use
+    #[serde(default)]
+    pub style: ListItemSpacingStyle,
+}
+
+impl RuleConfig for MD143Config {
+    const RULE_NAME: &'static str = "MD143";
+}
+
+/// The space between two sibling items
+struct Gap {
+    /// 0-based line index of the later item
+    item: usize,
+    /// 0-based line indices of the blank lines right before it
+    blank_lines: std::ops::Range<usize>,
+}
+
+impl Gap {
+    fn is_loose(&self) -> bool {
+        !self.blank_lines.is_empty()
+    }
+}
+
+/// Rule MD143: List items should be consistently separated by blank lines
+///
+/// See [docs/md143.md](../../docs/md143.md) for full documentation, configuration, and examples.
+///
+/// A list where only some items are separated by blank lines renders as loose (every item
+/// wrapped in a paragraph), which is rarely what the author meant. Items at the same level
+/// of a list are checked as a group, so a nested list can differ from its parent. An item
+/// with several paragraphs or blocks separated by blank lines can't be tight, so lists
+/// containing one are always expected to be loose.
+#[derive(Debug, Clone, Default)]
+pub struct MD143ListItemSpacing {
+    config: MD143Config,
+}
+
+impl MD143ListItemSpacing {
+    pub fn new(enabled: bool, style: ListItemSpacingStyle) -> Self {
+        Self {
+            config: MD143Config { enabled, style },
+        }
+    }
+
+    pub fn from_config_struct(config: MD143Config) -> Self {
+        Self { config }
+    }
+
+    /// Column where a line's content starts, counting any blockquote prefix
+    fn indent(line: &LineInfo) -> usize {
+        match &line.blockquote {
+            Some(bq) => bq.prefix.len() + bq.content.len() - bq.content.trim_start().len(),
+            None => line.indent,
+        }
+    }
+
+    fn is_blank(line: &LineInfo) -> bool {
+        line.is_blank && !line.in_code_block
+    }
+
+    /// Items of a list block grouped by level, as 0-based line indices; each group is a
+    /// run of siblings with the 0-based index of the last line the group covers
+    fn sibling_groups(ctx: &LintContext, block: &ListBlock) -> Vec<(Vec<usize>, usize)> {
+        let column = |line: usize| ctx.lines[line].list_item.as_ref().map_or(0, |item| item.marker_column);
+
+        // Open groups as (marker column, index into `groups`), innermost last
+        let mut groups: Vec<(Vec<usize>, usize)> = Vec::new();
+        let mut open: Vec<(usize, usize)> = Vec::new();
+        for line in block.item_lines.iter().map(|line| line - 1) {
+            let col = column(line);
+            while let Some(&(open_col, index)) = open.last() {
+                if open_col <= col {
+                    break;
+                }
+                groups[index].1 = line - 1;
+                open.pop();
+            }
+            match open.last() {
+                Some(&(open_col, index)) if open_col == col => groups[index].0.push(line),
+                _ => {
+                    open.push((col, groups.len()));
+                    groups.push((vec![line], block.end_line - 1));
+                }
+            }
+        }
+        groups
+    }
+
+    /// Whether an item has blank lines between its own blocks, such as two paragraphs,
+    /// in `lines` (0-based, without the blank lines that follow it)
+    fn has_inner_blank_line(ctx: &LintContext, item: usize, lines: std::ops::Range<usize>) -> bool {
+        let mut latest_item = item;
+        for line in lines.clone() {
+            let info = &ctx.lines[line];
+            if info.list_item.is_some() {
+                latest_item = line;
+            }
+            if !Self::is_blank(info) {
+                continue;
+            }
+            let Some(next) = (line + 1..lines.end).find(|&next| !ctx.lines[next].is_blank) else {
+                continue;
+            };
+            if latest_item == item {
+                return true;
+            }
+            // After a nested item, the blank line belongs to the nested list unless the
+            // content that follows is back at this item's level
+            let next = &ctx.lines[next];
+            let nested_content = ctx.lines[latest_item]
+                .list_item
+                .as_ref()
+                .map_or(0, |nested| nested.content_column);
+            if next.list_item.is_none() && Self::indent(next) < nested_content {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn check_group(&self, ctx: &LintContext, items: &[usize], last_line: usize, warnings: &mut Vec<LintWarning>) {
+        let mut gaps = Vec::new();
+        let mut inherently_loose = false;
+        for (index, &item) in items.iter().enumerate() {
+            let end = items.get(index + 1).copied().unwrap_or(last_line + 1);
+            let mut content_end = end;
+            while content_end > item + 1 && Self::is_blank(&ctx.lines[content_end - 1]) {
+                content_end -= 1;
+            }
+            inherently_loose |= Self::has_inner_blank_line(ctx, item, item + 1..content_end);
+            if index + 1 < items.len() {
+                gaps.push(Gap {
+                    item: end,
+                    blank_lines: content_end..end,
+                });
+            }
+        }
+        if gaps.is_empty() {
+            return;
+        }
+
+        let loose = inherently_loose
+            || match self.config.style {
+                ListItemSpacingStyle::Loose => true,
+                ListItemSpacingStyle::Tight => false,
+                ListItemSpacingStyle::Consistent => {
+                    let loose_gaps = gaps.iter().filter(|gap| gap.is_loose()).count();
+                    match (loose_gaps * 2).cmp(&gaps.len()) {
+                        std::cmp::Ordering::Greater => true,
+                        std::cmp::Ordering::Less => false,
+                        std::cmp::Ordering::Equal => gaps[0].is_loose(),
+                    }
+                }
+            };
+
+        for gap in gaps.iter().filter(|gap| gap.is_loose() != loose) {
+            let line = &ctx.lines[gap.item];
+            let (message, fix) = if loose {
+                let blank = line
+                    .blockquote
+                    .as_ref()
+                    .map_or(String::new(), |bq| bq.prefix.trim_end().to_string());
+                (
+                    "List item should be preceded by a blank line, as the list is loose",
+                    Fix {
+                        range: line.byte_offset..line.byte_offset,
+                        replacement: format!("{blank}\n"),
+                    },
+                )
+            } else {
+                (
+                    "List item should not be preceded by a blank line, as the list is tight",
+                    Fix {
+                        range: ctx.lines[gap.blank_lines.start].byte_offset..line.byte_offset,
+                        replacement: String::new(),
+                    },
+                )
+            };
+            let marker = line.list_item.as_ref();
+            let column = marker.map_or(0, |item| item.marker_column) + 1;
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line: gap.item + 1,
+                column,
+                end_line: gap.item + 1,
+                end_column: column + marker.map_or(0, |item| item.marker.len()),
+                message: message.to_string(),
+                severity: Severity::Warning,
+                fix: Some(fix),
+            });
+        }
+    }
+}
+
+impl Rule for MD143ListItemSpacing {
+    fn name(&self) -> &'static str {
+        "MD143"
+    }
+
+    fn description(&self) -> &'static str {
+        "List items should be consistently separated by blank lines"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::List
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !self.config.enabled || !ctx.likely_has_lists()
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        if self.should_skip(ctx) {
+            return Ok(warnings);
+        }
+
+        for block in ctx.list_blocks() {
+            for (items, last_line) in Self::sibling_groups(ctx, block) {
+                self.check_group(ctx, &items, last_line, &mut warnings);
+            }
+        }
+        warnings.sort_by_key(|warning| warning.line);
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+
+        let mut content = ctx.content.to_string();
+        for fix in warnings.into_iter().rev().filter_map(|w| w.fix) {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+
+        Ok(content)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::FullyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD143Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn rule(style: ListItemSpacingStyle) -> MD143ListItemSpacing {
+        MD143ListItemSpacing::new(true, style)
+    }
+
+    fn check(rule: &MD143ListItemSpacing, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    fn fix(rule: &MD143ListItemSpacing, content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_consistent_lists() {
+        let rule = rule(ListItemSpacingStyle::Consistent);
+        assert!(check(&rule, "- a\n- b\n- c\n").is_empty());
+        assert!(check(&rule, "1. a\n\n2. b\n\n3. c\n").is_empty());
+        // A nested list may differ from its parent
+        assert!(check(&rule, "- a\n  - x\n  - y\n\n- b\n").is_empty());
+        // Blank lines inside a fenced code block don't count
+        assert!(check(&rule, "- a\n  ```\n  x\n\n  y\n  ```\n- b\n").is_empty());
+    }
+
+    #[test]
+    fn test_majority_wins() {
+        let rule = rule(ListItemSpacingStyle::Consistent);
+        let content = "- a\n- b\n\n- c\n- d\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!((warnings[0].line, warnings[0].column), (4, 1));
+        assert_eq!(
+            warnings[0].message,
+            "List item should not be preceded by a blank line, as the list is tight"
+        );
+        assert_eq!(fix(&rule, content), "- a\n- b\n- c\n- d\n");
+
+        // On a tie, the first separator decides
+        assert_eq!(fix(&rule, "- a\n\n- b\n- c\n"), "- a\n\n- b\n\n- c\n");
+    }
+
+    #[test]
+    fn test_configured_style() {
+        let content = "- a\n- b\n\n- c\n- d\n";
+        assert_eq!(
+            fix(&rule(ListItemSpacingStyle::Loose), content),
+            "- a\n\n- b\n\n- c\n\n- d\n"
+        );
+        assert_eq!(fix(&rule(ListItemSpacingStyle::Tight), "- a\n\n\n- b\n"), "- a\n- b\n");
+    }
+
+    #[test]
+    fn test_multiple_paragraphs_are_loose() {
+        let content = "- a\n- b\n\n  More about b.\n- c\n";
+        let rule = rule(ListItemSpacingStyle::Tight);
+        assert_eq!(check(&rule, content).len(), 2);
+        assert_eq!(fix(&rule, content), "- a\n\n- b\n\n  More about b.\n\n- c\n");
+
+        // A blank line inside a nested list doesn't make the parent item loose
+        assert!(check(&rule, "- a\n  - x\n\n    More about x.\n- b\n").is_empty());
+    }
+
+    #[test]
+    fn test_blockquote_list() {
+        let rule = rule(ListItemSpacingStyle::Loose);
+        assert_eq!(fix(&rule, "> - a\n>\n> - b\n> - c\n"), "> - a\n>\n> - b\n>\n> - c\n");
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let rule = MD143ListItemSpacing::default();
+        assert!(check(&rule, "- a\n- b\n\n- c\n- d\n").is_empty());
+    }
+}
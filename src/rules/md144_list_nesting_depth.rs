@@ -0,0 +1,233 @@
+use crate::config::Config;
+use crate::lint_context::{LineInfo, LintContext};
+use crate::rule::{FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MD144 (Lists should not be nested too deeply)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD144Config {
+    /// Whether the rule is enabled (opt-in, like MD110)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Deepest nesting allowed; a top-level item is at depth 1
+    #[serde(default = "default_max_depth", alias = "max_depth")]
+    pub max_depth: usize,
+}
+
+fn default_max_depth() -> usize {
+    4
+}
+
+impl Default for MD144Config {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_depth: default_max_depth(),
+        }
+    }
+}
+
+impl RuleConfig for MD144Config {
+    const RULE_NAME: &'static str = "MD144";
+}
+
+/// Rule MD144: Lists should not be nested too deeply
+///
+/// See [docs/md144.md](../../docs/md144.md) for full documentation, configuration, and examples.
+///
+/// Depth follows CommonMark: an item is nested in an earlier one when its marker starts at
+/// or after that item's content column, whatever the list types. Blockquotes don't add
+/// depth, so a list inside a quote starts again at depth 1. Flattening a list changes its
+/// meaning, so there is no automatic fix.
+#[derive(Debug, Clone, Default)]
+pub struct MD144ListNestingDepth {
+    config: MD144Config,
+}
+
+impl MD144ListNestingDepth {
+    pub fn new(enabled: bool, max_depth: usize) -> Self {
+        Self {
+            config: MD144Config { enabled, max_depth },
+        }
+    }
+
+    pub fn from_config_struct(config: MD144Config) -> Self {
+        Self { config }
+    }
+
+    /// Column where a line's content starts, counting any blockquote prefix
+    fn indent(line: &LineInfo) -> usize {
+        match &line.blockquote {
+            Some(bq) => bq.prefix.len() + bq.content.len() - bq.content.trim_start().len(),
+            None => line.indent,
+        }
+    }
+
+    /// Nesting depth of every list item, as `(0-based line index, depth)` pairs
+    fn item_depths(ctx: &LintContext) -> Vec<(usize, usize)> {
+        let mut depths = Vec::new();
+        // Content columns of the items the current line may be nested in, outermost first
+        let mut open: Vec<usize> = Vec::new();
+        let mut quote_level = 0;
+
+        for (index, line) in ctx.lines.iter().enumerate() {
+            if line.in_code_block || line.in_front_matter || line.is_blank {
+                continue;
+            }
+            let level = line.blockquote.as_ref().map_or(0, |bq| bq.nesting_level);
+            if level != quote_level {
+                open.clear();
+                quote_level = level;
+            }
+
+            match &line.list_item {
+                Some(item) => {
+                    while open.last().is_some_and(|&content| item.marker_column < content) {
+                        open.pop();
+                    }
+                    open.push(item.content_column);
+                    depths.push((index, open.len()));
+                }
+                None => {
+                    // Content outside an item's indentation ends it
+                    let indent = Self::indent(line);
+                    while open.last().is_some_and(|&content| indent < content) {
+                        open.pop();
+                    }
+                }
+            }
+        }
+        depths
+    }
+}
+
+impl Rule for MD144ListNestingDepth {
+    fn name(&self) -> &'static str {
+        "MD144"
+    }
+
+    fn description(&self) -> &'static str {
+        "Lists should not be nested too deeply"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::List
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !self.config.enabled || !ctx.likely_has_lists()
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        if self.should_skip(ctx) {
+            return Ok(warnings);
+        }
+
+        for (index, depth) in Self::item_depths(ctx) {
+            if depth <= self.config.max_depth {
+                continue;
+            }
+            let Some(item) = &ctx.lines[index].list_item else {
+                continue;
+            };
+            warnings.push(LintWarning {
+                rule_name: Some(self.name().to_string()),
+                line: index + 1,
+                column: item.marker_column + 1,
+                end_line: index + 1,
+                end_column: item.marker_column + item.marker.len() + 1,
+                message: format!(
+                    "List item is nested {depth} levels deep (maximum: {})",
+                    self.config.max_depth
+                ),
+                severity: Severity::Warning,
+                fix: None,
+            });
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        // Flattening a list changes its structure, which only the author can decide
+        Ok(ctx.content.to_string())
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::Unfixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD144Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(rule: &MD144ListNestingDepth, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_within_limit() {
+        let rule = MD144ListNestingDepth::new(true, 2);
+        assert!(check(&rule, "- a\n  - b\n- c\n  1. d\n").is_empty());
+        // Not indented far enough to be nested under "1. a"
+        assert!(check(&rule, "1. a\n   - b\n  - c\n").is_empty());
+    }
+
+    #[test]
+    fn test_too_deep() {
+        let rule = MD144ListNestingDepth::new(true, 2);
+        let content = "- a\n  - b\n    1. c\n       - d\n  - e\n";
+        let warnings = check(&rule, content);
+        let found: Vec<_> = warnings.iter().map(|w| (w.line, w.column)).collect();
+        assert_eq!(found, [(3, 5), (4, 8)]);
+        assert_eq!(warnings[0].message, "List item is nested 3 levels deep (maximum: 2)");
+        assert_eq!(warnings[1].message, "List item is nested 4 levels deep (maximum: 2)");
+    }
+
+    #[test]
+    fn test_paragraph_ends_list() {
+        let rule = MD144ListNestingDepth::new(true, 1);
+        let content = "- a\n\nText\n\n  - b\n\n```\n- x\n  - y\n```\n";
+        assert!(check(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_blockquote_does_not_add_depth() {
+        let rule = MD144ListNestingDepth::new(true, 2);
+        assert!(check(&rule, "- a\n\n  > - b\n  >   - c\n").is_empty());
+        assert_eq!(check(&rule, "> - a\n>   - b\n>     - c\n").len(), 1);
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let rule = MD144ListNestingDepth::default();
+        assert!(check(&rule, "- a\n  - b\n    - c\n      - d\n        - e\n").is_empty());
+    }
+}
@@ -0,0 +1,289 @@
+use crate::config::Config;
+use crate::lint_context::{HeadingInfo, LintContext};
+use crate::rule::{Fix, FixCapability, LintError, LintResult, LintWarning, Rule, RuleCategory, Severity};
+use crate::rule_config_serde::RuleConfig;
+use crate::utils::anchor_styles::AnchorStyle;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Whether headings carry explicit `{#id}` anchors
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum HeadingIdStyle {
+    /// Each document follows the style most of its headings already use
+    #[default]
+    Consistent,
+    /// Every heading has an explicit ID
+    Explicit,
+    /// No heading has an explicit ID; anchors are generated from the text
+    Auto,
+}
+
+/// Configuration for MD145 (Heading IDs should be consistently explicit or generated)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MD145Config {
+    /// Whether the rule is enabled (opt-in, like MD110)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Which headings should have explicit IDs
+    #[serde(default)]
+    pub ids: HeadingIdStyle,
+
+    /// Slug algorithm for the IDs added by the fix
+    #[serde(default, alias = "anchor_style")]
+    pub anchor_style: AnchorStyle,
+}
+
+impl RuleConfig for MD145Config {
+    const RULE_NAME: &'static str = "MD145";
+}
+
+/// Rule MD145: Heading IDs should be consistently explicit or generated
+///
+/// See [docs/md145.md](../../docs/md145.md) for full documentation, configuration, and examples.
+///
+/// A document where some headings have `{#id}` anchors and others rely on generated slugs
+/// has anchors that change when some headings are reworded but not others. IDs given in a
+/// standalone attribute list on the next line count as explicit. The fix adds IDs built
+/// from the current slug, so existing links keep working; removing IDs could break links,
+/// so that is left to the author.
+#[derive(Debug, Clone, Default)]
+pub struct MD145HeadingIdStyle {
+    config: MD145Config,
+}
+
+impl MD145HeadingIdStyle {
+    pub fn new(enabled: bool, ids: HeadingIdStyle) -> Self {
+        Self {
+            config: MD145Config {
+                enabled,
+                ids,
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn from_config_struct(config: MD145Config) -> Self {
+        Self { config }
+    }
+
+    /// Byte offset where an ID is inserted: after the heading text, before any closing hashes
+    fn insertion_point(ctx: &LintContext, line: usize, heading: &HeadingInfo) -> usize {
+        let info = &ctx.lines[line];
+        let text = info.content(ctx.content).trim_end();
+        let end = if heading.has_closing_sequence {
+            text.strip_suffix(heading.closing_sequence.as_str())
+                .map_or(text.len(), |before| before.trim_end().len())
+        } else {
+            text.len()
+        };
+        info.byte_offset + end
+    }
+}
+
+impl Rule for MD145HeadingIdStyle {
+    fn name(&self) -> &'static str {
+        "MD145"
+    }
+
+    fn description(&self) -> &'static str {
+        "Heading IDs should be consistently explicit or generated"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Heading
+    }
+
+    fn should_skip(&self, ctx: &LintContext) -> bool {
+        !self.config.enabled || !ctx.likely_has_headings()
+    }
+
+    fn check(&self, ctx: &LintContext) -> LintResult {
+        let mut warnings = Vec::new();
+        if self.should_skip(ctx) {
+            return Ok(warnings);
+        }
+
+        let headings: Vec<(usize, &HeadingInfo)> = ctx
+            .lines
+            .iter()
+            .enumerate()
+            .filter_map(|(line, info)| info.heading.as_ref().map(|heading| (line, heading)))
+            .filter(|(_, heading)| !heading.text.trim().is_empty())
+            .collect();
+        if headings.is_empty() {
+            return Ok(warnings);
+        }
+
+        let explicit = match self.config.ids {
+            HeadingIdStyle::Explicit => true,
+            HeadingIdStyle::Auto => false,
+            HeadingIdStyle::Consistent => {
+                let with_id = headings
+                    .iter()
+                    .filter(|(_, heading)| heading.custom_id.is_some())
+                    .count();
+                match (with_id * 2).cmp(&headings.len()) {
+                    std::cmp::Ordering::Greater => true,
+                    std::cmp::Ordering::Less => false,
+                    std::cmp::Ordering::Equal => headings[0].1.custom_id.is_some(),
+                }
+            }
+        };
+
+        let mut used_ids: HashSet<String> = headings
+            .iter()
+            .filter_map(|(_, heading)| heading.custom_id.clone())
+            .collect();
+        for &(line, heading) in &headings {
+            let column = heading.content_column + 1;
+            let end_column = column + heading.raw_text.chars().count();
+            match (&heading.custom_id, explicit) {
+                (None, true) => {
+                    let slug = self.config.anchor_style.generate_fragment(&heading.text);
+                    let mut id = slug.clone();
+                    let mut suffix = 1;
+                    while id.is_empty() || used_ids.contains(&id) {
+                        id = format!("{slug}-{suffix}");
+                        suffix += 1;
+                    }
+                    used_ids.insert(id.clone());
+                    let at = Self::insertion_point(ctx, line, heading);
+                    warnings.push(LintWarning {
+                        rule_name: Some(self.name().to_string()),
+                        line: line + 1,
+                        column,
+                        end_line: line + 1,
+                        end_column,
+                        message: format!("Heading should have an explicit ID, such as {{#{id}}}"),
+                        severity: Severity::Warning,
+                        fix: Some(Fix {
+                            range: at..at,
+                            replacement: format!(" {{#{id}}}"),
+                        }),
+                    });
+                }
+                (Some(id), false) => warnings.push(LintWarning {
+                    rule_name: Some(self.name().to_string()),
+                    line: line + 1,
+                    column,
+                    end_line: line + 1,
+                    end_column,
+                    message: format!("Heading should not have an explicit ID ({{#{id}}})"),
+                    severity: Severity::Warning,
+                    fix: None,
+                }),
+                _ => {}
+            }
+        }
+
+        Ok(warnings)
+    }
+
+    fn fix(&self, ctx: &LintContext) -> Result<String, LintError> {
+        let warnings = self.check(ctx)?;
+
+        let mut content = ctx.content.to_string();
+        for fix in warnings.into_iter().rev().filter_map(|w| w.fix) {
+            content.replace_range(fix.range, &fix.replacement);
+        }
+
+        Ok(content)
+    }
+
+    fn fix_capability(&self) -> FixCapability {
+        FixCapability::ConditionallyFixable
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn default_config_section(&self) -> Option<(String, toml::Value)> {
+        let json_value = serde_json::to_value(&self.config).ok()?;
+        Some((
+            self.name().to_string(),
+            crate::rule_config_serde::json_to_toml_value(&json_value)?,
+        ))
+    }
+
+    fn from_config(config: &Config) -> Box<dyn Rule>
+    where
+        Self: Sized,
+    {
+        let rule_config = crate::rule_config_serde::load_rule_config::<MD145Config>(config);
+        Box::new(Self::from_config_struct(rule_config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarkdownFlavor;
+
+    fn check(rule: &MD145HeadingIdStyle, content: &str) -> Vec<LintWarning> {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.check(&ctx).unwrap()
+    }
+
+    fn fix(rule: &MD145HeadingIdStyle, content: &str) -> String {
+        let ctx = LintContext::new(content, MarkdownFlavor::Standard, None);
+        rule.fix(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_consistent_documents() {
+        let rule = MD145HeadingIdStyle::new(true, HeadingIdStyle::Consistent);
+        assert!(check(&rule, "# Title\n\n## Setup\n").is_empty());
+        assert!(check(&rule, "# Title {#title}\n\n## Setup\n{#setup}\n").is_empty());
+    }
+
+    #[test]
+    fn test_mixed_ids_follow_majority() {
+        let rule = MD145HeadingIdStyle::new(true, HeadingIdStyle::Consistent);
+        let content = "# Title {#top}\n\n## Getting Started\n\n## FAQ ##\n\nSetext {#setext}\n------\n";
+        let warnings = check(&rule, content);
+        let found: Vec<_> = warnings.iter().map(|w| (w.line, w.column)).collect();
+        assert_eq!(found, [(3, 4), (5, 4)]);
+        assert_eq!(
+            warnings[0].message,
+            "Heading should have an explicit ID, such as {#getting-started}"
+        );
+        assert_eq!(
+            fix(&rule, content),
+            "# Title {#top}\n\n## Getting Started {#getting-started}\n\n## FAQ {#faq} ##\n\nSetext {#setext}\n------\n"
+        );
+    }
+
+    #[test]
+    fn test_explicit_ids_are_unique() {
+        let rule = MD145HeadingIdStyle::new(true, HeadingIdStyle::Explicit);
+        let content = "# Usage {#usage}\n\n## Usage\n\n## Usage\n";
+        assert_eq!(
+            fix(&rule, content),
+            "# Usage {#usage}\n\n## Usage {#usage-1}\n\n## Usage {#usage-2}\n"
+        );
+    }
+
+    #[test]
+    fn test_auto_flags_explicit_ids() {
+        let rule = MD145HeadingIdStyle::new(true, HeadingIdStyle::Auto);
+        let content = "# Title\n\n## Setup {#install}\n";
+        let warnings = check(&rule, content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].message,
+            "Heading should not have an explicit ID ({#install})"
+        );
+        assert!(warnings[0].fix.is_none());
+        assert_eq!(fix(&rule, content), content);
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let rule = MD145HeadingIdStyle::default();
+        assert!(check(&rule, "# Title {#top}\n\n## Setup\n\n## Usage\n").is_empty());
+    }
+}
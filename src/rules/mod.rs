@@ -56,8 +56,47 @@ mod md059_link_text;
 mod md060_table_format;
 mod md061_forbidden_terms;
 mod md062_link_destination_whitespace;
-// mod md063_duplicate_footnotes;
-// mod md064_long_paragraph_footnotes;
+mod md063_duplicate_footnotes;
+mod md064_heading_anchor_links;
+mod md107_abbreviation_definitions;
+mod md108_invisible_characters;
+mod md109_admonition_types;
+mod md110_table_cell_padding;
+mod md111_closing_fence_content;
+mod md112_atx_closing_sequence;
+mod md113_snippet_targets;
+mod md114_mixed_up_link_syntax;
+mod md115_setext_underline_length;
+mod md116_front_matter_key_order;
+mod md117_empty_emphasis;
+mod md118_list_fence_indent;
+mod md119_fenced_code_blocks;
+mod md120_redundant_heading_label;
+mod md121_ordered_list_marker_width;
+mod md122_blockquote_lazy_continuation;
+mod md123_link_title_quotes;
+mod md124_link_forward_slashes;
+mod md125_code_block_trailing_spaces;
+mod md126_heading_matches_filename;
+mod md127_heading_outline;
+mod md128_list_marker_space_consistency;
+mod md129_code_block_language_inference;
+mod md130_document_length;
+mod md131_emphasis_nesting;
+mod md132_table_column_limit;
+mod md133_hr_list_marker;
+mod md134_mdx_import_paths;
+mod md135_atx_heading_spacing;
+mod md136_reference_definition_urls;
+mod md137_disable_comment_reason;
+mod md138_heading_link;
+mod md139_sorted_lists;
+mod md140_code_fence_length;
+mod md141_unreachable_links;
+mod md142_code_identifier_case;
+mod md143_list_item_spacing;
+mod md144_list_nesting_depth;
+mod md145_heading_id_style;
 
 pub use md001_heading_increment::MD001HeadingIncrement;
 pub use md003_heading_style::MD003HeadingStyle;
@@ -75,7 +114,7 @@ pub use md025_single_title::MD025SingleTitle;
 pub use md026_no_trailing_punctuation::MD026NoTrailingPunctuation;
 pub use md027_multiple_spaces_blockquote::MD027MultipleSpacesBlockquote;
 pub use md028_no_blanks_blockquote::MD028NoBlanksBlockquote;
-pub use md029_ordered_list_prefix::{ListStyle, MD029OrderedListPrefix};
+pub use md029_ordered_list_prefix::{ListStyle, MD029Config, MD029OrderedListPrefix};
 pub use md030_list_marker_space::MD030ListMarkerSpace;
 pub use md031_blanks_around_fences::MD031BlanksAroundFences;
 pub use md032_blanks_around_lists::MD032BlanksAroundLists;
@@ -108,7 +147,47 @@ pub use md059_link_text::MD059LinkText;
 pub use md060_table_format::MD060TableFormat;
 pub use md061_forbidden_terms::MD061ForbiddenTerms;
 pub use md062_link_destination_whitespace::MD062LinkDestinationWhitespace;
-pub use md901_duplicate_footnotes::MD901DuplicateFootnotes;
+pub use md063_duplicate_footnotes::MD063DuplicateFootnotes;
+pub use md064_heading_anchor_links::MD064HeadingAnchorLinks;
+pub use md107_abbreviation_definitions::MD107AbbreviationDefinitions;
+pub use md108_invisible_characters::MD108InvisibleCharacters;
+pub use md109_admonition_types::MD109AdmonitionTypes;
+pub use md110_table_cell_padding::MD110TableCellPadding;
+pub use md111_closing_fence_content::MD111ClosingFenceContent;
+pub use md112_atx_closing_sequence::MD112AtxClosingSequence;
+pub use md113_snippet_targets::MD113SnippetTargets;
+pub use md114_mixed_up_link_syntax::MD114MixedUpLinkSyntax;
+pub use md115_setext_underline_length::MD115SetextUnderlineLength;
+pub use md116_front_matter_key_order::MD116FrontMatterKeyOrder;
+pub use md117_empty_emphasis::MD117EmptyEmphasis;
+pub use md118_list_fence_indent::MD118ListFenceIndent;
+pub use md119_fenced_code_blocks::MD119FencedCodeBlocks;
+pub use md120_redundant_heading_label::MD120RedundantHeadingLabel;
+pub use md121_ordered_list_marker_width::MD121OrderedListMarkerWidth;
+pub use md122_blockquote_lazy_continuation::MD122BlockquoteLazyContinuation;
+pub use md123_link_title_quotes::MD123LinkTitleQuotes;
+pub use md124_link_forward_slashes::MD124LinkForwardSlashes;
+pub use md125_code_block_trailing_spaces::MD125CodeBlockTrailingSpaces;
+pub use md126_heading_matches_filename::MD126HeadingMatchesFilename;
+pub use md127_heading_outline::MD127HeadingOutline;
+pub use md128_list_marker_space_consistency::MD128ListMarkerSpaceConsistency;
+pub use md129_code_block_language_inference::MD129CodeBlockLanguageInference;
+pub use md130_document_length::MD130DocumentLength;
+pub use md131_emphasis_nesting::MD131EmphasisNesting;
+pub use md132_table_column_limit::MD132TableColumnLimit;
+pub use md133_hr_list_marker::MD133HrListMarker;
+pub use md134_mdx_import_paths::MD134MdxImportPaths;
+pub use md135_atx_heading_spacing::MD135AtxHeadingSpacing;
+pub use md136_reference_definition_urls::MD136ReferenceDefinitionUrls;
+pub use md137_disable_comment_reason::MD137DisableCommentReason;
+pub use md138_heading_link::MD138HeadingLink;
+pub use md139_sorted_lists::MD139SortedLists;
+pub use md140_code_fence_length::MD140CodeFenceLength;
+pub use md141_unreachable_links::MD141UnreachableLinks;
+pub use md142_code_identifier_case::MD142CodeIdentifierCase;
+pub use md143_list_item_spacing::MD143ListItemSpacing;
+pub use md144_list_nesting_depth::MD144ListNestingDepth;
+pub use md145_heading_id_style::MD145HeadingIdStyle;
 pub use md902_long_paragraph_footnotes::MD902LongParagraphFootnotes;
 
 mod md012_no_multiple_blanks;
@@ -135,77 +214,132 @@ mod md057_existing_relative_links;
 
 pub use md057_existing_relative_links::MD057ExistingRelativeLinks;
 
-mod md901_duplicate_footnotes;
 mod md902_long_paragraph_footnotes;
 
 use crate::rule::Rule;
 
+type RuleCtor = fn(&crate::config::Config) -> Box<dyn Rule>;
+
+/// Every rule name paired with its constructor, in rule order
+const RULES: &[(&str, RuleCtor)] = &[
+    ("MD001", MD001HeadingIncrement::from_config),
+    ("MD003", MD003HeadingStyle::from_config),
+    ("MD004", MD004UnorderedListStyle::from_config),
+    ("MD005", MD005ListIndent::from_config),
+    ("MD007", MD007ULIndent::from_config),
+    ("MD009", MD009TrailingSpaces::from_config),
+    ("MD010", MD010NoHardTabs::from_config),
+    ("MD011", MD011NoReversedLinks::from_config),
+    ("MD012", MD012NoMultipleBlanks::from_config),
+    ("MD013", MD013LineLength::from_config),
+    ("MD014", MD014CommandsShowOutput::from_config),
+    ("MD018", MD018NoMissingSpaceAtx::from_config),
+    ("MD019", MD019NoMultipleSpaceAtx::from_config),
+    ("MD020", MD020NoMissingSpaceClosedAtx::from_config),
+    ("MD021", MD021NoMultipleSpaceClosedAtx::from_config),
+    ("MD022", MD022BlanksAroundHeadings::from_config),
+    ("MD023", MD023HeadingStartLeft::from_config),
+    ("MD024", MD024NoDuplicateHeading::from_config),
+    ("MD025", MD025SingleTitle::from_config),
+    ("MD026", MD026NoTrailingPunctuation::from_config),
+    ("MD027", MD027MultipleSpacesBlockquote::from_config),
+    ("MD028", MD028NoBlanksBlockquote::from_config),
+    ("MD029", MD029OrderedListPrefix::from_config),
+    ("MD030", MD030ListMarkerSpace::from_config),
+    ("MD031", MD031BlanksAroundFences::from_config),
+    ("MD032", MD032BlanksAroundLists::from_config),
+    ("MD033", MD033NoInlineHtml::from_config),
+    ("MD034", MD034NoBareUrls::from_config),
+    ("MD035", MD035HRStyle::from_config),
+    ("MD036", MD036NoEmphasisAsHeading::from_config),
+    ("MD037", MD037NoSpaceInEmphasis::from_config),
+    ("MD038", MD038NoSpaceInCode::from_config),
+    ("MD039", MD039NoSpaceInLinks::from_config),
+    ("MD040", MD040FencedCodeLanguage::from_config),
+    ("MD041", MD041FirstLineHeading::from_config),
+    ("MD042", MD042NoEmptyLinks::from_config),
+    ("MD043", MD043RequiredHeadings::from_config),
+    ("MD044", MD044ProperNames::from_config),
+    ("MD045", MD045NoAltText::from_config),
+    ("MD046", MD046CodeBlockStyle::from_config),
+    ("MD047", MD047SingleTrailingNewline::from_config),
+    ("MD048", MD048CodeFenceStyle::from_config),
+    ("MD049", MD049EmphasisStyle::from_config),
+    ("MD050", MD050StrongStyle::from_config),
+    ("MD051", MD051LinkFragments::from_config),
+    ("MD052", MD052ReferenceLinkImages::from_config),
+    ("MD053", MD053LinkImageReferenceDefinitions::from_config),
+    ("MD054", MD054LinkImageStyle::from_config),
+    ("MD055", MD055TablePipeStyle::from_config),
+    ("MD056", MD056TableColumnCount::from_config),
+    ("MD057", MD057ExistingRelativeLinks::from_config),
+    ("MD058", MD058BlanksAroundTables::from_config),
+    ("MD059", MD059LinkText::from_config),
+    ("MD060", MD060TableFormat::from_config),
+    ("MD061", MD061ForbiddenTerms::from_config),
+    ("MD062", MD062LinkDestinationWhitespace::from_config),
+    ("MD063", MD063DuplicateFootnotes::from_config),
+    ("MD064", MD064HeadingAnchorLinks::from_config),
+    ("MD107", MD107AbbreviationDefinitions::from_config),
+    ("MD108", MD108InvisibleCharacters::from_config),
+    ("MD109", MD109AdmonitionTypes::from_config),
+    ("MD110", MD110TableCellPadding::from_config),
+    ("MD111", MD111ClosingFenceContent::from_config),
+    ("MD112", MD112AtxClosingSequence::from_config),
+    ("MD113", MD113SnippetTargets::from_config),
+    ("MD114", MD114MixedUpLinkSyntax::from_config),
+    ("MD115", MD115SetextUnderlineLength::from_config),
+    ("MD116", MD116FrontMatterKeyOrder::from_config),
+    ("MD117", MD117EmptyEmphasis::from_config),
+    ("MD118", MD118ListFenceIndent::from_config),
+    ("MD119", MD119FencedCodeBlocks::from_config),
+    ("MD120", MD120RedundantHeadingLabel::from_config),
+    ("MD121", MD121OrderedListMarkerWidth::from_config),
+    ("MD122", MD122BlockquoteLazyContinuation::from_config),
+    ("MD123", MD123LinkTitleQuotes::from_config),
+    ("MD124", MD124LinkForwardSlashes::from_config),
+    ("MD125", MD125CodeBlockTrailingSpaces::from_config),
+    ("MD126", MD126HeadingMatchesFilename::from_config),
+    ("MD127", MD127HeadingOutline::from_config),
+    ("MD128", MD128ListMarkerSpaceConsistency::from_config),
+    ("MD129", MD129CodeBlockLanguageInference::from_config),
+    ("MD130", MD130DocumentLength::from_config),
+    ("MD131", MD131EmphasisNesting::from_config),
+    ("MD132", MD132TableColumnLimit::from_config),
+    ("MD133", MD133HrListMarker::from_config),
+    ("MD134", MD134MdxImportPaths::from_config),
+    ("MD135", MD135AtxHeadingSpacing::from_config),
+    ("MD136", MD136ReferenceDefinitionUrls::from_config),
+    ("MD137", MD137DisableCommentReason::from_config),
+    ("MD138", MD138HeadingLink::from_config),
+    ("MD139", MD139SortedLists::from_config),
+    ("MD140", MD140CodeFenceLength::from_config),
+    ("MD141", MD141UnreachableLinks::from_config),
+    ("MD142", MD142CodeIdentifierCase::from_config),
+    ("MD143", MD143ListItemSpacing::from_config),
+    ("MD144", MD144ListNestingDepth::from_config),
+    ("MD145", MD145HeadingIdStyle::from_config),
+    ("MD902", MD902LongParagraphFootnotes::from_config),
+];
+
 /// Returns all rule instances for config validation and CLI
 pub fn all_rules(config: &crate::config::Config) -> Vec<Box<dyn Rule>> {
-    type RuleCtor = fn(&crate::config::Config) -> Box<dyn Rule>;
-    const RULES: &[(&str, RuleCtor)] = &[
-        ("MD001", MD001HeadingIncrement::from_config),
-        ("MD003", MD003HeadingStyle::from_config),
-        ("MD004", MD004UnorderedListStyle::from_config),
-        ("MD005", MD005ListIndent::from_config),
-        ("MD007", MD007ULIndent::from_config),
-        ("MD009", MD009TrailingSpaces::from_config),
-        ("MD010", MD010NoHardTabs::from_config),
-        ("MD011", MD011NoReversedLinks::from_config),
-        ("MD012", MD012NoMultipleBlanks::from_config),
-        ("MD013", MD013LineLength::from_config),
-        ("MD014", MD014CommandsShowOutput::from_config),
-        ("MD018", MD018NoMissingSpaceAtx::from_config),
-        ("MD019", MD019NoMultipleSpaceAtx::from_config),
-        ("MD020", MD020NoMissingSpaceClosedAtx::from_config),
-        ("MD021", MD021NoMultipleSpaceClosedAtx::from_config),
-        ("MD022", MD022BlanksAroundHeadings::from_config),
-        ("MD023", MD023HeadingStartLeft::from_config),
-        ("MD024", MD024NoDuplicateHeading::from_config),
-        ("MD025", MD025SingleTitle::from_config),
-        ("MD026", MD026NoTrailingPunctuation::from_config),
-        ("MD027", MD027MultipleSpacesBlockquote::from_config),
-        ("MD028", MD028NoBlanksBlockquote::from_config),
-        ("MD029", MD029OrderedListPrefix::from_config),
-        ("MD030", MD030ListMarkerSpace::from_config),
-        ("MD031", MD031BlanksAroundFences::from_config),
-        ("MD032", MD032BlanksAroundLists::from_config),
-        ("MD033", MD033NoInlineHtml::from_config),
-        ("MD034", MD034NoBareUrls::from_config),
-        ("MD035", MD035HRStyle::from_config),
-        ("MD036", MD036NoEmphasisAsHeading::from_config),
-        ("MD037", MD037NoSpaceInEmphasis::from_config),
-        ("MD038", MD038NoSpaceInCode::from_config),
-        ("MD039", MD039NoSpaceInLinks::from_config),
-        ("MD040", MD040FencedCodeLanguage::from_config),
-        ("MD041", MD041FirstLineHeading::from_config),
-        ("MD042", MD042NoEmptyLinks::from_config),
-        ("MD043", MD043RequiredHeadings::from_config),
-        ("MD044", MD044ProperNames::from_config),
-        ("MD045", MD045NoAltText::from_config),
-        ("MD046", MD046CodeBlockStyle::from_config),
-        ("MD047", MD047SingleTrailingNewline::from_config),
-        ("MD048", MD048CodeFenceStyle::from_config),
-        ("MD049", MD049EmphasisStyle::from_config),
-        ("MD050", MD050StrongStyle::from_config),
-        ("MD051", MD051LinkFragments::from_config),
-        ("MD052", MD052ReferenceLinkImages::from_config),
-        ("MD053", MD053LinkImageReferenceDefinitions::from_config),
-        ("MD054", MD054LinkImageStyle::from_config),
-        ("MD055", MD055TablePipeStyle::from_config),
-        ("MD056", MD056TableColumnCount::from_config),
-        ("MD057", MD057ExistingRelativeLinks::from_config),
-        ("MD058", MD058BlanksAroundTables::from_config),
-        ("MD059", MD059LinkText::from_config),
-        ("MD060", MD060TableFormat::from_config),
-        ("MD061", MD061ForbiddenTerms::from_config),
-        ("MD062", MD062LinkDestinationWhitespace::from_config),
-        ("MD901", MD901DuplicateFootnotes::from_config),
-        ("MD902", MD902LongParagraphFootnotes::from_config),
-    ];
     RULES.iter().map(|(_, ctor)| ctor(config)).collect()
 }
 
+/// Constructs a single rule by name without instantiating the others
+///
+/// The lookup is case-insensitive and the `MD` prefix is optional, so `MD013`, `md013`
+/// and `013` all find the line length rule. Returns `None` for unknown names.
+pub fn rule_by_name(name: &str, config: &crate::config::Config) -> Option<Box<dyn Rule>> {
+    let query = name.trim().to_ascii_uppercase();
+    let number = query.strip_prefix("MD").unwrap_or(&query);
+    RULES
+        .iter()
+        .find(|(rule_name, _)| rule_name.strip_prefix("MD") == Some(number))
+        .map(|(_, ctor)| ctor(config))
+}
+
 // Filter rules based on config (moved from main.rs)
 // Note: This needs access to GlobalConfig from the config module.
 use crate::config::GlobalConfig;
@@ -4,7 +4,6 @@ use crate::file_processor;
 use colored::*;
 use rumdl_lib::config as rumdl_config;
 use rumdl_lib::exit_codes::exit;
-use rumdl_lib::lint_context::LintContext;
 use rumdl_lib::rule::Rule;
 use std::io::{self, Read};
 use std::str::FromStr;
@@ -66,23 +65,21 @@ pub fn process_stdin(rules: &[Box<dyn Rule>], args: &crate::CheckArgs, config: &
     // Convert stdin-filename to PathBuf for LintContext
     let source_file = args.stdin_filename.as_ref().map(std::path::PathBuf::from);
 
-    // Create a lint context for the stdin content
-    let ctx = LintContext::new(&content, config.markdown_flavor(), source_file.clone());
-    let mut all_warnings = Vec::new();
-
-    // Run all enabled rules on the content
-    for rule in rules {
-        match rule.check(&ctx) {
-            Ok(warnings) => {
-                all_warnings.extend(warnings);
-            }
-            Err(e) => {
-                if !args.silent {
-                    eprintln!("Error running rule {}: {}", rule.name(), e);
-                }
-            }
+    // Lint through the config so flavor selection, inline disable comments and disable-in apply
+    let (result, _file_index) = rumdl_lib::lint_and_index_with_config(
+        &content,
+        rules,
+        args.verbose,
+        config,
+        config.markdown_flavor(),
+        source_file.clone(),
+    );
+    let mut all_warnings = result.unwrap_or_else(|e| {
+        if !args.silent {
+            eprintln!("Error linting {display_filename}: {e}");
         }
-    }
+        Vec::new()
+    });
 
     // Sort warnings by line/column
     all_warnings.sort_by(|a, b| {
@@ -95,6 +92,42 @@ pub fn process_stdin(rules: &[Box<dyn Rule>], args: &crate::CheckArgs, config: &
 
     let has_issues = !all_warnings.is_empty();
 
+    // Formatter mode: stdout carries only the formatted document, diagnostics go to stderr
+    if args.fix_mode == crate::FixMode::Format {
+        let formatted = match rumdl_lib::format_content(&content, rules, config, config.markdown_flavor()) {
+            Ok(formatted) => formatted,
+            Err(e) => {
+                if !silent {
+                    eprintln!("{}: {}", "Error".red().bold(), e);
+                }
+                content.clone()
+            }
+        };
+
+        print!(
+            "{}",
+            rumdl_lib::utils::normalize_line_ending(&formatted, original_line_ending)
+        );
+
+        if !silent && !quiet && has_issues {
+            let (result, _file_index) = rumdl_lib::lint_and_index_with_config(
+                &formatted,
+                rules,
+                false,
+                config,
+                config.markdown_flavor(),
+                source_file,
+            );
+            let remaining_warnings = result.unwrap_or_default();
+            if !remaining_warnings.is_empty() {
+                let formatter = output_format.create_formatter();
+                eprintln!("{}", formatter.format_warnings(&remaining_warnings, display_filename));
+            }
+        }
+
+        return;
+    }
+
     // Apply fixes if requested
     if args.fix_mode != crate::FixMode::Check {
         if has_issues {
@@ -103,7 +136,8 @@ pub fn process_stdin(rules: &[Box<dyn Rule>], args: &crate::CheckArgs, config: &
                 rules,
                 &all_warnings,
                 &mut fixed_content,
-                quiet,
+                display_filename,
+                args.verbose,
                 silent,
                 config,
             );
@@ -115,13 +149,15 @@ pub fn process_stdin(rules: &[Box<dyn Rule>], args: &crate::CheckArgs, config: &
             print!("{output_content}");
 
             // Re-check the fixed content to see if any issues remain
-            let fixed_ctx = LintContext::new(&fixed_content, config.markdown_flavor(), source_file.clone());
-            let mut remaining_warnings = Vec::new();
-            for rule in rules {
-                if let Ok(warnings) = rule.check(&fixed_ctx) {
-                    remaining_warnings.extend(warnings);
-                }
-            }
+            let (result, _file_index) = rumdl_lib::lint_and_index_with_config(
+                &fixed_content,
+                rules,
+                false,
+                config,
+                config.markdown_flavor(),
+                source_file.clone(),
+            );
+            let remaining_warnings = result.unwrap_or_default();
 
             // Only show diagnostics to stderr unless silent
             if !silent && !remaining_warnings.is_empty() {
@@ -139,22 +175,57 @@ pub fn process_stdin(rules: &[Box<dyn Rule>], args: &crate::CheckArgs, config: &
                 exit::violations_found();
             }
         } else {
-            print!("{content}");
+            print!(
+                "{}",
+                rumdl_lib::utils::normalize_line_ending(&content, original_line_ending)
+            );
         }
 
         return;
     }
 
+    // Counted up front because batch formats take ownership of the warnings
+    let (errors, warnings) = crate::formatter::count_by_severity(&all_warnings);
+    let fixable = file_processor::count_fixable(config, &all_warnings);
+    let json_summary = args
+        .json_summary()
+        .then(|| crate::formatter::SummaryRecord::new(&all_warnings, 1, usize::from(has_issues), fixable).to_json());
+
     // Normal check mode (no fix) - output diagnostics
     // Batch formats need all warnings collected before formatting
     match output_format {
-        OutputFormat::Json | OutputFormat::GitLab | OutputFormat::Sarif | OutputFormat::Junit => {
+        OutputFormat::Json
+        | OutputFormat::GitLab
+        | OutputFormat::CodeClimate
+        | OutputFormat::Sarif
+        | OutputFormat::Rdjson
+        | OutputFormat::Junit => {
             let file_warnings = vec![(display_filename.to_string(), all_warnings)];
             let output = match output_format {
                 OutputFormat::Json => rumdl_lib::output::formatters::json::format_all_warnings_as_json(&file_warnings),
                 OutputFormat::GitLab => rumdl_lib::output::formatters::gitlab::format_gitlab_report(&file_warnings),
+                OutputFormat::CodeClimate => {
+                    rumdl_lib::output::formatters::code_climate::format_code_climate_report_with_sources(
+                        &file_warnings,
+                        |_| Some(content.clone()),
+                    )
+                }
                 OutputFormat::Sarif => rumdl_lib::output::formatters::sarif::format_sarif_report(&file_warnings),
-                OutputFormat::Junit => rumdl_lib::output::formatters::junit::format_junit_report(&file_warnings, 0),
+                OutputFormat::Rdjson => {
+                    rumdl_lib::output::formatters::rdjson::format_rdjson_report_with_sources(&file_warnings, |_| {
+                        Some(content.clone())
+                    })
+                }
+                OutputFormat::Junit => rumdl_lib::output::formatters::junit::format_junit_report_with_granularity(
+                    &file_warnings,
+                    0,
+                    args.junit_granularity
+                        .as_deref()
+                        .unwrap_or("file")
+                        .parse()
+                        .unwrap_or_default(),
+                    &rumdl_lib::output::formatters::junit::rule_descriptions(rules),
+                ),
                 _ => unreachable!("Outer match guarantees only batch formats here"),
             };
 
@@ -192,6 +263,13 @@ pub fn process_stdin(rules: &[Box<dyn Rule>], args: &crate::CheckArgs, config: &
         }
     }
 
+    if args.text_summary() {
+        eprintln!("{}", crate::formatter::summary_line(errors, warnings, fixable, 1));
+    }
+    if let Some(summary) = json_summary {
+        eprintln!("{summary}");
+    }
+
     // Exit with error code if issues found
     if has_issues {
         exit::violations_found();
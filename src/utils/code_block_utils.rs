@@ -8,7 +8,7 @@
 //! - Mixed fence types (tilde fence contains backticks as content)
 //! - Indented code blocks with proper list context handling
 
-use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
 
 /// Classification of code blocks relative to list contexts
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -69,6 +69,33 @@ impl CodeBlockUtils {
         blocks
     }
 
+    /// Detect only fenced code blocks (``` and ~~~), skipping indented code blocks
+    ///
+    /// Returns (start, end) byte offsets like [`Self::detect_code_blocks`], in document order.
+    pub fn detect_fenced_code_blocks(content: &str) -> Vec<(usize, usize)> {
+        let parser = Parser::new_ext(content, Options::all()).into_offset_iter();
+        parser
+            .filter_map(|(event, range)| match event {
+                Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(_))) => Some((range.start, range.end)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Detect only indented code blocks, skipping fenced code blocks
+    ///
+    /// Each range starts after the block's indentation on its first line, so list
+    /// continuation content (which isn't code) is never included.
+    pub fn detect_indented_code_blocks(content: &str) -> Vec<(usize, usize)> {
+        let parser = Parser::new_ext(content, Options::all()).into_offset_iter();
+        parser
+            .filter_map(|(event, range)| match event {
+                Event::Start(Tag::CodeBlock(CodeBlockKind::Indented)) => Some((range.start, range.end)),
+                _ => None,
+            })
+            .collect()
+    }
+
     /// Check if a position is within a code block (for compatibility)
     pub fn is_in_code_block_or_span(blocks: &[(usize, usize)], pos: usize) -> bool {
         // This is a compatibility function - it only checks code blocks now, not spans
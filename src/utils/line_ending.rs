@@ -7,9 +7,11 @@ pub enum LineEnding {
 
 pub fn detect_line_ending_enum(content: &str) -> LineEnding {
     let has_crlf = content.contains("\r\n");
-    // Check if there are LF characters that are NOT part of CRLF
-    let content_without_crlf = content.replace("\r\n", "");
-    let has_standalone_lf = content_without_crlf.contains('\n');
+    // Check if there are LF characters that are NOT part of CRLF (without copying the content)
+    let bytes = content.as_bytes();
+    let has_standalone_lf = content
+        .match_indices('\n')
+        .any(|(i, _)| i == 0 || bytes[i - 1] != b'\r');
 
     match (has_crlf, has_standalone_lf) {
         (true, true) => LineEnding::Mixed, // Has both CRLF and standalone LF
@@ -83,6 +85,8 @@ mod tests {
         assert_eq!(detect_line_ending_enum("hello\r\nworld"), LineEnding::Crlf);
         assert_eq!(detect_line_ending_enum("hello\r\nworld\nmixed"), LineEnding::Mixed);
         assert_eq!(detect_line_ending_enum("no line endings"), LineEnding::Lf);
+        assert_eq!(detect_line_ending_enum("\nleading\r\n"), LineEnding::Mixed);
+        assert_eq!(detect_line_ending_enum("trailing\r\n\n"), LineEnding::Mixed);
     }
 
     #[test]
@@ -0,0 +1,106 @@
+use regex::Regex;
+/// MkDocs Abbreviations detection utilities
+///
+/// The Abbreviations extension (`abbr`) provides support for defining
+/// abbreviations that are wrapped in `<abbr>` tags wherever they appear.
+///
+/// Common patterns:
+/// - `*[HTML]: HyperText Markup Language` - Abbreviation definition
+/// - `*[W3C]:  World Wide Web Consortium` - Extra spaces are allowed
+/// - `*[HTML]:` - Empty definition (removes a previously defined abbreviation)
+use std::sync::LazyLock;
+
+/// Pattern to match abbreviation definitions at start of line
+/// `*[ABBR]: Definition text`
+static ABBREVIATION_DEF: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^( {0,3})\*\[([^\]]+)\]:[ \t]*(.*?)[ \t]*$").unwrap());
+
+/// A parsed abbreviation definition
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbbreviationDefinition<'a> {
+    /// The abbreviation being defined (e.g. `HTML`)
+    pub abbreviation: &'a str,
+    /// The expansion text (may be empty)
+    pub definition: &'a str,
+    /// Byte offset of the abbreviation within the line
+    pub abbreviation_start: usize,
+}
+
+/// Check if a line is an abbreviation definition
+pub fn is_abbreviation_definition(line: &str) -> bool {
+    ABBREVIATION_DEF.is_match(line)
+}
+
+/// Parse an abbreviation definition line
+pub fn parse_abbreviation_definition(line: &str) -> Option<AbbreviationDefinition<'_>> {
+    let caps = ABBREVIATION_DEF.captures(line)?;
+    let abbr = caps.get(2)?;
+    Some(AbbreviationDefinition {
+        abbreviation: abbr.as_str(),
+        definition: caps.get(3).map_or("", |m| m.as_str()),
+        abbreviation_start: abbr.start(),
+    })
+}
+
+/// Check if an abbreviation occurs as a whole word in the given text
+///
+/// Mirrors the word-boundary matching used by the Python-Markdown `abbr` extension.
+pub fn contains_abbreviation(text: &str, abbreviation: &str) -> bool {
+    let mut search_from = 0;
+    while let Some(pos) = text[search_from..].find(abbreviation) {
+        let start = search_from + pos;
+        let end = start + abbreviation.len();
+
+        let before_ok = text[..start].chars().last().is_none_or(|c| !is_word_char(c));
+        let after_ok = text[end..].chars().next().is_none_or(|c| !is_word_char(c));
+        if before_ok && after_ok {
+            return true;
+        }
+
+        search_from = start + abbreviation.chars().next().map_or(1, char::len_utf8);
+    }
+    false
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_abbreviation_definition() {
+        assert!(is_abbreviation_definition("*[HTML]: HyperText Markup Language"));
+        assert!(is_abbreviation_definition("*[W3C]:  World Wide Web Consortium"));
+        assert!(is_abbreviation_definition("   *[CSS]: Cascading Style Sheets"));
+        assert!(is_abbreviation_definition("*[HTML]:"));
+        assert!(!is_abbreviation_definition("    *[HTML]: Indented code"));
+        assert!(!is_abbreviation_definition("[HTML]: https://example.com"));
+        assert!(!is_abbreviation_definition("* [HTML]: list item"));
+    }
+
+    #[test]
+    fn test_parse_abbreviation_definition() {
+        let def = parse_abbreviation_definition("*[HTML]: HyperText Markup Language  ").unwrap();
+        assert_eq!(def.abbreviation, "HTML");
+        assert_eq!(def.definition, "HyperText Markup Language");
+        assert_eq!(def.abbreviation_start, 2);
+
+        let def = parse_abbreviation_definition("*[Two words]:").unwrap();
+        assert_eq!(def.abbreviation, "Two words");
+        assert_eq!(def.definition, "");
+    }
+
+    #[test]
+    fn test_contains_abbreviation() {
+        assert!(contains_abbreviation("The HTML spec", "HTML"));
+        assert!(contains_abbreviation("HTML", "HTML"));
+        assert!(contains_abbreviation("Use HTML.", "HTML"));
+        assert!(!contains_abbreviation("XHTML only", "HTML"));
+        assert!(!contains_abbreviation("HTML5 only", "HTML"));
+        assert!(!contains_abbreviation("html lowercase", "HTML"));
+        assert!(contains_abbreviation("XHTML and HTML", "HTML"));
+    }
+}
@@ -27,9 +27,39 @@ static ADMONITION_MARKER: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(\s*)
 /// Pattern to validate admonition type characters
 static VALID_TYPE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[a-zA-Z][a-zA-Z0-9_-]*$").unwrap());
 
-// Common admonition types recognized by MkDocs
-// Note: Any word is valid as a custom type, so this list is informational
-// Types: note, abstract, info, tip, success, question, warning, failure, danger, bug, example, quote
+/// Admonition types styled by Material for MkDocs, including their aliases
+///
+/// Any word is accepted by the extension itself, but unknown types fall back to the
+/// default styling, so this list is used to catch typos.
+pub const KNOWN_ADMONITION_TYPES: &[&str] = &[
+    "note",
+    "abstract",
+    "summary",
+    "tldr",
+    "info",
+    "todo",
+    "tip",
+    "hint",
+    "important",
+    "success",
+    "check",
+    "done",
+    "question",
+    "help",
+    "faq",
+    "warning",
+    "caution",
+    "attention",
+    "failure",
+    "fail",
+    "missing",
+    "danger",
+    "error",
+    "bug",
+    "example",
+    "quote",
+    "cite",
+];
 
 /// Check if a line is an admonition start marker
 pub fn is_admonition_start(line: &str) -> bool {
@@ -67,6 +97,15 @@ pub fn is_admonition_start(line: &str) -> bool {
     ADMONITION_START.is_match(line)
 }
 
+/// Extract the type keyword of an admonition start line with its byte offset in the line
+pub fn get_admonition_type(line: &str) -> Option<(&str, usize)> {
+    if !is_admonition_start(line) {
+        return None;
+    }
+    let type_match = ADMONITION_START.captures(line)?.get(2)?;
+    Some((type_match.as_str(), type_match.start()))
+}
+
 /// Check if a line contains any admonition marker
 pub fn is_admonition_marker(line: &str) -> bool {
     ADMONITION_MARKER.is_match(line)
@@ -180,6 +219,14 @@ mod tests {
         assert!(!is_admonition_start("# Heading"));
     }
 
+    #[test]
+    fn test_get_admonition_type() {
+        assert_eq!(get_admonition_type("!!! note"), Some(("note", 4)));
+        assert_eq!(get_admonition_type("  ???+ Warning \"Title\""), Some(("Warning", 7)));
+        assert_eq!(get_admonition_type("!!! tip inline end"), Some(("tip", 4)));
+        assert_eq!(get_admonition_type("Regular text"), None);
+    }
+
     #[test]
     fn test_admonition_indent() {
         assert_eq!(get_admonition_indent("!!! note"), Some(0));
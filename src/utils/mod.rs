@@ -15,6 +15,7 @@ pub mod jinja_utils;
 pub mod kramdown_utils;
 pub mod line_ending;
 pub mod markdown_elements;
+pub mod mkdocs_abbreviations;
 pub mod mkdocs_admonitions;
 pub mod mkdocs_common;
 pub mod mkdocs_critic;
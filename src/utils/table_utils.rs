@@ -3,7 +3,7 @@
 /// This module provides optimized table detection and processing functionality
 /// that can be shared across multiple table-related rules (MD055, MD056, MD058).
 /// Represents a table block in the document
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct TableBlock {
     pub start_line: usize,
     pub end_line: usize,
@@ -885,12 +885,12 @@ fn parse_markdown_elements(text: &str) -> Vec<Element> {
                 }
                 "html_entity" => {
                     // HTML entities are captured whole
-                    elements.push(Element::HtmlEntity(remaining[..match_obj.end()].to_string()));
+                    elements.push(Element::HtmlEntity(remaining[pos..match_obj.end()].to_string()));
                     remaining = &remaining[match_obj.end()..];
                 }
                 "html_tag" => {
                     // HTML tags are captured whole
-                    elements.push(Element::HtmlTag(remaining[..match_obj.end()].to_string()));
+                    elements.push(Element::HtmlTag(remaining[pos..match_obj.end()].to_string()));
                     remaining = &remaining[match_obj.end()..];
                 }
                 _ => {
@@ -963,6 +963,8 @@ fn reflow_elements_sentence_per_line(elements: &[Element], custom_abbreviations:
     let abbreviations = get_abbreviations(custom_abbreviations);
     let mut lines = Vec::new();
     let mut current_line = String::new();
+    // Whether the source had whitespace right before the current element
+    let mut space_before = false;
 
     for element in elements.iter() {
         let element_str = format!("{element}");
@@ -1015,16 +1017,14 @@ fn reflow_elements_sentence_per_line(elements: &[Element], custom_abbreviations:
             }
         } else {
             // Non-text elements (Code, Bold, Italic, etc.)
-            // Add space before element if needed (unless it's after an opening paren/bracket)
-            if !current_line.is_empty()
-                && !current_line.ends_with(' ')
-                && !current_line.ends_with('(')
-                && !current_line.ends_with('[')
-            {
+            // Restore the space before the element if sentence splitting trimmed it;
+            // an element glued to the preceding text stays glued
+            if space_before && !current_line.is_empty() && !current_line.ends_with(' ') {
                 current_line.push(' ');
             }
             current_line.push_str(&element_str);
         }
+        space_before = matches!(element, Element::Text(text) if text.ends_with(char::is_whitespace));
     }
 
     // Add any remaining content
@@ -1039,6 +1039,8 @@ fn reflow_elements(elements: &[Element], options: &ReflowOptions) -> Vec<String>
     let mut lines = Vec::new();
     let mut current_line = String::new();
     let mut current_length = 0;
+    // Whether the source had whitespace right before the current element
+    let mut space_before = false;
 
     for element in elements {
         let element_str = format!("{element}");
@@ -1058,7 +1060,14 @@ fn reflow_elements(elements: &[Element], options: &ReflowOptions) -> Vec<String>
                     .chars()
                     .all(|c| matches!(c, ',' | '.' | ':' | ';' | '!' | '?' | ')' | ']' | '}'));
 
-                if current_length > 0 && current_length + 1 + word_len > options.line_length && !is_trailing_punct {
+                // A word glued to the preceding element can't start a new line either
+                let glued = i == 0 && !has_leading_space;
+
+                if current_length > 0
+                    && current_length + 1 + word_len > options.line_length
+                    && !is_trailing_punct
+                    && !glued
+                {
                     // Start a new line (but never for trailing punctuation)
                     lines.push(current_line.trim().to_string());
                     current_line = word.to_string();
@@ -1078,7 +1087,11 @@ fn reflow_elements(elements: &[Element], options: &ReflowOptions) -> Vec<String>
         } else {
             // For non-text elements (code, links, references), treat as atomic units
             // These should never be broken across lines
-            if current_length > 0 && current_length + 1 + element_len > options.line_length {
+            if current_length > 0 && !space_before {
+                // Glued to the preceding text (e.g. `word<b>`), so no break and no space
+                current_line.push_str(&element_str);
+                current_length += element_len;
+            } else if current_length > 0 && current_length + 1 + element_len > options.line_length {
                 // Start a new line
                 lines.push(current_line.trim().to_string());
                 current_line = element_str;
@@ -1096,6 +1109,7 @@ fn reflow_elements(elements: &[Element], options: &ReflowOptions) -> Vec<String>
                 current_length += element_len;
             }
         }
+        space_before = matches!(element, Element::Text(text) if text.ends_with(char::is_whitespace));
     }
 
     // Don't forget the last line
@@ -6,9 +6,9 @@ use colored::*;
 use notify::{Config as NotifyConfig, Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
 use rayon::prelude::*;
 use rumdl_lib::config as rumdl_config;
-use rumdl_lib::rule::CrossFileScope;
-use rumdl_lib::workspace_index::WorkspaceIndex;
-use std::collections::HashMap;
+use rumdl_lib::rule::{CrossFileScope, LintWarning, Rule};
+use rumdl_lib::workspace_index::{FileIndex, WorkspaceIndex};
+use std::collections::{HashMap, HashSet};
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
@@ -55,9 +55,7 @@ pub fn change_detected(event: &Event) -> Option<ChangeKind> {
         }
 
         // Check for markdown files
-        if let Some(extension) = path.extension()
-            && matches!(extension.to_str(), Some("md" | "markdown" | "mdown" | "mkd" | "mdx"))
-        {
+        if is_markdown_path(path) {
             source_file = true;
         }
     }
@@ -69,6 +67,12 @@ pub fn change_detected(event: &Event) -> Option<ChangeKind> {
     }
 }
 
+/// Whether a path has a markdown file extension
+fn is_markdown_path(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| matches!(ext.to_str(), Some("md" | "markdown" | "mdown" | "mkd" | "mdx")))
+}
+
 /// Clear the terminal screen
 pub fn clear_screen() {
     // ANSI escape sequence to clear screen and move cursor to top-left
@@ -77,6 +81,9 @@ pub fn clear_screen() {
 }
 
 /// Perform a single check run (extracted from run_check for reuse in watch mode)
+///
+/// Watch mode passes a [`WatchState`] so cross-file checks are updated incrementally.
+#[allow(clippy::too_many_arguments)]
 pub fn perform_check_run(
     args: &crate::CheckArgs,
     config: &rumdl_config::Config,
@@ -84,8 +91,10 @@ pub fn perform_check_run(
     cache: Option<Arc<std::sync::Mutex<crate::cache::LintCache>>>,
     workspace_cache_dir: Option<&Path>,
     project_root: Option<&Path>,
+    dir_configs: Option<&crate::file_processor::DirectoryConfigs>,
+    watch_state: Option<&mut WatchState>,
 ) -> bool {
-    use rumdl_lib::output::{OutputFormat, OutputWriter};
+    use rumdl_lib::output::{OutputFormat, OutputWriter, SortBy};
 
     // Create output writer for linting results
     let output_writer = OutputWriter::new(args.stderr, quiet, args.silent);
@@ -109,6 +118,16 @@ pub fn perform_check_run(
         }
     };
 
+    // Global ordering for --sort-by: flat formats only, and only when reporting (not fixing)
+    let sort_by = args
+        .sort_by
+        .as_deref()
+        .and_then(|s| s.parse::<SortBy>().ok())
+        .filter(|_| output_format.supports_sorting() && args.fix_mode == crate::FixMode::Check && !args.diff);
+
+    let report_root = crate::file_processor::resolve_report_root(args, config, project_root);
+    let report_path = |file_path: &str| crate::file_processor::report_path(file_path, report_root.as_deref());
+
     // Initialize rules with configuration
     let enabled_rules = crate::file_processor::get_enabled_rules_from_checkargs(args, config);
 
@@ -118,23 +137,6 @@ pub fn perform_check_run(
         return false; // stdin processing handles its own exit codes
     }
 
-    // Find all markdown files to check
-    let file_paths = match crate::file_processor::find_markdown_files(&args.paths, args, config, project_root) {
-        Ok(paths) => paths,
-        Err(e) => {
-            if !args.silent {
-                eprintln!("{}: Failed to find markdown files: {}", "Error".red().bold(), e);
-            }
-            return true; // Has errors
-        }
-    };
-    if file_paths.is_empty() {
-        if !quiet {
-            println!("No markdown files found to check.");
-        }
-        return false;
-    }
-
     // Check if any enabled rule needs cross-file analysis
     let needs_cross_file = enabled_rules
         .iter()
@@ -143,27 +145,71 @@ pub fn perform_check_run(
     // For formats that need to collect all warnings first
     let needs_collection = matches!(
         output_format,
-        OutputFormat::Json | OutputFormat::GitLab | OutputFormat::Sarif | OutputFormat::Junit
+        OutputFormat::Json
+            | OutputFormat::GitLab
+            | OutputFormat::CodeClimate
+            | OutputFormat::Sarif
+            | OutputFormat::Rdjson
+            | OutputFormat::Junit
+            | OutputFormat::Canonical
     );
 
+    // --fail-fast only applies to checking; the file being edited is most likely the newest
+    let fail_fast = args.fail_fast && args.fix_mode == crate::FixMode::Check;
+    // Set when --fail-fast stops before every file is checked
+    let mut stopped_early = false;
+
+    // Files are linted while the directory walk is still running, unless the whole list is
+    // needed first: batch formats report once at the end, and --fail-fast reorders files
+    let stream_files = !needs_collection && !fail_fast;
+
+    // Find all markdown files to check (when streaming, they are found while linting)
+    let mut file_paths = if stream_files {
+        Vec::new()
+    } else {
+        match crate::file_processor::find_markdown_files(&args.paths, args, config, project_root) {
+            Ok(paths) => paths,
+            Err(e) => {
+                if !args.silent {
+                    eprintln!("{}: Failed to find markdown files: {}", "Error".red().bold(), e);
+                }
+                return true; // Has errors
+            }
+        }
+    };
+    if !stream_files && file_paths.is_empty() {
+        if !quiet {
+            println!("No markdown files found to check.");
+        }
+        return false;
+    }
+    if fail_fast {
+        sort_most_recently_modified_first(&mut file_paths);
+    }
+
     if needs_collection {
         let start_time = Instant::now();
         let mut all_file_warnings = Vec::new();
         let mut has_issues = false;
         let mut _files_with_issues = 0;
         let mut _total_issues = 0;
+        let mut total_fixable_issues = 0;
 
         // Phase 1: Lint all files and collect FileIndex data (no second pass needed)
-        let mut file_indices: HashMap<PathBuf, rumdl_lib::workspace_index::FileIndex> = HashMap::new();
+        let mut file_indices: HashMap<PathBuf, FileIndex> = HashMap::new();
 
         for file_path in &file_paths {
+            let dir_config = dir_configs.and_then(|d| d.for_file(file_path));
+            let (file_rules, file_config) = dir_config
+                .as_deref()
+                .map_or((enabled_rules.as_slice(), config), |c| (c.rules.as_slice(), &c.config));
             let result = crate::file_processor::process_file_with_index(
                 file_path,
-                &enabled_rules,
+                file_rules,
                 args.verbose && !args.silent,
                 quiet,
                 args.silent,
-                config,
+                file_config,
                 cache.as_ref().map(Arc::clone),
             );
 
@@ -171,105 +217,90 @@ pub fn perform_check_run(
                 has_issues = true;
                 _files_with_issues += 1;
                 _total_issues += result.warnings.len();
+                total_fixable_issues += result.fixable_warnings;
                 all_file_warnings.push((file_path.clone(), result.warnings));
+                if fail_fast {
+                    stopped_early = true;
+                    break;
+                }
             }
 
             // Store FileIndex for cross-file analysis (extracted from single linting pass)
             if needs_cross_file {
                 // Canonicalize path for consistent cache key matching
-                let canonical = std::fs::canonicalize(file_path).unwrap_or_else(|_| PathBuf::from(file_path));
+                let canonical = canonical_path(Path::new(file_path));
                 file_indices.insert(canonical, result.file_index);
             }
         }
 
-        // Phase 2: Run cross-file checks if needed
-        if needs_cross_file && !file_indices.is_empty() {
-            let index_start = Instant::now();
-
-            // Load workspace index from cache if available, otherwise start fresh
-            let mut workspace_index = workspace_cache_dir
-                .and_then(WorkspaceIndex::load_from_cache)
-                .unwrap_or_default();
-
-            let loaded_from_cache = workspace_index.file_count() > 0;
-            if args.verbose && !args.silent && loaded_from_cache {
-                eprintln!(
-                    "Loaded workspace index from cache with {} files",
-                    workspace_index.file_count()
-                );
-            }
-
-            // Incremental update: only update files that have changed (stale)
-            let mut updated_count = 0;
-            let mut skipped_count = 0;
-            for (path, file_index) in file_indices {
-                if workspace_index.is_file_stale(&path, &file_index.content_hash) {
-                    workspace_index.update_file(&path, file_index);
-                    updated_count += 1;
+        // Phase 2: Run cross-file checks if needed (a partial index would give false positives)
+        if needs_cross_file && !stopped_early && !file_indices.is_empty() {
+            let cross_file_results = run_cross_file_phase(
+                args,
+                &file_paths,
+                file_indices,
+                &enabled_rules,
+                config,
+                workspace_cache_dir,
+                watch_state,
+            );
+            let cross_file_results = retain_enabled_cross_file(cross_file_results, dir_configs);
+            for (file_path, cross_file_warnings) in cross_file_results {
+                let file_path_str = file_path.to_string_lossy().to_string();
+                total_fixable_issues += crate::file_processor::count_fixable(config, &cross_file_warnings);
+                // Find existing entry or create new one
+                if let Some((_, warnings)) = all_file_warnings.iter_mut().find(|(p, _)| p == &file_path_str) {
+                    warnings.extend(cross_file_warnings);
                 } else {
-                    skipped_count += 1;
+                    has_issues = true;
+                    _files_with_issues += 1;
+                    _total_issues += cross_file_warnings.len();
+                    all_file_warnings.push((file_path_str, cross_file_warnings));
                 }
             }
+        }
 
-            // Prune deleted files from workspace index (use canonical paths for matching)
-            let current_files: std::collections::HashSet<PathBuf> = file_paths
-                .iter()
-                .map(|p| std::fs::canonicalize(p).unwrap_or_else(|_| PathBuf::from(p)))
-                .collect();
-            let pruned_count = workspace_index.retain_only(&current_files);
-
-            if args.verbose && !args.silent {
-                eprintln!(
-                    "Workspace index: {} updated, {} unchanged, {} pruned ({} total) in {:?}",
-                    updated_count,
-                    skipped_count,
-                    pruned_count,
-                    workspace_index.file_count(),
-                    index_start.elapsed()
-                );
-            }
-
-            // Run cross-file checks for each file using the FileIndex (no re-parsing needed)
-            for (file_path, file_index) in workspace_index.files() {
-                if let Ok(cross_file_warnings) =
-                    rumdl_lib::run_cross_file_checks(file_path, file_index, &enabled_rules, &workspace_index)
-                    && !cross_file_warnings.is_empty()
-                {
-                    let file_path_str = file_path.to_string_lossy().to_string();
-                    // Find existing entry or create new one
-                    if let Some((_, warnings)) = all_file_warnings.iter_mut().find(|(p, _)| p == &file_path_str) {
-                        warnings.extend(cross_file_warnings);
-                    } else {
-                        has_issues = true;
-                        _files_with_issues += 1;
-                        _total_issues += cross_file_warnings.len();
-                        all_file_warnings.push((file_path_str, cross_file_warnings));
-                    }
-                }
-            }
+        let duration_ms = start_time.elapsed().as_millis() as u64;
 
-            // Save workspace index to cache
-            if let Some(cache_dir) = workspace_cache_dir {
-                if let Err(e) = workspace_index.save_to_cache(cache_dir) {
-                    log::warn!("Failed to save workspace index cache: {e}");
-                } else if args.verbose && !args.silent {
-                    eprintln!(
-                        "Saved workspace index cache with {} files",
-                        workspace_index.file_count()
-                    );
-                }
-            }
+        for (file_path, _) in &mut all_file_warnings {
+            *file_path = report_path(file_path);
         }
 
-        let duration_ms = start_time.elapsed().as_millis() as u64;
+        // With --sort-by, the flat JSON list holds one entry per warning in the requested order
+        let sorted_file_warnings = sort_by.map(|sort_by| {
+            let mut flat: Vec<(String, LintWarning)> = all_file_warnings
+                .iter()
+                .flat_map(|(path, warnings)| warnings.iter().map(|w| (path.clone(), w.clone())))
+                .collect();
+            rumdl_lib::output::sort_warnings(&mut flat, sort_by);
+            flat.into_iter()
+                .map(|(path, warning)| (path, vec![warning]))
+                .collect::<Vec<_>>()
+        });
 
         // Format output based on type
         let output = match output_format {
-            OutputFormat::Json => rumdl_lib::output::formatters::json::format_all_warnings_as_json(&all_file_warnings),
+            OutputFormat::Json => rumdl_lib::output::formatters::json::format_all_warnings_as_json(
+                sorted_file_warnings.as_deref().unwrap_or(&all_file_warnings),
+            ),
             OutputFormat::GitLab => rumdl_lib::output::formatters::gitlab::format_gitlab_report(&all_file_warnings),
+            OutputFormat::CodeClimate => {
+                rumdl_lib::output::formatters::code_climate::format_code_climate_report(&all_file_warnings)
+            }
             OutputFormat::Sarif => rumdl_lib::output::formatters::sarif::format_sarif_report(&all_file_warnings),
-            OutputFormat::Junit => {
-                rumdl_lib::output::formatters::junit::format_junit_report(&all_file_warnings, duration_ms)
+            OutputFormat::Rdjson => rumdl_lib::output::formatters::rdjson::format_rdjson_report(&all_file_warnings),
+            OutputFormat::Junit => rumdl_lib::output::formatters::junit::format_junit_report_with_granularity(
+                &all_file_warnings,
+                duration_ms,
+                args.junit_granularity
+                    .as_deref()
+                    .unwrap_or("file")
+                    .parse()
+                    .unwrap_or_default(),
+                &rumdl_lib::output::formatters::junit::rule_descriptions(&enabled_rules),
+            ),
+            OutputFormat::Canonical => {
+                rumdl_lib::output::formatters::canonical::format_canonical_report(&all_file_warnings)
             }
             _ => unreachable!("needs_collection check above guarantees only batch formats here"),
         };
@@ -278,29 +309,70 @@ pub fn perform_check_run(
             eprintln!("Error writing output: {e}");
         });
 
+        if args.check_idempotent && !check_idempotency(args, &file_paths, &enabled_rules, config) {
+            has_issues = true;
+        }
+
+        if args.statistics_history.is_some() {
+            let warnings: Vec<LintWarning> = all_file_warnings.iter().flat_map(|(_, w)| w.iter().cloned()).collect();
+            write_statistics_history(args, &warnings, file_paths.len());
+        }
+
+        if args.text_summary() {
+            let (errors, warnings) = all_file_warnings
+                .iter()
+                .map(|(_, w)| formatter::count_by_severity(w))
+                .fold((0, 0), |acc, (e, w)| (acc.0 + e, acc.1 + w));
+            eprintln!(
+                "{}",
+                formatter::summary_line(errors, warnings, total_fixable_issues, file_paths.len())
+            );
+        }
+        if args.json_summary() {
+            let summary = formatter::SummaryRecord::new(
+                all_file_warnings.iter().flat_map(|(_, w)| w),
+                file_paths.len(),
+                all_file_warnings.iter().filter(|(_, w)| !w.is_empty()).count(),
+                total_fixable_issues,
+            );
+            eprintln!("{}", summary.to_json());
+        }
+
         return has_issues;
     }
 
     let start_time = Instant::now();
 
-    // Enable parallel processing for both check and fix modes when there are multiple files
+    // Enable parallel processing for both check and fix modes as files are streamed in
     // Each file is processed independently (with all its fix iterations), so parallel processing is safe
-    // Single files cannot be parallelized at the file level (would need rule-level parallelization)
     // Cache is thread-safe (Arc<Mutex<>>) so parallel processing works with caching enabled
-    let use_parallel = file_paths.len() > 1;
+    // --fail-fast goes one file at a time so it can stop after the first file with issues
+    let use_parallel = stream_files;
 
-    // Collect all warnings for statistics if requested
+    // Collect all warnings for statistics or the JSON summary if requested
+    let collect_stats = args.statistics || args.statistics_history.is_some() || args.json_summary();
     let mut all_warnings_for_stats = Vec::new();
+    // (errors, warnings) for --summary
+    let mut severity_counts = (0, 0);
 
     // For cross-file analysis, we collect FileIndex data during linting (no second pass needed)
-    let mut file_indices: HashMap<PathBuf, rumdl_lib::workspace_index::FileIndex> = HashMap::new();
+    let mut file_indices: HashMap<PathBuf, FileIndex> = HashMap::new();
+
+    // With --sort-by, warnings are printed together once every file is linted
+    let mut sorted_warnings: Vec<(String, LintWarning)> = Vec::new();
+    let deferred_writer = OutputWriter::new(args.stderr, quiet, true);
+    let file_output_writer = if sort_by.is_some() {
+        &deferred_writer
+    } else {
+        &output_writer
+    };
 
     let (
         mut has_issues,
         mut files_with_issues,
         mut total_issues,
         total_issues_fixed,
-        total_fixable_issues,
+        mut total_fixable_issues,
         total_files_processed,
     ) = if use_parallel {
         // Parallel processing for multiple files with thread-safe cache
@@ -308,27 +380,65 @@ pub fn perform_check_run(
         let enabled_rules_arc = Arc::new(enabled_rules.clone());
 
         // Process files in parallel - now includes FileIndex in the result (no second pass needed)
-        let results: Vec<_> = file_paths
-            .par_iter()
-            .map(|file_path| {
-                // Clone Arc (cheap - just increments reference count)
-                // process_file_with_formatter locks mutex briefly for cache operations
-                let result = crate::file_processor::process_file_with_formatter(
-                    file_path,
-                    &enabled_rules_arc,
-                    args.fix_mode,
-                    args.diff,
-                    args.verbose && !args.silent,
-                    quiet,
-                    args.silent,
-                    &output_format,
-                    &output_writer,
-                    config,
-                    cache.as_ref().map(Arc::clone),
-                );
-                (file_path.clone(), result)
-            })
-            .collect();
+        let lint_file = |file_path: String| {
+            let dir_config = dir_configs.and_then(|d| d.for_file(&file_path));
+            let (file_rules, file_config) = dir_config
+                .as_deref()
+                .map_or((enabled_rules_arc.as_slice(), config), |c| {
+                    (c.rules.as_slice(), &c.config)
+                });
+            // Clone Arc (cheap - just increments reference count)
+            // process_file_with_formatter locks mutex briefly for cache operations
+            let result = crate::file_processor::process_file_with_formatter(
+                &file_path,
+                file_rules,
+                args.fix_mode,
+                args.diff,
+                args.backup_suffix(),
+                args.verbose && !args.silent,
+                quiet,
+                args.silent,
+                &output_format,
+                file_output_writer,
+                report_root.as_deref(),
+                file_config,
+                cache.as_ref().map(Arc::clone),
+            );
+            (file_path, result)
+        };
+
+        // The walk feeds files to the workers as it finds them, so linting starts right away
+        let (tx, rx) = channel();
+        let (walk_result, results): (Result<(), String>, Vec<_>) = std::thread::scope(|scope| {
+            let walker = scope.spawn(move || {
+                crate::file_processor::walk_markdown_files(&args.paths, args, config, project_root, |file_path| {
+                    // The receiver outlives the walk, so sending cannot fail
+                    let _ = tx.send(file_path);
+                })
+                .map_err(|e| e.to_string())
+            });
+            let results = rx.into_iter().par_bridge().map(lint_file).collect();
+            let walk_result = walker
+                .join()
+                .unwrap_or_else(|_| Err("file discovery panicked".to_string()));
+            (walk_result, results)
+        });
+        if let Err(e) = walk_result {
+            if !args.silent {
+                eprintln!("{}: Failed to find markdown files: {}", "Error".red().bold(), e);
+            }
+            return true; // Has errors
+        }
+        if results.is_empty() {
+            if !quiet {
+                println!("No markdown files found to check.");
+            }
+            return false;
+        }
+
+        // Cross-file checks and --check-idempotent need the complete list
+        file_paths = results.iter().map(|(file_path, _)| file_path.clone()).collect();
+        file_paths.sort();
 
         // Aggregate results and extract FileIndex for cross-file analysis
         let mut has_issues = false;
@@ -349,14 +459,22 @@ pub fn perform_check_run(
                 total_issues += issues_found;
             }
 
-            if args.statistics {
+            let (errors, warnings_count) = formatter::count_by_severity(&warnings);
+            severity_counts.0 += errors;
+            severity_counts.1 += warnings_count;
+
+            if sort_by.is_some() {
+                sorted_warnings.extend(warnings.iter().map(|w| (file_path.clone(), w.clone())));
+            }
+
+            if collect_stats {
                 all_warnings_for_stats.extend(warnings);
             }
 
             // Store FileIndex for cross-file analysis (no second pass needed!)
             if needs_cross_file {
                 // Canonicalize path for consistent cache key matching
-                let canonical = std::fs::canonicalize(&file_path).unwrap_or_else(|_| PathBuf::from(&file_path));
+                let canonical = canonical_path(Path::new(&file_path));
                 file_indices.insert(canonical, file_index);
             }
         }
@@ -370,7 +488,7 @@ pub fn perform_check_run(
             total_files_processed,
         )
     } else {
-        // Sequential processing for single files or when fixing
+        // Sequential processing for --fail-fast
         let mut has_issues = false;
         let mut files_with_issues = 0;
         let mut total_issues = 0;
@@ -379,26 +497,32 @@ pub fn perform_check_run(
         let mut total_files_processed = 0;
 
         for file_path in &file_paths {
+            let dir_config = dir_configs.and_then(|d| d.for_file(file_path));
+            let (file_rules, file_config) = dir_config
+                .as_deref()
+                .map_or((enabled_rules.as_slice(), config), |c| (c.rules.as_slice(), &c.config));
             // process_file_with_formatter now returns FileIndex (no second pass needed)
             let (file_has_issues, issues_found, issues_fixed, fixable_issues, warnings, file_index) =
                 crate::file_processor::process_file_with_formatter(
                     file_path,
-                    &enabled_rules,
+                    file_rules,
                     args.fix_mode,
                     args.diff,
+                    args.backup_suffix(),
                     args.verbose && !args.silent,
                     quiet,
                     args.silent,
                     &output_format,
-                    &output_writer,
-                    config,
+                    file_output_writer,
+                    report_root.as_deref(),
+                    file_config,
                     cache.as_ref().map(Arc::clone),
                 );
 
             // Store FileIndex for cross-file analysis (extracted from first pass)
             if needs_cross_file {
                 // Canonicalize path for consistent cache key matching
-                let canonical = std::fs::canonicalize(file_path).unwrap_or_else(|_| PathBuf::from(file_path));
+                let canonical = canonical_path(Path::new(file_path));
                 file_indices.insert(canonical, file_index);
             }
 
@@ -412,9 +536,22 @@ pub fn perform_check_run(
                 total_issues += issues_found;
             }
 
-            if args.statistics {
+            let (errors, warnings_count) = formatter::count_by_severity(&warnings);
+            severity_counts.0 += errors;
+            severity_counts.1 += warnings_count;
+
+            if sort_by.is_some() {
+                sorted_warnings.extend(warnings.iter().map(|w| (file_path.clone(), w.clone())));
+            }
+
+            if collect_stats {
                 all_warnings_for_stats.extend(warnings);
             }
+
+            if fail_fast && file_has_issues {
+                stopped_early = true;
+                break;
+            }
         }
 
         (
@@ -427,91 +564,78 @@ pub fn perform_check_run(
         )
     };
 
-    // Phase 2: Run cross-file checks if needed
-    if needs_cross_file && !file_indices.is_empty() {
-        let index_start = Instant::now();
-
-        // Load workspace index from cache if available, otherwise start fresh
-        let mut workspace_index = workspace_cache_dir
-            .and_then(WorkspaceIndex::load_from_cache)
-            .unwrap_or_default();
-
-        let loaded_from_cache = workspace_index.file_count() > 0;
-        if args.verbose && !args.silent && loaded_from_cache {
-            eprintln!(
-                "Loaded workspace index from cache with {} files",
-                workspace_index.file_count()
-            );
-        }
+    // Phase 2: Run cross-file checks if needed (a partial index would give false positives)
+    if needs_cross_file && !stopped_early && !file_indices.is_empty() {
+        let cross_file_results = run_cross_file_phase(
+            args,
+            &file_paths,
+            file_indices,
+            &enabled_rules,
+            config,
+            workspace_cache_dir,
+            watch_state,
+        );
+        let cross_file_results = retain_enabled_cross_file(cross_file_results, dir_configs);
+
+        // Output cross-file warnings
+        let formatter = output_format.create_formatter();
+        for (file_path, cross_file_warnings) in cross_file_results {
+            has_issues = true;
+            files_with_issues += 1;
+            total_issues += cross_file_warnings.len();
+            total_fixable_issues += crate::file_processor::count_fixable(config, &cross_file_warnings);
+            let (errors, warnings_count) = formatter::count_by_severity(&cross_file_warnings);
+            severity_counts.0 += errors;
+            severity_counts.1 += warnings_count;
+
+            if sort_by.is_some() {
+                let path = file_path.to_string_lossy().to_string();
+                sorted_warnings.extend(cross_file_warnings.iter().map(|w| (path.clone(), w.clone())));
+            } else if !args.silent {
+                let formatted =
+                    formatter.format_warnings(&cross_file_warnings, &report_path(&file_path.to_string_lossy()));
+                if !formatted.is_empty() {
+                    output_writer.writeln(&formatted).unwrap_or_else(|e| {
+                        eprintln!("Error writing output: {e}");
+                    });
+                }
+            }
 
-        // Incremental update: only update files that have changed (stale)
-        let mut updated_count = 0;
-        let mut skipped_count = 0;
-        for (path, file_index) in file_indices {
-            if workspace_index.is_file_stale(&path, &file_index.content_hash) {
-                workspace_index.update_file(&path, file_index);
-                updated_count += 1;
-            } else {
-                skipped_count += 1;
+            if collect_stats {
+                all_warnings_for_stats.extend(cross_file_warnings);
             }
         }
+    }
 
-        // Prune deleted files from workspace index (use canonical paths for matching)
-        let current_files: std::collections::HashSet<PathBuf> = file_paths
-            .iter()
-            .map(|p| std::fs::canonicalize(p).unwrap_or_else(|_| PathBuf::from(p)))
-            .collect();
-        let pruned_count = workspace_index.retain_only(&current_files);
-
-        if args.verbose && !args.silent {
-            eprintln!(
-                "Workspace index: {} updated, {} unchanged, {} pruned ({} total) in {:?}",
-                updated_count,
-                skipped_count,
-                pruned_count,
-                workspace_index.file_count(),
-                index_start.elapsed()
-            );
+    if let Some(sort_by) = sort_by
+        && !args.silent
+        && !sorted_warnings.is_empty()
+    {
+        for (file_path, _) in &mut sorted_warnings {
+            *file_path = report_path(file_path);
         }
-
-        // Run cross-file checks using FileIndex (no re-parsing needed)
+        rumdl_lib::output::sort_warnings(&mut sorted_warnings, sort_by);
         let formatter = output_format.create_formatter();
-        for (file_path, file_index) in workspace_index.files() {
-            if let Ok(cross_file_warnings) =
-                rumdl_lib::run_cross_file_checks(file_path, file_index, &enabled_rules, &workspace_index)
-                && !cross_file_warnings.is_empty()
-            {
-                has_issues = true;
-                files_with_issues += 1;
-                total_issues += cross_file_warnings.len();
-
-                // Output cross-file warnings
-                if !args.silent {
-                    let formatted = formatter.format_warnings(&cross_file_warnings, &file_path.to_string_lossy());
-                    if !formatted.is_empty() {
-                        output_writer.writeln(&formatted).unwrap_or_else(|e| {
-                            eprintln!("Error writing output: {e}");
-                        });
-                    }
-                }
+        let formatted: Vec<String> = sorted_warnings
+            .iter()
+            .map(|(path, warning)| formatter.format_warnings(std::slice::from_ref(warning), path))
+            .collect();
+        output_writer.writeln(&formatted.join("\n")).unwrap_or_else(|e| {
+            eprintln!("Error writing output: {e}");
+        });
+    }
 
-                if args.statistics {
-                    all_warnings_for_stats.extend(cross_file_warnings);
-                }
-            }
-        }
+    if args.check_idempotent && !check_idempotency(args, &file_paths, &enabled_rules, config) {
+        has_issues = true;
+    }
 
-        // Save workspace index to cache
-        if let Some(cache_dir) = workspace_cache_dir {
-            if let Err(e) = workspace_index.save_to_cache(cache_dir) {
-                log::warn!("Failed to save workspace index cache: {e}");
-            } else if args.verbose && !args.silent {
-                eprintln!(
-                    "Saved workspace index cache with {} files",
-                    workspace_index.file_count()
-                );
-            }
-        }
+    if stopped_early && total_files_processed < file_paths.len() && !quiet && !args.silent {
+        output_writer
+            .writeln(&format!(
+                "Stopped at the first file with issues (--fail-fast); {} more files were not checked",
+                file_paths.len() - total_files_processed
+            ))
+            .ok();
     }
 
     let duration = start_time.elapsed();
@@ -535,6 +659,28 @@ pub fn perform_check_run(
     if args.statistics && !quiet && !args.silent && !all_warnings_for_stats.is_empty() {
         formatter::print_statistics(&all_warnings_for_stats);
     }
+    write_statistics_history(args, &all_warnings_for_stats, total_files_processed);
+
+    if args.text_summary() {
+        eprintln!(
+            "{}",
+            formatter::summary_line(
+                severity_counts.0,
+                severity_counts.1,
+                total_fixable_issues,
+                total_files_processed
+            )
+        );
+    }
+    if args.json_summary() {
+        let summary = formatter::SummaryRecord::new(
+            &all_warnings_for_stats,
+            total_files_processed,
+            files_with_issues,
+            total_fixable_issues,
+        );
+        eprintln!("{}", summary.to_json());
+    }
 
     // Print profiling information if enabled and not in quiet or silent mode
     if args.profile && !quiet && !args.silent {
@@ -551,6 +697,308 @@ pub fn perform_check_run(
     has_issues
 }
 
+/// Order files by modification time, newest first, keeping the discovery order for ties
+fn sort_most_recently_modified_first(file_paths: &mut [String]) {
+    file_paths.sort_by_cached_key(|path| {
+        std::cmp::Reverse(std::fs::metadata(path).and_then(|metadata| metadata.modified()).ok())
+    });
+}
+
+/// Append the run to the `--statistics-history` file, if one was given
+fn write_statistics_history(args: &crate::CheckArgs, warnings: &[LintWarning], files: usize) {
+    if let Some(path) = &args.statistics_history
+        && let Err(e) = formatter::append_statistics_history(Path::new(path), warnings, files)
+    {
+        eprintln!("Error writing statistics history to {path}: {e}");
+    }
+}
+
+/// Build (or incrementally update) the workspace index and run cross-file checks
+///
+/// Returns the cross-file warnings of every file that has any. With an initialized
+/// `watch_state`, only the files changed since the last run are re-indexed and only
+/// the cross-file checks they affect are re-run.
+fn run_cross_file_phase(
+    args: &crate::CheckArgs,
+    file_paths: &[String],
+    file_indices: HashMap<PathBuf, FileIndex>,
+    enabled_rules: &[Box<dyn Rule>],
+    config: &rumdl_config::Config,
+    workspace_cache_dir: Option<&Path>,
+    watch_state: Option<&mut WatchState>,
+) -> Vec<(PathBuf, Vec<LintWarning>)> {
+    let index_start = Instant::now();
+
+    if let Some(state) = watch_state {
+        if state.initialized {
+            let (updated_count, removed_count, checked_count) = state.update(file_paths, enabled_rules, config);
+            if args.verbose && !args.silent {
+                eprintln!(
+                    "Workspace index: {} updated, {} removed, {} files re-checked ({} total) in {:?}",
+                    updated_count,
+                    removed_count,
+                    checked_count,
+                    state.index.file_count(),
+                    index_start.elapsed()
+                );
+            }
+            return state.warnings();
+        }
+
+        // First run in watch mode: build everything, then keep it for incremental updates
+        let results = run_full_cross_file_phase(args, file_paths, file_indices, enabled_rules, workspace_cache_dir);
+        state.index = results.0;
+        state.cross_file_warnings = results.1.into_iter().collect();
+        state.changed.clear();
+        state.initialized = true;
+        return state.warnings();
+    }
+
+    run_full_cross_file_phase(args, file_paths, file_indices, enabled_rules, workspace_cache_dir).1
+}
+
+/// Drops cross-file warnings of rules that the nested config of their file disables
+fn retain_enabled_cross_file(
+    results: Vec<(PathBuf, Vec<LintWarning>)>,
+    dir_configs: Option<&crate::file_processor::DirectoryConfigs>,
+) -> Vec<(PathBuf, Vec<LintWarning>)> {
+    let Some(dir_configs) = dir_configs else {
+        return results;
+    };
+    results
+        .into_iter()
+        .filter_map(|(file_path, mut warnings)| {
+            dir_configs.retain_enabled(&file_path.to_string_lossy(), &mut warnings);
+            (!warnings.is_empty()).then_some((file_path, warnings))
+        })
+        .collect()
+}
+
+/// Update the workspace index from freshly linted files and run every cross-file check
+fn run_full_cross_file_phase(
+    args: &crate::CheckArgs,
+    file_paths: &[String],
+    file_indices: HashMap<PathBuf, FileIndex>,
+    enabled_rules: &[Box<dyn Rule>],
+    workspace_cache_dir: Option<&Path>,
+) -> (WorkspaceIndex, Vec<(PathBuf, Vec<LintWarning>)>) {
+    let index_start = Instant::now();
+
+    // Load workspace index from cache if available, otherwise start fresh
+    let mut workspace_index = workspace_cache_dir
+        .and_then(WorkspaceIndex::load_from_cache)
+        .unwrap_or_default();
+
+    let loaded_from_cache = workspace_index.file_count() > 0;
+    if args.verbose && !args.silent && loaded_from_cache {
+        eprintln!(
+            "Loaded workspace index from cache with {} files",
+            workspace_index.file_count()
+        );
+    }
+
+    // Incremental update: only update files that have changed (stale)
+    let mut updated_count = 0;
+    let mut skipped_count = 0;
+    for (path, file_index) in file_indices {
+        if workspace_index.is_file_stale(&path, &file_index.content_hash) {
+            workspace_index.update_file(&path, file_index);
+            updated_count += 1;
+        } else {
+            skipped_count += 1;
+        }
+    }
+
+    // Prune deleted files from workspace index (use canonical paths for matching)
+    let current_files: HashSet<PathBuf> = file_paths.iter().map(|p| canonical_path(Path::new(p))).collect();
+    let pruned_count = workspace_index.retain_only(&current_files);
+
+    if args.verbose && !args.silent {
+        eprintln!(
+            "Workspace index: {} updated, {} unchanged, {} pruned ({} total) in {:?}",
+            updated_count,
+            skipped_count,
+            pruned_count,
+            workspace_index.file_count(),
+            index_start.elapsed()
+        );
+    }
+
+    // Run cross-file checks for each file using the FileIndex (no re-parsing needed)
+    let mut results = Vec::new();
+    for (file_path, file_index) in workspace_index.files() {
+        if let Ok(cross_file_warnings) =
+            rumdl_lib::run_cross_file_checks(file_path, file_index, enabled_rules, &workspace_index)
+            && !cross_file_warnings.is_empty()
+        {
+            results.push((file_path.to_path_buf(), cross_file_warnings));
+        }
+    }
+
+    // Save workspace index to cache
+    if let Some(cache_dir) = workspace_cache_dir {
+        if let Err(e) = workspace_index.save_to_cache(cache_dir) {
+            log::warn!("Failed to save workspace index cache: {e}");
+        } else if args.verbose && !args.silent {
+            eprintln!(
+                "Saved workspace index cache with {} files",
+                workspace_index.file_count()
+            );
+        }
+    }
+
+    (workspace_index, results)
+}
+
+/// Cross-file state kept between watch-mode runs
+///
+/// After the first full run, a change to a few files only re-indexes those files and
+/// re-runs the cross-file checks of the files that link to them (and the files
+/// themselves), instead of a full cross-file pass over the workspace.
+#[derive(Default)]
+pub struct WatchState {
+    /// Workspace index from the previous run
+    index: WorkspaceIndex,
+    /// Cross-file warnings from the previous run, by canonical path
+    cross_file_warnings: HashMap<PathBuf, Vec<LintWarning>>,
+    /// Markdown files touched since the previous run (canonical paths)
+    changed: HashSet<PathBuf>,
+    /// Whether a full run has populated the index
+    initialized: bool,
+}
+
+impl WatchState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the markdown files touched by a watcher event
+    pub fn record_event(&mut self, event: &Event) {
+        for path in event.paths.iter().filter(|p| is_markdown_path(p)) {
+            self.changed.insert(canonical_path(path));
+        }
+    }
+
+    /// Forget everything so the next run rebuilds the index (e.g. after a config change)
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Re-index changed files and re-run the cross-file checks they affect
+    ///
+    /// Returns (files re-indexed, files removed, files re-checked).
+    fn update(
+        &mut self,
+        file_paths: &[String],
+        rules: &[Box<dyn Rule>],
+        config: &rumdl_config::Config,
+    ) -> (usize, usize, usize) {
+        let current_files: HashSet<PathBuf> = file_paths.iter().map(|p| canonical_path(Path::new(p))).collect();
+
+        // Also pick up files that appeared or disappeared without an event reaching us
+        let mut changed = std::mem::take(&mut self.changed);
+        changed.extend(current_files.iter().filter(|p| !self.index.contains_file(p)).cloned());
+        changed.extend(
+            self.index
+                .files()
+                .map(|(p, _)| p)
+                .filter(|p| !current_files.contains(*p))
+                .map(Path::to_path_buf),
+        );
+
+        let mut updated_count = 0;
+        let mut removed_count = 0;
+        let mut affected: HashSet<PathBuf> = HashSet::new();
+
+        for path in changed {
+            if current_files.contains(&path) {
+                let Ok(content) = crate::read_file_efficiently(&path) else {
+                    continue;
+                };
+                let flavor = if config.markdown_flavor() == rumdl_config::MarkdownFlavor::Standard {
+                    rumdl_config::MarkdownFlavor::from_path(&path)
+                } else {
+                    config.markdown_flavor()
+                };
+                let file_index = rumdl_lib::build_file_index_only(
+                    &content,
+                    &crate::file_processor::rules_for_file(rules, config, &path),
+                    flavor,
+                );
+                if !self.index.is_file_stale(&path, &file_index.content_hash) {
+                    continue;
+                }
+
+                // Files linking here may point at headings that changed
+                affected.extend(self.index.get_dependents(&path));
+                self.index.update_file(&path, file_index);
+                affected.insert(path);
+                updated_count += 1;
+            } else if self.index.contains_file(&path) {
+                // Deleted (or no longer included): links to it from other files are now broken
+                affected.extend(self.index.get_dependents(&path));
+                self.index.remove_file(&path);
+                self.cross_file_warnings.remove(&path);
+                removed_count += 1;
+            }
+        }
+
+        let checked_count = affected.len();
+        for path in affected {
+            let Some(file_index) = self.index.get_file(&path) else {
+                continue;
+            };
+            match rumdl_lib::run_cross_file_checks(&path, file_index, rules, &self.index) {
+                Ok(warnings) if !warnings.is_empty() => {
+                    self.cross_file_warnings.insert(path, warnings);
+                }
+                _ => {
+                    self.cross_file_warnings.remove(&path);
+                }
+            }
+        }
+
+        (updated_count, removed_count, checked_count)
+    }
+
+    fn warnings(&self) -> Vec<(PathBuf, Vec<LintWarning>)> {
+        self.cross_file_warnings
+            .iter()
+            .map(|(path, warnings)| (path.clone(), warnings.clone()))
+            .collect()
+    }
+}
+
+/// Run the `--check-idempotent` pass; returns true when one fix pass settles every file
+fn check_idempotency(
+    args: &crate::CheckArgs,
+    file_paths: &[String],
+    rules: &[Box<dyn Rule>],
+    config: &rumdl_config::Config,
+) -> bool {
+    let write = args.fix_mode != crate::FixMode::Check;
+    let results: Vec<bool> = file_paths
+        .par_iter()
+        .map(|file_path| crate::file_processor::check_fix_idempotency(file_path, rules, config, write, args.silent))
+        .collect();
+    results.into_iter().all(|settled| settled)
+}
+
+/// Canonicalize a path, falling back to its canonical directory for deleted files
+fn canonical_path(path: &Path) -> PathBuf {
+    if let Ok(canonical) = std::fs::canonicalize(path) {
+        return canonical;
+    }
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    match (std::fs::canonicalize(parent), path.file_name()) {
+        (Ok(dir), Some(name)) => dir.join(name),
+        _ => path.to_path_buf(),
+    }
+}
+
 /// Run the linter in watch mode, re-running on file changes
 pub fn run_watch_mode(args: &crate::CheckArgs, global_config_path: Option<&str>, isolated: bool, quiet: bool) {
     // Always use current directory for config discovery to ensure config files are found
@@ -564,10 +1012,11 @@ pub fn run_watch_mode(args: &crate::CheckArgs, global_config_path: Option<&str>,
     let all_rules = rumdl_lib::rules::all_rules(&rumdl_config::Config::default());
     let registry = rumdl_config::RuleRegistry::from_rules(&all_rules);
     let validation_warnings = rumdl_config::validate_config_sourced(&sourced, &registry);
-    if !validation_warnings.is_empty() && !args.silent {
-        for warn in &validation_warnings {
-            eprintln!("\x1b[33m[config warning]\x1b[0m {}", warn.message);
-        }
+    crate::report_config_warnings(&validation_warnings, args.strict_config, args.silent);
+
+    // Link check results are kept in memory for the session rather than in the cache
+    if args.check_links_online {
+        crate::enable_link_checking(None, args.silent);
     }
 
     // Extract project_root before converting to Config (for exclude pattern resolution)
@@ -617,7 +1066,17 @@ pub fn run_watch_mode(args: &crate::CheckArgs, global_config_path: Option<&str>,
     println!("{}", "Press Ctrl-C to exit".cyan());
     println!();
 
-    let _has_issues = perform_check_run(args, &config, quiet, None, None, project_root.as_deref());
+    let mut watch_state = WatchState::new();
+    let _has_issues = perform_check_run(
+        args,
+        &config,
+        quiet,
+        None,
+        None,
+        project_root.as_deref(),
+        crate::file_processor::DirectoryConfigs::new(&sourced, args).as_ref(),
+        Some(&mut watch_state),
+    );
     if !quiet {
         println!("\n{}", "Watching for file changes...".cyan());
     }
@@ -634,12 +1093,14 @@ pub fn run_watch_mode(args: &crate::CheckArgs, global_config_path: Option<&str>,
                         let Some(mut change_kind) = change_detected(&first_event) else {
                             continue;
                         };
+                        watch_state.record_event(&first_event);
 
                         // Collect all events that occur within the debounce window
                         let start = Instant::now();
                         while start.elapsed() < debounce_duration {
                             // Try to receive more events with a short timeout
                             if let Ok(Ok(event)) = rx.recv_timeout(Duration::from_millis(10)) {
+                                watch_state.record_event(&event);
                                 // If we get a config change, that takes priority
                                 if let Some(kind) = change_detected(&event)
                                     && matches!(kind, ChangeKind::Configuration)
@@ -669,6 +1130,9 @@ pub fn run_watch_mode(args: &crate::CheckArgs, global_config_path: Option<&str>,
                             // Update project_root from reloaded config
                             project_root = sourced.project_root.clone();
                             config = sourced.clone().into();
+
+                            // Rules or file sets may have changed, so rebuild the cross-file index
+                            watch_state.reset();
                         }
 
                         // Build the header message before clearing
@@ -692,7 +1156,16 @@ pub fn run_watch_mode(args: &crate::CheckArgs, global_config_path: Option<&str>,
                         let _ = io::stdout().flush();
 
                         // Re-run the check
-                        let _has_issues = perform_check_run(args, &config, quiet, None, None, project_root.as_deref());
+                        let _has_issues = perform_check_run(
+                            args,
+                            &config,
+                            quiet,
+                            None,
+                            None,
+                            project_root.as_deref(),
+                            crate::file_processor::DirectoryConfigs::new(&sourced, args).as_ref(),
+                            Some(&mut watch_state),
+                        );
                         if !quiet {
                             println!("\n{}", "Watching for file changes...".cyan());
                         }
@@ -709,3 +1182,68 @@ pub fn run_watch_mode(args: &crate::CheckArgs, global_config_path: Option<&str>,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumdl_lib::rules::MD057ExistingRelativeLinks;
+    use std::fs;
+
+    fn file_list(dir: &Path, names: &[&str]) -> Vec<String> {
+        names
+            .iter()
+            .map(|n| dir.join(n).to_string_lossy().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_watch_state_detects_deleted_and_recreated_targets() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("a.md"), "# A\n\nSee [B](b.md).\n").unwrap();
+        fs::write(dir.path().join("b.md"), "# B\n").unwrap();
+        fs::write(dir.path().join("c.md"), "# C\n").unwrap();
+
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(MD057ExistingRelativeLinks::default())];
+        let config = rumdl_config::Config::default();
+        let mut state = WatchState::new();
+
+        state.update(&file_list(dir.path(), &["a.md", "b.md", "c.md"]), &rules, &config);
+        assert!(state.warnings().is_empty());
+
+        // Deleting the target breaks the link in a.md, which did not change itself
+        fs::remove_file(dir.path().join("b.md")).unwrap();
+        let (updated, removed, checked) = state.update(&file_list(dir.path(), &["a.md", "c.md"]), &rules, &config);
+        assert_eq!((updated, removed, checked), (0, 1, 1));
+        let warnings = state.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].0, canonical_path(&dir.path().join("a.md")));
+
+        // Bringing it back fixes the link again
+        fs::write(dir.path().join("b.md"), "# B\n").unwrap();
+        state.update(&file_list(dir.path(), &["a.md", "b.md", "c.md"]), &rules, &config);
+        assert!(state.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_watch_state_only_rechecks_affected_files() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("a.md"), "# A\n\nSee [B](b.md).\n").unwrap();
+        fs::write(dir.path().join("b.md"), "# B\n").unwrap();
+        fs::write(dir.path().join("c.md"), "# C\n").unwrap();
+        let files = file_list(dir.path(), &["a.md", "b.md", "c.md"]);
+
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(MD057ExistingRelativeLinks::default())];
+        let config = rumdl_config::Config::default();
+        let mut state = WatchState::new();
+        state.update(&files, &rules, &config);
+
+        // Editing b.md re-checks b.md and a.md (which links to it), not c.md
+        fs::write(dir.path().join("b.md"), "# B\n\nMore.\n").unwrap();
+        state.changed.insert(canonical_path(&dir.path().join("b.md")));
+        assert_eq!(state.update(&files, &rules, &config), (1, 0, 2));
+
+        // Unchanged content is not re-indexed
+        state.changed.insert(canonical_path(&dir.path().join("c.md")));
+        assert_eq!(state.update(&files, &rules, &config), (0, 0, 0));
+    }
+}
@@ -155,9 +155,13 @@ impl WorkspaceIndex {
     }
 
     /// Remove a file from the index
+    ///
+    /// Files linking *to* the removed file stay recorded as its dependents, so a
+    /// deleted target can still be traced back to the links it breaks (and a
+    /// re-created one to the links it satisfies).
     pub fn remove_file(&mut self, path: &Path) -> Option<FileIndex> {
-        // Clean up reverse deps for this file
-        self.clear_reverse_deps_for(path);
+        // Clean up the removed file's outgoing links
+        self.clear_reverse_deps_as_source(path);
 
         let result = self.files.remove(path);
         if result.is_some() {
@@ -396,18 +400,6 @@ impl WorkspaceIndex {
         self.reverse_deps.retain(|_, deps| !deps.is_empty());
     }
 
-    /// Remove a file completely from reverse dependency tracking
-    ///
-    /// Removes the file as both a source (dependent) and as a target.
-    /// Used when deleting a file from the index.
-    fn clear_reverse_deps_for(&mut self, path: &Path) {
-        // Remove as source (dependent)
-        self.clear_reverse_deps_as_source(path);
-
-        // Also remove as target
-        self.reverse_deps.remove(path);
-    }
-
     /// Resolve a relative path from a source file to an absolute target path
     fn resolve_target_path(&self, source_file: &Path, relative_target: &str) -> PathBuf {
         // Get the directory containing the source file
@@ -807,6 +799,29 @@ mod tests {
         assert!(index.get_dependents(Path::new("docs/b.md")).is_empty());
     }
 
+    #[test]
+    fn test_reverse_deps_survive_target_removal() {
+        let mut index = WorkspaceIndex::new();
+
+        // File A links to B
+        let mut file_a = FileIndex::new();
+        file_a.add_cross_file_link(CrossFileLinkIndex {
+            target_path: "b.md".to_string(),
+            fragment: "".to_string(),
+            line: 1,
+            column: 1,
+        });
+        index.update_file(Path::new("docs/a.md"), file_a);
+        index.update_file(Path::new("docs/b.md"), FileIndex::new());
+
+        // Removing B must not forget that A still links to it
+        index.remove_file(Path::new("docs/b.md"));
+        assert_eq!(
+            index.get_dependents(Path::new("docs/b.md")),
+            vec![PathBuf::from("docs/a.md")]
+        );
+    }
+
     #[test]
     fn test_normalize_path() {
         // Test .. handling
@@ -0,0 +1,57 @@
+/// Fixes for the ATX heading spacing rules (MD018-MD021, MD135) applied together
+///
+/// Each rule only fixes one side of the heading text, so a heading with problems on
+/// both sides is fixed by two rules in turn. These tests run the whole fix pipeline and
+/// check that the fixes compose into one clean heading and leave nothing behind.
+use rumdl_lib::rule::Rule;
+use rumdl_lib::{
+    MD018NoMissingSpaceAtx, MD019NoMultipleSpaceAtx, MD020NoMissingSpaceClosedAtx, MD021NoMultipleSpaceClosedAtx,
+    MD135AtxHeadingSpacing, lint_fix_and_report,
+};
+
+const CONTENT: &str = "#Missing\n\n##   Multiple\n\n###Closed###\n\n##  Both  ##\n\n#Left  #\n";
+const EXPECTED: &str = "# Missing\n\n## Multiple\n\n### Closed ###\n\n## Both ##\n\n# Left #\n";
+
+fn spacing_rules() -> Vec<Box<dyn Rule>> {
+    vec![
+        Box::new(MD018NoMissingSpaceAtx::new()),
+        Box::new(MD019NoMultipleSpaceAtx::new()),
+        Box::new(MD020NoMissingSpaceClosedAtx::new()),
+        Box::new(MD021NoMultipleSpaceClosedAtx::new()),
+    ]
+}
+
+fn fix(rules: &[Box<dyn Rule>], content: &str) -> String {
+    let (fixed, remaining) = lint_fix_and_report(content, rules, rumdl_lib::config::MarkdownFlavor::Standard).unwrap();
+    assert!(remaining.is_empty(), "Unexpected warnings after fixing: {remaining:?}");
+    fixed
+}
+
+#[test]
+fn test_md018_to_md021_fixes_compose() {
+    assert_eq!(fix(&spacing_rules(), CONTENT), EXPECTED);
+}
+
+#[test]
+fn test_md135_alone_matches_md018_to_md021() {
+    let rules: Vec<Box<dyn Rule>> = vec![Box::new(MD135AtxHeadingSpacing::new(true))];
+    assert_eq!(fix(&rules, CONTENT), EXPECTED);
+}
+
+#[test]
+fn test_md135_with_md018_to_md021() {
+    let mut rules = spacing_rules();
+    rules.push(Box::new(MD135AtxHeadingSpacing::new(true)));
+    assert_eq!(fix(&rules, CONTENT), EXPECTED);
+
+    // Already fixed content is left alone
+    assert_eq!(fix(&rules, EXPECTED), EXPECTED);
+}
+
+#[test]
+fn test_fixes_keep_line_endings() {
+    let mut rules = spacing_rules();
+    rules.push(Box::new(MD135AtxHeadingSpacing::new(true)));
+    let content = CONTENT.replace('\n', "\r\n");
+    assert_eq!(fix(&rules, &content), EXPECTED.replace('\n', "\r\n"));
+}
@@ -290,3 +290,106 @@ fn test_flag_combinations() {
     assert!(!stdout.contains("MD013"), "MD013 should be disabled via extend-disable");
     assert!(!stdout.contains("MD033"), "MD033 should be disabled via --disable");
 }
+
+#[test]
+fn test_rules_from_file() {
+    let temp_dir = setup_test_file();
+    let base_path = temp_dir.path();
+    let rumdl_exe = env!("CARGO_BIN_EXE_rumdl");
+
+    fs::write(
+        base_path.join("approved-rules.txt"),
+        "# Shared rule set\nMD001, MD013\nMD033 # inline HTML\n-MD013\n",
+    )
+    .unwrap();
+
+    // Test: --rules-from enables the listed rules and disables `-` entries
+    let output = Command::new(rumdl_exe)
+        .current_dir(base_path)
+        .args(["check", "test.md", "--rules-from", "approved-rules.txt", "--verbose"])
+        .output()
+        .expect("Failed to execute command");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    println!("Rules from file output: {stdout}");
+
+    assert!(stdout.contains("MD001"), "MD001 should be enabled from the file");
+    assert!(stdout.contains("MD033"), "MD033 should be enabled from the file");
+    assert!(!stdout.contains("MD013"), "MD013 should be disabled by -MD013");
+    assert!(!stdout.contains("MD022"), "Only rules listed in the file should run");
+
+    // A missing file is a tool error
+    let output = Command::new(rumdl_exe)
+        .current_dir(base_path)
+        .args(["check", "test.md", "--rules-from", "missing.txt"])
+        .output()
+        .expect("Failed to execute command");
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Failed to read rules file 'missing.txt'"));
+}
+
+#[test]
+fn test_check_links_online() {
+    use std::io::{BufRead, BufReader, Write};
+
+    // A local server: /ok exists, everything else is a 404
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let mut request_line = String::new();
+            BufReader::new(&stream).read_line(&mut request_line).unwrap();
+            let status = if request_line.contains(" /ok ") {
+                "200 OK"
+            } else {
+                "404 Not Found"
+            };
+            let response = format!("HTTP/1.1 {status}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n");
+            let _ = (&stream).write_all(response.as_bytes());
+        }
+    });
+
+    let temp_dir = tempdir().unwrap();
+    let base_path = temp_dir.path();
+    let rumdl_exe = env!("CARGO_BIN_EXE_rumdl");
+    fs::write(
+        base_path.join("links.md"),
+        format!("# Links\n\nSee [this]({base}/ok) and <{base}/missing>.\n"),
+    )
+    .unwrap();
+    fs::write(
+        base_path.join(".rumdl.toml"),
+        "[MD141]\nexclude-hosts = []\nhost-delay-ms = 0\n",
+    )
+    .unwrap();
+
+    // Without the flag, no requests are made
+    let output = Command::new(rumdl_exe)
+        .current_dir(base_path)
+        .args(["check", "links.md"])
+        .output()
+        .expect("Failed to execute command");
+    assert!(output.status.success());
+
+    // The flag adds MD141 even when --enable selects other rules
+    let output = Command::new(rumdl_exe)
+        .current_dir(base_path)
+        .args(["check", "links.md", "--enable", "MD001", "--check-links-online"])
+        .output()
+        .expect("Failed to execute command");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    println!("Online link check output: {stdout}");
+
+    assert_eq!(output.status.code(), Some(1));
+    let column = format!("See [this]({base}/ok) and <").len();
+    assert!(stdout.contains(&format!(
+        "links.md:3:{column}: [MD141] External link is unreachable: {base}/missing (HTTP 404)"
+    )));
+    assert!(!stdout.contains("/ok"));
+
+    // Reachable links are cached for later runs
+    let cache = fs::read_to_string(base_path.join(".rumdl_cache/external_links.json")).unwrap();
+    assert!(cache.contains(&format!("{base}/ok")));
+    assert!(!cache.contains("/missing"));
+}
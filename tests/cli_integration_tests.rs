@@ -1387,6 +1387,67 @@ fn test_stdin_formatting_no_issues() {
     assert!(output.status.success());
 }
 
+fn run_fmt_stdin(input: &str, extra_args: &[&str]) -> std::process::Output {
+    let rumdl_exe = env!("CARGO_BIN_EXE_rumdl");
+    let mut cmd = Command::new(rumdl_exe);
+    cmd.arg("fmt").arg("--stdin").arg("--no-config").args(extra_args);
+    cmd.stdin(std::process::Stdio::piped());
+    cmd.stdout(std::process::Stdio::piped());
+    cmd.stderr(std::process::Stdio::piped());
+
+    let mut child = cmd.spawn().expect("Failed to spawn command");
+
+    use std::io::Write;
+    let mut stdin = child.stdin.take().expect("Failed to open stdin");
+    stdin.write_all(input.as_bytes()).expect("Failed to write to stdin");
+    drop(stdin);
+
+    child.wait_with_output().expect("Failed to wait for command")
+}
+
+#[test]
+fn test_fmt_stdin_is_pure_formatter() {
+    // Fixable trailing spaces plus an unfixable duplicate heading
+    let output = run_fmt_stdin("# Test   \n\n## Dup\n\n## Dup\n", &["--stdin-filename", "doc.md"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    // Only the formatted document goes to stdout
+    assert_eq!(stdout, "# Test\n\n## Dup\n\n## Dup\n");
+    // Remaining diagnostics go to stderr, and formatting still succeeds
+    assert!(stderr.contains("doc.md"));
+    assert!(stderr.contains("MD024"));
+    assert!(output.status.success());
+
+    let quiet = run_fmt_stdin("# Test   \n\n## Dup\n\n## Dup\n", &["--quiet"]);
+    assert_eq!(String::from_utf8_lossy(&quiet.stderr), "");
+    assert!(quiet.status.success());
+}
+
+#[test]
+fn test_fmt_stdin_diagnostics_respect_inline_disable() {
+    // MD024 is disabled inline, so only the trailing-space fix happens and nothing is left to report
+    let input = "# Test   \n\n<!-- rumdl-disable MD024 -->\n\n## Dup\n\n## Dup\n";
+    let output = run_fmt_stdin(input, &["--stdin-filename", "doc.md"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "# Test\n\n<!-- rumdl-disable MD024 -->\n\n## Dup\n\n## Dup\n"
+    );
+    assert!(!stderr.contains("MD024"), "inline-disabled rule reported: {stderr}");
+    assert!(output.status.success());
+}
+
+#[test]
+fn test_fmt_stdin_preserves_crlf() {
+    let clean = run_fmt_stdin("# Title\r\n\r\nText\r\n", &["--quiet"]);
+    assert_eq!(String::from_utf8_lossy(&clean.stdout), "# Title\r\n\r\nText\r\n");
+
+    let fixed = run_fmt_stdin("# Title\r\n\r\nText   \r\n", &["--quiet"]);
+    assert_eq!(String::from_utf8_lossy(&fixed.stdout), "# Title\r\n\r\nText\r\n");
+}
+
 #[test]
 fn test_stdin_dash_syntax() {
     let rumdl_exe = env!("CARGO_BIN_EXE_rumdl");
@@ -1610,6 +1671,45 @@ fn test_fmt_vs_check_fix_exit_codes() {
     assert_eq!(output.status.code(), Some(1), "check --fix should exit with code 1");
 }
 
+#[test]
+fn test_fmt_config_limits_rules() {
+    let temp_dir = tempdir().unwrap();
+    let base_path = temp_dir.path();
+    let rumdl_exe = env!("CARGO_BIN_EXE_rumdl");
+    create_config(
+        base_path,
+        "[fmt]\nenable = [\"MD009\", \"MD022\"]\ndisable = [\"MD022\"]\n",
+    );
+
+    // Trailing spaces (MD009), missing blank line (MD022), and emphasis style (MD049)
+    let input = "# Title   \nSome *text* and _more_\n";
+    let file_path = base_path.join("doc.md");
+
+    fs::write(&file_path, input).unwrap();
+    let output = Command::new(rumdl_exe)
+        .current_dir(base_path)
+        .args(["fmt", "doc.md", "--quiet"])
+        .output()
+        .expect("Failed to run fmt");
+    assert!(output.status.success());
+    assert_eq!(
+        fs::read_to_string(&file_path).unwrap(),
+        "# Title\nSome *text* and _more_\n"
+    );
+
+    // check --fix still applies every enabled rule
+    fs::write(&file_path, input).unwrap();
+    Command::new(rumdl_exe)
+        .current_dir(base_path)
+        .args(["check", "--fix", "doc.md", "--quiet"])
+        .output()
+        .expect("Failed to run check --fix");
+    assert_eq!(
+        fs::read_to_string(&file_path).unwrap(),
+        "# Title\n\nSome *text* and *more*\n"
+    );
+}
+
 /// Test that --include allows checking files with non-standard extensions (issue #127)
 #[test]
 fn test_include_nonstandard_extensions() -> Result<(), Box<dyn std::error::Error>> {
@@ -1724,3 +1824,376 @@ fn test_include_multiple_nonstandard_extensions() -> Result<(), Box<dyn std::err
 
     Ok(())
 }
+
+#[test]
+fn test_fix_backup_writes_original_for_changed_files_only() {
+    let temp_dir = tempdir().unwrap();
+    let base_path = temp_dir.path();
+    let original = "# Title\n\nTrailing spaces   \n";
+    fs::write(base_path.join("changed.md"), original).unwrap();
+    fs::write(base_path.join("clean.md"), "# Clean\n\nNothing to fix.\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(base_path)
+        .args(["check", "--fix", "--backup", "--no-cache", "changed.md", "clean.md"])
+        .output()
+        .expect("Failed to execute command");
+    assert_ne!(
+        output.status.code(),
+        Some(2),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(fs::read_to_string(base_path.join("changed.md.bak")).unwrap(), original);
+    assert_eq!(
+        fs::read_to_string(base_path.join("changed.md")).unwrap(),
+        "# Title\n\nTrailing spaces\n"
+    );
+    assert!(
+        !base_path.join("clean.md.bak").exists(),
+        "Unchanged files should not get a backup"
+    );
+}
+
+#[test]
+fn test_fix_backup_suffix() {
+    let temp_dir = tempdir().unwrap();
+    let base_path = temp_dir.path();
+    fs::write(base_path.join("doc.md"), "#Title\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(base_path)
+        .args(["fmt", "--backup-suffix", ".orig", "--no-cache", "doc.md"])
+        .output()
+        .expect("Failed to execute command");
+    assert!(output.status.success());
+
+    assert_eq!(fs::read_to_string(base_path.join("doc.md.orig")).unwrap(), "#Title\n");
+    assert_eq!(fs::read_to_string(base_path.join("doc.md")).unwrap(), "# Title\n");
+    assert!(!base_path.join("doc.md.bak").exists());
+}
+
+#[test]
+fn test_backup_requires_fix() {
+    let temp_dir = tempdir().unwrap();
+    fs::write(temp_dir.path().join("doc.md"), "# Title\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(temp_dir.path())
+        .args(["check", "--backup", "doc.md"])
+        .output()
+        .expect("Failed to execute command");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--backup requires --fix"));
+}
+
+#[test]
+fn test_check_idempotent_dry_run() {
+    let temp_dir = tempdir().unwrap();
+    let base_path = temp_dir.path();
+    let original = "#Title\n\n* a\n    * b\nTrailing   \n";
+    fs::write(base_path.join("doc.md"), original).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(base_path)
+        .args(["check", "--check-idempotent", "--no-cache", "--no-config", "doc.md"])
+        .output()
+        .expect("Failed to execute command");
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!stderr.contains("[not settled]"), "{stderr}");
+    assert!(!stderr.contains("not idempotent"), "{stderr}");
+
+    // Without --fix, nothing is written
+    assert_eq!(fs::read_to_string(base_path.join("doc.md")).unwrap(), original);
+}
+
+#[test]
+fn test_check_idempotent_conflicts_with_diff() {
+    let temp_dir = tempdir().unwrap();
+    fs::write(temp_dir.path().join("doc.md"), "# Title\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(temp_dir.path())
+        .args(["check", "--check-idempotent", "--diff", "doc.md"])
+        .output()
+        .expect("Failed to execute command");
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn test_unexpanded_glob_paths_are_expanded() {
+    let temp_dir = tempdir().unwrap();
+    let base_path = temp_dir.path();
+    fs::create_dir_all(base_path.join("docs/nested")).unwrap();
+    fs::write(base_path.join("docs/top.md"), "# Top\n").unwrap();
+    fs::write(base_path.join("docs/nested/deep.md"), "# Deep\n").unwrap();
+    fs::write(base_path.join("docs/nested/notes.txt"), "not markdown\n").unwrap();
+    fs::write(base_path.join("other.md"), "# Other\n").unwrap();
+
+    // Passed as a single argument, as Windows cmd would do
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(base_path)
+        .args(["check", "--no-cache", "--verbose", "docs/**/*.md"])
+        .output()
+        .expect("Failed to execute command");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stdout: {stdout}\nstderr: {stderr}");
+    assert!(stdout.contains("docs/top.md"), "stdout: {stdout}");
+    assert!(stdout.contains("docs/nested/deep.md"), "stdout: {stdout}");
+    assert!(!stdout.contains("other.md"), "stdout: {stdout}");
+    assert!(!stdout.contains("notes.txt"), "stdout: {stdout}");
+
+    // `*` does not cross directory boundaries
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(base_path)
+        .args(["check", "--no-cache", "--verbose", "docs/*.md"])
+        .output()
+        .expect("Failed to execute command");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("docs/top.md"), "stdout: {stdout}");
+    assert!(!stdout.contains("deep.md"), "stdout: {stdout}");
+}
+
+#[test]
+fn test_glob_without_matches_is_an_error() {
+    let temp_dir = tempdir().unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(temp_dir.path())
+        .args(["check", "--no-cache", "missing/*.md"])
+        .output()
+        .expect("Failed to execute command");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("No files matched pattern: missing/*.md"));
+}
+
+#[test]
+fn test_no_glob_treats_paths_literally() {
+    let temp_dir = tempdir().unwrap();
+    let base_path = temp_dir.path();
+    fs::write(base_path.join("[draft].md"), "# Draft\n").unwrap();
+    fs::write(base_path.join("d.md"), "# D\n").unwrap();
+
+    // A file whose name contains glob characters is used as-is
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(base_path)
+        .args(["check", "--no-cache", "--verbose", "[draft].md"])
+        .output()
+        .expect("Failed to execute command");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("[draft].md"), "stdout: {stdout}");
+    assert!(
+        !stdout.contains("d.md\n") && !stdout.contains(" d.md"),
+        "stdout: {stdout}"
+    );
+
+    // With --no-glob, a pattern is a literal (missing) path
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(base_path)
+        .args(["check", "--no-cache", "--no-glob", "*.md"])
+        .output()
+        .expect("Failed to execute command");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("File not found: *.md"));
+}
+
+#[test]
+fn test_strict_config_fails_on_config_warnings() {
+    let temp_dir = tempdir().unwrap();
+    let base_path = temp_dir.path();
+    fs::write(base_path.join("doc.md"), "# Title\n").unwrap();
+    fs::write(base_path.join(".rumdl.toml"), "[MD013]\nline_lenght = 100\n").unwrap();
+
+    // Without the flag the typo is only a warning
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(base_path)
+        .args(["check", "--no-cache", "doc.md"])
+        .output()
+        .expect("Failed to execute command");
+    assert_eq!(output.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("[config warning]"));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(base_path)
+        .args(["check", "--no-cache", "--strict-config", "doc.md"])
+        .output()
+        .expect("Failed to execute command");
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Unknown option for rule MD013: line-lenght"),
+        "stderr: {stderr}"
+    );
+    assert!(String::from_utf8_lossy(&output.stdout).is_empty());
+}
+
+#[test]
+fn test_sort_by_rule_across_files() {
+    let temp_dir = tempdir().unwrap();
+    let base_path = temp_dir.path();
+    fs::write(base_path.join("a.md"), "# A\nText\n## B\n").unwrap();
+    fs::write(base_path.join("b.md"), "Intro\n# C\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(base_path)
+        .args([
+            "check",
+            "--no-cache",
+            "--output-format",
+            "concise",
+            "--sort-by",
+            "rule",
+            "a.md",
+            "b.md",
+        ])
+        .output()
+        .expect("Failed to execute command");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let rules: Vec<&str> = stdout
+        .lines()
+        .filter_map(|line| line.split_once('[').and_then(|(_, rest)| rest.split_once(']')))
+        .map(|(rule, _)| rule)
+        .collect();
+
+    // Each rule's warnings are together even though they come from different files
+    let mut sorted = rules.clone();
+    sorted.sort();
+    assert_eq!(rules, sorted, "stdout: {stdout}");
+    assert!(stdout.contains("b.md:1:1: [MD041]"), "stdout: {stdout}");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(base_path)
+        .args(["check", "--sort-by", "rule", "--fix", "a.md"])
+        .output()
+        .expect("Failed to execute command");
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn test_fail_fast_stops_at_newest_failing_file() {
+    let temp_dir = tempdir().unwrap();
+    let base_path = temp_dir.path();
+    fs::write(base_path.join("a.md"), "# A\nText\n").unwrap();
+    fs::write(base_path.join("b.md"), "# B\n\nText\n").unwrap();
+    fs::write(base_path.join("c.md"), "# C\nText\n").unwrap();
+    // c.md was edited last, so it is checked first
+    let now = std::time::SystemTime::now();
+    for (name, age) in [("a.md", 60), ("b.md", 30), ("c.md", 0)] {
+        let file = fs::File::options().write(true).open(base_path.join(name)).unwrap();
+        file.set_modified(now - std::time::Duration::from_secs(age)).unwrap();
+    }
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(base_path)
+        .args(["check", "--no-cache", "--fail-fast", "--output-format", "concise", "."])
+        .output()
+        .expect("Failed to execute command");
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("c.md:1:1: [MD022]"), "stdout: {stdout}");
+    assert!(!stdout.contains("a.md:"), "stdout: {stdout}");
+    assert!(stdout.contains("2 more files were not checked"), "stdout: {stdout}");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(base_path)
+        .args(["check", "--fail-fast", "--fix", "."])
+        .output()
+        .expect("Failed to execute command");
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn test_cache_reruns_filesystem_dependent_rules() {
+    let temp_dir = tempdir().unwrap();
+    let base_path = temp_dir.path();
+    fs::write(base_path.join("doc.md"), "# Doc\n\nSee [the diagram](diagram.png).\n").unwrap();
+    fs::write(base_path.join("diagram.png"), "").unwrap();
+
+    let run = || {
+        Command::new(env!("CARGO_BIN_EXE_rumdl"))
+            .current_dir(base_path)
+            .args(["check", "--no-config", "--cache-dir", ".cache", "doc.md"])
+            .output()
+            .expect("Failed to execute command")
+    };
+
+    let output = run();
+    assert!(
+        output.status.success(),
+        "stdout: {}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+
+    // doc.md is unchanged and cached, but its link target is gone
+    fs::remove_file(base_path.join("diagram.png")).unwrap();
+    let output = run();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!output.status.success(), "stdout: {stdout}");
+    assert!(stdout.contains("doc.md:3:"), "stdout: {stdout}");
+    assert!(stdout.contains("[MD057]"), "stdout: {stdout}");
+}
+
+#[test]
+fn test_debug_parse_dumps_lint_context() {
+    let temp_dir = tempdir().unwrap();
+    let base_path = temp_dir.path();
+    fs::write(
+        base_path.join("doc.md"),
+        "# Title\n\n- [link](a.md)\n\n```\n# not a heading\n```\n",
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(base_path)
+        .args(["debug-parse", "doc.md", "--flavor", "mkdocs"])
+        .output()
+        .expect("Failed to execute command");
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["flavor"], "mkdocs");
+    assert_eq!(parsed["headings"].as_array().unwrap().len(), 1);
+    assert_eq!(parsed["headings"][0]["line"], 1);
+    assert_eq!(parsed["links"][0]["url"], "a.md");
+    assert_eq!(parsed["list_blocks"][0]["item_lines"], serde_json::json!([3]));
+    assert_eq!(parsed["lines"][5]["text"], "# not a heading");
+    assert_eq!(parsed["lines"][5]["in_code_block"], true);
+    assert!(parsed["lines"][5]["heading"].is_null());
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(base_path)
+        .args(["debug-parse", "doc.md", "--flavor", "unknown"])
+        .output()
+        .expect("Failed to execute command");
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn test_streamed_discovery_checks_each_file_once() {
+    let temp_dir = tempdir().unwrap();
+    let base_path = temp_dir.path();
+    fs::create_dir(base_path.join("docs")).unwrap();
+    fs::write(base_path.join("top.md"), "# Top\nText\n").unwrap();
+    fs::write(base_path.join("docs/guide.md"), "# Guide\nText\n").unwrap();
+    fs::write(base_path.join("docs/notes.txt"), "# Not markdown\nText\n").unwrap();
+
+    // docs/ is reached through both arguments
+    let output = Command::new(env!("CARGO_BIN_EXE_rumdl"))
+        .current_dir(base_path)
+        .args(["check", "--no-cache", "--output-format", "concise", ".", "docs"])
+        .output()
+        .expect("Failed to execute command");
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.matches("guide.md:1:1: [MD022]").count(), 1, "stdout: {stdout}");
+    assert!(stdout.contains("top.md:1:1: [MD022]"), "stdout: {stdout}");
+    assert!(!stdout.contains("notes.txt"), "stdout: {stdout}");
+    assert!(stdout.contains("Found 2 issues in 2 files"), "stdout: {stdout}");
+}
@@ -272,6 +272,44 @@ fn get_test_content_for_rule(rule_name: &str) -> Option<&'static str> {
         "MD056" => Some("|col1|col2|\n|--|--|\n|a|"),
         "MD057" => Some("[link](missing.md)"),
         "MD058" => Some("Text\n|table|\nText"),
+        "MD108" => Some("Non\u{00A0}breaking and zero\u{200B}width"),
+        "MD109" => Some("!!! Note\n    Text"),
+        "MD110" => Some("|col1|col2|\n|--|--|\n|a|b|"),
+        "MD111" => Some("```\ncode\n``` trailing"),
+        "MD112" => Some("# Title #\n\n## Section"),
+        "MD113" => Some("--8<-- \"missing.md\""),
+        "MD063" => Some("Text[^1]\n\n[^1]: One\n[^1]: Two\n"),
+        "MD064" => Some("# Guide\n\n[x](#instal)\n\n## Install\n"),
+        "MD145" => Some("# Title {#top}\n\n## Setup {#setup}\n\n## Usage\n"),
+        "MD144" => Some("- a\n  - b\n    - c\n      - d\n        - e\n"),
+        "MD143" => Some("- a\n- b\n\n- c\n- d\n"),
+        "MD142" => Some("Set `Line_Length` here.\n"),
+        "MD141" => Some("<https://example.invalid/>\n"),
+        "MD140" => Some("`````\ncode\n`````\n"),
+        "MD139" => Some("<!-- rumdl-sorted -->\n- b\n  - nested\n- a\n"),
+        "MD138" => Some("## [Install](install.md)\n"),
+        "MD137" => Some("<!-- rumdl-disable MD013 -->\nText\n"),
+        "MD136" => Some("See [docs].\n\n[docs]: https://example.com/docs.\n"),
+        "MD135" => Some("#Title\n\n##  Both  ##\n"),
+        "MD134" => Some("import Missing from './missing'\n\n# Title\n"),
+        "MD133" => Some("- one\n\n***\n"),
+        "MD132" => Some("| a | b |\n| - | - |\n| 1 | 2 |\n"),
+        "MD131" => Some("**a *b** c*\n"),
+        "MD130" => Some("# Title\n\nText\n"),
+        "MD129" => Some("```\n{\"a\": 1}\n```\n"),
+        "MD128" => Some("-   One\n- Two\n-  Three"),
+        "MD125" => Some("```sh\necho hi  \n```\n"),
+        "MD124" => Some("[a](docs\\page.md)\n"),
+        "MD123" => Some("[site](https://example.com Example site)"),
+        "MD122" => Some("> Quote\nlazy line"),
+        "MD121" => Some("9. Nine\n10. Ten\n11. Eleven"),
+        "MD120" => Some("**Usage**\n\n## Usage\n\nText"),
+        "MD119" => Some("Text\n\n    code here\n\nMore text"),
+        "MD118" => Some("1. Step one\n\n ```bash\n echo hi\n ```\n\n2. Step two"),
+        "MD117" => Some("Some **** text and __ __ more."),
+        "MD116" => Some("---\ndate: 2024-01-01\ntitle: Post\n---\n\n# Post"),
+        "MD115" => Some("Title\n==\n\nText"),
+        "MD114" => Some("See !(Logo)[logo.png] and [docs](guide).\n\n[guide]: ./guide.md"),
         _ => None,
     }
 }
@@ -103,3 +103,119 @@ fn test_statistics_with_quiet_mode() {
         // Statistics should not be shown in silent mode
         .stdout(predicate::str::is_empty());
 }
+
+#[test]
+fn test_summary_line() {
+    let temp_dir = tempdir().unwrap();
+    // MD022 and MD009 report warnings, MD032 reports an error
+    let content = "# Title\nText\n* a\n\nTrailing   \n";
+    fs::write(temp_dir.path().join("a.md"), content).unwrap();
+    fs::write(temp_dir.path().join("b.md"), content).unwrap();
+    fs::write(temp_dir.path().join("clean.md"), "# Clean\n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("rumdl");
+    cmd.current_dir(temp_dir.path())
+        .args(["check", "--no-config", "--summary", "."]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "rumdl: 2 errors, 4 warnings, 6 fixable, 3 files\n",
+        ))
+        .stdout(predicate::str::contains("rumdl: ").not());
+
+    // Batch output formats still get the line, on stderr
+    let mut cmd = cargo_bin_cmd!("rumdl");
+    cmd.current_dir(temp_dir.path())
+        .args(["check", "--no-config", "--summary", "--output-format", "json", "a.md"]);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "rumdl: 1 errors, 2 warnings, 3 fixable, 1 files\n",
+    ));
+}
+
+#[test]
+fn test_summary_line_silent_and_clean() {
+    let temp_dir = tempdir().unwrap();
+    let test_file = temp_dir.path().join("clean.md");
+    fs::write(&test_file, "# Clean\n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("rumdl");
+    cmd.args(["check", "--no-config", "--summary"]).arg(&test_file);
+    cmd.assert().success().stderr(predicate::str::contains(
+        "rumdl: 0 errors, 0 warnings, 0 fixable, 1 files",
+    ));
+
+    let mut cmd = cargo_bin_cmd!("rumdl");
+    cmd.args(["check", "--no-config", "--summary", "--silent"])
+        .arg(&test_file);
+    cmd.assert().success().stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn test_summary_format_json() {
+    let temp_dir = tempdir().unwrap();
+    let content = "# Title\nText\n* a\n\nTrailing   \n";
+    fs::write(temp_dir.path().join("a.md"), content).unwrap();
+    fs::write(temp_dir.path().join("b.md"), content).unwrap();
+    fs::write(temp_dir.path().join("clean.md"), "# Clean\n").unwrap();
+
+    let summary = |args: &[&str]| -> serde_json::Value {
+        let mut cmd = cargo_bin_cmd!("rumdl");
+        cmd.current_dir(temp_dir.path())
+            .args(["check", "--no-config", "--summary-format", "json"])
+            .args(args);
+        let output = cmd.assert().failure().get_output().clone();
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(!String::from_utf8(output.stdout).unwrap().contains("files_checked"));
+        // The summary is the last line on stderr, as one JSON object
+        serde_json::from_str(stderr.lines().last().unwrap()).unwrap()
+    };
+
+    let expected = serde_json::json!({
+        "files_checked": 3,
+        "files_with_issues": 2,
+        "total_warnings": 6,
+        "fixable": 6,
+        "by_rule": { "MD009": 2, "MD022": 2, "MD032": 2 }
+    });
+    assert_eq!(summary(&["."]), expected);
+    // Orthogonal to the output format, batch or streaming
+    assert_eq!(summary(&["--output-format", "sarif", "."]), expected);
+    assert_eq!(summary(&["--stderr", "--output-format", "concise", "."]), expected);
+
+    let mut cmd = cargo_bin_cmd!("rumdl");
+    cmd.current_dir(temp_dir.path())
+        .args(["check", "--no-config", "--summary-format", "json", "--silent", "."]);
+    cmd.assert().failure().stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn test_statistics_history_appends_records() {
+    let temp_dir = tempdir().unwrap();
+    fs::write(temp_dir.path().join("a.md"), "# Title\nText\n\nTrailing   \n").unwrap();
+    fs::write(temp_dir.path().join("clean.md"), "# Clean\n").unwrap();
+    let history = temp_dir.path().join("stats.jsonl");
+
+    for _ in 0..2 {
+        let mut cmd = cargo_bin_cmd!("rumdl");
+        cmd.current_dir(temp_dir.path())
+            .args(["check", "--no-config", "--silent", "--statistics-history"])
+            .arg(&history)
+            .arg(".");
+        cmd.assert().failure();
+    }
+
+    let content = fs::read_to_string(&history).unwrap();
+    let records: Vec<serde_json::Value> = content
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(records.len(), 2);
+    let record = &records[0];
+    assert_eq!(record["files"], 2);
+    assert_eq!(record["violations"], 2);
+    assert_eq!(record["fixable"], 2);
+    assert_eq!(record["rules"]["MD009"], 1);
+    assert_eq!(record["rules"]["MD022"], 1);
+    assert!(record["timestamp"].as_str().unwrap().ends_with('Z'));
+    assert!(record.get("commit").is_some());
+}
@@ -151,6 +151,62 @@ fn test_gitlab_output_format() {
     assert!(stdout.contains(r#""check_name": "MD009""#));
 }
 
+#[test]
+fn test_code_climate_output_format() {
+    let (_temp_dir, test_file) = create_test_file();
+
+    let run = |format: &str| -> serde_json::Value {
+        let mut cmd = cargo_bin_cmd!("rumdl");
+        cmd.arg("check")
+            .arg("--no-cache")
+            .arg("--output-format")
+            .arg(format)
+            .arg(&test_file);
+        let output = cmd.assert().failure().get_output().stdout.clone();
+        serde_json::from_slice(&output).unwrap()
+    };
+
+    let issues = run("code-climate");
+    let issues = issues.as_array().unwrap();
+    let md009 = issues.iter().find(|issue| issue["check_name"] == "MD009").unwrap();
+    assert_eq!(md009["type"], "issue");
+    assert_eq!(md009["description"], "3 trailing spaces found");
+    assert_eq!(md009["severity"], "minor");
+    assert_eq!(md009["location"]["path"], test_file.to_string_lossy().as_ref());
+    assert_eq!(md009["location"]["lines"]["begin"], 2);
+    assert!(md009["fingerprint"].is_string());
+
+    // `codeclimate` is accepted too, and unchanged findings keep their fingerprints
+    let again = run("codeclimate");
+    let fingerprints = |issues: &[serde_json::Value]| -> Vec<String> {
+        issues.iter().map(|issue| issue["fingerprint"].to_string()).collect()
+    };
+    assert_eq!(fingerprints(issues), fingerprints(again.as_array().unwrap()));
+}
+
+#[test]
+fn test_rdjson_output_format() {
+    let (_temp_dir, test_file) = create_test_file();
+
+    let mut cmd = cargo_bin_cmd!("rumdl");
+    cmd.arg("check").arg("--output-format").arg("rdjson").arg(&test_file);
+
+    let output = cmd.assert().failure().get_output().stdout.clone();
+    let result: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+    assert_eq!(result["source"]["name"], "rumdl");
+    let diagnostics = result["diagnostics"].as_array().unwrap();
+    let md009 = diagnostics
+        .iter()
+        .find(|diagnostic| diagnostic["code"]["value"] == "MD009")
+        .unwrap();
+    assert_eq!(md009["location"]["range"]["start"]["line"], 2);
+    assert_eq!(md009["location"]["range"]["start"]["column"], 28);
+    // Removing the trailing spaces is offered as a suggestion
+    assert_eq!(md009["suggestions"][0]["text"], "");
+    assert_eq!(md009["suggestions"][0]["range"]["start"]["column"], 28);
+}
+
 #[test]
 fn test_pylint_output_format() {
     let (_temp_dir, test_file) = create_test_file();
@@ -24,6 +24,8 @@ fn create_test_config() -> Config {
     Config {
         global: GlobalConfig::default(),
         per_file_ignores: HashMap::new(),
+        vscode: Default::default(),
+        fmt: Default::default(),
         rules,
     }
 }
@@ -223,6 +225,8 @@ fn test_partial_rule_config() {
     let config = Config {
         global: GlobalConfig::default(),
         per_file_ignores: HashMap::new(),
+        vscode: Default::default(),
+        fmt: Default::default(),
         rules: rules_map,
     };
 
@@ -260,6 +264,8 @@ fn test_partial_rule_config() {
     let config = Config {
         global: GlobalConfig::default(),
         per_file_ignores: HashMap::new(),
+        vscode: Default::default(),
+        fmt: Default::default(),
         rules: rules_map,
     };
 
@@ -359,6 +359,30 @@ unknown_opt = true"#;
     assert!(warnings[0].message.contains("Unknown option"));
 }
 
+#[test]
+fn test_config_validation_unknown_rule_in_enable_disable() {
+    let temp_dir = tempdir().unwrap();
+    let config_path = temp_dir.path().join("unknown_list_rule.toml");
+    let config_content = r#"[global]
+enable = ["MD013", "MD999"]
+disable = ["line-length", "all"]"#;
+    fs::write(&config_path, config_content).unwrap();
+    let sourced =
+        rumdl_lib::config::SourcedConfig::load_with_discovery(Some(config_path.to_str().unwrap()), None, true)
+            .expect("config should load successfully");
+    let rules = rumdl_lib::all_rules(&rumdl_lib::config::Config::default());
+    let registry = RuleRegistry::from_rules(&rules);
+    let warnings = rumdl_lib::config::validate_config_sourced(&sourced, &registry);
+    let messages: Vec<&str> = warnings.iter().map(|w| w.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "Unknown rule in enable list: MD999",
+            "Unknown rule in disable list: line-length (did you mean: MD013?)",
+        ]
+    );
+}
+
 #[test]
 fn test_config_validation_type_mismatch() {
     let temp_dir = tempdir().unwrap();
@@ -851,6 +875,47 @@ line-length:
         );
     }
 
+    #[test]
+    fn test_dump_effective_config() {
+        use std::process::Command;
+
+        let temp_dir = tempdir().unwrap();
+        let binary_path = env!("CARGO_BIN_EXE_rumdl");
+
+        let config_path = temp_dir.path().join("base.toml");
+        fs::write(&config_path, "[global]\ndisable = [\"MD041\"]\n\n[MD007]\nindent = 4\n").unwrap();
+        let md_path = temp_dir.path().join("test.md");
+        fs::write(&md_path, "Some content.\n").unwrap();
+        let dump_path = temp_dir.path().join("effective.toml");
+
+        let output = Command::new(binary_path)
+            .args(["check", "--no-cache", "--config"])
+            .arg(&config_path)
+            .arg("--dump-effective")
+            .arg(&dump_path)
+            .arg(&md_path)
+            .output()
+            .expect("Failed to execute command");
+        assert!(
+            output.status.success(),
+            "stderr='{}'",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        // The dump loads as a config that reproduces the run, with defaults spelled out
+        let sourced = SourcedConfig::load_with_discovery(Some(dump_path.to_str().unwrap()), None, true).unwrap();
+        let dumped: rumdl_lib::config::Config = sourced.into();
+        assert_eq!(dumped.global.disable, vec!["MD041".to_string()]);
+        assert_eq!(
+            rumdl_lib::config::get_rule_config_value::<usize>(&dumped, "MD007", "indent"),
+            Some(4)
+        );
+        assert_eq!(
+            rumdl_lib::config::get_rule_config_value::<usize>(&dumped, "MD013", "line-length"),
+            Some(80)
+        );
+    }
+
     #[test]
     fn test_auto_discovery_vs_explicit_config() {
         let temp_dir = tempdir().unwrap();
@@ -1144,6 +1209,29 @@ line-length = 100
     );
 }
 
+#[test]
+fn test_report_root_config() {
+    let temp_dir = tempdir().expect("Failed to create temporary directory");
+    let temp_path = temp_dir.path();
+
+    let config_path = temp_path.join(".rumdl.toml");
+    fs::write(&config_path, "[global]\nreport-root = \"docs\"\n").expect("Failed to write test config file");
+    let sourced =
+        rumdl_lib::config::SourcedConfig::load_with_discovery(Some(config_path.to_str().unwrap()), None, true)
+            .expect("Should load config successfully");
+    let config: rumdl_lib::config::Config = sourced.into();
+    assert_eq!(config.global.report_root.as_deref(), Some("docs"));
+
+    let pyproject_path = temp_path.join("pyproject.toml");
+    fs::write(&pyproject_path, "[tool.rumdl]\nreport_root = \".\"\n").expect("Failed to write test pyproject.toml");
+    let sourced =
+        rumdl_lib::config::SourcedConfig::load_with_discovery(Some(pyproject_path.to_str().unwrap()), None, true)
+            .expect("Should load config successfully");
+    assert!(sourced.unknown_keys.is_empty(), "report_root should be a known key");
+    let config: rumdl_lib::config::Config = sourced.into();
+    assert_eq!(config.global.report_root.as_deref(), Some("."));
+}
+
 #[test]
 fn test_cache_enabled_config() {
     let temp_dir = tempdir().expect("Failed to create temporary directory");
@@ -1358,6 +1446,140 @@ mod project_root_tests {
         );
     }
 
+    #[test]
+    fn test_project_root_from_package_json_without_git() {
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path();
+
+        // Create structure: $ROOT/package.json + $ROOT/docs/.rumdl.toml (no .git)
+        fs::write(temp_path.join("package.json"), "{}").expect("Failed to write package.json");
+        fs::create_dir(temp_path.join("docs")).expect("Failed to create docs");
+        fs::write(temp_path.join("docs/.rumdl.toml"), "[global]").expect("Failed to write config");
+
+        let config_path = temp_path.join("docs/.rumdl.toml");
+        let sourced =
+            rumdl_lib::config::SourcedConfig::load_with_discovery(Some(config_path.to_str().unwrap()), None, true)
+                .expect("Should load config");
+
+        // .rumdl.toml has higher priority than package.json in the default markers
+        let project_root = sourced.project_root.expect("project_root should be set");
+        assert_eq!(
+            project_root.canonicalize().unwrap(),
+            temp_path.join("docs").canonicalize().unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_project_root_with_custom_root_markers() {
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path();
+
+        // Create structure: $ROOT/mkdocs.yml + $ROOT/docs/.rumdl.toml (no .git)
+        fs::write(temp_path.join("mkdocs.yml"), "site_name: test").expect("Failed to write mkdocs.yml");
+        fs::create_dir(temp_path.join("docs")).expect("Failed to create docs");
+        fs::write(
+            temp_path.join("docs/.rumdl.toml"),
+            r#"
+[global]
+root-markers = ["mkdocs.yml"]
+"#,
+        )
+        .expect("Failed to write config");
+
+        let config_path = temp_path.join("docs/.rumdl.toml");
+        let sourced =
+            rumdl_lib::config::SourcedConfig::load_with_discovery(Some(config_path.to_str().unwrap()), None, true)
+                .expect("Should load config");
+
+        assert_eq!(sourced.global.root_markers.value, vec!["mkdocs.yml".to_string()]);
+        let project_root = sourced.project_root.expect("project_root should be set");
+        assert_eq!(
+            project_root.canonicalize().unwrap(),
+            temp_path.canonicalize().unwrap(),
+            "project_root should be at the configured marker"
+        );
+    }
+
+    #[test]
+    fn test_hierarchical_config_for_directory() {
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path();
+
+        // Create structure: $ROOT/.rumdl.toml + $ROOT/docs/.rumdl.toml + $ROOT/docs/api/
+        fs::create_dir_all(temp_path.join("docs/api")).expect("Failed to create docs/api");
+        fs::write(
+            temp_path.join(".rumdl.toml"),
+            "[global]\nhierarchical = true\n\n[MD013]\nline-length = 100\n",
+        )
+        .expect("Failed to write config");
+        let nested_path = temp_path.join("docs/.rumdl.toml");
+        fs::write(
+            &nested_path,
+            "[global]\ndisable = [\"MD033\"]\n\n[MD013]\nline-length = 120\n",
+        )
+        .expect("Failed to write nested config");
+
+        let config_path = temp_path.join(".rumdl.toml");
+        let sourced =
+            rumdl_lib::config::SourcedConfig::load_with_discovery(Some(config_path.to_str().unwrap()), None, true)
+                .expect("Should load config");
+        assert!(sourced.global.hierarchical.value);
+
+        // The root directory has no nested config to apply
+        assert!(sourced.for_directory(temp_path).unwrap().is_none());
+
+        for dir in [temp_path.join("docs"), temp_path.join("docs/api")] {
+            let nested = sourced.for_directory(&dir).unwrap().expect("docs config should apply");
+            assert_eq!(nested.global.disable.value, vec!["MD033".to_string()]);
+            assert_eq!(
+                nested.global.disable.source,
+                rumdl_lib::config::ConfigSource::ProjectConfig
+            );
+            let nested_file = nested_path.display().to_string();
+            assert!(nested.loaded_files.contains(&nested_file));
+
+            // The nearer config wins for rule settings too
+            let line_length = &nested.rules["MD013"].values["line-length"];
+            assert_eq!(line_length.value.as_integer(), Some(120));
+            assert_eq!(line_length.source, rumdl_lib::config::ConfigSource::ProjectConfig);
+        }
+
+        // Without `hierarchical`, nested configs are never merged
+        fs::write(&config_path, "[global]\n").expect("Failed to write config");
+        let sourced =
+            rumdl_lib::config::SourcedConfig::load_with_discovery(Some(config_path.to_str().unwrap()), None, true)
+                .expect("Should load config");
+        assert!(sourced.for_directory(&temp_path.join("docs")).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_find_project_root_marker_priority() {
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path();
+
+        // Nested package.json inside a git repository
+        fs::create_dir(temp_path.join(".git")).expect("Failed to create .git");
+        fs::create_dir_all(temp_path.join("web/src")).expect("Failed to create dirs");
+        fs::write(temp_path.join("web/package.json"), "{}").expect("Failed to write package.json");
+
+        let markers: Vec<String> = rumdl_lib::config::DEFAULT_ROOT_MARKERS
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let root = rumdl_lib::config::find_project_root(&temp_path.join("web/src"), &markers).unwrap();
+        assert_eq!(root.canonicalize().unwrap(), temp_path.canonicalize().unwrap());
+
+        let markers = vec!["package.json".to_string()];
+        let root = rumdl_lib::config::find_project_root(&temp_path.join("web/src"), &markers).unwrap();
+        assert_eq!(
+            root.canonicalize().unwrap(),
+            temp_path.join("web").canonicalize().unwrap()
+        );
+
+        let markers = vec!["does-not-exist.marker".to_string()];
+        assert!(rumdl_lib::config::find_project_root(&temp_path.join("web/src"), &markers).is_none());
+    }
+
     #[test]
     fn test_config_dir_discovery() {
         // Test that .config/rumdl.toml is discovered when no root-level config exists
@@ -1439,3 +1661,212 @@ line-length = 42
         );
     }
 }
+
+mod extend_tests {
+    use rumdl_lib::config::{ConfigError, ConfigSource, SourcedConfig};
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn load(config_path: &std::path::Path) -> Result<SourcedConfig, ConfigError> {
+        SourcedConfig::load_with_discovery(Some(config_path.to_str().unwrap()), None, true)
+    }
+
+    #[test]
+    fn test_extend_single_base() {
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path();
+
+        let base_path = temp_path.join("base.toml");
+        fs::write(
+            &base_path,
+            "[global]\ndisable = [\"MD033\"]\nline-length = 100\n\n[MD007]\nindent = 4\n",
+        )
+        .expect("Failed to write base config");
+        let config_path = temp_path.join(".rumdl.toml");
+        fs::write(&config_path, "extend = \"base.toml\"\n\n[global]\nline-length = 120\n")
+            .expect("Failed to write config");
+
+        let sourced = load(&config_path).expect("Should load config");
+        let base_file = base_path.display().to_string();
+        let config_file = config_path.display().to_string();
+        assert_eq!(sourced.loaded_files, vec![base_file.clone(), config_file.clone()]);
+
+        // Inherited values point at the base file
+        assert_eq!(sourced.global.disable.value, vec!["MD033".to_string()]);
+        assert_eq!(
+            sourced.global.disable.overrides.last().and_then(|o| o.file.as_deref()),
+            Some(base_file.as_str())
+        );
+        let indent = &sourced.rules["MD007"].values["indent"];
+        assert_eq!(indent.value.as_integer(), Some(4));
+        assert_eq!(
+            indent.overrides.last().and_then(|o| o.file.as_deref()),
+            Some(base_file.as_str())
+        );
+
+        // The extending file wins over its base
+        assert_eq!(sourced.global.line_length.value.get(), 120);
+        assert_eq!(sourced.global.line_length.source, ConfigSource::ProjectConfig);
+        assert_eq!(
+            sourced
+                .global
+                .line_length
+                .overrides
+                .last()
+                .and_then(|o| o.file.as_deref()),
+            Some(config_file.as_str())
+        );
+    }
+
+    #[test]
+    fn test_extend_chain_resolves_relative_to_each_file() {
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path();
+        fs::create_dir_all(temp_path.join("shared/presets")).expect("Failed to create dirs");
+        fs::create_dir(temp_path.join("project")).expect("Failed to create project");
+
+        // project/.rumdl.toml -> shared/team.toml -> shared/presets/strict.toml
+        let strict_path = temp_path.join("shared/presets/strict.toml");
+        fs::write(&strict_path, "[global]\nline-length = 80\nflavor = \"mkdocs\"\n")
+            .expect("Failed to write strict config");
+        let team_path = temp_path.join("shared/team.toml");
+        fs::write(
+            &team_path,
+            "extend = [\"presets/strict.toml\"]\n\n[global]\nline-length = 100\n",
+        )
+        .expect("Failed to write team config");
+        let config_path = temp_path.join("project/.rumdl.toml");
+        fs::write(
+            &config_path,
+            "extend = \"../shared/team.toml\"\n\n[MD013]\ntables = false\n",
+        )
+        .expect("Failed to write config");
+
+        let sourced = load(&config_path).expect("Should load config");
+        assert_eq!(
+            sourced.loaded_files,
+            vec![
+                temp_path
+                    .join("project/../shared/presets/strict.toml")
+                    .display()
+                    .to_string(),
+                temp_path.join("project/../shared/team.toml").display().to_string(),
+                config_path.display().to_string(),
+            ]
+        );
+
+        // Nearer files in the chain override the files they extend
+        assert_eq!(sourced.global.line_length.value.get(), 100);
+        assert!(
+            sourced
+                .global
+                .line_length
+                .overrides
+                .last()
+                .and_then(|o| o.file.as_deref())
+                .is_some_and(|file| file.ends_with("team.toml"))
+        );
+        let config: rumdl_lib::config::Config = sourced.into();
+        assert_eq!(config.global.flavor, rumdl_lib::config::MarkdownFlavor::MkDocs);
+        assert_eq!(
+            rumdl_lib::config::get_rule_config_value::<bool>(&config, "MD013", "tables"),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn test_extend_from_pyproject() {
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path();
+
+        fs::write(
+            temp_path.join("base.toml"),
+            "[global]\nline-length = 90\ndisable = [\"MD033\"]\n",
+        )
+        .expect("Failed to write base config");
+        let config_path = temp_path.join("pyproject.toml");
+        fs::write(
+            &config_path,
+            "[tool.rumdl]\nextend = \"base.toml\"\nline-length = 110\n",
+        )
+        .expect("Failed to write pyproject.toml");
+
+        let sourced = load(&config_path).expect("Should load config");
+        assert_eq!(sourced.loaded_files.len(), 2);
+        assert_eq!(sourced.global.line_length.value.get(), 110);
+        assert_eq!(sourced.global.disable.value, vec!["MD033".to_string()]);
+        assert_eq!(sourced.global.disable.source, ConfigSource::PyprojectToml);
+    }
+
+    #[test]
+    fn test_extend_cycle_is_an_error() {
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path();
+
+        fs::write(temp_path.join("a.toml"), "extend = \"b.toml\"\n").expect("Failed to write a.toml");
+        fs::write(temp_path.join("b.toml"), "extend = \"./a.toml\"\n").expect("Failed to write b.toml");
+        let config_path = temp_path.join(".rumdl.toml");
+        fs::write(&config_path, "extend = \"a.toml\"\n").expect("Failed to write config");
+
+        let err = load(&config_path).expect_err("A cycle should be an error");
+        assert!(matches!(err, ConfigError::CircularExtend { .. }), "{err:?}");
+        let message = err.to_string();
+        let canonical = |name: &str| temp_path.join(name).canonicalize().unwrap().display().to_string();
+        assert_eq!(
+            message,
+            format!(
+                "Circular extend in config files: {} -> {} -> {}",
+                canonical("a.toml"),
+                canonical("b.toml"),
+                canonical("a.toml")
+            )
+        );
+
+        // A file extending itself is a cycle too
+        fs::write(&config_path, "extend = \".rumdl.toml\"\n").expect("Failed to write config");
+        assert!(matches!(load(&config_path), Err(ConfigError::CircularExtend { .. })));
+    }
+
+    #[test]
+    fn test_extend_missing_or_invalid() {
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        let config_path = temp_dir.path().join(".rumdl.toml");
+
+        fs::write(&config_path, "extend = \"missing.toml\"\n").expect("Failed to write config");
+        let err = load(&config_path).expect_err("A missing base should be an error");
+        assert!(err.to_string().contains("missing.toml"), "{err}");
+
+        fs::write(&config_path, "extend = 1\n").expect("Failed to write config");
+        let err = load(&config_path).expect_err("A non-path extend should be an error");
+        assert!(err.to_string().contains("`extend` must be a path"), "{err}");
+    }
+}
+
+#[test]
+fn test_renamed_rule_md901_resolves_to_md063() {
+    let temp_dir = tempdir().expect("Failed to create temporary directory");
+    let config_path = temp_dir.path().join(".rumdl.toml");
+    let config_content = r#"
+[global]
+disable = ["MD901"]
+
+[MD901]
+check-references = true
+"#;
+    fs::write(&config_path, config_content).expect("Failed to write test config file");
+
+    let sourced =
+        rumdl_lib::config::SourcedConfig::load_with_discovery(Some(config_path.to_str().unwrap()), None, true).unwrap();
+    let all_rules = all_rules(&Config::default());
+    let registry = RuleRegistry::from_rules(&all_rules);
+    let warnings = rumdl_lib::config::validate_config_sourced(&sourced, &registry);
+    assert!(warnings.is_empty(), "MD901 should be accepted as MD063: {warnings:?}");
+
+    let config: Config = sourced.into();
+    assert_eq!(config.global.disable, vec!["MD063".to_string()]);
+    assert_eq!(
+        config.rules["MD063"].values.get("check-references"),
+        Some(&toml::Value::Boolean(true))
+    );
+    assert!(!config.rules.contains_key("MD901"));
+}
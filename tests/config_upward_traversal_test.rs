@@ -205,3 +205,59 @@ disable = ["MD013", "MD033"]
         "Line length warning should not appear"
     );
 }
+
+#[test]
+fn test_hierarchical_nested_config_applies_below_its_directory() {
+    let temp_dir = tempdir().unwrap();
+    let project_dir = temp_dir.path();
+    fs::create_dir(project_dir.join(".git")).unwrap();
+    fs::create_dir_all(project_dir.join("docs/guide")).unwrap();
+
+    fs::write(project_dir.join(".rumdl.toml"), "[global]\nhierarchical = true\n").unwrap();
+    fs::write(
+        project_dir.join("docs/.rumdl.toml"),
+        "[global]\ndisable = [\"MD013\"]\n",
+    )
+    .unwrap();
+
+    let long_line = format!("# Test\n\n{}\n", "word ".repeat(30).trim_end());
+    fs::write(project_dir.join("README.md"), &long_line).unwrap();
+    fs::write(project_dir.join("docs/index.md"), &long_line).unwrap();
+    fs::write(project_dir.join("docs/guide/setup.md"), &long_line).unwrap();
+
+    let rumdl_exe = env!("CARGO_BIN_EXE_rumdl");
+    for extra_args in [&[][..], &["--output-format", "json"][..]] {
+        let output = Command::new(rumdl_exe)
+            .args(["check", ".", "--no-cache"])
+            .args(extra_args)
+            .current_dir(project_dir)
+            .output()
+            .expect("Failed to execute command");
+        let stdout = String::from_utf8(output.stdout).unwrap();
+
+        // Only README.md reports MD013; docs/ and docs/guide/ pick up the nested config
+        assert_eq!(stdout.matches("MD013").count(), 1, "{extra_args:?}: {stdout}");
+        let md013_line = stdout.lines().find(|line| line.contains("MD013")).unwrap();
+        assert!(!md013_line.contains("docs"), "{extra_args:?}: {stdout}");
+    }
+
+    // Discovery from a nested file still finds the root config that enables `hierarchical`
+    let output = Command::new(rumdl_exe)
+        .args(["check", "docs/index.md", "README.md", "--no-cache"])
+        .current_dir(project_dir)
+        .output()
+        .expect("Failed to execute command");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.matches("MD013").count(), 1, "{stdout}");
+    assert!(stdout.contains("README.md:3:81: [MD013]"), "{stdout}");
+
+    // Without `hierarchical`, the nested config is ignored
+    fs::write(project_dir.join(".rumdl.toml"), "[global]\n").unwrap();
+    let output = Command::new(rumdl_exe)
+        .args(["check", ".", "--no-cache"])
+        .current_dir(project_dir)
+        .output()
+        .expect("Failed to execute command");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.matches("MD013").count(), 3, "{stdout}");
+}
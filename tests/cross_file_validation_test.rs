@@ -311,3 +311,40 @@ Here are the features.
         filtered_warnings[0].line
     );
 }
+
+#[test]
+fn test_lint_documents_in_memory() {
+    let documents = [
+        (
+            "virtual/guide.md",
+            "# Guide\n\n[Install](install.md#setup)\n[Missing](install.md#nope)\n",
+        ),
+        ("virtual/install.md", "# Install\n\n## Setup\n\nRun it.\n"),
+    ];
+    let config = Config::default();
+    let rules = rumdl_lib::rules::all_rules(&config);
+
+    let results = rumdl_lib::lint_documents(&documents, &rules, &config);
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].0, PathBuf::from("virtual/guide.md"));
+
+    // The valid fragment resolves against the other in-memory document, which is not on disk
+    let guide_warnings = results[0].1.as_ref().unwrap();
+    assert_eq!(guide_warnings.len(), 1, "Got: {guide_warnings:?}");
+    assert_eq!(guide_warnings[0].rule_name.as_deref(), Some("MD051"));
+    assert_eq!(guide_warnings[0].line, 4);
+    assert!(results[1].1.as_ref().unwrap().is_empty());
+}
+
+#[test]
+fn test_lint_documents_per_file_ignores() {
+    let documents = [("docs/a.md", "# A\n\n[B](b.md#gone)\n"), ("docs/b.md", "# B\n")];
+    let mut config = Config::default();
+    config
+        .per_file_ignores
+        .insert("docs/a.md".to_string(), vec!["MD051".to_string()]);
+    let rules = rumdl_lib::rules::all_rules(&config);
+
+    let results = rumdl_lib::lint_documents(&documents, &rules, &config);
+    assert!(results[0].1.as_ref().unwrap().is_empty());
+}
@@ -0,0 +1,119 @@
+use rumdl_lib::config::{Config, MarkdownFlavor, RuleRegistry, SourcedConfig, validate_config_sourced};
+use rumdl_lib::rules;
+use std::fs;
+use tempfile::tempdir;
+
+fn load_config(config_content: &str) -> SourcedConfig {
+    let temp_dir = tempdir().unwrap();
+    let config_path = temp_dir.path().join(".rumdl.toml");
+    fs::write(&config_path, config_content).unwrap();
+    SourcedConfig::load(Some(config_path.to_str().unwrap()), None).unwrap()
+}
+
+fn md033_lines(config: &Config, content: &str) -> Vec<usize> {
+    let all_rules = rules::all_rules(config);
+    let enabled: Vec<_> = all_rules.into_iter().filter(|r| r.name() == "MD033").collect();
+    rumdl_lib::lint_with_config(content, &enabled, false, config, MarkdownFlavor::Standard)
+        .unwrap()
+        .iter()
+        .map(|w| w.line)
+        .collect()
+}
+
+const CONTENT: &str = "# Title\n\n<b>body</b>\n\n> <b>quoted</b>\n\n- <b>listed</b>\n  <i>continued</i>\n";
+
+#[test]
+fn test_no_disable_in_reports_everywhere() {
+    let config: Config = load_config("").into();
+    assert_eq!(md033_lines(&config, CONTENT), vec![3, 5, 7, 8]);
+}
+
+#[test]
+fn test_disable_in_blockquote() {
+    let config: Config = load_config("[MD033]\ndisable-in = [\"blockquote\"]\n").into();
+    assert_eq!(md033_lines(&config, CONTENT), vec![3, 7, 8]);
+}
+
+#[test]
+fn test_disable_in_list_includes_continuation_lines() {
+    let config: Config = load_config("[MD033]\ndisable_in = [\"list\"]\n").into();
+    assert_eq!(md033_lines(&config, CONTENT), vec![3, 5]);
+}
+
+#[test]
+fn test_disable_in_multiple_contexts() {
+    let config: Config = load_config("[MD033]\ndisable-in = [\"blockquote\", \"list\"]\n").into();
+    assert_eq!(md033_lines(&config, CONTENT), vec![3]);
+}
+
+#[test]
+fn test_disable_in_code() {
+    let config: Config = load_config("[MD010]\ndisable-in = [\"code\"]\n").into();
+    let all_rules = rules::all_rules(&config);
+    let enabled: Vec<_> = all_rules.into_iter().filter(|r| r.name() == "MD010").collect();
+    let content = "# Title\n\nText\twith tab\n\n```\ncode\twith tab\n```\n";
+    let warnings = rumdl_lib::lint_with_config(content, &enabled, false, &config, MarkdownFlavor::Standard).unwrap();
+    assert_eq!(warnings.iter().map(|w| w.line).collect::<Vec<_>>(), vec![3]);
+}
+
+#[test]
+fn test_disable_in_keeps_other_rule_options() {
+    let config: Config = load_config("[MD013]\nline-length = 20\ndisable-in = [\"blockquote\"]\n").into();
+    let all_rules = rules::all_rules(&config);
+    let enabled: Vec<_> = all_rules.into_iter().filter(|r| r.name() == "MD013").collect();
+    let content =
+        "# Title\n\nThis line is definitely longer than twenty.\n\n> This quoted line is also longer than twenty.\n";
+    let warnings = rumdl_lib::lint_with_config(content, &enabled, false, &config, MarkdownFlavor::Standard).unwrap();
+    assert_eq!(warnings.iter().map(|w| w.line).collect::<Vec<_>>(), vec![3]);
+}
+
+#[test]
+fn test_disable_in_is_a_known_rule_option() {
+    let sourced = load_config("[MD033]\ndisable-in = [\"blockquote\"]\n");
+    let all_rules = rules::all_rules(&Config::default());
+    let registry = RuleRegistry::from_rules(&all_rules);
+    let warnings = validate_config_sourced(&sourced, &registry);
+    assert!(
+        warnings.iter().all(|w| w.key.as_deref() != Some("disable-in")),
+        "disable-in should not be reported as unknown: {warnings:?}"
+    );
+}
+
+#[test]
+fn test_disable_in_format_content_leaves_disabled_context_untouched() {
+    let config: Config = load_config("[MD010]\ndisable-in = [\"list\"]\n").into();
+    let all_rules = rules::all_rules(&config);
+    let enabled: Vec<_> = all_rules.into_iter().filter(|r| r.name() == "MD010").collect();
+    let content = "# Title\n\n- item\twith tab\n\nPara\twith tab\n";
+    let fixed = rumdl_lib::format_content(content, &enabled, &config, MarkdownFlavor::Standard).unwrap();
+    assert_eq!(fixed, "# Title\n\n- item\twith tab\n\nPara    with tab\n");
+}
+
+#[test]
+fn test_disable_in_cli_fix_skips_table_in_blockquote() {
+    let temp_dir = tempdir().unwrap();
+    fs::write(
+        temp_dir.path().join(".rumdl.toml"),
+        "[MD058]\ndisable-in = [\"blockquote\"]\n",
+    )
+    .unwrap();
+    let file_path = temp_dir.path().join("doc.md");
+    let quoted = "> Quote\n> | a | b |\n> | - | - |\n> | 1 | 2 |\n> After\n";
+    fs::write(
+        &file_path,
+        format!("# Title\n\nText\n| a | b |\n| - | - |\n| 1 | 2 |\nAfter\n\n{quoted}"),
+    )
+    .unwrap();
+
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("rumdl");
+    cmd.current_dir(temp_dir.path())
+        .args(["check", "--fix", "--no-cache", "doc.md"])
+        .output()
+        .unwrap();
+
+    let fixed = fs::read_to_string(&file_path).unwrap();
+    assert_eq!(
+        fixed,
+        format!("# Title\n\nText\n\n| a | b |\n| - | - |\n| 1 | 2 |\n\nAfter\n\n{quoted}")
+    );
+}
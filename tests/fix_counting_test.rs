@@ -30,6 +30,8 @@ Another very very very very very very very very very very very very very very ve
 
     let config = MD013Config {
         line_length: LineLength::from_const(80),
+        heading_line_length: None,
+        code_block_line_length: None,
         code_blocks: true,
         tables: true, // Check tables for line length
         headings: true,
@@ -95,6 +97,8 @@ fn test_mixed_rules_fix_counting() {
 
     let md013_config = MD013Config {
         line_length: LineLength::from_const(80),
+        heading_line_length: None,
+        code_block_line_length: None,
         code_blocks: true,
         tables: true, // Check tables for line length
         headings: true,
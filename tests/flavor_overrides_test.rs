@@ -0,0 +1,86 @@
+use rumdl_lib::config::{Config, MarkdownFlavor, RuleRegistry, SourcedConfig, validate_config_sourced};
+use rumdl_lib::rules;
+use std::fs;
+use tempfile::tempdir;
+
+fn load_config(config_content: &str) -> SourcedConfig {
+    let temp_dir = tempdir().unwrap();
+    let config_path = temp_dir.path().join(".rumdl.toml");
+    fs::write(&config_path, config_content).unwrap();
+    SourcedConfig::load(Some(config_path.to_str().unwrap()), None).unwrap()
+}
+
+fn rule_names(config: &Config, content: &str, only: &[&str], flavor: MarkdownFlavor) -> Vec<String> {
+    let all_rules = rules::all_rules(config);
+    let enabled: Vec<_> = all_rules.into_iter().filter(|r| only.contains(&r.name())).collect();
+    let mut names: Vec<String> = rumdl_lib::lint_with_config(content, &enabled, false, config, flavor)
+        .unwrap()
+        .into_iter()
+        .filter_map(|w| w.rule_name)
+        .collect();
+    names.dedup();
+    names
+}
+
+const ABBREVIATIONS: &str =
+    "# Title\n\nThe HTML spec.\n\n*[HTML]: HyperText Markup Language\n*[HTML]: Hypertext Markup Language\n";
+
+#[test]
+fn test_flavor_specific_rule_skipped_by_default() {
+    let config: Config = load_config("").into();
+    assert!(rule_names(&config, ABBREVIATIONS, &["MD107"], MarkdownFlavor::Standard).is_empty());
+    assert_eq!(
+        rule_names(&config, ABBREVIATIONS, &["MD107"], MarkdownFlavor::MkDocs),
+        vec!["MD107"]
+    );
+}
+
+#[test]
+fn test_enabled_for_flavors_adds_flavor() {
+    let config: Config = load_config("[MD107]\nenabled-for-flavors = [\"standard\", \"mkdocs\"]\n").into();
+    assert_eq!(
+        rule_names(&config, ABBREVIATIONS, &["MD107"], MarkdownFlavor::Standard),
+        vec!["MD107"]
+    );
+    assert_eq!(
+        rule_names(&config, ABBREVIATIONS, &["MD107"], MarkdownFlavor::MkDocs),
+        vec!["MD107"]
+    );
+}
+
+#[test]
+fn test_enabled_for_flavors_removes_flavor() {
+    let config: Config = load_config("[MD033]\nenabled_for_flavors = [\"standard\"]\n").into();
+    let content = "# Title\n\n<b>bold</b>\n";
+    assert_eq!(
+        rule_names(&config, content, &["MD033"], MarkdownFlavor::Standard),
+        vec!["MD033"]
+    );
+    assert!(rule_names(&config, content, &["MD033"], MarkdownFlavor::MkDocs).is_empty());
+}
+
+#[test]
+fn test_enabled_for_flavors_empty_list_disables_rule() {
+    let config: Config = load_config("[MD033]\nenabled-for-flavors = []\n").into();
+    assert!(
+        rule_names(
+            &config,
+            "# Title\n\n<b>bold</b>\n",
+            &["MD033"],
+            MarkdownFlavor::Standard
+        )
+        .is_empty()
+    );
+}
+
+#[test]
+fn test_enabled_for_flavors_is_a_known_rule_option() {
+    let sourced = load_config("[MD033]\nenabled-for-flavors = [\"standard\"]\n");
+    let all_rules = rules::all_rules(&Config::default());
+    let registry = RuleRegistry::from_rules(&all_rules);
+    let warnings = validate_config_sourced(&sourced, &registry);
+    assert!(
+        warnings.iter().all(|w| w.key.as_deref() != Some("enabled-for-flavors")),
+        "enabled-for-flavors should not be reported as unknown: {warnings:?}"
+    );
+}
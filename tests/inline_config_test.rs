@@ -51,6 +51,29 @@ This is another very long line that exceeds 80 characters and should trigger MD0
     assert_eq!(md013_warnings[0].line, 5);
 }
 
+#[test]
+fn test_rumdl_trailing_suppression_comment() {
+    let content = r#"# Test Document
+
+This is a very long line that exceeds 80 characters and would normally trigger MD013 <!-- rumdl: MD013 -->
+
+This is a very long line with `<!-- rumdl: MD013 -->` inside a code span, so MD013 still triggers here
+
+This is another very long line that exceeds 80 characters and should trigger MD013 because it's not disabled
+"#;
+
+    let rules = all_rules(&Config::default());
+    let warnings = lint(content, &rules, false, rumdl_lib::config::MarkdownFlavor::Standard).unwrap();
+
+    let md013_lines: Vec<_> = warnings
+        .iter()
+        .filter(|w| w.rule_name.as_ref().is_some_and(|n| *n == "MD013"))
+        .map(|w| w.line)
+        .collect();
+
+    assert_eq!(md013_lines, vec![5, 7]);
+}
+
 #[test]
 fn test_markdownlint_disable_next_line() {
     let content = r#"# Test Document
@@ -443,3 +466,61 @@ This is another very long line that exceeds 80 characters and should trigger MD0
     assert_eq!(md013_warnings.len(), 1);
     assert_eq!(md013_warnings[0].line, 7);
 }
+
+#[test]
+fn test_jsx_comments_in_mdx() {
+    // MDX defaults to JSX comments, since HTML comments aren't valid there
+    let content = r#"# Test Document
+
+{/* rumdl-disable MD013 */}
+This is a very long line that exceeds 80 characters and would normally trigger MD013 but is disabled by the comment above
+
+{/* rumdl-enable MD013 */}
+This is another very long line that exceeds 80 characters and should trigger MD013 because it was re-enabled
+"#;
+
+    let rules = all_rules(&Config::default());
+    let count_md013 = |flavor| {
+        lint(content, &rules, false, flavor)
+            .unwrap()
+            .iter()
+            .filter(|w| w.rule_name.as_deref() == Some("MD013"))
+            .count()
+    };
+
+    assert_eq!(count_md013(rumdl_lib::config::MarkdownFlavor::MDX), 1);
+    // Standard Markdown only recognizes HTML comments by default
+    assert_eq!(count_md013(rumdl_lib::config::MarkdownFlavor::Standard), 2);
+}
+
+#[test]
+fn test_inline_comment_syntax_config() {
+    use rumdl_lib::config::InlineCommentSyntax;
+
+    let content = "# Title\n\n{/* rumdl-disable-next-line MD013 */}\nThis is a very long line that exceeds 80 characters and is suppressed with a JSX comment\n";
+    let mut config = Config::default();
+    config.global.inline_comment_syntax = Some(InlineCommentSyntax::Jsx);
+
+    let rules = all_rules(&config);
+    let warnings = rumdl_lib::lint_with_config(
+        content,
+        &rules,
+        false,
+        &config,
+        rumdl_lib::config::MarkdownFlavor::Standard,
+    )
+    .unwrap();
+    assert!(!warnings.iter().any(|w| w.rule_name.as_deref() == Some("MD013")));
+
+    // HTML comments are still honored with the JSX syntax
+    let html = InlineConfig::from_content_with_syntax("<!-- rumdl-disable MD001 -->\ntext\n", InlineCommentSyntax::Jsx);
+    assert!(html.is_rule_disabled("MD001", 2));
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let config_path = temp_dir.path().join(".rumdl.toml");
+    std::fs::write(&config_path, "[global]\ninline-comment-syntax = \"jsx\"\n").unwrap();
+    let sourced =
+        rumdl_lib::config::SourcedConfig::load_with_discovery(Some(config_path.to_str().unwrap()), None, true).unwrap();
+    let loaded: Config = sourced.into();
+    assert_eq!(loaded.global.inline_comment_syntax, Some(InlineCommentSyntax::Jsx));
+}
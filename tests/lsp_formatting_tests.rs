@@ -498,3 +498,64 @@ Final paragraph"#;
         assert!(edit.new_text.ends_with('\n'), "Final newline added");
     }
 }
+
+/// Test that formatting gives the same result as `rumdl fmt`, and that a formatted document needs no edits
+#[tokio::test]
+async fn test_formatting_matches_cli_fix() {
+    let (service, _socket) = LspService::new(|client| RumdlLanguageServer::new(client, None));
+    let server = service.inner();
+    server.initialize(InitializeParams::default()).await.unwrap();
+    server.initialized(InitializedParams {}).await;
+
+    let uri = Url::parse("file:///test/converge.md").unwrap();
+    // Fixing one issue here changes the ranges of others, so the fixes have to be applied in turn
+    let text = "#Title\nText with trailing spaces   \n\n- a\n  - b\n   - c\n\n_a_ *b* __c__ **d**\n";
+    let formatting_params = |uri: &Url| DocumentFormattingParams {
+        text_document: TextDocumentIdentifier { uri: uri.clone() },
+        options: FormattingOptions::default(),
+        work_done_progress_params: WorkDoneProgressParams::default(),
+    };
+    let open = |version: i32, text: &str| DidOpenTextDocumentParams {
+        text_document: TextDocumentItem {
+            uri: uri.clone(),
+            language_id: "markdown".to_string(),
+            version,
+            text: text.to_string(),
+        },
+    };
+
+    server.did_open(open(1, text)).await;
+    let edits = server.formatting(formatting_params(&uri)).await.unwrap().unwrap();
+    assert_eq!(edits.len(), 1, "The whole document is replaced in one edit");
+    let formatted = edits[0].new_text.clone();
+
+    let config = rumdl_lib::config::Config::default();
+    let rules = rumdl_lib::rules::all_rules(&config);
+    let expected = rumdl_lib::format_content(text, &rules, &config, config.markdown_flavor()).unwrap();
+    assert_eq!(formatted, expected);
+    assert_eq!(
+        formatted,
+        "# Title\n\nText with trailing spaces\n\n- a\n  - b\n  - c\n\n*a* *b* **c** **d**\n"
+    );
+    assert!(!formatted.contains("   \n"), "Trailing spaces should be removed");
+
+    // Range formatting is registered and formats the whole document
+    let range_edits = server
+        .range_formatting(DocumentRangeFormattingParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            range: Range {
+                start: Position { line: 1, character: 0 },
+                end: Position { line: 1, character: 5 },
+            },
+            options: FormattingOptions::default(),
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(range_edits, edits);
+
+    server.did_open(open(2, &formatted)).await;
+    let edits = server.formatting(formatting_params(&uri)).await.unwrap().unwrap();
+    assert!(edits.is_empty(), "Formatting should be stable, got {edits:?}");
+}
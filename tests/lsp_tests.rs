@@ -360,6 +360,108 @@ async fn test_code_action_request() {
     // which rules would trigger on our test content
 }
 
+/// Rule codes of the server's diagnostics for an open document
+async fn diagnostic_codes(server: &RumdlLanguageServer, uri: &Url) -> Vec<String> {
+    let params = DocumentDiagnosticParams {
+        text_document: TextDocumentIdentifier { uri: uri.clone() },
+        identifier: None,
+        previous_result_id: None,
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+    };
+    match server.diagnostic(params).await.unwrap() {
+        DocumentDiagnosticReportResult::Report(DocumentDiagnosticReport::Full(report)) => report
+            .full_document_diagnostic_report
+            .items
+            .into_iter()
+            .filter_map(|diagnostic| match diagnostic.code {
+                Some(NumberOrString::String(code)) => Some(code),
+                _ => None,
+            })
+            .collect(),
+        _ => panic!("Expected full diagnostic report"),
+    }
+}
+
+/// Test that the ignore code actions insert comments the linter honors
+#[tokio::test]
+async fn test_code_action_ignore_comments_clear_diagnostic() {
+    let (service, _socket) = LspService::new(|client| RumdlLanguageServer::new(client, None));
+    let server = service.inner();
+    server.initialize(InitializeParams::default()).await.unwrap();
+    server.initialized(InitializedParams {}).await;
+
+    let text = "# Test\n\nTrailing   \n\n```\ncode\n```\n";
+    let cases = [
+        ("MD009", 2, "Ignore MD009 for this line"),
+        ("MD009", 2, "Ignore MD009 for this file"),
+        ("MD040", 4, "Ignore MD040 for this line"),
+        ("MD040", 4, "Ignore MD040 for this file"),
+    ];
+
+    for (index, (rule, line, title)) in cases.into_iter().enumerate() {
+        let uri = Url::parse(&format!("file:///ignore-{index}.md")).unwrap();
+        server
+            .did_open(DidOpenTextDocumentParams {
+                text_document: TextDocumentItem {
+                    uri: uri.clone(),
+                    language_id: "markdown".to_string(),
+                    version: 1,
+                    text: text.to_string(),
+                },
+            })
+            .await;
+        assert!(diagnostic_codes(server, &uri).await.contains(&rule.to_string()));
+
+        let response = server
+            .code_action(CodeActionParams {
+                text_document: TextDocumentIdentifier { uri: uri.clone() },
+                range: Range {
+                    start: Position { line, character: 0 },
+                    end: Position { line, character: 0 },
+                },
+                context: CodeActionContext::default(),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+            })
+            .await
+            .unwrap()
+            .unwrap();
+        let action = response
+            .into_iter()
+            .find_map(|action| match action {
+                CodeActionOrCommand::CodeAction(action) if action.title == title => Some(action),
+                _ => None,
+            })
+            .unwrap_or_else(|| panic!("No '{title}' action"));
+
+        // Apply the single insertion and re-lint
+        let edit = &action.edit.unwrap().changes.unwrap()[&uri][0];
+        let mut lines: Vec<String> = text.split('\n').map(str::to_string).collect();
+        let position = edit.range.start;
+        lines[position.line as usize].insert_str(position.character as usize, &edit.new_text);
+        server
+            .did_change(DidChangeTextDocumentParams {
+                text_document: VersionedTextDocumentIdentifier {
+                    uri: uri.clone(),
+                    version: 2,
+                },
+                content_changes: vec![TextDocumentContentChangeEvent {
+                    range: None,
+                    range_length: None,
+                    text: lines.join("\n"),
+                }],
+            })
+            .await;
+
+        let codes = diagnostic_codes(server, &uri).await;
+        assert!(
+            !codes.contains(&rule.to_string()),
+            "{title}: still reported in {codes:?}"
+        );
+    }
+}
+
 /// Test diagnostic request
 #[tokio::test]
 async fn test_diagnostic_request() {
@@ -701,3 +803,184 @@ mod edge_cases {
         }
     }
 }
+
+/// Test that a broken link to another file carries the path that was looked for
+#[tokio::test]
+async fn test_cross_file_diagnostic_related_information() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let root = temp_dir.path().canonicalize().unwrap();
+    let text = "# Index\n\nSee the [guide](missing.md) and [setup](setup.md#nope).\n";
+    std::fs::write(root.join("index.md"), text).unwrap();
+    std::fs::write(root.join("setup.md"), "# Setup\n").unwrap();
+    // A project config, so the repository's own config isn't picked up as the fallback
+    std::fs::write(root.join(".rumdl.toml"), "[global]\n").unwrap();
+
+    let (service, _socket) = LspService::new(|client| RumdlLanguageServer::new(client, None));
+    let server = service.inner();
+    server
+        .initialize(InitializeParams {
+            root_uri: Some(Url::from_file_path(&root).unwrap()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    server.initialized(InitializedParams {}).await;
+
+    let uri = Url::from_file_path(root.join("index.md")).unwrap();
+    server
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "markdown".to_string(),
+                version: 1,
+                text: text.to_string(),
+            },
+        })
+        .await;
+
+    // Cross-file checks run once the workspace index is built
+    let mut diagnostics = Vec::new();
+    for _ in 0..50 {
+        let report = server
+            .diagnostic(DocumentDiagnosticParams {
+                text_document: TextDocumentIdentifier { uri: uri.clone() },
+                identifier: None,
+                previous_result_id: None,
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+            })
+            .await
+            .unwrap();
+        let DocumentDiagnosticReportResult::Report(DocumentDiagnosticReport::Full(report)) = report else {
+            panic!("Expected full diagnostic report");
+        };
+        diagnostics = report.full_document_diagnostic_report.items;
+        if diagnostics.iter().any(|d| d.related_information.is_some()) {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+
+    let related = |rule: &str| {
+        let diagnostic = diagnostics
+            .iter()
+            .find(|d| d.code == Some(NumberOrString::String(rule.to_string())))
+            .unwrap_or_else(|| panic!("No {rule} diagnostic in {diagnostics:?}"));
+        let related = diagnostic.related_information.clone().unwrap();
+        assert_eq!(related.len(), 1);
+        related.into_iter().next().unwrap()
+    };
+
+    let missing = related("MD057");
+    assert_eq!(
+        missing.location.uri,
+        Url::from_file_path(root.join("missing.md")).unwrap()
+    );
+    assert_eq!(
+        missing.message,
+        format!("Looked for {}, which does not exist", root.join("missing.md").display())
+    );
+
+    let fragment = related("MD051");
+    assert_eq!(
+        fragment.location.uri,
+        Url::from_file_path(root.join("setup.md")).unwrap()
+    );
+    assert!(
+        fragment.message.ends_with("has the anchor '#nope'"),
+        "{}",
+        fragment.message
+    );
+}
+
+/// Test that incremental changes are applied to the stored text and diagnostics follow them
+#[tokio::test]
+async fn test_incremental_changes_shift_diagnostics() {
+    let (service, _socket) = LspService::new(|client| RumdlLanguageServer::new(client, None));
+    let server = service.inner();
+    let caps = server
+        .initialize(InitializeParams::default())
+        .await
+        .unwrap()
+        .capabilities;
+    let Some(TextDocumentSyncCapability::Options(sync)) = caps.text_document_sync else {
+        panic!("Expected text document sync options");
+    };
+    assert_eq!(sync.change, Some(TextDocumentSyncKind::INCREMENTAL));
+    server.initialized(InitializedParams {}).await;
+
+    let uri = Url::parse("file:///incremental.md").unwrap();
+    server
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "markdown".to_string(),
+                version: 1,
+                text: "# Title\n\nTrailing   \n".to_string(),
+            },
+        })
+        .await;
+
+    let md009_lines = || async {
+        let params = DocumentDiagnosticParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            identifier: None,
+            previous_result_id: None,
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+        };
+        let DocumentDiagnosticReportResult::Report(DocumentDiagnosticReport::Full(report)) =
+            server.diagnostic(params).await.unwrap()
+        else {
+            panic!("Expected full diagnostic report");
+        };
+        report
+            .full_document_diagnostic_report
+            .items
+            .into_iter()
+            .filter(|d| d.code == Some(NumberOrString::String("MD009".to_string())))
+            .map(|d| d.range.start.line)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(md009_lines().await, [2]);
+
+    let edit = |version: i32, changes: Vec<(Range, &str)>| DidChangeTextDocumentParams {
+        text_document: VersionedTextDocumentIdentifier {
+            uri: uri.clone(),
+            version,
+        },
+        content_changes: changes
+            .into_iter()
+            .map(|(range, text)| TextDocumentContentChangeEvent {
+                range: Some(range),
+                range_length: None,
+                text: text.to_string(),
+            })
+            .collect(),
+    };
+    let at = |line, character| Position { line, character };
+
+    // Two paragraphs inserted after the heading push the trailing spaces down four lines
+    server
+        .did_change(edit(
+            2,
+            vec![
+                (Range::new(at(1, 0), at(1, 0)), "\nFirst\n"),
+                (Range::new(at(3, 0), at(3, 0)), "\nSecond\n"),
+            ],
+        ))
+        .await;
+    assert_eq!(md009_lines().await, [6]);
+
+    // Trailing spaces added to "First", and the later ones removed
+    server
+        .did_change(edit(
+            3,
+            vec![
+                (Range::new(at(2, 5), at(2, 5)), "   "),
+                (Range::new(at(6, 8), at(6, 11)), ""),
+            ],
+        ))
+        .await;
+    assert_eq!(md009_lines().await, [2]);
+}
@@ -84,6 +84,40 @@ Content with trailing space{}
         .stdout(predicate::str::contains("code=MD022]"));
 }
 
+#[test]
+fn test_report_root() {
+    let temp_dir = tempdir().unwrap();
+    let package_dir = temp_dir.path().join("packages/a");
+    fs::create_dir_all(package_dir.join("docs")).unwrap();
+    fs::write(temp_dir.path().join(".rumdl.toml"), "[global]\nreport-root = \".\"\n").unwrap();
+    fs::write(package_dir.join("docs/guide.md"), "# Guide\n#Bad\n").unwrap();
+
+    // The config value is relative to the project root, wherever rumdl runs from
+    for format in ["github", "json", "sarif"] {
+        let mut cmd = cargo_bin_cmd!("rumdl");
+        cmd.current_dir(&package_dir)
+            .args(["check", "docs", "--no-cache", "--output-format", format]);
+        cmd.assert()
+            .failure()
+            .stdout(predicate::str::contains("packages/a/docs/guide.md"));
+    }
+
+    // --report-root overrides the config and is relative to the working directory
+    let mut cmd = cargo_bin_cmd!("rumdl");
+    cmd.current_dir(&package_dir).args([
+        "check",
+        "docs",
+        "--no-cache",
+        "--output-format",
+        "github",
+        "--report-root",
+        "docs",
+    ]);
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("::warning file=guide.md,line=2"));
+}
+
 // Config tests are currently disabled because config loading happens after output format determination
 // TODO: Fix the order of config loading to support output format in config files
 
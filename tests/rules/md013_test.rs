@@ -448,6 +448,8 @@ fn test_heading_line_length_config() {
     // Create a config with standard settings (no longer supporting separate heading limits)
     let config = MD013Config {
         line_length: LineLength::from_const(50),
+        heading_line_length: None,
+        code_block_line_length: None,
         code_blocks: false, // false = skip code blocks
         tables: false,      // false = skip tables
         headings: false,    // false = skip headings (don't check them)
@@ -474,6 +476,8 @@ fn test_code_block_line_length_config() {
     // Create a config with standard settings (no longer supporting separate code block limits)
     let config = MD013Config {
         line_length: LineLength::from_const(50),
+        heading_line_length: None,
+        code_block_line_length: None,
         code_blocks: false, // false = skip code blocks (don't check them)
         tables: true,       // true = check tables
         headings: true,     // true = check headings
@@ -500,6 +504,8 @@ fn test_stern_mode() {
     // Create a config with strict mode (stern mode no longer exists)
     let config = MD013Config {
         line_length: LineLength::from_const(50),
+        heading_line_length: None,
+        code_block_line_length: None,
         code_blocks: false, // Don't skip code blocks
         tables: false,      // Don't skip tables
         headings: false,    // Don't skip headings
@@ -525,6 +531,8 @@ fn test_combined_heading_and_code_block_limits() {
     // Simplified configuration - using single line length for all content
     let config = MD013Config {
         line_length: LineLength::from_const(40),
+        heading_line_length: None,
+        code_block_line_length: None,
         code_blocks: false, // false = skip code blocks
         tables: true,       // true = check tables
         headings: false,    // false = skip headings
@@ -567,6 +575,8 @@ fn test_unicode_characters_counted_correctly() {
     // Test with explicit chars mode (for backward compatibility testing)
     let config = MD013Config {
         line_length: LineLength::from_const(20),
+        heading_line_length: None,
+        code_block_line_length: None,
         length_mode: LengthMode::Chars,
         ..Default::default()
     };
@@ -649,6 +659,8 @@ fn test_reflow_simple_paragraph() {
 
     let config = MD013Config {
         line_length: LineLength::from_const(40),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         ..Default::default()
     };
@@ -676,6 +688,8 @@ fn test_reflow_preserves_markdown_elements() {
 
     let config = MD013Config {
         line_length: LineLength::from_const(30),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         ..Default::default()
     };
@@ -704,6 +718,8 @@ fn test_reflow_multiple_paragraphs() {
 
     let config = MD013Config {
         line_length: LineLength::from_const(50),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         ..Default::default()
     };
@@ -736,6 +752,8 @@ fn test_reflow_list_items() {
 
     let config = MD013Config {
         line_length: LineLength::from_const(40),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         ..Default::default()
     };
@@ -769,6 +787,8 @@ fn test_reflow_numbered_lists() {
 
     let config = MD013Config {
         line_length: LineLength::from_const(35),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         ..Default::default()
     };
@@ -812,6 +832,8 @@ fn test_reflow_blockquotes() {
 
     let config = MD013Config {
         line_length: LineLength::from_const(40),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         ..Default::default()
     };
@@ -844,6 +866,8 @@ fn test_reflow_preserves_code_blocks() {
 
     let config = MD013Config {
         line_length: LineLength::from_const(30),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         ..Default::default()
     };
@@ -887,6 +911,8 @@ fn test_reflow_preserves_headings() {
 
     let config = MD013Config {
         line_length: LineLength::from_const(30),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         ..Default::default()
     };
@@ -922,6 +948,8 @@ fn test_reflow_preserves_tables() {
 
     let config = MD013Config {
         line_length: LineLength::from_const(30),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         ..Default::default()
     };
@@ -958,6 +986,8 @@ fn test_reflow_edge_cases() {
 
     let config = MD013Config {
         line_length: LineLength::from_const(20),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         ..Default::default()
     };
@@ -999,6 +1029,8 @@ fn test_reflow_complex_document() {
 
     let config = MD013Config {
         line_length: LineLength::from_const(50),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         ..Default::default()
     };
@@ -1073,6 +1105,8 @@ fn test_reflow_with_hard_line_breaks() {
 
     let config = MD013Config {
         line_length: LineLength::from_const(40),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         ..Default::default()
     };
@@ -1100,6 +1134,8 @@ fn test_reflow_unicode_handling() {
 
     let config = MD013Config {
         line_length: LineLength::from_const(30),
+        heading_line_length: None,
+        code_block_line_length: None,
         reflow: true,
         ..Default::default()
     };
@@ -1127,6 +1163,8 @@ fn test_length_mode_chars_with_cjk() {
     // Default mode (chars) counts CJK characters as 1 each
     let config = MD013Config {
         line_length: LineLength::from_const(20),
+        heading_line_length: None,
+        code_block_line_length: None,
         length_mode: LengthMode::Chars,
         ..Default::default()
     };
@@ -1156,6 +1194,8 @@ fn test_length_mode_visual_with_cjk() {
     // Visual mode counts CJK characters as 2 columns each
     let config = MD013Config {
         line_length: LineLength::from_const(20),
+        heading_line_length: None,
+        code_block_line_length: None,
         length_mode: LengthMode::Visual,
         ..Default::default()
     };
@@ -1201,6 +1241,8 @@ fn test_length_mode_chars_with_emoji() {
     // Chars mode counts emoji as 1 character each
     let config = MD013Config {
         line_length: LineLength::from_const(15),
+        heading_line_length: None,
+        code_block_line_length: None,
         length_mode: LengthMode::Chars,
         ..Default::default()
     };
@@ -1223,6 +1265,8 @@ fn test_length_mode_visual_with_emoji() {
     // Visual mode: Most emoji are 2 columns wide
     let config = MD013Config {
         line_length: LineLength::from_const(20),
+        heading_line_length: None,
+        code_block_line_length: None,
         length_mode: LengthMode::Visual,
         ..Default::default()
     };
@@ -1257,6 +1301,8 @@ fn test_length_mode_bytes() {
     // Bytes mode counts raw UTF-8 bytes
     let config = MD013Config {
         line_length: LineLength::from_const(20),
+        heading_line_length: None,
+        code_block_line_length: None,
         length_mode: LengthMode::Bytes,
         ..Default::default()
     };
@@ -1296,6 +1342,8 @@ fn test_length_mode_mixed_content() {
     // Chars mode: 3 + 2 + 1 + 5 = 11 chars
     let config_chars = MD013Config {
         line_length: LineLength::from_const(15),
+        heading_line_length: None,
+        code_block_line_length: None,
         length_mode: LengthMode::Chars,
         ..Default::default()
     };
@@ -1307,6 +1355,8 @@ fn test_length_mode_mixed_content() {
     // Visual mode: 3 + 4 + 2 + 5 = 14 visual columns
     let config_visual = MD013Config {
         line_length: LineLength::from_const(15),
+        heading_line_length: None,
+        code_block_line_length: None,
         length_mode: LengthMode::Visual,
         ..Default::default()
     };
@@ -1333,6 +1383,8 @@ fn test_length_mode_with_urls() {
     // Visual mode counts the same as chars mode for ASCII text
     let config = MD013Config {
         line_length: LineLength::from_const(100), // Set high limit to test behavior
+        heading_line_length: None,
+        code_block_line_length: None,
         length_mode: LengthMode::Visual,
         strict: false,
         ..Default::default()
@@ -1356,6 +1408,8 @@ fn test_length_mode_japanese_text() {
     // Chars mode: 12 characters
     let config_chars = MD013Config {
         line_length: LineLength::from_const(15),
+        heading_line_length: None,
+        code_block_line_length: None,
         length_mode: LengthMode::Chars,
         ..Default::default()
     };
@@ -1367,6 +1421,8 @@ fn test_length_mode_japanese_text() {
     // Visual mode: 24 visual columns (each Japanese char = 2 columns)
     let config_visual = MD013Config {
         line_length: LineLength::from_const(20),
+        heading_line_length: None,
+        code_block_line_length: None,
         length_mode: LengthMode::Visual,
         ..Default::default()
     };
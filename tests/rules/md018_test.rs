@@ -146,6 +146,15 @@ fn test_emoji_hashtags() {
     );
 }
 
+#[test]
+fn test_fix_keeps_emoji_hashtag_lines() {
+    let rule = MD018NoMissingSpaceAtx::new();
+    let content = "# Title\n\n#️⃣ Emoji\n\n#Missing space\n";
+    let ctx = LintContext::new(content, rumdl_lib::config::MarkdownFlavor::Standard, None);
+    let fixed = rule.fix(&ctx).unwrap();
+    assert_eq!(fixed, "# Title\n\n#️⃣ Emoji\n\n# Missing space\n");
+}
+
 #[test]
 fn test_hashtag_vs_heading() {
     let rule = MD018NoMissingSpaceAtx::new();
@@ -1,6 +1,6 @@
 use rumdl_lib::lint_context::LintContext;
 use rumdl_lib::rule::Rule;
-use rumdl_lib::rules::{ListStyle, MD029OrderedListPrefix};
+use rumdl_lib::rules::{ListStyle, MD029Config, MD029OrderedListPrefix};
 
 /// Tests for code block separation behavior in MD029
 /// Based on CommonMark specification and markdownlint compatibility testing
@@ -302,3 +302,51 @@ code block right after list
         );
     }
 }
+
+#[test]
+fn test_numbering_continues_after_code_block() {
+    // A list that picks up at 3 after the code block continues the first list
+    let content = "1. First item\n2. Second item\n\n```\ncode\n```\n\n3. Third item\n4. Fourth item\n";
+    let rule = MD029OrderedListPrefix::new(ListStyle::Ordered);
+    let ctx = LintContext::new(content, rumdl_lib::config::MarkdownFlavor::Standard, None);
+    assert!(rule.check(&ctx).unwrap().is_empty());
+
+    // Numbers that continue but skip ahead are fixed to follow on from the first list
+    let content = "1. First item\n2. Second item\n\n```\ncode\n```\n\n5. Third item\n9. Fourth item\n";
+    let ctx = LintContext::new(content, rumdl_lib::config::MarkdownFlavor::Standard, None);
+    let result = rule.check(&ctx).unwrap();
+    assert_eq!(result.len(), 2);
+    assert!(result[0].message.contains("expected 3"));
+    assert_eq!(
+        rule.fix(&ctx).unwrap(),
+        "1. First item\n2. Second item\n\n```\ncode\n```\n\n3. Third item\n4. Fourth item\n"
+    );
+}
+
+#[test]
+fn test_numbering_restarts_after_code_block() {
+    let content = "1. First item\n2. Second item\n\n```\ncode\n```\n\n3. Third item\n4. Fourth item\n";
+    let rule = MD029OrderedListPrefix::from_config_struct(MD029Config {
+        style: ListStyle::Ordered,
+        restart_after_interruption: true,
+    });
+    let ctx = LintContext::new(content, rumdl_lib::config::MarkdownFlavor::Standard, None);
+    let result = rule.check(&ctx).unwrap();
+    assert_eq!(result.len(), 2);
+    assert_eq!(result[0].line, 8);
+    assert!(result[0].message.contains("expected 1"));
+    assert_eq!(
+        rule.fix(&ctx).unwrap(),
+        "1. First item\n2. Second item\n\n```\ncode\n```\n\n1. Third item\n2. Fourth item\n"
+    );
+}
+
+#[test]
+fn test_heading_always_restarts_numbering() {
+    let content = "1. First item\n2. Second item\n\n## Next\n\n3. Third item\n";
+    let rule = MD029OrderedListPrefix::new(ListStyle::Ordered);
+    let ctx = LintContext::new(content, rumdl_lib::config::MarkdownFlavor::Standard, None);
+    let result = rule.check(&ctx).unwrap();
+    assert_eq!(result.len(), 1);
+    assert!(result[0].message.contains("expected 1"));
+}
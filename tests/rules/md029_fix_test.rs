@@ -1,11 +1,14 @@
 use rumdl_lib::lint_context::LintContext;
 use rumdl_lib::rule::Rule;
-use rumdl_lib::rules::MD029OrderedListPrefix;
+use rumdl_lib::rules::{MD029Config, MD029OrderedListPrefix};
 
 #[test]
 fn test_md029_fix_with_2_space_code_blocks() {
     // Test that fix correctly handles 2-space indented code blocks breaking lists
-    let rule = MD029OrderedListPrefix::default();
+    let rule = MD029OrderedListPrefix::from_config_struct(MD029Config {
+        restart_after_interruption: true,
+        ..Default::default()
+    });
     let content = r#"# Title
 
 1. Test 1
@@ -105,7 +108,10 @@ fn test_md029_fix_with_4_space_code_blocks() {
 #[test]
 fn test_md029_fix_matches_check() {
     // Test that fix and check are consistent
-    let rule = MD029OrderedListPrefix::default();
+    let rule = MD029OrderedListPrefix::from_config_struct(MD029Config {
+        restart_after_interruption: true,
+        ..Default::default()
+    });
     let content = r#"1. First item
 
   ```
@@ -141,7 +147,10 @@ fn test_md029_fix_matches_check() {
 #[test]
 fn test_md029_fix_preserves_content() {
     // Test that fix only changes the numbers, not the content
-    let rule = MD029OrderedListPrefix::default();
+    let rule = MD029OrderedListPrefix::from_config_struct(MD029Config {
+        restart_after_interruption: true,
+        ..Default::default()
+    });
     let content = r#"1. First item with some text
 
   ```python
@@ -1,12 +1,15 @@
 use rumdl_lib::lint_context::LintContext;
 use rumdl_lib::rule::Rule;
-use rumdl_lib::rules::MD029OrderedListPrefix;
+use rumdl_lib::rules::{MD029Config, MD029OrderedListPrefix};
 
 #[test]
 fn test_md029_2_space_code_blocks_break_lists() {
     // Test that 2-space indented code blocks break list continuity
     // This should match markdownlint's behavior
-    let rule = MD029OrderedListPrefix::default();
+    let rule = MD029OrderedListPrefix::from_config_struct(MD029Config {
+        restart_after_interruption: true,
+        ..Default::default()
+    });
     let content = r#"# Title
 
 1. Test 1
@@ -126,7 +129,10 @@ fn test_md029_3_space_code_blocks_continue_lists() {
 #[test]
 fn test_md029_unindented_code_blocks_break_lists() {
     // Test that unindented code blocks definitely break list continuity
-    let rule = MD029OrderedListPrefix::default();
+    let rule = MD029OrderedListPrefix::from_config_struct(MD029Config {
+        restart_after_interruption: true,
+        ..Default::default()
+    });
     let content = r#"# Title
 
 1. Test 1
@@ -162,7 +168,10 @@ cargo install ...
 #[test]
 fn test_md029_detection_with_2_space_code_blocks() {
     // Test that MD029 correctly detects issues with 2-space indented code blocks
-    let rule = MD029OrderedListPrefix::default();
+    let rule = MD029OrderedListPrefix::from_config_struct(MD029Config {
+        restart_after_interruption: true,
+        ..Default::default()
+    });
     let content = r#"1. Test 1
 
   ```sh
@@ -198,7 +207,10 @@ fn test_md029_detection_with_2_space_code_blocks() {
 #[test]
 fn test_md029_wider_markers() {
     // Test with wider list markers like "10." which affect min_continuation_indent
-    let rule = MD029OrderedListPrefix::default();
+    let rule = MD029OrderedListPrefix::from_config_struct(MD029Config {
+        restart_after_interruption: true,
+        ..Default::default()
+    });
     let content = r#"1. First item
 10. Test item with wide marker
 
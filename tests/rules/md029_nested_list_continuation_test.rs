@@ -3,7 +3,7 @@
 /// within list items according to CommonMark specification.
 use rumdl_lib::lint_context::LintContext;
 use rumdl_lib::rule::Rule;
-use rumdl_lib::rules::MD029OrderedListPrefix;
+use rumdl_lib::rules::{MD029Config, MD029OrderedListPrefix};
 
 #[test]
 fn test_md029_nested_bullets_continue_list() {
@@ -157,7 +157,10 @@ fn test_md029_complex_continuation_content() {
 #[test]
 fn test_md029_unindented_text_breaks_list() {
     // Test that unindented text between list items breaks continuity
-    let rule = MD029OrderedListPrefix::default();
+    let rule = MD029OrderedListPrefix::from_config_struct(MD029Config {
+        restart_after_interruption: true,
+        ..Default::default()
+    });
     let content = r#"1. First item
 
 2. Second item
@@ -1,6 +1,6 @@
 use rumdl_lib::lint_context::LintContext;
 use rumdl_lib::rule::Rule;
-use rumdl_lib::rules::{ListStyle, MD029OrderedListPrefix};
+use rumdl_lib::rules::{ListStyle, MD029Config, MD029OrderedListPrefix};
 use rumdl_lib::utils::range_utils::LineIndex;
 
 #[test]
@@ -83,7 +83,10 @@ fn test_line_index() {
 
 #[test]
 fn test_md029_with_code_blocks() {
-    let rule = MD029OrderedListPrefix::new(rumdl_lib::rules::ListStyle::Ordered);
+    let rule = MD029OrderedListPrefix::from_config_struct(MD029Config {
+        style: ListStyle::Ordered,
+        restart_after_interruption: true,
+    });
 
     // Non-indented code blocks break the list per CommonMark. Each list item
     // becomes its own list, so "2." and "3." should be "1." with ListStyle::Ordered.
@@ -113,7 +116,10 @@ final code
 
 #[test]
 fn test_md029_nested_with_code_blocks() {
-    let rule = MD029OrderedListPrefix::new(rumdl_lib::rules::ListStyle::Ordered);
+    let rule = MD029OrderedListPrefix::from_config_struct(MD029Config {
+        style: ListStyle::Ordered,
+        restart_after_interruption: true,
+    });
 
     // NOTE: The code block after "1. First substep" is NOT indented (column 0).
     // This breaks the nested list per CommonMark. "2. Second substep" becomes
@@ -224,7 +230,10 @@ fn test_zero_padded_numbers() {
 
 #[test]
 fn test_lists_with_inline_html() {
-    let rule = MD029OrderedListPrefix::default();
+    let rule = MD029OrderedListPrefix::from_config_struct(MD029Config {
+        restart_after_interruption: true,
+        ..Default::default()
+    });
     // Add blank line after HTML block so "4." becomes a new list item
     // (without blank line, "4." is consumed by the HTML block per CommonMark)
     let content = "\
@@ -518,7 +527,10 @@ fn test_md029_multiline_2_space_indent() {
 
 #[test]
 fn test_md029_multiline_mixed_content() {
-    let rule = MD029OrderedListPrefix::new(ListStyle::Ordered);
+    let rule = MD029OrderedListPrefix::from_config_struct(MD029Config {
+        style: ListStyle::Ordered,
+        restart_after_interruption: true,
+    });
 
     // Code blocks between items break the list per CommonMark
     let content = r#"1. First item
@@ -1475,3 +1487,123 @@ mod item_counts {
         assert!(result.is_empty());
     }
 }
+
+/// Fixing all-`1.` lists into sequence (and back)
+mod renumber_fix {
+    use super::*;
+
+    fn fix(style: ListStyle, content: &str) -> String {
+        let rule = MD029OrderedListPrefix::new(style);
+        let ctx = LintContext::new(content, rumdl_lib::config::MarkdownFlavor::Standard, None);
+        let fixed = rule.fix(&ctx).unwrap();
+
+        // The fixed content must be clean, so the fix is stable
+        let fixed_ctx = LintContext::new(&fixed, rumdl_lib::config::MarkdownFlavor::Standard, None);
+        assert!(
+            rule.check(&fixed_ctx).unwrap().is_empty(),
+            "Fix left warnings:\n{fixed}"
+        );
+        fixed
+    }
+
+    #[test]
+    fn test_all_ones_to_sequential() {
+        let content = "1. First\n1. Second\n1. Third\n";
+        assert_eq!(fix(ListStyle::Ordered, content), "1. First\n2. Second\n3. Third\n");
+    }
+
+    #[test]
+    fn test_sequential_to_all_ones() {
+        let content = "1. First\n2. Second\n3. Third\n";
+        assert_eq!(fix(ListStyle::One, content), "1. First\n1. Second\n1. Third\n");
+    }
+
+    #[test]
+    fn test_all_ones_to_ordered0() {
+        let content = "1. First\n1. Second\n1. Third\n";
+        assert_eq!(fix(ListStyle::Ordered0, content), "0. First\n1. Second\n2. Third\n");
+    }
+
+    #[test]
+    fn test_nested_levels_renumber_independently() {
+        let content = "\
+1. One
+1. Two
+   1. Two a
+   1. Two b
+      1. Deep
+      1. Deeper
+   1. Two c
+1. Three
+   1. Three a
+";
+        let expected = "\
+1. One
+2. Two
+   1. Two a
+   2. Two b
+      1. Deep
+      2. Deeper
+   3. Two c
+3. Three
+   1. Three a
+";
+        assert_eq!(fix(ListStyle::Ordered, content), expected);
+    }
+
+    #[test]
+    fn test_lists_in_blockquotes() {
+        let content = "\
+> 1. Quoted
+> 1. Quoted two
+>    1. Nested
+>    1. Nested two
+> 1. Quoted three
+";
+        let expected = "\
+> 1. Quoted
+> 2. Quoted two
+>    1. Nested
+>    2. Nested two
+> 3. Quoted three
+";
+        assert_eq!(fix(ListStyle::Ordered, content), expected);
+    }
+
+    #[test]
+    fn test_parenthesis_markers() {
+        let content = "1) First\n1) Second\n1) Third\n";
+        assert_eq!(fix(ListStyle::Ordered, content), "1) First\n2) Second\n3) Third\n");
+        assert_eq!(fix(ListStyle::One, "1) First\n2) Second\n"), "1) First\n1) Second\n");
+    }
+
+    #[test]
+    fn test_separate_lists_restart() {
+        let content = "1. A\n1. B\n\nText\n\n1. C\n1. D\n";
+        assert_eq!(fix(ListStyle::Ordered, content), "1. A\n2. B\n\nText\n\n1. C\n2. D\n");
+    }
+
+    #[test]
+    fn test_widened_marker_keeps_continuation_in_item() {
+        // `1.` becomes `10.`, so content indented for the old marker has to move along,
+        // or the paragraph and nested list would no longer belong to the tenth item
+        let mut content = "1. Item\n".repeat(9);
+        content.push_str("1. Tenth\n\n   Continuation\n\n   1. Sub\n   1. Sub two\n\n1. Eleventh\n");
+
+        let mut expected: String = (1..=9).map(|i| format!("{i}. Item\n")).collect();
+        expected.push_str("10. Tenth\n\n    Continuation\n\n    1. Sub\n    2. Sub two\n\n11. Eleventh\n");
+
+        assert_eq!(fix(ListStyle::Ordered, &content), expected);
+    }
+
+    #[test]
+    fn test_widened_marker_in_blockquote() {
+        let mut content = "> 1. Item\n".repeat(9);
+        content.push_str("> 1. Tenth\n>    more\n> 1. Eleventh\n");
+
+        let mut expected: String = (1..=9).map(|i| format!("> {i}. Item\n")).collect();
+        expected.push_str("> 10. Tenth\n>     more\n> 11. Eleventh\n");
+
+        assert_eq!(fix(ListStyle::Ordered, &content), expected);
+    }
+}
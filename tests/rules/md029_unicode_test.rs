@@ -1,6 +1,6 @@
 use rumdl_lib::lint_context::LintContext;
 use rumdl_lib::rule::Rule;
-use rumdl_lib::rules::MD029OrderedListPrefix;
+use rumdl_lib::rules::{MD029Config, MD029OrderedListPrefix};
 
 #[test]
 fn test_unicode_ordered_list_valid() {
@@ -80,7 +80,10 @@ fn test_unicode_nested_ordered_lists_invalid() {
 
 #[test]
 fn test_unicode_with_code_blocks() {
-    let rule = MD029OrderedListPrefix::default();
+    let rule = MD029OrderedListPrefix::from_config_struct(MD029Config {
+        restart_after_interruption: true,
+        ..Default::default()
+    });
     let content = "\
 1. First item with Unicode café
 ```
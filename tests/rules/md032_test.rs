@@ -415,3 +415,69 @@ fn test_still_flags_inappropriate_cases() {
         assert!(!result.is_empty(), "Should still flag inappropriate cases: {case}");
     }
 }
+
+#[test]
+fn test_colon_intro_requires_blank_line() {
+    let rule = MD032BlanksAroundLists;
+
+    // A list directly after "Steps:" is still a list that needs a blank line before it
+    let cases = [
+        ("Steps:\n- Install\n- Run\n", "Steps:\n\n- Install\n- Run\n"),
+        ("Steps:\n1. Install\n2. Run\n", "Steps:\n\n1. Install\n2. Run\n"),
+        ("Steps:\n* Install\n", "Steps:\n\n* Install\n"),
+        (
+            "# Setup\n\nYou will need:\n  - Rust\n",
+            "# Setup\n\nYou will need:\n\n  - Rust\n",
+        ),
+        (
+            "Before:\n- a\n\nAfter:\n- b\n\nDone\n",
+            "Before:\n\n- a\n\nAfter:\n\n- b\n\nDone\n",
+        ),
+    ];
+
+    for (content, expected) in cases {
+        let ctx = LintContext::new(content, rumdl_lib::config::MarkdownFlavor::Standard, None);
+        let result = rule.check(&ctx).unwrap();
+        assert!(!result.is_empty(), "Should flag list after colon: {content:?}");
+        assert!(
+            result
+                .iter()
+                .all(|w| w.message == "List should be preceded by blank line")
+        );
+
+        let fixed = rule.fix(&ctx).unwrap();
+        assert_eq!(fixed, expected);
+        let ctx = LintContext::new(&fixed, rumdl_lib::config::MarkdownFlavor::Standard, None);
+        assert!(rule.check(&ctx).unwrap().is_empty(), "Fix should be stable: {fixed:?}");
+    }
+}
+
+#[test]
+fn test_colon_intro_in_blockquote() {
+    let rule = MD032BlanksAroundLists;
+    let content = "> Steps:\n> - Install\n> - Run\n";
+    let ctx = LintContext::new(content, rumdl_lib::config::MarkdownFlavor::Standard, None);
+    let result = rule.check(&ctx).unwrap();
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].line, 2);
+}
+
+#[test]
+fn test_colon_intro_nested_list_not_flagged() {
+    let rule = MD032BlanksAroundLists;
+    // A sub-list under a list item ending in a colon belongs to that item
+    let content = "- Steps:\n  - Install\n  - Run\n\n1. Steps:\n   - Install\n";
+    let ctx = LintContext::new(content, rumdl_lib::config::MarkdownFlavor::Standard, None);
+    assert!(rule.check(&ctx).unwrap().is_empty());
+}
+
+#[test]
+fn test_colon_intro_lazy_continuation_not_flagged() {
+    let rule = MD032BlanksAroundLists;
+    // Indented four columns past the paragraph, the marker is paragraph text; a blank
+    // line would turn it into an indented code block
+    let content = "Steps:\n    - not a list\nMore text\n";
+    let ctx = LintContext::new(content, rumdl_lib::config::MarkdownFlavor::Standard, None);
+    assert!(rule.check(&ctx).unwrap().is_empty());
+    assert_eq!(rule.fix(&ctx).unwrap(), content);
+}
@@ -1,5 +1,5 @@
 use rumdl_lib::config::{Config, GlobalConfig};
-use rumdl_lib::rules::{all_rules, filter_rules};
+use rumdl_lib::rules::{all_rules, filter_rules, rule_by_name};
 use std::collections::HashSet;
 
 #[test]
@@ -7,8 +7,8 @@ fn test_all_rules_returns_all_rules() {
     let config = Config::default();
     let rules = all_rules(&config);
 
-    // Should return all 56 rules as defined in the RULES array
-    assert_eq!(rules.len(), 56);
+    // Should return all 98 rules as defined in the RULES array
+    assert_eq!(rules.len(), 98);
 
     // Verify some specific rules are present
     let rule_names: HashSet<String> = rules.iter().map(|r| r.name().to_string()).collect();
@@ -216,3 +216,38 @@ fn test_filter_rules_preserves_rule_order() {
 
     assert_eq!(all_names, filtered_names);
 }
+
+#[test]
+fn test_rule_by_name_matches_all_rules() {
+    let config = Config::default();
+    for rule in all_rules(&config) {
+        let found = rule_by_name(rule.name(), &config).expect("every registered rule should be found by name");
+        assert_eq!(found.name(), rule.name());
+    }
+}
+
+#[test]
+fn test_rule_by_name_lookup_forms() {
+    let config = Config::default();
+    for query in ["MD013", "md013", "013", " MD013 "] {
+        assert_eq!(rule_by_name(query, &config).map(|r| r.name()), Some("MD013"), "{query}");
+    }
+    assert!(rule_by_name("MD999", &config).is_none());
+    assert!(rule_by_name("13", &config).is_none());
+    assert!(rule_by_name("", &config).is_none());
+}
+
+#[test]
+fn test_rule_by_name_uses_config() {
+    let mut config = Config::default();
+    let mut rule_config = rumdl_lib::config::RuleConfig::default();
+    rule_config
+        .values
+        .insert("line-length".to_string(), toml::Value::Integer(120));
+    config.rules.insert("MD013".to_string(), rule_config);
+
+    let rule = rule_by_name("MD013", &config).unwrap();
+    let content = format!("{}\n", "a".repeat(100));
+    let ctx = rumdl_lib::lint_context::LintContext::new(&content, rumdl_lib::config::MarkdownFlavor::Standard, None);
+    assert!(rule.check(&ctx).unwrap().is_empty());
+}
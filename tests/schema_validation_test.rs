@@ -66,6 +66,20 @@ flavor = "mkdocs"
     assert!(validate_toml_config(toml).is_ok());
 }
 
+#[test]
+fn test_extend() {
+    assert!(validate_toml_config("extend = \"../base.toml\"\n\n[global]\nline_length = 100\n").is_ok());
+    assert!(validate_toml_config("extend = [\"base.toml\", \"strict.toml\"]\n").is_ok());
+    assert!(validate_toml_config("extend = 1\n").is_err());
+}
+
+#[test]
+fn test_enabled_for_flavors() {
+    assert!(validate_toml_config("[MD033]\nenabled-for-flavors = [\"standard\", \"mkdocs\"]\n").is_ok());
+    assert!(validate_toml_config("[MD033]\nenabled-for-flavors = [\"asciidoc\"]\n").is_err());
+    assert!(validate_toml_config("[MD033]\nenabled-for-flavors = \"mdx\"\n").is_err());
+}
+
 #[test]
 fn test_per_file_ignores() {
     let toml = r#"
@@ -76,6 +90,38 @@ fn test_per_file_ignores() {
     assert!(validate_toml_config(toml).is_ok());
 }
 
+#[test]
+fn test_vscode_section() {
+    let toml = r#"
+[vscode]
+format-on-save = true
+min-severity = "error"
+"#;
+    assert!(validate_toml_config(toml).is_ok());
+
+    let invalid = r#"
+[vscode]
+min-severity = "info"
+"#;
+    assert!(validate_toml_config(invalid).is_err());
+}
+
+#[test]
+fn test_fmt_section() {
+    let toml = r#"
+[fmt]
+enable = ["MD009", "MD012"]
+disable = ["MD022"]
+"#;
+    assert!(validate_toml_config(toml).is_ok());
+
+    let invalid = r#"
+[fmt]
+enable = "MD009"
+"#;
+    assert!(validate_toml_config(invalid).is_err());
+}
+
 #[test]
 fn test_rule_specific_config() {
     let toml = r#"
@@ -7,6 +7,8 @@ use rumdl_lib::types::LineLength;
 fn create_sentence_per_line_rule() -> MD013LineLength {
     MD013LineLength::from_config_struct(MD013Config {
         line_length: LineLength::from_const(80),
+        heading_line_length: None,
+        code_block_line_length: None,
         code_blocks: false,
         tables: false,
         headings: false,
@@ -16,6 +18,7 @@ fn create_sentence_per_line_rule() -> MD013LineLength {
         reflow_mode: ReflowMode::SentencePerLine,
         length_mode: rumdl_lib::rules::md013_line_length::md013_config::LengthMode::default(),
         abbreviations: None,
+        ignore_reference_definitions: true,
     })
 }
 
@@ -173,6 +176,8 @@ fn test_single_sentence_with_no_line_length_constraint() {
     // Reported in issue #124
     let rule = MD013LineLength::from_config_struct(MD013Config {
         line_length: LineLength::from_const(0), // No line-length constraint
+        heading_line_length: None,
+        code_block_line_length: None,
         code_blocks: false,
         tables: false,
         headings: false,
@@ -182,6 +187,7 @@ fn test_single_sentence_with_no_line_length_constraint() {
         reflow_mode: ReflowMode::SentencePerLine,
         length_mode: rumdl_lib::rules::md013_line_length::md013_config::LengthMode::default(),
         abbreviations: None,
+        ignore_reference_definitions: true,
     });
     let content = "This document provides advice for porting Rust code using PyO3 to run under\n\
                    free-threaded Python.";
@@ -233,6 +239,8 @@ fn test_custom_abbreviations_recognized() {
     // "Assn" is not a built-in abbreviation, so without configuration it would split
     let rule = MD013LineLength::from_config_struct(MD013Config {
         line_length: LineLength::from_const(0), // No line-length constraint
+        heading_line_length: None,
+        code_block_line_length: None,
         code_blocks: false,
         tables: false,
         headings: false,
@@ -242,6 +250,7 @@ fn test_custom_abbreviations_recognized() {
         reflow_mode: ReflowMode::SentencePerLine,
         length_mode: rumdl_lib::rules::md013_line_length::md013_config::LengthMode::default(),
         abbreviations: Some(vec!["Assn".to_string()]),
+        ignore_reference_definitions: true,
     });
 
     // With custom "Assn" abbreviation, this should be ONE sentence
@@ -261,6 +270,8 @@ fn test_custom_abbreviations_merged_with_builtin() {
     // Test that custom abbreviations are ADDED to built-in ones, not replacing them
     let rule = MD013LineLength::from_config_struct(MD013Config {
         line_length: LineLength::from_const(0),
+        heading_line_length: None,
+        code_block_line_length: None,
         code_blocks: false,
         tables: false,
         headings: false,
@@ -270,6 +281,7 @@ fn test_custom_abbreviations_merged_with_builtin() {
         reflow_mode: ReflowMode::SentencePerLine,
         length_mode: rumdl_lib::rules::md013_line_length::md013_config::LengthMode::default(),
         abbreviations: Some(vec!["Assn".to_string()]),
+        ignore_reference_definitions: true,
     });
 
     // Both "Dr." (built-in) and "Assn." (custom) should be recognized
@@ -289,6 +301,8 @@ fn test_custom_abbreviation_with_period_in_config() {
     // Test that abbreviations work whether configured with or without trailing period
     let rule_without_period = MD013LineLength::from_config_struct(MD013Config {
         line_length: LineLength::from_const(0),
+        heading_line_length: None,
+        code_block_line_length: None,
         code_blocks: false,
         tables: false,
         headings: false,
@@ -298,10 +312,13 @@ fn test_custom_abbreviation_with_period_in_config() {
         reflow_mode: ReflowMode::SentencePerLine,
         length_mode: rumdl_lib::rules::md013_line_length::md013_config::LengthMode::default(),
         abbreviations: Some(vec!["Univ".to_string()]),
+        ignore_reference_definitions: true,
     });
 
     let rule_with_period = MD013LineLength::from_config_struct(MD013Config {
         line_length: LineLength::from_const(0),
+        heading_line_length: None,
+        code_block_line_length: None,
         code_blocks: false,
         tables: false,
         headings: false,
@@ -311,6 +328,7 @@ fn test_custom_abbreviation_with_period_in_config() {
         reflow_mode: ReflowMode::SentencePerLine,
         length_mode: rumdl_lib::rules::md013_line_length::md013_config::LengthMode::default(),
         abbreviations: Some(vec!["Univ.".to_string()]),
+        ignore_reference_definitions: true,
     });
 
     let content = "Visit Univ. Campus for the tour.";
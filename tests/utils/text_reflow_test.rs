@@ -1404,3 +1404,25 @@ mod issue_170_nested_link_image {
         );
     }
 }
+
+#[test]
+fn test_reflow_inline_html_tags_and_entities() {
+    let options = ReflowOptions {
+        line_length: 30,
+        ..Default::default()
+    };
+
+    // Text before a tag or entity must not be repeated, and tags stay attached to their text
+    let result = reflow_line(
+        "Some words before <span class=\"x\">html</span> and&nbsp;an entity at the end.",
+        &options,
+    );
+    assert_eq!(
+        result,
+        vec![
+            "Some words before",
+            "<span class=\"x\">html</span>",
+            "and&nbsp;an entity at the end."
+        ]
+    );
+}